@@ -7,6 +7,8 @@ pub mod v003030;
 pub mod v004010;
 #[cfg(feature = "v004030")]
 pub mod v004030;
+#[cfg(feature = "v004060")]
+pub mod v004060;
 
 #[cfg(feature = "v005010")]
 pub mod v005010;
@@ -0,0 +1,140 @@
+//! Monetary amounts (`R` elements) as exact fixed-point values.
+//!
+//! X12 transmits amounts as decimal strings like `1234.5` or `-12`. [`Amount`]
+//! keeps them in hundredths so totals can be compared without floating point
+//! rounding.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub};
+use std::str::FromStr;
+
+/// A monetary amount in hundredths of the currency unit.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(i64);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+
+    pub fn from_cents(cents: i64) -> Amount {
+        Amount(cents)
+    }
+
+    pub fn cents(self) -> i64 {
+        self.0
+    }
+
+    /// Parses an optional element, treating a missing or empty value as zero.
+    pub fn parse_opt(value: Option<&str>) -> Result<Amount, AmountError> {
+        match value {
+            Some(v) if !v.is_empty() => v.parse(),
+            _ => Ok(Amount::ZERO),
+        }
+    }
+}
+
+/// The value is not a decimal number with at most two significant fraction digits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmountError(pub String);
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid amount {:?}", self.0)
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+impl FromStr for Amount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Amount, AmountError> {
+        let err = || AmountError(s.to_string());
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(err());
+        }
+        if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        // digits past the cents must be zeros, anything else would be rounded away
+        if frac.bytes().skip(2).any(|b| b != b'0') {
+            return Err(err());
+        }
+        let mut cents: i64 = 0;
+        let cents_digits = frac.bytes().chain(std::iter::repeat(b'0')).take(2);
+        for b in int.bytes().chain(cents_digits) {
+            cents = cents
+                .checked_mul(10)
+                .and_then(|c| c.checked_add(i64::from(b - b'0')))
+                .ok_or_else(err)?;
+        }
+        Ok(Amount(if negative { -cents } else { cents }))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        write!(f, "{sign}{}.{:02}", abs / 100, abs % 100)
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Amount) -> Amount {
+        Amount(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, rhs: Amount) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, rhs: Amount) -> Amount {
+        Amount(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(-self.0)
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Amount {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+/// An amount reported in the data next to the amount computed from its parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Balance {
+    pub expected: Amount,
+    pub actual: Amount,
+}
+
+impl Balance {
+    pub fn is_balanced(&self) -> bool {
+        self.expected == self.actual
+    }
+
+    /// `expected - actual`
+    pub fn difference(&self) -> Amount {
+        self.expected - self.actual
+    }
+}
@@ -0,0 +1,445 @@
+//! Fluent builders for segments, loops and transaction sets.
+//!
+//! Every segment has a [`SegmentBuilder`] with one setter per element, named
+//! after the element and taking `impl Into<String>`. Elements whose name
+//! occurs more than once in a segment carry their position, e.g.
+//! `product_service_id_03`.
+//!
+//! Every transaction set and loop has a builder that takes its segments and
+//! loops either finished or as builders. They refuse to [`build`] while a
+//! mandatory segment or the segment that starts a loop is missing, or a
+//! mandatory loop occurs too few times, instead of silently falling back to
+//! `Default`. Every segment handed in is checked for its mandatory elements,
+//! and transaction set builders fill `ST` and compute the `SE` segment count.
+//! HL levels of the 837 and 856 are numbered as on rendering. Where `ST` has
+//! a third element, as in 005010, it is set with
+//! `implementation_convention_reference`.
+//!
+//! ```
+//! use x12_types::v004010::*;
+//!
+//! let tx = _315::builder("0001")
+//!     .b4(B4::builder().shipment_status_code("AE").date("20230104"))
+//!     .loop_r4(
+//!         _315LoopR4::builder()
+//!             .r4(R4::builder()
+//!                 .port_or_terminal_function_code("L")
+//!                 .location_qualifier("UN")
+//!                 .location_identifier("USLGB")),
+//!     )
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(tx.se._01, "4");
+//! ```
+//!
+//! [`build`]: SegmentBuilder::build
+
+use crate::util::meta::{Requirement, SegmentMeta};
+use std::fmt;
+
+/// Reason a builder refused to build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// a mandatory segment or loop was not set, e.g. `B2A`
+    MissingSegment(&'static str),
+    /// a mandatory loop occurs fewer times than required, named by its first segment
+    MissingLoop { id: &'static str, min: usize },
+    /// a mandatory element of a segment is missing or empty
+    MissingElement {
+        segment: &'static str,
+        position: usize,
+        name: &'static str,
+    },
+    /// an element value is longer than the dictionary allows
+    ElementTooLong {
+        segment: &'static str,
+        position: usize,
+        max: usize,
+    },
+    /// the element position does not exist on the segment
+    UnknownElement {
+        segment: &'static str,
+        position: usize,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::MissingSegment(id) => write!(f, "mandatory segment {id} is missing"),
+            BuildError::MissingLoop { id, min } => {
+                write!(
+                    f,
+                    "mandatory loop {id} occurs less than the minimum of {min}"
+                )
+            }
+            BuildError::MissingElement {
+                segment,
+                position,
+                name,
+            } => write!(
+                f,
+                "mandatory element {segment}{position:02} ({name}) is missing"
+            ),
+            BuildError::ElementTooLong {
+                segment,
+                position,
+                max,
+            } => write!(
+                f,
+                "element {segment}{position:02} is longer than {max} characters"
+            ),
+            BuildError::UnknownElement { segment, position } => {
+                write!(f, "segment {segment} has no element {position:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Checks that every mandatory element of `segment` holds a non-empty value.
+pub fn check_segment<S: SegmentMeta>(segment: &S) -> Result<(), BuildError> {
+    for element in S::DESCRIPTOR.elements {
+        if element.requirement != Requirement::Mandatory {
+            continue;
+        }
+        if segment
+            .element(element.position)
+            .map_or(true, str::is_empty)
+        {
+            return Err(BuildError::MissingElement {
+                segment: S::DESCRIPTOR.id,
+                position: element.position,
+                name: element.name,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// A segment or loop handed to a builder, either finished or as its own builder.
+pub trait Buildable<T> {
+    /// Returns the finished value, or the first error of its builder.
+    fn finish(self) -> Result<T, BuildError>;
+}
+
+impl<S: SegmentMeta> Buildable<S> for S {
+    /// Checks the mandatory elements of the segment.
+    fn finish(self) -> Result<S, BuildError> {
+        check_segment(&self)?;
+        Ok(self)
+    }
+}
+
+impl<S: SegmentMeta + Default> Buildable<S> for SegmentBuilder<S> {
+    fn finish(self) -> Result<S, BuildError> {
+        self.build()
+    }
+}
+
+/// Number of segments in the rendered form of a transaction set, including `ST` and `SE`.
+pub fn segment_count<T: fmt::Display>(transaction: &T) -> usize {
+    transaction.to_string().matches("~\n").count()
+}
+
+/// Builds a segment element by element.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentBuilder<S> {
+    segment: S,
+    error: Option<BuildError>,
+}
+
+impl<S: SegmentMeta + Default> SegmentBuilder<S> {
+    pub fn new() -> Self {
+        SegmentBuilder {
+            segment: S::default(),
+            error: None,
+        }
+    }
+
+    /// Sets the element at `position` (starting at 1).
+    pub fn element(mut self, position: usize, value: impl Into<String>) -> Self {
+        if !self.segment.set_element(position, value.into()) && self.error.is_none() {
+            self.error = Some(BuildError::UnknownElement {
+                segment: S::DESCRIPTOR.id,
+                position,
+            });
+        }
+        self
+    }
+
+    /// Returns the segment, or an error if a mandatory element is missing.
+    pub fn build(self) -> Result<S, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        check_segment(&self.segment)?;
+        Ok(self.segment)
+    }
+}
+
+/// Generates a builder for a loop struct.
+///
+/// Fields are grouped by kind: mandatory `segments` and `loop`, `optional`
+/// segments and `optional_loop`, `repeated` segments and `loops`, and
+/// `required_loops` with their minimum occurrence and the ID of their first
+/// segment (`loop_300: _204Loop300 >= 1 as "S5"`). Setters take anything
+/// [`Buildable`]; the first error of a segment or loop handed in is returned
+/// by `build`.
+macro_rules! loop_builder {
+    (
+        $builder:ident => $target:ident {
+            $(trigger: [$($trg:ident: $trg_ty:ident),* $(,)?],)?
+            $(repeated_trigger: [$($rtrg:ident: $rtrg_ty:ident),* $(,)?],)?
+            $(segments: [$($seg:ident: $seg_ty:ident),* $(,)?],)?
+            $(loop: [$($lp:ident: $lp_ty:ident),* $(,)?],)?
+            $(optional: [$($opt:ident: $opt_ty:ident),* $(,)?],)?
+            $(optional_loop: [$($olp:ident: $olp_ty:ident),* $(,)?],)?
+            $(repeated: [$($rep:ident: $rep_ty:ident),* $(,)?],)?
+            $(loops: [$($lps:ident: $lps_ty:ident),* $(,)?],)?
+            $(required_loops: [$($rl:ident: $rl_ty:ident >= $rl_min:literal as $rl_id:literal),* $(,)?],)?
+        }
+    ) => {
+        #[doc = concat!("Builder for [`", stringify!($target), "`].")]
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            error: Option<$crate::util::builder::BuildError>,
+            $($($trg: Option<$trg_ty>,)*)?
+            $($($rtrg: Vec<$rtrg_ty>,)*)?
+            $($($seg: Option<$seg_ty>,)*)?
+            $($($lp: Option<$lp_ty>,)*)?
+            $($($opt: Option<$opt_ty>,)*)?
+            $($($olp: Option<$olp_ty>,)*)?
+            $($($rep: Vec<$rep_ty>,)*)?
+            $($($lps: Vec<$lps_ty>,)*)?
+            $($($rl: Vec<$rl_ty>,)*)?
+        }
+
+        impl $target {
+            #[doc = concat!("Starts a [`", stringify!($builder), "`].")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $crate::util::builder::Buildable<$target> for $target {
+            fn finish(self) -> Result<$target, $crate::util::builder::BuildError> {
+                Ok(self)
+            }
+        }
+
+        impl $crate::util::builder::Buildable<$target> for $builder {
+            fn finish(self) -> Result<$target, $crate::util::builder::BuildError> {
+                self.build()
+            }
+        }
+
+        impl $builder {
+            $crate::util::builder::builder_setters! {
+                $(set: [$($trg: $trg_ty,)*],)?
+                $(push: [$($rtrg: $rtrg_ty,)*],)?
+                $(set: [$($seg: $seg_ty,)*],)?
+                $(set: [$($lp: $lp_ty,)*],)?
+                $(set: [$($opt: $opt_ty,)*],)?
+                $(set: [$($olp: $olp_ty,)*],)?
+                $(push: [$($rep: $rep_ty,)*],)?
+                $(push: [$($lps: $lps_ty,)*],)?
+                $(push: [$($rl: $rl_ty,)*],)?
+            }
+
+            /// Returns the loop, or an error if a mandatory segment or element is missing.
+            pub fn build(self) -> Result<$target, $crate::util::builder::BuildError> {
+                $crate::util::builder::check_required! {
+                    self;
+                    $(segments: [$($trg: $trg_ty,)*],)?
+                    $(segments: [$($seg: $seg_ty,)*],)?
+                    $(segments: [$($lp: $lp_ty,)*],)?
+                    $(repeated_segments: [$($rtrg: $rtrg_ty,)*],)?
+                    $(required_loops: [$($rl: $rl_ty >= $rl_min as $rl_id,)*],)?
+                }
+                Ok($target {
+                    $($($trg: self.$trg,)*)?
+                    $($($rtrg: self.$rtrg,)*)?
+                    $($($seg: self.$seg.unwrap(),)*)?
+                    $($($lp: self.$lp.unwrap(),)*)?
+                    $($($opt: self.$opt,)*)?
+                    $($($olp: self.$olp,)*)?
+                    $($($rep: self.$rep,)*)?
+                    $($($lps: self.$lps,)*)?
+                    $($($rl: self.$rl,)*)?
+                })
+            }
+        }
+    };
+}
+pub(crate) use loop_builder;
+
+/// Generates a builder for a transaction set struct.
+///
+/// Takes the same field groups as [`loop_builder`] minus `ST` and `SE`:
+/// the builder is started with the transaction set control number, fills
+/// `ST` and computes the segment count of `SE` on build.
+macro_rules! transaction_builder {
+    (
+        $builder:ident => $target:ident ($id:literal) {
+            $(segments: [$($seg:ident: $seg_ty:ident),* $(,)?],)?
+            $(loop: [$($lp:ident: $lp_ty:ident),* $(,)?],)?
+            $(optional: [$($opt:ident: $opt_ty:ident),* $(,)?],)?
+            $(optional_loop: [$($olp:ident: $olp_ty:ident),* $(,)?],)?
+            $(repeated: [$($rep:ident: $rep_ty:ident),* $(,)?],)?
+            $(loops: [$($lps:ident: $lps_ty:ident),* $(,)?],)?
+            $(required_loops: [$($rl:ident: $rl_ty:ident >= $rl_min:literal as $rl_id:literal),* $(,)?],)?
+            $(renumber: [$($hl:ident),* $(,)?],)?
+        }
+    ) => {
+        #[doc = concat!("Builder for [`", stringify!($target), "`].")]
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            control_number: String,
+            implementation_convention_reference: Option<String>,
+            error: Option<$crate::util::builder::BuildError>,
+            $($($seg: Option<$seg_ty>,)*)?
+            $($($lp: Option<$lp_ty>,)*)?
+            $($($opt: Option<$opt_ty>,)*)?
+            $($($olp: Option<$olp_ty>,)*)?
+            $($($rep: Vec<$rep_ty>,)*)?
+            $($($lps: Vec<$lps_ty>,)*)?
+            $($($rl: Vec<$rl_ty>,)*)?
+        }
+
+        impl $target {
+            #[doc = concat!("Starts a [`", stringify!($builder), "`] with the transaction set control number (`ST02`/`SE02`).")]
+            pub fn builder(control_number: impl Into<String>) -> $builder {
+                $builder {
+                    control_number: control_number.into(),
+                    ..Default::default()
+                }
+            }
+        }
+
+        impl $builder {
+            /// Sets `ST03`, the implementation guide the transaction set follows,
+            /// e.g. `005010X279A1`.
+            pub fn implementation_convention_reference(mut self, value: impl Into<String>) -> Self {
+                self.implementation_convention_reference = Some(value.into());
+                self
+            }
+
+            $crate::util::builder::builder_setters! {
+                $(set: [$($seg: $seg_ty,)*],)?
+                $(set: [$($lp: $lp_ty,)*],)?
+                $(set: [$($opt: $opt_ty,)*],)?
+                $(set: [$($olp: $olp_ty,)*],)?
+                $(push: [$($rep: $rep_ty,)*],)?
+                $(push: [$($lps: $lps_ty,)*],)?
+                $(push: [$($rl: $rl_ty,)*],)?
+            }
+
+            /// Returns the transaction set with `ST` and `SE` filled in, or an
+            /// error if a mandatory segment or element is missing.
+            pub fn build(self) -> Result<$target, $crate::util::builder::BuildError> {
+                let mut st = $crate::util::builder::SegmentBuilder::<ST>::new()
+                    .element(1, $id)
+                    .element(2, self.control_number.as_str());
+                if let Some(reference) = &self.implementation_convention_reference {
+                    // fails on versions whose ST has no third element
+                    st = st.element(3, reference.as_str());
+                }
+                let st = st.build()?;
+                $crate::util::builder::check_required! {
+                    self;
+                    $(segments: [$($seg: $seg_ty,)*],)?
+                    $(segments: [$($lp: $lp_ty,)*],)?
+                    $(required_loops: [$($rl: $rl_ty >= $rl_min as $rl_id,)*],)?
+                }
+                let mut output = $target {
+                    st,
+                    $($($seg: self.$seg.unwrap(),)*)?
+                    $($($lp: self.$lp.unwrap(),)*)?
+                    $($($opt: self.$opt,)*)?
+                    $($($olp: self.$olp,)*)?
+                    $($($rep: self.$rep,)*)?
+                    $($($lps: self.$lps,)*)?
+                    $($($rl: self.$rl,)*)?
+                    se: SE::default(),
+                };
+                $($($crate::util::hl::renumber(&mut output.$hl);)*)?
+                // an empty SE would not be rendered, so count with a placeholder
+                output.se._01 = "0".to_string();
+                output.se._02 = output.st._02.clone();
+                output.se._01 = $crate::util::builder::segment_count(&output).to_string();
+                Ok(output)
+            }
+        }
+    };
+}
+pub(crate) use transaction_builder;
+
+/// Returns the first error handed in, a missing mandatory or trigger segment
+/// or loop, or a loop occurring less than its minimum.
+macro_rules! check_required {
+    (
+        $self:ident;
+        $(segments: [$($seg:ident: $seg_ty:ident,)*],)*
+        $(repeated_segments: [$($rseg:ident: $rseg_ty:ident,)*],)?
+        $(required_loops: [$($rl:ident: $rl_ty:ident >= $rl_min:literal as $rl_id:literal,)*],)?
+    ) => {
+        if let Some(error) = $self.error {
+            return Err(error);
+        }
+        $($(
+            if $self.$seg.is_none() {
+                return Err($crate::util::builder::BuildError::MissingSegment(stringify!($seg_ty)));
+            }
+        )*)*
+        $($(
+            if $self.$rseg.is_empty() {
+                return Err($crate::util::builder::BuildError::MissingSegment(stringify!($rseg_ty)));
+            }
+        )*)?
+        $($(
+            if $self.$rl.len() < $rl_min {
+                return Err($crate::util::builder::BuildError::MissingLoop {
+                    id: $rl_id,
+                    min: $rl_min,
+                });
+            }
+        )*)?
+    };
+}
+pub(crate) use check_required;
+
+/// Setter methods shared by the generated builders.
+macro_rules! builder_setters {
+    ($($kind:ident: [$($field:ident: $ty:ident,)*],)*) => {
+        $($(
+            $crate::util::builder::builder_setters!(@$kind $field: $ty);
+        )*)*
+    };
+    (@set $field:ident: $ty:ident) => {
+        #[doc = concat!("Sets the `", stringify!($ty), "`.")]
+        pub fn $field(mut self, $field: impl $crate::util::builder::Buildable<$ty>) -> Self {
+            match $field.finish() {
+                Ok(value) => self.$field = Some(value),
+                Err(error) => {
+                    self.error.get_or_insert(error);
+                }
+            }
+            self
+        }
+    };
+    (@push $field:ident: $ty:ident) => {
+        #[doc = concat!("Appends a `", stringify!($ty), "`.")]
+        pub fn $field(mut self, $field: impl $crate::util::builder::Buildable<$ty>) -> Self {
+            match $field.finish() {
+                Ok(value) => self.$field.push(value),
+                Err(error) => {
+                    self.error.get_or_insert(error);
+                }
+            }
+            self
+        }
+    };
+}
+pub(crate) use builder_setters;
@@ -0,0 +1,112 @@
+//! Hierarchical (`HL`) loops as a tree.
+//!
+//! Transaction sets like the 856 send their `HL` loops as a flat list where
+//! `HL01` numbers each loop and `HL02` points at its parent. [`build_tree`]
+//! nests such a list using the parent pointers, [`renumber`] recomputes
+//! `HL01`, `HL02` and `HL04` from the tree shape before rendering.
+//!
+//! Sets whose levels are distinct loop types, like the 837 billing provider,
+//! subscriber and patient loops, implement [`Level`] instead of [`Hierarchy`].
+
+use std::collections::HashMap;
+
+/// A loop started by an `HL` segment that holds its child loops.
+pub trait Hierarchy: Sized {
+    /// Hierarchical ID Number (`HL01`)
+    fn id(&self) -> &str;
+    /// Hierarchical Parent ID Number (`HL02`)
+    fn parent_id(&self) -> Option<&str>;
+    /// Sets `HL01`, `HL02` and the Hierarchical Child Code (`HL04`).
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str);
+    fn children(&self) -> &[Self];
+    fn children_mut(&mut self) -> &mut Vec<Self>;
+}
+
+/// A loop started by an `HL` segment whose child loops may be of another type.
+pub trait Level {
+    type Child: Level;
+    /// Sets `HL01`, `HL02` and the Hierarchical Child Code (`HL04`).
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str);
+    fn levels_mut(&mut self) -> &mut [Self::Child];
+}
+
+impl<T: Hierarchy> Level for T {
+    type Child = T;
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        Hierarchy::set_ids(self, id, parent_id, child_code)
+    }
+    fn levels_mut(&mut self) -> &mut [T] {
+        self.children_mut()
+    }
+}
+
+/// Nests a flat list of loops by their parent pointers.
+///
+/// Loops without a parent, or whose parent did not appear earlier in the
+/// list, become roots. Document order is kept among siblings.
+pub fn build_tree<T: Hierarchy>(flat: Vec<T>) -> Vec<T> {
+    let mut roots: Vec<T> = vec![];
+    let mut paths: HashMap<String, Vec<usize>> = HashMap::new();
+    for node in flat {
+        let id = node.id().to_string();
+        let parent = node
+            .parent_id()
+            .filter(|p| !p.is_empty())
+            .and_then(|p| paths.get(p))
+            .cloned();
+        let path = match parent {
+            Some(mut path) => {
+                let siblings = node_at(&mut roots, &path).children_mut();
+                path.push(siblings.len());
+                siblings.push(node);
+                path
+            }
+            None => {
+                roots.push(node);
+                vec![roots.len() - 1]
+            }
+        };
+        paths.insert(id, path);
+    }
+    roots
+}
+
+fn node_at<'a, T: Hierarchy>(roots: &'a mut [T], path: &[usize]) -> &'a mut T {
+    let mut node = &mut roots[path[0]];
+    for &i in &path[1..] {
+        node = &mut node.children_mut()[i];
+    }
+    node
+}
+
+/// Numbers the loops 1, 2, 3, ... in document order, points `HL02` at the
+/// parent and sets `HL04` to `1` if the loop has children, `0` otherwise.
+pub fn renumber<T: Level>(roots: &mut [T]) {
+    fn visit<T: Level>(nodes: &mut [T], parent: Option<&str>, next: &mut usize) {
+        for node in nodes {
+            let id = next.to_string();
+            *next += 1;
+            let child_code = if node.levels_mut().is_empty() {
+                "0"
+            } else {
+                "1"
+            };
+            node.set_ids(id.clone(), parent.map(str::to_string), child_code);
+            visit(node.levels_mut(), Some(&id), next);
+        }
+    }
+    visit(roots, None, &mut 1);
+}
+
+/// Visits all loops depth-first in document order.
+pub fn walk<T: Hierarchy>(roots: &[T]) -> Vec<&T> {
+    fn visit<'a, T: Hierarchy>(nodes: &'a [T], out: &mut Vec<&'a T>) {
+        for node in nodes {
+            out.push(node);
+            visit(node.children(), out);
+        }
+    }
+    let mut out = vec![];
+    visit(roots, &mut out);
+    out
+}
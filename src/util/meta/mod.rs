@@ -0,0 +1,231 @@
+//! Element metadata for segment types.
+//!
+//! Every segment exposes a static [`SegmentDescriptor`] through the
+//! [`SegmentMeta`] trait, listing the reference number, name, data type,
+//! min/max length and requirement designator of each element position.
+
+/// Requirement designator of an element within a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// M - the element must be present.
+    Mandatory,
+    /// O - the element may be present.
+    Optional,
+    /// X - presence depends on a syntax rule of the segment.
+    Conditional,
+}
+
+/// Data type of a simple element, or `Composite` for a composite structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataType {
+    /// ID - identifier from a code list
+    Id,
+    /// AN - string
+    AlphaNumeric,
+    /// Nn - numeric with `n` implied decimal positions
+    Numeric(u8),
+    /// R - decimal number
+    Decimal,
+    /// DT - date
+    Date,
+    /// TM - time
+    Time,
+    /// B - binary
+    Binary,
+    /// composite data structure, e.g. C001
+    Composite,
+}
+
+impl DataType {
+    /// The X12 data type code, e.g. `AN` or `N2`.
+    pub fn code(&self) -> String {
+        match self {
+            DataType::Id => "ID".to_string(),
+            DataType::AlphaNumeric => "AN".to_string(),
+            DataType::Numeric(d) => format!("N{d}"),
+            DataType::Decimal => "R".to_string(),
+            DataType::Date => "DT".to_string(),
+            DataType::Time => "TM".to_string(),
+            DataType::Binary => "B".to_string(),
+            DataType::Composite => "".to_string(),
+        }
+    }
+}
+
+/// Describes one element position of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElementDescriptor {
+    /// position within the segment, starting at 1
+    pub position: usize,
+    /// data element reference number, e.g. `329` or `C001`
+    pub id: &'static str,
+    /// data element name, e.g. `Transaction Set Control Number`
+    pub name: &'static str,
+    pub requirement: Requirement,
+    pub data_type: DataType,
+    /// minimum length; zero for composites
+    pub min: usize,
+    /// maximum length; zero for composites
+    pub max: usize,
+}
+
+/// Describes a segment and its element positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SegmentDescriptor {
+    /// segment ID, e.g. `ST`
+    pub id: &'static str,
+    /// segment name, e.g. `Transaction Set Header`
+    pub name: &'static str,
+    /// purpose of the segment as given by the standard
+    pub purpose: &'static str,
+    /// element positions in order; positions unknown to the dictionary are omitted
+    pub elements: &'static [ElementDescriptor],
+}
+
+impl SegmentDescriptor {
+    /// Element descriptor at `position` (starting at 1).
+    pub fn element(&self, position: usize) -> Option<&'static ElementDescriptor> {
+        self.elements.iter().find(|e| e.position == position)
+    }
+
+    /// First element descriptor with the given name, compared case-insensitively.
+    pub fn element_by_name(&self, name: &str) -> Option<&'static ElementDescriptor> {
+        self.elements
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+
+    /// All element descriptors carrying the given element reference number.
+    pub fn elements_by_id<'a>(
+        &self,
+        id: &'a str,
+    ) -> impl Iterator<Item = &'static ElementDescriptor> + 'a {
+        self.elements.iter().filter(move |e| e.id == id)
+    }
+}
+
+/// Runtime access to the element metadata and values of a segment.
+pub trait SegmentMeta {
+    /// Static descriptor of the segment.
+    const DESCRIPTOR: &'static SegmentDescriptor;
+
+    /// Static descriptor of the segment.
+    fn descriptor(&self) -> &'static SegmentDescriptor {
+        Self::DESCRIPTOR
+    }
+
+    /// Number of element positions the segment type holds.
+    fn element_count(&self) -> usize;
+
+    /// Value of the element at `position` (starting at 1), `None` if the
+    /// position is not present.
+    fn element(&self, position: usize) -> Option<&str>;
+
+    /// Sets the element at `position` (starting at 1); returns `false` if the
+    /// segment type has no such position.
+    fn set_element(&mut self, position: usize, value: String) -> bool;
+
+    /// Value of the first element with the given name, e.g.
+    /// `"Transaction Set Control Number"`.
+    fn element_by_name(&self, name: &str) -> Option<&str> {
+        Self::DESCRIPTOR
+            .element_by_name(name)
+            .and_then(|e| self.element(e.position))
+    }
+}
+
+/// Value access shared by mandatory (`String`) and optional (`Option<String>`) elements.
+pub(crate) trait ElementValue {
+    fn value(&self) -> Option<&str>;
+    fn set(&mut self, value: String);
+}
+
+impl ElementValue for String {
+    fn value(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+
+    fn set(&mut self, value: String) {
+        *self = value;
+    }
+}
+
+impl ElementValue for Option<String> {
+    fn value(&self) -> Option<&str> {
+        self.as_deref()
+    }
+
+    fn set(&mut self, value: String) {
+        *self = Some(value);
+    }
+}
+
+/// Implements [`SegmentMeta`] for a segment struct from its dictionary table.
+///
+/// Each table row ends with the name of the element setter generated on
+/// [`SegmentBuilder`](crate::util::builder::SegmentBuilder) for the segment.
+macro_rules! segment_meta {
+    (
+        $seg:ident, $name:literal, $purpose:literal,
+        [$($field:ident),* $(,)?],
+        [$(($pos:literal, $id:literal, $ename:literal, $req:ident, $ty:ident $(($dec:literal))?, $min:literal, $max:literal, $setter:ident)),* $(,)?]
+    ) => {
+        impl $crate::util::meta::SegmentMeta for $seg {
+            const DESCRIPTOR: &'static $crate::util::meta::SegmentDescriptor =
+                &$crate::util::meta::SegmentDescriptor {
+                    id: stringify!($seg),
+                    name: $name,
+                    purpose: $purpose,
+                    elements: &[$(
+                        $crate::util::meta::ElementDescriptor {
+                            position: $pos,
+                            id: $id,
+                            name: $ename,
+                            requirement: $crate::util::meta::Requirement::$req,
+                            data_type: $crate::util::meta::DataType::$ty $(($dec))?,
+                            min: $min,
+                            max: $max,
+                        }
+                    ),*],
+                };
+
+            fn element_count(&self) -> usize {
+                [$(stringify!($field)),*].len()
+            }
+
+            fn element(&self, position: usize) -> Option<&str> {
+                use $crate::util::meta::ElementValue as _;
+                let values = [$(self.$field.value()),*];
+                values.get(position.checked_sub(1)?).copied().flatten()
+            }
+
+            fn set_element(&mut self, position: usize, value: String) -> bool {
+                let fields: Vec<&mut dyn $crate::util::meta::ElementValue> = vec![$(&mut self.$field),*];
+                match position.checked_sub(1).and_then(|idx| fields.into_iter().nth(idx)) {
+                    Some(field) => {
+                        field.set(value);
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+
+        impl $seg {
+            #[doc = concat!("Starts a [`SegmentBuilder`](crate::util::builder::SegmentBuilder) for `", stringify!($seg), "`.")]
+            pub fn builder() -> $crate::util::builder::SegmentBuilder<$seg> {
+                $crate::util::builder::SegmentBuilder::new()
+            }
+        }
+
+        impl $crate::util::builder::SegmentBuilder<$seg> {
+            $(
+                #[doc = concat!("Sets element ", stringify!($pos), " of `", stringify!($seg), "`, ", $id, " - ", $ename, ".")]
+                pub fn $setter(self, value: impl Into<String>) -> Self {
+                    self.element($pos, value)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use segment_meta;
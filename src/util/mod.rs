@@ -9,8 +9,16 @@ use nom::sequence::delimited;
 use nom::IResult;
 use nom::Parser as _;
 
+pub mod amount;
+pub mod builder;
 pub mod dt;
+pub mod hl;
+pub mod meta;
+pub mod named;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod tm;
+pub mod xml;
 
 pub fn is_equal_payload<T: PartialEq>(src: &Transmission<T>, target: &Transmission<T>) -> bool {
     let src_group = &src.functional_group;
@@ -42,6 +50,11 @@ pub trait Parser<I, O, E> {
     fn parse(str: I) -> IResult<I, O>;
 }
 
+/// The value of an optional element, `None` if it is missing or empty.
+pub(crate) fn non_empty(value: &Option<String>) -> Option<String> {
+    value.clone().filter(|v| !v.is_empty())
+}
+
 pub fn unborrow_string(input: &&str) -> String {
     input.to_string()
 }
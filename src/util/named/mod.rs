@@ -0,0 +1,859 @@
+//! Serde representation with descriptive element names.
+//!
+//! By default segments serialize their elements with positional keys (`"01"`,
+//! `"02"`, ...). Wrapping a value in [`Named`] emits keys built from the
+//! segment metadata instead, e.g. `"ST01_TransactionSetIdentifierCode"`.
+//! [`deserialize`] reads both forms back, so the named representation
+//! round-trips losslessly.
+//!
+//! ```
+//! use x12_types::util::named::{self, Named};
+//! use x12_types::v004010::*;
+//!
+//! let st = ST { _01: "204".to_string(), _02: "0001".to_string() };
+//! let json = serde_json::to_string(&Named::new(&st, segment_descriptor)).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"ST01_TransactionSetIdentifierCode":"204","ST02_TransactionSetControlNumber":"0001"}"#
+//! );
+//! let mut de = serde_json::Deserializer::from_str(&json);
+//! let back: ST = named::deserialize(&mut de).unwrap();
+//! assert_eq!(back, st);
+//! ```
+
+use crate::util::meta::SegmentDescriptor;
+use serde::de::{self, DeserializeSeed, IntoDeserializer};
+use serde::ser::{self, Serialize, Serializer};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// Looks up the descriptor of a segment by its ID, e.g. `v005010::segment_descriptor`.
+pub type Lookup = fn(&str) -> Option<&'static SegmentDescriptor>;
+
+/// Serializes the wrapped value with descriptive element keys.
+pub struct Named<'a, T: ?Sized> {
+    value: &'a T,
+    lookup: Lookup,
+}
+
+impl<'a, T: ?Sized> Named<'a, T> {
+    /// Wraps `value`; `lookup` resolves the segment dictionary of the version in use.
+    pub fn new(value: &'a T, lookup: Lookup) -> Self {
+        Named { value, lookup }
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for Named<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(NamedSerializer {
+            inner: serializer,
+            lookup: self.lookup,
+        })
+    }
+}
+
+/// Descriptive key of the element at `position`, e.g. `ST01_TransactionSetIdentifierCode`.
+///
+/// Positions unknown to the dictionary only carry the reference designator, e.g. `ST11`.
+pub fn element_key(descriptor: &SegmentDescriptor, position: usize) -> String {
+    let mut key = format!("{}{:02}", descriptor.id, position);
+    if let Some(element) = descriptor.element(position) {
+        key.push('_');
+        // apostrophes join words ("Submitter's" -> "Submitters") rather than separate them
+        let name: String = element.name.chars().filter(|c| *c != '\'').collect();
+        for word in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                key.push(first.to_ascii_uppercase());
+                key.extend(chars);
+            }
+        }
+    }
+    key
+}
+
+/// Positional key (`"01"`) for a descriptive key of the segment `segment`.
+///
+/// Keys that are already positional, or not prefixed with the segment ID,
+/// are returned unchanged.
+pub fn positional_key<'k>(segment: &str, key: &'k str) -> &'k str {
+    match key.strip_prefix(segment) {
+        Some(rest) if rest.len() >= 2 && rest.as_bytes()[..2].iter().all(u8::is_ascii_digit) => {
+            if rest.len() == 2 || rest.as_bytes()[2] == b'_' {
+                &rest[..2]
+            } else {
+                key
+            }
+        }
+        _ => key,
+    }
+}
+
+/// Deserializes a value from either the positional or the descriptive representation.
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize(NamedDeserializer {
+        inner: deserializer,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// serialization
+
+struct NamedSerializer<S> {
+    inner: S,
+    lookup: Lookup,
+}
+
+/// Serializes a nested value through the renaming serializer.
+struct Nested<'a, T: ?Sized> {
+    value: &'a T,
+    lookup: Lookup,
+}
+
+impl<T: ?Sized + Serialize> Serialize for Nested<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(NamedSerializer {
+            inner: serializer,
+            lookup: self.lookup,
+        })
+    }
+}
+
+macro_rules! forward_primitive {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for NamedSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = StructCompound<S::SerializeStruct, S::SerializeMap>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward_primitive!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_some(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_newtype_struct(
+            name,
+            &Nested {
+                value,
+                lookup: self.lookup,
+            },
+        )
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Nested {
+                value,
+                lookup: self.lookup,
+            },
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_seq(len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple(len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_tuple_struct(name, len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_tuple_variant(name, variant_index, variant, len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Compound {
+            inner: self.inner.serialize_map(len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        // segments become maps, as their descriptive keys are built at runtime
+        match (self.lookup)(name) {
+            Some(descriptor) => Ok(StructCompound::Segment {
+                inner: self.inner.serialize_map(Some(len))?,
+                descriptor,
+                lookup: self.lookup,
+            }),
+            None => Ok(StructCompound::Struct {
+                inner: self.inner.serialize_struct(name, len)?,
+                lookup: self.lookup,
+            }),
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(Compound {
+            inner: self
+                .inner
+                .serialize_struct_variant(name, variant_index, variant, len)?,
+            lookup: self.lookup,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct Compound<C> {
+    inner: C,
+    lookup: Lookup,
+}
+
+impl<C: ser::SerializeSeq> ser::SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTuple> ser::SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), C::Error> {
+        self.inner.serialize_key(&Nested {
+            value: key,
+            lookup: self.lookup,
+        })
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_value(&Nested {
+            value,
+            lookup: self.lookup,
+        })
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeStructVariant> ser::SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner.serialize_field(
+            key,
+            &Nested {
+                value,
+                lookup: self.lookup,
+            },
+        )
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+enum StructCompound<S, M> {
+    Struct {
+        inner: S,
+        lookup: Lookup,
+    },
+    Segment {
+        inner: M,
+        descriptor: &'static SegmentDescriptor,
+        lookup: Lookup,
+    },
+}
+
+impl<S, M> ser::SerializeStruct for StructCompound<S, M>
+where
+    S: ser::SerializeStruct,
+    M: ser::SerializeMap<Ok = S::Ok, Error = S::Error>,
+{
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        match self {
+            StructCompound::Struct { inner, lookup } => inner.serialize_field(
+                key,
+                &Nested {
+                    value,
+                    lookup: *lookup,
+                },
+            ),
+            StructCompound::Segment {
+                inner,
+                descriptor,
+                lookup,
+            } => {
+                let key = match key.parse::<usize>() {
+                    Ok(position) => element_key(descriptor, position),
+                    Err(_) => key.to_string(),
+                };
+                inner.serialize_entry(
+                    &key,
+                    &Nested {
+                        value,
+                        lookup: *lookup,
+                    },
+                )
+            }
+        }
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        match self {
+            StructCompound::Struct { inner, .. } => inner.end(),
+            StructCompound::Segment { inner, .. } => inner.end(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// deserialization
+
+struct NamedDeserializer<D> {
+    inner: D,
+}
+
+/// Wraps the visitor of a struct; `segment` is set when its keys need translating.
+struct NamedVisitor<V> {
+    inner: V,
+    segment: Option<&'static str>,
+}
+
+/// Whether the serde field list is the positional element list of a segment.
+fn is_segment(fields: &[&str]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .all(|f| f.len() == 2 && f.bytes().all(|b| b.is_ascii_digit()))
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+                self.inner.$method(NamedVisitor { inner: visitor, segment: None })
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for NamedDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    );
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_unit_struct(
+            name,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_newtype_struct(
+            name,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_tuple(
+            len,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_tuple_struct(
+            name,
+            len,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let segment = is_segment(fields).then_some(name);
+        self.inner.deserialize_struct(
+            name,
+            fields,
+            NamedVisitor {
+                inner: visitor,
+                segment,
+            },
+        )
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_enum(
+            name,
+            variants,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: de::Visitor<'de>> de::Visitor<'de> for NamedVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit!(
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_string(String),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    );
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(NamedDeserializer {
+            inner: deserializer,
+        })
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner.visit_newtype_struct(NamedDeserializer {
+            inner: deserializer,
+        })
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(NamedAccess {
+            inner: seq,
+            segment: None,
+        })
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(NamedAccess {
+            inner: map,
+            segment: self.segment,
+        })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(NamedAccess {
+            inner: data,
+            segment: None,
+        })
+    }
+}
+
+/// Wraps seq, map and enum access so nested values keep being translated.
+struct NamedAccess<A> {
+    inner: A,
+    segment: Option<&'static str>,
+}
+
+/// Wraps a seed so the value it deserializes is translated as well.
+struct NamedSeed<S> {
+    inner: S,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for NamedSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.inner.deserialize(NamedDeserializer {
+            inner: deserializer,
+        })
+    }
+}
+
+/// Translates a descriptive segment key back into its positional form.
+struct KeySeed<S> {
+    inner: S,
+    segment: &'static str,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for KeySeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        let key = positional_key(self.segment, &key).to_string();
+        self.inner.deserialize(key.into_deserializer())
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for NamedAccess<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        self.inner.next_element_seed(NamedSeed { inner: seed })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for NamedAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        match self.segment {
+            Some(segment) => self.inner.next_key_seed(KeySeed {
+                inner: seed,
+                segment,
+            }),
+            None => self.inner.next_key_seed(NamedSeed { inner: seed }),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        self.inner.next_value_seed(NamedSeed { inner: seed })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for NamedAccess<A> {
+    type Error = A::Error;
+    type Variant = NamedAccess<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), A::Error> {
+        let (value, variant) = self.inner.variant_seed(NamedSeed { inner: seed })?;
+        Ok((
+            value,
+            NamedAccess {
+                inner: variant,
+                segment: None,
+            },
+        ))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for NamedAccess<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, A::Error> {
+        self.inner.newtype_variant_seed(NamedSeed { inner: seed })
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        self.inner.tuple_variant(
+            len,
+            NamedVisitor {
+                inner: visitor,
+                segment: None,
+            },
+        )
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let segment = None;
+        self.inner.struct_variant(
+            fields,
+            NamedVisitor {
+                inner: visitor,
+                segment,
+            },
+        )
+    }
+}
@@ -0,0 +1,347 @@
+//! JSON Schema export.
+//!
+//! [`json_schema`] describes the serde JSON layout of a transmission,
+//! transaction set or segment: positional element keys (`"01"`), optional
+//! fields omitted by `skip_serializing_if`, nullable `Option`s and `Vec`
+//! loops. Segment elements carry their name, reference number and min/max
+//! length from the segment metadata.
+//!
+//! ```
+//! use x12_types::util::schema::json_schema;
+//! use x12_types::v004010::*;
+//!
+//! let schema = json_schema::<_204>(segment_descriptor).unwrap();
+//! assert_eq!(schema["$ref"], "#/$defs/_204");
+//! let st = &schema["$defs"]["ST"];
+//! assert_eq!(st["properties"]["01"]["minLength"], 3);
+//! assert_eq!(st["required"], serde_json::json!(["01", "02"]));
+//! ```
+
+use crate::util::meta::SegmentDescriptor;
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+pub use serde::de::value::Error;
+
+/// Looks up the descriptor of a segment by its ID, e.g. `v004010::segment_descriptor`.
+pub type Lookup = fn(&str) -> Option<&'static SegmentDescriptor>;
+
+/// Builds the JSON Schema (draft 2020-12) of the serde JSON form of `T`.
+///
+/// Every struct becomes an entry in `$defs`, named after the Rust type.
+pub fn json_schema<T: Serialize + DeserializeOwned>(lookup: Lookup) -> Result<Value, Error> {
+    let state = RefCell::new(State::default());
+    let out = RefCell::new(Shape::String);
+    T::deserialize(Trace {
+        state: &state,
+        out: &out,
+        path: Vec::new(),
+        minimal: false,
+    })?;
+    let root = match out.into_inner() {
+        Shape::Struct(name) => name,
+        _ => return Err(de::Error::custom("only structs have a JSON schema")),
+    };
+
+    // serialize an instance with one struct reduced to its mandatory content;
+    // the keys left over are the ones always written
+    let names: Vec<&'static str> = state.borrow().structs.keys().copied().collect();
+    let mut emitted = BTreeMap::new();
+    for name in names {
+        let path = state.borrow().structs[name].path.clone();
+        state.borrow_mut().minimize = Some(name);
+        let value = T::deserialize(Trace {
+            state: &state,
+            out: &RefCell::new(Shape::String),
+            path: Vec::new(),
+            minimal: false,
+        })?;
+        let json = serde_json::to_value(&value).map_err(de::Error::custom)?;
+        let keys: Vec<String> = match locate(&json, &path) {
+            Some(Value::Object(map)) => map.keys().cloned().collect(),
+            _ => Vec::new(),
+        };
+        emitted.insert(name, keys);
+    }
+
+    let state = state.into_inner();
+    let mut defs = Map::new();
+    for (name, info) in &state.structs {
+        defs.insert(
+            name.to_string(),
+            struct_schema(name, info, &emitted[name], lookup),
+        );
+    }
+    Ok(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": root,
+        "$ref": format!("#/$defs/{root}"),
+        "$defs": defs,
+    }))
+}
+
+fn locate<'v>(json: &'v Value, path: &[&str]) -> Option<&'v Value> {
+    let mut current = json;
+    for key in path {
+        current = current.get(key)?;
+        while let Value::Array(items) = current {
+            current = items.first()?;
+        }
+    }
+    Some(current)
+}
+
+fn struct_schema(name: &str, info: &StructInfo, emitted: &[String], lookup: Lookup) -> Value {
+    let descriptor = if info.segment { lookup(name) } else { None };
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (field, shape) in &info.fields {
+        let always = emitted.iter().any(|key| key == field);
+        let mut schema = match shape {
+            // an `Option` that is written even when empty shows up as `null`
+            Shape::Option(inner) if always => json!({
+                "anyOf": [shape_schema(inner), { "type": "null" }],
+            }),
+            Shape::Option(inner) => shape_schema(inner),
+            shape => shape_schema(shape),
+        };
+        if let Some(element) =
+            descriptor.and_then(|d| field.parse().ok().and_then(|p| d.element(p)))
+        {
+            schema["description"] = json!(format!("{} ({})", element.name, element.id));
+            let target = match schema.get_mut("anyOf") {
+                Some(Value::Array(items)) => &mut items[0],
+                _ => &mut schema,
+            };
+            if element.max > 0 {
+                target["minLength"] = json!(element.min);
+                target["maxLength"] = json!(element.max);
+            }
+        }
+        if always {
+            required.push(json!(field));
+        }
+        properties.insert(field.to_string(), schema);
+    }
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    });
+    if let Some(descriptor) = descriptor {
+        schema["title"] = json!(format!("{} - {}", descriptor.id, descriptor.name));
+        if !descriptor.purpose.is_empty() {
+            schema["description"] = json!(descriptor.purpose);
+        }
+    }
+    schema
+}
+
+fn shape_schema(shape: &Shape) -> Value {
+    match shape {
+        Shape::String => json!({ "type": "string" }),
+        Shape::Option(inner) => shape_schema(inner),
+        Shape::Seq(inner) => json!({ "type": "array", "items": shape_schema(inner) }),
+        Shape::Struct(name) => json!({ "$ref": format!("#/$defs/{name}") }),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// type tracing
+
+/// Serde shape of a field, as observed through its `Deserialize` impl.
+#[derive(Debug, Clone)]
+enum Shape {
+    String,
+    Option(Box<Shape>),
+    Seq(Box<Shape>),
+    Struct(&'static str),
+}
+
+#[derive(Debug, Default)]
+struct StructInfo {
+    fields: Vec<(&'static str, Shape)>,
+    segment: bool,
+    /// field keys leading to the first occurrence in a fully populated instance
+    path: Vec<&'static str>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    structs: BTreeMap<&'static str, StructInfo>,
+    /// struct to build without any optional content
+    minimize: Option<&'static str>,
+    /// structs currently being traced, innermost last
+    active: Vec<&'static str>,
+}
+
+/// Deserializer that produces a sample value while recording the shape of
+/// every type it is asked for. Options are `Some` and sequences hold one
+/// item, unless `minimal` is set.
+struct Trace<'s, 'o> {
+    state: &'s RefCell<State>,
+    out: &'o RefCell<Shape>,
+    path: Vec<&'static str>,
+    minimal: bool,
+}
+
+impl<'de> de::Deserializer<'de> for Trace<'_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        *self.out.borrow_mut() = Shape::String;
+        visitor.visit_str("")
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.minimal {
+            *self.out.borrow_mut() = Shape::Option(Box::new(Shape::String));
+            return visitor.visit_none();
+        }
+        let inner = RefCell::new(Shape::String);
+        let value = visitor.visit_some(Trace {
+            state: self.state,
+            out: &inner,
+            path: self.path,
+            minimal: false,
+        })?;
+        *self.out.borrow_mut() = Shape::Option(Box::new(inner.into_inner()));
+        Ok(value)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let inner = RefCell::new(Shape::String);
+        let value = visitor.visit_seq(TraceSeq {
+            state: self.state,
+            out: &inner,
+            path: (!self.minimal).then_some(self.path),
+        })?;
+        if !self.minimal {
+            *self.out.borrow_mut() = Shape::Seq(Box::new(inner.into_inner()));
+        }
+        Ok(value)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let minimize = self.state.borrow().minimize == Some(name);
+        // a struct that contains itself, like the 856 HL tree, is built
+        // without optional content the second time so the trace ends
+        let recursive = self.state.borrow().active.contains(&name);
+        let first = !self.state.borrow().structs.contains_key(name);
+        if first {
+            let info = StructInfo {
+                segment: fields.iter().all(|f| f.bytes().all(|b| b.is_ascii_digit())),
+                path: self.path.clone(),
+                ..Default::default()
+            };
+            self.state.borrow_mut().structs.insert(name, info);
+        }
+        *self.out.borrow_mut() = Shape::Struct(name);
+        self.state.borrow_mut().active.push(name);
+        let value = visitor.visit_map(TraceStruct {
+            state: self.state,
+            name,
+            fields,
+            index: 0,
+            path: self.path,
+            record: first,
+            minimal: minimize || recursive,
+        });
+        self.state.borrow_mut().active.pop();
+        value
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct TraceSeq<'s, 'o> {
+    state: &'s RefCell<State>,
+    out: &'o RefCell<Shape>,
+    /// taken by the single item
+    path: Option<Vec<&'static str>>,
+}
+
+impl<'de> de::SeqAccess<'de> for TraceSeq<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.path.take() {
+            Some(path) => seed
+                .deserialize(Trace {
+                    state: self.state,
+                    out: self.out,
+                    path,
+                    minimal: false,
+                })
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+struct TraceStruct<'s> {
+    state: &'s RefCell<State>,
+    name: &'static str,
+    fields: &'static [&'static str],
+    index: usize,
+    path: Vec<&'static str>,
+    record: bool,
+    minimal: bool,
+}
+
+impl<'de> de::MapAccess<'de> for TraceStruct<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.fields.get(self.index) {
+            Some(field) => seed.deserialize(field.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let field = self.fields[self.index];
+        self.index += 1;
+        let out = RefCell::new(Shape::String);
+        let mut path = self.path.clone();
+        path.push(field);
+        let value = seed.deserialize(Trace {
+            state: self.state,
+            out: &out,
+            path,
+            minimal: self.minimal,
+        })?;
+        if self.record {
+            if let Some(info) = self.state.borrow_mut().structs.get_mut(self.name) {
+                info.fields.push((field, out.into_inner()));
+            }
+        }
+        Ok(value)
+    }
+}
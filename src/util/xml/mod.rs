@@ -0,0 +1,786 @@
+//! X12 XML representation.
+//!
+//! Writes and reads the XML form of any transmission, transaction set or
+//! segment. Segments become elements named after their ID with one child per
+//! element position (`<ST><ST01>835</ST01>...</ST>`), loops are wrapped in
+//! `Loop...` elements named after the field (`loop_2000` → `<Loop2000>`),
+//! functional groups in `<FunctionalGroup>` and transaction sets in
+//! `<TransactionSet>`. A transmission is rendered as `<Interchange>`.
+//!
+//! ```
+//! use x12_types::util::xml;
+//! use x12_types::v004010::*;
+//!
+//! let st = ST { _01: "204".to_string(), _02: "0001".to_string() };
+//! let str = xml::to_string(&st).unwrap();
+//! assert_eq!(str, "<ST>\n  <ST01>204</ST01>\n  <ST02>0001</ST02>\n</ST>\n");
+//! let back: ST = xml::from_str(&str).unwrap();
+//! assert_eq!(back, st);
+//! ```
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::ser::{self, Serialize};
+use std::cell::Cell;
+use std::fmt;
+
+/// Error raised while writing or reading the XML representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Renders `value` as indented X12 XML.
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    let content = value.serialize(ContentSerializer)?;
+    let name = match content {
+        Content::Struct(name, _) => name,
+        _ => return Err(Error("only structs can be rendered as XML".to_string())),
+    };
+    let mut out = String::new();
+    write_element(&mut out, &root_tag(name), &content, 0)?;
+    Ok(out)
+}
+
+/// Reads a value from its X12 XML representation.
+pub fn from_str<T: DeserializeOwned>(str: &str) -> Result<T, Error> {
+    let root = Reader::new(str).document()?;
+    T::deserialize(NodeDeserializer { node: &root })
+}
+
+/// Element name of the top level struct.
+fn root_tag(name: &str) -> String {
+    if name == "Transmission" {
+        "Interchange".to_string()
+    } else if is_transaction_set(name) {
+        "TransactionSet".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Transaction set structs are named after their ID, e.g. `_204` or `_277CA`;
+/// loops and views carry a lower case suffix, e.g. `_204Loop100`.
+fn is_transaction_set(name: &str) -> bool {
+    name.strip_prefix('_').is_some_and(|id| {
+        id.starts_with(|c: char| c.is_ascii_digit())
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+    })
+}
+
+/// Whether the serde field list is the positional element list of a segment.
+fn is_segment(fields: &[&str]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .all(|f| !f.is_empty() && f.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Element name of a struct field, e.g. `loop_2000a` → `Loop2000A`, `_ref` → `REF`.
+fn field_tag(field: &str) -> String {
+    match field {
+        "functional_group" => "FunctionalGroup".to_string(),
+        "segments" => "TransactionSet".to_string(),
+        _ if field.starts_with("loop") => {
+            let mut tag = String::new();
+            for part in field.split('_') {
+                let mut chars = part.chars();
+                if let Some(first) = chars.next() {
+                    tag.push(first.to_ascii_uppercase());
+                    if part == "loop" {
+                        tag.extend(chars);
+                    } else {
+                        tag.extend(chars.map(|c| c.to_ascii_uppercase()));
+                    }
+                }
+            }
+            tag
+        }
+        _ => {
+            let field = field.trim_start_matches('_');
+            // repeated segments within one struct, e.g. `k1_2`
+            let field = match field.rsplit_once('_') {
+                Some((head, tail)) if tail.bytes().all(|b| b.is_ascii_digit()) => head,
+                _ => field,
+            };
+            field.to_ascii_uppercase()
+        }
+    }
+}
+
+/// Element names of the fields of struct `name`.
+fn child_tags(name: &str, fields: &[&str]) -> Vec<String> {
+    if is_segment(fields) {
+        fields.iter().map(|f| format!("{name}{f}")).collect()
+    } else {
+        fields.iter().map(|f| field_tag(f)).collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// writing
+
+/// Intermediate tree produced by serialization.
+enum Content {
+    None,
+    Text(String),
+    Seq(Vec<Content>),
+    Struct(&'static str, Vec<(&'static str, Content)>),
+}
+
+fn write_element(
+    out: &mut String,
+    tag: &str,
+    content: &Content,
+    depth: usize,
+) -> Result<(), Error> {
+    let indent = "  ".repeat(depth);
+    match content {
+        Content::None => {}
+        Content::Text(text) => {
+            out.push_str(&format!("{indent}<{tag}>{}</{tag}>\n", escape(text)));
+        }
+        Content::Seq(items) => {
+            for item in items {
+                if let Content::Seq(_) = item {
+                    return Err(Error(format!("nested sequence in <{tag}>")));
+                }
+                write_element(out, tag, item, depth)?;
+            }
+        }
+        Content::Struct(name, fields) => {
+            let keys: Vec<&str> = fields.iter().map(|(key, _)| *key).collect();
+            let tags = child_tags(name, &keys);
+            if fields.iter().all(|(_, value)| is_empty(value)) {
+                out.push_str(&format!("{indent}<{tag}/>\n"));
+                return Ok(());
+            }
+            out.push_str(&format!("{indent}<{tag}>\n"));
+            for ((_, value), child) in fields.iter().zip(tags) {
+                write_element(out, &child, value, depth + 1)?;
+            }
+            out.push_str(&format!("{indent}</{tag}>\n"));
+        }
+    }
+    Ok(())
+}
+
+fn is_empty(content: &Content) -> bool {
+    match content {
+        Content::None => true,
+        Content::Seq(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+struct ContentSerializer;
+
+fn unsupported<T>(kind: &str) -> Result<T, Error> {
+    Err(Error(format!("{kind} has no X12 XML representation")))
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Content, Error> {
+                Ok(Content::Text(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for ContentSerializer {
+    type Ok = Content;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = ser::Impossible<Content, Error>;
+    type SerializeMap = ser::Impossible<Content, Error>;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = ser::Impossible<Content, Error>;
+
+    serialize_display!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    );
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Content, Error> {
+        unsupported("bytes")
+    }
+
+    fn serialize_none(self) -> Result<Content, Error> {
+        Ok(Content::None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Content, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Content, Error> {
+        Ok(Content::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Content, Error> {
+        Ok(Content::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Content, Error> {
+        Ok(Content::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Content, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Content, Error> {
+        unsupported("enum")
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        unsupported("enum")
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        unsupported("map")
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer(name, Vec::with_capacity(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        unsupported("enum")
+    }
+}
+
+struct SeqSerializer(Vec<Content>);
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.0.push(value.serialize(ContentSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content, Error> {
+        Ok(Content::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Content, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Content, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct StructSerializer(&'static str, Vec<(&'static str, Content)>);
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.1.push((key, value.serialize(ContentSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content, Error> {
+        Ok(Content::Struct(self.0, self.1))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// reading
+
+/// Parsed XML element.
+#[derive(Debug, Default)]
+struct Node {
+    tag: String,
+    text: String,
+    children: Vec<Node>,
+}
+
+/// Minimal reader for the XML subset written by [`to_string`]: elements,
+/// text, the predefined entities, character references, comments and an
+/// optional declaration. Attributes are skipped.
+struct Reader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(input: &'a str) -> Self {
+        Reader { input, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error<T>(&self, msg: &str) -> Result<T, Error> {
+        Err(Error(format!("{msg} at offset {}", self.pos)))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_until(&mut self, end: &str) -> Result<(), Error> {
+        match self.rest().find(end) {
+            Some(idx) => {
+                self.pos += idx + end.len();
+                Ok(())
+            }
+            None => self.error(&format!("missing `{end}`")),
+        }
+    }
+
+    /// Skips whitespace, comments and processing instructions.
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn document(&mut self) -> Result<Node, Error> {
+        self.skip_misc()?;
+        let node = self.element()?;
+        self.skip_misc()?;
+        if !self.rest().is_empty() {
+            return self.error("unexpected content after root element");
+        }
+        Ok(node)
+    }
+
+    fn name(&mut self) -> Result<String, Error> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return self.error("missing element name");
+        }
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn element(&mut self) -> Result<Node, Error> {
+        if !self.rest().starts_with('<') {
+            return self.error("expected element");
+        }
+        self.pos += 1;
+        let mut node = Node {
+            tag: self.name()?,
+            ..Default::default()
+        };
+        let close = match self.rest().find('>') {
+            Some(idx) => idx,
+            None => return self.error("unterminated start tag"),
+        };
+        let self_closing = self.rest()[..close].ends_with('/');
+        self.pos += close + 1;
+        if self_closing {
+            return Ok(node);
+        }
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                let name = self.name()?;
+                if name != node.tag {
+                    return self.error(&format!("expected `</{}>`", node.tag));
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return self.error("unterminated end tag");
+                }
+                self.pos += 1;
+                break;
+            } else if rest.starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let end = match self.rest().find("]]>") {
+                    Some(idx) => idx,
+                    None => return self.error("unterminated CDATA section"),
+                };
+                node.text.push_str(&self.rest()[..end]);
+                self.pos += end + 3;
+            } else if rest.starts_with('<') {
+                node.children.push(self.element()?);
+            } else if rest.is_empty() {
+                return self.error(&format!("missing `</{}>`", node.tag));
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                node.text.push_str(&self.unescape(&rest[..end])?);
+                self.pos += end;
+            }
+        }
+        Ok(node)
+    }
+
+    fn unescape(&self, text: &str) -> Result<String, Error> {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(idx) = rest.find('&') {
+            out.push_str(&rest[..idx]);
+            rest = &rest[idx + 1..];
+            let end = match rest.find(';') {
+                Some(end) => end,
+                None => return self.error("unterminated entity"),
+            };
+            let entity = &rest[..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => match entity.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => entity
+                        .strip_prefix('#')
+                        .and_then(|dec| dec.parse().ok())
+                        .and_then(char::from_u32),
+                },
+            };
+            match c {
+                Some(c) => out.push(c),
+                None => return self.error(&format!("unknown entity `&{entity};`")),
+            }
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+/// Deserializes a single element.
+struct NodeDeserializer<'n> {
+    node: &'n Node,
+}
+
+impl<'de> de::Deserializer<'de> for NodeDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.node.children.is_empty() {
+            visitor.visit_str(&self.node.text)
+        } else {
+            Err(Error(format!(
+                "<{}> holds elements, expected text",
+                self.node.tag
+            )))
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        Run {
+            nodes: std::slice::from_ref(self.node),
+            consumed: &Cell::new(0),
+        }
+        .deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_map(StructAccess {
+            children: &self.node.children,
+            fields,
+            tags: child_tags(name, fields),
+            field: 0,
+            child: 0,
+            consumed: Cell::new(0),
+        })
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.node.text.as_str().into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map identifier ignored_any
+    }
+}
+
+/// Walks the fields of a struct in declaration order, matching each one
+/// against the next run of child elements carrying its tag.
+struct StructAccess<'n> {
+    children: &'n [Node],
+    fields: &'static [&'static str],
+    tags: Vec<String>,
+    field: usize,
+    child: usize,
+    consumed: Cell<usize>,
+}
+
+impl StructAccess<'_> {
+    fn run_len(&self) -> usize {
+        let tag = &self.tags[self.field - 1];
+        self.children[self.child..]
+            .iter()
+            .take_while(|node| &node.tag == tag)
+            .count()
+    }
+}
+
+impl<'de> de::MapAccess<'de> for StructAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if self.field == self.fields.len() {
+            if let Some(node) = self.children.get(self.child) {
+                return Err(Error(format!("unexpected element <{}>", node.tag)));
+            }
+            return Ok(None);
+        }
+        let key = self.fields[self.field];
+        self.field += 1;
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let len = self.run_len();
+        self.consumed.set(0);
+        let value = seed.deserialize(Run {
+            nodes: &self.children[self.child..self.child + len],
+            consumed: &self.consumed,
+        })?;
+        self.child += self.consumed.get();
+        Ok(value)
+    }
+}
+
+/// Consecutive elements with the same tag, consumed whole by sequences and
+/// one at a time otherwise.
+struct Run<'n, 'c> {
+    nodes: &'n [Node],
+    consumed: &'c Cell<usize>,
+}
+
+impl<'n> Run<'n, '_> {
+    fn first(&self) -> Result<NodeDeserializer<'n>, Error> {
+        match self.nodes.first() {
+            Some(node) => {
+                self.consumed.set(1);
+                Ok(NodeDeserializer { node })
+            }
+            None => Err(Error("missing element".to_string())),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Run<'_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.first()?.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.nodes.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.consumed.set(self.nodes.len());
+        visitor.visit_seq(de::value::SeqDeserializer::new(
+            self.nodes.iter().map(|node| NodeDeserializer { node }),
+        ))
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.first()?.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.first()?.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.consumed.set(self.nodes.len());
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map identifier
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for NodeDeserializer<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
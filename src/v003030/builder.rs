@@ -0,0 +1,8 @@
+use super::*;
+use crate::util::builder::transaction_builder;
+
+transaction_builder! {
+    _998Builder => _998 ("998") {
+        segments: [zd: ZD],
+    }
+}
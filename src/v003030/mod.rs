@@ -4,6 +4,8 @@ use nom::IResult;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
 use x12_types_macros::DisplayX12;
+mod builder;
+pub use builder::*;
 mod segment;
 pub use segment::*;
 
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
+mod meta;
+pub use meta::segment_descriptor;
+
 /// IEA - Interchange Control Trailer NEW
 ///
 /// To define the end of an interchange of one or more functional groups and interchange-related control segments
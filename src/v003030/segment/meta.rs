@@ -0,0 +1,119 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer NEW",
+    "To define the end of an interchange of one or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header NEW",
+    "To start and identify an interchange of one or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I10", "Interchange Control Standards Identifier", Mandatory, Id, 1, 1, interchange_control_standards_identifier),
+        (12, "I11", "Interchange Control Version Number", Mandatory, Id, 5, 5, interchange_control_version_number),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Test Indicator", Mandatory, Id, 1, 1, test_indicator),
+        (16, "I15", "Subelement Separator", Mandatory, AlphaNumeric, 1, 1, subelement_separator),
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "Functional Group Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 6, 6, date),
+        (5, "337", "Time", Mandatory, Time, 4, 6, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments).",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    ZD,
+    "Transaction Set Deletion - ID, Reason, and Source",
+    "This segment is used to specify the transaction set to be canceled",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (4, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (5, "244", "Transaction Reference Number", Optional, AlphaNumeric, 1, 15, transaction_reference_number),
+        (6, "243", "Transaction Reference Date", Optional, Date, 6, 6, transaction_reference_date),
+        (7, "202", "Correction Indicator", Mandatory, Id, 2, 2, correction_indicator),
+        (8, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        "ZD" => Some(ZD::DESCRIPTOR),
+        _ => None,
+    }
+}
@@ -0,0 +1,1576 @@
+use super::*;
+use crate::util::builder::{loop_builder, transaction_builder};
+use crate::util::meta::SegmentMeta;
+
+transaction_builder! {
+    _204Builder => _204 ("204") {
+        segments: [b2: B2, b2a: B2A],
+        optional: [g62: G62, ms3: MS3, at5: AT5, pld: PLD, nte: NTE, l3: L3],
+        repeated: [l11: L11, lh6: LH6],
+        loops: [loop_100: _204Loop100, loop_200: _204Loop200],
+        required_loops: [loop_300: _204Loop300 >= 1 as "S5"],
+    }
+}
+
+loop_builder! {
+    _204Loop100Builder => _204Loop100 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4, l11: L11],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _204Loop200Builder => _204Loop200 {
+        trigger: [n7: N7],
+        optional: [n7a: N7A, n7b: N7B, mea: MEA, m7: M7],
+    }
+}
+
+loop_builder! {
+    _204Loop300Builder => _204Loop300 {
+        segments: [s5: S5],
+        optional: [at8: AT8, pld: PLD],
+        repeated: [l11: L11, g62: G62, lad: LAD, at5: AT5, nte: NTE],
+        loops: [
+            loop_310: _204Loop310,
+            loop_320: _204Loop320,
+            loop_350: _204Loop350,
+            loop_380: _204Loop380,
+        ],
+    }
+}
+
+loop_builder! {
+    _204Loop310Builder => _204Loop310 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _204Loop320Builder => _204Loop320 {
+        trigger: [l5: L5],
+        optional: [at8: AT8],
+        loops: [loop_325: _204Loop325],
+    }
+}
+
+loop_builder! {
+    _204Loop325Builder => _204Loop325 {
+        trigger: [g61: G61],
+        optional: [lh6: LH6],
+        repeated: [l11: L11],
+        loops: [loop_330: _204Loop330],
+    }
+}
+
+loop_builder! {
+    _204Loop330Builder => _204Loop330 {
+        trigger: [lh1: LH1],
+        optional: [lh4: LH4],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH, lep: LEP, lht: LHT],
+    }
+}
+
+loop_builder! {
+    _204Loop350Builder => _204Loop350 {
+        trigger: [oid: OID],
+        repeated: [g62: G62, lad: LAD],
+        loops: [loop_360: _204Loop360],
+    }
+}
+
+loop_builder! {
+    _204Loop360Builder => _204Loop360 {
+        trigger: [l5: L5],
+        optional: [at8: AT8],
+        loops: [loop_365: _204Loop365],
+    }
+}
+
+loop_builder! {
+    _204Loop365Builder => _204Loop365 {
+        trigger: [g61: G61],
+        repeated: [l11: L11, lh6: LH6],
+        loops: [loop_370: _204Loop370],
+    }
+}
+
+loop_builder! {
+    _204Loop370Builder => _204Loop370 {
+        trigger: [lh1: LH1],
+        optional: [lh4: LH4],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH, lep: LEP, lht: LHT],
+    }
+}
+
+loop_builder! {
+    _204Loop380Builder => _204Loop380 {
+        trigger: [n7: N7],
+        optional: [n7a: N7A, n7b: N7B, mea: MEA, m7: M7],
+    }
+}
+
+transaction_builder! {
+    _210Builder => _210 ("210") {
+        segments: [b3: B3, l3: L3],
+        optional: [c3: C3, itd: ITD],
+        repeated: [n9: N9, g62: G62, r3: R3, h3: H3, k1: K1],
+        loops: [
+            loop_0100: _210Loop0100,
+            loop_0200: _210Loop0200,
+            loop_0250: _210Loop0250,
+            loop_0400: _210Loop0400,
+        ],
+    }
+}
+
+loop_builder! {
+    _210Loop0100Builder => _210Loop0100 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, n9: N9],
+    }
+}
+
+loop_builder! {
+    _210Loop0200Builder => _210Loop0200 {
+        segments: [n7: N7],
+        repeated: [m7: M7],
+    }
+}
+
+loop_builder! {
+    _210Loop0250Builder => _210Loop0250 {
+        segments: [s5: S5],
+        repeated: [n9: N9, g62: G62, h3: H3],
+        loops: [loop_0260: _210Loop0260],
+    }
+}
+
+loop_builder! {
+    _210Loop0260Builder => _210Loop0260 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, n9: N9],
+    }
+}
+
+loop_builder! {
+    _210Loop0400Builder => _210Loop0400 {
+        segments: [lx: LX],
+        optional: [pod: POD],
+        repeated: [n9: N9, l5: L5, h1: H1, h2: H2, l0: L0, l1: L1, l4: L4, l7: L7, k1: K1],
+    }
+}
+
+transaction_builder! {
+    _214Builder => _214 ("214") {
+        segments: [b10: B10],
+        repeated: [l11: L11, man: MAN, k1: K1, ms3: MS3],
+        loops: [loop_0100: _214Loop0100, loop_0200: _214Loop0200],
+    }
+}
+
+loop_builder! {
+    _214Loop0100Builder => _214Loop0100 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4, g61: G61, g62: G62],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0200Builder => _214Loop0200 {
+        segments: [lx: LX],
+        repeated: [l11: L11, man: MAN, q7: Q7, k1: K1, at5: AT5, at8: AT8],
+        loops: [
+            loop_0205: _214Loop0205,
+            loop_0210: _214Loop0210,
+            loop_0230: _214Loop0230,
+            loop_0250: _214Loop0250,
+            loop_0260: _214Loop0260,
+        ],
+    }
+}
+
+loop_builder! {
+    _214Loop0205Builder => _214Loop0205 {
+        segments: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Builder => _214Loop0210 {
+        trigger: [cd3: CD3],
+        optional: [nm1: NM1, at8: AT8],
+        repeated: [l11: L11, q7: Q7, man: MAN],
+        loops: [loop_0215: _214Loop0210Loop0215, loop_0220: _214Loop0210Loop0220],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Loop0215Builder => _214Loop0210Loop0215 {
+        trigger: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Loop0220Builder => _214Loop0210Loop0220 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0230Builder => _214Loop0230 {
+        trigger: [prf: PRF],
+        loops: [loop_0231: _214Loop0231, loop_0233: _214Loop0233],
+    }
+}
+
+loop_builder! {
+    _214Loop0231Builder => _214Loop0231 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0233Builder => _214Loop0233 {
+        trigger: [cd3: CD3],
+        repeated: [l11: L11],
+        loops: [loop_0240: _214Loop0240],
+    }
+}
+
+loop_builder! {
+    _214Loop0240Builder => _214Loop0240 {
+        trigger: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0250Builder => _214Loop0250 {
+        trigger: [spo: SPO],
+        optional: [sdq: SDQ],
+    }
+}
+
+loop_builder! {
+    _214Loop0260Builder => _214Loop0260 {
+        trigger: [efi: EFI],
+        segments: [bin: BIN],
+    }
+}
+
+transaction_builder! {
+    _300Builder => _300 ("300") {
+        segments: [b1: B1],
+        optional: [w09: W09],
+        repeated: [g61: G61, y6: Y6, n9: N9, h3: H3, y2: Y2, k1: K1, v1: V1],
+        loops: [loop_n1: _300LoopN1, loop_r4: _300LoopR4, loop_lx: _300LoopLX],
+    }
+}
+
+loop_builder! {
+    _300LoopN1Builder => _300LoopN1 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _300LoopR4Builder => _300LoopR4 {
+        segments: [r4: R4],
+        repeated: [dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _300LoopLXBuilder => _300LoopLX {
+        segments: [lx: LX],
+        optional: [n7: N7, w09: W09, l0: L0, l5: L5, l4: L4, l1: L1],
+        repeated: [k1: K1],
+        loops: [loop_h1: _300LoopH1],
+    }
+}
+
+loop_builder! {
+    _300LoopH1Builder => _300LoopH1 {
+        segments: [h1: H1],
+        repeated: [h2: H2],
+    }
+}
+
+transaction_builder! {
+    _301Builder => _301 ("301") {
+        segments: [b1: B1, y3: Y3],
+        optional: [w09: W09, h3: H3],
+        repeated: [n9: N9, r2a: R2A, ea: EA, v1: V1, v9: V9],
+        loops: [loop_y4: _301LoopY4, loop_n1: _301LoopN1, loop_r4: _301LoopR4, loop_lx: _301LoopLx],
+    }
+}
+
+loop_builder! {
+    _301LoopY4Builder => _301LoopY4 {
+        trigger: [y4: Y4],
+        optional: [w09: W09],
+    }
+}
+
+loop_builder! {
+    _301LoopN1Builder => _301LoopN1 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n3: N3, n4: N4, g61: G61],
+    }
+}
+
+loop_builder! {
+    _301LoopR4Builder => _301LoopR4 {
+        segments: [r4: R4],
+        repeated: [dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _301LoopLxBuilder => _301LoopLx {
+        segments: [lx: LX],
+        optional: [n7: N7, w09: W09, l0: L0, l5: L5, l4: L4, l1: L1],
+        repeated: [k1: K1],
+        loops: [loop_h1: _301LoopLxLoopH1],
+    }
+}
+
+loop_builder! {
+    _301LoopLxLoopH1Builder => _301LoopLxLoopH1 {
+        trigger: [h1: H1],
+        repeated: [h2: H2],
+    }
+}
+
+transaction_builder! {
+    _304Builder => _304 ("304") {
+        segments: [b2: B2],
+        optional: [b2a: B2A, c2: C2, c3: C3, l3: L3],
+        repeated: [y6: Y6, n9: N9, v1: V1, m1: M1, y2: Y2, r2a: R2A, k1: K1, h3: H3, k1_2: K1],
+        loops: [loop_n1: _304LoopN1, loop_r4: _304LoopR4, loop_c8: _304LoopC8, loop_lx: _304LoopLX],
+    }
+}
+
+loop_builder! {
+    _304LoopN1Builder => _304LoopN1 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _304LoopR4Builder => _304LoopR4 {
+        segments: [r4: R4],
+        repeated: [dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _304LoopC8Builder => _304LoopC8 {
+        segments: [c8: C8],
+        repeated: [c8c: C8C],
+    }
+}
+
+loop_builder! {
+    _304LoopLXBuilder => _304LoopLX {
+        segments: [lx: LX],
+        loops: [loop_n7: _304LoopN7, loop_l0: _304LoopL0],
+    }
+}
+
+loop_builder! {
+    _304LoopN7Builder => _304LoopN7 {
+        segments: [n7: N7],
+        optional: [qty: QTY, n12: N12, w09: W09],
+        repeated: [m7: M7, n9: N9],
+    }
+}
+
+loop_builder! {
+    _304LoopL0Builder => _304LoopL0 {
+        segments: [l0: L0],
+        optional: [l4: L4, x1: X1],
+        repeated: [l5: L5],
+        loops: [loop_h1: _304LoopH1],
+    }
+}
+
+loop_builder! {
+    _304LoopH1Builder => _304LoopH1 {
+        segments: [h1: H1],
+        repeated: [h2: H2],
+    }
+}
+
+transaction_builder! {
+    _309Builder => _309 ("309") {
+        segments: [m10: M10],
+        loops: [loop_p4: _309LoopP4],
+    }
+}
+
+loop_builder! {
+    _309LoopP4Builder => _309LoopP4 {
+        segments: [p4: P4],
+        loops: [loop_lx: _309LoopLX],
+    }
+}
+
+loop_builder! {
+    _309LoopLXBuilder => _309LoopLX {
+        segments: [lx: LX],
+        optional: [m13: M13, m11: M11],
+        repeated: [n9: N9],
+        loops: [loop_n1: _309LoopN1, loop_m12: _309LoopM12, loop_vid: _309LoopVID],
+    }
+}
+
+loop_builder! {
+    _309LoopN1Builder => _309LoopN1 {
+        trigger: [n1: N1],
+        optional: [n3: N3, n4: N4, dtm: DTM, per: PER],
+    }
+}
+
+loop_builder! {
+    _309LoopM12Builder => _309LoopM12 {
+        trigger: [m12: M12],
+        repeated: [r4: R4],
+    }
+}
+
+loop_builder! {
+    _309LoopVIDBuilder => _309LoopVID {
+        trigger: [vid: VID],
+        repeated: [m7: M7],
+        loops: [loop_n10: _309LoopN10],
+    }
+}
+
+loop_builder! {
+    _309LoopN10Builder => _309LoopN10 {
+        trigger: [n10: N10],
+        repeated: [vc: VC],
+        loops: [loop_h1: _309LoopH1],
+    }
+}
+
+loop_builder! {
+    _309LoopH1Builder => _309LoopH1 {
+        trigger: [h1: H1],
+        repeated: [h2: H2],
+    }
+}
+
+transaction_builder! {
+    _310Builder => _310 ("310") {
+        segments: [b3: B3, l3: L3],
+        optional: [b2a: B2A, g3: G3, m0: M0, c2: C2, c3: C3, l5: L5, l11: L11],
+        repeated: [
+            y6: Y6,
+            n9: N9,
+            v1: V1,
+            m1: M1,
+            y2: Y2,
+            g61: G61,
+            r2a: R2A,
+            r2: R2,
+            k1: K1,
+            h3: H3,
+            pwk: PWK,
+            v9: V9,
+            c8: C8,
+            k1_2: K1,
+        ],
+        loops: [
+            loop_n1: _310LoopN1,
+            loop_r4: _310LoopR4,
+            loop_c8: _310LoopC8,
+            loop_lx: _310LoopLX,
+            loop_l1: _310LoopL1,
+        ],
+    }
+}
+
+loop_builder! {
+    _310LoopN1Builder => _310LoopN1 {
+        segments: [n1: N1],
+        optional: [n2: N2, n3: N3, n4: N4],
+    }
+}
+
+loop_builder! {
+    _310LoopR4Builder => _310LoopR4 {
+        segments: [r4: R4],
+        optional: [dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _310LoopC8Builder => _310LoopC8 {
+        trigger: [c8: C8],
+        repeated: [c8c: C8C],
+    }
+}
+
+loop_builder! {
+    _310LoopLXBuilder => _310LoopLX {
+        segments: [lx: LX],
+        loops: [loop_n7: _310LoopN7, loop_l0: _310LoopL0],
+    }
+}
+
+loop_builder! {
+    _310LoopN7Builder => _310LoopN7 {
+        trigger: [n7: N7],
+        optional: [qty: QTY, v4: V4, n12: N12, w09: W09, l7: L7, x1: X1, x2: X2],
+        repeated: [m7: M7, n9: N9],
+        loops: [loop_l1: _310LoopL1, loop_h1: _310LoopH1],
+    }
+}
+
+loop_builder! {
+    _310LoopL1Builder => _310LoopL1 {
+        trigger: [l1: L1],
+        optional: [c3: C3],
+    }
+}
+
+loop_builder! {
+    _310LoopH1Builder => _310LoopH1 {
+        trigger: [h1: H1],
+        repeated: [h2: H2],
+    }
+}
+
+loop_builder! {
+    _310LoopL0Builder => _310LoopL0 {
+        trigger: [l0: L0],
+        optional: [l7: L7, x1: X1, x2: X2],
+        repeated: [l5: L5],
+        loops: [loop_l1: _310LoopL1, loop_c8: _310LoopC8, loop_h1: _310LoopH1],
+    }
+}
+
+transaction_builder! {
+    _315Builder => _315 ("315") {
+        segments: [b4: B4],
+        optional: [q2: Q2, v9: V9],
+        repeated: [n9: N9, sg: SG],
+        loops: [loop_r4: _315LoopR4],
+    }
+}
+
+loop_builder! {
+    _315LoopR4Builder => _315LoopR4 {
+        segments: [r4: R4],
+        repeated: [dtm: DTM],
+    }
+}
+
+transaction_builder! {
+    _322Builder => _322 ("322") {
+        segments: [q5: Q5],
+        optional: [zc1: ZC1],
+        loops: [loop_n7: _322LoopN7],
+    }
+}
+
+loop_builder! {
+    _322LoopN7Builder => _322LoopN7 {
+        segments: [n7: N7],
+        optional: [v4: V4, w09: W09, w2: W2, y7: Y7, v1: V1],
+        repeated: [dtm: DTM, m7: M7, na: NA, gr5: GR5, h3: H3, k1: K1, n9: N9, l3: L3],
+        loops: [loop_r4: _322LoopR4, loop_n1: _322LoopN1, loop_l0: _322LoopL0],
+    }
+}
+
+loop_builder! {
+    _322LoopR4Builder => _322LoopR4 {
+        segments: [r4: R4],
+        repeated: [dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _322LoopN1Builder => _322LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n3: N3],
+    }
+}
+
+loop_builder! {
+    _322LoopL0Builder => _322LoopL0 {
+        trigger: [l0: L0],
+        optional: [l5: L5],
+        repeated: [h1: H1],
+    }
+}
+
+transaction_builder! {
+    _404Builder => _404 ("404") {
+        segments: [m3: M3, f9: F9, d9: D9],
+        optional: [
+            zc1: ZC1,
+            bx: BX,
+            bnx: BNX,
+            m1: M1,
+            dtm: DTM,
+            na: NA,
+            r9: R9,
+            l3: L3,
+            ls: LS,
+            le: LE,
+            per: PER,
+            lh2: LH2,
+            lhr: LHR,
+            lh6: LH6,
+            xh: XH,
+            x7: X7,
+        ],
+        repeated: [n9: N9, cm: CM, r2: R2, h3: H3, ps: PS],
+        loops: [
+            loop_n7: _404LoopN7,
+            loop_n1: _404LoopN1,
+            loop_s1: _404LoopS1,
+            loop_e1: _404LoopE1,
+            loop_lx: _404LoopLX,
+            loop_t1: _404LoopT1,
+            loop_lh1: _404LoopLH1,
+        ],
+    }
+}
+
+loop_builder! {
+    _404LoopN7Builder => _404LoopN7 {
+        segments: [n7: N7],
+        optional: [em: EM, m7: M7, n5: N5, ic: IC, im: IM, m12: M12, ga: GA],
+        loops: [loop_vc: _404LoopVC, loop_e1: _404LoopN7E1, loop_ref: _404LoopN7Ref],
+    }
+}
+
+loop_builder! {
+    _404LoopVCBuilder => _404LoopVC {
+        trigger: [vc: VC],
+        loops: [loop_n1: _404LoopVcN1],
+    }
+}
+
+loop_builder! {
+    _404LoopVcN1Builder => _404LoopVcN1 {
+        trigger: [n1: N1],
+        optional: [n3: N3, n4: N4, h3: H3],
+    }
+}
+
+loop_builder! {
+    _404LoopN7E1Builder => _404LoopN7E1 {
+        segments: [e1: E1],
+        optional: [e4: E4, e5: E5, pi: PI],
+    }
+}
+
+loop_builder! {
+    _404LoopN7RefBuilder => _404LoopN7Ref {
+        trigger: [_ref: REF],
+        optional: [n10: N10],
+        loops: [loop_n1: _404LoopN7RefN1],
+    }
+}
+
+loop_builder! {
+    _404LoopN7RefN1Builder => _404LoopN7RefN1 {
+        trigger: [n1: N1],
+        optional: [n3: N3, n4: N4],
+    }
+}
+
+loop_builder! {
+    _404LoopN1Builder => _404LoopN1 {
+        segments: [n1: N1],
+        optional: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER, bl: BL],
+    }
+}
+
+loop_builder! {
+    _404LoopS1Builder => _404LoopS1 {
+        trigger: [s1: S1],
+        optional: [s2: S2, s9: S9, n1: N1, n2: N2, n3: N3, n4: N4, per: PER],
+    }
+}
+
+loop_builder! {
+    _404LoopE1Builder => _404LoopE1 {
+        segments: [e1: E1],
+        optional: [e4: E4, e5: E5, pi: PI],
+    }
+}
+
+loop_builder! {
+    _404LoopLXBuilder => _404LoopLX {
+        segments: [lx: LX, l5: L5],
+        optional: [x1: X1],
+        loops: [loop_l0: _404LoopL0],
+    }
+}
+
+loop_builder! {
+    _404LoopL0Builder => _404LoopL0 {
+        trigger: [l0: L0],
+        optional: [mea: MEA, l1: L1, pi: PI],
+    }
+}
+
+loop_builder! {
+    _404LoopT1Builder => _404LoopT1 {
+        trigger: [t1: T1],
+        optional: [t2: T2, t3: T3, t6: T6, t8: T8],
+    }
+}
+
+loop_builder! {
+    _404LoopLH1Builder => _404LoopLH1 {
+        trigger: [lh1: LH1],
+        optional: [lep: LEP, lh4: LH4, lht: LHT, lhr: LHR, per: PER],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH],
+    }
+}
+
+transaction_builder! {
+    _810Builder => _810 ("810") {
+        segments: [big: BIG, tds: TDS],
+        optional: [cur: CUR, fob: FOB, l7: L7, inc: INC, cad: CAD, ctt: CTT],
+        repeated: [
+            nte: NTE,
+            r#ref: REF,
+            ynq: YNQ,
+            per: PER,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            bal: BAL,
+            pam: PAM,
+            txi: TXI,
+            amt: AMT,
+        ],
+        loops: [
+            loop_n1: _810LoopN1,
+            loop_lm: _810LoopLM,
+            loop_n9: _810LoopN9,
+            loop_v1: _810LoopV1,
+            loop_fa1: _810LoopFA1,
+            loop_it1: _810LoopIT1,
+            loop_sac: _810LoopSAC,
+            loop_iss: _810LoopISS,
+        ],
+    }
+}
+
+loop_builder! {
+    _810LoopN1Builder => _810LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4, dmg: DMG],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _810LoopLMBuilder => _810LoopLM {
+        segments: [lm: LM, lq: LQ],
+    }
+}
+
+loop_builder! {
+    _810LoopN9Builder => _810LoopN9 {
+        segments: [n9: N9],
+        repeated: [msg: MSG],
+    }
+}
+
+loop_builder! {
+    _810LoopV1Builder => _810LoopV1 {
+        segments: [v1: V1],
+        repeated: [r4: R4, dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _810LoopFA1Builder => _810LoopFA1 {
+        segments: [fa1: FA1],
+        repeated: [fa2: FA2],
+    }
+}
+
+loop_builder! {
+    _810LoopIT1Builder => _810LoopIT1 {
+        segments: [it1: IT1],
+        optional: [crc: CRC, cur: CUR, po4: PO4, sr: SR],
+        repeated: [
+            qty: QTY,
+            it3: IT3,
+            txi: TXI,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            itd: ITD,
+            r#ref: REF,
+            ynq: YNQ,
+            per: PER,
+            sdq: SDQ,
+            dtm: DTM,
+            cad: CAD,
+            l7: L7,
+        ],
+        loops: [
+            loop_pid: _810LoopPID,
+            loop_sac: _810LoopSAC,
+            loop_sln: _810LoopSLN,
+            loop_n1: _810LoopN1,
+            loop_lm: _810LoopLM,
+            loop_v1: _810LoopV1,
+            loop_fa1: _810LoopFA1,
+        ],
+    }
+}
+
+loop_builder! {
+    _810LoopPIDBuilder => _810LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _810LoopSACBuilder => _810LoopSAC {
+        segments: [sac: SAC],
+        repeated: [txi: TXI],
+    }
+}
+
+loop_builder! {
+    _810LoopSLNBuilder => _810LoopSLN {
+        segments: [sln: SLN],
+        optional: [dtm: DTM],
+        repeated: [r#ref: REF, pid: PID, sac: SAC, tc2: TC2, txi: TXI],
+    }
+}
+
+loop_builder! {
+    _810LoopISSBuilder => _810LoopISS {
+        segments: [iss: ISS],
+        optional: [pid: PID],
+    }
+}
+
+transaction_builder! {
+    _820Builder => _820 ("820") {
+        segments: [bpr: BPR],
+        optional: [trn: TRN, cur: CUR],
+        repeated: [nte: NTE, r#ref: REF, dtm: DTM],
+        loops: [loop_n1: _820LoopN1, loop_ent: _820LoopENT],
+    }
+}
+
+loop_builder! {
+    _820LoopN1Builder => _820LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER, dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _820LoopENTBuilder => _820LoopENT {
+        segments: [ent: ENT],
+        loops: [loop_nm1: _820LoopNM1, loop_adx: _820LoopADX, loop_rmr: _820LoopRMR],
+    }
+}
+
+loop_builder! {
+    _820LoopNM1Builder => _820LoopNM1 {
+        segments: [nm1: NM1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _820LoopADXBuilder => _820LoopADX {
+        segments: [adx: ADX],
+        optional: [dtm: DTM],
+        repeated: [nte: NTE, per: PER, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _820LoopRMRBuilder => _820LoopRMR {
+        segments: [rmr: RMR],
+        repeated: [nte: NTE, r#ref: REF, dtm: DTM],
+        loops: [loop_adx: _820LoopADX],
+    }
+}
+
+transaction_builder! {
+    _824Builder => _824 ("824") {
+        segments: [bgn: BGN],
+        loops: [loop_n1: _824LoopN1, loop_oti: _824LoopOTI],
+    }
+}
+
+loop_builder! {
+    _824LoopN1Builder => _824LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _824LoopOTIBuilder => _824LoopOTI {
+        segments: [oti: OTI],
+        repeated: [r#ref: REF, dtm: DTM, per: PER, amt: AMT, qty: QTY, nm1: NM1],
+        loops: [loop_ted: _824LoopTED],
+    }
+}
+
+loop_builder! {
+    _824LoopTEDBuilder => _824LoopTED {
+        segments: [ted: TED],
+        repeated: [nte: NTE, red: RED],
+    }
+}
+
+transaction_builder! {
+    _850Builder => _850 ("850") {
+        segments: [beg: BEG],
+        optional: [cur: CUR, inc: INC, ctt: CTT, amt: AMT],
+        repeated: [
+            r#ref: REF,
+            per: PER,
+            fob: FOB,
+            ctp: CTP,
+            pam: PAM,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            txi: TXI,
+        ],
+        loops: [
+            loop_sac: _850LoopSAC,
+            loop_n9: _850LoopN9,
+            loop_n1: _850LoopN1,
+            loop_po1: _850LoopPO1,
+        ],
+    }
+}
+
+loop_builder! {
+    _850LoopSACBuilder => _850LoopSAC {
+        segments: [sac: SAC],
+        optional: [cur: CUR],
+    }
+}
+
+loop_builder! {
+    _850LoopN9Builder => _850LoopN9 {
+        segments: [n9: N9],
+        repeated: [dtm: DTM, msg: MSG],
+    }
+}
+
+loop_builder! {
+    _850LoopN1Builder => _850LoopN1 {
+        segments: [n1: N1],
+        repeated: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _850LoopPO1Builder => _850LoopPO1 {
+        segments: [po1: PO1],
+        optional: [cur: CUR, td1: TD1],
+        repeated: [
+            po3: PO3,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            po4: PO4,
+            r#ref: REF,
+            per: PER,
+            itd: ITD,
+            fob: FOB,
+            sdq: SDQ,
+            it3: IT3,
+            dtm: DTM,
+            tc2: TC2,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            msg: MSG,
+            txi: TXI,
+        ],
+        loops: [
+            loop_pid: _850LoopPID,
+            loop_sac: _850LoopSAC,
+            loop_sch: _850LoopSCH,
+            loop_n9: _850LoopN9,
+            loop_n1: _850LoopN1,
+            loop_sln: _850LoopSLN,
+        ],
+    }
+}
+
+loop_builder! {
+    _850LoopPIDBuilder => _850LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _850LoopSCHBuilder => _850LoopSCH {
+        segments: [sch: SCH],
+        repeated: [td1: TD1, td5: TD5, td3: TD3, td4: TD4, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _850LoopSLNBuilder => _850LoopSLN {
+        segments: [sln: SLN],
+        optional: [po4: PO4],
+        repeated: [msg: MSG, pid: PID, po3: PO3, dtm: DTM, ctp: CTP, pam: PAM],
+    }
+}
+
+transaction_builder! {
+    _855Builder => _855 ("855") {
+        segments: [bak: BAK],
+        optional: [cur: CUR, inc: INC, ctt: CTT, amt: AMT],
+        repeated: [
+            r#ref: REF,
+            per: PER,
+            fob: FOB,
+            ctp: CTP,
+            pam: PAM,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            txi: TXI,
+        ],
+        loops: [
+            loop_sac: _855LoopSAC,
+            loop_n9: _855LoopN9,
+            loop_n1: _855LoopN1,
+            loop_po1: _855LoopPO1,
+        ],
+    }
+}
+
+loop_builder! {
+    _855LoopSACBuilder => _855LoopSAC {
+        segments: [sac: SAC],
+        optional: [cur: CUR],
+    }
+}
+
+loop_builder! {
+    _855LoopN9Builder => _855LoopN9 {
+        segments: [n9: N9],
+        repeated: [dtm: DTM, msg: MSG],
+    }
+}
+
+loop_builder! {
+    _855LoopN1Builder => _855LoopN1 {
+        segments: [n1: N1],
+        repeated: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _855LoopPO1Builder => _855LoopPO1 {
+        segments: [po1: PO1],
+        optional: [cur: CUR, td1: TD1],
+        repeated: [
+            po3: PO3,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            po4: PO4,
+            r#ref: REF,
+            per: PER,
+            itd: ITD,
+            fob: FOB,
+            sdq: SDQ,
+            it3: IT3,
+            dtm: DTM,
+            tc2: TC2,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            msg: MSG,
+            txi: TXI,
+            ack: ACK,
+        ],
+        loops: [
+            loop_pid: _855LoopPID,
+            loop_sac: _855LoopSAC,
+            loop_sch: _855LoopSCH,
+            loop_n9: _855LoopN9,
+            loop_n1: _855LoopN1,
+            loop_sln: _855LoopSLN,
+        ],
+    }
+}
+
+loop_builder! {
+    _855LoopPIDBuilder => _855LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _855LoopSCHBuilder => _855LoopSCH {
+        segments: [sch: SCH],
+        repeated: [td1: TD1, td5: TD5, td3: TD3, td4: TD4, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _855LoopSLNBuilder => _855LoopSLN {
+        segments: [sln: SLN],
+        optional: [po4: PO4],
+        repeated: [msg: MSG, pid: PID, po3: PO3, dtm: DTM, ctp: CTP, pam: PAM],
+    }
+}
+
+transaction_builder! {
+    _856Builder => _856 ("856") {
+        segments: [bsn: BSN],
+        optional: [ctt: CTT],
+        repeated: [dtm: DTM],
+        loops: [loop_hl: _856LoopHL],
+        renumber: [loop_hl],
+    }
+}
+
+loop_builder! {
+    _856LoopHLBuilder => _856LoopHL {
+        segments: [hl: HL],
+        optional: [lin: LIN, sn1: SN1, prf: PRF, po4: PO4, fob: FOB],
+        repeated: [
+            sln: SLN,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            r#ref: REF,
+            per: PER,
+            man: MAN,
+            dtm: DTM,
+            sdq: SDQ,
+        ],
+        loops: [loop_cld: _856LoopCLD, loop_n1: _856LoopN1, loop_hl: _856LoopHL],
+    }
+}
+
+loop_builder! {
+    _856LoopCLDBuilder => _856LoopCLD {
+        segments: [cld: CLD],
+        optional: [dtm: DTM],
+        repeated: [r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _856LoopN1Builder => _856LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4, fob: FOB],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+transaction_builder! {
+    _860Builder => _860 ("860") {
+        segments: [bch: BCH],
+        optional: [cur: CUR, inc: INC, ctt: CTT, amt: AMT],
+        repeated: [
+            r#ref: REF,
+            per: PER,
+            fob: FOB,
+            ctp: CTP,
+            pam: PAM,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            txi: TXI,
+        ],
+        loops: [
+            loop_sac: _860LoopSAC,
+            loop_n9: _860LoopN9,
+            loop_n1: _860LoopN1,
+            loop_poc: _860LoopPOC,
+        ],
+    }
+}
+
+loop_builder! {
+    _860LoopSACBuilder => _860LoopSAC {
+        segments: [sac: SAC],
+        optional: [cur: CUR],
+    }
+}
+
+loop_builder! {
+    _860LoopN9Builder => _860LoopN9 {
+        segments: [n9: N9],
+        repeated: [dtm: DTM, msg: MSG],
+    }
+}
+
+loop_builder! {
+    _860LoopN1Builder => _860LoopN1 {
+        segments: [n1: N1],
+        repeated: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _860LoopPOCBuilder => _860LoopPOC {
+        segments: [poc: POC],
+        optional: [cur: CUR, td1: TD1],
+        repeated: [
+            po3: PO3,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            po4: PO4,
+            r#ref: REF,
+            per: PER,
+            itd: ITD,
+            fob: FOB,
+            sdq: SDQ,
+            it3: IT3,
+            dtm: DTM,
+            tc2: TC2,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            msg: MSG,
+            txi: TXI,
+        ],
+        loops: [
+            loop_pid: _860LoopPID,
+            loop_sac: _860LoopSAC,
+            loop_sch: _860LoopSCH,
+            loop_n9: _860LoopN9,
+            loop_n1: _860LoopN1,
+            loop_sln: _860LoopSLN,
+        ],
+    }
+}
+
+loop_builder! {
+    _860LoopPIDBuilder => _860LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _860LoopSCHBuilder => _860LoopSCH {
+        segments: [sch: SCH],
+        repeated: [td1: TD1, td5: TD5, td3: TD3, td4: TD4, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _860LoopSLNBuilder => _860LoopSLN {
+        segments: [sln: SLN],
+        optional: [po4: PO4],
+        repeated: [msg: MSG, pid: PID, po3: PO3, dtm: DTM, ctp: CTP, pam: PAM],
+    }
+}
+
+transaction_builder! {
+    _940Builder => _940 ("940") {
+        segments: [w05: W05, w66: W66],
+        optional: [w76: W76],
+        repeated: [n9: N9, g62: G62, nte: NTE],
+        loops: [loop_0100: _940Loop0100, loop_0300: _940Loop0300],
+    }
+}
+
+loop_builder! {
+    _940Loop0100Builder => _940Loop0100 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _940Loop0300Builder => _940Loop0300 {
+        trigger: [lx: LX],
+        loops: [loop_0310: _940Loop0310],
+    }
+}
+
+loop_builder! {
+    _940Loop0310Builder => _940Loop0310 {
+        segments: [w01: W01],
+        repeated: [g69: G69, n9: N9],
+    }
+}
+
+transaction_builder! {
+    _943Builder => _943 ("943") {
+        segments: [w06: W06, w27: W27, w03: W03],
+        repeated: [n9: N9, g62: G62, nte: NTE],
+        loops: [loop_0100: _943Loop0100, loop_0200: _943Loop0200],
+    }
+}
+
+loop_builder! {
+    _943Loop0100Builder => _943Loop0100 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _943Loop0200Builder => _943Loop0200 {
+        segments: [w04: W04],
+        repeated: [g69: G69, n9: N9],
+    }
+}
+
+transaction_builder! {
+    _944Builder => _944 ("944") {
+        segments: [w17: W17, w14: W14],
+        repeated: [n9: N9, g62: G62],
+        loops: [loop_0100: _944Loop0100, loop_0200: _944Loop0200],
+    }
+}
+
+loop_builder! {
+    _944Loop0100Builder => _944Loop0100 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _944Loop0200Builder => _944Loop0200 {
+        trigger: [lx: LX],
+        loops: [loop_0210: _944Loop0210],
+    }
+}
+
+loop_builder! {
+    _944Loop0210Builder => _944Loop0210 {
+        segments: [w07: W07],
+        repeated: [g69: G69, n9: N9],
+    }
+}
+
+transaction_builder! {
+    _945Builder => _945 ("945") {
+        segments: [w06: W06, w27: W27, w03: W03],
+        repeated: [n9: N9, g62: G62],
+        loops: [loop_0100: _945Loop0100, loop_0200: _945Loop0200],
+    }
+}
+
+loop_builder! {
+    _945Loop0100Builder => _945Loop0100 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _945Loop0200Builder => _945Loop0200 {
+        trigger: [lx: LX],
+        repeated: [man: MAN],
+        loops: [loop_0210: _945Loop0210],
+    }
+}
+
+loop_builder! {
+    _945Loop0210Builder => _945Loop0210 {
+        segments: [w12: W12],
+        repeated: [g69: G69, n9: N9],
+    }
+}
+
+transaction_builder! {
+    _947Builder => _947 ("947") {
+        segments: [w15: W15],
+        repeated: [n9: N9, g62: G62],
+        loops: [loop_0100: _947Loop0100, loop_0200: _947Loop0200],
+    }
+}
+
+loop_builder! {
+    _947Loop0100Builder => _947Loop0100 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _947Loop0200Builder => _947Loop0200 {
+        segments: [w19: W19],
+        repeated: [g69: G69, n9: N9],
+    }
+}
+
+transaction_builder! {
+    _990Builder => _990 ("990") {
+        segments: [b1: B1],
+        optional: [n9: N9, g62: G62],
+        repeated: [k1: K1],
+    }
+}
+
+transaction_builder! {
+    _997Builder => _997 ("997") {
+        segments: [ak1: AK1, ak9: AK9],
+        loops: [loop_ak2: _997LoopAK2],
+    }
+}
+
+loop_builder! {
+    _997LoopAK2Builder => _997LoopAK2 {
+        segments: [ak2: AK2, ak5: AK5],
+        loops: [loop_ak3: _997LoopAK3],
+    }
+}
+
+loop_builder! {
+    _997LoopAK3Builder => _997LoopAK3 {
+        segments: [ak3: AK3],
+        repeated: [ak4: AK4],
+    }
+}
+
+transaction_builder! {
+    _998Builder => _998 ("998") {
+        segments: [zd: ZD],
+    }
+}
+
+/// `G62-01` qualifiers whose date is copied into `B1-03`: requested pick-up
+/// (`10`), ship not before (`37`), ship no later (`38`) and must respond by (`64`).
+const TENDER_DATE_QUALIFIERS: [&str; 4] = ["10", "37", "38", "64"];
+
+impl _990 {
+    /// Answers a load tender.
+    ///
+    /// Copies the SCAC (`B2-02`), the shipment ID (`B2-04`) and the date of the
+    /// tender (`G62-02`) into `B1` and sets the reservation action code, e.g.
+    /// `A` to accept or `D` to decline. The date is only copied if `G62-01`
+    /// qualifies it as a pick-up or response date. A reason is sent as `K1`
+    /// remark and must fit into `K1-01`.
+    pub fn respond_to(
+        tender: &_204,
+        control_number: impl Into<String>,
+        action_code: impl Into<String>,
+        reason: Option<&str>,
+    ) -> Result<_990, crate::util::builder::BuildError> {
+        let date = tender
+            .g62
+            .as_ref()
+            .filter(|g62| {
+                g62._01
+                    .as_deref()
+                    .is_some_and(|qualifier| TENDER_DATE_QUALIFIERS.contains(&qualifier))
+            })
+            .and_then(|g62| g62._02.clone());
+        let b1 = B1 {
+            _01: tender.b2._02.clone(),
+            _02: tender.b2._04.clone().unwrap_or_default(),
+            _03: date,
+            _04: action_code.into(),
+        };
+        let mut builder = _990::builder(control_number).b1(b1);
+        if let Some(reason) = reason {
+            let max = K1::DESCRIPTOR.element(1).map_or(usize::MAX, |e| e.max);
+            if reason.chars().count() > max {
+                return Err(crate::util::builder::BuildError::ElementTooLong {
+                    segment: "K1",
+                    position: 1,
+                    max,
+                });
+            }
+            builder = builder.k1(K1 {
+                _01: reason.to_string(),
+                _02: None,
+            });
+        }
+        builder.build()
+    }
+}
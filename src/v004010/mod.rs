@@ -1,6 +1,8 @@
 //! v004010 repesents all entities of the 004010 specification.
 
+use crate::util::amount::{Amount, AmountError, Balance};
 use crate::util::Parser;
+pub use builder::*;
 use nom::combinator::opt;
 use nom::combinator::peek;
 use nom::multi::many0;
@@ -10,21 +12,31 @@ pub use segment::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::fmt::Display;
-use x12_types_macros::DisplayX12;
+use x12_types_macros::{DisplayX12, ParseX12};
 
+mod builder;
 mod segment;
 
 #[cfg(test)]
+#[allow(clippy::needless_update)]
 mod test_204;
 #[cfg(test)]
+mod test_210;
+#[cfg(test)]
 mod test_214;
 #[cfg(test)]
+mod test_300;
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod test_301;
 #[cfg(test)]
+mod test_304;
+#[cfg(test)]
 mod test_309;
 #[cfg(test)]
 mod test_310;
 #[cfg(test)]
+#[allow(clippy::needless_update)]
 mod test_315;
 #[cfg(test)]
 mod test_322;
@@ -33,6 +45,18 @@ mod test_404;
 #[cfg(test)]
 mod test_810;
 #[cfg(test)]
+mod test_820;
+#[cfg(test)]
+mod test_824;
+#[cfg(test)]
+mod test_850;
+#[cfg(test)]
+mod test_856;
+#[cfg(test)]
+mod test_94x;
+#[cfg(test)]
+mod test_990;
+#[cfg(test)]
 mod test_997;
 #[cfg(test)]
 mod test_998;
@@ -186,7 +210,7 @@ pub struct _204 {
     pub st: ST,
     pub b2: B2,
     pub b2a: B2A,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub l11: Vec<L11>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub g62: Option<G62>,
@@ -196,7 +220,7 @@ pub struct _204 {
     pub at5: Option<AT5>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pld: Option<PLD>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub lh6: Vec<LH6>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nte: Option<NTE>,
@@ -511,6 +535,127 @@ pub struct _204Loop380 {
     pub m7: Option<M7>,
 }
 
+/// 210 - Motor Carrier Freight Details and Invoice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Motor Carrier Freight Details and Invoice Transaction Set (210) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used to allow a motor carrier to provide detailed bill of lading, rating and/or scheduling information pertinent to a shipment.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B3 | Beginning Segment for Carrier's Invoice | M | 1
+/// 0040 | C3 | Currency | O | 1
+/// 0050 | ITD | Terms of Sale/Deferred Terms of Sale | O | 1
+/// 0060 | N9 | Reference Identification | O | 300
+/// 0070 | G62 | Date/Time | O | 6
+/// 0080 | R3 | Route Information - Motor | O | 12
+/// 0090 | H3 | Special Handling Instructions | O | 6
+/// 0100 | K1 | Remarks | O | 10
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0110 | N1 | Name | O | 1
+/// 0100 -> 0120 | N2 | Additional Name Information | O | 1
+/// 0100 -> 0130 | N3 | Address Information | O | 2
+/// 0100 -> 0140 | N4 | Geographic Location | O | 1
+/// 0100 -> 0150 | N9 | Reference Identification | O | 5
+/// LOOP ID - 0200 | 10
+/// 0200 -> 0160 | N7 | Equipment Details | O | 1
+/// 0200 -> 0170 | M7 | Seal Numbers | O | 5
+/// LOOP ID - 0250 | 999
+/// 0250 -> 0180 | S5 | Stop Off Details | O | 1
+/// 0250 -> 0190 | N9 | Reference Identification | O | 10
+/// 0250 -> 0200 | G62 | Date/Time | O | 2
+/// 0250 -> 0210 | H3 | Special Handling Instructions | O | 6
+/// 0250 -> LOOP ID - 0260 | 2
+/// 0250 -> 0260 -> 0220 | N1 | Name | O | 1
+/// 0250 -> 0260 -> 0230 | N2 | Additional Name Information | O | 1
+/// 0250 -> 0260 -> 0240 | N3 | Address Information | O | 2
+/// 0250 -> 0260 -> 0250 | N4 | Geographic Location | O | 1
+/// 0250 -> 0260 -> 0260 | N9 | Reference Identification | O | 5
+/// LOOP ID - 0400 | 9999
+/// 0400 -> 0010 | LX | Assigned Number | O | 1
+/// 0400 -> 0020 | N9 | Reference Identification | O | 5
+/// 0400 -> 0030 | POD | Proof of Delivery | O | 1
+/// 0400 -> 0040 | L5 | Description, Marks and Numbers | O | 30
+/// 0400 -> 0050 | H1 | Hazardous Material | O | 3
+/// 0400 -> 0060 | H2 | Additional Hazardous Material Description | O | 2
+/// 0400 -> 0070 | L0 | Line Item - Quantity and Weight | O | 10
+/// 0400 -> 0080 | L1 | Rate and Charges | O | 50
+/// 0400 -> 0090 | L4 | Measurement | O | 10
+/// 0400 -> 0100 | L7 | Tariff Reference | O | 10
+/// 0400 -> 0110 | K1 | Remarks | O | 10
+/// 0010 | L3 | Total Weight and Charges | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210 {
+    pub st: ST,
+    pub b3: B3,
+    pub c3: Option<C3>,
+    pub itd: Option<ITD>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub r3: Vec<R3>,
+    pub h3: Vec<H3>,
+    pub k1: Vec<K1>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_210Loop0100>,
+    #[x12(loop_trigger = "N7")]
+    pub loop_0200: Vec<_210Loop0200>,
+    #[x12(loop_trigger = "S5")]
+    pub loop_0250: Vec<_210Loop0250>,
+    #[x12(loop_trigger = "LX")]
+    pub loop_0400: Vec<_210Loop0400>,
+    pub l3: L3,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0100 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub n9: Vec<N9>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0200 {
+    pub n7: N7,
+    pub m7: Vec<M7>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0250 {
+    pub s5: S5,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub h3: Vec<H3>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0260: Vec<_210Loop0260>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0260 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub n9: Vec<N9>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0400 {
+    pub lx: LX,
+    pub n9: Vec<N9>,
+    pub pod: Option<POD>,
+    pub l5: Vec<L5>,
+    pub h1: Vec<H1>,
+    pub h2: Vec<H2>,
+    pub l0: Vec<L0>,
+    pub l1: Vec<L1>,
+    pub l4: Vec<L4>,
+    pub l7: Vec<L7>,
+    pub k1: Vec<K1>,
+}
+
 /// 214 - Transportation Carrier Shipment Status Message
 ///
 /// This Draft Standard for Trial Use contains the format and establishes the data contents of the Transportation Carrier Shipment Status Message Transaction Set (214) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used by a transportation carrier to provide shippers, consignees, and their agents with the status of shipments in terms of dates, times, locations, route, identifying numbers, and conveyance.
@@ -788,6 +933,100 @@ pub struct _214Loop0260 {
     pub bin: BIN,
 }
 
+/// 300 - Reservation (Booking Request) (Ocean)
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Reservation (Booking Request) (Ocean) Transaction Set (300) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide all the information necessary for a shipper or forwarder to reserve space, equipment, or both, with an ocean carrier.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | B1 | Beginning Segment for Booking or Pick-up/Delivery | M | 1
+/// 030 | G61 | Contact | O | 3
+/// 040 | Y6 | Authentication | O | 2
+/// 050 | N9 | Reference Identification | O | 15
+/// LOOP ID - N1 | 10
+/// N1 -> 060 | N1 | Name | M | 1
+/// N1 -> 070 | N2 | Additional Name Information | O | 1
+/// N1 -> 080 | N3 | Address Information | O | 2
+/// N1 -> 090 | N4 | Geographic Location | O | 1
+/// N1 -> 100 | G61 | Contact | O | 3
+/// LOOP ID - R4 | 20
+/// R4 -> 110 | R4 | Port or Terminal | M | 1
+/// R4 -> 120 | DTM | Date/Time Reference | O | 3
+/// 130 | W09 | Equipment and Temperature | O | 1
+/// 140 | H3 | Special Handling Instructions | O | 6
+/// 150 | Y2 | Container Details | O | 10
+/// 160 | K1 | Remarks | O | 12
+/// LOOP ID - LX | 999
+/// LX -> 010 | LX | Assigned Number | M | 1
+/// LX -> 020 | N7 | Equipment Details | O | 1
+/// LX -> 030 | W09 | Equipment and Temperature | O | 1
+/// LX -> 040 | K1 | Remarks | O | 10
+/// LX -> 050 | L0 | Line Item - Quantity and Weight | O | 1
+/// LX -> 060 | L5 | Description, Marks and Numbers | O | 1
+/// LX -> 070 | L4 | Measurement | O | 1
+/// LX -> 080 | L1 | Rate and Charges | O | 1
+/// LX -> LOOP ID - H1 | 10
+/// LX -> H1 -> 090 | H1 | Hazardous Material | M | 1
+/// LX -> H1 -> 100 | H2 | Additional Hazardous Material Description | O | 10
+/// 010 | V1 | Vessel Identification | O | 2
+/// 020 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _300 {
+    pub st: ST,
+    pub b1: B1,
+    pub g61: Vec<G61>,
+    pub y6: Vec<Y6>,
+    pub n9: Vec<N9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_300LoopN1>,
+    #[x12(loop_trigger = "R4")]
+    pub loop_r4: Vec<_300LoopR4>,
+    pub w09: Option<W09>,
+    pub h3: Vec<H3>,
+    pub y2: Vec<Y2>,
+    pub k1: Vec<K1>,
+    #[x12(loop_trigger = "LX")]
+    pub loop_lx: Vec<_300LoopLX>,
+    pub v1: Vec<V1>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _300LoopN1 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub g61: Vec<G61>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _300LoopR4 {
+    pub r4: R4,
+    pub dtm: Vec<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _300LoopLX {
+    pub lx: LX,
+    pub n7: Option<N7>,
+    pub w09: Option<W09>,
+    pub k1: Vec<K1>,
+    pub l0: Option<L0>,
+    pub l5: Option<L5>,
+    pub l4: Option<L4>,
+    pub l1: Option<L1>,
+    #[x12(loop_trigger = "H1")]
+    pub loop_h1: Vec<_300LoopH1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _300LoopH1 {
+    pub h1: H1,
+    pub h2: Vec<H2>,
+}
+
 /// 301 Confirmation (Ocean)
 #[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
 pub struct _301 {
@@ -961,6 +1200,143 @@ pub struct _301LoopR4 {
     pub dtm: Vec<DTM>,
 }
 
+/// 304 - Shipping Instructions
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Shipping Instructions Transaction Set (304) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a shipper or forwarder to provide an ocean carrier with the information necessary to issue a bill of lading.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | B2 | Beginning Segment for Shipment Information Transaction | M | 1
+/// 030 | B2A | Set Purpose | O | 1
+/// 040 | Y6 | Authentication | O | 2
+/// 050 | N9 | Reference Identification | O | 15
+/// 060 | V1 | Vessel Identification | O | 2
+/// 070 | M1 | Insurance | O | 5
+/// 080 | C2 | Bank ID | O | 1
+/// 090 | C3 | Currency | O | 1
+/// 100 | Y2 | Container Details | O | 10
+/// LOOP ID - N1 | 10
+/// N1 -> 110 | N1 | Name | M | 1
+/// N1 -> 120 | N2 | Additional Name Information | O | 1
+/// N1 -> 130 | N3 | Address Information | O | 2
+/// N1 -> 140 | N4 | Geographic Location | O | 1
+/// N1 -> 150 | G61 | Contact | O | 3
+/// LOOP ID - R4 | 20
+/// R4 -> 160 | R4 | Port or Terminal | M | 1
+/// R4 -> 170 | DTM | Date/Time Reference | O | 15
+/// 180 | R2A | Route Information with Preference | O | 25
+/// 190 | K1 | Remarks | O | 12
+/// 200 | H3 | Special Handling Instructions | O | 6
+/// LOOP ID - C8 | 20
+/// C8 -> 210 | C8 | Certifications and Clauses | O | 1
+/// C8 -> 220 | C8C | Certifications Clauses Continuation | O | 5
+/// LOOP ID - LX | 999
+/// LX -> 010 | LX | Assigned Number | M | 1
+/// LX -> LOOP ID - N7 | 999
+/// LX -> N7 -> 020 | N7 | Equipment Details | M | 1
+/// LX -> N7 -> 030 | QTY | Quantity | O | 1
+/// LX -> N7 -> 040 | N12 | Equipment Environment | O | 1
+/// LX -> N7 -> 050 | M7 | Seal Numbers | O | 5
+/// LX -> N7 -> 060 | W09 | Equipment and Temperature | O | 1
+/// LX -> N7 -> 070 | N9 | Reference Identification | O | 3
+/// LX -> LOOP ID - L0 | 120
+/// LX -> L0 -> 080 | L0 | Line Item - Quantity and Weight | M | 1
+/// LX -> L0 -> 090 | L5 | Description, Marks and Numbers | O | 999
+/// LX -> L0 -> 100 | L4 | Measurement | O | 1
+/// LX -> L0 -> 110 | X1 | Export License | O | 1
+/// LX -> L0 -> LOOP ID - H1 | 10
+/// LX -> L0 -> H1 -> 120 | H1 | Hazardous Material | M | 1
+/// LX -> L0 -> H1 -> 130 | H2 | Additional Hazardous Material Description | O | 10
+/// 010 | L3 | Total Weight and Charges | O | 1
+/// 020 | K1 | Remarks | O | 999
+/// 030 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304 {
+    pub st: ST,
+    pub b2: B2,
+    pub b2a: Option<B2A>,
+    pub y6: Vec<Y6>,
+    pub n9: Vec<N9>,
+    pub v1: Vec<V1>,
+    pub m1: Vec<M1>,
+    pub c2: Option<C2>,
+    pub c3: Option<C3>,
+    pub y2: Vec<Y2>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_304LoopN1>,
+    #[x12(loop_trigger = "R4")]
+    pub loop_r4: Vec<_304LoopR4>,
+    pub r2a: Vec<R2A>,
+    /// heading remarks
+    pub k1: Vec<K1>,
+    pub h3: Vec<H3>,
+    #[x12(loop_trigger = "C8")]
+    pub loop_c8: Vec<_304LoopC8>,
+    #[x12(loop_trigger = "LX")]
+    pub loop_lx: Vec<_304LoopLX>,
+    pub l3: Option<L3>,
+    /// summary remarks
+    pub k1_2: Vec<K1>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopN1 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub g61: Vec<G61>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopR4 {
+    pub r4: R4,
+    pub dtm: Vec<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopC8 {
+    pub c8: C8,
+    pub c8c: Vec<C8C>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopLX {
+    pub lx: LX,
+    #[x12(loop_trigger = "N7")]
+    pub loop_n7: Vec<_304LoopN7>,
+    #[x12(loop_trigger = "L0")]
+    pub loop_l0: Vec<_304LoopL0>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopN7 {
+    pub n7: N7,
+    pub qty: Option<QTY>,
+    pub n12: Option<N12>,
+    pub m7: Vec<M7>,
+    pub w09: Option<W09>,
+    pub n9: Vec<N9>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopL0 {
+    pub l0: L0,
+    pub l5: Vec<L5>,
+    pub l4: Option<L4>,
+    pub x1: Option<X1>,
+    #[x12(loop_trigger = "H1")]
+    pub loop_h1: Vec<_304LoopH1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _304LoopH1 {
+    pub h1: H1,
+    pub h2: Vec<H2>,
+}
+
 /// 309 - U.S. Customs Manifest
 ///
 /// This Draft Standard for Trial Use contains the format and establishes the data contents of the U.S. Customs Manifest Transaction Set (309) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by carriers, terminal operators, port authorities, or service centers to provide U.S. Customs with manifest data on cargo arriving in or departing from the U.S. on oceangoing vessels, railroad trains, or other types of conveyances. The transaction set can be also used by carriers to provide terminal operators, port authorities, or service centers with manifest data on cargo arriving at their facilities via the conveyances mentioned above.
@@ -1492,7 +1868,7 @@ pub struct _310LoopR4 {
 pub struct _310LoopC8 {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub c8: Option<C8>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub c8c: Vec<C8C>,
 }
 
@@ -2571,6 +2947,1466 @@ pub struct _810LoopISS {
     pub pid: Option<PID>,
 }
 
+/// 820 - Payment Order/Remittance Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Payment Order/Remittance Advice Transaction Set (820) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to make a payment, send a remittance advice, or make a payment and send a remittance advice.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BPR | Beginning Segment for Payment Order/Remittance Advice | M | 1
+/// 030 | NTE | Note/Special Instruction | O | >1
+/// 035 | TRN | Trace | O | 1
+/// 040 | CUR | Currency | O | 1
+/// 050 | REF | Reference Identification | O | >1
+/// 060 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - N1 | >1
+/// N1 -> 070 | N1 | Name | O | 1
+/// N1 -> 080 | N2 | Additional Name Information | O | >1
+/// N1 -> 090 | N3 | Address Information | O | >1
+/// N1 -> 100 | N4 | Geographic Location | O | 1
+/// N1 -> 110 | REF | Reference Identification | O | >1
+/// N1 -> 120 | PER | Administrative Communications Contact | O | >1
+/// N1 -> 140 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - ENT | >1
+/// ENT -> 010 | ENT | Entity | O | 1
+/// ENT -> LOOP ID - NM1 | >1
+/// ENT -> NM1 -> 020 | NM1 | Individual or Organizational Name | O | 1
+/// ENT -> NM1 -> 030 | N2 | Additional Name Information | O | >1
+/// ENT -> NM1 -> 040 | N3 | Address Information | O | >1
+/// ENT -> NM1 -> 050 | N4 | Geographic Location | O | 1
+/// ENT -> NM1 -> 060 | REF | Reference Identification | O | >1
+/// ENT -> NM1 -> 070 | PER | Administrative Communications Contact | O | >1
+/// ENT -> LOOP ID - ADX | >1
+/// ENT -> ADX -> 080 | ADX | Adjustment | O | 1
+/// ENT -> ADX -> 090 | NTE | Note/Special Instruction | O | >1
+/// ENT -> ADX -> 100 | PER | Administrative Communications Contact | O | >1
+/// ENT -> ADX -> 105 | DTM | Date/Time Reference | O | 1
+/// ENT -> ADX -> 110 | REF | Reference Identification | O | >1
+/// ENT -> LOOP ID - RMR | >1
+/// ENT -> RMR -> 150 | RMR | Remittance Advice Accounts Receivable Open Item Reference | O | 1
+/// ENT -> RMR -> 160 | NTE | Note/Special Instruction | O | >1
+/// ENT -> RMR -> 170 | REF | Reference Identification | O | >1
+/// ENT -> RMR -> 180 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> LOOP ID - ADX | >1
+/// ENT -> RMR -> ADX -> 210 | ADX | Adjustment | O | 1
+/// ENT -> RMR -> ADX -> 220 | NTE | Note/Special Instruction | O | >1
+/// ENT -> RMR -> ADX -> 230 | PER | Administrative Communications Contact | O | >1
+/// 010 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820 {
+    pub st: ST,
+    pub bpr: BPR,
+    pub nte: Vec<NTE>,
+    pub trn: Option<TRN>,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_820LoopN1>,
+    #[x12(loop_trigger = "ENT")]
+    pub loop_ent: Vec<_820LoopENT>,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub dtm: Vec<DTM>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820LoopENT {
+    pub ent: ENT,
+    #[x12(loop_trigger = "NM1")]
+    pub loop_nm1: Vec<_820LoopNM1>,
+    #[x12(loop_trigger = "ADX")]
+    pub loop_adx: Vec<_820LoopADX>,
+    #[x12(loop_trigger = "RMR")]
+    pub loop_rmr: Vec<_820LoopRMR>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820LoopNM1 {
+    pub nm1: NM1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+/// Used on the `ENT` level and below `RMR`, which only allows `NTE` and `PER`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820LoopADX {
+    pub adx: ADX,
+    pub nte: Vec<NTE>,
+    pub per: Vec<PER>,
+    pub dtm: Option<DTM>,
+    pub r#ref: Vec<REF>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _820LoopRMR {
+    pub rmr: RMR,
+    pub nte: Vec<NTE>,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    #[x12(loop_trigger = "ADX")]
+    pub loop_adx: Vec<_820LoopADX>,
+}
+
+impl _820 {
+    /// Sum of the amounts paid (`RMR04`) and the adjustments (`ADX01`) per
+    /// `ENT` loop, in document order.
+    pub fn remittance_totals(&self) -> Result<Vec<Amount>, AmountError> {
+        self.loop_ent
+            .iter()
+            .map(|ent| {
+                let paid: Amount = ent
+                    .loop_rmr
+                    .iter()
+                    .map(|rmr| Amount::parse_opt(rmr.rmr._04.as_deref()))
+                    .sum::<Result<_, _>>()?;
+                let adjusted: Amount = ent
+                    .loop_adx
+                    .iter()
+                    .chain(ent.loop_rmr.iter().flat_map(|rmr| &rmr.loop_adx))
+                    .map(|adx| adx.adx._01.parse::<Amount>())
+                    .sum::<Result<_, _>>()?;
+                Ok(paid + adjusted)
+            })
+            .collect()
+    }
+
+    /// Compares the total payment (`BPR02`) with the sum of all `RMR04` and
+    /// `ADX01` amounts.
+    pub fn check_remittance(&self) -> Result<Balance, AmountError> {
+        Ok(Balance {
+            expected: self.bpr._02.parse()?,
+            actual: self.remittance_totals()?.into_iter().sum(),
+        })
+    }
+}
+
+/// 824 - Application Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Application Advice Transaction Set (824) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide the ability to report the results of an application system's data content edits of transaction sets. The results of editing transaction sets can be reported at the functional group and transaction set level, in either coded or free-form format. It is designed to accommodate the business need of reporting the acceptance, rejection or acceptance with change of any transaction set.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BGN | Beginning Segment | M | 1
+/// LOOP ID - N1 | >1
+/// N1 -> 030 | N1 | Name | O | 1
+/// N1 -> 040 | N2 | Additional Name Information | O | 2
+/// N1 -> 050 | N3 | Address Information | O | 2
+/// N1 -> 060 | N4 | Geographic Location | O | 1
+/// N1 -> 070 | REF | Reference Identification | O | 12
+/// N1 -> 080 | PER | Administrative Communications Contact | O | 3
+/// LOOP ID - OTI | >1
+/// OTI -> 010 | OTI | Original Transaction Identification | M | 1
+/// OTI -> 020 | REF | Reference Identification | O | 12
+/// OTI -> 030 | DTM | Date/Time Reference | O | 2
+/// OTI -> 040 | PER | Administrative Communications Contact | O | 3
+/// OTI -> 050 | AMT | Monetary Amount | O | 10
+/// OTI -> 060 | QTY | Quantity | O | 10
+/// OTI -> 065 | NM1 | Individual or Organizational Name | O | 9
+/// OTI -> LOOP ID - TED | >1
+/// OTI -> TED -> 070 | TED | Technical Error Description | O | 1
+/// OTI -> TED -> 080 | NTE | Note/Special Instruction | O | 100
+/// OTI -> TED -> 085 | RED | Related Data | O | 100
+/// 090 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _824 {
+    pub st: ST,
+    pub bgn: BGN,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_824LoopN1>,
+    #[x12(loop_trigger = "OTI")]
+    pub loop_oti: Vec<_824LoopOTI>,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _824LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _824LoopOTI {
+    pub oti: OTI,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    pub per: Vec<PER>,
+    pub amt: Vec<AMT>,
+    pub qty: Vec<QTY>,
+    pub nm1: Vec<NM1>,
+    #[x12(loop_trigger = "TED")]
+    pub loop_ted: Vec<_824LoopTED>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _824LoopTED {
+    pub ted: TED,
+    pub nte: Vec<NTE>,
+    pub red: Vec<RED>,
+}
+
+/// 850 - Purchase Order
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Purchase Order Transaction Set (850) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide for customary and established business and industry practice relative to the placement of purchase orders for goods and services.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BEG | Beginning Segment for Purchase Order | M | 1
+/// 040 | CUR | Currency | O | 1
+/// 050 | REF | Reference Identification | O | >1
+/// 060 | PER | Administrative Communications Contact | O | 3
+/// 080 | FOB | F.O.B. Related Instructions | O | >1
+/// 090 | CTP | Pricing Information | O | >1
+/// 095 | PAM | Period Amount | O | 10
+/// LOOP ID - SAC | 25
+/// SAC -> 120 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 125 | CUR | Currency | O | 1
+/// 130 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 145 | INC | Installment Information | O | 1
+/// 150 | DTM | Date/Time Reference | O | 10
+/// 200 | PID | Product/Item Description | O | 200
+/// 210 | MEA | Measurements | O | 40
+/// 220 | PWK | Paperwork | O | 25
+/// 230 | PKG | Marking, Packaging, Loading | O | 200
+/// 240 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 250 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// 260 | TD3 | Carrier Details (Equipment) | O | 12
+/// 270 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 280 | MAN | Marks and Numbers | O | 10
+/// 285 | TXI | Tax Information | O | >1
+/// LOOP ID - N9 | 1000
+/// N9 -> 295 | N9 | Reference Identification | O | 1
+/// N9 -> 300 | DTM | Date/Time Reference | O | >1
+/// N9 -> 305 | MSG | Message Text | O | 1000
+/// LOOP ID - N1 | 200
+/// N1 -> 310 | N1 | Name | O | 1
+/// N1 -> 320 | N2 | Additional Name Information | O | 2
+/// N1 -> 330 | N3 | Address Information | O | 2
+/// N1 -> 340 | N4 | Geographic Location | O | >1
+/// N1 -> 350 | REF | Reference Identification | O | 12
+/// N1 -> 360 | PER | Administrative Communications Contact | O | >1
+/// LOOP ID - PO1 | 100000
+/// PO1 -> 010 | PO1 | Baseline Item Data | M | 1
+/// PO1 -> 040 | CUR | Currency | O | 1
+/// PO1 -> 050 | PO3 | Additional Item Detail | O | 25
+/// PO1 -> 060 | CTP | Pricing Information | O | >1
+/// PO1 -> 065 | PAM | Period Amount | O | 10
+/// PO1 -> 070 | MEA | Measurements | O | 40
+/// PO1 -> LOOP ID - PID | 1000
+/// PO1 -> PID -> 080 | PID | Product/Item Description | O | 1
+/// PO1 -> PID -> 090 | MEA | Measurements | O | 10
+/// PO1 -> 100 | PWK | Paperwork | O | 25
+/// PO1 -> 110 | PO4 | Item Physical Details | O | >1
+/// PO1 -> 120 | REF | Reference Identification | O | >1
+/// PO1 -> 130 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SAC | 25
+/// PO1 -> SAC -> 130 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// PO1 -> SAC -> 135 | CUR | Currency | O | 1
+/// PO1 -> 150 | ITD | Terms of Sale/Deferred Terms of Sale | O | 2
+/// PO1 -> 180 | FOB | F.O.B. Related Instructions | O | >1
+/// PO1 -> 190 | SDQ | Destination Quantity | O | 500
+/// PO1 -> 200 | IT3 | Additional Item Data | O | 5
+/// PO1 -> 210 | DTM | Date/Time Reference | O | 10
+/// PO1 -> 220 | TC2 | Commodity | O | >1
+/// PO1 -> 230 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// PO1 -> 240 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> 250 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> 260 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> 271 | MAN | Marks and Numbers | O | 10
+/// PO1 -> 280 | MSG | Message Text | O | 12
+/// PO1 -> 291 | TXI | Tax Information | O | >1
+/// PO1 -> LOOP ID - SCH | 200
+/// PO1 -> SCH -> 295 | SCH | Line Item Schedule | O | 1
+/// PO1 -> SCH -> 296 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// PO1 -> SCH -> 297 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> SCH -> 298 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> SCH -> 299 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> SCH -> 300 | REF | Reference Identification | O | >1
+/// PO1 -> LOOP ID - N9 | 1000
+/// PO1 -> N9 -> 330 | N9 | Reference Identification | O | 1
+/// PO1 -> N9 -> 340 | DTM | Date/Time Reference | O | >1
+/// PO1 -> N9 -> 350 | MSG | Message Text | O | 1000
+/// PO1 -> LOOP ID - N1 | 200
+/// PO1 -> N1 -> 350 | N1 | Name | O | 1
+/// PO1 -> N1 -> 360 | N2 | Additional Name Information | O | 2
+/// PO1 -> N1 -> 370 | N3 | Address Information | O | 2
+/// PO1 -> N1 -> 380 | N4 | Geographic Location | O | 1
+/// PO1 -> N1 -> 400 | REF | Reference Identification | O | 12
+/// PO1 -> N1 -> 410 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SLN | 1000
+/// PO1 -> SLN -> 470 | SLN | Subline Item Detail | O | 1
+/// PO1 -> SLN -> 480 | MSG | Message Text | O | 100
+/// PO1 -> SLN -> 500 | PID | Product/Item Description | O | 1000
+/// PO1 -> SLN -> 510 | PO3 | Additional Item Detail | O | 104
+/// PO1 -> SLN -> 530 | DTM | Date/Time Reference | O | 10
+/// PO1 -> SLN -> 540 | CTP | Pricing Information | O | 25
+/// PO1 -> SLN -> 545 | PAM | Period Amount | O | 10
+/// PO1 -> SLN -> 550 | PO4 | Item Physical Details | O | 1
+/// LOOP ID - CTT | 1
+/// CTT -> 010 | CTT | Transaction Totals | O | 1
+/// CTT -> 020 | AMT | Monetary Amount | O | 1
+/// 030 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850 {
+    pub st: ST,
+    pub beg: BEG,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Vec<FOB>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_850LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub inc: Option<INC>,
+    pub dtm: Vec<DTM>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_850LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_850LoopN1>,
+    #[x12(loop_trigger = "PO1")]
+    pub loop_po1: Vec<_850LoopPO1>,
+    pub ctt: Option<CTT>,
+    pub amt: Option<AMT>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSAC {
+    pub sac: SAC,
+    pub cur: Option<CUR>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopN9 {
+    pub n9: N9,
+    pub dtm: Vec<DTM>,
+    pub msg: Vec<MSG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Vec<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopPO1 {
+    pub po1: PO1,
+    pub cur: Option<CUR>,
+    pub po3: Vec<PO3>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub mea: Vec<MEA>,
+    #[x12(loop_trigger = "PID")]
+    pub loop_pid: Vec<_850LoopPID>,
+    pub pwk: Vec<PWK>,
+    pub po4: Vec<PO4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_850LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub fob: Vec<FOB>,
+    pub sdq: Vec<SDQ>,
+    pub it3: Vec<IT3>,
+    pub dtm: Vec<DTM>,
+    pub tc2: Vec<TC2>,
+    pub td1: Option<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub msg: Vec<MSG>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "SCH")]
+    pub loop_sch: Vec<_850LoopSCH>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_850LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_850LoopN1>,
+    #[x12(loop_trigger = "SLN")]
+    pub loop_sln: Vec<_850LoopSLN>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopPID {
+    pub pid: PID,
+    pub mea: Vec<MEA>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSCH {
+    pub sch: SCH,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSLN {
+    pub sln: SLN,
+    pub msg: Vec<MSG>,
+    pub pid: Vec<PID>,
+    pub po3: Vec<PO3>,
+    pub dtm: Vec<DTM>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub po4: Option<PO4>,
+}
+
+/// 855 - Purchase Order Acknowledgment
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Purchase Order Acknowledgment Transaction Set (855) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can provide for customary and established business and industry practice relative to a seller's acknowledgment of a buyer's purchase order.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BAK | Beginning Segment for Purchase Order Acknowledgment | M | 1
+/// 040 | CUR | Currency | O | 1
+/// 050 | REF | Reference Identification | O | >1
+/// 060 | PER | Administrative Communications Contact | O | 3
+/// 080 | FOB | F.O.B. Related Instructions | O | >1
+/// 090 | CTP | Pricing Information | O | >1
+/// 095 | PAM | Period Amount | O | 10
+/// LOOP ID - SAC | 25
+/// SAC -> 120 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 125 | CUR | Currency | O | 1
+/// 130 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 145 | INC | Installment Information | O | 1
+/// 150 | DTM | Date/Time Reference | O | 10
+/// 200 | PID | Product/Item Description | O | 200
+/// 210 | MEA | Measurements | O | 40
+/// 220 | PWK | Paperwork | O | 25
+/// 230 | PKG | Marking, Packaging, Loading | O | 200
+/// 240 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 250 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// 260 | TD3 | Carrier Details (Equipment) | O | 12
+/// 270 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 280 | MAN | Marks and Numbers | O | 10
+/// 285 | TXI | Tax Information | O | >1
+/// LOOP ID - N9 | 1000
+/// N9 -> 295 | N9 | Reference Identification | O | 1
+/// N9 -> 300 | DTM | Date/Time Reference | O | >1
+/// N9 -> 305 | MSG | Message Text | O | 1000
+/// LOOP ID - N1 | 200
+/// N1 -> 310 | N1 | Name | O | 1
+/// N1 -> 320 | N2 | Additional Name Information | O | 2
+/// N1 -> 330 | N3 | Address Information | O | 2
+/// N1 -> 340 | N4 | Geographic Location | O | >1
+/// N1 -> 350 | REF | Reference Identification | O | 12
+/// N1 -> 360 | PER | Administrative Communications Contact | O | >1
+/// LOOP ID - PO1 | 100000
+/// PO1 -> 010 | PO1 | Baseline Item Data | M | 1
+/// PO1 -> 040 | CUR | Currency | O | 1
+/// PO1 -> 050 | PO3 | Additional Item Detail | O | 25
+/// PO1 -> 060 | CTP | Pricing Information | O | >1
+/// PO1 -> 065 | PAM | Period Amount | O | 10
+/// PO1 -> 070 | MEA | Measurements | O | 40
+/// PO1 -> LOOP ID - PID | 1000
+/// PO1 -> PID -> 080 | PID | Product/Item Description | O | 1
+/// PO1 -> PID -> 090 | MEA | Measurements | O | 10
+/// PO1 -> 100 | PWK | Paperwork | O | 25
+/// PO1 -> 110 | PO4 | Item Physical Details | O | >1
+/// PO1 -> 120 | REF | Reference Identification | O | >1
+/// PO1 -> 130 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SAC | 25
+/// PO1 -> SAC -> 130 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// PO1 -> SAC -> 135 | CUR | Currency | O | 1
+/// PO1 -> 150 | ITD | Terms of Sale/Deferred Terms of Sale | O | 2
+/// PO1 -> 180 | FOB | F.O.B. Related Instructions | O | >1
+/// PO1 -> 190 | SDQ | Destination Quantity | O | 500
+/// PO1 -> 200 | IT3 | Additional Item Data | O | 5
+/// PO1 -> 210 | DTM | Date/Time Reference | O | 10
+/// PO1 -> 220 | TC2 | Commodity | O | >1
+/// PO1 -> 230 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// PO1 -> 240 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> 250 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> 260 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> 271 | MAN | Marks and Numbers | O | 10
+/// PO1 -> 280 | MSG | Message Text | O | 12
+/// PO1 -> 291 | TXI | Tax Information | O | >1
+/// PO1 -> 293 | ACK | Line Item Acknowledgment | O | 104
+/// PO1 -> LOOP ID - SCH | 200
+/// PO1 -> SCH -> 295 | SCH | Line Item Schedule | O | 1
+/// PO1 -> SCH -> 296 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// PO1 -> SCH -> 297 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> SCH -> 298 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> SCH -> 299 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> SCH -> 300 | REF | Reference Identification | O | >1
+/// PO1 -> LOOP ID - N9 | 1000
+/// PO1 -> N9 -> 330 | N9 | Reference Identification | O | 1
+/// PO1 -> N9 -> 340 | DTM | Date/Time Reference | O | >1
+/// PO1 -> N9 -> 350 | MSG | Message Text | O | 1000
+/// PO1 -> LOOP ID - N1 | 200
+/// PO1 -> N1 -> 350 | N1 | Name | O | 1
+/// PO1 -> N1 -> 360 | N2 | Additional Name Information | O | 2
+/// PO1 -> N1 -> 370 | N3 | Address Information | O | 2
+/// PO1 -> N1 -> 380 | N4 | Geographic Location | O | 1
+/// PO1 -> N1 -> 400 | REF | Reference Identification | O | 12
+/// PO1 -> N1 -> 410 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SLN | 1000
+/// PO1 -> SLN -> 470 | SLN | Subline Item Detail | O | 1
+/// PO1 -> SLN -> 480 | MSG | Message Text | O | 100
+/// PO1 -> SLN -> 500 | PID | Product/Item Description | O | 1000
+/// PO1 -> SLN -> 510 | PO3 | Additional Item Detail | O | 104
+/// PO1 -> SLN -> 530 | DTM | Date/Time Reference | O | 10
+/// PO1 -> SLN -> 540 | CTP | Pricing Information | O | 25
+/// PO1 -> SLN -> 545 | PAM | Period Amount | O | 10
+/// PO1 -> SLN -> 550 | PO4 | Item Physical Details | O | 1
+/// LOOP ID - CTT | 1
+/// CTT -> 010 | CTT | Transaction Totals | O | 1
+/// CTT -> 020 | AMT | Monetary Amount | O | 1
+/// 030 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855 {
+    pub st: ST,
+    pub bak: BAK,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Vec<FOB>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_855LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub inc: Option<INC>,
+    pub dtm: Vec<DTM>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_855LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_855LoopN1>,
+    #[x12(loop_trigger = "PO1")]
+    pub loop_po1: Vec<_855LoopPO1>,
+    pub ctt: Option<CTT>,
+    pub amt: Option<AMT>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopSAC {
+    pub sac: SAC,
+    pub cur: Option<CUR>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopN9 {
+    pub n9: N9,
+    pub dtm: Vec<DTM>,
+    pub msg: Vec<MSG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Vec<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopPO1 {
+    pub po1: PO1,
+    pub cur: Option<CUR>,
+    pub po3: Vec<PO3>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub mea: Vec<MEA>,
+    #[x12(loop_trigger = "PID")]
+    pub loop_pid: Vec<_855LoopPID>,
+    pub pwk: Vec<PWK>,
+    pub po4: Vec<PO4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_855LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub fob: Vec<FOB>,
+    pub sdq: Vec<SDQ>,
+    pub it3: Vec<IT3>,
+    pub dtm: Vec<DTM>,
+    pub tc2: Vec<TC2>,
+    pub td1: Option<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub msg: Vec<MSG>,
+    pub txi: Vec<TXI>,
+    pub ack: Vec<ACK>,
+    #[x12(loop_trigger = "SCH")]
+    pub loop_sch: Vec<_855LoopSCH>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_855LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_855LoopN1>,
+    #[x12(loop_trigger = "SLN")]
+    pub loop_sln: Vec<_855LoopSLN>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopPID {
+    pub pid: PID,
+    pub mea: Vec<MEA>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopSCH {
+    pub sch: SCH,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _855LoopSLN {
+    pub sln: SLN,
+    pub msg: Vec<MSG>,
+    pub pid: Vec<PID>,
+    pub po3: Vec<PO3>,
+    pub dtm: Vec<DTM>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub po4: Option<PO4>,
+}
+
+/// 856 - Ship Notice/Manifest
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Ship Notice/Manifest Transaction Set (856) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to list the contents of a shipment of goods as well as additional information relating to the shipment, such as order information, product description, physical characteristics, type of packaging, marking, carrier information, and configuration of goods within the transportation equipment.
+///
+/// The `HL` loops are nested by their parent pointers (`HL02`): a shipment
+/// holds its orders, an order its tares and packs, a pack its items.
+/// Rendering recomputes `HL01` and `HL02` from that nesting, see [`crate::util::hl`].
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BSN | Beginning Segment for Ship Notice | M | 1
+/// 040 | DTM | Date/Time Reference | O | 10
+/// LOOP ID - HL | 200000
+/// HL -> 010 | HL | Hierarchical Level | M | 1
+/// HL -> 020 | LIN | Item Identification | O | 1
+/// HL -> 030 | SN1 | Item Detail (Shipment) | O | 1
+/// HL -> 040 | SLN | Subline Item Detail | O | 1000
+/// HL -> 050 | PRF | Purchase Order Reference | O | 1
+/// HL -> 060 | PO4 | Item Physical Details | O | 1
+/// HL -> 070 | PID | Product/Item Description | O | 200
+/// HL -> 080 | MEA | Measurements | O | 40
+/// HL -> 090 | PWK | Paperwork | O | 25
+/// HL -> 100 | PKG | Marking, Packaging, Loading | O | 25
+/// HL -> 110 | TD1 | Carrier Details (Quantity and Weight) | O | 20
+/// HL -> 120 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// HL -> 130 | TD3 | Carrier Details (Equipment) | O | 12
+/// HL -> 140 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// HL -> 150 | REF | Reference Identification | O | >1
+/// HL -> 160 | PER | Administrative Communications Contact | O | 3
+/// HL -> LOOP ID - CLD | 200
+/// HL -> CLD -> 170 | CLD | Load Detail | O | 1
+/// HL -> CLD -> 180 | REF | Reference Identification | O | 200
+/// HL -> CLD -> 185 | DTM | Date/Time Reference | O | 1
+/// HL -> 190 | MAN | Marks and Numbers | O | >1
+/// HL -> 200 | DTM | Date/Time Reference | O | 10
+/// HL -> 210 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> LOOP ID - N1 | 200
+/// HL -> N1 -> 220 | N1 | Name | O | 1
+/// HL -> N1 -> 230 | N2 | Additional Name Information | O | 2
+/// HL -> N1 -> 240 | N3 | Address Information | O | 2
+/// HL -> N1 -> 250 | N4 | Geographic Location | O | 1
+/// HL -> N1 -> 260 | REF | Reference Identification | O | 12
+/// HL -> N1 -> 270 | PER | Administrative Communications Contact | O | 3
+/// HL -> N1 -> 280 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> 290 | SDQ | Destination Quantity | O | 50
+/// 010 | CTT | Transaction Totals | O | 1
+/// 020 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct _856 {
+    pub st: ST,
+    pub bsn: BSN,
+    pub dtm: Vec<DTM>,
+    pub loop_hl: Vec<_856LoopHL>,
+    pub ctt: Option<CTT>,
+    pub se: SE,
+}
+
+impl<'a> Parser<&'a str, _856, nom::error::Error<&'a str>> for _856 {
+    fn parse(input: &'a str) -> IResult<&'a str, _856> {
+        let mut output = _856::default();
+        let (rest, obj) = ST::parse(input)?;
+        output.st = obj;
+        let (rest, obj) = BSN::parse(rest)?;
+        output.bsn = obj;
+        let (rest, obj) = many0(DTM::parse).parse(rest)?;
+        output.dtm = obj;
+        // the HL loops come as a flat list, nested afterwards by HL02
+        let (rest, obj) = many0(_856LoopHL::parse).parse(rest)?;
+        output.loop_hl = crate::util::hl::build_tree(obj);
+        let (rest, obj) = opt(CTT::parse).parse(rest)?;
+        output.ctt = obj;
+        let (rest, obj) = SE::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+impl Display for _856 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut loop_hl = self.loop_hl.clone();
+        crate::util::hl::renumber(&mut loop_hl);
+        write!(f, "{}{}", self.st, self.bsn)?;
+        for dtm in &self.dtm {
+            write!(f, "{dtm}")?;
+        }
+        for hl in &loop_hl {
+            write!(f, "{hl}")?;
+        }
+        if let Some(ctt) = &self.ctt {
+            // the hash total counts the HL segments rendered above
+            let mut ctt = ctt.clone();
+            ctt._01 = crate::util::hl::walk(&loop_hl).len().to_string();
+            write!(f, "{ctt}")?;
+        }
+        write!(f, "{}", self.se)
+    }
+}
+
+/// One level of the 856 hierarchy (shipment, order, tare, pack or item)
+/// together with the levels below it.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
+pub struct _856LoopHL {
+    pub hl: HL,
+    pub lin: Option<LIN>,
+    pub sn1: Option<SN1>,
+    pub sln: Vec<SLN>,
+    pub prf: Option<PRF>,
+    pub po4: Option<PO4>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub loop_cld: Vec<_856LoopCLD>,
+    pub man: Vec<MAN>,
+    pub dtm: Vec<DTM>,
+    pub fob: Option<FOB>,
+    pub loop_n1: Vec<_856LoopN1>,
+    pub sdq: Vec<SDQ>,
+    /// levels whose `HL02` points at this one
+    pub loop_hl: Vec<_856LoopHL>,
+}
+
+impl _856LoopHL {
+    /// Hierarchical Level Code (`HL03`), e.g. `S` for shipment or `I` for item.
+    pub fn level(&self) -> &str {
+        &self.hl._03
+    }
+}
+
+/// Parses a single level; `loop_hl` is left empty.
+impl<'a> Parser<&'a str, _856LoopHL, nom::error::Error<&'a str>> for _856LoopHL {
+    fn parse(input: &'a str) -> IResult<&'a str, _856LoopHL> {
+        let mut output = _856LoopHL::default();
+        let (rest, obj) = HL::parse(input)?;
+        output.hl = obj;
+        let (rest, obj) = opt(LIN::parse).parse(rest)?;
+        output.lin = obj;
+        let (rest, obj) = opt(SN1::parse).parse(rest)?;
+        output.sn1 = obj;
+        let (rest, obj) = many0(SLN::parse).parse(rest)?;
+        output.sln = obj;
+        let (rest, obj) = opt(PRF::parse).parse(rest)?;
+        output.prf = obj;
+        let (rest, obj) = opt(PO4::parse).parse(rest)?;
+        output.po4 = obj;
+        let (rest, obj) = many0(PID::parse).parse(rest)?;
+        output.pid = obj;
+        let (rest, obj) = many0(MEA::parse).parse(rest)?;
+        output.mea = obj;
+        let (rest, obj) = many0(PWK::parse).parse(rest)?;
+        output.pwk = obj;
+        let (rest, obj) = many0(PKG::parse).parse(rest)?;
+        output.pkg = obj;
+        let (rest, obj) = many0(TD1::parse).parse(rest)?;
+        output.td1 = obj;
+        let (rest, obj) = many0(TD5::parse).parse(rest)?;
+        output.td5 = obj;
+        let (rest, obj) = many0(TD3::parse).parse(rest)?;
+        output.td3 = obj;
+        let (rest, obj) = many0(TD4::parse).parse(rest)?;
+        output.td4 = obj;
+        let (rest, obj) = many0(REF::parse).parse(rest)?;
+        output.r#ref = obj;
+        let (rest, obj) = many0(PER::parse).parse(rest)?;
+        output.per = obj;
+        let (rest, obj) = many0(_856LoopCLD::parse).parse(rest)?;
+        output.loop_cld = obj;
+        let (rest, obj) = many0(MAN::parse).parse(rest)?;
+        output.man = obj;
+        let (rest, obj) = many0(DTM::parse).parse(rest)?;
+        output.dtm = obj;
+        let (rest, obj) = opt(FOB::parse).parse(rest)?;
+        output.fob = obj;
+        let (rest, obj) = many0(_856LoopN1::parse).parse(rest)?;
+        output.loop_n1 = obj;
+        let (rest, obj) = many0(SDQ::parse).parse(rest)?;
+        output.sdq = obj;
+        Ok((rest, output))
+    }
+}
+
+impl crate::util::hl::Hierarchy for _856LoopHL {
+    fn id(&self) -> &str {
+        &self.hl._01
+    }
+    fn parent_id(&self) -> Option<&str> {
+        self.hl._02.as_deref()
+    }
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        self.hl._01 = id;
+        self.hl._02 = parent_id;
+        // HL04 is optional in the 856 and kept absent if it was not sent
+        if self.hl._04.is_some() {
+            self.hl._04 = Some(child_code.to_string());
+        }
+    }
+    fn children(&self) -> &[Self] {
+        &self.loop_hl
+    }
+    fn children_mut(&mut self) -> &mut Vec<Self> {
+        &mut self.loop_hl
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _856LoopCLD {
+    pub cld: CLD,
+    pub r#ref: Vec<REF>,
+    pub dtm: Option<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _856LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Option<FOB>,
+}
+
+/// 860 - Purchase Order Change Request - Buyer Initiated
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Purchase Order Change Request - Buyer Initiated Transaction Set (860) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide the information required for the customary and established business and industry practice relative to a purchase order change.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BCH | Beginning Segment for Purchase Order Change | M | 1
+/// 040 | CUR | Currency | O | 1
+/// 050 | REF | Reference Identification | O | >1
+/// 060 | PER | Administrative Communications Contact | O | 3
+/// 080 | FOB | F.O.B. Related Instructions | O | >1
+/// 090 | CTP | Pricing Information | O | >1
+/// 095 | PAM | Period Amount | O | 10
+/// LOOP ID - SAC | 25
+/// SAC -> 120 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 125 | CUR | Currency | O | 1
+/// 130 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 145 | INC | Installment Information | O | 1
+/// 150 | DTM | Date/Time Reference | O | 10
+/// 200 | PID | Product/Item Description | O | 200
+/// 210 | MEA | Measurements | O | 40
+/// 220 | PWK | Paperwork | O | 25
+/// 230 | PKG | Marking, Packaging, Loading | O | 200
+/// 240 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 250 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// 260 | TD3 | Carrier Details (Equipment) | O | 12
+/// 270 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 280 | MAN | Marks and Numbers | O | 10
+/// 285 | TXI | Tax Information | O | >1
+/// LOOP ID - N9 | 1000
+/// N9 -> 295 | N9 | Reference Identification | O | 1
+/// N9 -> 300 | DTM | Date/Time Reference | O | >1
+/// N9 -> 305 | MSG | Message Text | O | 1000
+/// LOOP ID - N1 | 200
+/// N1 -> 310 | N1 | Name | O | 1
+/// N1 -> 320 | N2 | Additional Name Information | O | 2
+/// N1 -> 330 | N3 | Address Information | O | 2
+/// N1 -> 340 | N4 | Geographic Location | O | >1
+/// N1 -> 350 | REF | Reference Identification | O | 12
+/// N1 -> 360 | PER | Administrative Communications Contact | O | >1
+/// LOOP ID - POC | 100000
+/// POC -> 010 | POC | Line Item Change | M | 1
+/// POC -> 040 | CUR | Currency | O | 1
+/// POC -> 050 | PO3 | Additional Item Detail | O | 25
+/// POC -> 060 | CTP | Pricing Information | O | >1
+/// POC -> 065 | PAM | Period Amount | O | 10
+/// POC -> 070 | MEA | Measurements | O | 40
+/// POC -> LOOP ID - PID | 1000
+/// POC -> PID -> 080 | PID | Product/Item Description | O | 1
+/// POC -> PID -> 090 | MEA | Measurements | O | 10
+/// POC -> 100 | PWK | Paperwork | O | 25
+/// POC -> 110 | PO4 | Item Physical Details | O | >1
+/// POC -> 120 | REF | Reference Identification | O | >1
+/// POC -> 130 | PER | Administrative Communications Contact | O | 3
+/// POC -> LOOP ID - SAC | 25
+/// POC -> SAC -> 130 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// POC -> SAC -> 135 | CUR | Currency | O | 1
+/// POC -> 150 | ITD | Terms of Sale/Deferred Terms of Sale | O | 2
+/// POC -> 180 | FOB | F.O.B. Related Instructions | O | >1
+/// POC -> 190 | SDQ | Destination Quantity | O | 500
+/// POC -> 200 | IT3 | Additional Item Data | O | 5
+/// POC -> 210 | DTM | Date/Time Reference | O | 10
+/// POC -> 220 | TC2 | Commodity | O | >1
+/// POC -> 230 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// POC -> 240 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// POC -> 250 | TD3 | Carrier Details (Equipment) | O | 12
+/// POC -> 260 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// POC -> 271 | MAN | Marks and Numbers | O | 10
+/// POC -> 280 | MSG | Message Text | O | 12
+/// POC -> 291 | TXI | Tax Information | O | >1
+/// POC -> LOOP ID - SCH | 200
+/// POC -> SCH -> 295 | SCH | Line Item Schedule | O | 1
+/// POC -> SCH -> 296 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// POC -> SCH -> 297 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// POC -> SCH -> 298 | TD3 | Carrier Details (Equipment) | O | 12
+/// POC -> SCH -> 299 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// POC -> SCH -> 300 | REF | Reference Identification | O | >1
+/// POC -> LOOP ID - N9 | 1000
+/// POC -> N9 -> 330 | N9 | Reference Identification | O | 1
+/// POC -> N9 -> 340 | DTM | Date/Time Reference | O | >1
+/// POC -> N9 -> 350 | MSG | Message Text | O | 1000
+/// POC -> LOOP ID - N1 | 200
+/// POC -> N1 -> 350 | N1 | Name | O | 1
+/// POC -> N1 -> 360 | N2 | Additional Name Information | O | 2
+/// POC -> N1 -> 370 | N3 | Address Information | O | 2
+/// POC -> N1 -> 380 | N4 | Geographic Location | O | 1
+/// POC -> N1 -> 400 | REF | Reference Identification | O | 12
+/// POC -> N1 -> 410 | PER | Administrative Communications Contact | O | 3
+/// POC -> LOOP ID - SLN | 1000
+/// POC -> SLN -> 470 | SLN | Subline Item Detail | O | 1
+/// POC -> SLN -> 480 | MSG | Message Text | O | 100
+/// POC -> SLN -> 500 | PID | Product/Item Description | O | 1000
+/// POC -> SLN -> 510 | PO3 | Additional Item Detail | O | 104
+/// POC -> SLN -> 530 | DTM | Date/Time Reference | O | 10
+/// POC -> SLN -> 540 | CTP | Pricing Information | O | 25
+/// POC -> SLN -> 545 | PAM | Period Amount | O | 10
+/// POC -> SLN -> 550 | PO4 | Item Physical Details | O | 1
+/// LOOP ID - CTT | 1
+/// CTT -> 010 | CTT | Transaction Totals | O | 1
+/// CTT -> 020 | AMT | Monetary Amount | O | 1
+/// 030 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860 {
+    pub st: ST,
+    pub bch: BCH,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Vec<FOB>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_860LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub inc: Option<INC>,
+    pub dtm: Vec<DTM>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_860LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_860LoopN1>,
+    #[x12(loop_trigger = "POC")]
+    pub loop_poc: Vec<_860LoopPOC>,
+    pub ctt: Option<CTT>,
+    pub amt: Option<AMT>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopSAC {
+    pub sac: SAC,
+    pub cur: Option<CUR>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopN9 {
+    pub n9: N9,
+    pub dtm: Vec<DTM>,
+    pub msg: Vec<MSG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Vec<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopPOC {
+    pub poc: POC,
+    pub cur: Option<CUR>,
+    pub po3: Vec<PO3>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub mea: Vec<MEA>,
+    #[x12(loop_trigger = "PID")]
+    pub loop_pid: Vec<_860LoopPID>,
+    pub pwk: Vec<PWK>,
+    pub po4: Vec<PO4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_860LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub fob: Vec<FOB>,
+    pub sdq: Vec<SDQ>,
+    pub it3: Vec<IT3>,
+    pub dtm: Vec<DTM>,
+    pub tc2: Vec<TC2>,
+    pub td1: Option<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub msg: Vec<MSG>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "SCH")]
+    pub loop_sch: Vec<_860LoopSCH>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_860LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_860LoopN1>,
+    #[x12(loop_trigger = "SLN")]
+    pub loop_sln: Vec<_860LoopSLN>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopPID {
+    pub pid: PID,
+    pub mea: Vec<MEA>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopSCH {
+    pub sch: SCH,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _860LoopSLN {
+    pub sln: SLN,
+    pub msg: Vec<MSG>,
+    pub pid: Vec<PID>,
+    pub po3: Vec<PO3>,
+    pub dtm: Vec<DTM>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub po4: Option<PO4>,
+}
+
+/// 940 - Warehouse Shipping Order
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Shipping Order Transaction Set (940) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a depositor to advise a warehouse to make a shipment and to provide shipment information.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W05 | Shipping Order Identification | M | 1
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0040 | N1 | Name | O | 1
+/// 0100 -> 0050 | N2 | Additional Name Information | O | 2
+/// 0100 -> 0060 | N3 | Address Information | O | 2
+/// 0100 -> 0070 | N4 | Geographic Location | O | 1
+/// 0100 -> 0080 | REF | Reference Identification | O | 2
+/// 0100 -> 0090 | PER | Administrative Communications Contact | O | 2
+/// 0090 | N9 | Reference Identification | O | 10
+/// 0100 | G62 | Date/Time | O | 10
+/// 0110 | NTE | Note/Special Instruction | O | 10
+/// LOOP ID - 0300 | 9999
+/// 0300 -> 0010 | LX | Assigned Number | O | 1
+/// 0300 -> LOOP ID - 0310 | 9999
+/// 0300 -> 0310 -> 0020 | W01 | Line Item Detail - Warehouse | M | 1
+/// 0300 -> 0310 -> 0030 | G69 | Line Item Detail - Description | O | 5
+/// 0300 -> 0310 -> 0040 | N9 | Reference Identification | O | 200
+/// 0005 | W66 | Warehouse Carrier Information | M | 1
+/// 0010 | W76 | Total Shipping Order | O | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _940 {
+    pub st: ST,
+    pub w05: W05,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_940Loop0100>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub nte: Vec<NTE>,
+    #[x12(loop_trigger = "LX|W01")]
+    pub loop_0300: Vec<_940Loop0300>,
+    pub w66: W66,
+    pub w76: Option<W76>,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _940Loop0100 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _940Loop0300 {
+    pub lx: Option<LX>,
+    #[x12(loop_trigger = "W01")]
+    pub loop_0310: Vec<_940Loop0310>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _940Loop0310 {
+    pub w01: W01,
+    pub g69: Vec<G69>,
+    pub n9: Vec<N9>,
+}
+
+/// 943 - Warehouse Stock Transfer Shipment Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Stock Transfer Shipment Advice Transaction Set (943) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a depositor to advise a warehouse of an inbound stock transfer shipment.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W06 | Warehouse Shipment Identification | M | 1
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0030 | N1 | Name | O | 1
+/// 0100 -> 0040 | N2 | Additional Name Information | O | 2
+/// 0100 -> 0050 | N3 | Address Information | O | 2
+/// 0100 -> 0060 | N4 | Geographic Location | O | 1
+/// 0100 -> 0070 | REF | Reference Identification | O | 2
+/// 0100 -> 0080 | PER | Administrative Communications Contact | O | 2
+/// 0090 | N9 | Reference Identification | O | 10
+/// 0100 | G62 | Date/Time | O | 5
+/// 0110 | NTE | Note/Special Instruction | O | 10
+/// 0120 | W27 | Carrier Detail | M | 1
+/// LOOP ID - 0200 | 9999
+/// 0200 -> 0010 | W04 | Item Detail Total | M | 1
+/// 0200 -> 0020 | G69 | Line Item Detail - Description | O | 5
+/// 0200 -> 0030 | N9 | Reference Identification | O | 200
+/// 0010 | W03 | Total Shipment Information | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _943 {
+    pub st: ST,
+    pub w06: W06,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_943Loop0100>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub nte: Vec<NTE>,
+    pub w27: W27,
+    #[x12(loop_trigger = "W04")]
+    pub loop_0200: Vec<_943Loop0200>,
+    pub w03: W03,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _943Loop0100 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _943Loop0200 {
+    pub w04: W04,
+    pub g69: Vec<G69>,
+    pub n9: Vec<N9>,
+}
+
+/// 944 - Warehouse Stock Transfer Receipt Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Stock Transfer Receipt Advice Transaction Set (944) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a warehouse to advise a depositor of the receipt of a stock transfer shipment.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W17 | Warehouse Receipt Identification | M | 1
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0030 | N1 | Name | O | 1
+/// 0100 -> 0040 | N2 | Additional Name Information | O | 2
+/// 0100 -> 0050 | N3 | Address Information | O | 2
+/// 0100 -> 0060 | N4 | Geographic Location | O | 1
+/// 0100 -> 0070 | REF | Reference Identification | O | 2
+/// 0100 -> 0080 | PER | Administrative Communications Contact | O | 2
+/// 0090 | N9 | Reference Identification | O | 10
+/// 0100 | G62 | Date/Time | O | 5
+/// LOOP ID - 0200 | 9999
+/// 0200 -> 0010 | LX | Assigned Number | O | 1
+/// 0200 -> LOOP ID - 0210 | 9999
+/// 0200 -> 0210 -> 0020 | W07 | Item Detail For Stock Receipt | M | 1
+/// 0200 -> 0210 -> 0030 | G69 | Line Item Detail - Description | O | 5
+/// 0200 -> 0210 -> 0040 | N9 | Reference Identification | O | 200
+/// 0010 | W14 | Total Receipt Information | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _944 {
+    pub st: ST,
+    pub w17: W17,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_944Loop0100>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    #[x12(loop_trigger = "LX|W07")]
+    pub loop_0200: Vec<_944Loop0200>,
+    pub w14: W14,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _944Loop0100 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _944Loop0200 {
+    pub lx: Option<LX>,
+    #[x12(loop_trigger = "W07")]
+    pub loop_0210: Vec<_944Loop0210>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _944Loop0210 {
+    pub w07: W07,
+    pub g69: Vec<G69>,
+    pub n9: Vec<N9>,
+}
+
+/// 945 - Warehouse Shipping Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Shipping Advice Transaction Set (945) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a warehouse to advise a depositor that a shipment was made.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W06 | Warehouse Shipment Identification | M | 1
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0040 | N1 | Name | O | 1
+/// 0100 -> 0050 | N2 | Additional Name Information | O | 2
+/// 0100 -> 0060 | N3 | Address Information | O | 2
+/// 0100 -> 0070 | N4 | Geographic Location | O | 1
+/// 0100 -> 0080 | REF | Reference Identification | O | 2
+/// 0100 -> 0090 | PER | Administrative Communications Contact | O | 2
+/// 0100 | N9 | Reference Identification | O | 30
+/// 0110 | G62 | Date/Time | O | 10
+/// 0130 | W27 | Carrier Detail | M | 1
+/// LOOP ID - 0200 | 9999
+/// 0200 -> 0010 | LX | Assigned Number | O | 1
+/// 0200 -> 0020 | MAN | Marks and Numbers | O | 10
+/// 0200 -> LOOP ID - 0210 | 9999
+/// 0200 -> 0210 -> 0050 | W12 | Warehouse Item Detail | M | 1
+/// 0200 -> 0210 -> 0060 | G69 | Line Item Detail - Description | O | 5
+/// 0200 -> 0210 -> 0070 | N9 | Reference Identification | O | 200
+/// 0010 | W03 | Total Shipment Information | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _945 {
+    pub st: ST,
+    pub w06: W06,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_945Loop0100>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub w27: W27,
+    #[x12(loop_trigger = "LX|W12")]
+    pub loop_0200: Vec<_945Loop0200>,
+    pub w03: W03,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _945Loop0100 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _945Loop0200 {
+    pub lx: Option<LX>,
+    pub man: Vec<MAN>,
+    #[x12(loop_trigger = "W12")]
+    pub loop_0210: Vec<_945Loop0210>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _945Loop0210 {
+    pub w12: W12,
+    pub g69: Vec<G69>,
+    pub n9: Vec<N9>,
+}
+
+/// 947 - Warehouse Inventory Adjustment Advice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Inventory Adjustment Advice Transaction Set (947) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by a warehouse to notify a depositor of adjustments to inventory.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W15 | Warehouse Adjustment Identification | M | 1
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0030 | N1 | Name | O | 1
+/// 0100 -> 0040 | N2 | Additional Name Information | O | 2
+/// 0100 -> 0050 | N3 | Address Information | O | 2
+/// 0100 -> 0060 | N4 | Geographic Location | O | 1
+/// 0100 -> 0070 | REF | Reference Identification | O | 2
+/// 0100 -> 0080 | PER | Administrative Communications Contact | O | 2
+/// 0090 | N9 | Reference Identification | O | 10
+/// 0100 | G62 | Date/Time | O | 5
+/// LOOP ID - 0200 | 9999
+/// 0200 -> 0010 | W19 | Inventory Adjustment Details | M | 1
+/// 0200 -> 0020 | G69 | Line Item Detail - Description | O | 5
+/// 0200 -> 0030 | N9 | Reference Identification | O | 10
+/// 0300 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _947 {
+    pub st: ST,
+    pub w15: W15,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_947Loop0100>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    #[x12(loop_trigger = "W19")]
+    pub loop_0200: Vec<_947Loop0200>,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _947Loop0100 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _947Loop0200 {
+    pub w19: W19,
+    pub g69: Vec<G69>,
+    pub n9: Vec<N9>,
+}
+
+/// 990 - Response to a Load Tender
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Response to a Load Tender Transaction Set (990) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to allow carriers to indicate whether a motor carrier will pick up a shipment offered by the shipper.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B1 | Beginning Segment for Booking or Pick-up/Delivery | M | 1
+/// 0030 | N9 | Reference Identification | O | 1
+/// 0040 | G62 | Date/Time | O | 1
+/// 0050 | K1 | Remarks | O | 10
+/// 0060 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _990 {
+    pub st: ST,
+    pub b1: B1,
+    pub n9: Option<N9>,
+    pub g62: Option<G62>,
+    pub k1: Vec<K1>,
+    pub se: SE,
+}
+
 /// 997 - Functional Acknowledgment
 ///
 /// This Draft Standard for Trial Use contains the format and establishes the data contents of the Functional Acknowledgment Transaction Set (997) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to define the control structures for a set of acknowledgments to indicate the results of the syntactical analysis of the electronically encoded documents. The encoded documents are the transaction sets, which are grouped in functional groups, used in defining transactions for business data interchange. This standard does not cover the semantic meaning of the information encoded in the transaction sets.
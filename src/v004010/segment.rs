@@ -3,6 +3,141 @@ use validator::Validate;
 use x12_types_macros::DisplaySegment;
 use x12_types_macros::ParseSegment;
 
+mod meta;
+pub use meta::segment_descriptor;
+
+/// ACK - Line Item Acknowledgment
+///
+/// To acknowledge the ordered quantities and specify the ready date for a specific line item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 668 | Line Item Status Code | 1 | M | ID | 2/2
+/// 02 | 380 | Quantity | 1 | X | R | 1/15
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 05 | 373 | Date | 1 | O | DT | 8/8
+/// 06 | 326 | Request Reference Number | 1 | O | AN | 1/45
+/// 07 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 08 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 09 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 10 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 11 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 12 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 13 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 14 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 15 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 16 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 17 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 18 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 19 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 20 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 21 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 22 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 23 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 24 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 25 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 26 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 27 | 559 | Agency Qualifier Code | 1 | O | ID | 2/2
+/// 28 | 822 | Source Subqualifier | 1 | O | AN | 1/15
+/// 29 | 1271 | Industry Code | 1 | X | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct ACK {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+    #[serde(rename = "26")]
+    pub _26: Option<String>,
+    #[serde(rename = "27")]
+    pub _27: Option<String>,
+    #[serde(rename = "28")]
+    pub _28: Option<String>,
+    #[serde(rename = "29")]
+    pub _29: Option<String>,
+}
+
+/// ADX - Adjustment
+///
+/// To convey accounts-payable adjustment information for the purpose of cash application, including payer-generated debit/credit memos
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 02 | 426 | Adjustment Reason Code | 1 | M | ID | 2/2
+/// 03 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 04 | 127 | Reference Identification | 1 | X | AN | 1/30
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct ADX {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
 /// AK1 - Functional Group Response Header
 ///
 /// To start acknowledgment of a functional group
@@ -88,6 +223,17 @@ pub struct AK4 {
 }
 
 /// AK5 - Transaction Set Response Trailer
+///
+/// To acknowledge acceptance or rejection and report errors in a transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 717 | Transaction Set Acknowledgment Code | 1 | M | ID | 1/1
+/// 02 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
+/// 03 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
+/// 04 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
+/// 05 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
+/// 06 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -148,6 +294,12 @@ pub struct AK9 {
 /// AMT - Monetary Amount Information
 ///
 /// To indicate the total monetary amount
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 522 | Amount Qualifier Code | 1 | M | ID | 1/3
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 478 | Credit/Debit Flag Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -262,6 +414,13 @@ pub struct AT8 {
 /// B1 - Beginning Segment for Booking or Pick-up/Delivery
 ///
 /// To transmit identifying number, data, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 02 | 145 | Shipment Identification Number | 1 | M | AN | 1/30
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 558 | Reservation Action Code | 1 | M | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -492,9 +651,57 @@ pub struct B10 {
     pub _07: Option<String>,
 }
 
+/// BAK - Beginning Segment for Purchase Order Acknowledgment
+///
+/// To indicate the beginning of the Purchase Order Acknowledgment Transaction Set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 587 | Acknowledgment Type | 1 | M | ID | 2/2
+/// 03 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 04 | 373 | Date | 1 | M | DT | 8/8
+/// 05 | 328 | Release Number | 1 | O | AN | 1/30
+/// 06 | 326 | Request Reference Number | 1 | O | AN | 1/45
+/// 07 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 08 | 127 | Reference Identification | 1 | O | AN | 1/30
+/// 09 | 373 | Date | 1 | O | DT | 8/8
+/// 10 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BAK {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
+
 /// BAL - Balance Detail
 ///
 /// To identify the specific monetary balances associated with a particular account
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 951 | Balance Type Code | 1 | M | ID | 1/2
+/// 02 | 522 | Amount Qualifier Code | 1 | M | ID | 1/3
+/// 03 | 782 | Monetary Amount | 1 | M | R | 1/18
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -507,9 +714,170 @@ pub struct BAL {
     pub _03: String,
 }
 
+/// BCH - Beginning Segment for Purchase Order Change
+///
+/// To indicate the beginning of the Purchase Order Change Transaction Set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 92 | Purchase Order Type Code | 1 | M | ID | 2/2
+/// 03 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 04 | 328 | Release Number | 1 | O | AN | 1/30
+/// 05 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
+/// 06 | 373 | Date | 1 | M | DT | 8/8
+/// 07 | 326 | Request Reference Number | 1 | O | AN | 1/45
+/// 08 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 09 | 373 | Date | 1 | O | DT | 8/8
+/// 10 | 373 | Date | 1 | O | DT | 8/8
+/// 11 | 373 | Date | 1 | O | DT | 8/8
+/// 12 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 13 | 1232 | Purchase Category | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BCH {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+}
+
+/// BEG - Beginning Segment for Purchase Order
+///
+/// To indicate the beginning of the Purchase Order Transaction Set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 92 | Purchase Order Type Code | 1 | M | ID | 2/2
+/// 03 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 04 | 328 | Release Number | 1 | O | AN | 1/30
+/// 05 | 373 | Date | 1 | M | DT | 8/8
+/// 06 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 07 | 587 | Acknowledgment Type | 1 | O | ID | 2/2
+/// 08 | 1019 | Invoice Type Code | 1 | O | ID | 3/3
+/// 09 | 1166 | Contract Type Code | 1 | O | ID | 2/2
+/// 10 | 1232 | Purchase Category | 1 | O | ID | 2/2
+/// 11 | 786 | Security Level Code | 1 | O | ID | 2/2
+/// 12 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BEG {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+}
+
+/// BGN - Beginning Segment
+///
+/// To indicate the beginning of a transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 337 | Time | 1 | X | TM | 4/8
+/// 05 | 623 | Time Code | 1 | O | ID | 2/2
+/// 06 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 07 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 08 | 306 | Action Code | 1 | O | ID | 1/2
+/// 09 | 786 | Security Level Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct BGN {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
 /// BIG - Beginning Segment for Invoice
 ///
 /// To indicate the beginning of an invoice transaction set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 373 | Date | 1 | M | DT | 8/8
+/// 02 | 76 | Invoice Number | 1 | M | AN | 1/22
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+/// 05 | 328 | Release Number | 1 | O | AN | 1/30
+/// 06 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
+/// 07 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 08 | 353 | Transaction Set Purpose Code | 1 | O | ID | 2/2
+/// 09 | 306 | Action Code | 1 | O | ID | 1/2
+/// 10 | 76 | Invoice Number | 1 | O | AN | 1/22
+/// 11 | 1019 | Invoice Type Code | 1 | O | ID | 1/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -667,9 +1035,126 @@ pub struct BNX {
     pub _04: Option<String>,
 }
 
-/// BX - General Shipment Information
+/// BPR - Beginning Segment for Payment Order/Remittance Advice
 ///
-/// To transmit identification numbers and other basic shipment data
+/// To indicate the beginning of a Payment Order/Remittance Advice Transaction Set and total payment amount, or to enable related transfer of funds and/or information from payer to payee to occur
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 305 | Transaction Handling Code | 1 | M | ID | 1/2
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 478 | Credit/Debit Flag Code | 1 | M | ID | 1/1
+/// 04 | 591 | Payment Method Code | 1 | M | ID | 3/3
+/// 05 | 812 | Payment Format Code | 1 | O | ID | 1/10
+/// 06 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 07 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 08 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 09 | 508 | Account Number | 1 | X | AN | 1/35
+/// 10 | 509 | Originating Company Identifier | 1 | O | AN | 10/10
+/// 11 | 510 | Originating Company Supplemental Code | 1 | O | AN | 9/9
+/// 12 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 13 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 14 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 15 | 508 | Account Number | 1 | X | AN | 1/35
+/// 16 | 373 | Date | 1 | O | DT | 8/8
+/// 17 | 1048 | Business Function Code | 1 | O | ID | 1/3
+/// 18 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 19 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 20 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 21 | 508 | Account Number | 1 | X | AN | 1/35
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct BPR {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+}
+
+/// BSN - Beginning Segment for Ship Notice
+///
+/// To transmit identifying numbers, dates, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 396 | Shipment Identification | 1 | M | AN | 2/30
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 337 | Time | 1 | M | TM | 4/8
+/// 05 | 1005 | Hierarchical Structure Code | 1 | O | ID | 4/4
+/// 06 | 640 | Transaction Type Code | 1 | X | ID | 2/2
+/// 07 | 641 | Status Reason Code | 1 | O | ID | 3/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BSN {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// BX - General Shipment Information
+///
+/// To transmit identification numbers and other basic shipment data
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
 /// ----|----|------|--------|----|------|-------
@@ -826,6 +1311,18 @@ pub struct C8C {
 /// CAD - Carrier Details
 ///
 /// To specify transportation details for the transaction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 02 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 03 | 207 | Equipment Number | 1 | O | AN | 1/10
+/// 04 | 140 | Standard Carrier Alpha Code | 1 | X | ID | 2/4
+/// 05 | 387 | Routing | 1 | X | AN | 1/35
+/// 06 | 368 | Shipment/Order Status Code | 1 | O | ID | 2/2
+/// 07 | 128 | Reference Identification Qualifier | 1 | O | ID | 2/3
+/// 08 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 09 | 284 | Service Level Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -910,6 +1407,33 @@ pub struct CD3 {
     pub _14: Option<String>,
 }
 
+/// CLD - Load Detail
+///
+/// To specify the number of material loads shipped
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 622 | Number of Loads | 1 | M | N0 | 1/5
+/// 02 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 03 | 103 | Packaging Code | 1 | O | AN | 3/5
+/// 04 | 357 | Size | 1 | O | R | 1/8
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CLD {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
 /// CM - Cargo Manifest
 ///
 /// To identify specific flight or voyage information for multimodal shipments
@@ -994,6 +1518,16 @@ pub struct CM {
 /// CRC - Conditions Indicator
 ///
 /// To supply information on conditions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1136 | Code Category | 1 | M | ID | 2/2
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 03 | 1321 | Condition Indicator | 1 | M | ID | 2/3
+/// 04 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 05 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 06 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 07 | 1321 | Condition Indicator | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1017,6 +1551,20 @@ pub struct CRC {
 /// CTP - Pricing Information
 ///
 /// To specify pricing information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 687 | Class of Trade Code | 1 | O | ID | 2/2
+/// 02 | 236 | Price Identifier Code | 1 | X | ID | 3/3
+/// 03 | 212 | Unit Price | 1 | X | R | 1/17
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 648 | Price Multiplier Qualifier | 1 | X | ID | 3/3
+/// 07 | 649 | Multiplier | 1 | X | R | 1/10
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 10 | 499 | Condition Value | 1 | O | AN | 1/10
+/// 11 | 289 | Multiple Price Quantity | 1 | O | N0 | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1048,6 +1596,16 @@ pub struct CTP {
 /// CTT - Transaction Totals
 ///
 /// To transmit a hash total for a specific element in the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 354 | Number of Line Items | 1 | M | N0 | 1/6
+/// 02 | 347 | Hash Total | 1 | O | R | 1/10
+/// 03 | 81 | Weight | 1 | X | R | 1/10
+/// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 05 | 183 | Volume | 1 | X | R | 1/8
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1071,6 +1629,30 @@ pub struct CTT {
 /// CUR - Currency
 ///
 /// To specify the currency (dollars, pounds, francs, etc.) used in a transaction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 100 | Currency Code | 1 | M | ID | 3/3
+/// 03 | 280 | Exchange Rate | 1 | O | R | 4/10
+/// 04 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 05 | 100 | Currency Code | 1 | X | ID | 3/3
+/// 06 | 669 | Currency Market/Exchange Code | 1 | O | ID | 3/3
+/// 07 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 08 | 373 | Date | 1 | O | DT | 8/8
+/// 09 | 337 | Time | 1 | O | TM | 4/8
+/// 10 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 11 | 373 | Date | 1 | X | DT | 8/8
+/// 12 | 337 | Time | 1 | X | TM | 4/8
+/// 13 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 14 | 373 | Date | 1 | X | DT | 8/8
+/// 15 | 337 | Time | 1 | X | TM | 4/8
+/// 16 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 17 | 373 | Date | 1 | X | DT | 8/8
+/// 18 | 337 | Time | 1 | X | TM | 4/8
+/// 19 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 20 | 373 | Date | 1 | X | DT | 8/8
+/// 21 | 337 | Time | 1 | X | TM | 4/8
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1194,6 +1776,18 @@ pub struct D9 {
 /// DMG - Demographic Information
 ///
 /// To supply demographic information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 02 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 03 | 1068 | Gender Code | 1 | O | ID | 1/1
+/// 04 | 1067 | Marital Status Code | 1 | O | ID | 1/1
+/// 05 | 1109 | Race or Ethnicity Code | 1 | O | ID | 1/1
+/// 06 | 1066 | Citizenship Status Code | 1 | O | ID | 1/2
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 659 | Basis of Verification Code | 1 | O | ID | 1/2
+/// 09 | 380 | Quantity | 1 | O | R | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1361,6 +1955,12 @@ pub struct E5 {
 /// EA - Equipment Attributes
 ///
 /// To specify attributes required for a piece of equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1092 | Attribute Code | 1 | M | ID | 1/2
+/// 02 | 1093 | Attribute Value | 1 | O | AN | 1/30
+/// 03 | 1094 | Attribute Description | 1 | O | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1478,6 +2078,54 @@ pub struct EM {
     pub _07: Option<String>,
 }
 
+/// ENT - Entity
+///
+/// To designate the entities which are parties to a transaction and specify a reference meaningful to those entities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | O | N0 | 1/6
+/// 02 | 98 | Entity Identifier Code | 1 | X | ID | 2/3
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 98 | Entity Identifier Code | 1 | X | ID | 2/3
+/// 06 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 07 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 08 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 09 | 127 | Reference Identification | 1 | X | AN | 1/30
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct ENT {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
 /// F9 - Origin Station
 ///
 /// To identify the rail origin of the shipment
@@ -1553,6 +2201,12 @@ pub struct F9 {
 /// FA1 - Type of Financial Accounting Data
 ///
 /// To specify the organization controlling the content of the accounting citation, and the purpose associated with the accounting citation
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 559 | Agency Qualifier Code | 1 | M | ID | 2/2
+/// 02 | 1301 | Service, Promotion, Allowance, or Charge Code | 1 | O | ID | 4/4
+/// 03 | 1212 | Allowance or Charge Indicator Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1568,6 +2222,11 @@ pub struct FA1 {
 /// FA2 - Accounting Data
 ///
 /// To specify the detailed accounting data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1196 | Breakdown Structure Detail Code | 1 | M | ID | 2/2
+/// 02 | 1195 | Financial Information Code | 1 | M | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1581,6 +2240,18 @@ pub struct FA2 {
 /// FOB - F.O.B. Related Instructions
 ///
 /// To specify transportation instructions relating to shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 146 | Shipment Method of Payment | 1 | M | ID | 2/2
+/// 02 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 03 | 352 | Description | 1 | O | AN | 1/80
+/// 04 | 334 | Transportation Terms Qualifier Code | 1 | O | ID | 2/2
+/// 05 | 335 | Transportation Terms Code | 1 | X | ID | 3/3
+/// 06 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+/// 08 | 54 | Risk of Loss Code | 1 | O | ID | 2/2
+/// 09 | 352 | Description | 1 | X | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1707,6 +2378,21 @@ pub struct G62 {
     pub _05: Option<String>,
 }
 
+/// G69 - Line Item Detail - Description
+///
+/// To describe an item in free-form format
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 352 | Description | 1 | M | AN | 1/45
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct G69 {
+    #[serde(rename = "01")]
+    pub _01: String,
+}
+
 /// GA - Canadian Grain Information
 ///
 /// To transmit the transportation and distribution requirements of grain at Canadian ports
@@ -1965,6 +2651,30 @@ pub struct H3 {
     pub _05: Option<String>,
 }
 
+/// HL - Hierarchical Level
+///
+/// To identify dependencies among and the content of hierarchically related groups of data segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 628 | Hierarchical ID Number | 1 | M | AN | 1/12
+/// 02 | 734 | Hierarchical Parent ID Number | 1 | O | AN | 1/12
+/// 03 | 735 | Hierarchical Level Code | 1 | M | ID | 1/2
+/// 04 | 736 | Hierarchical Child Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct HL {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
 /// IC - Intermodal Chassis Equipment
 ///
 /// To specify the chassis equipment details in terms of identifying numbers, weights, and ownership
@@ -2025,6 +2735,15 @@ pub struct IM {
 /// INC - Installment Information
 ///
 /// To specify installment billing arrangement
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 336 | Terms Type Code | 1 | M | ID | 2/2
+/// 02 | C001 | Composite Unit of Measure | 1 | M |  |
+/// 03 | 380 | Quantity | 1 | M | R | 1/15
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 06 | 107 | Payment Method Code | 1 | O | ID | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2245,6 +2964,17 @@ pub struct ISA {
 /// ISS - Invoice Shipment Summary
 ///
 /// To specify summary details of total items shipped in terms of quantity, weight, and volume
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | X | R | 1/10
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 03 | 81 | Weight | 1 | X | R | 1/10
+/// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 05 | 183 | Volume | 1 | X | R | 1/8
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 380 | Quantity | 1 | X | R | 1/15
+/// 08 | 81 | Weight | 1 | O | R | 1/10
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2270,6 +3000,34 @@ pub struct ISS {
 /// IT1 - Baseline Item Data (Invoice)
 ///
 /// To specify the basic and most frequently used line item data for the invoice and related transactions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 358 | Quantity Invoiced | 1 | X | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 212 | Unit Price | 1 | X | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2304,6 +3062,14 @@ pub struct IT1 {
 /// IT3 - Additional Item Data
 ///
 /// To specify additional item details relating to variations between ordered and shipped quantities, or to specify alternate units of measures and quantities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | X | R | 1/10
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 03 | 368 | Shipment/Order Status Code | 1 | X | ID | 2/2
+/// 04 | 383 | Quantity Difference | 1 | O | R | 1/9
+/// 05 | 371 | Change Reason Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2323,6 +3089,24 @@ pub struct IT3 {
 /// ITD - Terms of Sale/Deferred Terms of Sale
 ///
 /// To specify terms of sale
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 336 | Terms Type Code | 1 | O | ID | 2/2
+/// 02 | 333 | Terms Basis Date Code | 1 | O | ID | 1/2
+/// 03 | 338 | Terms Discount Percent | 1 | O | R | 1/6
+/// 04 | 370 | Terms Discount Due Date | 1 | X | DT | 8/8
+/// 05 | 351 | Terms Discount Days Due | 1 | X | N0 | 1/3
+/// 06 | 446 | Terms Net Due Date | 1 | O | DT | 8/8
+/// 07 | 386 | Terms Net Days | 1 | O | N0 | 1/3
+/// 08 | 362 | Terms Discount Amount | 1 | O | N2 | 1/10
+/// 09 | 388 | Terms Deferred Due Date | 1 | O | DT | 8/8
+/// 10 | 389 | Deferred Amount Due | 1 | X | N2 | 1/10
+/// 11 | 342 | Percent of Invoice Payable | 1 | X | R | 1/5
+/// 12 | 352 | Description | 1 | O | AN | 1/80
+/// 13 | 765 | Day of Month | 1 | X | N0 | 1/2
+/// 14 | 107 | Payment Method Code | 1 | O | ID | 1/2
+/// 15 | 954 | Percent | 1 | O | R | 1/10
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2569,6 +3353,15 @@ pub struct L3 {
 /// L4 - Measurement
 ///
 /// To describe physical ddimensions and quantities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 82 | Length | 1 | M | R | 1/8
+/// 02 | 189 | Width | 1 | M | R | 1/8
+/// 03 | 65 | Height | 1 | M | R | 1/8
+/// 04 | 90 | Measurement Unit Qualifier | 1 | M | ID | 1/1
+/// 05 | 380 | Quantity | 1 | O | R | 1/15
+/// 06 | 271 | Industry Code | 1 | O | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -3077,31 +3870,146 @@ pub struct LHT {
     pub _03: Option<String>,
 }
 
-/// LM - Code Source Information
+/// LIN - Item Identification
 ///
-/// To transmit standard code list identification information
-#[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
-)]
-pub struct LM {
-    #[serde(rename = "01")]
-    pub _01: String,
-    #[serde(rename = "02")]
-    pub _02: Option<String>,
-}
-
-/// LQ - Industry Code
+/// To specify basic item identification data
 ///
-/// Code to transmit standard industry codes
-#[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
-)]
-pub struct LQ {
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 235 | Product/Service ID Qualifier | 1 | M | ID | 2/2
+/// 03 | 234 | Product/Service ID | 1 | M | AN | 1/48
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 26 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 27 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 28 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 29 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 30 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 31 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LIN {
     #[serde(rename = "01")]
     pub _01: Option<String>,
     #[serde(rename = "02")]
-    pub _02: Option<String>,
-}
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+    #[serde(rename = "26")]
+    pub _26: Option<String>,
+    #[serde(rename = "27")]
+    pub _27: Option<String>,
+    #[serde(rename = "28")]
+    pub _28: Option<String>,
+    #[serde(rename = "29")]
+    pub _29: Option<String>,
+    #[serde(rename = "30")]
+    pub _30: Option<String>,
+    #[serde(rename = "31")]
+    pub _31: Option<String>,
+}
+
+/// LM - Code Source Information
+///
+/// To transmit standard code list identification information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 559 | Agency Qualifier Code | 1 | M | ID | 2/2
+/// 02 | 822 | Source Subqualifier | 1 | O | AN | 1/15
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LM {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
+
+/// LQ - Industry Code
+///
+/// Code to transmit standard industry codes
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 02 | 1271 | Industry Code | 1 | X | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LQ {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
 
 /// LS - Loop Header
 ///
@@ -3606,6 +4514,12 @@ pub struct MS3 {
 /// MSG - Message Text
 ///
 /// To provide a free-form format that allows the transmission of text information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 933 | Free-Form Message Text | 1 | M | AN | 1/264
+/// 02 | 934 | Printer Carriage Control Code | 1 | X | ID | 2/2
+/// 03 | 1470 | Number | 1 | O | N0 | 1/9
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -4121,6 +5035,78 @@ pub struct OID {
     pub _09: Option<String>,
 }
 
+/// OTI - Original Transaction Identification
+///
+/// To identify the edited transaction set and the level at which the results of the edit are reported, and to indicate the accepted, rejected, or accepted-with-change edit result
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 110 | Application Acknowledgment Code | 1 | M | ID | 1/2
+/// 02 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 03 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 04 | 142 | Application Sender's Code | 1 | O | AN | 2/15
+/// 05 | 124 | Application Receiver's Code | 1 | O | AN | 2/15
+/// 06 | 373 | Date | 1 | O | DT | 8/8
+/// 07 | 337 | Time | 1 | O | TM | 4/8
+/// 08 | 28 | Group Control Number | 1 | X | N0 | 1/9
+/// 09 | 329 | Transaction Set Control Number | 1 | O | AN | 4/9
+/// 10 | 143 | Transaction Set Identifier Code | 1 | O | ID | 3/3
+/// 11 | 480 | Version / Release / Industry Identifier Code | 1 | O | AN | 1/12
+/// 12 | 353 | Transaction Set Purpose Code | 1 | O | ID | 2/2
+/// 13 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 14 | 346 | Application Type | 1 | O | ID | 2/2
+/// 15 | 306 | Action Code | 1 | O | ID | 1/2
+/// 16 | 305 | Transaction Handling Code | 1 | O | ID | 1/2
+/// 17 | 633 | Purpose Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct OTI {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+}
+
 /// P4 - U.S. Port Information
 ///
 /// To transmit identifying information for a U.S. port
@@ -4167,6 +5153,24 @@ pub struct P5 {
 /// PAM - Period Amount
 ///
 /// To indicate a quantity, and/or amount for an identified period
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 673 | Quantity Qualifier | 1 | X | ID | 2/2
+/// 02 | 380 | Quantity | 1 | X | R | 1/15
+/// 03 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 04 | 344 | Unit of Time Period or Interval | 1 | X | ID | 2/2
+/// 05 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 06 | 373 | Date | 1 | X | DT | 8/8
+/// 07 | 337 | Time | 1 | O | TM | 4/8
+/// 08 | 373 | Date | 1 | X | DT | 8/8
+/// 09 | 337 | Time | 1 | O | TM | 4/8
+/// 10 | 522 | Amount Qualifier Code | 1 | X | ID | 1/3
+/// 11 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 12 | 344 | Unit of Time Period or Interval | 1 | X | ID | 2/2
+/// 13 | 1345 | Percent Qualifier | 1 | X | ID | 1/2
+/// 14 | 954 | Percent | 1 | X | R | 1/10
+/// 15 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -4296,6 +5300,18 @@ pub struct PI {
 /// PID - Product/Item Description
 ///
 /// To describe a product or process in coded or free-form format
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 349 | Item Description Type | 1 | M | ID | 1/1
+/// 02 | 750 | Product/Process Characteristic Code | 1 | O | ID | 2/3
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 751 | Product Description Code | 1 | X | AN | 1/12
+/// 05 | 352 | Description | 1 | X | AN | 1/80
+/// 06 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 07 | 822 | Source Subqualifier | 1 | O | AN | 1/15
+/// 08 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 09 | 819 | Language Code | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -4325,6 +5341,15 @@ pub struct PID {
 /// PKG - Marking, Packaging, Loading
 ///
 /// To describe marking, packaging, loading, and unloading requirements
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 349 | Item Description Type | 1 | X | ID | 1/1
+/// 02 | 753 | Packaging Characteristic Code | 1 | O | ID | 1/5
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 754 | Packaging Description Code | 1 | X | AN | 1/7
+/// 05 | 352 | Description | 1 | X | AN | 1/80
+/// 06 | 400 | Unit Load Option Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -4369,13 +5394,41 @@ pub struct PLD {
     pub _04: Option<String>,
 }
 
-/// PO4 Item Physical Details
+/// PO1 - Baseline Item Data
 ///
-/// To specify the physical qualities, packaging, weights, and dimensions relating to the item
-#[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
-)]
-pub struct PO4 {
+/// To specify basic and most frequently used line item data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 330 | Quantity Ordered | 1 | X | R | 1/15
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 04 | 212 | Unit Price | 1 | X | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PO1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
     #[serde(rename = "02")]
@@ -4412,88 +5465,318 @@ pub struct PO4 {
     pub _17: Option<String>,
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
 }
 
-/// PRF - Purchase Order Reference
+/// PO3 - Additional Item Detail
 ///
-/// To provide reference to a specific purchase order
+/// To specify additional item-related data involving variations in normal price/quantity structure
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 324 | Purchase Order Number | 1 | M | AN | 1/22
-/// 02 | 328 | Release Number | 1 | O | AN | 1/30
-/// 03 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
-/// 04 | 373 | Date | 1 | O/Z | DT | 8/8
-/// 05 | 350 | Assigned Identification | 1 | O | AN | 1/20
-/// 06 | 367 | Contract Number | 1 | O | AN | 1/30
-/// 07 | 92 | Purchase Order Type Code | 1 | O | ID | 2/2
+/// ----|----|------|--------|----|------|-------
+/// 01 | 371 | Change Reason Code | 1 | M | ID | 2/2
+/// 02 | 373 | Date | 1 | O | DT | 8/8
+/// 03 | 236 | Price Identifier Code | 1 | X | ID | 3/3
+/// 04 | 212 | Unit Price | 1 | O | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | X | ID | 2/2
+/// 06 | 380 | Quantity | 1 | M | R | 1/15
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 08 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct PRF {
+pub struct PO3 {
+    #[serde(rename = "01")]
     pub _01: String,
+    #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[serde(rename = "03")]
     pub _03: Option<String>,
-    /// 373 - Date
-    ///
-    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
-    /// - TYPE=DT
-    /// - MIN=8
-    /// - MAX=8
+    #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[serde(rename = "05")]
     pub _05: Option<String>,
-    pub _06: Option<String>,
-    pub _07: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: String,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
 }
 
-/// PS - Protective Service Instructions
+/// PO4 Item Physical Details
 ///
-/// To specify mechanical protective service and ventilation instructions
+/// To specify the physical qualities, packaging, weights, and dimensions relating to the item
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 746 | Protective Service Rule Code | 1 | M | ID | 3/9
-/// 02 | 241 | Protective Service Code | 1 | M | ID | 1/4
-/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X/Z | ID | 2/2
-/// 04 | 408 | Temperature | 1 | X/Z | R | 1/4
-/// 05 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
-/// 06 | 573 | Freight Station Accounting Code | 1 | O | ID | 1/5
-/// 07 | 19 | City Name | 1 | O | AN | 2/30
-/// 08 | 156 | State or Province Code | 1 | O | ID | 2/2
-/// 09 | 81 | Weight | 1 | O | R | 1/10
-/// 10 | 745 | Pre-Cooled (Rule 710) Code | 1 | O | ID | 1/1
-/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
-/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
-/// 13 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
-/// 14 | 408 | Temperature | 1 | X/Z | R | 1/4
+/// ----|----|------|--------|----|------|-------
+/// 01 | 356 | Pack | 1 | O | N0 | 1/6
+/// 02 | 357 | Size | 1 | X | R | 1/8
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 103 | Packaging Code | 1 | X | AN | 3/5
+/// 05 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 06 | 384 | Gross Weight per Pack | 1 | X | R | 1/9
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 08 | 385 | Gross Volume per Pack | 1 | X | R | 1/9
+/// 09 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 10 | 82 | Length | 1 | X | R | 1/8
+/// 11 | 189 | Width | 1 | X | R | 1/8
+/// 12 | 65 | Height | 1 | X | R | 1/8
+/// 13 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 14 | 810 | Inner Pack | 1 | O | N0 | 1/6
+/// 15 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 16 | 350 | Assigned Identification | 1 | X | AN | 1/20
+/// 17 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 18 | 1470 | Number | 1 | O | N0 | 1/9
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct PS {
-    pub _01: String,
-    pub _02: String,
+pub struct PO4 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[serde(rename = "06")]
     pub _06: Option<String>,
-    /// 19 - City Name
-    ///
-    /// Free-form text for city name
-    /// - TYPE=AN
-    /// - MIN=2
-    /// - MAX=30
+    #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[serde(rename = "14")]
     pub _14: Option<String>,
-}
-
-/// PWK - Paperwork
-///
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+}
+
+/// POC - Line Item Change
+///
+/// To specify changes to a line item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 670 | Change or Response Type Code | 1 | M | ID | 2/2
+/// 03 | 330 | Quantity Ordered | 1 | X | R | 1/15
+/// 04 | 671 | Quantity Left to Receive | 1 | X | R | 1/9
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 212 | Unit Price | 1 | X | R | 1/17
+/// 07 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 26 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 27 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct POC {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+    #[serde(rename = "26")]
+    pub _26: Option<String>,
+    #[serde(rename = "27")]
+    pub _27: Option<String>,
+}
+
+/// POD - Proof of Delivery
+///
+/// To supply proof of delivery information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 373 | Date | 1 | M | DT | 8/8
+/// 02 | 337 | Time | 1 | O | TM | 4/8
+/// 03 | 93 | Name | 1 | M | AN | 1/60
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct POD {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: String,
+}
+
+/// PRF - Purchase Order Reference
+///
+/// To provide reference to a specific purchase order
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 02 | 328 | Release Number | 1 | O | AN | 1/30
+/// 03 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
+/// 04 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 05 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 06 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 07 | 92 | Purchase Order Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PRF {
+    pub _01: String,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+}
+
+/// PS - Protective Service Instructions
+///
+/// To specify mechanical protective service and ventilation instructions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 746 | Protective Service Rule Code | 1 | M | ID | 3/9
+/// 02 | 241 | Protective Service Code | 1 | M | ID | 1/4
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X/Z | ID | 2/2
+/// 04 | 408 | Temperature | 1 | X/Z | R | 1/4
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 06 | 573 | Freight Station Accounting Code | 1 | O | ID | 1/5
+/// 07 | 19 | City Name | 1 | O | AN | 2/30
+/// 08 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 09 | 81 | Weight | 1 | O | R | 1/10
+/// 10 | 745 | Pre-Cooled (Rule 710) Code | 1 | O | ID | 1/1
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 13 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 14 | 408 | Temperature | 1 | X/Z | R | 1/4
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PS {
+    pub _01: String,
+    pub _02: String,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    /// 19 - City Name
+    ///
+    /// Free-form text for city name
+    /// - TYPE=AN
+    /// - MIN=2
+    /// - MAX=30
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+    pub _10: Option<String>,
+    pub _11: Option<String>,
+    pub _12: Option<String>,
+    pub _13: Option<String>,
+    pub _14: Option<String>,
+}
+
+/// PWK - Paperwork
+///
 /// To identify the type or transmission or both of paperwork or supporting information
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
@@ -4817,6 +6100,45 @@ pub struct R2A {
     pub _10: Option<String>,
 }
 
+/// R3 - Route Information - Motor
+///
+/// To specify carrier and routing sequences and details
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 140 | Standard Carrier Alpha Code | 1 | M | ID | 2/4
+/// 02 | 133 | Routing Sequence Code | 1 | M | ID | 1/2
+/// 03 | 19 | City Name | 1 | X | AN | 2/30
+/// 04 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 05 | 154 | Standard Point Location Code | 1 | X | ID | 6/9
+/// 06 | 76 | Invoice Number | 1 | O | AN | 1/22
+/// 07 | 373 | Date | 1 | O | DT | 8/8
+/// 08 | 1 | Amount | 1 | O | N2 | 1/15
+/// 09 | 352 | Description | 1 | O | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct R3 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
 /// R4 - Port or Terminal
 ///
 /// Contractual or operational port or point relevant to the movement of the cargo
@@ -4894,6 +6216,36 @@ pub struct R9 {
     pub _08: Option<String>,
 }
 
+/// RED - Related Data
+///
+/// To relate data that is associated with a previously defined error condition
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 352 | Description | 1 | M | AN | 1/80
+/// 02 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 03 | 1271 | Industry Code | 1 | X | AN | 1/30
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct RED {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
 /// REF - Reference Identification
 ///
 /// To specify identifying information
@@ -4918,6 +6270,51 @@ pub struct REF {
     pub _04: Option<String>,
 }
 
+/// RMR - Remittance Advice Accounts Receivable Open Item Reference
+///
+/// To specify the accounts receivable open item(s) to be included in the cash application and to convey the appropriate detail
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 03 | 482 | Payment Action Code | 1 | O | ID | 2/2
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 06 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 07 | 426 | Adjustment Reason Code | 1 | O | ID | 2/2
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct RMR {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
 /// S1 - Stop-off Name
 ///
 /// To identify a stop-off party
@@ -5046,7 +6443,26 @@ pub struct S9 {
 /// SAC - Service, Promotion, Allowance, or Charge Information
 ///
 /// To request or identify a service, promotion, allowance, or charge; to specify the amount or percentage for the service, promotion, allowance, or charge
-#[derive(
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 248 | Allowance or Charge Indicator | 1 | M | ID | 1/1
+/// 02 | 1300 | Service, Promotion, Allowance, or Charge Code | 1 | X | ID | 4/4
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 1301 | Agency Service, Promotion, Allowance, or Charge Code | 1 | X | AN | 1/10
+/// 05 | 610 | Amount | 1 | O | N2 | 1/15
+/// 06 | 378 | Allowance/Charge Percent Qualifier | 1 | X | ID | 1/1
+/// 07 | 332 | Percent | 1 | X | R | 1/6
+/// 08 | 118 | Rate | 1 | O | R | 1/9
+/// 09 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 10 | 380 | Quantity | 1 | X | R | 1/15
+/// 11 | 380 | Quantity | 1 | O | R | 1/15
+/// 12 | 331 | Allowance or Charge Method of Handling Code | 1 | O | ID | 2/2
+/// 13 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 14 | 770 | Option Number | 1 | O | AN | 1/20
+/// 15 | 352 | Description | 1 | X | AN | 1/80
+/// 16 | 819 | Language Code | 1 | O | ID | 2/3
+#[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
 pub struct SAC {
@@ -5084,9 +6500,88 @@ pub struct SAC {
     pub _16: Option<String>,
 }
 
+/// SCH - Line Item Schedule
+///
+/// To specify the data for scheduling a specific line-item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | M | R | 1/15
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 04 | 93 | Name | 1 | X | AN | 1/60
+/// 05 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 06 | 373 | Date | 1 | M | DT | 8/8
+/// 07 | 337 | Time | 1 | X | TM | 4/8
+/// 08 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 09 | 373 | Date | 1 | X | DT | 8/8
+/// 10 | 337 | Time | 1 | X | TM | 4/8
+/// 11 | 326 | Request Reference Number | 1 | O | AN | 1/45
+/// 12 | 350 | Assigned Identification | 1 | O | AN | 1/20
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SCH {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+}
+
 /// SLN - Subline Item Detail
 ///
 /// To specify product subline detail item data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | M | AN | 1/20
+/// 02 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 03 | 662 | Relationship Code | 1 | M | ID | 1/1
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 212 | Unit Price | 1 | X | R | 1/17
+/// 07 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 08 | 662 | Relationship Code | 1 | O | ID | 1/1
+/// 09 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 10 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 11 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 12 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 13 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 14 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 15 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 16 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 17 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 18 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 19 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 20 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 21 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 22 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 23 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 24 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 25 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 26 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 27 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 28 | 234 | Product/Service ID | 1 | X | AN | 1/48
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -5285,6 +6780,42 @@ pub struct SG {
     pub _06: Option<String>,
 }
 
+/// SN1 - Item Detail (Shipment)
+///
+/// To specify line-item detail relative to shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 04 | 646 | Quantity Shipped to Date | 1 | O | R | 1/15
+/// 05 | 330 | Quantity Ordered | 1 | X | R | 1/15
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 728 | Returnable Container Load Make-Up Code | 1 | O | ID | 1/2
+/// 08 | 668 | Line Item Status Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SN1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
 /// SPO - Shipment Purchase Order Detail
 ///
 /// To specify the purchase order details for a shipment
@@ -5588,6 +7119,11 @@ pub struct T8 {
 /// TC2 - Commodity
 ///
 /// To identify a commodity or a group of commodities or a tariff page commodity
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 23 | Commodity Code Qualifier | 1 | M | ID | 1/1
+/// 02 | 22 | Commodity Code | 1 | M | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -5598,32 +7134,28 @@ pub struct TC2 {
     pub _02: String,
 }
 
-/// TDS - Total Monetary Value Summary
+/// TD1 - Carrier Details (Quantity and Weight)
 ///
-/// To specify the total invoice discounts and amounts
-#[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
-)]
-pub struct TDS {
-    #[serde(rename = "01")]
-    pub _01: String,
-    #[serde(rename = "02")]
-    pub _02: Option<String>,
-    #[serde(rename = "03")]
-    pub _03: Option<String>,
-    #[serde(rename = "04")]
-    pub _04: Option<String>,
-}
-
-/// TXI - Tax Information
+/// To specify the transportation details relative to commodity, weight, and quantity
 ///
-/// To specify tax information
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 103 | Packaging Code | 1 | O | AN | 3/5
+/// 02 | 80 | Lading Quantity | 1 | X | N0 | 1/7
+/// 03 | 23 | Commodity Code Qualifier | 1 | O | ID | 1/1
+/// 04 | 22 | Commodity Code | 1 | X | AN | 1/30
+/// 05 | 79 | Lading Description | 1 | O | AN | 1/50
+/// 06 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 07 | 81 | Weight | 1 | X | R | 1/10
+/// 08 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 09 | 183 | Volume | 1 | X | R | 1/8
+/// 10 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct TXI {
+pub struct TD1 {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: Option<String>,
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
@@ -5642,43 +7174,30 @@ pub struct TXI {
     pub _09: Option<String>,
     #[serde(rename = "10")]
     pub _10: Option<String>,
-    #[serde(rename = "11")]
-    pub _11: Option<String>,
-    #[serde(rename = "12")]
-    pub _12: Option<String>,
-    #[serde(rename = "13")]
-    pub _13: Option<String>,
 }
 
-/// V1 - Vessel Identification
+/// TD3 - Carrier Details (Equipment)
 ///
-/// To provide vessel details and voyage number
+/// To specify transportation details relating to the equipment used by the carrier
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 597 | Vessel Code | 1 | X | ID | 1/8
-/// 02 | 182 | Vessel Name | 1 | X | AN | 2/28
-/// 03 | 26 | Country Code | 1 | O/Z | ID | 2/3
-/// 04 | 55 | Flight/Voyage Number | 1 | O | AN | 2/10
-/// 05 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
-/// 06 | 249 | Vessel Requirement Code | 1 | O | ID | 1/1
-/// 07 | 854 | Vessel Type Code | 1 | O | ID | 2/2
-/// 08 | 897 | Vessel Code Qualifier | 1 | O | ID | 1/1
-/// 09 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// ----|----|------|--------|----|------|-------
+/// 01 | 40 | Equipment Description Code | 1 | X | ID | 2/2
+/// 02 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 03 | 207 | Equipment Number | 1 | X | AN | 1/10
+/// 04 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 05 | 81 | Weight | 1 | X | R | 1/10
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 225 | Seal Number | 1 | O | AN | 2/15
+/// 08 | 24 | Equipment Type | 1 | X | ID | 4/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct V1 {
+pub struct TD3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
     #[serde(rename = "02")]
     pub _02: Option<String>,
-    /// 26 - Country Code
-    ///
-    /// Code identifying the country
-    /// - TYPE=ID
-    /// - MIN=2
-    /// - MAX=3
     #[serde(rename = "03")]
     pub _03: Option<String>,
     #[serde(rename = "04")]
@@ -5691,91 +7210,72 @@ pub struct V1 {
     pub _07: Option<String>,
     #[serde(rename = "08")]
     pub _08: Option<String>,
-    #[serde(rename = "09")]
-    pub _09: Option<String>,
 }
 
-/// V4 - Cargo Location Reference
+/// TD4 - Carrier Details (Special Handling, or Hazardous Materials, or Both)
 ///
-/// To specify the cargo location on board the vessel
+/// To specify transportation special handling requirements, or hazardous materials information, or both
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 877 | Vessel Stowage Location | 1 | M | AN | 1/12
+/// ----|----|------|--------|----|------|-------
+/// 01 | 152 | Special Handling Code | 1 | X | ID | 2/3
+/// 02 | 208 | Hazardous Material Code Qualifier | 1 | X | ID | 1/1
+/// 03 | 209 | Hazardous Material Class Code | 1 | X | AN | 1/4
+/// 04 | 352 | Description | 1 | X | AN | 1/80
+/// 05 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct V4 {
+pub struct TD4 {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
 }
 
-/// V9 - Event Detail
+/// TD5 - Carrier Details (Routing Sequence/Transit Time)
 ///
-/// To specify information about a specific event
+/// To specify the carrier and sequence of routing and provide transit time information
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 304 | Event Code | 1 | M | ID | 3/3
-/// 02 | 106 | Event | 1 | O | AN | 1/25
-/// 03 | 373 | Date | 1 | O/Z | DT | 8/8
-/// 04 | 337 | Time | 1 | X/Z | TM | 4/8
-/// 05 | 19 | City Name | 1 | O | AN | 2/30
-/// 06 | 156 | State or Province Code | 1 | O | ID | 2/2
-/// 07 | 26 | Country Code | 1 | O | ID | 2/3
-/// 08 | 641 | Status Reason Code | 1 | O | ID | 3/3
-/// 09 | 154 | Standard Point Location Code | 1 | X/Z | ID | 6/9
-/// 10 | 380 | Quantity | 1 | X/Z | R | 1/15
-/// 11 | 1274 | Train Delay Reason Code | 1 | X | AN | 2/2
-/// 12 | 61 | Free-Form Message | 1 | O | AN | 1/30
-/// 13 | 623 | Time Code | 1 | O/Z | ID | 2/2
-/// 14 | 380 | Quantity | 1 | O/Z | R | 1/15
-/// 15 | 154 | Standard Point Location Code NEW | 1 | O/Z | ID | 6/9
-/// 16 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
-/// 17 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
-/// 18 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
-/// 19 | 81 | Weight NEW | 1 | O/Z | R | 1/10
-/// 20 | 82 | Length NEW | 1 | O/Z | R | 1/8
+/// ----|----|------|--------|----|------|-------
+/// 01 | 133 | Routing Sequence Code | 1 | O | ID | 1/2
+/// 02 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 03 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 04 | 91 | Transportation Method/Type Code | 1 | X | ID | 1/2
+/// 05 | 387 | Routing | 1 | X | AN | 1/35
+/// 06 | 368 | Shipment/Order Status Code | 1 | X | ID | 2/2
+/// 07 | 309 | Location Qualifier | 1 | O | ID | 1/2
+/// 08 | 310 | Location Identifier | 1 | X | AN | 1/30
+/// 09 | 731 | Transit Direction Code | 1 | O | ID | 2/2
+/// 10 | 732 | Transit Time Direction Qualifier | 1 | O | ID | 2/2
+/// 11 | 733 | Transit Time | 1 | X | R | 1/4
+/// 12 | 284 | Service Level Code | 1 | X | ID | 2/2
+/// 13 | 284 | Service Level Code | 1 | O | ID | 2/2
+/// 14 | 284 | Service Level Code | 1 | O | ID | 2/2
+/// 15 | 26 | Country Code | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct V9 {
+pub struct TD5 {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: Option<String>,
     #[serde(rename = "02")]
     pub _02: Option<String>,
-    /// 373 - Date
-    ///
-    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
-    /// - TYPE=DT
-    /// - MIN=8
-    /// - MAX=8
     #[serde(rename = "03")]
     pub _03: Option<String>,
-    /// 337 - Time
-    ///
-    /// Time expressed in 24-hour clock time as follows: HHMM, or HHMMSS, or HHMMSSD, or HHMMSSDD, where H = hours (00-23), M = minutes (00-59), S = integer seconds (00-59) and DD = decimal seconds; decimal seconds are expressed as follows: D = tenths (0-9) and DD = hundredths (00-99)
-    /// - TYPE=TM
-    /// - MIN=4
-    /// - MAX=8
     #[serde(rename = "04")]
     pub _04: Option<String>,
-    /// 19 - City Name
-    ///
-    /// Free-form text for city name
-    /// - TYPE=AN
-    /// - MIN=2
-    /// - MAX=30
     #[serde(rename = "05")]
     pub _05: Option<String>,
     #[serde(rename = "06")]
     pub _06: Option<String>,
-    /// 26 - Country Code
-    ///
-    /// Code identifying the country
-    /// - TYPE=ID
-    /// - MIN=2
-    /// - MAX=3
     #[serde(rename = "07")]
     pub _07: Option<String>,
     #[serde(rename = "08")]
@@ -5788,51 +7288,28 @@ pub struct V9 {
     pub _11: Option<String>,
     #[serde(rename = "12")]
     pub _12: Option<String>,
-    /// 623 - Time Code
-    ///
-    /// Code identifying the time. In accordance with International Standards Organization standard 8601, time can be specified by a + or - and an indication in hours in relation to Universal Time Coordinate (UTC) time; since + is a restricted character, + and - are substituted by P and M in the codes that follow
-    /// - TYPE=ID
-    /// - MIN=2
-    /// - MAX=2
     #[serde(rename = "13")]
     pub _13: Option<String>,
     #[serde(rename = "14")]
     pub _14: Option<String>,
     #[serde(rename = "15")]
     pub _15: Option<String>,
-    #[serde(rename = "16")]
-    pub _16: Option<String>,
-    #[serde(rename = "17")]
-    pub _17: Option<String>,
-    #[serde(rename = "18")]
-    pub _18: Option<String>,
-    #[serde(rename = "19")]
-    pub _19: Option<String>,
-    #[serde(rename = "20")]
-    pub _20: Option<String>,
 }
 
-/// VC - Motor Vehicle Control
+/// TDS - Total Monetary Value Summary
 ///
-/// To define motor vehicle identification and logistics
+/// To specify the total invoice discounts and amounts
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 539 | Vehicle Identification Number | 1 | M | AN | 1/25
-/// 02 | 836 | Vehicle Deck Position Code | 1 | O | ID | 2/2
-/// 03 | 837 | Vehicle Type Code | 1 | O | ID | 1/1
-/// 04 | 838 | Dealer Code | 1 | O | AN | 2/9
-/// 05 | 1 | Route Code | 1 | O/Z | AN | 1/13
-/// 06 | 839 | Bay Location | 1 | O | AN | 1/6
-/// 07 | 833 | Automotive Manufacturers Code | 1 | O | ID | 2/2
-/// 08 | 308 | Damage Exception Indicator | 1 | O | ID | 1/1
-/// 09 | 835 | Supplemental Inspection Code | 1 | O | ID | 1/1
-/// 10 | 583 | Factory Car Order Number | 1 | O | AN | 6/10
-/// 11 | 877 | Vessel Stowage Location | 1 | O | AN | 1/12
+/// ----|----|------|--------|----|------|-------
+/// 01 | 610 | Amount | 1 | M | N2 | 1/15
+/// 02 | 610 | Amount | 1 | O | N2 | 1/15
+/// 03 | 610 | Amount | 1 | O | N2 | 1/15
+/// 04 | 610 | Amount | 1 | O | N2 | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct VC {
+pub struct TDS {
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
@@ -5841,29 +7318,348 @@ pub struct VC {
     pub _03: Option<String>,
     #[serde(rename = "04")]
     pub _04: Option<String>,
-    #[serde(rename = "05")]
-    pub _05: Option<String>,
-    #[serde(rename = "06")]
-    pub _06: Option<String>,
-    #[serde(rename = "07")]
-    pub _07: Option<String>,
-    #[serde(rename = "08")]
-    pub _08: Option<String>,
-    #[serde(rename = "09")]
-    pub _09: Option<String>,
-    #[serde(rename = "10")]
-    pub _10: Option<String>,
-    #[serde(rename = "11")]
-    pub _11: Option<String>,
 }
 
-/// VID - Conveyance Identification
+/// TED - Technical Error Description
 ///
-/// To identify a conveyance and its attributes
+/// To identify the error and, if feasible, the erroneous segment, or data element, or both
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 40 | Equipment Description Code | M |  | ID 2/2
+/// ----|----|------|--------|----|------|-------
+/// 01 | 647 | Application Error Condition Code | 1 | M | ID | 1/3
+/// 02 | 3 | Free Form Message | 1 | O | AN | 1/60
+/// 03 | 721 | Segment ID Code | 1 | O | ID | 2/3
+/// 04 | 719 | Segment Position in Transaction Set | 1 | O | N0 | 1/10
+/// 05 | C030 | Position in Segment | 1 | O |  |
+/// 06 | 725 | Data Element Reference Number | 1 | O | N0 | 1/4
+/// 07 | 724 | Copy of Bad Data Element | 1 | O | AN | 1/99
+/// 08 | 961 | Data Element New Content | 1 | O | AN | 1/99
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct TED {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// TRN - Trace
+///
+/// To uniquely identify a transaction to an application
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 481 | Trace Type Code | 1 | M | ID | 1/2
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 03 | 509 | Originating Company Identifier | 1 | O | AN | 10/10
+/// 04 | 127 | Reference Identification | 1 | O | AN | 1/30
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct TRN {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
+/// TXI - Tax Information
+///
+/// To specify tax information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 963 | Tax Type Code | 1 | M | ID | 2/2
+/// 02 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 03 | 954 | Percent | 1 | X | R | 1/10
+/// 04 | 955 | Tax Jurisdiction Code Qualifier | 1 | X | ID | 2/2
+/// 05 | 956 | Tax Jurisdiction Code | 1 | X | AN | 1/10
+/// 06 | 441 | Tax Exempt Code | 1 | X | ID | 1/1
+/// 07 | 662 | Relationship Code | 1 | O | ID | 1/1
+/// 08 | 828 | Dollar Basis For Percent | 1 | O | R | 1/9
+/// 09 | 325 | Tax Identification Number | 1 | O | AN | 1/20
+/// 10 | 350 | Assigned Identification | 1 | O | AN | 1/20
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TXI {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+}
+
+/// V1 - Vessel Identification
+///
+/// To provide vessel details and voyage number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 597 | Vessel Code | 1 | X | ID | 1/8
+/// 02 | 182 | Vessel Name | 1 | X | AN | 2/28
+/// 03 | 26 | Country Code | 1 | O/Z | ID | 2/3
+/// 04 | 55 | Flight/Voyage Number | 1 | O | AN | 2/10
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 06 | 249 | Vessel Requirement Code | 1 | O | ID | 1/1
+/// 07 | 854 | Vessel Type Code | 1 | O | ID | 2/2
+/// 08 | 897 | Vessel Code Qualifier | 1 | O | ID | 1/1
+/// 09 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct V1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    /// 26 - Country Code
+    ///
+    /// Code identifying the country
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=3
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+/// V4 - Cargo Location Reference
+///
+/// To specify the cargo location on board the vessel
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 877 | Vessel Stowage Location | 1 | M | AN | 1/12
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct V4 {
+    #[serde(rename = "01")]
+    pub _01: String,
+}
+
+/// V9 - Event Detail
+///
+/// To specify information about a specific event
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 304 | Event Code | 1 | M | ID | 3/3
+/// 02 | 106 | Event | 1 | O | AN | 1/25
+/// 03 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 04 | 337 | Time | 1 | X/Z | TM | 4/8
+/// 05 | 19 | City Name | 1 | O | AN | 2/30
+/// 06 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 641 | Status Reason Code | 1 | O | ID | 3/3
+/// 09 | 154 | Standard Point Location Code | 1 | X/Z | ID | 6/9
+/// 10 | 380 | Quantity | 1 | X/Z | R | 1/15
+/// 11 | 1274 | Train Delay Reason Code | 1 | X | AN | 2/2
+/// 12 | 61 | Free-Form Message | 1 | O | AN | 1/30
+/// 13 | 623 | Time Code | 1 | O/Z | ID | 2/2
+/// 14 | 380 | Quantity | 1 | O/Z | R | 1/15
+/// 15 | 154 | Standard Point Location Code NEW | 1 | O/Z | ID | 6/9
+/// 16 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
+/// 17 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
+/// 18 | 86 | Total Equipment NEW | 1 | O/Z | N0 | 1/3
+/// 19 | 81 | Weight NEW | 1 | O/Z | R | 1/10
+/// 20 | 82 | Length NEW | 1 | O/Z | R | 1/8
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct V9 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    /// 337 - Time
+    ///
+    /// Time expressed in 24-hour clock time as follows: HHMM, or HHMMSS, or HHMMSSD, or HHMMSSDD, where H = hours (00-23), M = minutes (00-59), S = integer seconds (00-59) and DD = decimal seconds; decimal seconds are expressed as follows: D = tenths (0-9) and DD = hundredths (00-99)
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=8
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    /// 19 - City Name
+    ///
+    /// Free-form text for city name
+    /// - TYPE=AN
+    /// - MIN=2
+    /// - MAX=30
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    /// 26 - Country Code
+    ///
+    /// Code identifying the country
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=3
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    /// 623 - Time Code
+    ///
+    /// Code identifying the time. In accordance with International Standards Organization standard 8601, time can be specified by a + or - and an indication in hours in relation to Universal Time Coordinate (UTC) time; since + is a restricted character, + and - are substituted by P and M in the codes that follow
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+}
+
+/// VC - Motor Vehicle Control
+///
+/// To define motor vehicle identification and logistics
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 539 | Vehicle Identification Number | 1 | M | AN | 1/25
+/// 02 | 836 | Vehicle Deck Position Code | 1 | O | ID | 2/2
+/// 03 | 837 | Vehicle Type Code | 1 | O | ID | 1/1
+/// 04 | 838 | Dealer Code | 1 | O | AN | 2/9
+/// 05 | 1 | Route Code | 1 | O/Z | AN | 1/13
+/// 06 | 839 | Bay Location | 1 | O | AN | 1/6
+/// 07 | 833 | Automotive Manufacturers Code | 1 | O | ID | 2/2
+/// 08 | 308 | Damage Exception Indicator | 1 | O | ID | 1/1
+/// 09 | 835 | Supplemental Inspection Code | 1 | O | ID | 1/1
+/// 10 | 583 | Factory Car Order Number | 1 | O | AN | 6/10
+/// 11 | 877 | Vessel Stowage Location | 1 | O | AN | 1/12
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct VC {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+}
+
+/// VID - Conveyance Identification
+///
+/// To identify a conveyance and its attributes
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 40 | Equipment Description Code | M |  | ID 2/2
 /// 02 | 206 | Equipment Initial | O |  | AN 1/4
 /// 03 | 207 | Equipment Number | M |  | AN 1/10
 /// 04 | 225 | Seal Number | O |  | AN 2/15
@@ -5879,13 +7675,315 @@ pub struct VC {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct VID {
+pub struct VID {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+}
+
+/// W01 - Line Item Detail - Warehouse
+///
+/// To specify the content and format of line item detail for a warehouse shipping order
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 330 | Quantity Ordered | 1 | M | R | 1/15
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 438 | U.P.C. Case Code | 1 | X | AN | 12/12
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W01 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// W2 - Equipment Identification
+///
+/// To identify equipment and the commodity being carried
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 206 | Equipment Initial | 1 | M | AN | 1/4
+/// 02 | 207 | Equipment Number | 1 | M | AN | 1/10
+/// 03 | 22 | Commodity Code | 1 | O/Z | AN | 1/30
+/// 04 | 40 | Equipment Description Code | 1 | M | ID | 2/2
+/// 05 | 578 | Equipment Status Code | 1 | M | ID | 1/2
+/// 06 | 577 | Net Tons | 1 | O | N0 | 1/3
+/// 07 | 177 | Intermodal Service Code | 1 | O | ID | 1/2
+/// 08 | 240 | Car Service Order Code | 1 | O | ID | 3/5
+/// 09 | 373 | Date | 1 | X/Z | DT | 8/8
+/// 10 | 502 | Type of Locomotive Maintenance Code | 1 | X | AN | 2/2
+/// 11 | 206 | Equipment Initial | 1 | X | AN | 1/4
+/// 12 | 207 | Equipment Number | 1 | X/Z | AN | 1/10
+/// 13 | 761 | Equipment Number Check Digit | 1 | O | N0 | 1/1
+/// 14 | 219 | Position | 1 | O | AN | 1/3
+/// 15 | 301 | Car Type Code | 1 | O | ID | 1/4
+/// 16 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W2 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+}
+
+/// W03 - Total Shipment Information
+///
+/// To specify the total shipment in terms of quantity, weight and volume
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 02 | 81 | Weight | 1 | O | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 183 | Volume | 1 | O | R | 1/8
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 06 | 80 | Lading Quantity | 1 | O | N0 | 1/7
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W03 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// W04 - Item Detail Total
+///
+/// To specify the content and quantity of an item shipped in a stock transfer
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 438 | U.P.C. Case Code | 1 | X | AN | 12/12
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W04 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// W05 - Shipping Order Identification
+///
+/// To indicate the beginning of the Shipping Order Transaction Set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 473 | Order Status Code | 1 | M | ID | 1/2
+/// 02 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 03 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+/// 04 | 328 | Release Number | 1 | O | AN | 1/30
+/// 05 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W05 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// W06 - Warehouse Shipment Identification
+///
+/// To indicate the beginning of the Warehouse Shipping Advice Transaction Set and transmit identifying numbers, dates, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 592 | Reporting Code | 1 | M | ID | 1/1
+/// 02 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 145 | Shipment Identification Number | 1 | O | AN | 1/30
+/// 05 | 595 | Agent Shipment ID Number | 1 | O | AN | 1/30
+/// 06 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W06 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+}
+
+/// W07 - Item Detail For Stock Receipt
+///
+/// To specify the content and quantities of an item received in a stock transfer
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | M | R | 1/15
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 438 | U.P.C. Case Code | 1 | X | AN | 12/12
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W07 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// W09 - Equipment and Temperature
+///
+/// To relate equipment type and required temperatures
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 40 | Equipment Description Code | 1 | M | ID | 2/2
+/// 02 | 408 | Temperature | 1 | X/Z | R | 1/4
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 408 | Temperature | 1 | X/Z | R | 1/4
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 06 | 3 | Free Form Message | 1 | O/Z | AN | 1/60
+/// 07 | 1122 | Vent Setting Code | 1 | O | ID | 1/1
+/// 08 | 488 | Percent | 1 | O/Z | N0 | 1/3
+/// 09 | 380 | Quantity | 1 | O/Z | R | 1/15
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W09 {
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
-    pub _03: String,
+    pub _03: Option<String>,
     #[serde(rename = "04")]
     pub _04: Option<String>,
     #[serde(rename = "05")]
@@ -5898,64 +7996,46 @@ pub struct VID {
     pub _08: Option<String>,
     #[serde(rename = "09")]
     pub _09: Option<String>,
-    #[serde(rename = "10")]
-    pub _10: Option<String>,
-    #[serde(rename = "11")]
-    pub _11: Option<String>,
-    #[serde(rename = "12")]
-    pub _12: Option<String>,
-    #[serde(rename = "13")]
-    pub _13: Option<String>,
 }
 
-/// W2 - Equipment Identification
+/// W12 - Warehouse Item Detail
 ///
-/// To identify equipment and the commodity being carried
+/// To designate those line items that were shipped
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 206 | Equipment Initial | 1 | M | AN | 1/4
-/// 02 | 207 | Equipment Number | 1 | M | AN | 1/10
-/// 03 | 22 | Commodity Code | 1 | O/Z | AN | 1/30
-/// 04 | 40 | Equipment Description Code | 1 | M | ID | 2/2
-/// 05 | 578 | Equipment Status Code | 1 | M | ID | 1/2
-/// 06 | 577 | Net Tons | 1 | O | N0 | 1/3
-/// 07 | 177 | Intermodal Service Code | 1 | O | ID | 1/2
-/// 08 | 240 | Car Service Order Code | 1 | O | ID | 3/5
-/// 09 | 373 | Date | 1 | X/Z | DT | 8/8
-/// 10 | 502 | Type of Locomotive Maintenance Code | 1 | X | AN | 2/2
-/// 11 | 206 | Equipment Initial | 1 | X | AN | 1/4
-/// 12 | 207 | Equipment Number | 1 | X/Z | AN | 1/10
-/// 13 | 761 | Equipment Number Check Digit | 1 | O | N0 | 1/1
-/// 14 | 219 | Position | 1 | O | AN | 1/3
-/// 15 | 301 | Car Type Code | 1 | O | ID | 1/4
-/// 16 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// ----|----|------|--------|----|------|-------
+/// 01 | 368 | Shipment/Order Status Code | 1 | M | ID | 2/2
+/// 02 | 330 | Quantity Ordered | 1 | O | R | 1/15
+/// 03 | 382 | Number of Units Shipped | 1 | X | R | 1/10
+/// 04 | 380 | Quantity | 1 | O | R | 1/15
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 06 | 438 | U.P.C. Case Code | 1 | X | AN | 12/12
+/// 07 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 08 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 09 | 461 | Warehouse Lot Number | 1 | O | AN | 1/12
+/// 10 | 81 | Weight | 1 | X | R | 1/10
+/// 11 | 187 | Weight Qualifier | 1 | X | ID | 1/2
+/// 12 | 188 | Weight Unit Code | 1 | X | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct W2 {
+pub struct W12 {
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
-    pub _02: String,
+    pub _02: Option<String>,
     #[serde(rename = "03")]
     pub _03: Option<String>,
     #[serde(rename = "04")]
-    pub _04: String,
+    pub _04: Option<String>,
     #[serde(rename = "05")]
-    pub _05: String,
+    pub _05: Option<String>,
     #[serde(rename = "06")]
     pub _06: Option<String>,
     #[serde(rename = "07")]
     pub _07: Option<String>,
     #[serde(rename = "08")]
     pub _08: Option<String>,
-    /// 373 - Date
-    ///
-    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
-    /// - TYPE=DT
-    /// - MIN=8
-    /// - MAX=8
     #[serde(rename = "09")]
     pub _09: Option<String>,
     #[serde(rename = "10")]
@@ -5964,35 +8044,136 @@ pub struct W2 {
     pub _11: Option<String>,
     #[serde(rename = "12")]
     pub _12: Option<String>,
-    #[serde(rename = "13")]
-    pub _13: Option<String>,
-    #[serde(rename = "14")]
-    pub _14: Option<String>,
-    #[serde(rename = "15")]
-    pub _15: Option<String>,
-    #[serde(rename = "16")]
-    pub _16: Option<String>,
 }
 
-/// W09 - Equipment and Temperature
+/// W14 - Total Receipt Information
 ///
-/// To relate equipment type and required temperatures
+/// To specify summary details of total items received in a stock transfer
 ///
 /// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
-/// ----|----|-------|--------|----|------|-------
-/// 01 | 40 | Equipment Description Code | 1 | M | ID | 2/2
-/// 02 | 408 | Temperature | 1 | X/Z | R | 1/4
-/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
-/// 04 | 408 | Temperature | 1 | X/Z | R | 1/4
-/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
-/// 06 | 3 | Free Form Message | 1 | O/Z | AN | 1/60
-/// 07 | 1122 | Vent Setting Code | 1 | O | ID | 1/1
-/// 08 | 488 | Percent | 1 | O/Z | N0 | 1/3
-/// 09 | 380 | Quantity | 1 | O/Z | R | 1/15
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | M | R | 1/15
+/// 02 | 380 | Quantity | 1 | O | R | 1/15
+/// 03 | 380 | Quantity | 1 | O | R | 1/15
+/// 04 | 380 | Quantity | 1 | O | R | 1/15
+/// 05 | 380 | Quantity | 1 | O | R | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
-pub struct W09 {
+pub struct W14 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// W15 - Warehouse Adjustment Identification
+///
+/// To identify the beginning of an inventory adjustment advice
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 373 | Date | 1 | M | DT | 8/8
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 03 | 127 | Reference Identification | 1 | O | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W15 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+/// W17 - Warehouse Receipt Identification
+///
+/// To indicate the beginning of the Warehouse Stock Transfer Receipt Advice Transaction Set and transmit identifying numbers, dates, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 592 | Reporting Code | 1 | M | ID | 1/1
+/// 02 | 373 | Date | 1 | M | DT | 8/8
+/// 03 | 586 | Warehouse Receipt Number | 1 | M | AN | 1/12
+/// 04 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+/// 05 | 145 | Shipment Identification Number | 1 | O | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W17 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// W19 - Inventory Adjustment Details
+///
+/// To specify the inventory adjustment details of a line item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1271 | Quantity or Status Adjustment Reason Code | 1 | M | ID | 2/2
+/// 02 | 380 | Quantity | 1 | M | R | 1/15
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 04 | 438 | U.P.C. Case Code | 1 | X | AN | 12/12
+/// 05 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 06 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 07 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
+/// 08 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W19 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// W27 - Carrier Detail
+///
+/// To specify the carrier and transportation details for a warehouse shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 91 | Transportation Method/Type Code | 1 | M | ID | 1/2
+/// 02 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 03 | 387 | Routing | 1 | O | AN | 1/35
+/// 04 | 146 | Shipment Method of Payment | 1 | O | ID | 2/2
+/// 05 | 40 | Equipment Description Code | 1 | O | ID | 2/2
+/// 06 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 07 | 207 | Equipment Number | 1 | O | AN | 1/10
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W27 {
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
@@ -6007,10 +8188,84 @@ pub struct W09 {
     pub _06: Option<String>,
     #[serde(rename = "07")]
     pub _07: Option<String>,
+}
+
+/// W66 - Warehouse Carrier Information
+///
+/// To specify shipping information for a warehouse shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 146 | Shipment Method of Payment | 1 | M | ID | 2/2
+/// 02 | 91 | Transportation Method/Type Code | 1 | M | ID | 1/2
+/// 03 | 152 | Special Handling Code | 1 | O | ID | 2/3
+/// 04 | 310 | Location Identifier | 1 | O | AN | 1/30
+/// 05 | 387 | Routing | 1 | O | AN | 1/35
+/// 06 | 190 | Pallet Exchange Code | 1 | O | ID | 1/1
+/// 07 | 193 | Unit Load Option Code | 1 | O | ID | 2/2
+/// 08 | 610 | Amount | 1 | O | N2 | 1/15
+/// 09 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 10 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct W66 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
     #[serde(rename = "08")]
     pub _08: Option<String>,
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
+
+/// W76 - Total Shipping Order
+///
+/// To specify summary details of the total shipping order
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 330 | Quantity Ordered | 1 | M | R | 1/15
+/// 02 | 81 | Weight | 1 | X | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 183 | Volume | 1 | X | R | 1/8
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct W76 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
 }
 
 /// X1 - Export License
@@ -6211,6 +8466,20 @@ pub struct Y2 {
 /// Y3 - Space Confirmation
 ///
 /// To specify confirmation information for space booking including number, dates and load time
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 13 | Booking Number | 1 | M | AN | 1/17
+/// 02 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 373 | Date | 1 | O | DT | 8/8
+/// 05 | 154 | Pier Number | 1 | O | AN | 1/4
+/// 06 | 155 | Pier Name | 1 | O | AN | 2/14
+/// 07 | 373 | Date | 1 | O | DT | 8/8
+/// 08 | 337 | Time | 1 | O | TM | 4/8
+/// 09 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 10 | 310 | Location Identifier | 1 | O | AN | 1/30
+/// 11 | 375 | Tariff Service Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -6242,6 +8511,19 @@ pub struct Y3 {
 /// Y4 - Container Release
 ///
 /// To transmit information relative to containers available for release
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 13 | Booking Number | 1 | O | AN | 1/17
+/// 02 | 13 | Booking Number | 1 | O | AN | 1/17
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 154 | Pier Number | 1 | O | AN | 1/4
+/// 05 | 95 | Number of Containers | 1 | O | N0 | 1/4
+/// 06 | 24 | Equipment Type | 1 | O | ID | 4/4
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 08 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 09 | 310 | Location Identifier | 1 | X | AN | 1/30
+/// 10 | 56 | Type of Service Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -6325,6 +8607,19 @@ pub struct Y7 {
 /// YNQ - Yes/No Question
 ///
 /// To identify and answer yes and no questions, including the date, time, and comments further qualifying the condition
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1321 | Condition Indicator | 1 | X | ID | 2/3
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 03 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 04 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 05 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 06 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 07 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 08 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 09 | 1271 | Industry Code | 1 | X | AN | 1/30
+/// 10 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
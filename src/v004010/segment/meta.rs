@@ -0,0 +1,4061 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    ACK,
+    "Line Item Acknowledgment",
+    "To acknowledge the ordered quantities and specify the ready date for a specific line item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29],
+    [
+        (1, "668", "Line Item Status Code", Mandatory, Id, 2, 2, line_item_status_code),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier),
+        (5, "373", "Date", Optional, Date, 8, 8, date),
+        (6, "326", "Request Reference Number", Optional, AlphaNumeric, 1, 45, request_reference_number),
+        (7, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_07),
+        (8, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_08),
+        (9, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_09),
+        (10, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_11),
+        (12, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_12),
+        (13, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_13),
+        (14, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_14),
+        (15, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_15),
+        (16, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_16),
+        (17, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_17),
+        (18, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_18),
+        (19, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_19),
+        (20, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_20),
+        (21, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_21),
+        (22, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_22),
+        (23, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_23),
+        (24, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_24),
+        (25, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_25),
+        (26, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_26),
+        (27, "559", "Agency Qualifier Code", Optional, Id, 2, 2, agency_qualifier_code),
+        (28, "822", "Source Subqualifier", Optional, AlphaNumeric, 1, 15, source_subqualifier),
+        (29, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    ADX,
+    "Adjustment",
+    "To convey accounts-payable adjustment information for the purpose of cash application, including payer-generated debit/credit memos",
+    [_01, _02, _03, _04],
+    [
+        (1, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (2, "426", "Adjustment Reason Code", Mandatory, Id, 2, 2, adjustment_reason_code),
+        (3, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (4, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+    ]
+}
+
+segment_meta! {
+    AK1,
+    "Functional Group Response Header",
+    "To start acknowledgment of a functional group",
+    [_01, _02],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    AK2,
+    "Transaction Set Response Header",
+    "To start acknowledgment of a single transaction set",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    AK3,
+    "Data Segment Note",
+    "To report errors in a data segment and identify the location of the data segment",
+    [_01, _02, _03, _04],
+    [
+        (1, "721", "Segment ID Code", Mandatory, Id, 2, 3, segment_id_code),
+        (2, "719", "Segment Position in Transaction Set", Mandatory, Numeric(0), 1, 6, segment_position_in_transaction_set),
+        (3, "447", "Loop Identifier Code", Optional, AlphaNumeric, 1, 6, loop_identifier_code),
+        (4, "720", "Segment Syntax Error Code", Optional, Id, 1, 3, segment_syntax_error_code),
+    ]
+}
+
+segment_meta! {
+    AK4,
+    "Data Element Note",
+    "To report errors in a data element or composite data structure and identify the location of the data element",
+    [_01, _02, _03, _04],
+    [
+        (1, "C030", "Position in Segment", Mandatory, Composite, 0, 0, position_in_segment),
+        (2, "725", "Data Element Reference Number", Optional, Numeric(0), 1, 4, data_element_reference_number),
+        (3, "723", "Data Element Syntax Error Code", Mandatory, Id, 1, 3, data_element_syntax_error_code),
+        (4, "724", "Copy of Bad Data Element", Optional, AlphaNumeric, 1, 99, copy_of_bad_data_element),
+    ]
+}
+
+segment_meta! {
+    AK5,
+    "Transaction Set Response Trailer",
+    "To acknowledge acceptance or rejection and report errors in a transaction set",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "717", "Transaction Set Acknowledgment Code", Mandatory, Id, 1, 1, transaction_set_acknowledgment_code),
+        (2, "718", "Transaction Set Syntax Error Code", Optional, Id, 1, 3, transaction_set_syntax_error_code_02),
+        (3, "718", "Transaction Set Syntax Error Code", Optional, Id, 1, 3, transaction_set_syntax_error_code_03),
+        (4, "718", "Transaction Set Syntax Error Code", Optional, Id, 1, 3, transaction_set_syntax_error_code_04),
+        (5, "718", "Transaction Set Syntax Error Code", Optional, Id, 1, 3, transaction_set_syntax_error_code_05),
+        (6, "718", "Transaction Set Syntax Error Code", Optional, Id, 1, 3, transaction_set_syntax_error_code_06),
+    ]
+}
+
+segment_meta! {
+    AK9,
+    "Functional Group Response Trailer",
+    "To acknowledge acceptance or rejection of a functional group and report the number of included transaction sets from the original trailer, the accepted sets, and the received sets in this functional group",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "715", "Functional Group Acknowledge Code", Mandatory, Id, 1, 1, functional_group_acknowledge_code),
+        (2, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (3, "123", "Number of Received Transaction Sets", Mandatory, Numeric(0), 1, 6, number_of_received_transaction_sets),
+        (4, "2", "Number of Accepted Transaction Sets", Mandatory, Numeric(0), 1, 6, number_of_accepted_transaction_sets),
+        (5, "716", "Functional Group Syntax Error Code", Optional, Id, 1, 3, functional_group_syntax_error_code_05),
+        (6, "716", "Functional Group Syntax Error Code", Optional, Id, 1, 3, functional_group_syntax_error_code_06),
+        (7, "716", "Functional Group Syntax Error Code", Optional, Id, 1, 3, functional_group_syntax_error_code_07),
+        (8, "716", "Functional Group Syntax Error Code", Optional, Id, 1, 3, functional_group_syntax_error_code_08),
+        (9, "716", "Functional Group Syntax Error Code", Optional, Id, 1, 3, functional_group_syntax_error_code_09),
+    ]
+}
+
+segment_meta! {
+    AMT,
+    "Monetary Amount Information",
+    "To indicate the total monetary amount",
+    [_01, _02, _03],
+    [
+        (1, "522", "Amount Qualifier Code", Mandatory, Id, 1, 3, amount_qualifier_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "478", "Credit/Debit Flag Code", Optional, Id, 1, 1, credit_debit_flag_code),
+    ]
+}
+
+segment_meta! {
+    AT5,
+    "Bill of Lading Handling Requirements",
+    "To identify Bill of Lading handling and service requirements",
+    [_01, _02, _03],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "560", "Special Services Code", Conditional, Id, 2, 10, special_services_code),
+        (3, "153", "Special Handling Description", Conditional, AlphaNumeric, 2, 30, special_handling_description),
+    ]
+}
+
+segment_meta! {
+    AT7,
+    "Shipment Status Details",
+    "To specify the status of a shipment, the reason for that status, the date and time of the status and the date and time of any appointments scheduled.",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1650", "Shipment Status Code", Conditional, Id, 2, 2, shipment_status_code),
+        (2, "1651", "Shipment Status or Appointment Reason Code", Conditional, Id, 2, 2, shipment_status_or_appointment_reason_code_02),
+        (3, "1652", "Shipment Appointment Status Code", Conditional, Id, 2, 2, shipment_appointment_status_code),
+        (4, "1651", "Shipment Status or Appointment Reason Code", Conditional, Id, 2, 2, shipment_status_or_appointment_reason_code_04),
+        (5, "373", "Date", Conditional, Date, 8, 8, date),
+        (6, "337", "Time", Conditional, Time, 4, 8, time),
+        (7, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    AT8,
+    "Shipment Weight, Packaging and Quantity Data",
+    "To specify shipment details in terms of weight, and quantity of handling units",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (2, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity_04),
+        (5, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity_05),
+        (6, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (7, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+    ]
+}
+
+segment_meta! {
+    B1,
+    "Beginning Segment for Booking or Pick-up/Delivery",
+    "To transmit identifying number, data, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "145", "Shipment Identification Number", Mandatory, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "558", "Reservation Action Code", Mandatory, Id, 1, 1, reservation_action_code),
+    ]
+}
+
+segment_meta! {
+    B2,
+    "Beginning Segment for Shipment Information Transaction",
+    "To transmit basic data relating to shipment information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "375", "Tariff Service Code", Optional, Id, 2, 2, tariff_service_code),
+        (2, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (4, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (5, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (6, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (7, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (8, "86", "Total Equipment", Optional, Numeric(0), 1, 3, total_equipment),
+        (9, "460", "Shipment Weight Code", Optional, Id, 1, 1, shipment_weight_code),
+        (10, "501", "Customs Documentation Handling Code", Optional, Id, 2, 2, customs_documentation_handling_code),
+        (11, "335", "Transportation Terms Code", Optional, Id, 3, 3, transportation_terms_code),
+        (12, "591", "Payment Method Code", Optional, Id, 3, 3, payment_method_code),
+    ]
+}
+
+segment_meta! {
+    B2A,
+    "Set Purpose",
+    "To allow for positive identification of transaction set purpose",
+    [_01, _02],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "346", "Application Type", Optional, Id, 2, 2, application_type),
+    ]
+}
+
+segment_meta! {
+    B3,
+    "Beginning Segment for Carrier's Invoice",
+    "To transmit basic data relating to the carrier's invoice",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (2, "76", "Invoice Number", Mandatory, AlphaNumeric, 1, 22, invoice_number),
+        (3, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (4, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (5, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (6, "373", "Date", Mandatory, Date, 8, 8, date_06),
+        (7, "193", "Net Amount Due", Mandatory, Numeric(2), 1, 12, net_amount_due),
+        (8, "202", "Correction Indicator", Optional, Id, 2, 2, correction_indicator),
+        (9, "32", "Delivery Date", Conditional, Date, 8, 8, delivery_date),
+        (10, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier),
+        (11, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (12, "373", "Date", Optional, Date, 8, 8, date_12),
+        (13, "375", "Tariff Service Code", Optional, Id, 2, 2, tariff_service_code),
+        (14, "335", "Transportation Terms Code", Optional, Id, 3, 3, transportation_terms_code),
+    ]
+}
+
+segment_meta! {
+    B4,
+    "Beginning Segment for Inquiry or Reply",
+    "To transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "152", "Special Handling Code", Optional, Id, 2, 3, special_handling_code),
+        (2, "71", "Inquiry Request Number", Optional, Numeric(0), 1, 3, inquiry_request_number),
+        (3, "157", "Shipment Status Code", Optional, Id, 1, 2, shipment_status_code),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "161", "Status Time", Optional, Time, 4, 4, status_time),
+        (6, "159", "Status Location", Optional, AlphaNumeric, 3, 5, status_location),
+        (7, "206", "Equipment Initial", Conditional, AlphaNumeric, 1, 4, equipment_initial),
+        (8, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+        (9, "578", "Equipment Status Code", Optional, Id, 1, 2, equipment_status_code),
+        (10, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (11, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (12, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (13, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+    ]
+}
+
+segment_meta! {
+    B10,
+    "Beginning Segment for Transportation Carrier Shipment Status Message",
+    "To transmit identifying numbers and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_01),
+        (2, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (4, "71", "Inquiry Request Number", Optional, Numeric(0), 1, 3, inquiry_request_number),
+        (5, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (6, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_06),
+        (7, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    BAK,
+    "Beginning Segment for Purchase Order Acknowledgment",
+    "To indicate the beginning of the Purchase Order Acknowledgment Transaction Set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "587", "Acknowledgment Type", Mandatory, Id, 2, 2, acknowledgment_type),
+        (3, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (4, "373", "Date", Mandatory, Date, 8, 8, date_04),
+        (5, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (6, "326", "Request Reference Number", Optional, AlphaNumeric, 1, 45, request_reference_number),
+        (7, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (8, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (9, "373", "Date", Optional, Date, 8, 8, date_09),
+        (10, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+    ]
+}
+
+segment_meta! {
+    BAL,
+    "Balance Detail",
+    "To identify the specific monetary balances associated with a particular account",
+    [_01, _02, _03],
+    [
+        (1, "951", "Balance Type Code", Mandatory, Id, 1, 2, balance_type_code),
+        (2, "522", "Amount Qualifier Code", Mandatory, Id, 1, 3, amount_qualifier_code),
+        (3, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+    ]
+}
+
+segment_meta! {
+    BCH,
+    "Beginning Segment for Purchase Order Change",
+    "To indicate the beginning of the Purchase Order Change Transaction Set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "92", "Purchase Order Type Code", Mandatory, Id, 2, 2, purchase_order_type_code),
+        (3, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (4, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (5, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (6, "373", "Date", Mandatory, Date, 8, 8, date_06),
+        (7, "326", "Request Reference Number", Optional, AlphaNumeric, 1, 45, request_reference_number),
+        (8, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (9, "373", "Date", Optional, Date, 8, 8, date_09),
+        (10, "373", "Date", Optional, Date, 8, 8, date_10),
+        (11, "373", "Date", Optional, Date, 8, 8, date_11),
+        (12, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (13, "1232", "Purchase Category", Optional, Id, 2, 2, purchase_category),
+    ]
+}
+
+segment_meta! {
+    BEG,
+    "Beginning Segment for Purchase Order",
+    "To indicate the beginning of the Purchase Order Transaction Set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "92", "Purchase Order Type Code", Mandatory, Id, 2, 2, purchase_order_type_code),
+        (3, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (4, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (5, "373", "Date", Mandatory, Date, 8, 8, date),
+        (6, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (7, "587", "Acknowledgment Type", Optional, Id, 2, 2, acknowledgment_type),
+        (8, "1019", "Invoice Type Code", Optional, Id, 3, 3, invoice_type_code),
+        (9, "1166", "Contract Type Code", Optional, Id, 2, 2, contract_type_code),
+        (10, "1232", "Purchase Category", Optional, Id, 2, 2, purchase_category),
+        (11, "786", "Security Level Code", Optional, Id, 2, 2, security_level_code),
+        (12, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+    ]
+}
+
+segment_meta! {
+    BGN,
+    "Beginning Segment",
+    "To indicate the beginning of a transaction set",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification_02),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "337", "Time", Conditional, Time, 4, 8, time),
+        (5, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (6, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_06),
+        (7, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (8, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (9, "786", "Security Level Code", Optional, Id, 2, 2, security_level_code),
+    ]
+}
+
+segment_meta! {
+    BIG,
+    "Beginning Segment for Invoice",
+    "To indicate the beginning of an invoice transaction set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "373", "Date", Mandatory, Date, 8, 8, date_01),
+        (2, "76", "Invoice Number", Mandatory, AlphaNumeric, 1, 22, invoice_number_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number),
+        (5, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (6, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (7, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (8, "353", "Transaction Set Purpose Code", Optional, Id, 2, 2, transaction_set_purpose_code),
+        (9, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (10, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number_10),
+        (11, "1019", "Invoice Type Code", Optional, Id, 1, 3, invoice_type_code),
+    ]
+}
+
+segment_meta! {
+    BIN,
+    "Binary Data",
+    "To transfer binary data in a single data segment and allow identification of the end of the data segment through a count; there is no identification of the internal structure of the binary data in this segment",
+    [_01, _02],
+    [
+        (1, "784", "Length of Binary Data", Mandatory, Numeric(0), 1, 15, length_of_binary_data),
+        (2, "785", "Binary Data", Mandatory, Binary, 1, 9999999999999999, binary_data),
+    ]
+}
+
+segment_meta! {
+    BL,
+    "Billing Information",
+    "To identify the individual billing segments within a movement when joint rail rates have been established between carriers but do not cover the entire movement",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "747", "Rebill Reason Code", Mandatory, Id, 2, 2, rebill_reason_code),
+        (2, "573", "Freight Station Accounting Code", Mandatory, Id, 1, 5, freight_station_accounting_code_02),
+        (3, "573", "Freight Station Accounting Code", Mandatory, Id, 1, 5, freight_station_accounting_code_03),
+        (4, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code_04),
+        (5, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name_05),
+        (6, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code_06),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code_07),
+        (8, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code_08),
+        (9, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name_09),
+        (10, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code_10),
+        (11, "26", "Country Code", Optional, Id, 2, 3, country_code_11),
+        (12, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_13),
+        (14, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_14),
+        (15, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_15),
+        (16, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_16),
+        (17, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_17),
+    ]
+}
+
+segment_meta! {
+    BNX,
+    "Rail Shipment Information",
+    "To transmit rail-specific shipment data",
+    [_01, _02, _03, _04],
+    [
+        (1, "460", "Shipment Weight Code", Optional, Id, 1, 1, shipment_weight_code),
+        (2, "129", "Referenced Pattern Identifier", Optional, AlphaNumeric, 1, 13, referenced_pattern_identifier),
+        (3, "11", "Billing Code", Optional, Id, 1, 1, billing_code),
+        (4, "223", "Repetitive Pattern Number", Optional, Numeric(0), 5, 5, repetitive_pattern_number),
+    ]
+}
+
+segment_meta! {
+    BPR,
+    "Beginning Segment for Payment Order/Remittance Advice",
+    "To indicate the beginning of a Payment Order/Remittance Advice Transaction Set and total payment amount, or to enable related transfer of funds and/or information from payer to payee to occur",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "305", "Transaction Handling Code", Mandatory, Id, 1, 2, transaction_handling_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "478", "Credit/Debit Flag Code", Mandatory, Id, 1, 1, credit_debit_flag_code),
+        (4, "591", "Payment Method Code", Mandatory, Id, 3, 3, payment_method_code),
+        (5, "812", "Payment Format Code", Optional, Id, 1, 10, payment_format_code),
+        (6, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_06),
+        (7, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_07),
+        (8, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_08),
+        (9, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_09),
+        (10, "509", "Originating Company Identifier", Optional, AlphaNumeric, 10, 10, originating_company_identifier),
+        (11, "510", "Originating Company Supplemental Code", Optional, AlphaNumeric, 9, 9, originating_company_supplemental_code),
+        (12, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_12),
+        (13, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_13),
+        (14, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_14),
+        (15, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_15),
+        (16, "373", "Date", Optional, Date, 8, 8, date),
+        (17, "1048", "Business Function Code", Optional, Id, 1, 3, business_function_code),
+        (18, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_18),
+        (19, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_19),
+        (20, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_20),
+        (21, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_21),
+    ]
+}
+
+segment_meta! {
+    BSN,
+    "Beginning Segment for Ship Notice",
+    "To transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "396", "Shipment Identification", Mandatory, AlphaNumeric, 2, 30, shipment_identification),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "337", "Time", Mandatory, Time, 4, 8, time),
+        (5, "1005", "Hierarchical Structure Code", Optional, Id, 4, 4, hierarchical_structure_code),
+        (6, "640", "Transaction Type Code", Conditional, Id, 2, 2, transaction_type_code),
+        (7, "641", "Status Reason Code", Optional, Id, 3, 3, status_reason_code),
+    ]
+}
+
+segment_meta! {
+    BX,
+    "General Shipment Information",
+    "To transmit identification numbers and other basic shipment data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+        (3, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (4, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (7, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (8, "226", "Section Seven Code", Optional, Id, 1, 1, section_seven_code),
+        (9, "195", "Capacity Load Code", Optional, Id, 1, 1, capacity_load_code),
+        (10, "160", "Status Report Request Code", Optional, Id, 1, 1, status_report_request_code),
+        (11, "501", "Customs Documentation Handling Code", Optional, Id, 2, 2, customs_documentation_handling_code),
+        (12, "199", "Confidential Billing Request Code", Optional, Id, 1, 1, confidential_billing_request_code),
+        (13, "714", "Goods and Services Tax Reason Code", Optional, Id, 1, 1, goods_and_services_tax_reason_code),
+        (14, "346", "Application Type", Optional, Id, 2, 2, application_type),
+    ]
+}
+
+segment_meta! {
+    C2,
+    "Bank ID",
+    "To specify data required for electronic payment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "8", "Bank Client Code", Mandatory, Id, 1, 1, bank_client_code),
+        (2, "66", "Identification Code Qualifier", Mandatory, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Mandatory, AlphaNumeric, 2, 80, identification_code),
+        (4, "20", "Client Bank Number", Optional, AlphaNumeric, 3, 9, client_bank_number),
+        (5, "7", "Bank Account Number", Optional, AlphaNumeric, 6, 17, bank_account_number),
+        (6, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+        (7, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    C3,
+    "Currency",
+    "To specify the currency being used in the transaction set",
+    [_01, _02, _03, _04],
+    [
+        (1, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code_01),
+        (2, "280", "Exchange Rate", Optional, Decimal, 4, 10, exchange_rate),
+        (3, "100", "Currency Code", Optional, Id, 3, 3, currency_code_03),
+        (4, "100", "Currency Code", Optional, Id, 3, 3, currency_code_04),
+    ]
+}
+
+segment_meta! {
+    C8,
+    "Certifications and Clauses",
+    "To specify applicable certifications and clauses",
+    [_01, _02, _03, _04],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "246", "Certification/Clause Code", Conditional, Id, 2, 4, certification_clause_code),
+        (3, "247", "Certification/Clause Text", Conditional, AlphaNumeric, 2, 60, certification_clause_text),
+        (4, "1302", "Shipper's Export Declaration Requirements", Optional, AlphaNumeric, 1, 2, shippers_export_declaration_requirements),
+    ]
+}
+
+segment_meta! {
+    C8C,
+    "Certifications Clauses Continuation",
+    "To specify additional applicable certifications and clauses",
+    [_01, _02, _03],
+    [
+        (1, "247", "Certification/Clause Text", Mandatory, AlphaNumeric, 2, 60, certification_clause_text_01),
+        (2, "247", "Certification/Clause Text", Optional, AlphaNumeric, 2, 60, certification_clause_text_02),
+        (3, "247", "Certification/Clause Text", Optional, AlphaNumeric, 2, 60, certification_clause_text_03),
+    ]
+}
+
+segment_meta! {
+    CAD,
+    "Carrier Details",
+    "To specify transportation details for the transaction",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Optional, AlphaNumeric, 1, 10, equipment_number),
+        (4, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code),
+        (5, "387", "Routing", Conditional, AlphaNumeric, 1, 35, routing),
+        (6, "368", "Shipment/Order Status Code", Optional, Id, 2, 2, shipment_order_status_code),
+        (7, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (8, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (9, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code),
+    ]
+}
+
+segment_meta! {
+    CD3,
+    "Carton (Package) Detail",
+    "To transmit identifying codes, weights, and other related information related to an individual carton (package)",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (2, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (3, "619", "Zone", Optional, AlphaNumeric, 2, 3, zone),
+        (4, "34", "Service Standard", Optional, Numeric(1), 1, 4, service_standard),
+        (5, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_05),
+        (6, "108", "Pick-up or Delivery Code", Optional, Id, 1, 2, pick_up_or_delivery_code),
+        (7, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_07),
+        (8, "58", "Charge", Conditional, Numeric(2), 1, 12, charge_08),
+        (9, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_09),
+        (10, "58", "Charge", Conditional, Numeric(2), 1, 12, charge_10),
+        (11, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_11),
+        (12, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_12),
+        (13, "591", "Payment Method Code", Optional, Id, 3, 3, payment_method_code),
+        (14, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    CLD,
+    "Load Detail",
+    "To specify the number of material loads shipped",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "622", "Number of Loads", Mandatory, Numeric(0), 1, 5, number_of_loads),
+        (2, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (3, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (4, "357", "Size", Optional, Decimal, 1, 8, size),
+        (5, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+    ]
+}
+
+segment_meta! {
+    CM,
+    "Cargo Manifest",
+    "To identify specific flight or voyage information for multimodal shipments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "55", "Flight/Voyage Number", Optional, AlphaNumeric, 2, 10, flight_voyage_number),
+        (2, "115", "Port or Terminal Function Code", Conditional, Id, 1, 1, port_or_terminal_function_code),
+        (3, "114", "Port Name", Optional, AlphaNumeric, 2, 24, port_name),
+        (4, "373", "Date", Optional, Date, 8, 8, date_04),
+        (5, "13", "Booking Number", Optional, AlphaNumeric, 1, 17, booking_number),
+        (6, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_06),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "182", "Vessel Name", Optional, AlphaNumeric, 2, 28, vessel_name),
+        (10, "113", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (11, "112", "Pier Name", Optional, AlphaNumeric, 2, 14, pier_name),
+        (12, "174", "Terminal Name", Optional, AlphaNumeric, 2, 30, terminal_name),
+        (13, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (14, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (15, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (16, "202", "Correction Indicator", Optional, Id, 2, 2, correction_indicator),
+        (17, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+segment_meta! {
+    CRC,
+    "Conditions Indicator",
+    "To supply information on conditions",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1136", "Code Category", Mandatory, Id, 2, 2, code_category),
+        (2, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "1321", "Condition Indicator", Mandatory, Id, 2, 3, condition_indicator_03),
+        (4, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_04),
+        (5, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_05),
+        (6, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_06),
+        (7, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_07),
+    ]
+}
+
+segment_meta! {
+    CTP,
+    "Pricing Information",
+    "To specify pricing information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "687", "Class of Trade Code", Optional, Id, 2, 2, class_of_trade_code),
+        (2, "236", "Price Identifier Code", Conditional, Id, 3, 3, price_identifier_code),
+        (3, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "648", "Price Multiplier Qualifier", Conditional, Id, 3, 3, price_multiplier_qualifier),
+        (7, "649", "Multiplier", Conditional, Decimal, 1, 10, multiplier),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (9, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (10, "499", "Condition Value", Optional, AlphaNumeric, 1, 10, condition_value),
+        (11, "289", "Multiple Price Quantity", Optional, Numeric(0), 1, 2, multiple_price_quantity),
+    ]
+}
+
+segment_meta! {
+    CTT,
+    "Transaction Totals",
+    "To transmit a hash total for a specific element in the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "354", "Number of Line Items", Mandatory, Numeric(0), 1, 6, number_of_line_items),
+        (2, "347", "Hash Total", Optional, Decimal, 1, 10, hash_total),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_04),
+        (5, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    CUR,
+    "Currency",
+    "To specify the currency (dollars, pounds, francs, etc.) used in a transaction",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code_02),
+        (3, "280", "Exchange Rate", Optional, Decimal, 4, 10, exchange_rate),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_04),
+        (5, "100", "Currency Code", Conditional, Id, 3, 3, currency_code_05),
+        (6, "669", "Currency Market/Exchange Code", Optional, Id, 3, 3, currency_market_exchange_code),
+        (7, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_07),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "337", "Time", Optional, Time, 4, 8, time_09),
+        (10, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_10),
+        (11, "373", "Date", Conditional, Date, 8, 8, date_11),
+        (12, "337", "Time", Conditional, Time, 4, 8, time_12),
+        (13, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_13),
+        (14, "373", "Date", Conditional, Date, 8, 8, date_14),
+        (15, "337", "Time", Conditional, Time, 4, 8, time_15),
+        (16, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_16),
+        (17, "373", "Date", Conditional, Date, 8, 8, date_17),
+        (18, "337", "Time", Conditional, Time, 4, 8, time_18),
+        (19, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_19),
+        (20, "373", "Date", Conditional, Date, 8, 8, date_20),
+        (21, "337", "Time", Conditional, Time, 4, 8, time_21),
+    ]
+}
+
+segment_meta! {
+    D9,
+    "Destination Station",
+    "To identify the rail destination of the shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_01),
+        (2, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name_02),
+        (3, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code_03),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code_04),
+        (5, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_05),
+        (6, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_06),
+        (7, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code_07),
+        (8, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_08),
+        (9, "116", "Postal Code", Optional, Id, 3, 15, postal_code_09),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_10),
+        (11, "116", "Postal Code", Optional, Id, 3, 15, postal_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+    ]
+}
+
+segment_meta! {
+    DMG,
+    "Demographic Information",
+    "To supply demographic information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (2, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (3, "1068", "Gender Code", Optional, Id, 1, 1, gender_code),
+        (4, "1067", "Marital Status Code", Optional, Id, 1, 1, marital_status_code),
+        (5, "1109", "Race or Ethnicity Code", Optional, Id, 1, 1, race_or_ethnicity_code),
+        (6, "1066", "Citizenship Status Code", Optional, Id, 1, 2, citizenship_status_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "659", "Basis of Verification Code", Optional, Id, 1, 2, basis_of_verification_code),
+        (9, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+    ]
+}
+
+segment_meta! {
+    DTM,
+    "Date/Time Reference",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (5, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (6, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+    ]
+}
+
+segment_meta! {
+    E1,
+    "Empty Car Disposition - Pended Destination Consignee",
+    "To identify the party receiving the empty car",
+    [_01, _02, _03],
+    [
+        (1, "459", "Name (30 Character Format)", Mandatory, AlphaNumeric, 2, 30, name_30_character_format),
+        (2, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+    ]
+}
+
+segment_meta! {
+    E4,
+    "Empty Car Disposition - Pended Destination City",
+    "To specify the geographic place of named party receiving the empty car",
+    [_01, _02, _03, _04],
+    [
+        (1, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    E5,
+    "Empty Car Disposition - Pended Destination Route",
+    "To specify the routing of the empty car",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (4, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+    ]
+}
+
+segment_meta! {
+    EA,
+    "Equipment Attributes",
+    "To specify attributes required for a piece of equipment",
+    [_01, _02, _03],
+    [
+        (1, "1092", "Attribute Code", Mandatory, Id, 1, 2, attribute_code),
+        (2, "1093", "Attribute Value", Optional, AlphaNumeric, 1, 30, attribute_value),
+        (3, "1094", "Attribute Description", Optional, AlphaNumeric, 1, 80, attribute_description),
+    ]
+}
+
+segment_meta! {
+    EFI,
+    "Electronic Format Identification",
+    "To provide basic information about the electronic format of the interchange data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "786", "Security Level Code", Mandatory, Id, 2, 2, security_level_code),
+        (2, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text),
+        (3, "797", "Security Technique Code", Optional, Id, 2, 2, security_technique_code),
+        (4, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_04),
+        (5, "802", "Program Identifier", Optional, AlphaNumeric, 1, 30, program_identifier),
+        (6, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_06),
+        (7, "801", "Interchange Format", Optional, AlphaNumeric, 1, 30, interchange_format),
+        (8, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_08),
+        (9, "800", "Compression Technique", Optional, AlphaNumeric, 1, 30, compression_technique),
+        (10, "789", "Drawing Sheet Size Code", Optional, AlphaNumeric, 2, 2, drawing_sheet_size_code),
+        (11, "803", "File Name", Optional, AlphaNumeric, 1, 64, file_name),
+        (12, "804", "Block Type", Optional, AlphaNumeric, 1, 4, block_type),
+        (13, "787", "Record Length", Optional, Numeric(0), 1, 15, record_length),
+        (14, "788", "Block Length", Optional, Numeric(0), 1, 5, block_length),
+        (15, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_15),
+        (16, "1570", "Filter ID Code", Conditional, Id, 3, 3, filter_id_code),
+    ]
+}
+
+segment_meta! {
+    EM,
+    "Equipment Characteristics",
+    "To send additional information regarding a specific piece of equipment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (2, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (3, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (4, "183", "Volume", Optional, Decimal, 1, 8, volume),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "1429", "Construction Type", Optional, Id, 1, 2, construction_type),
+        (7, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    ENT,
+    "Entity",
+    "To designate the entities which are parties to a transaction and specify a reference meaningful to those entities",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "554", "Assigned Number", Optional, Numeric(0), 1, 6, assigned_number),
+        (2, "98", "Entity Identifier Code", Conditional, Id, 2, 3, entity_identifier_code_02),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier_03),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_04),
+        (5, "98", "Entity Identifier Code", Conditional, Id, 2, 3, entity_identifier_code_05),
+        (6, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier_06),
+        (7, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_07),
+        (8, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (9, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+    ]
+}
+
+segment_meta! {
+    F9,
+    "Origin Station",
+    "To identify the rail origin of the shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_01),
+        (2, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name_02),
+        (3, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code_03),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code_04),
+        (5, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_05),
+        (6, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_06),
+        (7, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code_07),
+        (8, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_08),
+        (9, "116", "Postal Code", Optional, Id, 3, 15, postal_code_09),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_10),
+        (11, "116", "Postal Code", Optional, Id, 3, 15, postal_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+    ]
+}
+
+segment_meta! {
+    FA1,
+    "Type of Financial Accounting Data",
+    "To specify the organization controlling the content of the accounting citation, and the purpose associated with the accounting citation",
+    [_01, _02, _03],
+    [
+        (1, "559", "Agency Qualifier Code", Mandatory, Id, 2, 2, agency_qualifier_code),
+        (2, "1301", "Service, Promotion, Allowance, or Charge Code", Optional, Id, 4, 4, service_promotion_allowance_or_charge_code),
+        (3, "1212", "Allowance or Charge Indicator Code", Optional, Id, 1, 1, allowance_or_charge_indicator_code),
+    ]
+}
+
+segment_meta! {
+    FA2,
+    "Accounting Data",
+    "To specify the detailed accounting data",
+    [_01, _02],
+    [
+        (1, "1196", "Breakdown Structure Detail Code", Mandatory, Id, 2, 2, breakdown_structure_detail_code),
+        (2, "1195", "Financial Information Code", Mandatory, AlphaNumeric, 1, 80, financial_information_code),
+    ]
+}
+
+segment_meta! {
+    FOB,
+    "F.O.B. Related Instructions",
+    "To specify transportation instructions relating to shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (2, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier_02),
+        (3, "352", "Description", Optional, AlphaNumeric, 1, 80, description_03),
+        (4, "334", "Transportation Terms Qualifier Code", Optional, Id, 2, 2, transportation_terms_qualifier_code),
+        (5, "335", "Transportation Terms Code", Conditional, Id, 3, 3, transportation_terms_code),
+        (6, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier_06),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description_07),
+        (8, "54", "Risk of Loss Code", Optional, Id, 2, 2, risk_of_loss_code),
+        (9, "352", "Description", Conditional, AlphaNumeric, 1, 80, description_09),
+    ]
+}
+
+segment_meta! {
+    G3,
+    "Compensation Information",
+    "To convey brokerage, freight forwarder compensation, and other compensation information related to shipments",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "315", "Compensation Paid", Optional, Decimal, 2, 5, compensation_paid),
+        (2, "317", "Total Compensation Amount", Mandatory, Numeric(0), 3, 10, total_compensation_amount),
+        (3, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (4, "201", "Business Transaction Status", Optional, Id, 1, 3, business_transaction_status),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (6, "73", "Compensation Qualifier", Optional, Id, 1, 1, compensation_qualifier),
+    ]
+}
+
+segment_meta! {
+    G61,
+    "Contact",
+    "To identify a person or office to whom communications should be directed",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number),
+        (5, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    G62,
+    "Date/Time",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "432", "Date Qualifier", Conditional, Id, 2, 2, date_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "176", "Time Qualifier", Conditional, Id, 1, 2, time_qualifier),
+        (4, "337", "Time", Conditional, Time, 4, 8, time),
+        (5, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    G69,
+    "Line Item Detail - Description",
+    "To describe an item in free-form format",
+    [_01],
+    [
+        (1, "352", "Description", Mandatory, AlphaNumeric, 1, 45, description),
+    ]
+}
+
+segment_meta! {
+    GA,
+    "Canadian Grain Information",
+    "To transmit the transportation and distribution requirements of grain at Canadian ports",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "1275", "Fumigated/Cleaned Indicator", Optional, Id, 1, 1, fumigated_cleaned_indicator),
+        (2, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (3, "1576", "Inspected/Weighed Indicator Code", Optional, Id, 1, 2, inspected_weighed_indicator_code),
+        (4, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (5, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (6, "642", "Week", Optional, Numeric(0), 4, 4, week),
+        (7, "899", "Unload Terminal Elevator Code", Optional, Id, 3, 4, unload_terminal_elevator_code),
+        (8, "373", "Date", Optional, Date, 8, 8, date),
+        (9, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+        (10, "1276", "Machine Separable Indicator Code", Optional, Id, 2, 2, machine_separable_indicator_code),
+        (11, "1277", "Canadian Wheat Board (CWB) Marketing Class Code", Optional, Id, 1, 1, canadian_wheat_board_cwb_marketing_class_code),
+        (12, "1278", "Canadian Wheat Board (CWB) Marketing Class Type Code", Optional, Id, 1, 1, canadian_wheat_board_cwb_marketing_class_type_code),
+        (13, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (14, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (15, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (16, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier),
+        (17, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GR5,
+    "Loading Details",
+    "To provide loading details for equipment",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Mandatory, Id, 2, 3, special_handling_code),
+        (2, "752", "Surface/Layer/Position Code", Conditional, Id, 2, 2, surface_layer_position_code),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (5, "641", "Status Reason Code", Optional, Id, 3, 3, status_reason_code),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "Functional Group Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 8, 8, date),
+        (5, "337", "Time", Mandatory, Time, 4, 8, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    H1,
+    "Hazardous Material",
+    "To specify information relative to hazardous material",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "62", "Hazardous Material Code", Mandatory, AlphaNumeric, 4, 10, hazardous_material_code),
+        (2, "209", "Hazardous Material Class Code", Optional, AlphaNumeric, 1, 4, hazardous_material_class_code),
+        (3, "208", "Hazardous Material Code Qualifier", Optional, Id, 1, 1, hazardous_material_code_qualifier),
+        (4, "64", "Hazardous Material Description", Optional, AlphaNumeric, 2, 30, hazardous_material_description),
+        (5, "63", "Hazardous Material Contact", Optional, AlphaNumeric, 1, 24, hazardous_material_contact),
+        (6, "200", "Hazardous Materials Page", Optional, AlphaNumeric, 1, 6, hazardous_materials_page),
+        (7, "77", "Flashpoint Temperature", Conditional, Numeric(0), 1, 3, flashpoint_temperature),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (9, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+    ]
+}
+
+segment_meta! {
+    H2,
+    "Additional Hazardous Material Description",
+    "To specify free-form hazardous material descriptive data in addition to the information provided in the H1 segment",
+    [_01, _02],
+    [
+        (1, "64", "Hazardous Material Description", Mandatory, AlphaNumeric, 2, 30, hazardous_material_description),
+        (2, "274", "Hazardous Material Classification", Optional, AlphaNumeric, 1, 30, hazardous_material_classification),
+    ]
+}
+
+segment_meta! {
+    H3,
+    "Special Handling Instructions",
+    "To specify special handling instructions in coded or free-form format",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "153", "Special Handling Description", Conditional, AlphaNumeric, 2, 30, special_handling_description),
+        (3, "241", "Protective Service Code", Optional, Id, 1, 4, protective_service_code),
+        (4, "242", "Vent Instruction Code", Optional, Id, 1, 7, vent_instruction_code),
+        (5, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+    ]
+}
+
+segment_meta! {
+    HL,
+    "Hierarchical Level",
+    "To identify dependencies among and the content of hierarchically related groups of data segments",
+    [_01, _02, _03, _04],
+    [
+        (1, "628", "Hierarchical ID Number", Mandatory, AlphaNumeric, 1, 12, hierarchical_id_number),
+        (2, "734", "Hierarchical Parent ID Number", Optional, AlphaNumeric, 1, 12, hierarchical_parent_id_number),
+        (3, "735", "Hierarchical Level Code", Mandatory, Id, 1, 2, hierarchical_level_code),
+        (4, "736", "Hierarchical Child Code", Optional, Id, 1, 1, hierarchical_child_code),
+    ]
+}
+
+segment_meta! {
+    IC,
+    "Intermodal Chassis Equipment",
+    "To specify the chassis equipment details in terms of identifying numbers, weights, and ownership",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (3, "167", "Tare Weight", Conditional, Numeric(0), 3, 8, tare_weight),
+        (4, "571", "Tare Qualifier Code", Conditional, Id, 1, 1, tare_qualifier_code),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_05),
+        (6, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "845", "Chassis Type", Optional, Id, 2, 2, chassis_type),
+    ]
+}
+
+segment_meta! {
+    IM,
+    "Intermodal Movement Information",
+    "To specify the overall movement of a shipment",
+    [_01, _02, _03],
+    [
+        (1, "533", "Water Movement Code", Optional, Id, 1, 1, water_movement_code),
+        (2, "152", "Special Handling Code", Optional, Id, 2, 3, special_handling_code),
+        (3, "534", "Inland Transportation Code", Optional, Id, 2, 2, inland_transportation_code),
+    ]
+}
+
+segment_meta! {
+    INC,
+    "Installment Information",
+    "To specify installment billing arrangement",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "336", "Terms Type Code", Mandatory, Id, 2, 2, terms_type_code),
+        (2, "C001", "Composite Unit of Measure", Mandatory, Composite, 0, 0, composite_unit_of_measure),
+        (3, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_03),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_04),
+        (5, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (6, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+    ]
+}
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer",
+    "To define the end of an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header",
+    "To start and identify an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I10", "Interchange Control Standards Identifier", Mandatory, Id, 1, 1, interchange_control_standards_identifier),
+        (12, "I11", "Interchange Control Version Number", Mandatory, Id, 5, 5, interchange_control_version_number),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Usage Indicator", Mandatory, Id, 1, 1, usage_indicator),
+        (16, "I15", "Component Element Separator", Mandatory, Composite, 1, 1, component_element_separator),
+    ]
+}
+
+segment_meta! {
+    ISS,
+    "Invoice Shipment Summary",
+    "To specify summary details of total items shipped in terms of quantity, weight, and volume",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (2, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_02),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight_03),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_04),
+        (5, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (8, "81", "Weight", Optional, Decimal, 1, 10, weight_08),
+    ]
+}
+
+segment_meta! {
+    IT1,
+    "Baseline Item Data (Invoice)",
+    "To specify the basic and most frequently used line item data for the invoice and related transactions",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "358", "Quantity Invoiced", Conditional, Decimal, 1, 10, quantity_invoiced),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+    ]
+}
+
+segment_meta! {
+    IT3,
+    "Additional Item Data",
+    "To specify additional item details relating to variations between ordered and shipped quantities, or to specify alternate units of measures and quantities",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (2, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "368", "Shipment/Order Status Code", Conditional, Id, 2, 2, shipment_order_status_code),
+        (4, "383", "Quantity Difference", Optional, Decimal, 1, 9, quantity_difference),
+        (5, "371", "Change Reason Code", Optional, Id, 2, 2, change_reason_code),
+    ]
+}
+
+segment_meta! {
+    ITD,
+    "Terms of Sale/Deferred Terms of Sale",
+    "To specify terms of sale",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "336", "Terms Type Code", Optional, Id, 2, 2, terms_type_code),
+        (2, "333", "Terms Basis Date Code", Optional, Id, 1, 2, terms_basis_date_code),
+        (3, "338", "Terms Discount Percent", Optional, Decimal, 1, 6, terms_discount_percent),
+        (4, "370", "Terms Discount Due Date", Conditional, Date, 8, 8, terms_discount_due_date),
+        (5, "351", "Terms Discount Days Due", Conditional, Numeric(0), 1, 3, terms_discount_days_due),
+        (6, "446", "Terms Net Due Date", Optional, Date, 8, 8, terms_net_due_date),
+        (7, "386", "Terms Net Days", Optional, Numeric(0), 1, 3, terms_net_days),
+        (8, "362", "Terms Discount Amount", Optional, Numeric(2), 1, 10, terms_discount_amount),
+        (9, "388", "Terms Deferred Due Date", Optional, Date, 8, 8, terms_deferred_due_date),
+        (10, "389", "Deferred Amount Due", Conditional, Numeric(2), 1, 10, deferred_amount_due),
+        (11, "342", "Percent of Invoice Payable", Conditional, Decimal, 1, 5, percent_of_invoice_payable),
+        (12, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (13, "765", "Day of Month", Conditional, Numeric(0), 1, 2, day_of_month),
+        (14, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+        (15, "954", "Percent", Optional, Decimal, 1, 10, percent),
+    ]
+}
+
+segment_meta! {
+    K1,
+    "Remarks",
+    "To transmit information in a free-form format for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "61", "Free-Form Message", Mandatory, AlphaNumeric, 1, 30, free_form_message_01),
+        (2, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message_02),
+    ]
+}
+
+segment_meta! {
+    L0,
+    "Line Item - Quantity and Weight",
+    "To specify quantity, weight, volume, and type of service for a line item including applicable \"quantity/rate-as\" data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (3, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (4, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (5, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (6, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (7, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (8, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (9, "211", "Packaging Form Code", Conditional, Id, 3, 3, packaging_form_code_09),
+        (10, "458", "Dunnage Description", Optional, AlphaNumeric, 2, 25, dunnage_description),
+        (11, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (12, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (13, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (14, "211", "Packaging Form Code", Optional, Id, 3, 3, packaging_form_code_14),
+        (15, "1073", "Yes/No Condition or Response Code", Conditional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    L1,
+    "Rate and Charges",
+    "To specify rate and charges detail relative to a line item including freight charges, advances, special charges, and entitlements",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (3, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_03),
+        (4, "58", "Charge", Conditional, Numeric(2), 1, 12, charge),
+        (5, "191", "Advances", Conditional, Numeric(2), 1, 9, advances),
+        (6, "117", "Prepaid Amount", Conditional, Numeric(2), 1, 9, prepaid_amount),
+        (7, "120", "Rate Combination Point Code", Optional, AlphaNumeric, 3, 9, rate_combination_point_code),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "121", "Rate Class Code", Optional, Id, 1, 3, rate_class_code),
+        (10, "39", "Entitlement Code", Optional, Id, 1, 1, entitlement_code),
+        (11, "16", "Charge Method of Payment", Optional, Id, 1, 1, charge_method_of_payment),
+        (12, "276", "Special Charge Description", Optional, AlphaNumeric, 2, 25, special_charge_description),
+        (13, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+        (16, "372", "Lading Liability Code", Optional, Id, 1, 1, lading_liability_code),
+        (17, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (18, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (19, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (20, "100", "Currency Code", Optional, Id, 3, 3, currency_code),
+        (21, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+    ]
+}
+
+segment_meta! {
+    L3,
+    "Total Weight and Charges",
+    "To specify the total shipment in terms of weight, volume, rates, charges, advances, and prepaid amounts applicable to one or more line items",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (2, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (3, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (4, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_04),
+        (5, "58", "Charge", Optional, Numeric(2), 1, 12, charge),
+        (6, "191", "Advances", Optional, Numeric(2), 1, 9, advances),
+        (7, "117", "Prepaid Amount", Optional, Numeric(2), 1, 9, prepaid_amount),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (10, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (11, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (12, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (13, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+    ]
+}
+
+segment_meta! {
+    L4,
+    "Measurement",
+    "To describe physical ddimensions and quantities",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "82", "Length", Mandatory, Decimal, 1, 8, length),
+        (2, "189", "Width", Mandatory, Decimal, 1, 8, width),
+        (3, "65", "Height", Mandatory, Decimal, 1, 8, height),
+        (4, "90", "Measurement Unit Qualifier", Mandatory, Id, 1, 1, measurement_unit_qualifier),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (6, "271", "Industry Code", Optional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    L5,
+    "Description, Marks and Numbers",
+    "To specify the line item in terms of description, quantity, packaging, and marks and numbers",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (3, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_03),
+        (4, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_04),
+        (5, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (6, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers),
+        (7, "88", "Marks and Numbers Qualifier", Optional, Id, 1, 2, marks_and_numbers_qualifier),
+        (8, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_08),
+        (9, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_09),
+        (10, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+    ]
+}
+
+segment_meta! {
+    L7,
+    "Tariff Reference",
+    "To reference details of the tariff used to arrive at applicable rates or charge",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "168", "Tariff Agency Code", Optional, Id, 1, 4, tariff_agency_code),
+        (3, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (4, "172", "Tariff Section", Optional, AlphaNumeric, 1, 2, tariff_section),
+        (5, "169", "Tariff Item Number", Optional, AlphaNumeric, 1, 16, tariff_item_number),
+        (6, "170", "Tariff Item Part", Optional, Numeric(0), 1, 2, tariff_item_part),
+        (7, "59", "Freight Class Code", Optional, AlphaNumeric, 2, 5, freight_class_code),
+        (8, "173", "Tariff Supplement Identifier", Optional, AlphaNumeric, 1, 4, tariff_supplement_identifier),
+        (9, "46", "Ex Parte", Optional, AlphaNumeric, 4, 4, ex_parte),
+        (10, "373", "Date", Optional, Date, 8, 8, date),
+        (11, "119", "Rate Basis Number", Optional, AlphaNumeric, 1, 6, rate_basis_number),
+        (12, "227", "Tariff Column", Optional, AlphaNumeric, 1, 2, tariff_column),
+        (13, "294", "Tariff Distance", Optional, Numeric(0), 1, 5, tariff_distance),
+        (14, "295", "Distance Qualifier", Optional, Id, 1, 1, distance_qualifier),
+        (15, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (16, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    L11,
+    "Business Instructions and Reference Number",
+    "To specify instructions in this business relationship or a reference number",
+    [_01, _02, _03],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (2, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    LAD,
+    "Lading Detail",
+    "To transmit detailed lading data pertinent to a pickup or delivery",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "211", "Packaging Form Code", Conditional, Id, 3, 3, packaging_form_code),
+        (2, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (3, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code_03),
+        (4, "395", "Unit Weight", Conditional, Decimal, 1, 8, unit_weight),
+        (5, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code_05),
+        (6, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (7, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_07),
+        (8, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_08),
+        (9, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_09),
+        (10, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_11),
+        (12, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_12),
+        (13, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+    ]
+}
+
+segment_meta! {
+    LE,
+    "Loop Trailer",
+    "To indicate that the loop immediately preceding this segment is complete",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 6, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LEP,
+    "EPA Required Data",
+    "To specify the Environmental Protection Agency (EPA) information relating to shipments of hazardous material",
+    [_01, _02, _03, _04],
+    [
+        (1, "806", "EPA Waste Stream Number Code", Optional, Id, 4, 6, epa_waste_stream_number_code),
+        (2, "807", "Waste Characteristics Code", Optional, Id, 12, 16, waste_characteristics_code),
+        (3, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (4, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+    ]
+}
+
+segment_meta! {
+    LFH,
+    "Freeform Hazardous Material Information",
+    "To uniquely identify the variable information required by government regulation covering the transportation of hazardous material shipments",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "808", "Hazardous Material Shipment Information Qualifier", Mandatory, Id, 3, 3, hazardous_material_shipment_information_qualifier),
+        (2, "809", "Hazardous Material Shipment Information", Mandatory, AlphaNumeric, 1, 25, hazardous_material_shipment_information_02),
+        (3, "809", "Hazardous Material Shipment Information", Optional, AlphaNumeric, 1, 25, hazardous_material_shipment_information_03),
+        (4, "1023", "Hazard Zone Code", Optional, Id, 1, 1, hazard_zone_code),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity_07),
+    ]
+}
+
+segment_meta! {
+    LH1,
+    "Hazardous Identification Information",
+    "To specify the hazardous commodity identification reference number and quantity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code_01),
+        (2, "80", "Lading Quantity", Mandatory, Numeric(0), 1, 7, lading_quantity),
+        (3, "277", "UN/NA Identification Code", Optional, Id, 6, 6, un_na_identification_code),
+        (4, "200", "Hazardous Materials Page", Optional, AlphaNumeric, 1, 6, hazardous_materials_page),
+        (5, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (8, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+        (9, "665", "Residue Indicator Code", Optional, Id, 1, 1, residue_indicator_code),
+        (10, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (11, "1375", "Interim Hazardous Material Regulatory Number", Optional, AlphaNumeric, 1, 5, interim_hazardous_material_regulatory_number),
+    ]
+}
+
+segment_meta! {
+    LH2,
+    "Hazardous Classification Information",
+    "To specify the hazardous notation and endorsement information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "983", "Hazardous Class Qualifier", Optional, Id, 1, 1, hazardous_class_qualifier),
+        (3, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (4, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+        (5, "759", "Reportable Quantity Code", Optional, Id, 2, 2, reportable_quantity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_07),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_08),
+        (9, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_09),
+        (10, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_10),
+        (11, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_11),
+    ]
+}
+
+segment_meta! {
+    LH3,
+    "Hazardous Material Shipping Name",
+    "To specify the hazardous material shipping name and additional descriptive requirements",
+    [_01, _02, _03, _04],
+    [
+        (1, "224", "Hazardous Material Shipping Name", Conditional, AlphaNumeric, 1, 25, hazardous_material_shipping_name),
+        (2, "984", "Hazardous Material Shipping Name Qualifier", Conditional, Id, 1, 1, hazardous_material_shipping_name_qualifier),
+        (3, "985", "N.O.S. Indicator Code", Optional, Id, 3, 3, n_o_s_indicator_code),
+        (4, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    LH4,
+    "Canadian Dangerous Requirements",
+    "To specify additional Transport Canada requirements covering transportation of dangerous goods in Canada",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "238", "Emergency Response Plan Number", Optional, AlphaNumeric, 1, 12, emergency_response_plan_number),
+        (2, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_02),
+        (3, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (4, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_04),
+        (5, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_05),
+        (6, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_06),
+        (7, "271", "Subsidiary Risk Indicator", Optional, Id, 1, 2, subsidiary_risk_indicator),
+        (8, "267", "Net Explosive Quantity", Conditional, Numeric(0), 1, 6, net_explosive_quantity),
+        (9, "805", "Canadian Hazardous Notation", Optional, AlphaNumeric, 1, 25, canadian_hazardous_notation),
+        (10, "986", "Special Commodity Indicator Code", Optional, Id, 1, 1, special_commodity_indicator_code),
+        (11, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_11),
+        (12, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+    ]
+}
+
+segment_meta! {
+    LH6,
+    "Hazardous Certification",
+    "To specify the name of the person certifying that the shipment complies with the regulations and/or the actual certification",
+    [_01, _02, _03, _04],
+    [
+        (1, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (2, "272", "Hazardous Certification Code", Conditional, Id, 1, 1, hazardous_certification_code),
+        (3, "273", "Hazardous Certification Declaration", Conditional, AlphaNumeric, 1, 25, hazardous_certification_declaration_03),
+        (4, "273", "Hazardous Certification Declaration", Optional, AlphaNumeric, 1, 25, hazardous_certification_declaration_04),
+    ]
+}
+
+segment_meta! {
+    LHR,
+    "Hazardous Material Identifying Reference Numbers",
+    "To transmit specific hazardous material reference numbers",
+    [_01, _02, _03],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    LHT,
+    "Transborder Hazardous Requirements",
+    "To specify the placard information required by the second government agency when shipment is to cross into another country",
+    [_01, _02, _03],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (3, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+    ]
+}
+
+segment_meta! {
+    LIN,
+    "Item Identification",
+    "To specify basic item identification data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "235", "Product/Service ID Qualifier", Mandatory, Id, 2, 2, product_service_id_qualifier_02),
+        (3, "234", "Product/Service ID", Mandatory, AlphaNumeric, 1, 48, product_service_id_03),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+        (26, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_26),
+        (27, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_27),
+        (28, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_28),
+        (29, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_29),
+        (30, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_30),
+        (31, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_31),
+    ]
+}
+
+segment_meta! {
+    LM,
+    "Code Source Information",
+    "To transmit standard code list identification information",
+    [_01, _02],
+    [
+        (1, "559", "Agency Qualifier Code", Mandatory, Id, 2, 2, agency_qualifier_code),
+        (2, "822", "Source Subqualifier", Optional, AlphaNumeric, 1, 15, source_subqualifier),
+    ]
+}
+
+segment_meta! {
+    LQ,
+    "Industry Code",
+    "Code to transmit standard industry codes",
+    [_01, _02],
+    [
+        (1, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (2, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    LS,
+    "Loop Header",
+    "To indicate that the next segment begins a loop",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 6, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LX,
+    "Assigned Number",
+    "To reference a line number in a transaction set",
+    [_01],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+    ]
+}
+
+segment_meta! {
+    M0,
+    "Letter of Credit Reference",
+    "To transmit letter of credit details",
+    [_01, _02, _03, _04],
+    [
+        (1, "250", "Letter of Credit Number", Mandatory, AlphaNumeric, 2, 40, letter_of_credit_number),
+        (2, "373", "Date", Optional, Date, 8, 8, date_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "373", "Date", Optional, Date, 8, 8, date_04),
+    ]
+}
+
+segment_meta! {
+    M1,
+    "Insurance",
+    "To specify details related to insurance",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "26", "Country Code", Mandatory, Id, 2, 3, country_code),
+        (2, "14", "Carriage Value", Optional, Numeric(0), 2, 8, carriage_value),
+        (3, "74", "Declared Value", Optional, Numeric(2), 2, 12, declared_value),
+        (4, "122", "Rate/Value Qualifier", Optional, Id, 2, 2, rate_value_qualifier_04),
+        (5, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (6, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message),
+        (7, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_07),
+        (8, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (9, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier_09),
+        (10, "954", "Percent", Conditional, Decimal, 1, 10, percent_10),
+        (11, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier_11),
+        (12, "954", "Percent", Conditional, Decimal, 1, 10, percent_12),
+    ]
+}
+
+segment_meta! {
+    M3,
+    "Release",
+    "To indicate that the equipment is or is not to be released",
+    [_01, _02, _03, _04],
+    [
+        (1, "132", "Release Code", Mandatory, Id, 1, 1, release_code),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    M7,
+    "Seal Numbers",
+    "To record seal numbers used and the organization that applied the seals",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "225", "Seal Number", Mandatory, AlphaNumeric, 2, 15, seal_number_01),
+        (2, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_02),
+        (3, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_03),
+        (4, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_04),
+        (5, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+    ]
+}
+
+segment_meta! {
+    M10,
+    "Manifest Identifying Information",
+    "To transmit manifest identifying information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+        (3, "26", "Country Code", Mandatory, Id, 2, 3, country_code),
+        (4, "597", "Vessel Code", Conditional, Id, 1, 8, vessel_code),
+        (5, "182", "Vessel Name", Conditional, AlphaNumeric, 2, 28, vessel_name),
+        (6, "55", "Flight/Voyage Number", Mandatory, AlphaNumeric, 2, 10, flight_voyage_number),
+        (7, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_07),
+        (8, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (9, "256", "Manifest Type Code", Mandatory, Id, 1, 1, manifest_type_code),
+        (10, "897", "Vessel Code Qualifier", Conditional, Id, 1, 1, vessel_code_qualifier),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (12, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_12),
+    ]
+}
+
+segment_meta! {
+    M11,
+    "Manifest Bill of Lading Details",
+    "To transmit bill of lading detail information for a manifest",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19],
+    [
+        (1, "598", "Bill of Lading/Waybill Number", Mandatory, AlphaNumeric, 1, 12, bill_of_lading_waybill_number_01),
+        (2, "310", "Location Identifier", Mandatory, AlphaNumeric, 1, 30, location_identifier),
+        (3, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (4, "599", "Manifest Unit Code", Mandatory, Id, 1, 3, manifest_unit_code),
+        (5, "81", "Weight", Mandatory, Decimal, 1, 10, weight),
+        (6, "188", "Weight Unit Code", Mandatory, Id, 1, 1, weight_unit_code),
+        (7, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (8, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (9, "582", "Bill of Lading Type Code", Optional, Id, 2, 2, bill_of_lading_type_code),
+        (10, "600", "Place of Receipt by Pre-carrier", Optional, AlphaNumeric, 1, 17, place_of_receipt_by_pre_carrier),
+        (11, "598", "Bill of Lading/Waybill Number", Conditional, AlphaNumeric, 1, 12, bill_of_lading_waybill_number_11),
+        (12, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code_13),
+        (14, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code_14),
+        (15, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code_15),
+        (16, "1302", "Shipper's Export Declaration Requirements", Optional, AlphaNumeric, 1, 2, shippers_export_declaration_requirements),
+        (17, "1578", "Export Exception Code", Optional, Id, 2, 2, export_exception_code),
+        (18, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code_18),
+        (19, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_19),
+    ]
+}
+
+segment_meta! {
+    M12,
+    "In-bond Identifying Information",
+    "To transmit in-bond information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "581", "Customs Entry Type Code", Mandatory, Id, 2, 2, customs_entry_type_code),
+        (2, "601", "Customs Entry Number", Conditional, AlphaNumeric, 1, 15, customs_entry_number),
+        (3, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier_03),
+        (4, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier_04),
+        (5, "602", "Customs Shipment Value", Optional, AlphaNumeric, 2, 8, customs_shipment_value),
+        (6, "603", "In-bond Control Number", Conditional, AlphaNumeric, 1, 25, in_bond_control_number),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (9, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (10, "91", "Transportation Method/Type Code", Conditional, Id, 1, 2, transportation_method_type_code),
+        (11, "182", "Vessel Name", Conditional, AlphaNumeric, 2, 28, vessel_name),
+    ]
+}
+
+segment_meta! {
+    M13,
+    "Manifest Amendment Details",
+    "To correct a manifest record prior to conveyance arrival or to amend a manifest record after conveyance arrival",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code_01),
+        (2, "310", "Location Identifier", Mandatory, AlphaNumeric, 1, 30, location_identifier),
+        (3, "580", "Amendment Type Code", Optional, Id, 1, 1, amendment_type_code),
+        (4, "598", "Bill of Lading/Waybill Number", Mandatory, AlphaNumeric, 1, 12, bill_of_lading_waybill_number_04),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (6, "393", "Amendment Code", Optional, Id, 2, 2, amendment_code),
+        (7, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (8, "598", "Bill of Lading/Waybill Number", Conditional, AlphaNumeric, 1, 12, bill_of_lading_waybill_number_08),
+        (9, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code_09),
+        (10, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code_10),
+    ]
+}
+
+segment_meta! {
+    MAN,
+    "Marks and Numbers",
+    "To indicate identifying marks and numbers for shipping containers",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "88", "Marks and Numbers Qualifier", Mandatory, Id, 1, 2, marks_and_numbers_qualifier_01),
+        (2, "87", "Marks and Numbers", Mandatory, AlphaNumeric, 1, 48, marks_and_numbers_02),
+        (3, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_03),
+        (4, "88", "Marks and Numbers Qualifier", Conditional, Id, 1, 2, marks_and_numbers_qualifier_04),
+        (5, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers_05),
+        (6, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_06),
+    ]
+}
+
+segment_meta! {
+    MEA,
+    "Measurements",
+    "To specify physical measurements or counts, including dimensions, tolerances, variances, and weights (See Figures Appendix for example of use of C001)",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "737", "Measurement Reference ID Code", Optional, Id, 2, 2, measurement_reference_id_code),
+        (2, "738", "Measurement Qualifier", Optional, Id, 1, 3, measurement_qualifier),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (5, "740", "Range Minimum", Conditional, Decimal, 1, 20, range_minimum),
+        (6, "741", "Range Maximum", Conditional, Decimal, 1, 20, range_maximum),
+        (7, "935", "Measurement Significance Code", Optional, Id, 2, 2, measurement_significance_code),
+        (8, "936", "Measurement Attribute Code", Conditional, Id, 2, 2, measurement_attribute_code),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (10, "1373", "Measurement Method or Device", Optional, Id, 2, 4, measurement_method_or_device),
+    ]
+}
+
+segment_meta! {
+    MS1,
+    "Equipment, Shipment, or Real Property Location",
+    "To specify the location of a piece of equipment, a shipment, or real property in terms of city and state or longitude and latitude",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (3, "26", "Country Code", Conditional, Id, 2, 3, country_code),
+        (4, "1654", "Longitude Code", Conditional, Id, 7, 7, longitude_code),
+        (5, "1655", "Latitude Code", Conditional, Id, 7, 7, latitude_code),
+        (6, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_06),
+        (7, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_07),
+    ]
+}
+
+segment_meta! {
+    MS2,
+    "Equipment or Container Owner and Type",
+    "To specify the owner, the identification number assigned by that owner, and the type of equipment",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+        (3, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (4, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+    ]
+}
+
+segment_meta! {
+    MS3,
+    "Interline Information",
+    "To identify the interline carrier and relevant data",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (4, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (5, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    MSG,
+    "Message Text",
+    "To provide a free-form format that allows the transmission of text information",
+    [_01, _02, _03],
+    [
+        (1, "933", "Free-Form Message Text", Mandatory, AlphaNumeric, 1, 264, free_form_message_text),
+        (2, "934", "Printer Carriage Control Code", Conditional, Id, 2, 2, printer_carriage_control_code),
+        (3, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    N1,
+    "Name",
+    "To identify a party by type of organization, name, and code",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "706", "Entity Relationship Code", Optional, Id, 2, 2, entity_relationship_code),
+        (6, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_06),
+    ]
+}
+
+segment_meta! {
+    N2,
+    "Additional Name Information",
+    "To specify additional names or those longer than 35 characters in length",
+    [_01, _02],
+    [
+        (1, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name_02),
+    ]
+}
+
+segment_meta! {
+    N3,
+    "Address Information",
+    "To specify the location of the named party",
+    [_01, _02],
+    [
+        (1, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_01),
+        (2, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_02),
+    ]
+}
+
+segment_meta! {
+    N4,
+    "Geographic Location",
+    "To specify the geographic place of the named party",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (5, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    N5,
+    "Equipment Ordered",
+    "To specify carrier equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (2, "233", "Weight Capacity", Optional, Numeric(0), 2, 3, weight_capacity),
+        (3, "203", "Cubic Capacity", Optional, Numeric(0), 2, 4, cubic_capacity),
+        (4, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+        (5, "216", "Metric Qualifier", Optional, Id, 1, 1, metric_qualifier),
+        (6, "65", "Height", Optional, Decimal, 1, 8, height),
+        (7, "643", "Lading Percentage", Conditional, Numeric(2), 2, 4, lading_percentage),
+        (8, "644", "Lading Percent Qualifier", Conditional, Id, 1, 1, lading_percent_qualifier),
+        (9, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+    ]
+}
+
+segment_meta! {
+    N7,
+    "Equipment Details",
+    "To identify the equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24],
+    [
+        (1, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (5, "167", "Tare Weight", Conditional, Numeric(0), 3, 8, tare_weight),
+        (6, "232", "Weight Allowance", Optional, Numeric(0), 2, 6, weight_allowance),
+        (7, "205", "Dunnage", Optional, Numeric(0), 1, 6, dunnage),
+        (8, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (9, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (10, "102", "Ownership Code", Optional, Id, 1, 1, ownership_code),
+        (11, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (12, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "319", "Temperature Control", Optional, AlphaNumeric, 3, 6, temperature_control),
+        (14, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (15, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (16, "571", "Tare Qualifier Code", Conditional, Id, 1, 1, tare_qualifier_code),
+        (17, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (18, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+        (19, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (20, "65", "Height", Optional, Decimal, 1, 8, height),
+        (21, "189", "Width", Optional, Decimal, 1, 8, width),
+        (22, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (23, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_23),
+        (24, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+    ]
+}
+
+segment_meta! {
+    N7A,
+    "Accessorial Equipment Details",
+    "To identify the accessorial equipment required to load or unload product",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1042", "Load or Device Code", Optional, Id, 2, 2, load_or_device_code),
+        (2, "82", "Length", Optional, Decimal, 1, 8, length),
+        (3, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_03),
+        (4, "1044", "Hose Type Code", Optional, Id, 3, 3, hose_type_code),
+        (5, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_05),
+        (6, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_06),
+        (7, "1045", "Inlet or Outlet Material Type Code", Optional, Id, 2, 2, inlet_or_outlet_material_type_code),
+        (8, "1046", "Inlet or Outlet Fitting Type Code", Optional, Id, 2, 2, inlet_or_outlet_fitting_type_code),
+        (9, "1047", "Miscellaneous Equipment Code", Optional, Id, 2, 2, miscellaneous_equipment_code),
+    ]
+}
+
+segment_meta! {
+    N7B,
+    "Additional Equipment Details",
+    "To identify additional equipment details",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1024", "Number of Tank Compartments", Optional, Numeric(0), 1, 2, number_of_tank_compartments),
+        (2, "1025", "Loading or Discharge Location Code", Optional, Id, 1, 1, loading_or_discharge_location_code),
+        (3, "1026", "Vessel Material Code", Optional, Id, 3, 3, vessel_material_code),
+        (4, "1030", "Gasket Type Code", Optional, Id, 3, 3, gasket_type_code),
+        (5, "1031", "Trailer Lining Type Code", Optional, Id, 3, 3, trailer_lining_type_code),
+        (6, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+    ]
+}
+
+segment_meta! {
+    N9,
+    "Reference Identification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "369", "Free-form Description", Conditional, AlphaNumeric, 1, 45, free_form_description),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Conditional, Time, 4, 8, time),
+        (6, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (7, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    N10,
+    "Quantity and Description",
+    "To indicate line item quantity, description, marks and numbers, commodity code, weight, and customs value",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (2, "369", "Free-form Description", Optional, AlphaNumeric, 1, 45, free_form_description),
+        (3, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers),
+        (4, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier),
+        (5, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code),
+        (6, "602", "Customs Shipment Value", Conditional, AlphaNumeric, 2, 8, customs_shipment_value),
+        (7, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (8, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (9, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (10, "599", "Manifest Unit Code", Optional, Id, 1, 3, manifest_unit_code),
+        (11, "26", "Country Code", Optional, Id, 2, 3, country_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+        (13, "100", "Currency Code", Conditional, Id, 3, 3, currency_code),
+    ]
+}
+
+segment_meta! {
+    N12,
+    "Equipment Environment",
+    "To describe the operating environment of the equipment",
+    [_01, _02],
+    [
+        (1, "829", "Fuel Type", Mandatory, Id, 1, 1, fuel_type),
+        (2, "C001", "Composite Unit of Measure", Mandatory, Composite, 0, 0, composite_unit_of_measure),
+    ]
+}
+
+segment_meta! {
+    NA,
+    "Cross-Reference Equipment",
+    "To cross-reference additional equipment to a primary piece of equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (4, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (5, "231", "Cross Reference Type Code", Optional, Id, 1, 1, cross_reference_type_code),
+        (6, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (9, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_09),
+        (10, "845", "Chassis Type", Optional, Id, 2, 2, chassis_type),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    NM1,
+    "Individual or Organizational Name",
+    "To supply the full name of an individual or organizational entity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "1065", "Entity Type Qualifier", Mandatory, Id, 1, 1, entity_type_qualifier),
+        (3, "1035", "Name Last or Organization Name", Optional, AlphaNumeric, 1, 35, name_last_or_organization_name),
+        (4, "1036", "Name First", Optional, AlphaNumeric, 1, 25, name_first),
+        (5, "1037", "Name Middle", Optional, AlphaNumeric, 1, 25, name_middle),
+        (6, "1038", "Name Prefix", Optional, AlphaNumeric, 1, 10, name_prefix),
+        (7, "1039", "Name Suffix", Optional, AlphaNumeric, 1, 10, name_suffix),
+        (8, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (10, "706", "Entity Relationship Code", Conditional, Id, 2, 2, entity_relationship_code),
+        (11, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_11),
+    ]
+}
+
+segment_meta! {
+    NTE,
+    "Note/Special Instruction",
+    "To transmit information in a free-form format, if necessary, for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "363", "Note Reference Code", Optional, Id, 3, 3, note_reference_code),
+        (2, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    OID,
+    "Order Identification Detail NEW",
+    "To specify order identification detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_01),
+        (2, "324", "Purchase Order Number", Conditional, AlphaNumeric, 1, 22, purchase_order_number),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_03),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (5, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (6, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (7, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (8, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+    ]
+}
+
+segment_meta! {
+    OTI,
+    "Original Transaction Identification",
+    "To identify the edited transaction set and the level at which the results of the edit are reported, and to indicate the accepted, rejected, or accepted-with-change edit result",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "110", "Application Acknowledgment Code", Mandatory, Id, 1, 2, application_acknowledgment_code),
+        (2, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (3, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification),
+        (4, "142", "Application Sender's Code", Optional, AlphaNumeric, 2, 15, application_senders_code),
+        (5, "124", "Application Receiver's Code", Optional, AlphaNumeric, 2, 15, application_receivers_code),
+        (6, "373", "Date", Optional, Date, 8, 8, date),
+        (7, "337", "Time", Optional, Time, 4, 8, time),
+        (8, "28", "Group Control Number", Conditional, Numeric(0), 1, 9, group_control_number),
+        (9, "329", "Transaction Set Control Number", Optional, AlphaNumeric, 4, 9, transaction_set_control_number),
+        (10, "143", "Transaction Set Identifier Code", Optional, Id, 3, 3, transaction_set_identifier_code),
+        (11, "480", "Version / Release / Industry Identifier Code", Optional, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+        (12, "353", "Transaction Set Purpose Code", Optional, Id, 2, 2, transaction_set_purpose_code),
+        (13, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (14, "346", "Application Type", Optional, Id, 2, 2, application_type),
+        (15, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (16, "305", "Transaction Handling Code", Optional, Id, 1, 2, transaction_handling_code),
+        (17, "633", "Purpose Code", Optional, Id, 2, 2, purpose_code),
+    ]
+}
+
+segment_meta! {
+    P4,
+    "U.S. Port Information",
+    "To transmit identifying information for a U.S. port",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "310", "Location Identifier", Mandatory, AlphaNumeric, 1, 30, location_identifier_01),
+        (2, "373", "Date", Mandatory, Date, 8, 8, date),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (4, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier_04),
+        (5, "337", "Time", Optional, Time, 4, 8, time),
+    ]
+}
+
+segment_meta! {
+    P5,
+    "Port Information",
+    "To indicate port-related data",
+    [_01, _02, _03],
+    [
+        (1, "115", "Port or Terminal Function Code", Mandatory, Id, 1, 1, port_or_terminal_function_code),
+        (2, "309", "Location Qualifier", Mandatory, Id, 1, 2, location_qualifier),
+        (3, "310", "Location Identifier", Mandatory, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    PAM,
+    "Period Amount",
+    "To indicate a quantity, and/or amount for an identified period",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "673", "Quantity Qualifier", Conditional, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (4, "344", "Unit of Time Period or Interval", Conditional, Id, 2, 2, unit_of_time_period_or_interval_04),
+        (5, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier),
+        (6, "373", "Date", Conditional, Date, 8, 8, date_06),
+        (7, "337", "Time", Optional, Time, 4, 8, time_07),
+        (8, "373", "Date", Conditional, Date, 8, 8, date_08),
+        (9, "337", "Time", Optional, Time, 4, 8, time_09),
+        (10, "522", "Amount Qualifier Code", Conditional, Id, 1, 3, amount_qualifier_code),
+        (11, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (12, "344", "Unit of Time Period or Interval", Conditional, Id, 2, 2, unit_of_time_period_or_interval_12),
+        (13, "1345", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier),
+        (14, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+        (15, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    PER,
+    "Administrative Communications Contact",
+    "To identify a person or office to whom administrative communications should be directed",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_03),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_04),
+        (5, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_05),
+        (6, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_06),
+        (7, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_07),
+        (8, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_08),
+        (9, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    PI,
+    "Price Authority Identification",
+    "To communicate basis of pricing, such as contract number, quote number, or tariff number",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification),
+        (3, "436", "Primary Publication Authority Code", Optional, Id, 2, 2, primary_publication_authority_code),
+        (4, "930", "Regulatory Agency Code", Optional, Id, 3, 5, regulatory_agency_code),
+        (5, "168", "Tariff Agency Code", Optional, Id, 1, 4, tariff_agency_code),
+        (6, "965", "Issuing Carrier Identifier", Optional, AlphaNumeric, 1, 10, issuing_carrier_identifier),
+        (7, "660", "Contract Suffix", Optional, AlphaNumeric, 1, 2, contract_suffix_07),
+        (8, "169", "Tariff Item Number", Optional, AlphaNumeric, 1, 16, tariff_item_number),
+        (9, "173", "Tariff Supplement Identifier", Optional, AlphaNumeric, 1, 4, tariff_supplement_identifier),
+        (10, "172", "Tariff Section", Optional, AlphaNumeric, 1, 2, tariff_section),
+        (11, "660", "Contract Suffix", Optional, AlphaNumeric, 1, 2, contract_suffix_11),
+        (12, "373", "Date", Conditional, Date, 8, 8, date_12),
+        (13, "373", "Date", Conditional, Date, 8, 8, date_13),
+        (14, "629", "Alternation Precedence Code", Optional, Id, 1, 1, alternation_precedence_code_14),
+        (15, "629", "Alternation Precedence Code", Optional, Id, 1, 1, alternation_precedence_code_15),
+    ]
+}
+
+segment_meta! {
+    PID,
+    "Product/Item Description",
+    "To describe a product or process in coded or free-form format",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "349", "Item Description Type", Mandatory, Id, 1, 1, item_description_type),
+        (2, "750", "Product/Process Characteristic Code", Optional, Id, 2, 3, product_process_characteristic_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "751", "Product Description Code", Conditional, AlphaNumeric, 1, 12, product_description_code),
+        (5, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (6, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (7, "822", "Source Subqualifier", Optional, AlphaNumeric, 1, 15, source_subqualifier),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (9, "819", "Language Code", Optional, Id, 2, 3, language_code),
+    ]
+}
+
+segment_meta! {
+    PKG,
+    "Marking, Packaging, Loading",
+    "To describe marking, packaging, loading, and unloading requirements",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "349", "Item Description Type", Conditional, Id, 1, 1, item_description_type),
+        (2, "753", "Packaging Characteristic Code", Optional, Id, 1, 5, packaging_characteristic_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "754", "Packaging Description Code", Conditional, AlphaNumeric, 1, 7, packaging_description_code),
+        (5, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (6, "400", "Unit Load Option Code", Optional, Id, 2, 2, unit_load_option_code),
+    ]
+}
+
+segment_meta! {
+    PLD,
+    "Pallet Information NEW",
+    "To specify pallet information including quantity, exchange, and weight",
+    [_01, _02, _03, _04],
+    [
+        (1, "406", "Quantity of Pallets Shipped", Mandatory, Numeric(0), 1, 3, quantity_of_pallets_shipped),
+        (2, "399", "Pallet Exchange Code", Optional, Id, 1, 1, pallet_exchange_code),
+        (3, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (4, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+    ]
+}
+
+segment_meta! {
+    PO1,
+    "Baseline Item Data",
+    "To specify basic and most frequently used line item data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "330", "Quantity Ordered", Conditional, Decimal, 1, 15, quantity_ordered),
+        (3, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+    ]
+}
+
+segment_meta! {
+    PO3,
+    "Additional Item Detail",
+    "To specify additional item-related data involving variations in normal price/quantity structure",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "371", "Change Reason Code", Mandatory, Id, 2, 2, change_reason_code),
+        (2, "373", "Date", Optional, Date, 8, 8, date),
+        (3, "236", "Price Identifier Code", Conditional, Id, 3, 3, price_identifier_code),
+        (4, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Conditional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (7, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (8, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    PO4,
+    "Item Physical Details",
+    "To specify the physical qualities, packaging, weights, and dimensions relating to the item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+        (1, "356", "Pack", Optional, Numeric(0), 1, 6, pack),
+        (2, "357", "Size", Conditional, Decimal, 1, 8, size),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "103", "Packaging Code", Conditional, AlphaNumeric, 3, 5, packaging_code),
+        (5, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (6, "384", "Gross Weight per Pack", Conditional, Decimal, 1, 9, gross_weight_per_pack),
+        (7, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_07),
+        (8, "385", "Gross Volume per Pack", Conditional, Decimal, 1, 9, gross_volume_per_pack),
+        (9, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_09),
+        (10, "82", "Length", Conditional, Decimal, 1, 8, length),
+        (11, "189", "Width", Conditional, Decimal, 1, 8, width),
+        (12, "65", "Height", Conditional, Decimal, 1, 8, height),
+        (13, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_13),
+        (14, "810", "Inner Pack", Optional, Numeric(0), 1, 6, inner_pack),
+        (15, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (16, "350", "Assigned Identification", Conditional, AlphaNumeric, 1, 20, assigned_identification_16),
+        (17, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification_17),
+        (18, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    POC,
+    "Line Item Change",
+    "To specify changes to a line item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "670", "Change or Response Type Code", Mandatory, Id, 2, 2, change_or_response_type_code),
+        (3, "330", "Quantity Ordered", Conditional, Decimal, 1, 15, quantity_ordered),
+        (4, "671", "Quantity Left to Receive", Conditional, Decimal, 1, 9, quantity_left_to_receive),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (7, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+        (26, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_26),
+        (27, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_27),
+    ]
+}
+
+segment_meta! {
+    POD,
+    "Proof of Delivery",
+    "To supply proof of delivery information",
+    [_01, _02, _03],
+    [
+        (1, "373", "Date", Mandatory, Date, 8, 8, date),
+        (2, "337", "Time", Optional, Time, 4, 8, time),
+        (3, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name),
+    ]
+}
+
+segment_meta! {
+    PRF,
+    "Purchase Order Reference",
+    "To provide reference to a specific purchase order",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (2, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (3, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (6, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (7, "92", "Purchase Order Type Code", Optional, Id, 2, 2, purchase_order_type_code),
+    ]
+}
+
+segment_meta! {
+    PS,
+    "Protective Service Instructions",
+    "To specify mechanical protective service and ventilation instructions",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "746", "Protective Service Rule Code", Mandatory, Id, 3, 9, protective_service_rule_code),
+        (2, "241", "Protective Service Code", Mandatory, Id, 1, 4, protective_service_code),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_04),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code),
+        (7, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (8, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (9, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (10, "745", "Pre-Cooled (Rule 710) Code", Optional, Id, 1, 1, pre_cooled_rule_710_code),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_11),
+        (12, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_12),
+        (13, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_13),
+        (14, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_14),
+    ]
+}
+
+segment_meta! {
+    PWK,
+    "Paperwork",
+    "To identify the type or transmission or both of paperwork or supporting information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "755", "Report Type Code", Mandatory, Id, 2, 2, report_type_code),
+        (2, "756", "Report Transmission Code", Optional, Id, 1, 2, report_transmission_code),
+        (3, "757", "Report Copies Needed", Optional, Numeric(0), 1, 2, report_copies_needed),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (5, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (6, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (8, "C002", "Actions Indicated", Optional, Composite, 0, 0, actions_indicated),
+        (9, "1525", "Request Category Code", Optional, Id, 1, 2, request_category_code),
+    ]
+}
+
+segment_meta! {
+    Q2,
+    "Status Details (Ocean)",
+    "To transmit identifying information relative to identification of vessel, transportation dates, lading quantity, weight, and cube",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "597", "Vessel Code", Optional, Id, 1, 8, vessel_code),
+        (2, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "373", "Date", Optional, Date, 8, 8, date_04),
+        (5, "373", "Date", Optional, Date, 8, 8, date_05),
+        (6, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (7, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (8, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (9, "55", "Flight/Voyage Number", Optional, AlphaNumeric, 2, 10, flight_voyage_number),
+        (10, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (11, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (12, "897", "Vessel Code Qualifier", Optional, Id, 1, 1, vessel_code_qualifier),
+        (13, "182", "Vessel Name", Optional, AlphaNumeric, 2, 28, vessel_name),
+        (14, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (15, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (16, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+    ]
+}
+
+segment_meta! {
+    Q5,
+    "Status Details",
+    "To specify the status of the shipment in terms of dates, time, reference numbers, and location",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+        (1, "157", "Shipment Status Code", Optional, Id, 1, 2, shipment_status_code),
+        (2, "373", "Date", Optional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Conditional, Id, 2, 2, time_code),
+        (5, "641", "Status Reason Code", Optional, Id, 3, 3, status_reason_code),
+        (6, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (7, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (8, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (9, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (10, "207", "Equipment Number", Optional, AlphaNumeric, 1, 10, equipment_number),
+        (11, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier_11),
+        (12, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_12),
+        (13, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_13),
+        (14, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier_14),
+        (15, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification_15),
+        (16, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_16),
+        (17, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (18, "108", "Pick-up or Delivery Code", Optional, Id, 1, 2, pick_up_or_delivery_code),
+    ]
+}
+
+segment_meta! {
+    Q7,
+    "Lading Exception Code",
+    "To specify the status of the shipment in terms of lading exception information",
+    [_01, _02, _03],
+    [
+        (1, "33", "Lading Exception Code", Mandatory, Id, 1, 1, lading_exception_code),
+        (2, "211", "Packaging Form Code", Optional, Id, 3, 3, packaging_form_code),
+        (3, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+    ]
+}
+
+segment_meta! {
+    QTY,
+    "Quantity",
+    "To specify quantity information",
+    [_01, _02, _03, _04],
+    [
+        (1, "673", "Quantity Qualifier", Mandatory, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "C001", "Composite Unit of Measure", Optional, Composite, 0, 0, composite_unit_of_measure),
+        (4, "61", "Free-Form Message", Conditional, AlphaNumeric, 1, 30, free_form_message),
+    ]
+}
+
+segment_meta! {
+    R2,
+    "Route Information",
+    "To specify carrier and routing sequences and details",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (4, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (5, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (6, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (7, "296", "Intermediate Switch Carrier", Conditional, Id, 2, 4, intermediate_switch_carrier_07),
+        (8, "296", "Intermediate Switch Carrier", Optional, Id, 2, 4, intermediate_switch_carrier_08),
+        (9, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number),
+        (10, "373", "Date", Optional, Date, 8, 8, date),
+        (11, "369", "Free-form Description", Optional, AlphaNumeric, 1, 45, free_form_description),
+        (12, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (13, "742", "Route Description", Optional, AlphaNumeric, 1, 35, route_description),
+    ]
+}
+
+segment_meta! {
+    R2A,
+    "Route Information with Preference",
+    "To specify the responsibilities and carrier preference",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (2, "1431", "Preference", Mandatory, Id, 1, 1, preference),
+        (3, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (4, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (5, "309", "Location Qualifier", Optional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (7, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (8, "1", "Route Code", Optional, AlphaNumeric, 1, 13, route_code),
+        (9, "742", "Route Description", Optional, AlphaNumeric, 1, 35, route_description),
+        (10, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+    ]
+}
+
+segment_meta! {
+    R3,
+    "Route Information - Motor",
+    "To specify carrier and routing sequences and details",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (4, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (5, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code),
+        (6, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number),
+        (7, "373", "Date", Optional, Date, 8, 8, date),
+        (8, "1", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (9, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    R4,
+    "Port or Terminal",
+    "Contractual or operational port or point relevant to the movement of the cargo",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "115", "Port or Terminal Function Code", Mandatory, Id, 1, 1, port_or_terminal_function_code),
+        (2, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (3, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (4, "114", "Port Name", Optional, AlphaNumeric, 2, 24, port_name),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "174", "Terminal Name", Optional, AlphaNumeric, 2, 30, terminal_name),
+        (7, "113", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (8, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    R9,
+    "Route Code",
+    "To specify the route using a single code",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "1", "Route Code", Mandatory, AlphaNumeric, 1, 13, route_code),
+        (2, "192", "Agent/Shipper Routing Code", Optional, Id, 1, 1, agent_shipper_routing_code),
+        (3, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (4, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_04),
+        (5, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (6, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_06),
+        (7, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_07),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_08),
+    ]
+}
+
+segment_meta! {
+    RED,
+    "Related Data",
+    "To relate data that is associated with a previously defined error condition",
+    [_01, _02, _03],
+    [
+        (1, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+        (2, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (3, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    REF,
+    "Reference Identification",
+    "To specify identifying information",
+    [_01, _02, _03, _04],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (4, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    RMR,
+    "Remittance Advice Accounts Receivable Open Item Reference",
+    "To specify the accounts receivable open item(s) to be included in the cash application and to convey the appropriate detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "482", "Payment Action Code", Optional, Id, 2, 2, payment_action_code),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_04),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_05),
+        (6, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_06),
+        (7, "426", "Adjustment Reason Code", Optional, Id, 2, 2, adjustment_reason_code),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+    ]
+}
+
+segment_meta! {
+    S1,
+    "Stop-off Name",
+    "To identify a stop-off party",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "459", "Name (30 Character Format)", Mandatory, AlphaNumeric, 2, 30, name_30_character_format),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "190", "Accomplish Code", Mandatory, Id, 1, 1, accomplish_code),
+    ]
+}
+
+segment_meta! {
+    S2,
+    "Stop-off Address",
+    "To specify the address of the stop-off party",
+    [_01, _02, _03],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_02),
+        (3, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_03),
+    ]
+}
+
+segment_meta! {
+    S5,
+    "Stop Off Details",
+    "To specify stop-off detail reference numbers and stop reason",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "163", "Stop Reason Code", Mandatory, Id, 2, 2, stop_reason_code),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (5, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (7, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (8, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (9, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (11, "190", "Accomplish Code", Optional, Id, 1, 1, accomplish_code),
+    ]
+}
+
+segment_meta! {
+    S9,
+    "Stop-off Station",
+    "To specify location details for a stop-off",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (3, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name),
+        (4, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "163", "Stop Reason Code", Mandatory, Id, 2, 2, stop_reason_code),
+        (7, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (8, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    SAC,
+    "Service, Promotion, Allowance, or Charge Information",
+    "To request or identify a service, promotion, allowance, or charge; to specify the amount or percentage for the service, promotion, allowance, or charge",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "248", "Allowance or Charge Indicator", Mandatory, Id, 1, 1, allowance_or_charge_indicator),
+        (2, "1300", "Service, Promotion, Allowance, or Charge Code", Conditional, Id, 4, 4, service_promotion_allowance_or_charge_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "1301", "Agency Service, Promotion, Allowance, or Charge Code", Conditional, AlphaNumeric, 1, 10, agency_service_promotion_allowance_or_charge_code),
+        (5, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (6, "378", "Allowance/Charge Percent Qualifier", Conditional, Id, 1, 1, allowance_charge_percent_qualifier),
+        (7, "332", "Percent", Conditional, Decimal, 1, 6, percent),
+        (8, "118", "Rate", Optional, Decimal, 1, 9, rate),
+        (9, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "380", "Quantity", Optional, Decimal, 1, 15, quantity_11),
+        (12, "331", "Allowance or Charge Method of Handling Code", Optional, Id, 2, 2, allowance_or_charge_method_of_handling_code),
+        (13, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (14, "770", "Option Number", Optional, AlphaNumeric, 1, 20, option_number),
+        (15, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (16, "819", "Language Code", Optional, Id, 2, 3, language_code),
+    ]
+}
+
+segment_meta! {
+    SCH,
+    "Line Item Schedule",
+    "To specify the data for scheduling a specific line-item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (4, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (5, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier_05),
+        (6, "373", "Date", Mandatory, Date, 8, 8, date_06),
+        (7, "337", "Time", Conditional, Time, 4, 8, time_07),
+        (8, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_08),
+        (9, "373", "Date", Conditional, Date, 8, 8, date_09),
+        (10, "337", "Time", Conditional, Time, 4, 8, time_10),
+        (11, "326", "Request Reference Number", Optional, AlphaNumeric, 1, 45, request_reference_number),
+        (12, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+    ]
+}
+
+segment_meta! {
+    SLN,
+    "Subline Item Detail",
+    "To specify product subline detail item data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28],
+    [
+        (1, "350", "Assigned Identification", Mandatory, AlphaNumeric, 1, 20, assigned_identification_01),
+        (2, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification_02),
+        (3, "662", "Relationship Code", Mandatory, Id, 1, 1, relationship_code_03),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (7, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (8, "662", "Relationship Code", Optional, Id, 1, 1, relationship_code_08),
+        (9, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_09),
+        (10, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_11),
+        (12, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_12),
+        (13, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_13),
+        (14, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_14),
+        (15, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_15),
+        (16, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_16),
+        (17, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_17),
+        (18, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_18),
+        (19, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_19),
+        (20, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_20),
+        (21, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_21),
+        (22, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_22),
+        (23, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_23),
+        (24, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_24),
+        (25, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_25),
+        (26, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_26),
+        (27, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_27),
+        (28, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_28),
+    ]
+}
+
+segment_meta! {
+    SDQ,
+    "Destination Quantity",
+    "To specify destination and quantity detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (2, "66", "Identification Code Qualifier", Optional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Mandatory, AlphaNumeric, 2, 80, identification_code_03),
+        (4, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_04),
+        (5, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_05),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_07),
+        (8, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_08),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_09),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_11),
+        (12, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_12),
+        (13, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_13),
+        (14, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_14),
+        (15, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_15),
+        (16, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_16),
+        (17, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_17),
+        (18, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_18),
+        (19, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_19),
+        (20, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_20),
+        (21, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_21),
+        (22, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_22),
+        (23, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments)",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    SG,
+    "Shipment Status",
+    "To convey the status of a shipment",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "157", "Shipment Status Code", Conditional, Id, 1, 2, shipment_status_code),
+        (2, "641", "Status Reason Code", Conditional, Id, 3, 3, status_reason_code),
+        (3, "35", "Disposition Code", Conditional, Id, 2, 2, disposition_code),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Conditional, Time, 4, 8, time),
+        (6, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    SN1,
+    "Item Detail (Shipment)",
+    "To specify line-item detail relative to shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (3, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "646", "Quantity Shipped to Date", Optional, Decimal, 1, 15, quantity_shipped_to_date),
+        (5, "330", "Quantity Ordered", Conditional, Decimal, 1, 15, quantity_ordered),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "728", "Returnable Container Load Make-Up Code", Optional, Id, 1, 2, returnable_container_load_make_up_code),
+        (8, "668", "Line Item Status Code", Optional, Id, 2, 2, line_item_status_code),
+    ]
+}
+
+segment_meta! {
+    SPO,
+    "Shipment Purchase Order Detail",
+    "To specify the purchase order details for a shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (2, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_02),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (6, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (7, "647", "Application Error Condition Code", Optional, Id, 1, 3, application_error_condition_code),
+        (8, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_08),
+    ]
+}
+
+segment_meta! {
+    SR,
+    "Requested Service Schedule",
+    "To identify requested service schedules",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    T1,
+    "Transit Inbound Origin",
+    "To specify origin point and waybill references of movement to transit waybill point",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "186", "Waybill Number", Optional, Numeric(0), 1, 6, waybill_number),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (5, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (6, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (7, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (8, "229", "Transit Registration Number", Optional, AlphaNumeric, 1, 6, transit_registration_number),
+        (9, "461", "Transit Level Code", Optional, Id, 1, 3, transit_level_code),
+    ]
+}
+
+segment_meta! {
+    T2,
+    "Transit Inbound Lading",
+    "To specify lading description, including weight and rate details applying to the associated T1 segment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (3, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (4, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (5, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_05),
+        (6, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_06),
+        (7, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_07),
+        (8, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_08),
+        (9, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_09),
+        (10, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_10),
+        (11, "462", "Through Surcharge Percent", Optional, Numeric(2), 2, 4, through_surcharge_percent),
+        (12, "463", "Paid-In Surcharge Percent", Optional, Numeric(2), 2, 4, paid_in_surcharge_percent),
+    ]
+}
+
+segment_meta! {
+    T3,
+    "Transit Inbound Route",
+    "To specify transit inbound routing, including equipment identifications for associated T1 and T2 segments",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "133", "Routing Sequence Code", Optional, Id, 1, 2, routing_sequence_code),
+        (4, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (5, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (6, "206", "Equipment Initial", Conditional, AlphaNumeric, 1, 4, equipment_initial),
+        (7, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+    ]
+}
+
+segment_meta! {
+    T6,
+    "Transit Inbound Rates",
+    "To identify the transit inbound prior origin point and waybill reference of movement to the point specified in T1 segment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_02),
+        (3, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_03),
+        (4, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_04),
+        (5, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_05),
+        (6, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_06),
+        (7, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_07),
+    ]
+}
+
+segment_meta! {
+    T8,
+    "Free-form Transit Data",
+    "To transmit information in a free-form format relating to a specified transit sequence number",
+    [_01, _02],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "299", "Free-form Transit Data", Mandatory, AlphaNumeric, 1, 80, free_form_transit_data),
+    ]
+}
+
+segment_meta! {
+    TC2,
+    "Commodity",
+    "To identify a commodity or a group of commodities or a tariff page commodity",
+    [_01, _02],
+    [
+        (1, "23", "Commodity Code Qualifier", Mandatory, Id, 1, 1, commodity_code_qualifier),
+        (2, "22", "Commodity Code", Mandatory, AlphaNumeric, 1, 30, commodity_code),
+    ]
+}
+
+segment_meta! {
+    TD1,
+    "Carrier Details (Quantity and Weight)",
+    "To specify the transportation details relative to commodity, weight, and quantity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (2, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (3, "23", "Commodity Code Qualifier", Optional, Id, 1, 1, commodity_code_qualifier),
+        (4, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code),
+        (5, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (6, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (7, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_08),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (10, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_10),
+    ]
+}
+
+segment_meta! {
+    TD3,
+    "Carrier Details (Equipment)",
+    "To specify transportation details relating to the equipment used by the carrier",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "40", "Equipment Description Code", Conditional, Id, 2, 2, equipment_description_code),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+        (4, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (5, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (7, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number),
+        (8, "24", "Equipment Type", Conditional, Id, 4, 4, equipment_type),
+    ]
+}
+
+segment_meta! {
+    TD4,
+    "Carrier Details (Special Handling, or Hazardous Materials, or Both)",
+    "To specify transportation special handling requirements, or hazardous materials information, or both",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "208", "Hazardous Material Code Qualifier", Conditional, Id, 1, 1, hazardous_material_code_qualifier),
+        (3, "209", "Hazardous Material Class Code", Conditional, AlphaNumeric, 1, 4, hazardous_material_class_code),
+        (4, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (5, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    TD5,
+    "Carrier Details (Routing Sequence/Transit Time)",
+    "To specify the carrier and sequence of routing and provide transit time information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "133", "Routing Sequence Code", Optional, Id, 1, 2, routing_sequence_code),
+        (2, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (4, "91", "Transportation Method/Type Code", Conditional, Id, 1, 2, transportation_method_type_code),
+        (5, "387", "Routing", Conditional, AlphaNumeric, 1, 35, routing),
+        (6, "368", "Shipment/Order Status Code", Conditional, Id, 2, 2, shipment_order_status_code),
+        (7, "309", "Location Qualifier", Optional, Id, 1, 2, location_qualifier),
+        (8, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (9, "731", "Transit Direction Code", Optional, Id, 2, 2, transit_direction_code),
+        (10, "732", "Transit Time Direction Qualifier", Optional, Id, 2, 2, transit_time_direction_qualifier),
+        (11, "733", "Transit Time", Conditional, Decimal, 1, 4, transit_time),
+        (12, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_12),
+        (13, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_13),
+        (14, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_14),
+        (15, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    TDS,
+    "Total Monetary Value Summary",
+    "To specify the total invoice discounts and amounts",
+    [_01, _02, _03, _04],
+    [
+        (1, "610", "Amount", Mandatory, Numeric(2), 1, 15, amount_01),
+        (2, "610", "Amount", Optional, Numeric(2), 1, 15, amount_02),
+        (3, "610", "Amount", Optional, Numeric(2), 1, 15, amount_03),
+        (4, "610", "Amount", Optional, Numeric(2), 1, 15, amount_04),
+    ]
+}
+
+segment_meta! {
+    TED,
+    "Technical Error Description",
+    "To identify the error and, if feasible, the erroneous segment, or data element, or both",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "647", "Application Error Condition Code", Mandatory, Id, 1, 3, application_error_condition_code),
+        (2, "3", "Free Form Message", Optional, AlphaNumeric, 1, 60, free_form_message),
+        (3, "721", "Segment ID Code", Optional, Id, 2, 3, segment_id_code),
+        (4, "719", "Segment Position in Transaction Set", Optional, Numeric(0), 1, 10, segment_position_in_transaction_set),
+        (5, "C030", "Position in Segment", Optional, Composite, 0, 0, position_in_segment),
+        (6, "725", "Data Element Reference Number", Optional, Numeric(0), 1, 4, data_element_reference_number),
+        (7, "724", "Copy of Bad Data Element", Optional, AlphaNumeric, 1, 99, copy_of_bad_data_element),
+        (8, "961", "Data Element New Content", Optional, AlphaNumeric, 1, 99, data_element_new_content),
+    ]
+}
+
+segment_meta! {
+    TRN,
+    "Trace",
+    "To uniquely identify a transaction to an application",
+    [_01, _02, _03, _04],
+    [
+        (1, "481", "Trace Type Code", Mandatory, Id, 1, 2, trace_type_code),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification_02),
+        (3, "509", "Originating Company Identifier", Optional, AlphaNumeric, 10, 10, originating_company_identifier),
+        (4, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_04),
+    ]
+}
+
+segment_meta! {
+    TXI,
+    "Tax Information",
+    "To specify tax information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "963", "Tax Type Code", Mandatory, Id, 2, 2, tax_type_code),
+        (2, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (3, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+        (4, "955", "Tax Jurisdiction Code Qualifier", Conditional, Id, 2, 2, tax_jurisdiction_code_qualifier),
+        (5, "956", "Tax Jurisdiction Code", Conditional, AlphaNumeric, 1, 10, tax_jurisdiction_code),
+        (6, "441", "Tax Exempt Code", Conditional, Id, 1, 1, tax_exempt_code),
+        (7, "662", "Relationship Code", Optional, Id, 1, 1, relationship_code),
+        (8, "828", "Dollar Basis For Percent", Optional, Decimal, 1, 9, dollar_basis_for_percent),
+        (9, "325", "Tax Identification Number", Optional, AlphaNumeric, 1, 20, tax_identification_number),
+        (10, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+    ]
+}
+
+segment_meta! {
+    V1,
+    "Vessel Identification",
+    "To provide vessel details and voyage number",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "597", "Vessel Code", Conditional, Id, 1, 8, vessel_code),
+        (2, "182", "Vessel Name", Conditional, AlphaNumeric, 2, 28, vessel_name),
+        (3, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (4, "55", "Flight/Voyage Number", Optional, AlphaNumeric, 2, 10, flight_voyage_number),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "249", "Vessel Requirement Code", Optional, Id, 1, 1, vessel_requirement_code),
+        (7, "854", "Vessel Type Code", Optional, Id, 2, 2, vessel_type_code),
+        (8, "897", "Vessel Code Qualifier", Optional, Id, 1, 1, vessel_code_qualifier),
+        (9, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+segment_meta! {
+    V4,
+    "Cargo Location Reference",
+    "To specify the cargo location on board the vessel",
+    [_01],
+    [
+        (1, "877", "Vessel Stowage Location", Mandatory, AlphaNumeric, 1, 12, vessel_stowage_location),
+    ]
+}
+
+segment_meta! {
+    V9,
+    "Event Detail",
+    "To specify information about a specific event",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20],
+    [
+        (1, "304", "Event Code", Mandatory, Id, 3, 3, event_code),
+        (2, "106", "Event", Optional, AlphaNumeric, 1, 25, event),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "337", "Time", Conditional, Time, 4, 8, time),
+        (5, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (6, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "641", "Status Reason Code", Optional, Id, 3, 3, status_reason_code),
+        (9, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code_09),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "1274", "Train Delay Reason Code", Conditional, AlphaNumeric, 2, 2, train_delay_reason_code),
+        (12, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message),
+        (13, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (14, "380", "Quantity", Optional, Decimal, 1, 15, quantity_14),
+        (15, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_15),
+        (16, "86", "Total Equipment", Optional, Numeric(0), 1, 3, total_equipment_16),
+        (17, "86", "Total Equipment", Optional, Numeric(0), 1, 3, total_equipment_17),
+        (18, "86", "Total Equipment", Optional, Numeric(0), 1, 3, total_equipment_18),
+        (19, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (20, "82", "Length", Optional, Decimal, 1, 8, length),
+    ]
+}
+
+segment_meta! {
+    VC,
+    "Motor Vehicle Control",
+    "To define motor vehicle identification and logistics",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "539", "Vehicle Identification Number", Mandatory, AlphaNumeric, 1, 25, vehicle_identification_number),
+        (2, "836", "Vehicle Deck Position Code", Optional, Id, 2, 2, vehicle_deck_position_code),
+        (3, "837", "Vehicle Type Code", Optional, Id, 1, 1, vehicle_type_code),
+        (4, "838", "Dealer Code", Optional, AlphaNumeric, 2, 9, dealer_code),
+        (5, "1", "Route Code", Optional, AlphaNumeric, 1, 13, route_code),
+        (6, "839", "Bay Location", Optional, AlphaNumeric, 1, 6, bay_location),
+        (7, "833", "Automotive Manufacturers Code", Optional, Id, 2, 2, automotive_manufacturers_code),
+        (8, "308", "Damage Exception Indicator", Optional, Id, 1, 1, damage_exception_indicator),
+        (9, "835", "Supplemental Inspection Code", Optional, Id, 1, 1, supplemental_inspection_code),
+        (10, "583", "Factory Car Order Number", Optional, AlphaNumeric, 6, 10, factory_car_order_number),
+        (11, "877", "Vessel Stowage Location", Optional, AlphaNumeric, 1, 12, vessel_stowage_location),
+    ]
+}
+
+segment_meta! {
+    VID,
+    "Conveyance Identification",
+    "To identify a conveyance and its attributes",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "40", "Equipment Description Code", Mandatory, Id, 2, 2, equipment_description_code),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (4, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_04),
+        (5, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_05),
+        (6, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (7, "65", "Height", Optional, Decimal, 1, 8, height),
+        (8, "189", "Width", Optional, Decimal, 1, 8, width),
+        (9, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (10, "322", "Load/Empty Status Code", Optional, Id, 1, 1, load_empty_status_code),
+        (11, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (12, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (13, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+    ]
+}
+
+segment_meta! {
+    W01,
+    "Line Item Detail - Warehouse",
+    "To specify the content and format of line item detail for a warehouse shipping order",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "330", "Quantity Ordered", Mandatory, Decimal, 1, 15, quantity_ordered),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "438", "U.P.C. Case Code", Conditional, AlphaNumeric, 12, 12, u_p_c_case_code),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Optional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+    ]
+}
+
+segment_meta! {
+    W2,
+    "Equipment Identification",
+    "To identify equipment and the commodity being carried",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial_01),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number_02),
+        (3, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (4, "40", "Equipment Description Code", Mandatory, Id, 2, 2, equipment_description_code),
+        (5, "578", "Equipment Status Code", Mandatory, Id, 1, 2, equipment_status_code),
+        (6, "577", "Net Tons", Optional, Numeric(0), 1, 3, net_tons),
+        (7, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (8, "240", "Car Service Order Code", Optional, Id, 3, 5, car_service_order_code),
+        (9, "373", "Date", Conditional, Date, 8, 8, date),
+        (10, "502", "Type of Locomotive Maintenance Code", Conditional, AlphaNumeric, 2, 2, type_of_locomotive_maintenance_code),
+        (11, "206", "Equipment Initial", Conditional, AlphaNumeric, 1, 4, equipment_initial_11),
+        (12, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number_12),
+        (13, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+        (14, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (15, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+        (16, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    W03,
+    "Total Shipment Information",
+    "To specify the total shipment in terms of quantity, weight and volume",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (2, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "183", "Volume", Optional, Decimal, 1, 8, volume),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_05),
+        (6, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (7, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_07),
+    ]
+}
+
+segment_meta! {
+    W04,
+    "Item Detail Total",
+    "To specify the content and quantity of an item shipped in a stock transfer",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "438", "U.P.C. Case Code", Conditional, AlphaNumeric, 12, 12, u_p_c_case_code),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Optional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+    ]
+}
+
+segment_meta! {
+    W05,
+    "Shipping Order Identification",
+    "To indicate the beginning of the Shipping Order Transaction Set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "473", "Order Status Code", Mandatory, Id, 1, 2, order_status_code),
+        (2, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number_02),
+        (3, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number_03),
+        (4, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (5, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+    ]
+}
+
+segment_meta! {
+    W06,
+    "Warehouse Shipment Identification",
+    "To indicate the beginning of the Warehouse Shipping Advice Transaction Set and transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "592", "Reporting Code", Mandatory, Id, 1, 1, reporting_code),
+        (2, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (5, "595", "Agent Shipment ID Number", Optional, AlphaNumeric, 1, 30, agent_shipment_id_number),
+        (6, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number_06),
+    ]
+}
+
+segment_meta! {
+    W07,
+    "Item Detail For Stock Receipt",
+    "To specify the content and quantities of an item received in a stock transfer",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "438", "U.P.C. Case Code", Conditional, AlphaNumeric, 12, 12, u_p_c_case_code),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Optional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+    ]
+}
+
+segment_meta! {
+    W09,
+    "Equipment and Temperature",
+    "To relate equipment type and required temperatures",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "40", "Equipment Description Code", Mandatory, Id, 2, 2, equipment_description_code),
+        (2, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_02),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_04),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_05),
+        (6, "3", "Free Form Message", Optional, AlphaNumeric, 1, 60, free_form_message),
+        (7, "1122", "Vent Setting Code", Optional, Id, 1, 1, vent_setting_code),
+        (8, "488", "Percent", Optional, Numeric(0), 1, 3, percent),
+        (9, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+    ]
+}
+
+segment_meta! {
+    W12,
+    "Warehouse Item Detail",
+    "To designate those line items that were shipped",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "368", "Shipment/Order Status Code", Mandatory, Id, 2, 2, shipment_order_status_code),
+        (2, "330", "Quantity Ordered", Optional, Decimal, 1, 15, quantity_ordered),
+        (3, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (4, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "438", "U.P.C. Case Code", Conditional, AlphaNumeric, 12, 12, u_p_c_case_code),
+        (7, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier),
+        (8, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id),
+        (9, "461", "Warehouse Lot Number", Optional, AlphaNumeric, 1, 12, warehouse_lot_number),
+        (10, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (11, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (12, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+    ]
+}
+
+segment_meta! {
+    W14,
+    "Total Receipt Information",
+    "To specify summary details of total items received in a stock transfer",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_01),
+        (2, "380", "Quantity", Optional, Decimal, 1, 15, quantity_02),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity_03),
+        (4, "380", "Quantity", Optional, Decimal, 1, 15, quantity_04),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity_05),
+    ]
+}
+
+segment_meta! {
+    W15,
+    "Warehouse Adjustment Identification",
+    "To identify the beginning of an inventory adjustment advice",
+    [_01, _02, _03],
+    [
+        (1, "373", "Date", Mandatory, Date, 8, 8, date),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification_02),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification_03),
+    ]
+}
+
+segment_meta! {
+    W17,
+    "Warehouse Receipt Identification",
+    "To indicate the beginning of the Warehouse Stock Transfer Receipt Advice Transaction Set and transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "592", "Reporting Code", Mandatory, Id, 1, 1, reporting_code),
+        (2, "373", "Date", Mandatory, Date, 8, 8, date),
+        (3, "586", "Warehouse Receipt Number", Mandatory, AlphaNumeric, 1, 12, warehouse_receipt_number),
+        (4, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number),
+        (5, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+    ]
+}
+
+segment_meta! {
+    W19,
+    "Inventory Adjustment Details",
+    "To specify the inventory adjustment details of a line item",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "1271", "Quantity or Status Adjustment Reason Code", Mandatory, Id, 2, 2, quantity_or_status_adjustment_reason_code),
+        (2, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (3, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "438", "U.P.C. Case Code", Conditional, AlphaNumeric, 12, 12, u_p_c_case_code),
+        (5, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_05),
+        (6, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_06),
+        (7, "235", "Product/Service ID Qualifier", Optional, Id, 2, 2, product_service_id_qualifier_07),
+        (8, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_08),
+    ]
+}
+
+segment_meta! {
+    W27,
+    "Carrier Detail",
+    "To specify the carrier and transportation details for a warehouse shipment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+        (2, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "387", "Routing", Optional, AlphaNumeric, 1, 35, routing),
+        (4, "146", "Shipment Method of Payment", Optional, Id, 2, 2, shipment_method_of_payment),
+        (5, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (6, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (7, "207", "Equipment Number", Optional, AlphaNumeric, 1, 10, equipment_number),
+    ]
+}
+
+segment_meta! {
+    W66,
+    "Warehouse Carrier Information",
+    "To specify shipping information for a warehouse shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (2, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code_02),
+        (3, "152", "Special Handling Code", Optional, Id, 2, 3, special_handling_code),
+        (4, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (5, "387", "Routing", Optional, AlphaNumeric, 1, 35, routing),
+        (6, "190", "Pallet Exchange Code", Optional, Id, 1, 1, pallet_exchange_code),
+        (7, "193", "Unit Load Option Code", Optional, Id, 2, 2, unit_load_option_code),
+        (8, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (9, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code_09),
+        (10, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+    ]
+}
+
+segment_meta! {
+    W76,
+    "Total Shipping Order",
+    "To specify summary details of the total shipping order",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "330", "Quantity Ordered", Mandatory, Decimal, 1, 15, quantity_ordered),
+        (2, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_05),
+    ]
+}
+
+segment_meta! {
+    X1,
+    "Export License",
+    "To transmit information contained on an export license",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "83", "Licensing Agency Code", Optional, Id, 1, 1, licensing_agency_code),
+        (2, "50", "Export License Number", Optional, AlphaNumeric, 6, 12, export_license_number),
+        (3, "51", "Export License Status Code", Optional, Id, 1, 1, export_license_status_code),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "52", "Export License Symbol Code", Optional, Id, 1, 2, export_license_symbol_code),
+        (6, "48", "Export License Control Code", Optional, Id, 1, 1, export_license_control_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "141", "Schedule B Code", Optional, Id, 7, 10, schedule_b_code),
+        (9, "210", "International/Domestic Code", Optional, Id, 1, 1, international_domestic_code),
+        (10, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (11, "148", "Lading Value", Optional, Decimal, 2, 9, lading_value),
+        (12, "47", "Export Filing Key Code", Optional, Id, 1, 1, export_filing_key_code),
+        (13, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (14, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+        (15, "1306", "U.S. Government License Type", Optional, AlphaNumeric, 1, 1, u_s_government_license_type),
+        (16, "67", "Identification Code", Optional, AlphaNumeric, 2, 80, identification_code),
+    ]
+}
+
+segment_meta! {
+    X2,
+    "Import License",
+    "To transmit import license number and effective dates",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "70", "Import License Number", Mandatory, AlphaNumeric, 6, 30, import_license_number_01),
+        (2, "373", "Date", Optional, Date, 8, 8, date_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "70", "Import License Number", Conditional, AlphaNumeric, 6, 30, import_license_number_04),
+        (5, "373", "Date", Optional, Date, 8, 8, date_05),
+        (6, "373", "Date", Optional, Date, 8, 8, date_06),
+    ]
+}
+
+segment_meta! {
+    X7,
+    "Customs Information",
+    "To indicate customs information",
+    [_01, _02],
+    [
+        (1, "61", "Free-Form Message", Mandatory, AlphaNumeric, 1, 30, free_form_message_01),
+        (2, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message_02),
+    ]
+}
+
+segment_meta! {
+    XH,
+    "Pro Forma - B13 Information",
+    "This segment is used to specify a pro forma invoice and B13 Canadian Customs Export Declaration information, required by U.S. and Canadian customs",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code),
+        (2, "645", "Related Company Indication Code", Optional, Id, 1, 1, related_company_indication_code),
+        (3, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (4, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (5, "503", "Block 20 Code", Optional, Id, 1, 1, block_20_code),
+        (6, "504", "Chemical Analysis Percentage", Optional, Numeric(2), 2, 9, chemical_analysis_percentage),
+        (7, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+    ]
+}
+
+segment_meta! {
+    Y2,
+    "Container Details",
+    "To specify container information and transportation service to be used",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "95", "Number of Containers", Mandatory, Numeric(0), 1, 4, number_of_containers),
+        (2, "78", "Container Type Request Code", Optional, Id, 1, 1, container_type_request_code),
+        (3, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (4, "24", "Equipment Type", Mandatory, Id, 4, 4, equipment_type),
+        (5, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (6, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "464", "Container Terms Code", Optional, Id, 3, 3, container_terms_code),
+        (9, "465", "Container Terms Code Qualifier", Optional, Id, 1, 1, container_terms_code_qualifier),
+        (10, "466", "Total Stop-offs", Optional, Numeric(0), 1, 2, total_stop_offs),
+    ]
+}
+
+segment_meta! {
+    Y3,
+    "Space Confirmation",
+    "To specify confirmation information for space booking including number, dates and load time",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "13", "Booking Number", Mandatory, AlphaNumeric, 1, 17, booking_number),
+        (2, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "373", "Date", Optional, Date, 8, 8, date_04),
+        (5, "154", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (6, "155", "Pier Name", Optional, AlphaNumeric, 2, 14, pier_name),
+        (7, "373", "Date", Optional, Date, 8, 8, date_07),
+        (8, "337", "Time", Optional, Time, 4, 8, time),
+        (9, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (10, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (11, "375", "Tariff Service Code", Optional, Id, 2, 2, tariff_service_code),
+    ]
+}
+
+segment_meta! {
+    Y4,
+    "Container Release",
+    "To transmit information relative to containers available for release",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "13", "Booking Number", Optional, AlphaNumeric, 1, 17, booking_number_01),
+        (2, "13", "Booking Number", Optional, AlphaNumeric, 1, 17, booking_number_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "154", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (5, "95", "Number of Containers", Optional, Numeric(0), 1, 4, number_of_containers),
+        (6, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (9, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (10, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+    ]
+}
+
+segment_meta! {
+    Y6,
+    "Authentication",
+    "To specify the authority for authorizing an action and the date authentication is made",
+    [_01, _02, _03],
+    [
+        (1, "313", "Authority Identifier Code", Optional, Id, 2, 2, authority_identifier_code),
+        (2, "151", "Authority", Mandatory, AlphaNumeric, 1, 20, authority),
+        (3, "275", "Authorization Date", Mandatory, Date, 8, 8, authorization_date),
+    ]
+}
+
+segment_meta! {
+    Y7,
+    "Priority",
+    "To assign a priority to a booking which would increase the possibility that this cargo would be booked on said voyage and not be shut out",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "467", "Priority", Optional, Numeric(0), 1, 1, priority),
+        (2, "470", "Priority Code", Conditional, Numeric(0), 1, 1, priority_code),
+        (3, "471", "Priority Code Qualifier", Conditional, AlphaNumeric, 1, 1, priority_code_qualifier),
+        (4, "468", "Port Call File Number", Optional, Numeric(0), 4, 4, port_call_file_number),
+        (5, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    YNQ,
+    "Yes/No Question",
+    "To identify and answer yes and no questions, including the date, time, and comments further qualifying the condition",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "1321", "Condition Indicator", Conditional, Id, 2, 3, condition_indicator),
+        (2, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (4, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (5, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_05),
+        (6, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_06),
+        (7, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_07),
+        (8, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (9, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+        (10, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_10),
+    ]
+}
+
+segment_meta! {
+    ZC1,
+    "Beginning Segment For Data Correction Or Change",
+    "To transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (4, "244", "Transaction Reference Number", Mandatory, AlphaNumeric, 1, 15, transaction_reference_number),
+        (5, "243", "Transaction Reference Date", Mandatory, Date, 8, 8, transaction_reference_date),
+        (6, "202", "Correction Indicator", Mandatory, Id, 2, 2, correction_indicator),
+        (7, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+segment_meta! {
+    ZD,
+    "Transaction Set Deletion - ID, Reason, and Source",
+    "This segment is used to specify the transaction set to be canceled",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (4, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 15, equipment_number),
+        (5, "244", "Transaction Reference Number", Optional, AlphaNumeric, 1, 50, transaction_reference_number),
+        (6, "243", "Transaction Reference Date", Optional, Date, 8, 8, transaction_reference_date),
+        (7, "202", "Correction Indicator Code", Mandatory, Id, 2, 2, correction_indicator_code),
+        (8, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "ACK" => Some(ACK::DESCRIPTOR),
+        "ADX" => Some(ADX::DESCRIPTOR),
+        "AK1" => Some(AK1::DESCRIPTOR),
+        "AK2" => Some(AK2::DESCRIPTOR),
+        "AK3" => Some(AK3::DESCRIPTOR),
+        "AK4" => Some(AK4::DESCRIPTOR),
+        "AK5" => Some(AK5::DESCRIPTOR),
+        "AK9" => Some(AK9::DESCRIPTOR),
+        "AMT" => Some(AMT::DESCRIPTOR),
+        "AT5" => Some(AT5::DESCRIPTOR),
+        "AT7" => Some(AT7::DESCRIPTOR),
+        "AT8" => Some(AT8::DESCRIPTOR),
+        "B1" => Some(B1::DESCRIPTOR),
+        "B2" => Some(B2::DESCRIPTOR),
+        "B2A" => Some(B2A::DESCRIPTOR),
+        "B3" => Some(B3::DESCRIPTOR),
+        "B4" => Some(B4::DESCRIPTOR),
+        "B10" => Some(B10::DESCRIPTOR),
+        "BAK" => Some(BAK::DESCRIPTOR),
+        "BAL" => Some(BAL::DESCRIPTOR),
+        "BCH" => Some(BCH::DESCRIPTOR),
+        "BEG" => Some(BEG::DESCRIPTOR),
+        "BGN" => Some(BGN::DESCRIPTOR),
+        "BIG" => Some(BIG::DESCRIPTOR),
+        "BIN" => Some(BIN::DESCRIPTOR),
+        "BL" => Some(BL::DESCRIPTOR),
+        "BNX" => Some(BNX::DESCRIPTOR),
+        "BPR" => Some(BPR::DESCRIPTOR),
+        "BSN" => Some(BSN::DESCRIPTOR),
+        "BX" => Some(BX::DESCRIPTOR),
+        "C2" => Some(C2::DESCRIPTOR),
+        "C3" => Some(C3::DESCRIPTOR),
+        "C8" => Some(C8::DESCRIPTOR),
+        "C8C" => Some(C8C::DESCRIPTOR),
+        "CAD" => Some(CAD::DESCRIPTOR),
+        "CD3" => Some(CD3::DESCRIPTOR),
+        "CLD" => Some(CLD::DESCRIPTOR),
+        "CM" => Some(CM::DESCRIPTOR),
+        "CRC" => Some(CRC::DESCRIPTOR),
+        "CTP" => Some(CTP::DESCRIPTOR),
+        "CTT" => Some(CTT::DESCRIPTOR),
+        "CUR" => Some(CUR::DESCRIPTOR),
+        "D9" => Some(D9::DESCRIPTOR),
+        "DMG" => Some(DMG::DESCRIPTOR),
+        "DTM" => Some(DTM::DESCRIPTOR),
+        "E1" => Some(E1::DESCRIPTOR),
+        "E4" => Some(E4::DESCRIPTOR),
+        "E5" => Some(E5::DESCRIPTOR),
+        "EA" => Some(EA::DESCRIPTOR),
+        "EFI" => Some(EFI::DESCRIPTOR),
+        "EM" => Some(EM::DESCRIPTOR),
+        "ENT" => Some(ENT::DESCRIPTOR),
+        "F9" => Some(F9::DESCRIPTOR),
+        "FA1" => Some(FA1::DESCRIPTOR),
+        "FA2" => Some(FA2::DESCRIPTOR),
+        "FOB" => Some(FOB::DESCRIPTOR),
+        "G3" => Some(G3::DESCRIPTOR),
+        "G61" => Some(G61::DESCRIPTOR),
+        "G62" => Some(G62::DESCRIPTOR),
+        "G69" => Some(G69::DESCRIPTOR),
+        "GA" => Some(GA::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GR5" => Some(GR5::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "H1" => Some(H1::DESCRIPTOR),
+        "H2" => Some(H2::DESCRIPTOR),
+        "H3" => Some(H3::DESCRIPTOR),
+        "HL" => Some(HL::DESCRIPTOR),
+        "IC" => Some(IC::DESCRIPTOR),
+        "IM" => Some(IM::DESCRIPTOR),
+        "INC" => Some(INC::DESCRIPTOR),
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "ISS" => Some(ISS::DESCRIPTOR),
+        "IT1" => Some(IT1::DESCRIPTOR),
+        "IT3" => Some(IT3::DESCRIPTOR),
+        "ITD" => Some(ITD::DESCRIPTOR),
+        "K1" => Some(K1::DESCRIPTOR),
+        "L0" => Some(L0::DESCRIPTOR),
+        "L1" => Some(L1::DESCRIPTOR),
+        "L3" => Some(L3::DESCRIPTOR),
+        "L4" => Some(L4::DESCRIPTOR),
+        "L5" => Some(L5::DESCRIPTOR),
+        "L7" => Some(L7::DESCRIPTOR),
+        "L11" => Some(L11::DESCRIPTOR),
+        "LAD" => Some(LAD::DESCRIPTOR),
+        "LE" => Some(LE::DESCRIPTOR),
+        "LEP" => Some(LEP::DESCRIPTOR),
+        "LFH" => Some(LFH::DESCRIPTOR),
+        "LH1" => Some(LH1::DESCRIPTOR),
+        "LH2" => Some(LH2::DESCRIPTOR),
+        "LH3" => Some(LH3::DESCRIPTOR),
+        "LH4" => Some(LH4::DESCRIPTOR),
+        "LH6" => Some(LH6::DESCRIPTOR),
+        "LHR" => Some(LHR::DESCRIPTOR),
+        "LHT" => Some(LHT::DESCRIPTOR),
+        "LIN" => Some(LIN::DESCRIPTOR),
+        "LM" => Some(LM::DESCRIPTOR),
+        "LQ" => Some(LQ::DESCRIPTOR),
+        "LS" => Some(LS::DESCRIPTOR),
+        "LX" => Some(LX::DESCRIPTOR),
+        "M0" => Some(M0::DESCRIPTOR),
+        "M1" => Some(M1::DESCRIPTOR),
+        "M3" => Some(M3::DESCRIPTOR),
+        "M7" => Some(M7::DESCRIPTOR),
+        "M10" => Some(M10::DESCRIPTOR),
+        "M11" => Some(M11::DESCRIPTOR),
+        "M12" => Some(M12::DESCRIPTOR),
+        "M13" => Some(M13::DESCRIPTOR),
+        "MAN" => Some(MAN::DESCRIPTOR),
+        "MEA" => Some(MEA::DESCRIPTOR),
+        "MS1" => Some(MS1::DESCRIPTOR),
+        "MS2" => Some(MS2::DESCRIPTOR),
+        "MS3" => Some(MS3::DESCRIPTOR),
+        "MSG" => Some(MSG::DESCRIPTOR),
+        "N1" => Some(N1::DESCRIPTOR),
+        "N2" => Some(N2::DESCRIPTOR),
+        "N3" => Some(N3::DESCRIPTOR),
+        "N4" => Some(N4::DESCRIPTOR),
+        "N5" => Some(N5::DESCRIPTOR),
+        "N7" => Some(N7::DESCRIPTOR),
+        "N7A" => Some(N7A::DESCRIPTOR),
+        "N7B" => Some(N7B::DESCRIPTOR),
+        "N9" => Some(N9::DESCRIPTOR),
+        "N10" => Some(N10::DESCRIPTOR),
+        "N12" => Some(N12::DESCRIPTOR),
+        "NA" => Some(NA::DESCRIPTOR),
+        "NM1" => Some(NM1::DESCRIPTOR),
+        "NTE" => Some(NTE::DESCRIPTOR),
+        "OID" => Some(OID::DESCRIPTOR),
+        "OTI" => Some(OTI::DESCRIPTOR),
+        "P4" => Some(P4::DESCRIPTOR),
+        "P5" => Some(P5::DESCRIPTOR),
+        "PAM" => Some(PAM::DESCRIPTOR),
+        "PER" => Some(PER::DESCRIPTOR),
+        "PI" => Some(PI::DESCRIPTOR),
+        "PID" => Some(PID::DESCRIPTOR),
+        "PKG" => Some(PKG::DESCRIPTOR),
+        "PLD" => Some(PLD::DESCRIPTOR),
+        "PO1" => Some(PO1::DESCRIPTOR),
+        "PO3" => Some(PO3::DESCRIPTOR),
+        "PO4" => Some(PO4::DESCRIPTOR),
+        "POC" => Some(POC::DESCRIPTOR),
+        "POD" => Some(POD::DESCRIPTOR),
+        "PRF" => Some(PRF::DESCRIPTOR),
+        "PS" => Some(PS::DESCRIPTOR),
+        "PWK" => Some(PWK::DESCRIPTOR),
+        "Q2" => Some(Q2::DESCRIPTOR),
+        "Q5" => Some(Q5::DESCRIPTOR),
+        "Q7" => Some(Q7::DESCRIPTOR),
+        "QTY" => Some(QTY::DESCRIPTOR),
+        "R2" => Some(R2::DESCRIPTOR),
+        "R2A" => Some(R2A::DESCRIPTOR),
+        "R3" => Some(R3::DESCRIPTOR),
+        "R4" => Some(R4::DESCRIPTOR),
+        "R9" => Some(R9::DESCRIPTOR),
+        "RED" => Some(RED::DESCRIPTOR),
+        "REF" => Some(REF::DESCRIPTOR),
+        "RMR" => Some(RMR::DESCRIPTOR),
+        "S1" => Some(S1::DESCRIPTOR),
+        "S2" => Some(S2::DESCRIPTOR),
+        "S5" => Some(S5::DESCRIPTOR),
+        "S9" => Some(S9::DESCRIPTOR),
+        "SAC" => Some(SAC::DESCRIPTOR),
+        "SCH" => Some(SCH::DESCRIPTOR),
+        "SLN" => Some(SLN::DESCRIPTOR),
+        "SDQ" => Some(SDQ::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "SG" => Some(SG::DESCRIPTOR),
+        "SN1" => Some(SN1::DESCRIPTOR),
+        "SPO" => Some(SPO::DESCRIPTOR),
+        "SR" => Some(SR::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        "T1" => Some(T1::DESCRIPTOR),
+        "T2" => Some(T2::DESCRIPTOR),
+        "T3" => Some(T3::DESCRIPTOR),
+        "T6" => Some(T6::DESCRIPTOR),
+        "T8" => Some(T8::DESCRIPTOR),
+        "TC2" => Some(TC2::DESCRIPTOR),
+        "TD1" => Some(TD1::DESCRIPTOR),
+        "TD3" => Some(TD3::DESCRIPTOR),
+        "TD4" => Some(TD4::DESCRIPTOR),
+        "TD5" => Some(TD5::DESCRIPTOR),
+        "TDS" => Some(TDS::DESCRIPTOR),
+        "TED" => Some(TED::DESCRIPTOR),
+        "TRN" => Some(TRN::DESCRIPTOR),
+        "TXI" => Some(TXI::DESCRIPTOR),
+        "V1" => Some(V1::DESCRIPTOR),
+        "V4" => Some(V4::DESCRIPTOR),
+        "V9" => Some(V9::DESCRIPTOR),
+        "VC" => Some(VC::DESCRIPTOR),
+        "VID" => Some(VID::DESCRIPTOR),
+        "W01" => Some(W01::DESCRIPTOR),
+        "W2" => Some(W2::DESCRIPTOR),
+        "W03" => Some(W03::DESCRIPTOR),
+        "W04" => Some(W04::DESCRIPTOR),
+        "W05" => Some(W05::DESCRIPTOR),
+        "W06" => Some(W06::DESCRIPTOR),
+        "W07" => Some(W07::DESCRIPTOR),
+        "W09" => Some(W09::DESCRIPTOR),
+        "W12" => Some(W12::DESCRIPTOR),
+        "W14" => Some(W14::DESCRIPTOR),
+        "W15" => Some(W15::DESCRIPTOR),
+        "W17" => Some(W17::DESCRIPTOR),
+        "W19" => Some(W19::DESCRIPTOR),
+        "W27" => Some(W27::DESCRIPTOR),
+        "W66" => Some(W66::DESCRIPTOR),
+        "W76" => Some(W76::DESCRIPTOR),
+        "X1" => Some(X1::DESCRIPTOR),
+        "X2" => Some(X2::DESCRIPTOR),
+        "X7" => Some(X7::DESCRIPTOR),
+        "XH" => Some(XH::DESCRIPTOR),
+        "Y2" => Some(Y2::DESCRIPTOR),
+        "Y3" => Some(Y3::DESCRIPTOR),
+        "Y4" => Some(Y4::DESCRIPTOR),
+        "Y6" => Some(Y6::DESCRIPTOR),
+        "Y7" => Some(Y7::DESCRIPTOR),
+        "YNQ" => Some(YNQ::DESCRIPTOR),
+        "ZC1" => Some(ZC1::DESCRIPTOR),
+        "ZD" => Some(ZD::DESCRIPTOR),
+        _ => None,
+    }
+}
@@ -266,3 +266,230 @@ fn test_3() {
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn named_json_204() {
+    use crate::util::named::{self, Named};
+    let str = r#"ISA*00*          *00*          *ZZ*USANYC999      *ZZ*8435778122     *230524*1214*U*00401*000136909*0*P*>~GS*SM*USANYC999*8435778122*20230524*1214*136909*X*004010~ST*204*136581~B2******DE~B2A*00~L11*SUDU33SYD002692X*BM~AT5*IP**IMPORT~N1*SH*ADCHEM (AUSTRALIA) PTY LTD~S5*1*PA~N1*RO*CN~S5*2*DT~N1*RD*CSX~SE*10*136581~GE*1*136909~IEA*1*000136909~"#;
+    let (_, obj) = Transmission::<_204>::parse(str).unwrap();
+    let json = serde_json::to_string(&Named::new(&obj, segment_descriptor)).unwrap();
+    assert!(json.contains(r#""ST01_TransactionSetIdentifierCode":"204""#));
+    assert!(json.contains(r#""B206_ShipmentMethodOfPayment":"DE""#));
+    assert!(!json.contains(r#""01":"#));
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let back: Transmission<_204> = named::deserialize(&mut de).unwrap();
+    assert_eq!(format!("{back}"), format!("{obj}"));
+    // the positional form stays the default
+    let json = serde_json::to_string(&obj).unwrap();
+    assert!(json.contains(r#""st":{"01":"204","02":"136581"}"#));
+}
+
+#[test]
+fn xml_204() {
+    use crate::util::xml;
+    let str = r#"ST*204*18711~
+B2**SNDR**6XULT02DCM**DE~
+B2A*00~
+L11*6XULT02DCM*9R~
+L11*SHIPPER & SONS <REF>*4F~
+N1*SH*EXPORT US LLC*93*753244123~
+N3*2400 WASHINGTON AVE~
+S5*1*LD~
+N1*PW*BNSF OAKLAND RAMP~
+S5*2*DT~
+SE*10*18711~"#;
+    let (_, obj) = _204::parse(str).unwrap();
+    let x = xml::to_string(&obj).unwrap();
+    assert!(x.starts_with("<TransactionSet>\n  <ST>\n"));
+    assert!(x.contains("<L1101>SHIPPER &amp; SONS &lt;REF&gt;</L1101>"));
+    assert!(x.contains("  <Loop300>\n    <S5>\n"));
+    let back: _204 = xml::from_str(&x).unwrap();
+    assert_eq!(format!("{back}"), format!("{obj}"));
+    let back: _204 = xml::from_str(&x.replace('\n', "")).unwrap();
+    assert_eq!(format!("{back}"), format!("{obj}"));
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn json_schema_204() {
+    use crate::util::schema::json_schema;
+    let schema = json_schema::<Transmission<_204>>(segment_descriptor).unwrap();
+    assert_eq!(schema["$ref"], "#/$defs/Transmission");
+    let defs = &schema["$defs"];
+    let tx = &defs["_204"];
+    // loops skipped while empty are not required
+    assert_eq!(tx["properties"]["loop_300"]["type"], "array");
+    assert_eq!(
+        tx["properties"]["loop_300"]["items"]["$ref"],
+        "#/$defs/_204Loop300"
+    );
+    assert_eq!(tx["required"], serde_json::json!(["st", "b2", "b2a", "se"]));
+    let b2 = &defs["B2"];
+    assert_eq!(
+        b2["title"],
+        "B2 - Beginning Segment for Shipment Information Transaction"
+    );
+    assert_eq!(
+        b2["properties"]["04"]["description"],
+        "Shipment Identification Number (145)"
+    );
+    assert_eq!(b2["properties"]["04"]["anyOf"][0]["maxLength"], 30);
+    assert_eq!(b2["properties"]["04"]["anyOf"][1]["type"], "null");
+    assert_eq!(b2["properties"]["06"]["type"], "string");
+}
+
+#[test]
+fn build_204() {
+    use crate::util::builder::{BuildError, SegmentBuilder};
+    let b2: B2 = SegmentBuilder::new()
+        .element(2, "SNDR")
+        .element(4, "6XULT02DCM")
+        .element(6, "DE")
+        .build()
+        .unwrap();
+    let b2a: B2A = SegmentBuilder::new().element(1, "00").build().unwrap();
+    let stop = _204Loop300::builder()
+        .s5(S5 {
+            _01: "1".to_string(),
+            _02: "LD".to_string(),
+            ..Default::default()
+        })
+        .loop_310(
+            _204Loop310::builder()
+                .n1(N1 {
+                    _01: "PW".to_string(),
+                    _02: Some("BNSF OAKLAND RAMP".to_string()),
+                    ..Default::default()
+                })
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let obj = _204::builder("18711")
+        .b2(b2.clone())
+        .b2a(b2a.clone())
+        .l11(L11 {
+            _01: Some("6XULT02DCM".to_string()),
+            _02: Some("9R".to_string()),
+            ..Default::default()
+        })
+        .loop_300(stop)
+        .build()
+        .unwrap();
+    assert_eq!(
+        format!("{obj}"),
+        "ST*204*18711~\nB2**SNDR**6XULT02DCM**DE~\nB2A*00~\nL11*6XULT02DCM*9R~\nS5*1*LD~\nN1*PW*BNSF OAKLAND RAMP~\nSE*7*18711~\n"
+    );
+    // mandatory segments and elements are enforced
+    let err = _204::builder("18711").b2(b2.clone()).build().unwrap_err();
+    assert_eq!(err, BuildError::MissingSegment("B2A"));
+    let err = _204::builder("18711")
+        .b2(B2::default())
+        .b2a(b2a.clone())
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BuildError::MissingElement {
+            segment: "B2",
+            position: 6,
+            ..
+        }
+    ));
+    // a 204 needs at least one stop (loop 0300)
+    let err = _204::builder("18711")
+        .b2(b2.clone())
+        .b2a(b2a.clone())
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::MissingLoop { id: "S5", min: 1 });
+    assert_eq!(
+        err.to_string(),
+        "mandatory loop S5 occurs less than the minimum of 1"
+    );
+    let err = _204::builder("").b2(b2).b2a(b2a).build().unwrap_err();
+    assert!(matches!(
+        err,
+        BuildError::MissingElement {
+            segment: "ST",
+            position: 2,
+            ..
+        }
+    ));
+    let err = _204Loop300::builder().build().unwrap_err();
+    assert_eq!(err, BuildError::MissingSegment("S5"));
+    // N1 starts loop 0100, so it is required although the field is optional
+    let err = _204Loop100::builder()
+        .n3(N3 {
+            _01: "1 MAIN ST".to_string(),
+            ..Default::default()
+        })
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::MissingSegment("N1"));
+}
+
+#[test]
+fn build_204_named_setters() {
+    use crate::util::builder::BuildError;
+    let b2 = B2::builder()
+        .standard_carrier_alpha_code("SNDR")
+        .shipment_identification_number("6XULT02DCM")
+        .shipment_method_of_payment("DE");
+    let obj = _204::builder("18711")
+        .b2(b2.clone())
+        .b2a(B2A::builder().transaction_set_purpose_code("00"))
+        .l11(
+            L11::builder()
+                .reference_identification("6XULT02DCM")
+                .reference_identification_qualifier("9R"),
+        )
+        .loop_300(
+            _204Loop300::builder()
+                .s5(S5::builder()
+                    .stop_sequence_number("1")
+                    .stop_reason_code("LD"))
+                .loop_310(
+                    _204Loop310::builder().n1(N1::builder()
+                        .entity_identifier_code_01("PW")
+                        .name("BNSF OAKLAND RAMP")),
+                ),
+        )
+        .build()
+        .unwrap();
+    assert_eq!(
+        format!("{obj}"),
+        "ST*204*18711~\nB2**SNDR**6XULT02DCM**DE~\nB2A*00~\nL11*6XULT02DCM*9R~\nS5*1*LD~\nN1*PW*BNSF OAKLAND RAMP~\nSE*7*18711~\n"
+    );
+    // errors of nested builders surface when the transaction set is built
+    let err = _204::builder("18711")
+        .b2(b2)
+        .b2a(B2A::builder().transaction_set_purpose_code("00"))
+        .loop_300(_204Loop300::builder().s5(S5::builder().stop_sequence_number("1")))
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BuildError::MissingElement {
+            segment: "S5",
+            position: 2,
+            ..
+        }
+    ));
+    // the 004010 ST has no implementation convention reference
+    let err = _990::builder("0001")
+        .b1(B1::builder()
+            .shipment_identification_number("6XULT02DCM")
+            .reservation_action_code("A"))
+        .implementation_convention_reference("004010")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::UnknownElement {
+            segment: "ST",
+            position: 3
+        }
+    );
+}
@@ -0,0 +1,75 @@
+use crate::v004010::*;
+
+#[test]
+fn parse_210() {
+    let str = r#"ST*210*0001~
+B3**INV12345*SHIP123*PP**20231101*125000**20231030*035*ABCD~
+C3*USD~
+N9*BM*BOL12345~
+G62*86*20231030~
+R3*ABCD*B**M~
+N1*SH*ACME SHIPPING~
+N3*100 MAIN ST~
+N4*CHICAGO*IL*60601*US~
+N1*CN*WIDGET CO~
+N3*200 ELM ST~
+N4*DALLAS*TX*75201*US~
+N7*ABCD*123456~
+S5*1*LD~
+N9*PO*PO1234~
+S5*2*UL~
+LX*1~
+L5*1*FREIGHT ALL KINDS~
+L0*1*1000*G*1000*G***10*PLT**L~
+L1*1*1.25*PH*125000~
+L3*1000*G***125000~
+SE*22*0001~
+"#;
+    let (rest, obj) = _210::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b3._02, "INV12345");
+    assert_eq!(obj.r3[0]._01, "ABCD");
+    assert_eq!(obj.loop_0100.len(), 2);
+    assert_eq!(obj.loop_0200[0].n7._02, "123456");
+    assert_eq!(obj.loop_0250.len(), 2);
+    assert_eq!(obj.loop_0250[0].n9.len(), 1);
+    assert_eq!(obj.loop_0400[0].l1.len(), 1);
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_210_transmission() {
+    let str = r#"ISA*00*          *00*          *ZZ*CARRIER        *ZZ*SHIPPER        *231101*0900*U*00401*000000210*0*P*>~
+GS*IM*CARRIER*SHIPPER*20231101*0900*210*X*004010~
+ST*210*0001~
+B3**INV12345*SHIP123*PP**20231101*125000**20231030*035*ABCD~
+N1*BT*ACME SHIPPING~
+S5*1*LD~
+N1*SH*ACME SHIPPING~
+N4*CHICAGO*IL*60601*US~
+S5*2*UL~
+N1*CN*WIDGET CO~
+N4*DALLAS*TX*75201*US~
+LX*1~
+POD*20231031*1415*J SMITH~
+L5*1*FREIGHT ALL KINDS~
+L1*1*1.25*PH*100000~
+LX*2~
+L1*2***25000****FUE~
+L7*1*ABCD*100~
+L3*1000*G***125000~
+SE*18*0001~
+GE*1*210~
+IEA*1*000000210~
+"#;
+    let (rest, obj) = Transmission::<_210>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let invoice = &obj.functional_group[0].segments[0];
+    assert_eq!(invoice.loop_0250[0].loop_0260[0].n1._01, "SH");
+    assert_eq!(invoice.loop_0250[1].loop_0260[0].n1._01, "CN");
+    assert_eq!(invoice.loop_0400.len(), 2);
+    assert_eq!(invoice.loop_0400[0].pod.as_ref().unwrap()._03, "J SMITH");
+    assert_eq!(invoice.loop_0400[1].l1[0]._08, Some("FUE".to_string()));
+    assert_eq!(invoice.l3._05, Some("125000".to_string()));
+    assert_eq!(format!("{obj}"), str);
+}
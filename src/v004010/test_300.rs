@@ -0,0 +1,128 @@
+use crate::v004010::*;
+
+#[test]
+fn test_300() {
+    let obj = _300 {
+        st: ST {
+            _01: "300".to_string(),
+            _02: "0001".to_string(),
+        },
+        b1: B1 {
+            _01: Some("SNDR".to_string()),
+            _02: "BKG4711".to_string(),
+            _03: Some("20230601".to_string()),
+            _04: "N".to_string(),
+        },
+        loop_n1: vec![_300LoopN1 {
+            n1: N1 {
+                _01: "SH".to_string(),
+                _02: Some("ABC GMBH".to_string()),
+                _03: Some("25".to_string()),
+                _04: Some("312343123".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }],
+        loop_r4: vec![_300LoopR4 {
+            r4: R4 {
+                _01: "L".to_string(),
+                _02: Some("UN".to_string()),
+                _03: Some("DEHAM".to_string()),
+                _04: Some("HAMBURG".to_string()),
+                _05: Some("DE".to_string()),
+                ..Default::default()
+            },
+            dtm: vec![],
+        }],
+        y2: vec![Y2 {
+            _01: "1".to_string(),
+            _04: "45G1".to_string(),
+            ..Default::default()
+        }],
+        loop_lx: vec![_300LoopLX {
+            lx: LX {
+                _01: "1".to_string(),
+            },
+            l0: Some(L0 {
+                _01: Some("1".to_string()),
+                _04: Some("14000".to_string()),
+                _05: Some("G".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        se: SE {
+            _01: "8".to_string(),
+            _02: "0001".to_string(),
+        },
+        ..Default::default()
+    };
+    let obj = format!("{obj}");
+    assert_eq!(
+        obj,
+        r#"ST*300*0001~
+B1*SNDR*BKG4711*20230601*N~
+N1*SH*ABC GMBH*25*312343123~
+R4*L*UN*DEHAM*HAMBURG*DE~
+Y2*1***45G1~
+LX*1~
+L0*1***14000*G~
+SE*8*0001~
+"#
+    );
+}
+
+#[test]
+fn test_parse_300_nom() {
+    let str = r#"ST*300*0001~
+B1*SNDR*BKG4711*20230601*N~
+G61*IC*JOHN DOE*TE*4940123456~
+N9*SI*SI778899~
+N1*SH*ABC GMBH*25*312343123~
+N3*TEST STR. 56~
+N4*MUNICH*BY*80348*DE~
+N1*CN*XYZ LTDA~
+R4*R*UN*DEMUC*MUNICH*DE~
+DTM*118*20230610~
+R4*L*UN*DEHAM*HAMBURG*DE~
+R4*D*UN*BRSSZ*SANTOS*BR~
+W09*CZ*-18*CE~
+H3*MR~
+Y2*2***45R1~
+K1*PLEASE PROVIDE GENSET~
+LX*1~
+K1*KEEP FROZEN~
+L0*1***24000*G***1200*CTN**K~
+L5*1*FROZEN BEEF~
+LX*2~
+L0*2***14000*G***800*DRM**K~
+L5*1*RESIN~
+H1*1866*3*I*RESIN SOLUTION~
+H2*FLAMMABLE~
+V1*9450571*SYNERGY BUSAN*MH*324S****L~
+SE*27*0001~
+"#;
+    let (rest, obj) = _300::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b1._02, "BKG4711");
+    assert_eq!(obj.g61.len(), 1);
+    assert_eq!(obj.loop_n1.len(), 2);
+    assert_eq!(obj.loop_n1[0].n3.len(), 1);
+    assert_eq!(obj.loop_r4.len(), 3);
+    assert_eq!(obj.loop_r4[0].dtm.len(), 1);
+    assert_eq!(obj.w09.as_ref().unwrap()._02, Some("-18".to_string()));
+    assert_eq!(obj.k1.len(), 1);
+    assert_eq!(obj.loop_lx.len(), 2);
+    assert_eq!(obj.loop_lx[0].k1.len(), 1);
+    assert_eq!(obj.loop_lx[1].loop_h1[0].h1._01, "1866");
+    assert_eq!(obj.v1.len(), 1);
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn test_300_transmission() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *230601*1315*U*00401*000000001*0*P*>~GS*IO*SOURCE*TARGET*20230601*1315*1*X*004010~ST*300*0001~B1*SNDR*BKG4711*20230601*N~N1*SH*ABC GMBH*25*312343123~R4*L*UN*DEHAM*HAMBURG*DE~Y2*1***45G1~LX*1~L0*1***14000*G~SE*8*0001~GE*1*1~IEA*1*000000001~"#;
+    let (rest, obj) = Transmission::<_300>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group[0].segments[0].y2[0]._04, "45G1");
+}
@@ -0,0 +1,63 @@
+use crate::v004010::*;
+
+#[test]
+fn test_304_transmission() {
+    let edi = r#"ISA*00*          *00*          *ZZ*SOURCE         *02*TARGET         *230612*0930*U*00401*000000002*0*P*>~GS*SO*SOURCE*TARGET*20230612*0930*2*X*004010~ST*304*0002~B2**SNDR**BKG4711**PP~B2A*00~N9*BN*BKG4711~V1*9450571*SYNERGY BUSAN*MH*324S****L~N1*SH*ABC GMBH*25*312343123~N1*CN*XYZ LTDA~R4*L*UN*DEHAM*HAMBURG*DE~R4*D*UN*BRSSZ*SANTOS*BR~LX*1~N7*TCNU*6849731*24000*G~M7*SEAL123~L0*1***24000*G***1200*CTN**K~L5*1*FROZEN BEEF~SE*15*0002~GE*1*2~IEA*1*000000002~"#;
+    let (rest, obj) = Transmission::<_304>::parse(edi).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group[0].segments[0].b2._06, "PP");
+}
+
+#[test]
+fn test_304_parse() {
+    let edi = r#"ST*304*0002~
+B2**SNDR**BKG4711**PP~
+B2A*00~
+N9*BN*BKG4711*BOOKING NUMBER~
+N9*SI*SI778899~
+V1*9450571*SYNERGY BUSAN*MH*324S****L~
+N1*SH*ABC GMBH*25*312343123~
+N3*TEST STR. 56~
+N4*MUNICH*BY*80348*DE~
+G61*IC*JOHN DOE*TE*4940123456~
+N1*CN*XYZ LTDA~
+R4*L*UN*DEHAM*HAMBURG*DE~
+DTM*139*20230615~
+R4*D*UN*BRSSZ*SANTOS*BR~
+K1*ORIGINAL BILL OF LADING REQUIRED~
+C8***SHIPPER'S LOAD, STOW AND COUNT~
+C8C*SAID TO CONTAIN~
+LX*1~
+N7*TCNU*6849731*24000*G~
+M7*SEAL123~
+W09*CZ*-18*CE~
+L0*1***24000*G***1200*CTN**K~
+L5*1*FROZEN BEEF~
+L5*1*HS CODE 020230~
+LX*2~
+N7*MSKU*1234565*14000*G~
+L0*1***14000*G***800*DRM**K~
+L5*1*RESIN~
+H1*1866*3*I*RESIN SOLUTION~
+H2*FLAMMABLE~
+L3*38000*G~
+K1*FREIGHT PREPAID~
+SE*33*0002~
+"#;
+    let (rest, obj) = _304::parse(edi).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b2._04, Some("BKG4711".to_string()));
+    assert_eq!(obj.n9.len(), 2);
+    assert_eq!(obj.loop_n1.len(), 2);
+    assert_eq!(obj.loop_n1[0].g61.len(), 1);
+    assert_eq!(obj.loop_r4[0].dtm.len(), 1);
+    assert_eq!(obj.k1.len(), 1);
+    assert_eq!(obj.loop_c8[0].c8c.len(), 1);
+    assert_eq!(obj.loop_lx.len(), 2);
+    assert_eq!(obj.loop_lx[0].loop_n7[0].m7[0]._01, "SEAL123");
+    assert_eq!(obj.loop_lx[0].loop_l0[0].l5.len(), 2);
+    assert_eq!(obj.loop_lx[1].loop_l0[0].loop_h1[0].h2.len(), 1);
+    assert!(obj.l3.is_some());
+    assert_eq!(obj.k1_2[0]._01, "FREIGHT PREPAID");
+    assert_eq!(format!("{obj}"), edi);
+}
@@ -437,3 +437,47 @@ fn test_3() {
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn build_315() {
+    use crate::util::builder::SegmentBuilder;
+    let b4: B4 = SegmentBuilder::new()
+        .element(3, "AE")
+        .element(4, "20230104")
+        .element(7, "MSKU")
+        .element(8, "913109")
+        .build()
+        .unwrap();
+    let r4: R4 = SegmentBuilder::new()
+        .element(1, "L")
+        .element(2, "UN")
+        .element(3, "USLGB")
+        .build()
+        .unwrap();
+    let dtm: DTM = SegmentBuilder::new()
+        .element(1, "140")
+        .element(2, "20230104")
+        .build()
+        .unwrap();
+    let obj = _315::builder("0001")
+        .b4(b4)
+        .n9(N9 {
+            _01: "BM".to_string(),
+            _02: "ERXX412223".to_string(),
+            ..Default::default()
+        })
+        .loop_r4(_315LoopR4::builder().r4(r4).dtm(dtm).build().unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(obj.st._01, "315");
+    assert_eq!(obj.se._01, "6");
+    assert_eq!(obj.se._02, "0001");
+    let str = format!("{obj}");
+    let (rest, parsed) = _315::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(format!("{parsed}"), str);
+    assert!(SegmentBuilder::<R4>::new()
+        .element(12, "X")
+        .build()
+        .is_err());
+}
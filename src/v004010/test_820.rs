@@ -0,0 +1,77 @@
+use crate::util::amount::Amount;
+use crate::v004010::*;
+
+const REMITTANCE: &str = r#"ST*820*0001~
+BPR*C*1485.25*C*ACH*CTX*01*091000019*DA*123456789*1234567890**01*071000013*DA*987654321*20240301~
+TRN*1*0012345678*1234567890~
+CUR*PR*USD~
+REF*VV*0012345678~
+DTM*097*20240228~
+N1*PR*ACME DISTRIBUTION*91*ACME01~
+N3*100 MAIN STREET~
+N4*CHICAGO*IL*60601~
+N1*PE*WIDGET SUPPLY CO*91*WID01~
+ENT*1~
+NM1*PR*2*ACME STORE 12~
+RMR*IV*INV1001**1000.00*1020.00*20.00~
+REF*PO*PO7788~
+DTM*003*20240201~
+RMR*IV*INV1002**250~
+ADX*-5.00*01*IV*INV1002~
+NTE**PRICING DIFFERENCE~
+ENT*2~
+ADX*-10.00*04~
+RMR*IV*INV1003**250.25~
+SE*22*0001~
+"#;
+
+#[test]
+fn parse_820() {
+    let (rest, obj) = _820::parse(REMITTANCE).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.bpr._02, "1485.25");
+    assert_eq!(obj.trn.as_ref().unwrap()._02, "0012345678");
+    assert_eq!(obj.loop_n1.len(), 2);
+    assert_eq!(obj.loop_n1[0].n3.len(), 1);
+    assert_eq!(obj.loop_ent.len(), 2);
+    let first = &obj.loop_ent[0];
+    assert_eq!(first.loop_nm1[0].nm1._03, Some("ACME STORE 12".to_string()));
+    assert_eq!(first.loop_rmr.len(), 2);
+    assert_eq!(first.loop_rmr[0].r#ref[0]._02, Some("PO7788".to_string()));
+    assert_eq!(first.loop_rmr[1].loop_adx[0].adx._01, "-5.00");
+    assert_eq!(first.loop_rmr[1].loop_adx[0].nte.len(), 1);
+    let second = &obj.loop_ent[1];
+    assert_eq!(second.loop_adx[0].adx._02, "04");
+    assert_eq!(second.loop_rmr[0].rmr._02, Some("INV1003".to_string()));
+    assert_eq!(format!("{obj}"), REMITTANCE);
+}
+
+#[test]
+fn remittance_totals_820() {
+    let (_, obj) = _820::parse(REMITTANCE).unwrap();
+    assert_eq!(
+        obj.remittance_totals().unwrap(),
+        vec![Amount::from_cents(124500), Amount::from_cents(24025)]
+    );
+    let balance = obj.check_remittance().unwrap();
+    assert!(balance.is_balanced());
+    assert_eq!(balance.expected.to_string(), "1485.25");
+}
+
+#[test]
+fn remittance_mismatch_820() {
+    let (_, mut obj) = _820::parse(REMITTANCE).unwrap();
+    obj.bpr._02 = "1600".to_string();
+    let balance = obj.check_remittance().unwrap();
+    assert!(!balance.is_balanced());
+    assert_eq!(balance.difference().to_string(), "114.75");
+
+    obj.loop_ent[1].loop_adx.clear();
+    obj.loop_ent[0].loop_rmr[1].loop_adx.clear();
+    let balance = obj.check_remittance().unwrap();
+    assert_eq!(balance.difference().to_string(), "99.75");
+
+    obj.loop_ent[1].loop_rmr[0].rmr._04 = Some("12,50".to_string());
+    let err = obj.check_remittance().unwrap_err();
+    assert_eq!(err.to_string(), r#"invalid amount "12,50""#);
+}
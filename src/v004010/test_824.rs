@@ -0,0 +1,30 @@
+use crate::v004010::*;
+
+#[test]
+fn test_824() {
+    let str = r#"ST*824*0001~
+BGN*00*AA20240110*20240110~
+N1*SU*ACME SUPPLY*92*SUP01~
+PER*IC*JANE SMITH*TE*8005550100~
+OTI*TR*IV*INV1001*SENDER*RECEIVER*20240109*1200*17*0001*810*004010~
+REF*PO*PO7788~
+AMT*TT*1250.00~
+TED*K*PRICE DOES NOT MATCH PURCHASE ORDER*IT1*3**212*12.99~
+RED*CONTRACT PRICE 11.99*ZZ*PRC~
+OTI*TA*IV*INV1002~
+SE*11*0001~
+"#;
+    let (rest, obj) = _824::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.bgn._02, "AA20240110");
+    assert_eq!(obj.loop_n1[0].per.len(), 1);
+    assert_eq!(obj.loop_oti.len(), 2);
+    let rejected = &obj.loop_oti[0];
+    assert_eq!(rejected.oti._10, Some("810".to_string()));
+    assert_eq!(rejected.amt[0]._02, "1250.00");
+    assert_eq!(rejected.loop_ted[0].ted._03, Some("IT1".to_string()));
+    assert_eq!(rejected.loop_ted[0].red.len(), 1);
+    assert_eq!(obj.loop_oti[1].oti._03, "INV1002");
+    assert!(obj.loop_oti[1].loop_ted.is_empty());
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,64 @@
+use crate::v004010::*;
+
+#[test]
+fn test_850() {
+    let str = include_str!("../../test-data/004010_850.edi");
+    let (rest, obj) = Transmission::<_850>::parse(str).unwrap();
+    assert!(rest.trim().is_empty());
+    let po = &obj.functional_group[0].segments[0];
+    assert_eq!(po.beg._03, "A99999-01");
+    assert_eq!(po.loop_n1.len(), 2);
+    assert_eq!(po.loop_po1.len(), 7);
+    assert_eq!(po.loop_po1[2].po1._02, Some("32".to_string()));
+    assert_eq!(
+        po.loop_po1[2].loop_pid[0].pid._05,
+        Some("POTATO CHIPS".to_string())
+    );
+    assert_eq!(po.ctt.as_ref().unwrap()._01, "7");
+    let s = format!("{obj}");
+    assert_eq!(s.replace('\n', ""), str.replace(['\n', '\r'], ""));
+}
+
+#[test]
+fn test_855() {
+    let str = r#"ST*855*0001~
+BAK*00*AC*A99999-01*19970215~
+REF*VR*54321~
+N1*ST*BUYSNACKS PORT*9*1223334445~
+PO1*1*16*CA*12.34**CB*000111111*UA*002840022222~
+PID*F****CRUNCHY CHIPS LSS~
+ACK*IA*16*CA*068*19970220~
+PO1*2*13*CA*12.34**CB*000555555*UA*002840033333~
+ACK*IR*13*CA~
+CTT*2~
+SE*11*0001~
+"#;
+    let (rest, obj) = _855::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.bak._02, "AC");
+    assert_eq!(obj.loop_po1.len(), 2);
+    assert_eq!(obj.loop_po1[0].ack[0]._01, "IA");
+    assert_eq!(obj.loop_po1[1].ack[0]._01, "IR");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn test_860() {
+    let str = r#"ST*860*0001~
+BCH*04*SA*A99999-01**1*19970220~
+DTM*002*19971226~
+N1*ST*BUYSNACKS PORT*9*1223334445~
+POC*1*QI*20*4*CA*12.34**CB*000111111*UA*002840022222~
+PID*F****CRUNCHY CHIPS LSS~
+POC*2*DI~
+CTT*2~
+SE*9*0001~
+"#;
+    let (rest, obj) = _860::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.bch._05, Some("1".to_string()));
+    assert_eq!(obj.loop_poc.len(), 2);
+    assert_eq!(obj.loop_poc[0].poc._02, "QI");
+    assert_eq!(obj.loop_poc[1].poc._02, "DI");
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,122 @@
+use crate::util::hl::walk;
+use crate::v004010::*;
+
+#[test]
+fn test_856_tree() {
+    let str = include_str!("../../test-data/004010_856.edi");
+    let (rest, obj) = Transmission::<_856>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let asn = &obj.functional_group[0].segments[0];
+    assert_eq!(asn.bsn._02, "SHP20240305");
+    // shipment -> order -> 2 packs -> 1 item each
+    assert_eq!(asn.loop_hl.len(), 1);
+    let shipment = &asn.loop_hl[0];
+    assert_eq!(shipment.level(), "S");
+    assert_eq!(shipment.td1.len(), 1);
+    assert_eq!(shipment.r#ref.len(), 1);
+    assert_eq!(shipment.loop_n1.len(), 2);
+    assert_eq!(shipment.loop_hl.len(), 1);
+    let order = &shipment.loop_hl[0];
+    assert_eq!(order.level(), "O");
+    assert_eq!(order.prf.as_ref().unwrap()._01, "PO778899");
+    let packs: Vec<_> = order.loop_hl.iter().map(|p| p.level()).collect();
+    assert_eq!(packs, vec!["P", "P"]);
+    let pack = &order.loop_hl[1];
+    assert_eq!(pack.man[0]._02, Some("00000123450000000025".to_string()));
+    assert_eq!(pack.loop_hl.len(), 1);
+    assert_eq!(pack.loop_hl[0].level(), "I");
+    assert_eq!(pack.loop_hl[0].pid[0]._05, Some("WIDGET 200".to_string()));
+    assert_eq!(walk(&asn.loop_hl).len(), 6);
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn test_856_renumber() {
+    let str = include_str!("../../test-data/004010_856.edi");
+    let (_, mut obj) = Transmission::<_856>::parse(str).unwrap();
+    let asn = &mut obj.functional_group[0].segments[0];
+    // consolidate both items into the first pack
+    let order = &mut asn.loop_hl[0].loop_hl[0];
+    let mut second = order.loop_hl.remove(1);
+    order.loop_hl[0].loop_hl.append(&mut second.loop_hl);
+    let s = format!("{}", obj.functional_group[0].segments[0]);
+    let hls: Vec<&str> = s.lines().filter(|l| l.starts_with("HL*")).collect();
+    assert_eq!(
+        hls,
+        vec![
+            "HL*1**S~",
+            "HL*2*1*O~",
+            "HL*3*2*P~",
+            "HL*4*3*I~",
+            "HL*5*3*I~"
+        ]
+    );
+    // the rendered form parses back into the same tree
+    let (_, again) = _856::parse(&s).unwrap();
+    let levels: Vec<(&str, Option<&str>)> = walk(&again.loop_hl)
+        .into_iter()
+        .map(|hl| {
+            (
+                hl.hl._01.as_str(),
+                hl.hl._02.as_deref().filter(|p| !p.is_empty()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        levels,
+        vec![
+            ("1", None),
+            ("2", Some("1")),
+            ("3", Some("2")),
+            ("4", Some("3")),
+            ("5", Some("3"))
+        ]
+    );
+    assert_eq!(again.loop_hl[0].loop_hl[0].loop_hl[0].loop_hl.len(), 2);
+}
+
+#[test]
+fn test_856_ctt_counts_rendered_levels() {
+    let str = include_str!("../../test-data/004010_856.edi");
+    let (_, mut obj) = Transmission::<_856>::parse(str).unwrap();
+    let asn = &mut obj.functional_group[0].segments[0];
+    assert_eq!(asn.ctt.as_ref().unwrap()._01, "6");
+    // add a third pack with an item to the order
+    let order = &mut asn.loop_hl[0].loop_hl[0];
+    let pack = order.loop_hl[1].clone();
+    order.loop_hl.push(pack);
+    let s = format!("{asn}");
+    assert_eq!(s.lines().filter(|l| l.starts_with("HL*")).count(), 8);
+    assert!(s.contains("\nCTT*8~\n"));
+    let (_, again) = _856::parse(&s).unwrap();
+    assert_eq!(again.ctt.unwrap()._01, "8");
+}
+
+#[test]
+fn xml_856_nested_levels() {
+    use crate::util::xml;
+    let str = include_str!("../../test-data/004010_856.edi");
+    let (_, obj) = Transmission::<_856>::parse(str).unwrap();
+    let asn = &obj.functional_group[0].segments[0];
+    let x = xml::to_string(asn).unwrap();
+    // nested levels are loops of their parent level
+    assert!(x.contains("  <LoopHL>\n    <HL>\n"));
+    assert!(x.contains("    <LoopHL>\n      <HL>\n"));
+    assert!(!x.contains("CHILDREN"));
+    let back: _856 = xml::from_str(&x).unwrap();
+    assert_eq!(format!("{back}"), format!("{asn}"));
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn json_schema_856() {
+    use crate::util::schema::json_schema;
+    let schema = json_schema::<_856>(segment_descriptor).unwrap();
+    assert_eq!(schema["$ref"], "#/$defs/_856");
+    let hl = &schema["$defs"]["_856LoopHL"];
+    assert_eq!(
+        hl["properties"]["loop_hl"]["items"]["$ref"],
+        "#/$defs/_856LoopHL"
+    );
+    assert_eq!(schema["$defs"]["HL"]["properties"]["03"]["minLength"], 1);
+}
@@ -0,0 +1,138 @@
+use crate::v004010::*;
+
+#[test]
+fn parse_940() {
+    let str = r#"ST*940*0001~
+W05*N*538686**001538686~
+N1*ST*SHIP TO LOCATION*9*1234567890001~
+N3*123 MAIN STREET~
+N4*ANYTOWN*PA*19101*US~
+N1*SF*WAREHOUSE*9*1234567890002~
+N9*PO*PO12345~
+G62*10*20240115~
+NTE*WHI*DELIVER TO DOCK 3~
+LX*1~
+W01*12*CA*000100000001*VN*ABC123~
+G69*WIDGET BLUE~
+N9*LI*1~
+LX*2~
+W01*5*CA*000100000002*VN*ABC456~
+G69*WIDGET RED~
+W66*PP*M***UPS GROUND*****UPSN~
+W76*17*340*LB~
+SE*19*0001~
+"#;
+    let (rest, obj) = _940::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.w05._02, "538686");
+    assert_eq!(obj.loop_0100.len(), 2);
+    assert_eq!(obj.loop_0100[0].n3.len(), 1);
+    assert_eq!(obj.nte.len(), 1);
+    assert_eq!(obj.loop_0300.len(), 2);
+    assert_eq!(obj.loop_0300[0].loop_0310[0].w01._01, "12");
+    assert_eq!(obj.loop_0300[0].loop_0310[0].n9.len(), 1);
+    assert_eq!(obj.loop_0300[1].loop_0310[0].g69[0]._01, "WIDGET RED");
+    assert_eq!(obj.w66._01, "PP");
+    assert_eq!(obj.w66._05, Some("UPS GROUND".to_string()));
+    assert_eq!(obj.w66._10, Some("UPSN".to_string()));
+    assert_eq!(obj.w76.as_ref().unwrap()._01, "17");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_943() {
+    let str = r#"ST*943*0002~
+W06*N*ST123456*20240115*SH98765~
+N1*ST*WAREHOUSE*9*1234567890002~
+N9*BM*BOL778899~
+G62*17*20240118~
+W27*M*ABCD~
+W04*100*CA*000100000001~
+G69*WIDGET BLUE~
+W04*40*CA*000100000002~
+W03*140*2800*LB~
+SE*11*0002~
+"#;
+    let (rest, obj) = _943::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.w06._02, Some("ST123456".to_string()));
+    assert_eq!(obj.w27._02, Some("ABCD".to_string()));
+    assert_eq!(obj.loop_0200.len(), 2);
+    assert_eq!(obj.loop_0200[1].w04._01, "40");
+    assert_eq!(obj.w03._01, "140");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_944() {
+    let str = r#"ST*944*0003~
+W17*F*20240118*WR5544*ST123456~
+N1*WH*WAREHOUSE*9*1234567890002~
+G62*09*20240118~
+LX*1~
+W07*98*CA*000100000001~
+N9*LT*LOT0042~
+W07*2*CA*000100000001~
+LX*2~
+W07*40*CA*000100000002~
+W14*140~
+SE*12*0003~
+"#;
+    let (rest, obj) = _944::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.w17._03, "WR5544");
+    assert_eq!(obj.loop_0200.len(), 2);
+    assert_eq!(obj.loop_0200[0].loop_0210.len(), 2);
+    assert_eq!(obj.loop_0200[0].loop_0210[0].n9[0]._02, "LOT0042");
+    assert_eq!(obj.w14._01, "140");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_945() {
+    let str = r#"ST*945*0004~
+W06*F*538686*20240116*SH2001**PO12345~
+N1*ST*SHIP TO LOCATION*9*1234567890001~
+N1*SF*WAREHOUSE*9*1234567890002~
+G62*11*20240116~
+W27*M*ABCD~
+LX*1~
+MAN*GM*00000123450000000017~
+W12*CC*12*12*0*CA*000100000001~
+N9*LT*LOT0042~
+LX*2~
+W12*CP*5*4*1*CA*000100000002~
+W03*16*320*LB~
+SE*15*0004~
+"#;
+    let (rest, obj) = _945::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.w06._06, Some("PO12345".to_string()));
+    assert_eq!(obj.loop_0100.len(), 2);
+    assert_eq!(obj.loop_0200.len(), 2);
+    assert_eq!(obj.loop_0200[0].man.len(), 1);
+    assert_eq!(obj.loop_0200[1].loop_0210[0].w12._01, "CP");
+    assert_eq!(obj.loop_0200[1].loop_0210[0].w12._04, Some("1".to_string()));
+    assert_eq!(obj.w03._01, "16");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_947() {
+    let str = r#"ST*947*0005~
+W15*20240120*ADJ001~
+N1*WH*WAREHOUSE*9*1234567890002~
+W19*AA*-2*CA*000100000001~
+G69*WIDGET BLUE~
+N9*LT*LOT0042~
+W19*07*1*CA*000100000002~
+SE*8*0005~
+"#;
+    let (rest, obj) = _947::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.w15._02, "ADJ001");
+    assert_eq!(obj.loop_0200.len(), 2);
+    assert_eq!(obj.loop_0200[0].w19._02, "-2");
+    assert_eq!(obj.loop_0200[1].w19._01, "07");
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,84 @@
+use crate::util::builder::BuildError;
+use crate::v004010::*;
+
+const TENDER: &str = r#"ST*204*18711~
+B2**SNDR**6XULT02DCM**DE~
+B2A*00~
+L11*6XULT02DCM*9R~
+G62*64*20221121**1513*LT~
+S5*1*LD~
+N1*PW*BNSF OAKLAND RAMP~
+S5*2*DT~
+N1*DA*OAKLAND INTERNATIONAL CONTAINER TERMINAL~
+SE*10*18711~
+"#;
+
+#[test]
+fn parse_990() {
+    let str = r#"ST*990*0001~
+B1*SNDR*6XULT02DCM*20221121*D~
+N9*CN*PRO12345~
+K1*NO EQUIPMENT AVAILABLE~
+SE*5*0001~
+"#;
+    let (rest, obj) = _990::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b1._02, "6XULT02DCM");
+    assert_eq!(obj.k1[0]._01, "NO EQUIPMENT AVAILABLE");
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn respond_to_204() {
+    let (_, tender) = _204::parse(TENDER).unwrap();
+    let accept = _990::respond_to(&tender, "0001", "A", None).unwrap();
+    assert_eq!(
+        format!("{accept}"),
+        "ST*990*0001~\nB1*SNDR*6XULT02DCM*20221121*A~\nSE*3*0001~\n"
+    );
+    let decline = _990::respond_to(&tender, "0002", "D", Some("NO EQUIPMENT AVAILABLE")).unwrap();
+    assert_eq!(
+        format!("{decline}"),
+        "ST*990*0002~\nB1*SNDR*6XULT02DCM*20221121*D~\nK1*NO EQUIPMENT AVAILABLE~\nSE*4*0002~\n"
+    );
+    // a tender without shipment ID cannot be answered
+    let mut tender = tender;
+    tender.b2._04 = None;
+    let err = _990::respond_to(&tender, "0003", "A", None).unwrap_err();
+    assert!(matches!(
+        err,
+        BuildError::MissingElement {
+            segment: "B1",
+            position: 2,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn respond_to_204_date_and_reason() {
+    let (_, mut tender) = _204::parse(TENDER).unwrap();
+    // a delivery date is not the date of the tender
+    tender.g62.as_mut().unwrap()._01 = Some("54".to_string());
+    let accept = _990::respond_to(&tender, "0001", "A", None).unwrap();
+    assert_eq!(accept.b1._03, None);
+    // neither is a G62 carrying only a time
+    tender.g62 = Some(G62 {
+        _03: Some("U".to_string()),
+        _04: Some("1513".to_string()),
+        ..Default::default()
+    });
+    let accept = _990::respond_to(&tender, "0001", "A", None).unwrap();
+    assert_eq!(accept.b1._03, None);
+    // K1-01 holds at most 30 characters
+    let reason = "NO EQUIPMENT AVAILABLE IN THE OAKLAND AREA";
+    let err = _990::respond_to(&tender, "0002", "D", Some(reason)).unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::ElementTooLong {
+            segment: "K1",
+            position: 1,
+            max: 30
+        }
+    );
+}
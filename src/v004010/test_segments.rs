@@ -313,3 +313,45 @@ fn test_se() {
 //     let obj = parse_301(&str);
 //     println!("{:?}", obj);
 // }
+
+#[test]
+fn st_metadata() {
+    use crate::util::meta::{DataType, Requirement, SegmentMeta};
+    let obj = ST {
+        _01: "204".to_string(),
+        _02: "0001".to_string(),
+    };
+    let desc = obj.descriptor();
+    assert_eq!(desc.id, "ST");
+    assert_eq!(desc.name, "Transaction Set Header");
+    let e = desc.element(2).unwrap();
+    assert_eq!(e.id, "329");
+    assert_eq!(e.name, "Transaction Set Control Number");
+    assert_eq!(e.data_type, DataType::AlphaNumeric);
+    assert_eq!(e.requirement, Requirement::Mandatory);
+    assert_eq!((e.min, e.max), (4, 9));
+    assert_eq!(obj.element(1), Some("204"));
+    assert_eq!(obj.element(3), None);
+    assert_eq!(
+        obj.element_by_name("transaction set control number"),
+        Some("0001")
+    );
+}
+
+#[test]
+fn b2_metadata() {
+    use crate::util::meta::SegmentMeta;
+    let obj = B2 {
+        _02: Some("SCAC".to_string()),
+        _04: Some("SHIPMENT1".to_string()),
+        _06: "PP".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(obj.element_count(), 12);
+    assert_eq!(obj.element(1), None);
+    assert_eq!(
+        obj.element_by_name("Shipment Identification Number"),
+        Some("SHIPMENT1")
+    );
+    assert_eq!(B2::DESCRIPTOR.elements_by_id("140").count(), 1);
+}
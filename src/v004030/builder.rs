@@ -0,0 +1,278 @@
+use super::*;
+use crate::util::builder::{loop_builder, transaction_builder};
+
+transaction_builder! {
+    _204Builder => _204 ("204") {
+        segments: [b2: B2, b2a: B2A],
+        optional: [g62: G62, ms3: MS3, at5: AT5, pld: PLD, nte: NTE, l3: L3],
+        repeated: [l11: L11, lh6: LH6],
+        loops: [loop_100: _204Loop100, loop_200: _204Loop200, loop_300: _204Loop300],
+    }
+}
+
+loop_builder! {
+    _204Loop100Builder => _204Loop100 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4, l11: L11],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _204Loop200Builder => _204Loop200 {
+        trigger: [n7: N7],
+        optional: [n7a: N7A, n7b: N7B, mea: MEA, m7: M7],
+    }
+}
+
+loop_builder! {
+    _204Loop300Builder => _204Loop300 {
+        segments: [s5: S5],
+        optional: [at8: AT8, pld: PLD],
+        repeated: [l11: L11, g62: G62, lad: LAD, at5: AT5, nte: NTE],
+        loops: [
+            loop_310: _204Loop310,
+            loop_320: _204Loop320,
+            loop_350: _204Loop350,
+            loop_380: _204Loop380,
+        ],
+    }
+}
+
+loop_builder! {
+    _204Loop310Builder => _204Loop310 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, g61: G61],
+    }
+}
+
+loop_builder! {
+    _204Loop320Builder => _204Loop320 {
+        trigger: [l5: L5],
+        optional: [at8: AT8],
+        loops: [loop_325: _204Loop325],
+    }
+}
+
+loop_builder! {
+    _204Loop325Builder => _204Loop325 {
+        trigger: [g61: G61],
+        optional: [lh6: LH6],
+        repeated: [l11: L11],
+        loops: [loop_330: _204Loop330],
+    }
+}
+
+loop_builder! {
+    _204Loop330Builder => _204Loop330 {
+        trigger: [lh1: LH1],
+        optional: [lh4: LH4],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH, lep: LEP, lht: LHT],
+    }
+}
+
+loop_builder! {
+    _204Loop350Builder => _204Loop350 {
+        trigger: [oid: OID],
+        repeated: [g62: G62, lad: LAD],
+        loops: [loop_360: _204Loop360],
+    }
+}
+
+loop_builder! {
+    _204Loop360Builder => _204Loop360 {
+        trigger: [l5: L5],
+        optional: [at8: AT8],
+        loops: [loop_365: _204Loop365],
+    }
+}
+
+loop_builder! {
+    _204Loop365Builder => _204Loop365 {
+        trigger: [g61: G61],
+        repeated: [l11: L11, lh6: LH6],
+        loops: [loop_370: _204Loop370],
+    }
+}
+
+loop_builder! {
+    _204Loop370Builder => _204Loop370 {
+        trigger: [lh1: LH1],
+        optional: [lh4: LH4],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH, lep: LEP, lht: LHT],
+    }
+}
+
+loop_builder! {
+    _204Loop380Builder => _204Loop380 {
+        trigger: [n7: N7],
+        optional: [n7a: N7A, n7b: N7B, mea: MEA, m7: M7],
+    }
+}
+
+transaction_builder! {
+    _210Builder => _210 ("210") {
+        segments: [b3: B3, l3: L3],
+        optional: [c3: C3, itd: ITD],
+        repeated: [n9: N9, g62: G62, r3: R3, h3: H3, k1: K1],
+        loops: [
+            loop_0100: _210Loop0100,
+            loop_0200: _210Loop0200,
+            loop_0250: _210Loop0250,
+            loop_0400: _210Loop0400,
+        ],
+    }
+}
+
+loop_builder! {
+    _210Loop0100Builder => _210Loop0100 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, n9: N9],
+    }
+}
+
+loop_builder! {
+    _210Loop0200Builder => _210Loop0200 {
+        segments: [n7: N7],
+        repeated: [m7: M7],
+    }
+}
+
+loop_builder! {
+    _210Loop0250Builder => _210Loop0250 {
+        segments: [s5: S5],
+        repeated: [n9: N9, g62: G62, h3: H3],
+        loops: [loop_0260: _210Loop0260],
+    }
+}
+
+loop_builder! {
+    _210Loop0260Builder => _210Loop0260 {
+        segments: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, n9: N9],
+    }
+}
+
+loop_builder! {
+    _210Loop0400Builder => _210Loop0400 {
+        segments: [lx: LX],
+        optional: [pod: POD],
+        repeated: [n9: N9, l5: L5, h1: H1, h2: H2, l0: L0, l1: L1, l4: L4, l7: L7, k1: K1],
+    }
+}
+
+transaction_builder! {
+    _214Builder => _214 ("214") {
+        segments: [b10: B10],
+        repeated: [l11: L11, man: MAN, k1: K1, ms3: MS3],
+        loops: [loop_0100: _214Loop0100, loop_0200: _214Loop0200],
+    }
+}
+
+loop_builder! {
+    _214Loop0100Builder => _214Loop0100 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4, g61: G61, g62: G62],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0200Builder => _214Loop0200 {
+        segments: [lx: LX],
+        repeated: [l11: L11, man: MAN, q7: Q7, k1: K1, at5: AT5, at8: AT8],
+        loops: [
+            loop_0205: _214Loop0205,
+            loop_0210: _214Loop0210,
+            loop_0230: _214Loop0230,
+            loop_0250: _214Loop0250,
+            loop_0260: _214Loop0260,
+        ],
+    }
+}
+
+loop_builder! {
+    _214Loop0205Builder => _214Loop0205 {
+        segments: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Builder => _214Loop0210 {
+        trigger: [cd3: CD3],
+        optional: [nm1: NM1, at8: AT8],
+        repeated: [l11: L11, q7: Q7, man: MAN],
+        loops: [loop_0215: _214Loop0210Loop0215, loop_0220: _214Loop0210Loop0220],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Loop0215Builder => _214Loop0210Loop0215 {
+        trigger: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0210Loop0220Builder => _214Loop0210Loop0220 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0230Builder => _214Loop0230 {
+        trigger: [prf: PRF],
+        loops: [loop_0231: _214Loop0231, loop_0233: _214Loop0233],
+    }
+}
+
+loop_builder! {
+    _214Loop0231Builder => _214Loop0231 {
+        trigger: [n1: N1],
+        optional: [n2: N2, n4: N4],
+        repeated: [n3: N3, l11: L11],
+    }
+}
+
+loop_builder! {
+    _214Loop0233Builder => _214Loop0233 {
+        trigger: [cd3: CD3],
+        repeated: [l11: L11],
+        loops: [loop_0240: _214Loop0240],
+    }
+}
+
+loop_builder! {
+    _214Loop0240Builder => _214Loop0240 {
+        trigger: [at7: AT7],
+        optional: [ms1: MS1, ms2: MS2],
+    }
+}
+
+loop_builder! {
+    _214Loop0250Builder => _214Loop0250 {
+        trigger: [spo: SPO],
+        optional: [sdq: SDQ],
+    }
+}
+
+loop_builder! {
+    _214Loop0260Builder => _214Loop0260 {
+        trigger: [efi: EFI],
+        segments: [bin: BIN],
+    }
+}
+
+transaction_builder! {
+    _990Builder => _990 ("990") {
+        segments: [b1: B1],
+        optional: [n9: N9, g62: G62],
+        repeated: [k1: K1],
+    }
+}
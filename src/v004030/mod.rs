@@ -1 +1,925 @@
 //! v004030 repesents all entities of the 004030 specification.
+//!
+//! The segment dictionary reuses the 004010 element definitions, except for
+//! element 127 (Reference Identification) which is 1/50 in 004030. Segment
+//! layouts are exercised by the 204, 210, 214 and 990 samples in the tests,
+//! which cover AT7, AT8, B1, B10, B2, B2A, B3, C3, G62, K1, L0, L1, L11, L3,
+//! L5, LX, MS1, N1, N3, N4, N7, N9, R3 and S5; the other segments are carried
+//! over from 004010 unchecked.
+
+use crate::util::Parser;
+pub use builder::*;
+use nom::combinator::opt;
+use nom::combinator::peek;
+use nom::multi::many0;
+use nom::IResult;
+use nom::Parser as _;
+pub use segment::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::fmt::Display;
+use x12_types_macros::{DisplayX12, ParseX12};
+
+mod builder;
+mod segment;
+
+#[cfg(test)]
+mod test_204;
+#[cfg(test)]
+mod test_210;
+#[cfg(test)]
+mod test_214;
+#[cfg(test)]
+mod test_990;
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct Transmission<T> {
+    pub isa: ISA,
+    pub functional_group: Vec<FunctionalGroup<T>>,
+    pub iea: IEA,
+}
+
+impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
+    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
+        let mut output = Transmission::default();
+        let (input, obj) = ISA::parse(input)?;
+        output.isa = obj;
+        // functional group
+        let (input, gs) = GS::parse(input)?;
+        let (input, t_obj) = many0(T::parse).parse(input)?;
+        // let (input, t_obj) = T::parse(input)?;
+        let (input, ge) = GE::parse(input)?;
+        let fg = FunctionalGroup {
+            gs,
+            segments: t_obj,
+            // segments: vec![t_obj],
+            ge,
+        };
+        output.functional_group.push(fg);
+        let (input, obj) = IEA::parse(input)?;
+        output.iea = obj;
+        Ok((input, output))
+    }
+}
+
+impl<T: Display> Display for Transmission<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines = vec![];
+        lines.push(format!("{}", self.isa));
+        for fg in &self.functional_group {
+            lines.push(format!("{}", fg.gs));
+            for segment in &fg.segments {
+                lines.push(format!("{}", segment));
+            }
+            lines.push(format!("{}", fg.ge));
+        }
+        lines.push(format!("{}", self.iea));
+        let all = lines.join("");
+        write!(f, "{all}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct FunctionalGroup<T> {
+    pub gs: GS,
+    pub segments: Vec<T>,
+    pub ge: GE,
+}
+
+/// 204 - Motor Carrier Load Tender
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Motor Carrier Load Tender Transaction Set (204) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used to allow shippers or other interested parties to offer (tender) a shipment to a full load (truckload) motor carrier including detailed scheduling, equipment requirements, commodities, and shipping instructions pertinent to a load tender. It is not to be used to provide a motor carrier with data relative to a Less-than-Truckload bill of lading, pick-up notification, or manifest.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B2 | Beginning Segment for Shipment Information Transaction | M | 1
+/// 0030 | B2A | Set Purpose | M | 1
+/// 0080 | L11 | Business Instructions and Reference Number | O | 50
+/// 0090 | G62 | Date/Time | O | 1
+/// 0100 | MS3 | Interline Information | O | 1
+/// 0110 | AT5 | Bill of Lading Handling Requirements | O | 6
+/// 0120 | PLD | Pallet Information | O | 1
+/// 0125 | LH6 | Hazardous Certification | O | 6
+/// 0130 | NTE | Note/Special Instruction | O | 10
+/// LOOP ID - 0100 | 5
+/// 0100 -> 0140 | N1 | Name | O | 1
+/// 0100 -> 0150 | N2 | Additional Name Information | O | 1
+/// 0100 -> 0160 | N3 | Address Information | O | 2
+/// 0100 -> 0170 | N4 | Geographic Location | O | 1
+/// 0100 -> 0180 | L11 | Business Instructions and Reference Number | O | 1
+/// 0100 -> 0190 | G61 | Contact | O | 3
+/// LOOP ID - 0200 | 10
+/// 0200 -> 0200 | N7 | Equipment Details | O | 1
+/// 0200 -> 0203 | N7A | Accessorial Equipment Details | O | 1
+/// 0200 -> 0205 | N7B | Additional Equipment Details | O | 1
+/// 0200 -> 0208 | MEA | Measurements | O | 1
+/// 0200 -> 0210 | M7 | Seal Numbers | O | 2
+/// LOOP ID - 0300 | 999
+/// 0300 -> 0010 | S5 | Stop Off Details | M | 1
+/// 0300 -> 0020 | L11 | Business Instructions and Reference Number | O | 50
+/// 0300 -> 0030 | G62 | Date/Time | O | 2
+/// 0300 -> 0040 | AT8 | Shipment Weight, Packaging and Quantity Data | O | 1
+/// 0300 -> 0050 | LAD | Lading Detail | O | 999
+/// 0300 -> 0060 | AT5 | Bill of Lading Handling Requirements | O | 6
+/// 0300 -> 0063 | PLD | Pallet Information | O | 1
+/// 0300 -> 0065 | NTE | Note/Special Instruction | O | 20
+/// 0300 -> LOOP ID - 0310 | 1 |  
+/// 0300 -> 0310 -> 0070 | N1 | Name | O | 1
+/// 0300 -> 0310 -> 0080 | N2 | Additional Name Information | O | 1
+/// 0300 -> 0310 -> 0090 | N3 | Address Information | O | 2
+/// 0300 -> 0310 -> 0100 | N4 | Geographic Location | O | 1
+/// 0300 -> 0310 -> 0120 | G61 | Contact | O | 3
+/// 0300 -> LOOP ID - 0320 | 99 |  
+/// 0300 -> 0320 -> 0130 | L5 | Description, Marks and Numbers | O | 1
+/// 0300 -> 0320 -> 0135 | AT8 | Shipment Weight, Packaging and Quantity Data | O | 1
+/// 0300 -> 0320 -> LOOP ID - 0325 | 99 |   |  
+/// 0300 -> 0320 -> 0325 -> 0140 | G61 | Contact | O | 1
+/// 0300 -> 0320 -> 0325 -> 0141 | L11 | Business Instructions and Reference Number | O | 5
+/// 0300 -> 0320 -> 0325 -> 0142 | LH6 | Hazardous Certification | O | 6
+/// 0300 -> 0320 -> 0325 -> LOOP ID - 0330 | 25 |   |   |  
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0143 | LH1 | Hazardous Identification Information | O | 1
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0144 | LH2 | Hazardous Classification Information | O | 4
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0145 | LH3 | Hazardous Material Shipping Name | O | 10
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0146 | LFH | Freeform Hazardous Material Information | O | 20
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0147 | LEP | EPA Required Data | O | 3
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0148 | LH4 | Canadian Dangerous Requirements | O | 1
+/// 0300 -> 0320 -> 0325 -> 0330 -> 0149 | LHT | Transborder Hazardous Requirements | O | 3
+/// 0300 -> LOOP ID - 0350 | 999 |  
+/// 0300 -> 0350 -> 0150 | OID | Order Identification Detail | O | 1
+/// 0300 -> 0350 -> 0160 | G62 | Date/Time | O | 2
+/// 0300 -> 0350 -> 0180 | LAD | Lading Detail | O | 999
+/// 0300 -> 0350 -> LOOP ID - 0360 | 99 |   |  
+/// 0300 -> 0350 -> 0360 -> 0190 | L5 | Description, Marks and Numbers | O | 1
+/// 0300 -> 0350 -> 0360 -> 0195 | AT8 | Shipment Weight, Packaging and Quantity Data | O | 1
+/// 0300 -> 0350 -> 0360 -> LOOP ID - 0365 | 99 |   |   |  
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0200 | G61 | Contact | O | 1
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0201 | L11 | Business Instructions and Reference Number | O | 5
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0202 | LH6 | Hazardous Certification | O | 6
+/// 0300 -> 0350 -> 0360 -> 0365 -> LOOP ID - 0370 | 25 |   |   |   |  
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0203 | LH1 | Hazardous Identification Information | O | 1
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0204 | LH2 | Hazardous Classification Information | O | 4
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0205 | LH3 | Hazardous Material Shipping Name | O | 10
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0206 | LFH | Freeform Hazardous Material Information | O | 20
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0207 | LEP | EPA Required Data | O | 3
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0208 | LH4 | Canadian Dangerous Requirements | O | 1
+/// 0300 -> 0350 -> 0360 -> 0365 -> 0370 -> 0209 | LHT | Transborder Hazardous Requirements | O | 3
+/// 0300 -> LOOP ID - 0380 | 10 |  
+/// 0300 -> 0380 -> 0210 | N7 | Equipment Details | O | 1
+/// 0300 -> 0380 -> 0220 | N7A | Accessorial Equipment Details | O | 1
+/// 0300 -> 0380 -> 0230 | N7B | Additional Equipment Details | O | 1
+/// 0300 -> 0380 -> 0240 | MEA | Measurements | O | 1
+/// 0300 -> 0380 -> 0250 | M7 | Seal Numbers | O | 2
+/// 9010 | L3 | Total Weight and Charges | O | 1
+/// 9020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204 {
+    pub st: ST,
+    pub b2: B2,
+    pub b2a: B2A,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub l11: Vec<L11>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub g62: Option<G62>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ms3: Option<MS3>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at5: Option<AT5>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pld: Option<PLD>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lh6: Vec<LH6>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nte: Option<NTE>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub loop_100: Vec<_204Loop100>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub loop_200: Vec<_204Loop200>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub loop_300: Vec<_204Loop300>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l3: Option<L3>,
+    pub se: SE,
+}
+
+impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
+    fn parse(input: &'a str) -> IResult<&'a str, _204> {
+        let mut output = _204::default();
+        let (rest, obj) = ST::parse(input)?;
+        output.st = obj;
+        let (rest, obj) = B2::parse(rest)?;
+        output.b2 = obj;
+        let (rest, obj) = B2A::parse(rest)?;
+        output.b2a = obj;
+        let (rest, obj) = many0(L11::parse).parse(rest)?;
+        output.l11 = obj;
+        let (rest, obj) = opt(G62::parse).parse(rest)?;
+        output.g62 = obj;
+        let (rest, obj) = opt(MS3::parse).parse(rest)?;
+        output.ms3 = obj;
+        let (rest, obj) = opt(AT5::parse).parse(rest)?;
+        output.at5 = obj;
+        let (rest, obj) = opt(PLD::parse).parse(rest)?;
+        output.pld = obj;
+        let (rest, obj) = many0(LH6::parse).parse(rest)?;
+        output.lh6 = obj;
+        let (rest, obj) = opt(NTE::parse).parse(rest)?;
+        output.nte = obj;
+        // loop 100
+        let mut loop_100 = vec![];
+        let mut loop_rest = rest;
+        while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
+            let (rest, n2) = opt(N2::parse).parse(rest)?;
+            let (rest, n3) = many0(N3::parse).parse(rest)?;
+            let (rest, n4) = opt(N4::parse).parse(rest)?;
+            let (rest, l11) = opt(L11::parse).parse(rest)?;
+            let (rest, g61) = many0(G61::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_100.push(_204Loop100 {
+                n1,
+                n2,
+                n3,
+                n4,
+                l11,
+                g61,
+            });
+        }
+        let rest = loop_rest;
+        output.loop_100 = loop_100;
+        // loop 200
+        let mut loop_200 = vec![];
+        let mut loop_rest = rest;
+        while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, n7) = opt(N7::parse).parse(loop_rest)?;
+            let (rest, n7a) = opt(N7A::parse).parse(rest)?;
+            let (rest, n7b) = opt(N7B::parse).parse(rest)?;
+            let (rest, mea) = opt(MEA::parse).parse(rest)?;
+            let (rest, m7) = opt(M7::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_200.push(_204Loop200 {
+                n7,
+                n7a,
+                n7b,
+                mea,
+                m7,
+            });
+        }
+        let rest = loop_rest;
+        output.loop_200 = loop_200;
+        // loop 300
+        let mut loop_300 = vec![];
+        let mut loop_rest = rest;
+        while peek(opt(S5::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, s5) = S5::parse(loop_rest)?;
+            let (rest, l11) = many0(L11::parse).parse(rest)?;
+            let (rest, g62) = many0(G62::parse).parse(rest)?;
+            let (rest, at8) = opt(AT8::parse).parse(rest)?;
+            let (rest, lad) = many0(LAD::parse).parse(rest)?;
+            let (rest, at5) = many0(AT5::parse).parse(rest)?;
+            let (rest, pld) = opt(PLD::parse).parse(rest)?;
+            let (rest, nte) = many0(NTE::parse).parse(rest)?;
+            loop_rest = rest;
+            // loop 310
+            let mut loop_310 = vec![];
+            while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
+                let (rest, n2) = opt(N2::parse).parse(rest)?;
+                let (rest, n3) = many0(N3::parse).parse(rest)?;
+                let (rest, n4) = opt(N4::parse).parse(rest)?;
+                let (rest, g61) = many0(G61::parse).parse(rest)?;
+                loop_rest = rest;
+                loop_310.push(_204Loop310 {
+                    n1,
+                    n2,
+                    n3,
+                    n4,
+                    g61,
+                });
+            }
+            // loop 320
+            let mut loop_320 = vec![];
+            while peek(opt(L5::parse)).parse(loop_rest)?.1.is_some()
+                || peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some()
+            {
+                let (rest, l5) = opt(L5::parse).parse(loop_rest)?;
+                let (rest, at8) = opt(AT8::parse).parse(rest)?;
+                loop_rest = rest;
+                // loop 325
+                let mut loop_325 = vec![];
+                while peek(opt(G61::parse)).parse(loop_rest)?.1.is_some()
+                    || peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some()
+                {
+                    let (rest, g61) = opt(G61::parse).parse(loop_rest)?;
+                    let (rest, l11) = many0(L11::parse).parse(rest)?;
+                    let (rest, lh6) = opt(LH6::parse).parse(rest)?;
+                    loop_rest = rest;
+                    // loop 330
+                    let mut loop_330 = vec![];
+                    while peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some() {
+                        let (rest, lh1) = opt(LH1::parse).parse(loop_rest)?;
+                        let (rest, lh2) = many0(LH2::parse).parse(rest)?;
+                        let (rest, lh3) = many0(LH3::parse).parse(rest)?;
+                        let (rest, lfh) = many0(LFH::parse).parse(rest)?;
+                        let (rest, lep) = many0(LEP::parse).parse(rest)?;
+                        let (rest, lh4) = opt(LH4::parse).parse(rest)?;
+                        let (rest, lht) = many0(LHT::parse).parse(rest)?;
+                        loop_rest = rest;
+                        loop_330.push(_204Loop330 {
+                            lh1,
+                            lh2,
+                            lh3,
+                            lfh,
+                            lep,
+                            lh4,
+                            lht,
+                        });
+                    }
+                    loop_325.push(_204Loop325 {
+                        g61,
+                        l11,
+                        lh6,
+                        loop_330,
+                    });
+                }
+                loop_320.push(_204Loop320 { l5, at8, loop_325 });
+            }
+            // loop 380
+            let mut loop_380 = vec![];
+            while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+                let (rest, n7) = opt(N7::parse).parse(loop_rest)?;
+                let (rest, n7a) = opt(N7A::parse).parse(rest)?;
+                let (rest, n7b) = opt(N7B::parse).parse(rest)?;
+                let (rest, mea) = opt(MEA::parse).parse(rest)?;
+                let (rest, m7) = opt(M7::parse).parse(rest)?;
+                loop_rest = rest;
+                loop_380.push(_204Loop380 {
+                    n7,
+                    n7a,
+                    n7b,
+                    mea,
+                    m7,
+                });
+            }
+            loop_300.push(_204Loop300 {
+                s5,
+                l11,
+                g62,
+                at8,
+                lad,
+                at5,
+                pld,
+                nte,
+                loop_310,
+                loop_320,
+                loop_350: vec![],
+                loop_380,
+            });
+        }
+        let rest = loop_rest;
+        output.loop_300 = loop_300;
+        let (rest, obj) = opt(L3::parse).parse(rest)?;
+        output.l3 = obj;
+        let (rest, obj) = SE::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop100 {
+    pub n1: Option<N1>,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub l11: Option<L11>,
+    pub g61: Vec<G61>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop200 {
+    pub n7: Option<N7>,
+    pub n7a: Option<N7A>,
+    pub n7b: Option<N7B>,
+    pub mea: Option<MEA>,
+    pub m7: Option<M7>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop300 {
+    pub s5: S5,
+    pub l11: Vec<L11>,
+    pub g62: Vec<G62>,
+    pub at8: Option<AT8>,
+    pub lad: Vec<LAD>,
+    pub at5: Vec<AT5>,
+    pub pld: Option<PLD>,
+    pub nte: Vec<NTE>,
+    pub loop_310: Vec<_204Loop310>,
+    pub loop_320: Vec<_204Loop320>,
+    pub loop_350: Vec<_204Loop350>,
+    pub loop_380: Vec<_204Loop380>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop310 {
+    pub n1: Option<N1>,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub g61: Vec<G61>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop320 {
+    pub l5: Option<L5>,
+    pub at8: Option<AT8>,
+    pub loop_325: Vec<_204Loop325>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop325 {
+    pub g61: Option<G61>,
+    pub l11: Vec<L11>,
+    pub lh6: Option<LH6>,
+    pub loop_330: Vec<_204Loop330>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop330 {
+    pub lh1: Option<LH1>,
+    pub lh2: Vec<LH2>,
+    pub lh3: Vec<LH3>,
+    pub lfh: Vec<LFH>,
+    pub lep: Vec<LEP>,
+    pub lh4: Option<LH4>,
+    pub lht: Vec<LHT>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop350 {
+    pub oid: Option<OID>,
+    pub g62: Vec<G62>,
+    pub lad: Vec<LAD>,
+    pub loop_360: Vec<_204Loop360>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop360 {
+    pub l5: Option<L5>,
+    pub at8: Option<AT8>,
+    pub loop_365: Vec<_204Loop365>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop365 {
+    pub g61: Option<G61>,
+    pub l11: Vec<L11>,
+    pub lh6: Vec<LH6>,
+    pub loop_370: Vec<_204Loop370>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop370 {
+    pub lh1: Option<LH1>,
+    pub lh2: Vec<LH2>,
+    pub lh3: Vec<LH3>,
+    pub lfh: Vec<LFH>,
+    pub lep: Vec<LEP>,
+    pub lh4: Option<LH4>,
+    pub lht: Vec<LHT>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _204Loop380 {
+    pub n7: Option<N7>,
+    pub n7a: Option<N7A>,
+    pub n7b: Option<N7B>,
+    pub mea: Option<MEA>,
+    pub m7: Option<M7>,
+}
+
+/// 210 - Motor Carrier Freight Details and Invoice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Motor Carrier Freight Details and Invoice Transaction Set (210) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used to allow a motor carrier to provide detailed bill of lading, rating and/or scheduling information pertinent to a shipment.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B3 | Beginning Segment for Carrier's Invoice | M | 1
+/// 0040 | C3 | Currency | O | 1
+/// 0050 | ITD | Terms of Sale/Deferred Terms of Sale | O | 1
+/// 0060 | N9 | Reference Identification | O | 300
+/// 0070 | G62 | Date/Time | O | 6
+/// 0080 | R3 | Route Information - Motor | O | 12
+/// 0090 | H3 | Special Handling Instructions | O | 6
+/// 0100 | K1 | Remarks | O | 10
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0110 | N1 | Name | O | 1
+/// 0100 -> 0120 | N2 | Additional Name Information | O | 1
+/// 0100 -> 0130 | N3 | Address Information | O | 2
+/// 0100 -> 0140 | N4 | Geographic Location | O | 1
+/// 0100 -> 0150 | N9 | Reference Identification | O | 5
+/// LOOP ID - 0200 | 10
+/// 0200 -> 0160 | N7 | Equipment Details | O | 1
+/// 0200 -> 0170 | M7 | Seal Numbers | O | 5
+/// LOOP ID - 0250 | 999
+/// 0250 -> 0180 | S5 | Stop Off Details | O | 1
+/// 0250 -> 0190 | N9 | Reference Identification | O | 10
+/// 0250 -> 0200 | G62 | Date/Time | O | 2
+/// 0250 -> 0210 | H3 | Special Handling Instructions | O | 6
+/// 0250 -> LOOP ID - 0260 | 2
+/// 0250 -> 0260 -> 0220 | N1 | Name | O | 1
+/// 0250 -> 0260 -> 0230 | N2 | Additional Name Information | O | 1
+/// 0250 -> 0260 -> 0240 | N3 | Address Information | O | 2
+/// 0250 -> 0260 -> 0250 | N4 | Geographic Location | O | 1
+/// 0250 -> 0260 -> 0260 | N9 | Reference Identification | O | 5
+/// LOOP ID - 0400 | 9999
+/// 0400 -> 0010 | LX | Assigned Number | O | 1
+/// 0400 -> 0020 | N9 | Reference Identification | O | 5
+/// 0400 -> 0030 | POD | Proof of Delivery | O | 1
+/// 0400 -> 0040 | L5 | Description, Marks and Numbers | O | 30
+/// 0400 -> 0050 | H1 | Hazardous Material | O | 3
+/// 0400 -> 0060 | H2 | Additional Hazardous Material Description | O | 2
+/// 0400 -> 0070 | L0 | Line Item - Quantity and Weight | O | 10
+/// 0400 -> 0080 | L1 | Rate and Charges | O | 50
+/// 0400 -> 0090 | L4 | Measurement | O | 10
+/// 0400 -> 0100 | L7 | Tariff Reference | O | 10
+/// 0400 -> 0110 | K1 | Remarks | O | 10
+/// 0010 | L3 | Total Weight and Charges | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210 {
+    pub st: ST,
+    pub b3: B3,
+    pub c3: Option<C3>,
+    pub itd: Option<ITD>,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub r3: Vec<R3>,
+    pub h3: Vec<H3>,
+    pub k1: Vec<K1>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0100: Vec<_210Loop0100>,
+    #[x12(loop_trigger = "N7")]
+    pub loop_0200: Vec<_210Loop0200>,
+    #[x12(loop_trigger = "S5")]
+    pub loop_0250: Vec<_210Loop0250>,
+    #[x12(loop_trigger = "LX")]
+    pub loop_0400: Vec<_210Loop0400>,
+    pub l3: L3,
+    pub se: SE,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0100 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub n9: Vec<N9>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0200 {
+    pub n7: N7,
+    pub m7: Vec<M7>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0250 {
+    pub s5: S5,
+    pub n9: Vec<N9>,
+    pub g62: Vec<G62>,
+    pub h3: Vec<H3>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_0260: Vec<_210Loop0260>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0260 {
+    pub n1: N1,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub n9: Vec<N9>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _210Loop0400 {
+    pub lx: LX,
+    pub n9: Vec<N9>,
+    pub pod: Option<POD>,
+    pub l5: Vec<L5>,
+    pub h1: Vec<H1>,
+    pub h2: Vec<H2>,
+    pub l0: Vec<L0>,
+    pub l1: Vec<L1>,
+    pub l4: Vec<L4>,
+    pub l7: Vec<L7>,
+    pub k1: Vec<K1>,
+}
+
+/// 214 - Transportation Carrier Shipment Status Message
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Transportation Carrier Shipment Status Message Transaction Set (214) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used by a transportation carrier to provide shippers, consignees, and their agents with the status of shipments in terms of dates, times, locations, route, identifying numbers, and conveyance.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B10 | Beginning Segment for Transportation Carrier Shipment Status Message | M | 1
+/// 0030 | L11 | Business Instructions and Reference Number | O | 300
+/// 0035 | MAN | Marks and Numbers | O | 9999
+/// 0040 | K1 | Remarks | O | 10
+/// LOOP ID - 0100 | 10
+/// 0100 -> 0050 | N1 | Name | O | 1
+/// 0100 -> 0060 | N2 | Additional Name Information | O | 1
+/// 0100 -> 0070 | N3 | Address Information | O | 2
+/// 0100 -> 0080 | N4 | Geographic Location | O | 1
+/// 0100 -> 0090 | G61 | Contact | O | 1
+/// 0100 -> 0100 | G62 | Date/Time | O | 1
+/// 0100 -> 0110 | L11 | Business Instructions and Reference Number | O | 10
+/// 0120 | MS3 | Interline Information | O | 12
+/// LOOP ID - 0200 | 999999
+/// 0200 -> 0130 | LX | Assigned Number | O | 1
+/// 0200 -> LOOP ID - 0205 | 10
+/// 0200 -> 0205 -> 0140 | AT7 | Shipment Status Details | O | 1
+/// 0200 -> 0205 -> 0143 | MS1 | Equipment, Shipment, or Real Property Location | O | 1
+/// 0200 -> 0205 -> 0146 | MS2 | Equipment or Container Owner and Type | O | 1
+/// 0200 -> 0150 | L11 | Business Instructions and Reference Number | O | 10
+/// 0200 -> 0155 | MAN | Marks and Numbers | O | 9999
+/// 0200 -> 0160 | Q7 | Lading Exception Code | O | 10
+/// 0200 -> 0170 | K1 | Remarks | O | 10
+/// 0200 -> 0180 | AT5 | Bill of Lading Handling Requirements | O | 10
+/// 0200 -> 0200 | AT8 | Shipment Weight, Packaging and Quantity Data | O | 10
+/// 0200 -> LOOP ID - 0210 | 999999
+/// 0200 -> 0210 -> 0210 | CD3 | Carton (Package) Detail | O | 1
+/// 0200 -> 0210 -> 0220 | L11 | Business Instructions and Reference Number | O | 20
+/// 0200 -> 0210 -> LOOP ID - 0215 | 10
+/// 0200 -> 0210 -> 0215 -> 0230 | AT7 | Shipment Status Details | O | 1
+/// 0200 -> 0210 -> 0215 -> 0233 | MS1 | Equipment, Shipment, or Real Property Location | O | 1
+/// 0200 -> 0210 -> 0215 -> 0236 | MS2 | Equipment or Container Owner and Type | O | 1
+/// 0200 -> 0210 -> 0240 | NM1 | Individual or Organizational Name | O | 1
+/// 0200 -> 0210 -> 0250 | Q7 | Lading Exception Code | O | 10
+/// 0200 -> 0210 -> 0260 | AT8 | Shipment Weight, Packaging and Quantity Data | O | 1
+/// 0200 -> 0210 -> 0265 | MAN | Marks and Numbers | O | 9999
+/// 0200 -> 0210 -> LOOP ID - 0220 | 999999
+/// 0200 -> 0210 -> 0220 -> 0270 | N1 | Name | O | 1
+/// 0200 -> 0210 -> 0220 -> 0280 | N2 | Additional Name Information | O | 1
+/// 0200 -> 0210 -> 0220 -> 0290 | N3 | Address Information | O | 3
+/// 0200 -> 0210 -> 0220 -> 0300 | N4 | Geographic Location | O | 1
+/// 0200 -> 0210 -> 0220 -> 0310 | L11 | Business Instructions and Reference Number | O | 10
+/// 0200 -> LOOP ID - 0230 | 999999
+/// 0200 -> 0230 -> 0320 | PRF | Purchase Order Reference | O | 1
+/// 0200 -> 0230 -> LOOP ID - 0231 | 999999
+/// 0200 -> 0230 -> 0231 -> 0330 | N1 | Name | O | 1
+/// 0200 -> 0230 -> 0231 -> 0340 | N2 | Additional Name Information | O | 1
+/// 0200 -> 0230 -> 0231 -> 0350 | N3 | Address Information | O | 2
+/// 0200 -> 0230 -> 0231 -> 0360 | N4 | Geographic Location | O | 1
+/// 0200 -> 0230 -> 0231 -> 0370 | L11 | Business Instructions and Reference Number | O | 10
+/// 0200 -> 0230 -> LOOP ID - 0233 | 999999
+/// 0200 -> 0230 -> 0233 -> 0380 | CD3 | Carton (Package) Detail | O | 1
+/// 0200 -> 0230 -> 0233 -> 0390 | L11 | Business Instructions and Reference Number | O | 20
+/// 0200 -> 0230 -> 0233 -> LOOP ID - 0240 | 10
+/// 0200 -> 0230 -> 0233 -> 0240 -> 0400 | AT7 | Shipment Status Details | O | 1
+/// 0200 -> 0230 -> 0233 -> 0240 -> 0402 | MS1 | Equipment, Shipment, or Real Property Location | O | 1
+/// 0200 -> 0230 -> 0233 -> 0240 -> 0404 | MS2 | Equipment or Container Owner and Type | O | 1
+/// 0200 -> 0230 -> 0233 -> 0405 | MAN | Marks and Numbers | O | 9999
+/// 0200 -> LOOP ID - 0250 | 999999
+/// 0200 -> 0250 -> 0410 | SPO | Shipment Purchase Order Detail | O | 1
+/// 0200 -> 0250 -> 0420 | SDQ | Destination Quantity | O | 10
+/// 0200 -> LOOP ID - 0260 | >1
+/// 0200 -> 0260 -> 0423 | EFI | Electronic Format Identification | O | 1
+/// 0200 -> 0260 -> 0426 | BIN | Binary Data | M | 1
+/// 0610 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214 {
+    pub st: ST,
+    pub b10: B10,
+    pub l11: Vec<L11>,
+    pub man: Vec<MAN>,
+    pub k1: Vec<K1>,
+    pub loop_0100: Vec<_214Loop0100>,
+    pub ms3: Vec<MS3>,
+    pub loop_0200: Vec<_214Loop0200>,
+    pub se: SE,
+}
+
+impl<'a> Parser<&'a str, _214, nom::error::Error<&'a str>> for _214 {
+    fn parse(input: &'a str) -> IResult<&'a str, _214> {
+        let mut output = _214::default();
+        let (rest, obj) = ST::parse(input)?;
+        output.st = obj;
+        let (rest, obj) = B10::parse(rest)?;
+        output.b10 = obj;
+        let (rest, obj) = many0(L11::parse).parse(rest)?;
+        output.l11 = obj;
+        let (rest, obj) = many0(MAN::parse).parse(rest)?;
+        output.man = obj;
+        let (rest, obj) = many0(K1::parse).parse(rest)?;
+        output.k1 = obj;
+        // loop 100
+        let mut loop_100 = vec![];
+        let mut loop_rest = rest;
+        while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
+            let (rest, n2) = opt(N2::parse).parse(rest)?;
+            let (rest, n3) = many0(N3::parse).parse(rest)?;
+            let (rest, n4) = opt(N4::parse).parse(rest)?;
+            let (rest, g61) = opt(G61::parse).parse(rest)?;
+            let (rest, g62) = opt(G62::parse).parse(rest)?;
+            let (rest, l11) = many0(L11::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_100.push(_214Loop0100 {
+                n1,
+                n2,
+                n3,
+                n4,
+                g61,
+                g62,
+                l11,
+            });
+        }
+        let rest = loop_rest;
+        output.loop_0100 = loop_100;
+        let (rest, obj) = many0(MS3::parse).parse(rest)?;
+        output.ms3 = obj;
+        // loop 200
+        let mut loop_200 = vec![];
+        loop_rest = rest;
+        while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, lx) = LX::parse(loop_rest)?;
+            // loop 0205
+            let mut loop_0205 = vec![];
+            loop_rest = rest;
+            while peek(opt(AT7::parse)).parse(loop_rest)?.1.is_some() {
+                let (rest, at7) = AT7::parse(loop_rest)?;
+                let (rest, ms1) = opt(MS1::parse).parse(rest)?;
+                let (rest, ms2) = opt(MS2::parse).parse(rest)?;
+                loop_rest = rest;
+                loop_0205.push(_214Loop0205 { at7, ms1, ms2 });
+            }
+            let rest = loop_rest;
+            let (rest, l11) = many0(L11::parse).parse(rest)?;
+            let (rest, man) = many0(MAN::parse).parse(rest)?;
+            let (rest, q7) = many0(Q7::parse).parse(rest)?;
+            let (rest, k1) = many0(K1::parse).parse(rest)?;
+            let (rest, at5) = many0(AT5::parse).parse(rest)?;
+            let (rest, at8) = many0(AT8::parse).parse(rest)?;
+            loop_rest = rest;
+            // loop 0210
+            // loop 0230
+            // loop 0250
+            // loop 0260
+            loop_200.push(_214Loop0200 {
+                lx,
+                loop_0205,
+                l11,
+                man,
+                q7,
+                k1,
+                at5,
+                at8,
+                loop_0210: vec![],
+                loop_0230: vec![],
+                loop_0250: vec![],
+                loop_0260: vec![],
+            });
+        }
+        let rest = loop_rest;
+        output.loop_0200 = loop_200;
+
+        let (rest, obj) = SE::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0100 {
+    pub n1: Option<N1>,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub g61: Option<G61>,
+    pub g62: Option<G62>,
+    pub l11: Vec<L11>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0200 {
+    pub lx: LX,
+    pub loop_0205: Vec<_214Loop0205>,
+    pub l11: Vec<L11>,
+    pub man: Vec<MAN>,
+    pub q7: Vec<Q7>,
+    pub k1: Vec<K1>,
+    pub at5: Vec<AT5>,
+    pub at8: Vec<AT8>,
+    pub loop_0210: Vec<_214Loop0210>,
+    pub loop_0230: Vec<_214Loop0230>,
+    pub loop_0250: Vec<_214Loop0250>,
+    pub loop_0260: Vec<_214Loop0260>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0205 {
+    pub at7: AT7,
+    pub ms1: Option<MS1>,
+    pub ms2: Option<MS2>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0210 {
+    pub cd3: Option<CD3>,
+    pub l11: Vec<L11>,
+    pub loop_0215: Vec<_214Loop0210Loop0215>,
+    pub nm1: Option<NM1>,
+    pub q7: Vec<Q7>,
+    pub at8: Option<AT8>,
+    pub man: Vec<MAN>,
+    pub loop_0220: Vec<_214Loop0210Loop0220>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0210Loop0215 {
+    pub at7: Option<AT7>,
+    pub ms1: Option<MS1>,
+    pub ms2: Option<MS2>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0210Loop0220 {
+    pub n1: Option<N1>,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub l11: Vec<L11>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0230 {
+    pub prf: Option<PRF>,
+    pub loop_0231: Vec<_214Loop0231>,
+    pub loop_0233: Vec<_214Loop0233>,
+}
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0231 {
+    pub n1: Option<N1>,
+    pub n2: Option<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub l11: Vec<L11>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0233 {
+    pub cd3: Option<CD3>,
+    pub l11: Vec<L11>,
+    pub loop_0240: Vec<_214Loop0240>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0240 {
+    pub at7: Option<AT7>,
+    pub ms1: Option<MS1>,
+    pub ms2: Option<MS2>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0250 {
+    pub spo: Option<SPO>,
+    pub sdq: Option<SDQ>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
+pub struct _214Loop0260 {
+    pub efi: Option<EFI>,
+    pub bin: BIN,
+}
+
+/// 990 - Response to a Load Tender
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Response to a Load Tender Transaction Set (990) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to allow carriers to indicate whether a motor carrier will pick up a shipment offered by the shipper.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B1 | Beginning Segment for Booking or Pick-up/Delivery | M | 1
+/// 0030 | N9 | Reference Identification | O | 1
+/// 0040 | G62 | Date/Time | O | 1
+/// 0050 | K1 | Remarks | O | 10
+/// 0060 | SE | Transaction Set Trailer | M | 1
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12, ParseX12)]
+pub struct _990 {
+    pub st: ST,
+    pub b1: B1,
+    pub n9: Option<N9>,
+    pub g62: Option<G62>,
+    pub k1: Vec<K1>,
+    pub se: SE,
+}
@@ -0,0 +1,1191 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    AT5,
+    "Bill of Lading Handling Requirements",
+    "To identify Bill of Lading handling and service requirements",
+    [_01, _02, _03],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "560", "Special Services Code", Conditional, Id, 2, 10, special_services_code),
+        (3, "153", "Special Handling Description", Conditional, AlphaNumeric, 2, 30, special_handling_description),
+    ]
+}
+
+segment_meta! {
+    AT7,
+    "Shipment Status Details",
+    "To specify the status of a shipment, the reason for that status, the date and time of the status and the date and time of any appointments scheduled.",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1650", "Shipment Status Code", Conditional, Id, 2, 2, shipment_status_code),
+        (2, "1651", "Shipment Status or Appointment Reason Code", Conditional, Id, 2, 2, shipment_status_or_appointment_reason_code_02),
+        (3, "1652", "Shipment Appointment Status Code", Conditional, Id, 2, 2, shipment_appointment_status_code),
+        (4, "1651", "Shipment Status or Appointment Reason Code", Conditional, Id, 2, 2, shipment_status_or_appointment_reason_code_04),
+        (5, "373", "Date", Conditional, Date, 8, 8, date),
+        (6, "337", "Time", Conditional, Time, 4, 8, time),
+        (7, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    AT8,
+    "Shipment Weight, Packaging and Quantity Data",
+    "To specify shipment details in terms of weight, and quantity of handling units",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (2, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity_04),
+        (5, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity_05),
+        (6, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (7, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+    ]
+}
+
+segment_meta! {
+    B1,
+    "Beginning Segment for Booking or Pick-up/Delivery",
+    "To transmit identifying number, data, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "145", "Shipment Identification Number", Mandatory, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "558", "Reservation Action Code", Mandatory, Id, 1, 1, reservation_action_code),
+    ]
+}
+
+segment_meta! {
+    B2,
+    "Beginning Segment for Shipment Information Transaction",
+    "To transmit basic data relating to shipment information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "375", "Tariff Service Code", Optional, Id, 2, 2, tariff_service_code),
+        (2, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (4, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (5, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (6, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (7, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (8, "86", "Total Equipment", Optional, Numeric(0), 1, 3, total_equipment),
+        (9, "460", "Shipment Weight Code", Optional, Id, 1, 1, shipment_weight_code),
+        (10, "501", "Customs Documentation Handling Code", Optional, Id, 2, 2, customs_documentation_handling_code),
+        (11, "335", "Transportation Terms Code", Optional, Id, 3, 3, transportation_terms_code),
+        (12, "591", "Payment Method Code", Optional, Id, 3, 3, payment_method_code),
+    ]
+}
+
+segment_meta! {
+    B2A,
+    "Set Purpose",
+    "To allow for positive identification of transaction set purpose",
+    [_01, _02],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "346", "Application Type", Optional, Id, 2, 2, application_type),
+    ]
+}
+
+segment_meta! {
+    B3,
+    "Beginning Segment for Carrier's Invoice",
+    "To transmit basic data relating to the carrier's invoice",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (2, "76", "Invoice Number", Mandatory, AlphaNumeric, 1, 22, invoice_number),
+        (3, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (4, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (5, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (6, "373", "Date", Mandatory, Date, 8, 8, date_06),
+        (7, "193", "Net Amount Due", Mandatory, Numeric(2), 1, 12, net_amount_due),
+        (8, "202", "Correction Indicator", Optional, Id, 2, 2, correction_indicator),
+        (9, "32", "Delivery Date", Conditional, Date, 8, 8, delivery_date),
+        (10, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier),
+        (11, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (12, "373", "Date", Optional, Date, 8, 8, date_12),
+        (13, "375", "Tariff Service Code", Optional, Id, 2, 2, tariff_service_code),
+        (14, "335", "Transportation Terms Code", Optional, Id, 3, 3, transportation_terms_code),
+    ]
+}
+
+segment_meta! {
+    B10,
+    "Beginning Segment for Transportation Carrier Shipment Status Message",
+    "To transmit identifying numbers and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification_01),
+        (2, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (3, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (4, "71", "Inquiry Request Number", Optional, Numeric(0), 1, 3, inquiry_request_number),
+        (5, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (6, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification_06),
+        (7, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    BIN,
+    "Binary Data",
+    "To transfer binary data in a single data segment and allow identification of the end of the data segment through a count; there is no identification of the internal structure of the binary data in this segment",
+    [_01, _02],
+    [
+        (1, "784", "Length of Binary Data", Mandatory, Numeric(0), 1, 15, length_of_binary_data),
+        (2, "785", "Binary Data", Mandatory, Binary, 1, 9999999999999999, binary_data),
+    ]
+}
+
+segment_meta! {
+    C3,
+    "Currency",
+    "To specify the currency being used in the transaction set",
+    [_01, _02, _03, _04],
+    [
+        (1, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code_01),
+        (2, "280", "Exchange Rate", Optional, Decimal, 4, 10, exchange_rate),
+        (3, "100", "Currency Code", Optional, Id, 3, 3, currency_code_03),
+        (4, "100", "Currency Code", Optional, Id, 3, 3, currency_code_04),
+    ]
+}
+
+segment_meta! {
+    CD3,
+    "Carton (Package) Detail",
+    "To transmit identifying codes, weights, and other related information related to an individual carton (package)",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (2, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (3, "619", "Zone", Optional, AlphaNumeric, 2, 3, zone),
+        (4, "34", "Service Standard", Optional, Numeric(1), 1, 4, service_standard),
+        (5, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_05),
+        (6, "108", "Pick-up or Delivery Code", Optional, Id, 1, 2, pick_up_or_delivery_code),
+        (7, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_07),
+        (8, "58", "Charge", Conditional, Numeric(2), 1, 12, charge_08),
+        (9, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_09),
+        (10, "58", "Charge", Conditional, Numeric(2), 1, 12, charge_10),
+        (11, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_11),
+        (12, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_12),
+        (13, "591", "Payment Method Code", Optional, Id, 3, 3, payment_method_code),
+        (14, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    EFI,
+    "Electronic Format Identification",
+    "To provide basic information about the electronic format of the interchange data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "786", "Security Level Code", Mandatory, Id, 2, 2, security_level_code),
+        (2, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text),
+        (3, "797", "Security Technique Code", Optional, Id, 2, 2, security_technique_code),
+        (4, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_04),
+        (5, "802", "Program Identifier", Optional, AlphaNumeric, 1, 30, program_identifier),
+        (6, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_06),
+        (7, "801", "Interchange Format", Optional, AlphaNumeric, 1, 30, interchange_format),
+        (8, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_08),
+        (9, "800", "Compression Technique", Optional, AlphaNumeric, 1, 30, compression_technique),
+        (10, "789", "Drawing Sheet Size Code", Optional, AlphaNumeric, 2, 2, drawing_sheet_size_code),
+        (11, "803", "File Name", Optional, AlphaNumeric, 1, 64, file_name),
+        (12, "804", "Block Type", Optional, AlphaNumeric, 1, 4, block_type),
+        (13, "787", "Record Length", Optional, Numeric(0), 1, 15, record_length),
+        (14, "788", "Block Length", Optional, Numeric(0), 1, 5, block_length),
+        (15, "799", "Version Identifier", Conditional, AlphaNumeric, 1, 30, version_identifier_15),
+        (16, "1570", "Filter ID Code", Conditional, Id, 3, 3, filter_id_code),
+    ]
+}
+
+segment_meta! {
+    G61,
+    "Contact",
+    "To identify a person or office to whom communications should be directed",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number),
+        (5, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    G62,
+    "Date/Time",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "432", "Date Qualifier", Conditional, Id, 2, 2, date_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "176", "Time Qualifier", Conditional, Id, 1, 2, time_qualifier),
+        (4, "337", "Time", Conditional, Time, 4, 8, time),
+        (5, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "Functional Group Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 8, 8, date),
+        (5, "337", "Time", Mandatory, Time, 4, 8, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    H1,
+    "Hazardous Material",
+    "To specify information relative to hazardous material",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "62", "Hazardous Material Code", Mandatory, AlphaNumeric, 4, 10, hazardous_material_code),
+        (2, "209", "Hazardous Material Class Code", Optional, AlphaNumeric, 1, 4, hazardous_material_class_code),
+        (3, "208", "Hazardous Material Code Qualifier", Optional, Id, 1, 1, hazardous_material_code_qualifier),
+        (4, "64", "Hazardous Material Description", Optional, AlphaNumeric, 2, 30, hazardous_material_description),
+        (5, "63", "Hazardous Material Contact", Optional, AlphaNumeric, 1, 24, hazardous_material_contact),
+        (6, "200", "Hazardous Materials Page", Optional, AlphaNumeric, 1, 6, hazardous_materials_page),
+        (7, "77", "Flashpoint Temperature", Conditional, Numeric(0), 1, 3, flashpoint_temperature),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (9, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+    ]
+}
+
+segment_meta! {
+    H2,
+    "Additional Hazardous Material Description",
+    "To specify free-form hazardous material descriptive data in addition to the information provided in the H1 segment",
+    [_01, _02],
+    [
+        (1, "64", "Hazardous Material Description", Mandatory, AlphaNumeric, 2, 30, hazardous_material_description),
+        (2, "274", "Hazardous Material Classification", Optional, AlphaNumeric, 1, 30, hazardous_material_classification),
+    ]
+}
+
+segment_meta! {
+    H3,
+    "Special Handling Instructions",
+    "To specify special handling instructions in coded or free-form format",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "153", "Special Handling Description", Conditional, AlphaNumeric, 2, 30, special_handling_description),
+        (3, "241", "Protective Service Code", Optional, Id, 1, 4, protective_service_code),
+        (4, "242", "Vent Instruction Code", Optional, Id, 1, 7, vent_instruction_code),
+        (5, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+    ]
+}
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer",
+    "To define the end of an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header",
+    "To start and identify an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I10", "Interchange Control Standards Identifier", Mandatory, Id, 1, 1, interchange_control_standards_identifier),
+        (12, "I11", "Interchange Control Version Number", Mandatory, Id, 5, 5, interchange_control_version_number),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Usage Indicator", Mandatory, Id, 1, 1, usage_indicator),
+        (16, "I15", "Component Element Separator", Mandatory, Composite, 1, 1, component_element_separator),
+    ]
+}
+
+segment_meta! {
+    ITD,
+    "Terms of Sale/Deferred Terms of Sale",
+    "To specify terms of sale",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "336", "Terms Type Code", Optional, Id, 2, 2, terms_type_code),
+        (2, "333", "Terms Basis Date Code", Optional, Id, 1, 2, terms_basis_date_code),
+        (3, "338", "Terms Discount Percent", Optional, Decimal, 1, 6, terms_discount_percent),
+        (4, "370", "Terms Discount Due Date", Conditional, Date, 8, 8, terms_discount_due_date),
+        (5, "351", "Terms Discount Days Due", Conditional, Numeric(0), 1, 3, terms_discount_days_due),
+        (6, "446", "Terms Net Due Date", Optional, Date, 8, 8, terms_net_due_date),
+        (7, "386", "Terms Net Days", Optional, Numeric(0), 1, 3, terms_net_days),
+        (8, "362", "Terms Discount Amount", Optional, Numeric(2), 1, 10, terms_discount_amount),
+        (9, "388", "Terms Deferred Due Date", Optional, Date, 8, 8, terms_deferred_due_date),
+        (10, "389", "Deferred Amount Due", Conditional, Numeric(2), 1, 10, deferred_amount_due),
+        (11, "342", "Percent of Invoice Payable", Conditional, Decimal, 1, 5, percent_of_invoice_payable),
+        (12, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (13, "765", "Day of Month", Conditional, Numeric(0), 1, 2, day_of_month),
+        (14, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+        (15, "954", "Percent", Optional, Decimal, 1, 10, percent),
+    ]
+}
+
+segment_meta! {
+    K1,
+    "Remarks",
+    "To transmit information in a free-form format for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "61", "Free-Form Message", Mandatory, AlphaNumeric, 1, 30, free_form_message_01),
+        (2, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message_02),
+    ]
+}
+
+segment_meta! {
+    L0,
+    "Line Item - Quantity and Weight",
+    "To specify quantity, weight, volume, and type of service for a line item including applicable \"quantity/rate-as\" data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (3, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (4, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (5, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (6, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (7, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (8, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (9, "211", "Packaging Form Code", Conditional, Id, 3, 3, packaging_form_code_09),
+        (10, "458", "Dunnage Description", Optional, AlphaNumeric, 2, 25, dunnage_description),
+        (11, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (12, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (13, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (14, "211", "Packaging Form Code", Optional, Id, 3, 3, packaging_form_code_14),
+        (15, "1073", "Yes/No Condition or Response Code", Conditional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    L1,
+    "Rate and Charges",
+    "To specify rate and charges detail relative to a line item including freight charges, advances, special charges, and entitlements",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (3, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_03),
+        (4, "58", "Charge", Conditional, Numeric(2), 1, 12, charge),
+        (5, "191", "Advances", Conditional, Numeric(2), 1, 9, advances),
+        (6, "117", "Prepaid Amount", Conditional, Numeric(2), 1, 9, prepaid_amount),
+        (7, "120", "Rate Combination Point Code", Optional, AlphaNumeric, 3, 9, rate_combination_point_code),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "121", "Rate Class Code", Optional, Id, 1, 3, rate_class_code),
+        (10, "39", "Entitlement Code", Optional, Id, 1, 1, entitlement_code),
+        (11, "16", "Charge Method of Payment", Optional, Id, 1, 1, charge_method_of_payment),
+        (12, "276", "Special Charge Description", Optional, AlphaNumeric, 2, 25, special_charge_description),
+        (13, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+        (16, "372", "Lading Liability Code", Optional, Id, 1, 1, lading_liability_code),
+        (17, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (18, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (19, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (20, "100", "Currency Code", Optional, Id, 3, 3, currency_code),
+        (21, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+    ]
+}
+
+segment_meta! {
+    L3,
+    "Total Weight and Charges",
+    "To specify the total shipment in terms of weight, volume, rates, charges, advances, and prepaid amounts applicable to one or more line items",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (2, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (3, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (4, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_04),
+        (5, "58", "Charge", Optional, Numeric(2), 1, 12, charge),
+        (6, "191", "Advances", Optional, Numeric(2), 1, 9, advances),
+        (7, "117", "Prepaid Amount", Optional, Numeric(2), 1, 9, prepaid_amount),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (10, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (11, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (12, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (13, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+    ]
+}
+
+segment_meta! {
+    L4,
+    "Measurement",
+    "To describe physical ddimensions and quantities",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "82", "Length", Mandatory, Decimal, 1, 8, length),
+        (2, "189", "Width", Mandatory, Decimal, 1, 8, width),
+        (3, "65", "Height", Mandatory, Decimal, 1, 8, height),
+        (4, "90", "Measurement Unit Qualifier", Mandatory, Id, 1, 1, measurement_unit_qualifier),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (6, "271", "Industry Code", Optional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    L5,
+    "Description, Marks and Numbers",
+    "To specify the line item in terms of description, quantity, packaging, and marks and numbers",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (3, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_03),
+        (4, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_04),
+        (5, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (6, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers),
+        (7, "88", "Marks and Numbers Qualifier", Optional, Id, 1, 2, marks_and_numbers_qualifier),
+        (8, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_08),
+        (9, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_09),
+        (10, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+    ]
+}
+
+segment_meta! {
+    L7,
+    "Tariff Reference",
+    "To reference details of the tariff used to arrive at applicable rates or charge",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "168", "Tariff Agency Code", Optional, Id, 1, 4, tariff_agency_code),
+        (3, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (4, "172", "Tariff Section", Optional, AlphaNumeric, 1, 2, tariff_section),
+        (5, "169", "Tariff Item Number", Optional, AlphaNumeric, 1, 16, tariff_item_number),
+        (6, "170", "Tariff Item Part", Optional, Numeric(0), 1, 2, tariff_item_part),
+        (7, "59", "Freight Class Code", Optional, AlphaNumeric, 2, 5, freight_class_code),
+        (8, "173", "Tariff Supplement Identifier", Optional, AlphaNumeric, 1, 4, tariff_supplement_identifier),
+        (9, "46", "Ex Parte", Optional, AlphaNumeric, 4, 4, ex_parte),
+        (10, "373", "Date", Optional, Date, 8, 8, date),
+        (11, "119", "Rate Basis Number", Optional, AlphaNumeric, 1, 6, rate_basis_number),
+        (12, "227", "Tariff Column", Optional, AlphaNumeric, 1, 2, tariff_column),
+        (13, "294", "Tariff Distance", Optional, Numeric(0), 1, 5, tariff_distance),
+        (14, "295", "Distance Qualifier", Optional, Id, 1, 1, distance_qualifier),
+        (15, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (16, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    L11,
+    "Business Instructions and Reference Number",
+    "To specify instructions in this business relationship or a reference number",
+    [_01, _02, _03],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (2, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    LAD,
+    "Lading Detail",
+    "To transmit detailed lading data pertinent to a pickup or delivery",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "211", "Packaging Form Code", Conditional, Id, 3, 3, packaging_form_code),
+        (2, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (3, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code_03),
+        (4, "395", "Unit Weight", Conditional, Decimal, 1, 8, unit_weight),
+        (5, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code_05),
+        (6, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (7, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_07),
+        (8, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_08),
+        (9, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_09),
+        (10, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_11),
+        (12, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_12),
+        (13, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+    ]
+}
+
+segment_meta! {
+    LEP,
+    "EPA Required Data",
+    "To specify the Environmental Protection Agency (EPA) information relating to shipments of hazardous material",
+    [_01, _02, _03, _04],
+    [
+        (1, "806", "EPA Waste Stream Number Code", Optional, Id, 4, 6, epa_waste_stream_number_code),
+        (2, "807", "Waste Characteristics Code", Optional, Id, 12, 16, waste_characteristics_code),
+        (3, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (4, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+    ]
+}
+
+segment_meta! {
+    LFH,
+    "Freeform Hazardous Material Information",
+    "To uniquely identify the variable information required by government regulation covering the transportation of hazardous material shipments",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "808", "Hazardous Material Shipment Information Qualifier", Mandatory, Id, 3, 3, hazardous_material_shipment_information_qualifier),
+        (2, "809", "Hazardous Material Shipment Information", Mandatory, AlphaNumeric, 1, 25, hazardous_material_shipment_information_02),
+        (3, "809", "Hazardous Material Shipment Information", Optional, AlphaNumeric, 1, 25, hazardous_material_shipment_information_03),
+        (4, "1023", "Hazard Zone Code", Optional, Id, 1, 1, hazard_zone_code),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity_07),
+    ]
+}
+
+segment_meta! {
+    LH1,
+    "Hazardous Identification Information",
+    "To specify the hazardous commodity identification reference number and quantity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code_01),
+        (2, "80", "Lading Quantity", Mandatory, Numeric(0), 1, 7, lading_quantity),
+        (3, "277", "UN/NA Identification Code", Optional, Id, 6, 6, un_na_identification_code),
+        (4, "200", "Hazardous Materials Page", Optional, AlphaNumeric, 1, 6, hazardous_materials_page),
+        (5, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (8, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+        (9, "665", "Residue Indicator Code", Optional, Id, 1, 1, residue_indicator_code),
+        (10, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (11, "1375", "Interim Hazardous Material Regulatory Number", Optional, AlphaNumeric, 1, 5, interim_hazardous_material_regulatory_number),
+    ]
+}
+
+segment_meta! {
+    LH2,
+    "Hazardous Classification Information",
+    "To specify the hazardous notation and endorsement information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "983", "Hazardous Class Qualifier", Optional, Id, 1, 1, hazardous_class_qualifier),
+        (3, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (4, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+        (5, "759", "Reportable Quantity Code", Optional, Id, 2, 2, reportable_quantity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_07),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_08),
+        (9, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_09),
+        (10, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_10),
+        (11, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_11),
+    ]
+}
+
+segment_meta! {
+    LH3,
+    "Hazardous Material Shipping Name",
+    "To specify the hazardous material shipping name and additional descriptive requirements",
+    [_01, _02, _03, _04],
+    [
+        (1, "224", "Hazardous Material Shipping Name", Conditional, AlphaNumeric, 1, 25, hazardous_material_shipping_name),
+        (2, "984", "Hazardous Material Shipping Name Qualifier", Conditional, Id, 1, 1, hazardous_material_shipping_name_qualifier),
+        (3, "985", "N.O.S. Indicator Code", Optional, Id, 3, 3, n_o_s_indicator_code),
+        (4, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    LH4,
+    "Canadian Dangerous Requirements",
+    "To specify additional Transport Canada requirements covering transportation of dangerous goods in Canada",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "238", "Emergency Response Plan Number", Optional, AlphaNumeric, 1, 12, emergency_response_plan_number),
+        (2, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_02),
+        (3, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (4, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_04),
+        (5, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_05),
+        (6, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_06),
+        (7, "271", "Subsidiary Risk Indicator", Optional, Id, 1, 2, subsidiary_risk_indicator),
+        (8, "267", "Net Explosive Quantity", Conditional, Numeric(0), 1, 6, net_explosive_quantity),
+        (9, "805", "Canadian Hazardous Notation", Optional, AlphaNumeric, 1, 25, canadian_hazardous_notation),
+        (10, "986", "Special Commodity Indicator Code", Optional, Id, 1, 1, special_commodity_indicator_code),
+        (11, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_11),
+        (12, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+    ]
+}
+
+segment_meta! {
+    LH6,
+    "Hazardous Certification",
+    "To specify the name of the person certifying that the shipment complies with the regulations and/or the actual certification",
+    [_01, _02, _03, _04],
+    [
+        (1, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (2, "272", "Hazardous Certification Code", Conditional, Id, 1, 1, hazardous_certification_code),
+        (3, "273", "Hazardous Certification Declaration", Conditional, AlphaNumeric, 1, 25, hazardous_certification_declaration_03),
+        (4, "273", "Hazardous Certification Declaration", Optional, AlphaNumeric, 1, 25, hazardous_certification_declaration_04),
+    ]
+}
+
+segment_meta! {
+    LHT,
+    "Transborder Hazardous Requirements",
+    "To specify the placard information required by the second government agency when shipment is to cross into another country",
+    [_01, _02, _03],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (3, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+    ]
+}
+
+segment_meta! {
+    LX,
+    "Assigned Number",
+    "To reference a line number in a transaction set",
+    [_01],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+    ]
+}
+
+segment_meta! {
+    M7,
+    "Seal Numbers",
+    "To record seal numbers used and the organization that applied the seals",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "225", "Seal Number", Mandatory, AlphaNumeric, 2, 15, seal_number_01),
+        (2, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_02),
+        (3, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_03),
+        (4, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_04),
+        (5, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+    ]
+}
+
+segment_meta! {
+    MAN,
+    "Marks and Numbers",
+    "To indicate identifying marks and numbers for shipping containers",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "88", "Marks and Numbers Qualifier", Mandatory, Id, 1, 2, marks_and_numbers_qualifier_01),
+        (2, "87", "Marks and Numbers", Mandatory, AlphaNumeric, 1, 48, marks_and_numbers_02),
+        (3, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_03),
+        (4, "88", "Marks and Numbers Qualifier", Conditional, Id, 1, 2, marks_and_numbers_qualifier_04),
+        (5, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers_05),
+        (6, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_06),
+    ]
+}
+
+segment_meta! {
+    MEA,
+    "Measurements",
+    "To specify physical measurements or counts, including dimensions, tolerances, variances, and weights (See Figures Appendix for example of use of C001)",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "737", "Measurement Reference ID Code", Optional, Id, 2, 2, measurement_reference_id_code),
+        (2, "738", "Measurement Qualifier", Optional, Id, 1, 3, measurement_qualifier),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (5, "740", "Range Minimum", Conditional, Decimal, 1, 20, range_minimum),
+        (6, "741", "Range Maximum", Conditional, Decimal, 1, 20, range_maximum),
+        (7, "935", "Measurement Significance Code", Optional, Id, 2, 2, measurement_significance_code),
+        (8, "936", "Measurement Attribute Code", Conditional, Id, 2, 2, measurement_attribute_code),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (10, "1373", "Measurement Method or Device", Optional, Id, 2, 4, measurement_method_or_device),
+    ]
+}
+
+segment_meta! {
+    MS1,
+    "Equipment, Shipment, or Real Property Location",
+    "To specify the location of a piece of equipment, a shipment, or real property in terms of city and state or longitude and latitude",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (3, "26", "Country Code", Conditional, Id, 2, 3, country_code),
+        (4, "1654", "Longitude Code", Conditional, Id, 7, 7, longitude_code),
+        (5, "1655", "Latitude Code", Conditional, Id, 7, 7, latitude_code),
+        (6, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_06),
+        (7, "1280", "Direction Identifier Code", Optional, Id, 1, 1, direction_identifier_code_07),
+    ]
+}
+
+segment_meta! {
+    MS2,
+    "Equipment or Container Owner and Type",
+    "To specify the owner, the identification number assigned by that owner, and the type of equipment",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+        (3, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (4, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+    ]
+}
+
+segment_meta! {
+    MS3,
+    "Interline Information",
+    "To identify the interline carrier and relevant data",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (4, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (5, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    N1,
+    "Name",
+    "To identify a party by type of organization, name, and code",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "706", "Entity Relationship Code", Optional, Id, 2, 2, entity_relationship_code),
+        (6, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_06),
+    ]
+}
+
+segment_meta! {
+    N2,
+    "Additional Name Information",
+    "To specify additional names or those longer than 35 characters in length",
+    [_01, _02],
+    [
+        (1, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name_02),
+    ]
+}
+
+segment_meta! {
+    N3,
+    "Address Information",
+    "To specify the location of the named party",
+    [_01, _02],
+    [
+        (1, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_01),
+        (2, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_02),
+    ]
+}
+
+segment_meta! {
+    N4,
+    "Geographic Location",
+    "To specify the geographic place of the named party",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (5, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    N7,
+    "Equipment Details",
+    "To identify the equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24],
+    [
+        (1, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (5, "167", "Tare Weight", Conditional, Numeric(0), 3, 8, tare_weight),
+        (6, "232", "Weight Allowance", Optional, Numeric(0), 2, 6, weight_allowance),
+        (7, "205", "Dunnage", Optional, Numeric(0), 1, 6, dunnage),
+        (8, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (9, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (10, "102", "Ownership Code", Optional, Id, 1, 1, ownership_code),
+        (11, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (12, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "319", "Temperature Control", Optional, AlphaNumeric, 3, 6, temperature_control),
+        (14, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (15, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (16, "571", "Tare Qualifier Code", Conditional, Id, 1, 1, tare_qualifier_code),
+        (17, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (18, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+        (19, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (20, "65", "Height", Optional, Decimal, 1, 8, height),
+        (21, "189", "Width", Optional, Decimal, 1, 8, width),
+        (22, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (23, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_23),
+        (24, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+    ]
+}
+
+segment_meta! {
+    N7A,
+    "Accessorial Equipment Details",
+    "To identify the accessorial equipment required to load or unload product",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1042", "Load or Device Code", Optional, Id, 2, 2, load_or_device_code),
+        (2, "82", "Length", Optional, Decimal, 1, 8, length),
+        (3, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_03),
+        (4, "1044", "Hose Type Code", Optional, Id, 3, 3, hose_type_code),
+        (5, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_05),
+        (6, "1043", "Diameter", Optional, Decimal, 1, 2, diameter_06),
+        (7, "1045", "Inlet or Outlet Material Type Code", Optional, Id, 2, 2, inlet_or_outlet_material_type_code),
+        (8, "1046", "Inlet or Outlet Fitting Type Code", Optional, Id, 2, 2, inlet_or_outlet_fitting_type_code),
+        (9, "1047", "Miscellaneous Equipment Code", Optional, Id, 2, 2, miscellaneous_equipment_code),
+    ]
+}
+
+segment_meta! {
+    N7B,
+    "Additional Equipment Details",
+    "To identify additional equipment details",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1024", "Number of Tank Compartments", Optional, Numeric(0), 1, 2, number_of_tank_compartments),
+        (2, "1025", "Loading or Discharge Location Code", Optional, Id, 1, 1, loading_or_discharge_location_code),
+        (3, "1026", "Vessel Material Code", Optional, Id, 3, 3, vessel_material_code),
+        (4, "1030", "Gasket Type Code", Optional, Id, 3, 3, gasket_type_code),
+        (5, "1031", "Trailer Lining Type Code", Optional, Id, 3, 3, trailer_lining_type_code),
+        (6, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+    ]
+}
+
+segment_meta! {
+    N9,
+    "Reference Identification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "369", "Free-form Description", Conditional, AlphaNumeric, 1, 45, free_form_description),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Conditional, Time, 4, 8, time),
+        (6, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (7, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    NM1,
+    "Individual or Organizational Name",
+    "To supply the full name of an individual or organizational entity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "1065", "Entity Type Qualifier", Mandatory, Id, 1, 1, entity_type_qualifier),
+        (3, "1035", "Name Last or Organization Name", Optional, AlphaNumeric, 1, 35, name_last_or_organization_name),
+        (4, "1036", "Name First", Optional, AlphaNumeric, 1, 25, name_first),
+        (5, "1037", "Name Middle", Optional, AlphaNumeric, 1, 25, name_middle),
+        (6, "1038", "Name Prefix", Optional, AlphaNumeric, 1, 10, name_prefix),
+        (7, "1039", "Name Suffix", Optional, AlphaNumeric, 1, 10, name_suffix),
+        (8, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (10, "706", "Entity Relationship Code", Conditional, Id, 2, 2, entity_relationship_code),
+        (11, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_11),
+    ]
+}
+
+segment_meta! {
+    NTE,
+    "Note/Special Instruction",
+    "To transmit information in a free-form format, if necessary, for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "363", "Note Reference Code", Optional, Id, 3, 3, note_reference_code),
+        (2, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    OID,
+    "Order Identification Detail NEW",
+    "To specify order identification detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification_01),
+        (2, "324", "Purchase Order Number", Conditional, AlphaNumeric, 1, 22, purchase_order_number),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_03),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (5, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (6, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (7, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (8, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+    ]
+}
+
+segment_meta! {
+    PLD,
+    "Pallet Information NEW",
+    "To specify pallet information including quantity, exchange, and weight",
+    [_01, _02, _03, _04],
+    [
+        (1, "406", "Quantity of Pallets Shipped", Mandatory, Numeric(0), 1, 3, quantity_of_pallets_shipped),
+        (2, "399", "Pallet Exchange Code", Optional, Id, 1, 1, pallet_exchange_code),
+        (3, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (4, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+    ]
+}
+
+segment_meta! {
+    POD,
+    "Proof of Delivery",
+    "To supply proof of delivery information",
+    [_01, _02, _03],
+    [
+        (1, "373", "Date", Mandatory, Date, 8, 8, date),
+        (2, "337", "Time", Optional, Time, 4, 8, time),
+        (3, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name),
+    ]
+}
+
+segment_meta! {
+    PRF,
+    "Purchase Order Reference",
+    "To provide reference to a specific purchase order",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (2, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (3, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (6, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (7, "92", "Purchase Order Type Code", Optional, Id, 2, 2, purchase_order_type_code),
+    ]
+}
+
+segment_meta! {
+    Q7,
+    "Lading Exception Code",
+    "To specify the status of the shipment in terms of lading exception information",
+    [_01, _02, _03],
+    [
+        (1, "33", "Lading Exception Code", Mandatory, Id, 1, 1, lading_exception_code),
+        (2, "211", "Packaging Form Code", Optional, Id, 3, 3, packaging_form_code),
+        (3, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+    ]
+}
+
+segment_meta! {
+    R3,
+    "Route Information - Motor",
+    "To specify carrier and routing sequences and details",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (4, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (5, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code),
+        (6, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number),
+        (7, "373", "Date", Optional, Date, 8, 8, date),
+        (8, "1", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (9, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    S5,
+    "Stop Off Details",
+    "To specify stop-off detail reference numbers and stop reason",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "163", "Stop Reason Code", Mandatory, Id, 2, 2, stop_reason_code),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (5, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (7, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (8, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (9, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (11, "190", "Accomplish Code", Optional, Id, 1, 1, accomplish_code),
+    ]
+}
+
+segment_meta! {
+    SDQ,
+    "Destination Quantity",
+    "To specify destination and quantity detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (2, "66", "Identification Code Qualifier", Optional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Mandatory, AlphaNumeric, 2, 80, identification_code_03),
+        (4, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_04),
+        (5, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_05),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_07),
+        (8, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_08),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_09),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_11),
+        (12, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_12),
+        (13, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_13),
+        (14, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_14),
+        (15, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_15),
+        (16, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_16),
+        (17, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_17),
+        (18, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_18),
+        (19, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_19),
+        (20, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_20),
+        (21, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_21),
+        (22, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_22),
+        (23, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments)",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    SPO,
+    "Shipment Purchase Order Detail",
+    "To specify the purchase order details for a shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (2, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_02),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (6, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (7, "647", "Application Error Condition Code", Optional, Id, 1, 3, application_error_condition_code),
+        (8, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_08),
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "AT5" => Some(AT5::DESCRIPTOR),
+        "AT7" => Some(AT7::DESCRIPTOR),
+        "AT8" => Some(AT8::DESCRIPTOR),
+        "B1" => Some(B1::DESCRIPTOR),
+        "B2" => Some(B2::DESCRIPTOR),
+        "B2A" => Some(B2A::DESCRIPTOR),
+        "B3" => Some(B3::DESCRIPTOR),
+        "B10" => Some(B10::DESCRIPTOR),
+        "BIN" => Some(BIN::DESCRIPTOR),
+        "C3" => Some(C3::DESCRIPTOR),
+        "CD3" => Some(CD3::DESCRIPTOR),
+        "EFI" => Some(EFI::DESCRIPTOR),
+        "G61" => Some(G61::DESCRIPTOR),
+        "G62" => Some(G62::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "H1" => Some(H1::DESCRIPTOR),
+        "H2" => Some(H2::DESCRIPTOR),
+        "H3" => Some(H3::DESCRIPTOR),
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "ITD" => Some(ITD::DESCRIPTOR),
+        "K1" => Some(K1::DESCRIPTOR),
+        "L0" => Some(L0::DESCRIPTOR),
+        "L1" => Some(L1::DESCRIPTOR),
+        "L3" => Some(L3::DESCRIPTOR),
+        "L4" => Some(L4::DESCRIPTOR),
+        "L5" => Some(L5::DESCRIPTOR),
+        "L7" => Some(L7::DESCRIPTOR),
+        "L11" => Some(L11::DESCRIPTOR),
+        "LAD" => Some(LAD::DESCRIPTOR),
+        "LEP" => Some(LEP::DESCRIPTOR),
+        "LFH" => Some(LFH::DESCRIPTOR),
+        "LH1" => Some(LH1::DESCRIPTOR),
+        "LH2" => Some(LH2::DESCRIPTOR),
+        "LH3" => Some(LH3::DESCRIPTOR),
+        "LH4" => Some(LH4::DESCRIPTOR),
+        "LH6" => Some(LH6::DESCRIPTOR),
+        "LHT" => Some(LHT::DESCRIPTOR),
+        "LX" => Some(LX::DESCRIPTOR),
+        "M7" => Some(M7::DESCRIPTOR),
+        "MAN" => Some(MAN::DESCRIPTOR),
+        "MEA" => Some(MEA::DESCRIPTOR),
+        "MS1" => Some(MS1::DESCRIPTOR),
+        "MS2" => Some(MS2::DESCRIPTOR),
+        "MS3" => Some(MS3::DESCRIPTOR),
+        "N1" => Some(N1::DESCRIPTOR),
+        "N2" => Some(N2::DESCRIPTOR),
+        "N3" => Some(N3::DESCRIPTOR),
+        "N4" => Some(N4::DESCRIPTOR),
+        "N7" => Some(N7::DESCRIPTOR),
+        "N7A" => Some(N7A::DESCRIPTOR),
+        "N7B" => Some(N7B::DESCRIPTOR),
+        "N9" => Some(N9::DESCRIPTOR),
+        "NM1" => Some(NM1::DESCRIPTOR),
+        "NTE" => Some(NTE::DESCRIPTOR),
+        "OID" => Some(OID::DESCRIPTOR),
+        "PLD" => Some(PLD::DESCRIPTOR),
+        "POD" => Some(POD::DESCRIPTOR),
+        "PRF" => Some(PRF::DESCRIPTOR),
+        "Q7" => Some(Q7::DESCRIPTOR),
+        "R3" => Some(R3::DESCRIPTOR),
+        "S5" => Some(S5::DESCRIPTOR),
+        "SDQ" => Some(SDQ::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "SPO" => Some(SPO::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        _ => None,
+    }
+}
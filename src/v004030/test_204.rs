@@ -0,0 +1,48 @@
+use crate::v004030::*;
+
+#[test]
+fn parse_204() {
+    let str = r#"ISA*00*          *00*          *ZZ*SHIPPER        *ZZ*CARRIER        *231030*0800*U*00403*000000101*0*P*>~
+GS*SM*SHIPPER*CARRIER*20231030*0800*101*X*004030~
+ST*204*0001~
+B2**ABCD**SHIP123**PP~
+B2A*00~
+L11*PO1234*PO~
+G62*64*20231030~
+N1*BT*ACME SHIPPING~
+N3*100 MAIN ST~
+N4*CHICAGO*IL*60601*US~
+S5*1*LD~
+G62*69*20231031~
+N1*SH*ACME SHIPPING~
+N3*100 MAIN ST~
+N4*CHICAGO*IL*60601*US~
+S5*2*UL~
+G62*70*20231102~
+N1*CN*WIDGET CO~
+N3*200 ELM ST~
+N4*DALLAS*TX*75201*US~
+L3*1000*G~
+SE*20*0001~
+GE*1*101~
+IEA*1*000000101~
+"#;
+    let (rest, obj) = Transmission::<_204>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let tender = &obj.functional_group[0].segments[0];
+    assert_eq!(tender.b2._04, Some("SHIP123".to_string()));
+    assert_eq!(tender.loop_300.len(), 2);
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn reference_identification_is_1_50() {
+    use crate::util::meta::SegmentMeta;
+    // 004010 allows 30 characters for element 127
+    for desc in [L11::DESCRIPTOR, N9::DESCRIPTOR] {
+        let element = desc.elements_by_id("127").next().unwrap();
+        assert_eq!((element.min, element.max), (1, 50));
+    }
+    let (_, l11) = L11::parse("L11*SHIPPER-REFERENCE-0000000000000000000042*PO~").unwrap();
+    assert_eq!(l11._01.as_ref().map(String::len), Some(40));
+}
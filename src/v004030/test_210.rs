@@ -0,0 +1,38 @@
+use crate::v004030::*;
+
+#[test]
+fn parse_210() {
+    let str = r#"ST*210*0001~
+B3**INV12345*SHIP123*PP**20231101*125000**20231030*035*ABCD~
+C3*USD~
+N9*BM*BOL12345~
+G62*86*20231030~
+R3*ABCD*B**M~
+N1*SH*ACME SHIPPING~
+N3*100 MAIN ST~
+N4*CHICAGO*IL*60601*US~
+N1*CN*WIDGET CO~
+N3*200 ELM ST~
+N4*DALLAS*TX*75201*US~
+N7*ABCD*123456~
+S5*1*LD~
+N9*PO*PO1234~
+S5*2*UL~
+LX*1~
+L5*1*FREIGHT ALL KINDS~
+L0*1*1000*G*1000*G***10*PLT**L~
+L1*1*1.25*PH*125000~
+L3*1000*G***125000~
+SE*22*0001~
+"#;
+    let (rest, obj) = _210::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b3._02, "INV12345");
+    assert_eq!(obj.r3[0]._01, "ABCD");
+    assert_eq!(obj.loop_0100.len(), 2);
+    assert_eq!(obj.loop_0200[0].n7._02, "123456");
+    assert_eq!(obj.loop_0250.len(), 2);
+    assert_eq!(obj.loop_0250[0].n9.len(), 1);
+    assert_eq!(obj.loop_0400[0].l1.len(), 1);
+    assert_eq!(format!("{obj}"), str);
+}
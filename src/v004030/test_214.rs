@@ -0,0 +1,26 @@
+use crate::v004030::*;
+
+#[test]
+fn parse_214() {
+    let str = r#"ISA*00*          *00*          *ZZ*CARRIER        *ZZ*SHIPPER        *231031*1200*U*00403*000000102*0*P*>~
+GS*QM*CARRIER*SHIPPER*20231031*1200*102*X*004030~
+ST*214*0001~
+B10*PRO12345*SHIP123*ABCD~
+L11*PO1234*PO~
+N1*SH*ACME SHIPPING~
+N4*CHICAGO*IL*60601*US~
+LX*1~
+AT7*X3*NS***20231031*0930*LT~
+MS1*CHICAGO*IL*US~
+AT8*G*L*1000*10~
+SE*10*0001~
+GE*1*102~
+IEA*1*000000102~
+"#;
+    let (rest, obj) = Transmission::<_214>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let status = &obj.functional_group[0].segments[0];
+    assert_eq!(status.b10._01, Some("PRO12345".to_string()));
+    assert_eq!(status.loop_0200.len(), 1);
+    assert_eq!(format!("{obj}"), str);
+}
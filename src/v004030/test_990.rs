@@ -0,0 +1,17 @@
+use crate::v004030::*;
+
+#[test]
+fn parse_990() {
+    let str = r#"ST*990*0001~
+B1*ABCD*SHIP123*20231030*A~
+N9*CN*PRO12345~
+G62*86*20231030~
+K1*ACCEPTED~
+SE*6*0001~
+"#;
+    let (rest, obj) = _990::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.b1._04, "A");
+    assert_eq!(obj.n9.as_ref().unwrap()._02, "PRO12345");
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,295 @@
+use super::*;
+use crate::util::builder::{loop_builder, transaction_builder};
+
+transaction_builder! {
+    _810Builder => _810 ("810") {
+        segments: [big: BIG, tds: TDS],
+        optional: [cur: CUR, fob: FOB, l7: L7, inc: INC, cad: CAD, ctt: CTT],
+        repeated: [
+            nte: NTE,
+            r#ref: REF,
+            ynq: YNQ,
+            per: PER,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            bal: BAL,
+            pam: PAM,
+            txi: TXI,
+            amt: AMT,
+        ],
+        loops: [
+            loop_n1: _810LoopN1,
+            loop_lm: _810LoopLM,
+            loop_n9: _810LoopN9,
+            loop_v1: _810LoopV1,
+            loop_fa1: _810LoopFA1,
+            loop_it1: _810LoopIT1,
+            loop_sac: _810LoopSAC,
+            loop_iss: _810LoopISS,
+        ],
+    }
+}
+
+loop_builder! {
+    _810LoopN1Builder => _810LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4, dmg: DMG],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _810LoopLMBuilder => _810LoopLM {
+        segments: [lm: LM, lq: LQ],
+    }
+}
+
+loop_builder! {
+    _810LoopN9Builder => _810LoopN9 {
+        segments: [n9: N9],
+        repeated: [msg: MSG],
+    }
+}
+
+loop_builder! {
+    _810LoopV1Builder => _810LoopV1 {
+        segments: [v1: V1],
+        repeated: [r4: R4, dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _810LoopFA1Builder => _810LoopFA1 {
+        segments: [fa1: FA1],
+        repeated: [fa2: FA2],
+    }
+}
+
+loop_builder! {
+    _810LoopIT1Builder => _810LoopIT1 {
+        segments: [it1: IT1],
+        optional: [crc: CRC, cur: CUR, po4: PO4, sr: SR],
+        repeated: [
+            qty: QTY,
+            it3: IT3,
+            txi: TXI,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            itd: ITD,
+            r#ref: REF,
+            ynq: YNQ,
+            per: PER,
+            sdq: SDQ,
+            dtm: DTM,
+            cad: CAD,
+            l7: L7,
+        ],
+        loops: [
+            loop_pid: _810LoopPID,
+            loop_sac: _810LoopSAC,
+            loop_sln: _810LoopSLN,
+            loop_n1: _810LoopN1,
+            loop_lm: _810LoopLM,
+            loop_v1: _810LoopV1,
+            loop_fa1: _810LoopFA1,
+        ],
+    }
+}
+
+loop_builder! {
+    _810LoopPIDBuilder => _810LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _810LoopSACBuilder => _810LoopSAC {
+        segments: [sac: SAC],
+        repeated: [txi: TXI],
+    }
+}
+
+loop_builder! {
+    _810LoopSLNBuilder => _810LoopSLN {
+        segments: [sln: SLN],
+        optional: [dtm: DTM],
+        repeated: [r#ref: REF, pid: PID, sac: SAC, tc2: TC2, txi: TXI],
+    }
+}
+
+loop_builder! {
+    _810LoopISSBuilder => _810LoopISS {
+        segments: [iss: ISS],
+        optional: [pid: PID],
+    }
+}
+
+transaction_builder! {
+    _850Builder => _850 ("850") {
+        segments: [beg: BEG],
+        optional: [cur: CUR, inc: INC, ctt: CTT, amt: AMT],
+        repeated: [
+            r#ref: REF,
+            per: PER,
+            fob: FOB,
+            ctp: CTP,
+            pam: PAM,
+            itd: ITD,
+            dtm: DTM,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            txi: TXI,
+        ],
+        loops: [
+            loop_sac: _850LoopSAC,
+            loop_n9: _850LoopN9,
+            loop_n1: _850LoopN1,
+            loop_po1: _850LoopPO1,
+        ],
+    }
+}
+
+loop_builder! {
+    _850LoopSACBuilder => _850LoopSAC {
+        segments: [sac: SAC],
+        optional: [cur: CUR],
+    }
+}
+
+loop_builder! {
+    _850LoopN9Builder => _850LoopN9 {
+        segments: [n9: N9],
+        repeated: [dtm: DTM, msg: MSG],
+    }
+}
+
+loop_builder! {
+    _850LoopN1Builder => _850LoopN1 {
+        segments: [n1: N1],
+        repeated: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _850LoopPO1Builder => _850LoopPO1 {
+        segments: [po1: PO1],
+        optional: [cur: CUR, td1: TD1],
+        repeated: [
+            po3: PO3,
+            ctp: CTP,
+            pam: PAM,
+            mea: MEA,
+            pwk: PWK,
+            po4: PO4,
+            r#ref: REF,
+            per: PER,
+            itd: ITD,
+            fob: FOB,
+            sdq: SDQ,
+            it3: IT3,
+            dtm: DTM,
+            tc2: TC2,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            man: MAN,
+            msg: MSG,
+            txi: TXI,
+        ],
+        loops: [
+            loop_pid: _850LoopPID,
+            loop_sac: _850LoopSAC,
+            loop_sch: _850LoopSCH,
+            loop_n9: _850LoopN9,
+            loop_n1: _850LoopN1,
+            loop_sln: _850LoopSLN,
+        ],
+    }
+}
+
+loop_builder! {
+    _850LoopPIDBuilder => _850LoopPID {
+        segments: [pid: PID],
+        repeated: [mea: MEA],
+    }
+}
+
+loop_builder! {
+    _850LoopSCHBuilder => _850LoopSCH {
+        segments: [sch: SCH],
+        repeated: [td1: TD1, td5: TD5, td3: TD3, td4: TD4, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _850LoopSLNBuilder => _850LoopSLN {
+        segments: [sln: SLN],
+        optional: [po4: PO4],
+        repeated: [msg: MSG, pid: PID, po3: PO3, dtm: DTM, ctp: CTP, pam: PAM],
+    }
+}
+
+transaction_builder! {
+    _856Builder => _856 ("856") {
+        segments: [bsn: BSN],
+        optional: [ctt: CTT],
+        repeated: [dtm: DTM],
+        loops: [loop_hl: _856LoopHL],
+        renumber: [loop_hl],
+    }
+}
+
+loop_builder! {
+    _856LoopHLBuilder => _856LoopHL {
+        segments: [hl: HL],
+        optional: [lin: LIN, sn1: SN1, prf: PRF, po4: PO4, fob: FOB],
+        repeated: [
+            sln: SLN,
+            pid: PID,
+            mea: MEA,
+            pwk: PWK,
+            pkg: PKG,
+            td1: TD1,
+            td5: TD5,
+            td3: TD3,
+            td4: TD4,
+            r#ref: REF,
+            per: PER,
+            man: MAN,
+            dtm: DTM,
+            sdq: SDQ,
+        ],
+        loops: [loop_cld: _856LoopCLD, loop_n1: _856LoopN1, loop_hl: _856LoopHL],
+    }
+}
+
+loop_builder! {
+    _856LoopCLDBuilder => _856LoopCLD {
+        segments: [cld: CLD],
+        optional: [dtm: DTM],
+        repeated: [r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _856LoopN1Builder => _856LoopN1 {
+        segments: [n1: N1],
+        optional: [n4: N4, fob: FOB],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
@@ -0,0 +1,693 @@
+//! v004060 repesents all entities of the 004060 specification.
+//!
+//! The segment dictionary reuses the 004010 element definitions, except for
+//! element 127 (Reference Identification) which is 1/50 in 004060. Segment
+//! layouts were checked against the 004060 samples in the tests, which cover
+//! BEG, BIG, BSN, CTT, CUR, DTM, HL, IT1, ITD, LIN, N1, N3, N4, PID, PO1, PO4,
+//! PRF, REF, SCH, SN1, TD1, TD5 and TDS; the other segments are carried over
+//! from 004010 unchecked.
+
+use crate::util::Parser;
+pub use builder::*;
+use nom::combinator::opt;
+use nom::multi::many0;
+use nom::IResult;
+use nom::Parser as _;
+pub use segment::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use x12_types_macros::{DisplayX12, ParseX12};
+
+mod builder;
+mod segment;
+
+#[cfg(test)]
+mod test_810;
+#[cfg(test)]
+mod test_850;
+#[cfg(test)]
+mod test_856;
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct Transmission<T> {
+    pub isa: ISA,
+    pub functional_group: Vec<FunctionalGroup<T>>,
+    pub iea: IEA,
+}
+
+impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
+    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
+        let mut output = Transmission::default();
+        let (input, obj) = ISA::parse(input)?;
+        output.isa = obj;
+        // functional group
+        let (input, gs) = GS::parse(input)?;
+        let (input, t_obj) = many0(T::parse).parse(input)?;
+        // let (input, t_obj) = T::parse(input)?;
+        let (input, ge) = GE::parse(input)?;
+        let fg = FunctionalGroup {
+            gs,
+            segments: t_obj,
+            // segments: vec![t_obj],
+            ge,
+        };
+        output.functional_group.push(fg);
+        let (input, obj) = IEA::parse(input)?;
+        output.iea = obj;
+        Ok((input, output))
+    }
+}
+
+impl<T: Display> Display for Transmission<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines = vec![];
+        lines.push(format!("{}", self.isa));
+        for fg in &self.functional_group {
+            lines.push(format!("{}", fg.gs));
+            for segment in &fg.segments {
+                lines.push(format!("{}", segment));
+            }
+            lines.push(format!("{}", fg.ge));
+        }
+        lines.push(format!("{}", self.iea));
+        let all = lines.join("");
+        write!(f, "{all}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct FunctionalGroup<T> {
+    pub gs: GS,
+    pub segments: Vec<T>,
+    pub ge: GE,
+}
+
+/// 810 - Invoice
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Invoice Transaction Set (810) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide for customary and established business and industry practice relative to the billing for goods and services provided.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810 {
+    pub st: ST,
+    pub big: BIG,
+    pub nte: Vec<NTE>,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub ynq: Vec<YNQ>,
+    pub per: Vec<PER>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_810LoopN1>,
+    pub itd: Vec<ITD>,
+    pub dtm: Vec<DTM>,
+    pub fob: Option<FOB>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub l7: Option<L7>,
+    pub bal: Vec<BAL>,
+    pub inc: Option<INC>,
+    pub pam: Vec<PAM>,
+    #[x12(loop_trigger = "LM")]
+    pub loop_lm: Vec<_810LoopLM>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_810LoopN9>,
+    #[x12(loop_trigger = "V1")]
+    pub loop_v1: Vec<_810LoopV1>,
+    #[x12(loop_trigger = "FA1")]
+    pub loop_fa1: Vec<_810LoopFA1>,
+    #[x12(loop_trigger = "IT1")]
+    pub loop_it1: Vec<_810LoopIT1>,
+    pub tds: TDS,
+    pub txi: Vec<TXI>,
+    pub cad: Option<CAD>,
+    pub amt: Vec<AMT>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_810LoopSAC>,
+    #[x12(loop_trigger = "ISS")]
+    pub loop_iss: Vec<_810LoopISS>,
+    pub ctt: Option<CTT>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub dmg: Option<DMG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopLM {
+    pub lm: LM,
+    pub lq: LQ,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopN9 {
+    pub n9: N9,
+    pub msg: Vec<MSG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopV1 {
+    pub v1: V1,
+    pub r4: Vec<R4>,
+    pub dtm: Vec<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopFA1 {
+    pub fa1: FA1,
+    pub fa2: Vec<FA2>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopIT1 {
+    pub it1: IT1,
+    pub crc: Option<CRC>,
+    pub qty: Vec<QTY>,
+    pub cur: Option<CUR>,
+    pub it3: Vec<IT3>,
+    pub txi: Vec<TXI>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub mea: Vec<MEA>,
+    #[x12(loop_trigger = "PID")]
+    pub loop_pid: Vec<_810LoopPID>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub po4: Option<PO4>,
+    pub itd: Vec<ITD>,
+    pub r#ref: Vec<REF>,
+    pub ynq: Vec<YNQ>,
+    pub per: Vec<PER>,
+    pub sdq: Vec<SDQ>,
+    pub dtm: Vec<DTM>,
+    pub cad: Vec<CAD>,
+    pub l7: Vec<L7>,
+    pub sr: Option<SR>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_810LoopSAC>,
+    #[x12(loop_trigger = "SLN")]
+    pub loop_sln: Vec<_810LoopSLN>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_810LoopN1>,
+    #[x12(loop_trigger = "LM")]
+    pub loop_lm: Vec<_810LoopLM>,
+    #[x12(loop_trigger = "V1")]
+    pub loop_v1: Vec<_810LoopV1>,
+    #[x12(loop_trigger = "FA1")]
+    pub loop_fa1: Vec<_810LoopFA1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopPID {
+    pub pid: PID,
+    pub mea: Vec<MEA>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopSAC {
+    pub sac: SAC,
+    pub txi: Vec<TXI>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopSLN {
+    pub sln: SLN,
+    pub dtm: Option<DTM>,
+    pub r#ref: Vec<REF>,
+    pub pid: Vec<PID>,
+    pub sac: Vec<SAC>,
+    pub tc2: Vec<TC2>,
+    pub txi: Vec<TXI>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _810LoopISS {
+    pub iss: ISS,
+    pub pid: Option<PID>,
+}
+
+/// 850 - Purchase Order
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Purchase Order Transaction Set (850) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to provide for customary and established business and industry practice relative to the placement of purchase orders for goods and services.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BEG | Beginning Segment for Purchase Order | M | 1
+/// 040 | CUR | Currency | O | 1
+/// 050 | REF | Reference Identification | O | >1
+/// 060 | PER | Administrative Communications Contact | O | 3
+/// 080 | FOB | F.O.B. Related Instructions | O | >1
+/// 090 | CTP | Pricing Information | O | >1
+/// 095 | PAM | Period Amount | O | 10
+/// LOOP ID - SAC | 25
+/// SAC -> 120 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 125 | CUR | Currency | O | 1
+/// 130 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 145 | INC | Installment Information | O | 1
+/// 150 | DTM | Date/Time Reference | O | 10
+/// 200 | PID | Product/Item Description | O | 200
+/// 210 | MEA | Measurements | O | 40
+/// 220 | PWK | Paperwork | O | 25
+/// 230 | PKG | Marking, Packaging, Loading | O | 200
+/// 240 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 250 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// 260 | TD3 | Carrier Details (Equipment) | O | 12
+/// 270 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 280 | MAN | Marks and Numbers | O | 10
+/// 285 | TXI | Tax Information | O | >1
+/// LOOP ID - N9 | 1000
+/// N9 -> 295 | N9 | Reference Identification | O | 1
+/// N9 -> 300 | DTM | Date/Time Reference | O | >1
+/// N9 -> 305 | MSG | Message Text | O | 1000
+/// LOOP ID - N1 | 200
+/// N1 -> 310 | N1 | Name | O | 1
+/// N1 -> 320 | N2 | Additional Name Information | O | 2
+/// N1 -> 330 | N3 | Address Information | O | 2
+/// N1 -> 340 | N4 | Geographic Location | O | >1
+/// N1 -> 350 | REF | Reference Identification | O | 12
+/// N1 -> 360 | PER | Administrative Communications Contact | O | >1
+/// LOOP ID - PO1 | 100000
+/// PO1 -> 010 | PO1 | Baseline Item Data | M | 1
+/// PO1 -> 040 | CUR | Currency | O | 1
+/// PO1 -> 050 | PO3 | Additional Item Detail | O | 25
+/// PO1 -> 060 | CTP | Pricing Information | O | >1
+/// PO1 -> 065 | PAM | Period Amount | O | 10
+/// PO1 -> 070 | MEA | Measurements | O | 40
+/// PO1 -> LOOP ID - PID | 1000
+/// PO1 -> PID -> 080 | PID | Product/Item Description | O | 1
+/// PO1 -> PID -> 090 | MEA | Measurements | O | 10
+/// PO1 -> 100 | PWK | Paperwork | O | 25
+/// PO1 -> 110 | PO4 | Item Physical Details | O | >1
+/// PO1 -> 120 | REF | Reference Identification | O | >1
+/// PO1 -> 130 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SAC | 25
+/// PO1 -> SAC -> 130 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// PO1 -> SAC -> 135 | CUR | Currency | O | 1
+/// PO1 -> 150 | ITD | Terms of Sale/Deferred Terms of Sale | O | 2
+/// PO1 -> 180 | FOB | F.O.B. Related Instructions | O | >1
+/// PO1 -> 190 | SDQ | Destination Quantity | O | 500
+/// PO1 -> 200 | IT3 | Additional Item Data | O | 5
+/// PO1 -> 210 | DTM | Date/Time Reference | O | 10
+/// PO1 -> 220 | TC2 | Commodity | O | >1
+/// PO1 -> 230 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// PO1 -> 240 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> 250 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> 260 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> 271 | MAN | Marks and Numbers | O | 10
+/// PO1 -> 280 | MSG | Message Text | O | 12
+/// PO1 -> 291 | TXI | Tax Information | O | >1
+/// PO1 -> LOOP ID - SCH | 200
+/// PO1 -> SCH -> 295 | SCH | Line Item Schedule | O | 1
+/// PO1 -> SCH -> 296 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// PO1 -> SCH -> 297 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// PO1 -> SCH -> 298 | TD3 | Carrier Details (Equipment) | O | 12
+/// PO1 -> SCH -> 299 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> SCH -> 300 | REF | Reference Identification | O | >1
+/// PO1 -> LOOP ID - N9 | 1000
+/// PO1 -> N9 -> 330 | N9 | Reference Identification | O | 1
+/// PO1 -> N9 -> 340 | DTM | Date/Time Reference | O | >1
+/// PO1 -> N9 -> 350 | MSG | Message Text | O | 1000
+/// PO1 -> LOOP ID - N1 | 200
+/// PO1 -> N1 -> 350 | N1 | Name | O | 1
+/// PO1 -> N1 -> 360 | N2 | Additional Name Information | O | 2
+/// PO1 -> N1 -> 370 | N3 | Address Information | O | 2
+/// PO1 -> N1 -> 380 | N4 | Geographic Location | O | 1
+/// PO1 -> N1 -> 400 | REF | Reference Identification | O | 12
+/// PO1 -> N1 -> 410 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SLN | 1000
+/// PO1 -> SLN -> 470 | SLN | Subline Item Detail | O | 1
+/// PO1 -> SLN -> 480 | MSG | Message Text | O | 100
+/// PO1 -> SLN -> 500 | PID | Product/Item Description | O | 1000
+/// PO1 -> SLN -> 510 | PO3 | Additional Item Detail | O | 104
+/// PO1 -> SLN -> 530 | DTM | Date/Time Reference | O | 10
+/// PO1 -> SLN -> 540 | CTP | Pricing Information | O | 25
+/// PO1 -> SLN -> 545 | PAM | Period Amount | O | 10
+/// PO1 -> SLN -> 550 | PO4 | Item Physical Details | O | 1
+/// LOOP ID - CTT | 1
+/// CTT -> 010 | CTT | Transaction Totals | O | 1
+/// CTT -> 020 | AMT | Monetary Amount | O | 1
+/// 030 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850 {
+    pub st: ST,
+    pub beg: BEG,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Vec<FOB>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_850LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub inc: Option<INC>,
+    pub dtm: Vec<DTM>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_850LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_850LoopN1>,
+    #[x12(loop_trigger = "PO1")]
+    pub loop_po1: Vec<_850LoopPO1>,
+    pub ctt: Option<CTT>,
+    pub amt: Option<AMT>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSAC {
+    pub sac: SAC,
+    pub cur: Option<CUR>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopN9 {
+    pub n9: N9,
+    pub dtm: Vec<DTM>,
+    pub msg: Vec<MSG>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Vec<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopPO1 {
+    pub po1: PO1,
+    pub cur: Option<CUR>,
+    pub po3: Vec<PO3>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub mea: Vec<MEA>,
+    #[x12(loop_trigger = "PID")]
+    pub loop_pid: Vec<_850LoopPID>,
+    pub pwk: Vec<PWK>,
+    pub po4: Vec<PO4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    #[x12(loop_trigger = "SAC")]
+    pub loop_sac: Vec<_850LoopSAC>,
+    pub itd: Vec<ITD>,
+    pub fob: Vec<FOB>,
+    pub sdq: Vec<SDQ>,
+    pub it3: Vec<IT3>,
+    pub dtm: Vec<DTM>,
+    pub tc2: Vec<TC2>,
+    pub td1: Option<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub man: Vec<MAN>,
+    pub msg: Vec<MSG>,
+    pub txi: Vec<TXI>,
+    #[x12(loop_trigger = "SCH")]
+    pub loop_sch: Vec<_850LoopSCH>,
+    #[x12(loop_trigger = "N9")]
+    pub loop_n9: Vec<_850LoopN9>,
+    #[x12(loop_trigger = "N1")]
+    pub loop_n1: Vec<_850LoopN1>,
+    #[x12(loop_trigger = "SLN")]
+    pub loop_sln: Vec<_850LoopSLN>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopPID {
+    pub pid: PID,
+    pub mea: Vec<MEA>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSCH {
+    pub sch: SCH,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _850LoopSLN {
+    pub sln: SLN,
+    pub msg: Vec<MSG>,
+    pub pid: Vec<PID>,
+    pub po3: Vec<PO3>,
+    pub dtm: Vec<DTM>,
+    pub ctp: Vec<CTP>,
+    pub pam: Vec<PAM>,
+    pub po4: Option<PO4>,
+}
+
+/// 856 - Ship Notice/Manifest
+///
+/// This Draft Standard for Trial Use contains the format and establishes the data contents of the Ship Notice/Manifest Transaction Set (856) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used to list the contents of a shipment of goods as well as additional information relating to the shipment, such as order information, product description, physical characteristics, type of packaging, marking, carrier information, and configuration of goods within the transportation equipment.
+///
+/// The `HL` loops are nested by their parent pointers (`HL02`): a shipment
+/// holds its orders, an order its tares and packs, a pack its items.
+/// Rendering recomputes `HL01` and `HL02` from that nesting, see [`crate::util::hl`].
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 010 | ST | Transaction Set Header | M | 1
+/// 020 | BSN | Beginning Segment for Ship Notice | M | 1
+/// 040 | DTM | Date/Time Reference | O | 10
+/// LOOP ID - HL | 200000
+/// HL -> 010 | HL | Hierarchical Level | M | 1
+/// HL -> 020 | LIN | Item Identification | O | 1
+/// HL -> 030 | SN1 | Item Detail (Shipment) | O | 1
+/// HL -> 040 | SLN | Subline Item Detail | O | 1000
+/// HL -> 050 | PRF | Purchase Order Reference | O | 1
+/// HL -> 060 | PO4 | Item Physical Details | O | 1
+/// HL -> 070 | PID | Product/Item Description | O | 200
+/// HL -> 080 | MEA | Measurements | O | 40
+/// HL -> 090 | PWK | Paperwork | O | 25
+/// HL -> 100 | PKG | Marking, Packaging, Loading | O | 25
+/// HL -> 110 | TD1 | Carrier Details (Quantity and Weight) | O | 20
+/// HL -> 120 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// HL -> 130 | TD3 | Carrier Details (Equipment) | O | 12
+/// HL -> 140 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// HL -> 150 | REF | Reference Identification | O | >1
+/// HL -> 160 | PER | Administrative Communications Contact | O | 3
+/// HL -> LOOP ID - CLD | 200
+/// HL -> CLD -> 170 | CLD | Load Detail | O | 1
+/// HL -> CLD -> 180 | REF | Reference Identification | O | 200
+/// HL -> CLD -> 185 | DTM | Date/Time Reference | O | 1
+/// HL -> 190 | MAN | Marks and Numbers | O | >1
+/// HL -> 200 | DTM | Date/Time Reference | O | 10
+/// HL -> 210 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> LOOP ID - N1 | 200
+/// HL -> N1 -> 220 | N1 | Name | O | 1
+/// HL -> N1 -> 230 | N2 | Additional Name Information | O | 2
+/// HL -> N1 -> 240 | N3 | Address Information | O | 2
+/// HL -> N1 -> 250 | N4 | Geographic Location | O | 1
+/// HL -> N1 -> 260 | REF | Reference Identification | O | 12
+/// HL -> N1 -> 270 | PER | Administrative Communications Contact | O | 3
+/// HL -> N1 -> 280 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> 290 | SDQ | Destination Quantity | O | 50
+/// 010 | CTT | Transaction Totals | O | 1
+/// 020 | SE | Transaction Set Trailer | M | 1
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct _856 {
+    pub st: ST,
+    pub bsn: BSN,
+    pub dtm: Vec<DTM>,
+    pub loop_hl: Vec<_856LoopHL>,
+    pub ctt: Option<CTT>,
+    pub se: SE,
+}
+
+impl<'a> Parser<&'a str, _856, nom::error::Error<&'a str>> for _856 {
+    fn parse(input: &'a str) -> IResult<&'a str, _856> {
+        let mut output = _856::default();
+        let (rest, obj) = ST::parse(input)?;
+        output.st = obj;
+        let (rest, obj) = BSN::parse(rest)?;
+        output.bsn = obj;
+        let (rest, obj) = many0(DTM::parse).parse(rest)?;
+        output.dtm = obj;
+        // the HL loops come as a flat list, nested afterwards by HL02
+        let (rest, obj) = many0(_856LoopHL::parse).parse(rest)?;
+        output.loop_hl = crate::util::hl::build_tree(obj);
+        let (rest, obj) = opt(CTT::parse).parse(rest)?;
+        output.ctt = obj;
+        let (rest, obj) = SE::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+impl Display for _856 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut loop_hl = self.loop_hl.clone();
+        crate::util::hl::renumber(&mut loop_hl);
+        write!(f, "{}{}", self.st, self.bsn)?;
+        for dtm in &self.dtm {
+            write!(f, "{dtm}")?;
+        }
+        for hl in &loop_hl {
+            write!(f, "{hl}")?;
+        }
+        if let Some(ctt) = &self.ctt {
+            // the hash total counts the HL segments rendered above
+            let mut ctt = ctt.clone();
+            ctt._01 = crate::util::hl::walk(&loop_hl).len().to_string();
+            write!(f, "{ctt}")?;
+        }
+        write!(f, "{}", self.se)
+    }
+}
+
+/// One level of the 856 hierarchy (shipment, order, tare, pack or item)
+/// together with the levels below it.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
+pub struct _856LoopHL {
+    pub hl: HL,
+    pub lin: Option<LIN>,
+    pub sn1: Option<SN1>,
+    pub sln: Vec<SLN>,
+    pub prf: Option<PRF>,
+    pub po4: Option<PO4>,
+    pub pid: Vec<PID>,
+    pub mea: Vec<MEA>,
+    pub pwk: Vec<PWK>,
+    pub pkg: Vec<PKG>,
+    pub td1: Vec<TD1>,
+    pub td5: Vec<TD5>,
+    pub td3: Vec<TD3>,
+    pub td4: Vec<TD4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub loop_cld: Vec<_856LoopCLD>,
+    pub man: Vec<MAN>,
+    pub dtm: Vec<DTM>,
+    pub fob: Option<FOB>,
+    pub loop_n1: Vec<_856LoopN1>,
+    pub sdq: Vec<SDQ>,
+    /// levels whose `HL02` points at this one
+    pub loop_hl: Vec<_856LoopHL>,
+}
+
+impl _856LoopHL {
+    /// Hierarchical Level Code (`HL03`), e.g. `S` for shipment or `I` for item.
+    pub fn level(&self) -> &str {
+        &self.hl._03
+    }
+}
+
+/// Parses a single level; `loop_hl` is left empty.
+impl<'a> Parser<&'a str, _856LoopHL, nom::error::Error<&'a str>> for _856LoopHL {
+    fn parse(input: &'a str) -> IResult<&'a str, _856LoopHL> {
+        let mut output = _856LoopHL::default();
+        let (rest, obj) = HL::parse(input)?;
+        output.hl = obj;
+        let (rest, obj) = opt(LIN::parse).parse(rest)?;
+        output.lin = obj;
+        let (rest, obj) = opt(SN1::parse).parse(rest)?;
+        output.sn1 = obj;
+        let (rest, obj) = many0(SLN::parse).parse(rest)?;
+        output.sln = obj;
+        let (rest, obj) = opt(PRF::parse).parse(rest)?;
+        output.prf = obj;
+        let (rest, obj) = opt(PO4::parse).parse(rest)?;
+        output.po4 = obj;
+        let (rest, obj) = many0(PID::parse).parse(rest)?;
+        output.pid = obj;
+        let (rest, obj) = many0(MEA::parse).parse(rest)?;
+        output.mea = obj;
+        let (rest, obj) = many0(PWK::parse).parse(rest)?;
+        output.pwk = obj;
+        let (rest, obj) = many0(PKG::parse).parse(rest)?;
+        output.pkg = obj;
+        let (rest, obj) = many0(TD1::parse).parse(rest)?;
+        output.td1 = obj;
+        let (rest, obj) = many0(TD5::parse).parse(rest)?;
+        output.td5 = obj;
+        let (rest, obj) = many0(TD3::parse).parse(rest)?;
+        output.td3 = obj;
+        let (rest, obj) = many0(TD4::parse).parse(rest)?;
+        output.td4 = obj;
+        let (rest, obj) = many0(REF::parse).parse(rest)?;
+        output.r#ref = obj;
+        let (rest, obj) = many0(PER::parse).parse(rest)?;
+        output.per = obj;
+        let (rest, obj) = many0(_856LoopCLD::parse).parse(rest)?;
+        output.loop_cld = obj;
+        let (rest, obj) = many0(MAN::parse).parse(rest)?;
+        output.man = obj;
+        let (rest, obj) = many0(DTM::parse).parse(rest)?;
+        output.dtm = obj;
+        let (rest, obj) = opt(FOB::parse).parse(rest)?;
+        output.fob = obj;
+        let (rest, obj) = many0(_856LoopN1::parse).parse(rest)?;
+        output.loop_n1 = obj;
+        let (rest, obj) = many0(SDQ::parse).parse(rest)?;
+        output.sdq = obj;
+        Ok((rest, output))
+    }
+}
+
+impl crate::util::hl::Hierarchy for _856LoopHL {
+    fn id(&self) -> &str {
+        &self.hl._01
+    }
+    fn parent_id(&self) -> Option<&str> {
+        self.hl._02.as_deref()
+    }
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        self.hl._01 = id;
+        self.hl._02 = parent_id;
+        // HL04 is optional in the 856 and kept absent if it was not sent
+        if self.hl._04.is_some() {
+            self.hl._04 = Some(child_code.to_string());
+        }
+    }
+    fn children(&self) -> &[Self] {
+        &self.loop_hl
+    }
+    fn children_mut(&mut self) -> &mut Vec<Self> {
+        &mut self.loop_hl
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _856LoopCLD {
+    pub cld: CLD,
+    pub r#ref: Vec<REF>,
+    pub dtm: Option<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12, ParseX12)]
+pub struct _856LoopN1 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+    pub fob: Option<FOB>,
+}
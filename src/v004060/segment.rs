@@ -0,0 +1,2813 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use x12_types_macros::DisplaySegment;
+use x12_types_macros::ParseSegment;
+
+mod meta;
+pub use meta::segment_descriptor;
+
+/// AMT - Monetary Amount Information
+///
+/// To indicate the total monetary amount
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 522 | Amount Qualifier Code | 1 | M | ID | 1/3
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 478 | Credit/Debit Flag Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct AMT {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+/// BAL - Balance Detail
+///
+/// To identify the specific monetary balances associated with a particular account
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 951 | Balance Type Code | 1 | M | ID | 1/2
+/// 02 | 522 | Amount Qualifier Code | 1 | M | ID | 1/3
+/// 03 | 782 | Monetary Amount | 1 | M | R | 1/18
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BAL {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+}
+
+/// BEG - Beginning Segment for Purchase Order
+///
+/// To indicate the beginning of the Purchase Order Transaction Set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 92 | Purchase Order Type Code | 1 | M | ID | 2/2
+/// 03 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 04 | 328 | Release Number | 1 | O | AN | 1/30
+/// 05 | 373 | Date | 1 | M | DT | 8/8
+/// 06 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 07 | 587 | Acknowledgment Type | 1 | O | ID | 2/2
+/// 08 | 1019 | Invoice Type Code | 1 | O | ID | 3/3
+/// 09 | 1166 | Contract Type Code | 1 | O | ID | 2/2
+/// 10 | 1232 | Purchase Category | 1 | O | ID | 2/2
+/// 11 | 786 | Security Level Code | 1 | O | ID | 2/2
+/// 12 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BEG {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+}
+
+/// BIG - Beginning Segment for Invoice
+///
+/// To indicate the beginning of an invoice transaction set and transmit identifying numbers and dates
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 373 | Date | 1 | M | DT | 8/8
+/// 02 | 76 | Invoice Number | 1 | M | AN | 1/22
+/// 03 | 373 | Date | 1 | O | DT | 8/8
+/// 04 | 324 | Purchase Order Number | 1 | O | AN | 1/22
+/// 05 | 328 | Release Number | 1 | O | AN | 1/30
+/// 06 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
+/// 07 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 08 | 353 | Transaction Set Purpose Code | 1 | O | ID | 2/2
+/// 09 | 306 | Action Code | 1 | O | ID | 1/2
+/// 10 | 76 | Invoice Number | 1 | O | AN | 1/22
+/// 11 | 1019 | Invoice Type Code | 1 | O | ID | 1/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BIG {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+}
+
+/// BSN - Beginning Segment for Ship Notice
+///
+/// To transmit identifying numbers, dates, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 396 | Shipment Identification | 1 | M | AN | 2/30
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 337 | Time | 1 | M | TM | 4/8
+/// 05 | 1005 | Hierarchical Structure Code | 1 | O | ID | 4/4
+/// 06 | 640 | Transaction Type Code | 1 | X | ID | 2/2
+/// 07 | 641 | Status Reason Code | 1 | O | ID | 3/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct BSN {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// CAD - Carrier Details
+///
+/// To specify transportation details for the transaction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 02 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 03 | 207 | Equipment Number | 1 | O | AN | 1/10
+/// 04 | 140 | Standard Carrier Alpha Code | 1 | X | ID | 2/4
+/// 05 | 387 | Routing | 1 | X | AN | 1/35
+/// 06 | 368 | Shipment/Order Status Code | 1 | O | ID | 2/2
+/// 07 | 128 | Reference Identification Qualifier | 1 | O | ID | 2/3
+/// 08 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 09 | 284 | Service Level Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CAD {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+/// CLD - Load Detail
+///
+/// To specify the number of material loads shipped
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 622 | Number of Loads | 1 | M | N0 | 1/5
+/// 02 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 03 | 103 | Packaging Code | 1 | O | AN | 3/5
+/// 04 | 357 | Size | 1 | O | R | 1/8
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CLD {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// CRC - Conditions Indicator
+///
+/// To supply information on conditions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1136 | Code Category | 1 | M | ID | 2/2
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 03 | 1321 | Condition Indicator | 1 | M | ID | 2/3
+/// 04 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 05 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 06 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 07 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CRC {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// CTP - Pricing Information
+///
+/// To specify pricing information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 687 | Class of Trade Code | 1 | O | ID | 2/2
+/// 02 | 236 | Price Identifier Code | 1 | X | ID | 3/3
+/// 03 | 212 | Unit Price | 1 | X | R | 1/17
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 648 | Price Multiplier Qualifier | 1 | X | ID | 3/3
+/// 07 | 649 | Multiplier | 1 | X | R | 1/10
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 10 | 499 | Condition Value | 1 | O | AN | 1/10
+/// 11 | 289 | Multiple Price Quantity | 1 | O | N0 | 1/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CTP {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+}
+
+/// CTT - Transaction Totals
+///
+/// To transmit a hash total for a specific element in the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 354 | Number of Line Items | 1 | M | N0 | 1/6
+/// 02 | 347 | Hash Total | 1 | O | R | 1/10
+/// 03 | 81 | Weight | 1 | X | R | 1/10
+/// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 05 | 183 | Volume | 1 | X | R | 1/8
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CTT {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// CUR - Currency
+///
+/// To specify the currency (dollars, pounds, francs, etc.) used in a transaction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 100 | Currency Code | 1 | M | ID | 3/3
+/// 03 | 280 | Exchange Rate | 1 | O | R | 4/10
+/// 04 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 05 | 100 | Currency Code | 1 | X | ID | 3/3
+/// 06 | 669 | Currency Market/Exchange Code | 1 | O | ID | 3/3
+/// 07 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 08 | 373 | Date | 1 | O | DT | 8/8
+/// 09 | 337 | Time | 1 | O | TM | 4/8
+/// 10 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 11 | 373 | Date | 1 | X | DT | 8/8
+/// 12 | 337 | Time | 1 | X | TM | 4/8
+/// 13 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 14 | 373 | Date | 1 | X | DT | 8/8
+/// 15 | 337 | Time | 1 | X | TM | 4/8
+/// 16 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 17 | 373 | Date | 1 | X | DT | 8/8
+/// 18 | 337 | Time | 1 | X | TM | 4/8
+/// 19 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 20 | 373 | Date | 1 | X | DT | 8/8
+/// 21 | 337 | Time | 1 | X | TM | 4/8
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct CUR {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+}
+
+/// DMG - Demographic Information
+///
+/// To supply demographic information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 02 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 03 | 1068 | Gender Code | 1 | O | ID | 1/1
+/// 04 | 1067 | Marital Status Code | 1 | O | ID | 1/1
+/// 05 | 1109 | Race or Ethnicity Code | 1 | O | ID | 1/1
+/// 06 | 1066 | Citizenship Status Code | 1 | O | ID | 1/2
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 659 | Basis of Verification Code | 1 | O | ID | 1/2
+/// 09 | 380 | Quantity | 1 | O | R | 1/15
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct DMG {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+/// DTM - Date/Time Reference
+///
+/// To specify pertinent dates and times
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 02 | 373 | Date | 1 | X | DT | 8/8
+/// 03 | 337 | Time | 1 | X | TM | 4/8
+/// 04 | 623 | Time Code | 1 | O | ID | 2/2
+/// 05 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 06 | 1251 | Date Time Period | 1 | X | AN | 1/35
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct DTM {
+    /// 374 - Date/Time Qualifier
+    ///
+    /// Code specifying type of date or time, or both date and time
+    /// - TYPE=ID
+    /// - MIN=3
+    /// - MAX=3
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    /// 337 - Time
+    ///
+    /// Time expressed in 24-hour clock time as follows: HHMM, or HHMMSS, or HHMMSSD, or HHMMSSDD, where H = hours (00-23), M = minutes (00-59), S = integer seconds (00-59) and DD = decimal seconds; decimal seconds are expressed as follows: D = tenths (0-9) and DD = hundredths (00-99)
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=8
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    /// 623 - Time Code
+    ///
+    /// Code identifying the time. In accordance with International Standards Organization standard 8601, time can be specified by a + or - and an indication in hours in relation to Universal Time Coordinate (UTC) time; since + is a restricted character, + and - are substituted by P and M in the codes that follow
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+}
+
+/// FA1 - Type of Financial Accounting Data
+///
+/// To specify the organization controlling the content of the accounting citation, and the purpose associated with the accounting citation
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 559 | Agency Qualifier Code | 1 | M | ID | 2/2
+/// 02 | 1301 | Service, Promotion, Allowance, or Charge Code | 1 | O | ID | 4/4
+/// 03 | 1212 | Allowance or Charge Indicator Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct FA1 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+/// FA2 - Accounting Data
+///
+/// To specify the detailed accounting data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1196 | Breakdown Structure Detail Code | 1 | M | ID | 2/2
+/// 02 | 1195 | Financial Information Code | 1 | M | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct FA2 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// FOB - F.O.B. Related Instructions
+///
+/// To specify transportation instructions relating to shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 146 | Shipment Method of Payment | 1 | M | ID | 2/2
+/// 02 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 03 | 352 | Description | 1 | O | AN | 1/80
+/// 04 | 334 | Transportation Terms Qualifier Code | 1 | O | ID | 2/2
+/// 05 | 335 | Transportation Terms Code | 1 | X | ID | 3/3
+/// 06 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+/// 08 | 54 | Risk of Loss Code | 1 | O | ID | 2/2
+/// 09 | 352 | Description | 1 | X | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct FOB {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+/// GE - Functional Group Trailer
+///
+/// To indicate the end of a functional group and to provide control information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 97 | Number of Transaction Sets Included | 1 | M | N0 | 1/6
+/// 02 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct GE {
+    /// 97 - Number of Transaction Sets Included
+    ///
+    /// Total number of transaction sets included in the functional group or interchange (transmission) group terminated by the trailer containing this data element
+    /// - TYPE=N0
+    /// - MIN=1
+    /// - MAX=6
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 28 - Group Control Number
+    ///
+    /// Assigned number originated and maintained by the sender
+    /// - TYPE=N0
+    /// - MIN=1
+    /// - MAX=9
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// GS - Functional Group Header
+///
+/// To indicate the beginning of a functional group and to provide control information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 479 | Functional Identifier Code | 1 | M | ID | 2/2
+/// 02 | 142 | Application Sender's Code | 1 | M | AN | 2/15
+/// 03 | 124 | Application Receiver's Code | 1 | M | AN | 2/15
+/// 04 | 373 | Date | 1 | M/Z | DT | 8/8
+/// 05 | 337 | Time | 1 | M/Z | TM | 4/8
+/// 06 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
+/// 07 | 455 | Responsible Agency Code | 1 | M | ID | 1/2
+/// 08 | 480 | Version / Release / Industry Identifier Code | 1 | M | AN | 1/12
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct GS {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    #[serde(rename = "04")]
+    pub _04: String,
+    /// 337 - Time
+    ///
+    /// Time expressed in 24-hour clock time as follows: HHMM, or HHMMSS, or HHMMSSD, or HHMMSSDD, where H = hours (00-23), M = minutes (00-59), S = integer seconds (00-59) and DD = decimal seconds; decimal seconds are expressed as follows: D = tenths (0-9) and DD = hundredths (00-99)
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=8
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: String,
+    #[serde(rename = "08")]
+    pub _08: String,
+}
+
+/// HL - Hierarchical Level
+///
+/// To identify dependencies among and the content of hierarchically related groups of data segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 628 | Hierarchical ID Number | 1 | M | AN | 1/12
+/// 02 | 734 | Hierarchical Parent ID Number | 1 | O | AN | 1/12
+/// 03 | 735 | Hierarchical Level Code | 1 | M | ID | 1/2
+/// 04 | 736 | Hierarchical Child Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct HL {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
+/// IEA - Interchange Control Trailer
+///
+/// To define the end of an interchange of zero or more functional groups and interchange-related control segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | I16 | Number of Included Functional Groups | 1 | M | N0 | 1/5
+/// 02 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct IEA {
+    /// I16 - Number of Included Functional Groups
+    ///
+    /// A count of the number of functional groups included in an interchange
+    /// - TYPE=N0
+    /// - MIN=1
+    /// - MAX=5
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// I12 - Interchange Control Number
+    ///
+    /// A control number assigned by the interchange sender
+    /// - TYPE=N0
+    /// - MIN=9
+    /// - MAX=9
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// INC - Installment Information
+///
+/// To specify installment billing arrangement
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 336 | Terms Type Code | 1 | M | ID | 2/2
+/// 02 | C001 | Composite Unit of Measure | 1 | M |  |
+/// 03 | 380 | Quantity | 1 | M | R | 1/15
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 06 | 107 | Payment Method Code | 1 | O | ID | 1/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct INC {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+}
+
+/// ISA - Interchange Control Header
+///
+/// To start and identify an interchange of zero or more functional groups and interchange-related control segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | I01 | Authorization Information Qualifier | 1 | M | ID | 2/2
+/// 02 | I02 | Authorization Information | 1 | M | AN | 10/10
+/// 03 | I03 | Security Information Qualifier | 1 | M | ID | 2/2
+/// 04 | I04 | Security Information | 1 | M | AN | 10/10
+/// 05 | I05 | Interchange ID Qualifier | 1 | M | ID | 2/2
+/// 06 | I06 | Interchange Sender ID | 1 | M | AN | 15/15
+/// 07 | I05 | Interchange ID Qualifier | 1 | M | ID | 2/2
+/// 08 | I07 | Interchange Receiver ID | 1 | M | AN | 15/15
+/// 09 | I08 | Interchange Date | 1 | M | DT | 6/6
+/// 10 | I09 | Interchange Time | 1 | M | TM | 4/4
+/// 11 | I10 | Interchange Control Standards Identifier | 1 | M | ID | 1/1
+/// 12 | I11 | Interchange Control Version Number | 1 | M | ID | 5/5
+/// 13 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+/// 14 | I13 | Acknowledgment Requested | 1 | M | ID | 1/1
+/// 15 | I14 | Usage Indicator | 1 | M | ID | 1/1
+/// 16 | I15 | Component Element Separator | 1 | M |  | 1/1
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct ISA {
+    /// I01 - Authorization Information Qualifier
+    ///
+    /// Code to identify the type of information in the Authorization Information
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[validate(length(equal = 2))]
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// I02 - Authorization Information
+    ///
+    /// Information used for additional identification or authorization of the interchange sender or the data in the interchange; the type of information is set by the Authorization Information Qualifier (I01)
+    /// - TYPE=AN
+    /// - MIN=10
+    /// - MAX=10
+    #[validate(length(equal = 10, message = "I04 must be 10 characters long"))]
+    #[serde(rename = "02")]
+    pub _02: String,
+    /// I03 - Security Information Qualifier
+    ///
+    /// Code to identify the type of information in the Security Information
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[validate(length(equal = 2))]
+    #[serde(rename = "03")]
+    pub _03: String,
+    /// I04 - Security Information
+    ///
+    /// This is used for identifying the security information about the interchange sender or the data in the interchange; the type of information is set by the Security Information Qualifier (I03)
+    /// - TYPE=AN
+    /// - MIN=10
+    /// - MAX=10
+    #[validate(length(equal = 10, message = "I04 must be 10 characters long"))]
+    #[serde(rename = "04")]
+    pub _04: String,
+    /// I05 - Interchange ID Qualifier
+    ///
+    /// Qualifier to designate the system/method of code structure used to designate the sender or receiver ID element being qualified
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[serde(rename = "05")]
+    pub _05: String,
+    /// I06 - Interchange Sender ID
+    ///
+    /// Identification code published by the sender for other parties to use as the receiver ID to route data to them; the sender always codes this value in the sender ID element
+    /// - TYPE=AN
+    /// - MIN=15
+    /// - MAX=15
+    #[serde(rename = "06")]
+    pub _06: String,
+    /// I05 - Interchange ID Qualifier
+    ///
+    /// Qualifier to designate the system/method of code structure used to designate the sender or receiver ID element being qualified
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[serde(rename = "07")]
+    pub _07: String,
+    /// I07 - Interchange Receiver ID
+    ///
+    /// Identification code published by the receiver of the data; When sending, it is used by the sender as their sending ID, thus other parties sending to them will use this as a receiving ID to route data to them
+    /// - TYPE=AN
+    /// - MIN=15
+    /// - MAX=15
+    #[serde(rename = "08")]
+    pub _08: String,
+    /// I08 - Interchange Date
+    ///
+    /// Date of the interchange
+    /// - TYPE=DT
+    /// - MIN=6
+    /// - MAX=6
+    #[serde(rename = "09")]
+    pub _09: String,
+    /// I09 - Interchange Time
+    ///
+    /// Time of the interchange
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=4
+    #[serde(rename = "10")]
+    pub _10: String,
+    /// I10 - Interchange Control Standards Identifier
+    ///
+    /// Code to identify the agency responsible for the control standard used by the message that is enclosed by the interchange header and trailer
+    /// - TYPE=ID
+    /// - MIN=1
+    /// - MAX=1
+    #[serde(rename = "11")]
+    pub _11: String,
+    /// I11 - Interchange Control Version Number
+    ///
+    /// This version number covers the interchange control segments
+    /// - TYPE=ID
+    /// - MIN=5
+    /// - MAX=5
+    #[serde(rename = "12")]
+    pub _12: String,
+    /// I12 - Interchange Control Number
+    ///
+    /// A control number assigned by the interchange sender
+    /// - TYPE=N0
+    /// - MIN=9
+    /// - MAX=9
+    #[serde(rename = "13")]
+    pub _13: String,
+    /// I13 - Acknowledgment Requested
+    ///
+    /// Code sent by the sender to request an interchange acknowledgment (TA1)
+    /// - TYPE=ID
+    /// - MIN=1
+    /// - MAX=1
+    #[serde(rename = "14")]
+    pub _14: String,
+    /// I14 - Usage Indicator
+    ///
+    /// Code to indicate whether data enclosed by this interchange envelope is test, production or information
+    /// - TYPE=ID
+    /// - MIN=1
+    /// - MAX=1
+    #[serde(rename = "15")]
+    pub _15: String,
+    /// I15 - Component Element Separator
+    ///
+    /// Type is not applicable; the component element separator is a delimiter and not a data element; this field provides the delimiter used to separate component data elements within a composite data structure; this value must be different than the data element separator and the segment terminator
+    /// - TYPE=
+    /// - MIN=1
+    /// - MAX=1
+    #[serde(rename = "16")]
+    pub _16: String,
+}
+
+/// ISS - Invoice Shipment Summary
+///
+/// To specify summary details of total items shipped in terms of quantity, weight, and volume
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | X | R | 1/10
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 03 | 81 | Weight | 1 | X | R | 1/10
+/// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 05 | 183 | Volume | 1 | X | R | 1/8
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 380 | Quantity | 1 | X | R | 1/15
+/// 08 | 81 | Weight | 1 | O | R | 1/10
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct ISS {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// IT1 - Baseline Item Data (Invoice)
+///
+/// To specify the basic and most frequently used line item data for the invoice and related transactions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 358 | Quantity Invoiced | 1 | X | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 212 | Unit Price | 1 | X | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct IT1 {
+    pub _01: Option<String>,
+    pub _02: String,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+    pub _10: Option<String>,
+    pub _11: Option<String>,
+    pub _12: Option<String>,
+    pub _13: Option<String>,
+    pub _14: Option<String>,
+    pub _15: Option<String>,
+    pub _16: Option<String>,
+    pub _17: Option<String>,
+    pub _18: Option<String>,
+    pub _19: Option<String>,
+    pub _20: Option<String>,
+    pub _21: Option<String>,
+    pub _22: Option<String>,
+    pub _23: Option<String>,
+    pub _24: Option<String>,
+    pub _25: Option<String>,
+}
+
+/// IT3 - Additional Item Data
+///
+/// To specify additional item details relating to variations between ordered and shipped quantities, or to specify alternate units of measures and quantities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 382 | Number of Units Shipped | 1 | X | R | 1/10
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 03 | 368 | Shipment/Order Status Code | 1 | X | ID | 2/2
+/// 04 | 383 | Quantity Difference | 1 | O | R | 1/9
+/// 05 | 371 | Change Reason Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct IT3 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// ITD - Terms of Sale/Deferred Terms of Sale
+///
+/// To specify terms of sale
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 336 | Terms Type Code | 1 | O | ID | 2/2
+/// 02 | 333 | Terms Basis Date Code | 1 | O | ID | 1/2
+/// 03 | 338 | Terms Discount Percent | 1 | O | R | 1/6
+/// 04 | 370 | Terms Discount Due Date | 1 | X | DT | 8/8
+/// 05 | 351 | Terms Discount Days Due | 1 | X | N0 | 1/3
+/// 06 | 446 | Terms Net Due Date | 1 | O | DT | 8/8
+/// 07 | 386 | Terms Net Days | 1 | O | N0 | 1/3
+/// 08 | 362 | Terms Discount Amount | 1 | O | N2 | 1/10
+/// 09 | 388 | Terms Deferred Due Date | 1 | O | DT | 8/8
+/// 10 | 389 | Deferred Amount Due | 1 | X | N2 | 1/10
+/// 11 | 342 | Percent of Invoice Payable | 1 | X | R | 1/5
+/// 12 | 352 | Description | 1 | O | AN | 1/80
+/// 13 | 765 | Day of Month | 1 | X | N0 | 1/2
+/// 14 | 107 | Payment Method Code | 1 | O | ID | 1/2
+/// 15 | 954 | Percent | 1 | O | R | 1/10
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct ITD {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+}
+
+/// L7 - Tariff Reference
+///
+/// To reference details of the tariff used to arrive at applicable rates or charge
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 213 | Lading Line Item Number | O |  | N0 1/3
+/// 02 | 168 | Tariff Agency Code | O |  | ID 1/4
+/// 03 | 171 | Tariff Number | O |  | AN 1/7
+/// 04 | 172 | Tariff Section | O |  | AN 1/2
+/// 05 | 169 | Tariff Item Number | O |  | AN 1/16
+/// 06 | 170 | Tariff Item Part | O |  | N0 1/2
+/// 07 | 59 | Freight Class Code | O |  | AN 2/5
+/// 08 | 173 | Tariff Supplement Identifier | O |  | AN 1/4
+/// 09 | 46 | Ex Parte | O |  | AN 4/4
+/// 10 | 373 | Date | O |  | DT 8/8
+/// 11 | 119 | Rate Basis Number | O |  | AN 1/6
+/// 12 | 227 | Tariff Column | O |  | AN 1/2
+/// 13 | 294 | Tariff Distance | O |  | N0 1/5
+/// 14 | 295 | Distance Qualifier | O |  | ID 1/1
+/// 15 | 19 | City Name | O |  | AN 2/30
+/// 16 | 156 | State or Province Code | O |  | ID 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct L7 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+}
+
+/// LIN - Item Identification
+///
+/// To specify basic item identification data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 235 | Product/Service ID Qualifier | 1 | M | ID | 2/2
+/// 03 | 234 | Product/Service ID | 1 | M | AN | 1/48
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 26 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 27 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 28 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 29 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 30 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 31 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LIN {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+    #[serde(rename = "26")]
+    pub _26: Option<String>,
+    #[serde(rename = "27")]
+    pub _27: Option<String>,
+    #[serde(rename = "28")]
+    pub _28: Option<String>,
+    #[serde(rename = "29")]
+    pub _29: Option<String>,
+    #[serde(rename = "30")]
+    pub _30: Option<String>,
+    #[serde(rename = "31")]
+    pub _31: Option<String>,
+}
+
+/// LM - Code Source Information
+///
+/// To transmit standard code list identification information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 559 | Agency Qualifier Code | 1 | M | ID | 2/2
+/// 02 | 822 | Source Subqualifier | 1 | O | AN | 1/15
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LM {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
+
+/// LQ - Industry Code
+///
+/// Code to transmit standard industry codes
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 02 | 1271 | Industry Code | 1 | X | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct LQ {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
+
+/// MAN - Marks and Numbers
+///
+/// To indicate identifying marks and numbers for shipping containers
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 88 | Marks and Numbers Qualifier | 1 | M/Z | ID | 1/2
+/// 02 | 87 | Marks and Numbers | 1 | M/Z | AN | 1/48
+/// 03 | 87 | Marks and Numbers | 1 | O | AN | 1/48
+/// 04 | 88 | Marks and Numbers Qualifier | 1 | X | ID | 1/2
+/// 05 | 87 | Marks and Numbers | 1 | X/Z | AN | 1/48
+/// 06 | 87 | Marks and Numbers | 1 | O | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct MAN {
+    pub _01: Option<String>,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+}
+
+/// MEA - Measurements
+///
+/// To specify physical measurements or counts, including dimensions, tolerances, variances, and weights (See Figures Appendix for example of use of C001)
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 737 | Measurement Reference ID Code | 1 | O | ID | 2/2
+/// 02 | 738 | Measurement Qualifier | 1 | O | ID | 1/3
+/// 03 | 739 | Measurement Value | 1 | X | R | 1/20
+/// 04 | C001 | Composite Unit of Measure | 1 | X/Z |  |
+/// 05 | 740 | Range Minimum | 1 | X | R | 1/20
+/// 06 | 741 | Range Maximum | 1 | X | R | 1/20
+/// 07 | 935 | Measurement Significance Code | 1 | O | ID | 2/2
+/// 08 | 936 | Measurement Attribute Code | 1 | X | ID | 2/2
+/// 09 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 10 | 1373 | Measurement Method or Device | 1 | O | ID | 2/4
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct MEA {
+    pub _01: Option<String>,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+    pub _10: Option<String>,
+}
+
+/// MSG - Message Text
+///
+/// To provide a free-form format that allows the transmission of text information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 933 | Free-Form Message Text | 1 | M | AN | 1/264
+/// 02 | 934 | Printer Carriage Control Code | 1 | X | ID | 2/2
+/// 03 | 1470 | Number | 1 | O | N0 | 1/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct MSG {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+/// N1 - Name
+///
+/// To identify a party by type of organization, name, and code
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 93 | Name | 1 | X | AN | 1/60
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 706 | Entity Relationship Code | 1 | O | ID | 2/2
+/// 06 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct N1 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 93 - Name
+    ///
+    /// Free-form name
+    /// - TYPE=AN
+    /// - MIN=1
+    /// - MAX=60
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+}
+
+/// N2 - Additional Name Information
+///
+/// To specify additional names or those longer than 35 characters in length
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 93 | Name | 1 | M | AN | 1/60
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct N2 {
+    /// 93 - Name
+    ///
+    /// Free-form name
+    /// - TYPE=AN
+    /// - MIN=1
+    /// - MAX=60
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 93 - Name
+    ///
+    /// Free-form name
+    /// - TYPE=AN
+    /// - MIN=1
+    /// - MAX=60
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
+
+/// N3 - Address Information
+///
+/// To specify the location of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 166 | Address Information | 1 | M | AN | 1/55
+/// 02 | 166 | Address Information | 1 | O | AN | 1/55
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct N3 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+}
+
+/// N4 - Geographic Location
+///
+/// To specify the geographic place of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 19 | City Name | 1 | O | AN | 2/30
+/// 02 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 03 | 116 | Postal Code | 1 | O | ID | 3/15
+/// 04 | 26 | Country Code | 1 | O | ID | 2/3
+/// 05 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 06 | 310 | Location Identifier | 1 | O | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct N4 {
+    /// 19 - City Name
+    ///
+    /// Free-form text for city name
+    /// - TYPE=AN
+    /// - MIN=2
+    /// - MAX=30
+    pub _01: Option<String>,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    /// 26 - Country Code
+    ///
+    /// Code identifying the country
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=3
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+}
+
+/// N9 - Reference Identification
+/// To transmit identifying information as specified by the Reference Identification Qualifier
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 03 | 369 | Free-form Description | 1 | X | AN | 1/45
+/// 04 | 373 | Date | 1 | O | DT | 8/8
+/// 05 | 337 | Time | 1 | X | TM | 4/8
+/// 06 | 623 | Time Code | 1 | O/Z | ID | 2/2
+/// 07 | C040 | Reference Identifier | 1 | O/Z
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct N9 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _03: Option<String>,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    #[serde(rename = "04")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _04: Option<String>,
+    /// 337 - Time
+    ///
+    /// Time expressed in 24-hour clock time as follows: HHMM, or HHMMSS, or HHMMSSD, or HHMMSSDD, where H = hours (00-23), M = minutes (00-59), S = integer seconds (00-59) and DD = decimal seconds; decimal seconds are expressed as follows: D = tenths (0-9) and DD = hundredths (00-99)
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=8
+    #[serde(rename = "05")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _05: Option<String>,
+    /// 623 - Time Code
+    ///
+    /// Code identifying the time. In accordance with International Standards Organization standard 8601, time can be specified by a + or - and an indication in hours in relation to Universal Time Coordinate (UTC) time; since + is a restricted character, + and - are substituted by P and M in the codes that follow
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=2
+    #[serde(rename = "06")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _07: Option<String>,
+}
+
+/// NTE - Note/Special Instruction
+///
+/// To transmit information in a free-form format, if necessary, for comment or special instruction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 363 | Note Reference Code | 1 | O | ID | 3/3
+/// 02 | 352 | Description | 1 | M | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct NTE {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// PAM - Period Amount
+///
+/// To indicate a quantity, and/or amount for an identified period
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 673 | Quantity Qualifier | 1 | X | ID | 2/2
+/// 02 | 380 | Quantity | 1 | X | R | 1/15
+/// 03 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 04 | 344 | Unit of Time Period or Interval | 1 | X | ID | 2/2
+/// 05 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 06 | 373 | Date | 1 | X | DT | 8/8
+/// 07 | 337 | Time | 1 | O | TM | 4/8
+/// 08 | 373 | Date | 1 | X | DT | 8/8
+/// 09 | 337 | Time | 1 | O | TM | 4/8
+/// 10 | 522 | Amount Qualifier Code | 1 | X | ID | 1/3
+/// 11 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 12 | 344 | Unit of Time Period or Interval | 1 | X | ID | 2/2
+/// 13 | 1345 | Percent Qualifier | 1 | X | ID | 1/2
+/// 14 | 954 | Percent | 1 | X | R | 1/10
+/// 15 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PAM {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+}
+
+/// PER - Administrative Communications Contact
+///
+/// To identify a person or office to whom administrative communications should be directed
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 366 | Contact Function Code | 1 | M | ID | 2/2
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+/// 03 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 04 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 05 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 06 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 07 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 08 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 09 | 443 | Contact Inquiry Reference | 1 | O | AN | 1/20
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PER {
+    pub _01: String,
+    /// 93 - Name
+    ///
+    /// Free-form name
+    /// - TYPE=AN
+    /// - MIN=1
+    /// - MAX=60
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+}
+
+/// PID - Product/Item Description
+///
+/// To describe a product or process in coded or free-form format
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 349 | Item Description Type | 1 | M | ID | 1/1
+/// 02 | 750 | Product/Process Characteristic Code | 1 | O | ID | 2/3
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 751 | Product Description Code | 1 | X | AN | 1/12
+/// 05 | 352 | Description | 1 | X | AN | 1/80
+/// 06 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 07 | 822 | Source Subqualifier | 1 | O | AN | 1/15
+/// 08 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 09 | 819 | Language Code | 1 | O | ID | 2/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PID {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
+
+/// PKG - Marking, Packaging, Loading
+///
+/// To describe marking, packaging, loading, and unloading requirements
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 349 | Item Description Type | 1 | X | ID | 1/1
+/// 02 | 753 | Packaging Characteristic Code | 1 | O | ID | 1/5
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 754 | Packaging Description Code | 1 | X | AN | 1/7
+/// 05 | 352 | Description | 1 | X | AN | 1/80
+/// 06 | 400 | Unit Load Option Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PKG {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+}
+
+/// PO1 - Baseline Item Data
+///
+/// To specify basic and most frequently used line item data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 330 | Quantity Ordered | 1 | X | R | 1/15
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 04 | 212 | Unit Price | 1 | X | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PO1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+}
+
+/// PO3 - Additional Item Detail
+///
+/// To specify additional item-related data involving variations in normal price/quantity structure
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 371 | Change Reason Code | 1 | M | ID | 2/2
+/// 02 | 373 | Date | 1 | O | DT | 8/8
+/// 03 | 236 | Price Identifier Code | 1 | X | ID | 3/3
+/// 04 | 212 | Unit Price | 1 | O | R | 1/17
+/// 05 | 639 | Basis of Unit Price Code | 1 | X | ID | 2/2
+/// 06 | 380 | Quantity | 1 | M | R | 1/15
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 08 | 352 | Description | 1 | O | AN | 1/80
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PO3 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: String,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// PO4 Item Physical Details
+///
+/// To specify the physical qualities, packaging, weights, and dimensions relating to the item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 356 | Pack | 1 | O | N0 | 1/6
+/// 02 | 357 | Size | 1 | X | R | 1/8
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 103 | Packaging Code | 1 | X | AN | 3/5
+/// 05 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 06 | 384 | Gross Weight per Pack | 1 | X | R | 1/9
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 08 | 385 | Gross Volume per Pack | 1 | X | R | 1/9
+/// 09 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 10 | 82 | Length | 1 | X | R | 1/8
+/// 11 | 189 | Width | 1 | X | R | 1/8
+/// 12 | 65 | Height | 1 | X | R | 1/8
+/// 13 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 14 | 810 | Inner Pack | 1 | O | N0 | 1/6
+/// 15 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 16 | 350 | Assigned Identification | 1 | X | AN | 1/20
+/// 17 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 18 | 1470 | Number | 1 | O | N0 | 1/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PO4 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+}
+
+/// PRF - Purchase Order Reference
+///
+/// To provide reference to a specific purchase order
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 324 | Purchase Order Number | 1 | M | AN | 1/22
+/// 02 | 328 | Release Number | 1 | O | AN | 1/30
+/// 03 | 327 | Change Order Sequence Number | 1 | O | AN | 1/8
+/// 04 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 05 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 06 | 367 | Contract Number | 1 | O | AN | 1/30
+/// 07 | 92 | Purchase Order Type Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PRF {
+    pub _01: String,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    /// 373 - Date
+    ///
+    /// Date expressed as CCYYMMDD where CC represents the first two digits of the calendar year
+    /// - TYPE=DT
+    /// - MIN=8
+    /// - MAX=8
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+}
+
+/// PWK - Paperwork
+///
+/// To identify the type or transmission or both of paperwork or supporting information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 755 | Report Type Code | M |  | ID 2/2
+/// 02 | 756 | Report Transmission Code | O |  | ID 1/2
+/// 03 | 757 | Report Copies Needed | O |  | N0 1/2
+/// 04 | 98 | Entity Identifier Code | O |  | ID 2/3
+/// 05 | 66 | Identification Code Qualifier | X |  | ID 1/2
+/// 06 | 67 | Identification Code | X |  | AN 2/80
+/// 07 | 352 | Description | O |  | AN 1/80
+/// 08 | C002 | Actions Indicated | O |  |
+/// 09 | 1525 | Request Category Code | O |  | ID 1/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct PWK {
+    pub _01: String,
+    pub _02: Option<String>,
+    pub _03: Option<String>,
+    pub _04: Option<String>,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+}
+
+/// QTY - Quantity
+///
+/// To specify quantity information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 673 | Quantity Qualifier | M |  | ID 2/2
+/// 02 | 380 | Quantity | X |  | R 1/15
+/// 03 | C001 | Composite Unit of Measure | O |  |
+/// 04 | 61 | Free-Form Message | X |  | AN 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct QTY {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
+/// R4 - Port or Terminal
+///
+/// Contractual or operational port or point relevant to the movement of the cargo
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 115 | Port or Terminal Function Code | 1 | M | ID | 1/1
+/// 02 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 03 | 310 | Location Identifier | 1 | X | AN | 1/30
+/// 04 | 114 | Port Name | 1 | O | AN | 2/24
+/// 05 | 26 | Country Code | 1 | O | ID | 2/3
+/// 06 | 174 | Terminal Name | 1 | O | AN | 2/30
+/// 07 | 113 | Pier Number | 1 | O | AN | 1/4
+/// 08 | 156 | State or Province Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct R4 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _04: Option<String>,
+    /// 26 - Country Code
+    ///
+    /// Code identifying the country
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=3
+    #[serde(rename = "05")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _08: Option<String>,
+}
+
+/// REF - Reference Identification
+///
+/// To specify identifying information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 03 | 352 | Description | 1 | X | AN | 1/80
+/// 04 | C040 | Reference Identifier | 1 | O/Z
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct REF {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
+/// SAC - Service, Promotion, Allowance, or Charge Information
+///
+/// To request or identify a service, promotion, allowance, or charge; to specify the amount or percentage for the service, promotion, allowance, or charge
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 248 | Allowance or Charge Indicator | 1 | M | ID | 1/1
+/// 02 | 1300 | Service, Promotion, Allowance, or Charge Code | 1 | X | ID | 4/4
+/// 03 | 559 | Agency Qualifier Code | 1 | X | ID | 2/2
+/// 04 | 1301 | Agency Service, Promotion, Allowance, or Charge Code | 1 | X | AN | 1/10
+/// 05 | 610 | Amount | 1 | O | N2 | 1/15
+/// 06 | 378 | Allowance/Charge Percent Qualifier | 1 | X | ID | 1/1
+/// 07 | 332 | Percent | 1 | X | R | 1/6
+/// 08 | 118 | Rate | 1 | O | R | 1/9
+/// 09 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 10 | 380 | Quantity | 1 | X | R | 1/15
+/// 11 | 380 | Quantity | 1 | O | R | 1/15
+/// 12 | 331 | Allowance or Charge Method of Handling Code | 1 | O | ID | 2/2
+/// 13 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 14 | 770 | Option Number | 1 | O | AN | 1/20
+/// 15 | 352 | Description | 1 | X | AN | 1/80
+/// 16 | 819 | Language Code | 1 | O | ID | 2/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SAC {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+}
+
+/// SCH - Line Item Schedule
+///
+/// To specify the data for scheduling a specific line-item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | M | R | 1/15
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 04 | 93 | Name | 1 | X | AN | 1/60
+/// 05 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 06 | 373 | Date | 1 | M | DT | 8/8
+/// 07 | 337 | Time | 1 | X | TM | 4/8
+/// 08 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 09 | 373 | Date | 1 | X | DT | 8/8
+/// 10 | 337 | Time | 1 | X | TM | 4/8
+/// 11 | 326 | Request Reference Number | 1 | O | AN | 1/45
+/// 12 | 350 | Assigned Identification | 1 | O | AN | 1/20
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SCH {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: String,
+    #[serde(rename = "06")]
+    pub _06: String,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+}
+
+/// SDQ - Destination Quantity
+///
+/// To specify destination and quantity detail
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 02 | 66 | Identification Code Qualifier | 1 | O | ID | 1/2
+/// 03 | 67 | Identification Code | 1 | M | AN | 2/80
+/// 04 | 380 | Quantity | 1 | M | R | 1/15
+/// 05 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 06 | 380 | Quantity | 1 | X | R | 1/15
+/// 07 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 08 | 380 | Quantity | 1 | X | R | 1/15
+/// 09 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 10 | 380 | Quantity | 1 | X | R | 1/15
+/// 11 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 12 | 380 | Quantity | 1 | X | R | 1/15
+/// 13 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 14 | 380 | Quantity | 1 | X | R | 1/15
+/// 15 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 16 | 380 | Quantity | 1 | X | R | 1/15
+/// 17 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 18 | 380 | Quantity | 1 | X | R | 1/15
+/// 19 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 20 | 380 | Quantity | 1 | X | R | 1/15
+/// 21 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 22 | 380 | Quantity | 1 | X | R | 1/15
+/// 23 | 310 | Location Identifier | 1 | O/Z | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SDQ {
+    pub _01: String,
+    pub _02: Option<String>,
+    pub _03: String,
+    pub _04: String,
+    pub _05: Option<String>,
+    pub _06: Option<String>,
+    pub _07: Option<String>,
+    pub _08: Option<String>,
+    pub _09: Option<String>,
+    pub _10: Option<String>,
+    pub _11: Option<String>,
+    pub _12: Option<String>,
+    pub _13: Option<String>,
+    pub _14: Option<String>,
+    pub _15: Option<String>,
+    pub _16: Option<String>,
+    pub _17: Option<String>,
+    pub _18: Option<String>,
+    pub _19: Option<String>,
+    pub _20: Option<String>,
+    pub _21: Option<String>,
+    pub _22: Option<String>,
+    pub _23: Option<String>,
+}
+
+/// SE - Transaction Set Trailer
+///
+/// To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments)
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
+/// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SE {
+    /// 96 - Number of Included Segments
+    ///
+    /// Total number of segments included in a transaction set including ST and SE segments
+    /// - TYPE=N0
+    /// - MIN=1
+    /// - MAX=10
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 329 - Transaction Set Control Number
+    ///
+    /// Identifying control number that must be unique within the transaction set functional group assigned by the originator for a transaction set
+    /// - TYPE=AN
+    /// - MIN=4
+    /// - MAX=9
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// SLN - Subline Item Detail
+///
+/// To specify product subline detail item data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | M | AN | 1/20
+/// 02 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 03 | 662 | Relationship Code | 1 | M | ID | 1/1
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 212 | Unit Price | 1 | X | R | 1/17
+/// 07 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 08 | 662 | Relationship Code | 1 | O | ID | 1/1
+/// 09 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 10 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 11 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 12 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 13 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 14 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 15 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 16 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 17 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 18 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 19 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 20 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 21 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 22 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 23 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 24 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 25 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 26 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 27 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 28 | 234 | Product/Service ID | 1 | X | AN | 1/48
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SLN {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+    #[serde(rename = "18")]
+    pub _18: Option<String>,
+    #[serde(rename = "19")]
+    pub _19: Option<String>,
+    #[serde(rename = "20")]
+    pub _20: Option<String>,
+    #[serde(rename = "21")]
+    pub _21: Option<String>,
+    #[serde(rename = "22")]
+    pub _22: Option<String>,
+    #[serde(rename = "23")]
+    pub _23: Option<String>,
+    #[serde(rename = "24")]
+    pub _24: Option<String>,
+    #[serde(rename = "25")]
+    pub _25: Option<String>,
+    #[serde(rename = "26")]
+    pub _26: Option<String>,
+    #[serde(rename = "27")]
+    pub _27: Option<String>,
+    #[serde(rename = "28")]
+    pub _28: Option<String>,
+}
+
+/// SN1 - Item Detail (Shipment)
+///
+/// To specify line-item detail relative to shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 382 | Number of Units Shipped | 1 | M | R | 1/10
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 04 | 646 | Quantity Shipped to Date | 1 | O | R | 1/15
+/// 05 | 330 | Quantity Ordered | 1 | X | R | 1/15
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 728 | Returnable Container Load Make-Up Code | 1 | O | ID | 1/2
+/// 08 | 668 | Line Item Status Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SN1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// SR - Requested Service Schedule
+///
+/// To identify requested service schedules
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct SR {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+}
+
+/// ST - Transaction Set Header
+///
+/// To indicate the start of a transaction set and to assign a control number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 143 | Transaction Set Identifier Code | 1 | M/Z | ID | 3/3
+/// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct ST {
+    /// 143 - Transaction Set Identifier Code 3/3
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// 329 - Transaction Set Control Number 4/9
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// TC2 - Commodity
+///
+/// To identify a commodity or a group of commodities or a tariff page commodity
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 23 | Commodity Code Qualifier | 1 | M | ID | 1/1
+/// 02 | 22 | Commodity Code | 1 | M | AN | 1/30
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TC2 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+}
+
+/// TD1 - Carrier Details (Quantity and Weight)
+///
+/// To specify the transportation details relative to commodity, weight, and quantity
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 103 | Packaging Code | 1 | O | AN | 3/5
+/// 02 | 80 | Lading Quantity | 1 | X | N0 | 1/7
+/// 03 | 23 | Commodity Code Qualifier | 1 | O | ID | 1/1
+/// 04 | 22 | Commodity Code | 1 | X | AN | 1/30
+/// 05 | 79 | Lading Description | 1 | O | AN | 1/50
+/// 06 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 07 | 81 | Weight | 1 | X | R | 1/10
+/// 08 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 09 | 183 | Volume | 1 | X | R | 1/8
+/// 10 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TD1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
+
+/// TD3 - Carrier Details (Equipment)
+///
+/// To specify transportation details relating to the equipment used by the carrier
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 40 | Equipment Description Code | 1 | X | ID | 2/2
+/// 02 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 03 | 207 | Equipment Number | 1 | X | AN | 1/10
+/// 04 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 05 | 81 | Weight | 1 | X | R | 1/10
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 07 | 225 | Seal Number | 1 | O | AN | 2/15
+/// 08 | 24 | Equipment Type | 1 | X | ID | 4/4
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TD3 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+/// TD4 - Carrier Details (Special Handling, or Hazardous Materials, or Both)
+///
+/// To specify transportation special handling requirements, or hazardous materials information, or both
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 152 | Special Handling Code | 1 | X | ID | 2/3
+/// 02 | 208 | Hazardous Material Code Qualifier | 1 | X | ID | 1/1
+/// 03 | 209 | Hazardous Material Class Code | 1 | X | AN | 1/4
+/// 04 | 352 | Description | 1 | X | AN | 1/80
+/// 05 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TD4 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
+/// TD5 - Carrier Details (Routing Sequence/Transit Time)
+///
+/// To specify the carrier and sequence of routing and provide transit time information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 133 | Routing Sequence Code | 1 | O | ID | 1/2
+/// 02 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 03 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 04 | 91 | Transportation Method/Type Code | 1 | X | ID | 1/2
+/// 05 | 387 | Routing | 1 | X | AN | 1/35
+/// 06 | 368 | Shipment/Order Status Code | 1 | X | ID | 2/2
+/// 07 | 309 | Location Qualifier | 1 | O | ID | 1/2
+/// 08 | 310 | Location Identifier | 1 | X | AN | 1/30
+/// 09 | 731 | Transit Direction Code | 1 | O | ID | 2/2
+/// 10 | 732 | Transit Time Direction Qualifier | 1 | O | ID | 2/2
+/// 11 | 733 | Transit Time | 1 | X | R | 1/4
+/// 12 | 284 | Service Level Code | 1 | X | ID | 2/2
+/// 13 | 284 | Service Level Code | 1 | O | ID | 2/2
+/// 14 | 284 | Service Level Code | 1 | O | ID | 2/2
+/// 15 | 26 | Country Code | 1 | O | ID | 2/3
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TD5 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+}
+
+/// TDS - Total Monetary Value Summary
+///
+/// To specify the total invoice discounts and amounts
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 610 | Amount | 1 | M | N2 | 1/15
+/// 02 | 610 | Amount | 1 | O | N2 | 1/15
+/// 03 | 610 | Amount | 1 | O | N2 | 1/15
+/// 04 | 610 | Amount | 1 | O | N2 | 1/15
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TDS {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
+/// TXI - Tax Information
+///
+/// To specify tax information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 963 | Tax Type Code | 1 | M | ID | 2/2
+/// 02 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 03 | 954 | Percent | 1 | X | R | 1/10
+/// 04 | 955 | Tax Jurisdiction Code Qualifier | 1 | X | ID | 2/2
+/// 05 | 956 | Tax Jurisdiction Code | 1 | X | AN | 1/10
+/// 06 | 441 | Tax Exempt Code | 1 | X | ID | 1/1
+/// 07 | 662 | Relationship Code | 1 | O | ID | 1/1
+/// 08 | 828 | Dollar Basis For Percent | 1 | O | R | 1/9
+/// 09 | 325 | Tax Identification Number | 1 | O | AN | 1/20
+/// 10 | 350 | Assigned Identification | 1 | O | AN | 1/20
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct TXI {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+}
+
+/// V1 - Vessel Identification
+///
+/// To provide vessel details and voyage number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | 597 | Vessel Code | 1 | X | ID | 1/8
+/// 02 | 182 | Vessel Name | 1 | X | AN | 2/28
+/// 03 | 26 | Country Code | 1 | O/Z | ID | 2/3
+/// 04 | 55 | Flight/Voyage Number | 1 | O | AN | 2/10
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 06 | 249 | Vessel Requirement Code | 1 | O | ID | 1/1
+/// 07 | 854 | Vessel Type Code | 1 | O | ID | 2/2
+/// 08 | 897 | Vessel Code Qualifier | 1 | O | ID | 1/1
+/// 09 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct V1 {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    /// 26 - Country Code
+    ///
+    /// Code identifying the country
+    /// - TYPE=ID
+    /// - MIN=2
+    /// - MAX=3
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+/// YNQ - Yes/No Question
+///
+/// To identify and answer yes and no questions, including the date, time, and comments further qualifying the condition
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1321 | Condition Indicator | 1 | X | ID | 2/3
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 03 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 04 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 05 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 06 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 07 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+/// 08 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 09 | 1271 | Industry Code | 1 | X | AN | 1/30
+/// 10 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
+#[derive(
+    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+)]
+pub struct YNQ {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
@@ -0,0 +1,1286 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    AMT,
+    "Monetary Amount Information",
+    "To indicate the total monetary amount",
+    [_01, _02, _03],
+    [
+        (1, "522", "Amount Qualifier Code", Mandatory, Id, 1, 3, amount_qualifier_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "478", "Credit/Debit Flag Code", Optional, Id, 1, 1, credit_debit_flag_code),
+    ]
+}
+
+segment_meta! {
+    BAL,
+    "Balance Detail",
+    "To identify the specific monetary balances associated with a particular account",
+    [_01, _02, _03],
+    [
+        (1, "951", "Balance Type Code", Mandatory, Id, 1, 2, balance_type_code),
+        (2, "522", "Amount Qualifier Code", Mandatory, Id, 1, 3, amount_qualifier_code),
+        (3, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+    ]
+}
+
+segment_meta! {
+    BEG,
+    "Beginning Segment for Purchase Order",
+    "To indicate the beginning of the Purchase Order Transaction Set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "92", "Purchase Order Type Code", Mandatory, Id, 2, 2, purchase_order_type_code),
+        (3, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (4, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (5, "373", "Date", Mandatory, Date, 8, 8, date),
+        (6, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (7, "587", "Acknowledgment Type", Optional, Id, 2, 2, acknowledgment_type),
+        (8, "1019", "Invoice Type Code", Optional, Id, 3, 3, invoice_type_code),
+        (9, "1166", "Contract Type Code", Optional, Id, 2, 2, contract_type_code),
+        (10, "1232", "Purchase Category", Optional, Id, 2, 2, purchase_category),
+        (11, "786", "Security Level Code", Optional, Id, 2, 2, security_level_code),
+        (12, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+    ]
+}
+
+segment_meta! {
+    BIG,
+    "Beginning Segment for Invoice",
+    "To indicate the beginning of an invoice transaction set and transmit identifying numbers and dates",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "373", "Date", Mandatory, Date, 8, 8, date_01),
+        (2, "76", "Invoice Number", Mandatory, AlphaNumeric, 1, 22, invoice_number_02),
+        (3, "373", "Date", Optional, Date, 8, 8, date_03),
+        (4, "324", "Purchase Order Number", Optional, AlphaNumeric, 1, 22, purchase_order_number),
+        (5, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (6, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (7, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (8, "353", "Transaction Set Purpose Code", Optional, Id, 2, 2, transaction_set_purpose_code),
+        (9, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (10, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number_10),
+        (11, "1019", "Invoice Type Code", Optional, Id, 1, 3, invoice_type_code),
+    ]
+}
+
+segment_meta! {
+    BSN,
+    "Beginning Segment for Ship Notice",
+    "To transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "396", "Shipment Identification", Mandatory, AlphaNumeric, 2, 30, shipment_identification),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "337", "Time", Mandatory, Time, 4, 8, time),
+        (5, "1005", "Hierarchical Structure Code", Optional, Id, 4, 4, hierarchical_structure_code),
+        (6, "640", "Transaction Type Code", Conditional, Id, 2, 2, transaction_type_code),
+        (7, "641", "Status Reason Code", Optional, Id, 3, 3, status_reason_code),
+    ]
+}
+
+segment_meta! {
+    CAD,
+    "Carrier Details",
+    "To specify transportation details for the transaction",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Optional, AlphaNumeric, 1, 10, equipment_number),
+        (4, "140", "Standard Carrier Alpha Code", Conditional, Id, 2, 4, standard_carrier_alpha_code),
+        (5, "387", "Routing", Conditional, AlphaNumeric, 1, 35, routing),
+        (6, "368", "Shipment/Order Status Code", Optional, Id, 2, 2, shipment_order_status_code),
+        (7, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (8, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (9, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code),
+    ]
+}
+
+segment_meta! {
+    CLD,
+    "Load Detail",
+    "To specify the number of material loads shipped",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "622", "Number of Loads", Mandatory, Numeric(0), 1, 5, number_of_loads),
+        (2, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (3, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (4, "357", "Size", Optional, Decimal, 1, 8, size),
+        (5, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+    ]
+}
+
+segment_meta! {
+    CRC,
+    "Conditions Indicator",
+    "To supply information on conditions",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1136", "Code Category", Mandatory, Id, 2, 2, code_category),
+        (2, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "1321", "Condition Indicator", Mandatory, Id, 2, 3, condition_indicator_03),
+        (4, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_04),
+        (5, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_05),
+        (6, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_06),
+        (7, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_07),
+    ]
+}
+
+segment_meta! {
+    CTP,
+    "Pricing Information",
+    "To specify pricing information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "687", "Class of Trade Code", Optional, Id, 2, 2, class_of_trade_code),
+        (2, "236", "Price Identifier Code", Conditional, Id, 3, 3, price_identifier_code),
+        (3, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "648", "Price Multiplier Qualifier", Conditional, Id, 3, 3, price_multiplier_qualifier),
+        (7, "649", "Multiplier", Conditional, Decimal, 1, 10, multiplier),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (9, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (10, "499", "Condition Value", Optional, AlphaNumeric, 1, 10, condition_value),
+        (11, "289", "Multiple Price Quantity", Optional, Numeric(0), 1, 2, multiple_price_quantity),
+    ]
+}
+
+segment_meta! {
+    CTT,
+    "Transaction Totals",
+    "To transmit a hash total for a specific element in the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "354", "Number of Line Items", Mandatory, Numeric(0), 1, 6, number_of_line_items),
+        (2, "347", "Hash Total", Optional, Decimal, 1, 10, hash_total),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_04),
+        (5, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    CUR,
+    "Currency",
+    "To specify the currency (dollars, pounds, francs, etc.) used in a transaction",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code_02),
+        (3, "280", "Exchange Rate", Optional, Decimal, 4, 10, exchange_rate),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_04),
+        (5, "100", "Currency Code", Conditional, Id, 3, 3, currency_code_05),
+        (6, "669", "Currency Market/Exchange Code", Optional, Id, 3, 3, currency_market_exchange_code),
+        (7, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_07),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "337", "Time", Optional, Time, 4, 8, time_09),
+        (10, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_10),
+        (11, "373", "Date", Conditional, Date, 8, 8, date_11),
+        (12, "337", "Time", Conditional, Time, 4, 8, time_12),
+        (13, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_13),
+        (14, "373", "Date", Conditional, Date, 8, 8, date_14),
+        (15, "337", "Time", Conditional, Time, 4, 8, time_15),
+        (16, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_16),
+        (17, "373", "Date", Conditional, Date, 8, 8, date_17),
+        (18, "337", "Time", Conditional, Time, 4, 8, time_18),
+        (19, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_19),
+        (20, "373", "Date", Conditional, Date, 8, 8, date_20),
+        (21, "337", "Time", Conditional, Time, 4, 8, time_21),
+    ]
+}
+
+segment_meta! {
+    DMG,
+    "Demographic Information",
+    "To supply demographic information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (2, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (3, "1068", "Gender Code", Optional, Id, 1, 1, gender_code),
+        (4, "1067", "Marital Status Code", Optional, Id, 1, 1, marital_status_code),
+        (5, "1109", "Race or Ethnicity Code", Optional, Id, 1, 1, race_or_ethnicity_code),
+        (6, "1066", "Citizenship Status Code", Optional, Id, 1, 2, citizenship_status_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "659", "Basis of Verification Code", Optional, Id, 1, 2, basis_of_verification_code),
+        (9, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+    ]
+}
+
+segment_meta! {
+    DTM,
+    "Date/Time Reference",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (5, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (6, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+    ]
+}
+
+segment_meta! {
+    FA1,
+    "Type of Financial Accounting Data",
+    "To specify the organization controlling the content of the accounting citation, and the purpose associated with the accounting citation",
+    [_01, _02, _03],
+    [
+        (1, "559", "Agency Qualifier Code", Mandatory, Id, 2, 2, agency_qualifier_code),
+        (2, "1301", "Service, Promotion, Allowance, or Charge Code", Optional, Id, 4, 4, service_promotion_allowance_or_charge_code),
+        (3, "1212", "Allowance or Charge Indicator Code", Optional, Id, 1, 1, allowance_or_charge_indicator_code),
+    ]
+}
+
+segment_meta! {
+    FA2,
+    "Accounting Data",
+    "To specify the detailed accounting data",
+    [_01, _02],
+    [
+        (1, "1196", "Breakdown Structure Detail Code", Mandatory, Id, 2, 2, breakdown_structure_detail_code),
+        (2, "1195", "Financial Information Code", Mandatory, AlphaNumeric, 1, 80, financial_information_code),
+    ]
+}
+
+segment_meta! {
+    FOB,
+    "F.O.B. Related Instructions",
+    "To specify transportation instructions relating to shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (2, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier_02),
+        (3, "352", "Description", Optional, AlphaNumeric, 1, 80, description_03),
+        (4, "334", "Transportation Terms Qualifier Code", Optional, Id, 2, 2, transportation_terms_qualifier_code),
+        (5, "335", "Transportation Terms Code", Conditional, Id, 3, 3, transportation_terms_code),
+        (6, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier_06),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description_07),
+        (8, "54", "Risk of Loss Code", Optional, Id, 2, 2, risk_of_loss_code),
+        (9, "352", "Description", Conditional, AlphaNumeric, 1, 80, description_09),
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "Functional Group Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 8, 8, date),
+        (5, "337", "Time", Mandatory, Time, 4, 8, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    HL,
+    "Hierarchical Level",
+    "To identify dependencies among and the content of hierarchically related groups of data segments",
+    [_01, _02, _03, _04],
+    [
+        (1, "628", "Hierarchical ID Number", Mandatory, AlphaNumeric, 1, 12, hierarchical_id_number),
+        (2, "734", "Hierarchical Parent ID Number", Optional, AlphaNumeric, 1, 12, hierarchical_parent_id_number),
+        (3, "735", "Hierarchical Level Code", Mandatory, Id, 1, 2, hierarchical_level_code),
+        (4, "736", "Hierarchical Child Code", Optional, Id, 1, 1, hierarchical_child_code),
+    ]
+}
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer",
+    "To define the end of an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    INC,
+    "Installment Information",
+    "To specify installment billing arrangement",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "336", "Terms Type Code", Mandatory, Id, 2, 2, terms_type_code),
+        (2, "C001", "Composite Unit of Measure", Mandatory, Composite, 0, 0, composite_unit_of_measure),
+        (3, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_03),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_04),
+        (5, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (6, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header",
+    "To start and identify an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I10", "Interchange Control Standards Identifier", Mandatory, Id, 1, 1, interchange_control_standards_identifier),
+        (12, "I11", "Interchange Control Version Number", Mandatory, Id, 5, 5, interchange_control_version_number),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Usage Indicator", Mandatory, Id, 1, 1, usage_indicator),
+        (16, "I15", "Component Element Separator", Mandatory, Composite, 1, 1, component_element_separator),
+    ]
+}
+
+segment_meta! {
+    ISS,
+    "Invoice Shipment Summary",
+    "To specify summary details of total items shipped in terms of quantity, weight, and volume",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (2, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_02),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight_03),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_04),
+        (5, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (8, "81", "Weight", Optional, Decimal, 1, 10, weight_08),
+    ]
+}
+
+segment_meta! {
+    IT1,
+    "Baseline Item Data (Invoice)",
+    "To specify the basic and most frequently used line item data for the invoice and related transactions",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "358", "Quantity Invoiced", Conditional, Decimal, 1, 10, quantity_invoiced),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+    ]
+}
+
+segment_meta! {
+    IT3,
+    "Additional Item Data",
+    "To specify additional item details relating to variations between ordered and shipped quantities, or to specify alternate units of measures and quantities",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "382", "Number of Units Shipped", Conditional, Decimal, 1, 10, number_of_units_shipped),
+        (2, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "368", "Shipment/Order Status Code", Conditional, Id, 2, 2, shipment_order_status_code),
+        (4, "383", "Quantity Difference", Optional, Decimal, 1, 9, quantity_difference),
+        (5, "371", "Change Reason Code", Optional, Id, 2, 2, change_reason_code),
+    ]
+}
+
+segment_meta! {
+    ITD,
+    "Terms of Sale/Deferred Terms of Sale",
+    "To specify terms of sale",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "336", "Terms Type Code", Optional, Id, 2, 2, terms_type_code),
+        (2, "333", "Terms Basis Date Code", Optional, Id, 1, 2, terms_basis_date_code),
+        (3, "338", "Terms Discount Percent", Optional, Decimal, 1, 6, terms_discount_percent),
+        (4, "370", "Terms Discount Due Date", Conditional, Date, 8, 8, terms_discount_due_date),
+        (5, "351", "Terms Discount Days Due", Conditional, Numeric(0), 1, 3, terms_discount_days_due),
+        (6, "446", "Terms Net Due Date", Optional, Date, 8, 8, terms_net_due_date),
+        (7, "386", "Terms Net Days", Optional, Numeric(0), 1, 3, terms_net_days),
+        (8, "362", "Terms Discount Amount", Optional, Numeric(2), 1, 10, terms_discount_amount),
+        (9, "388", "Terms Deferred Due Date", Optional, Date, 8, 8, terms_deferred_due_date),
+        (10, "389", "Deferred Amount Due", Conditional, Numeric(2), 1, 10, deferred_amount_due),
+        (11, "342", "Percent of Invoice Payable", Conditional, Decimal, 1, 5, percent_of_invoice_payable),
+        (12, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (13, "765", "Day of Month", Conditional, Numeric(0), 1, 2, day_of_month),
+        (14, "107", "Payment Method Code", Optional, Id, 1, 2, payment_method_code),
+        (15, "954", "Percent", Optional, Decimal, 1, 10, percent),
+    ]
+}
+
+segment_meta! {
+    L7,
+    "Tariff Reference",
+    "To reference details of the tariff used to arrive at applicable rates or charge",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "168", "Tariff Agency Code", Optional, Id, 1, 4, tariff_agency_code),
+        (3, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (4, "172", "Tariff Section", Optional, AlphaNumeric, 1, 2, tariff_section),
+        (5, "169", "Tariff Item Number", Optional, AlphaNumeric, 1, 16, tariff_item_number),
+        (6, "170", "Tariff Item Part", Optional, Numeric(0), 1, 2, tariff_item_part),
+        (7, "59", "Freight Class Code", Optional, AlphaNumeric, 2, 5, freight_class_code),
+        (8, "173", "Tariff Supplement Identifier", Optional, AlphaNumeric, 1, 4, tariff_supplement_identifier),
+        (9, "46", "Ex Parte", Optional, AlphaNumeric, 4, 4, ex_parte),
+        (10, "373", "Date", Optional, Date, 8, 8, date),
+        (11, "119", "Rate Basis Number", Optional, AlphaNumeric, 1, 6, rate_basis_number),
+        (12, "227", "Tariff Column", Optional, AlphaNumeric, 1, 2, tariff_column),
+        (13, "294", "Tariff Distance", Optional, Numeric(0), 1, 5, tariff_distance),
+        (14, "295", "Distance Qualifier", Optional, Id, 1, 1, distance_qualifier),
+        (15, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (16, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    LIN,
+    "Item Identification",
+    "To specify basic item identification data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "235", "Product/Service ID Qualifier", Mandatory, Id, 2, 2, product_service_id_qualifier_02),
+        (3, "234", "Product/Service ID", Mandatory, AlphaNumeric, 1, 48, product_service_id_03),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+        (26, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_26),
+        (27, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_27),
+        (28, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_28),
+        (29, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_29),
+        (30, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_30),
+        (31, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_31),
+    ]
+}
+
+segment_meta! {
+    LM,
+    "Code Source Information",
+    "To transmit standard code list identification information",
+    [_01, _02],
+    [
+        (1, "559", "Agency Qualifier Code", Mandatory, Id, 2, 2, agency_qualifier_code),
+        (2, "822", "Source Subqualifier", Optional, AlphaNumeric, 1, 15, source_subqualifier),
+    ]
+}
+
+segment_meta! {
+    LQ,
+    "Industry Code",
+    "Code to transmit standard industry codes",
+    [_01, _02],
+    [
+        (1, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (2, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    MAN,
+    "Marks and Numbers",
+    "To indicate identifying marks and numbers for shipping containers",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "88", "Marks and Numbers Qualifier", Mandatory, Id, 1, 2, marks_and_numbers_qualifier_01),
+        (2, "87", "Marks and Numbers", Mandatory, AlphaNumeric, 1, 48, marks_and_numbers_02),
+        (3, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_03),
+        (4, "88", "Marks and Numbers Qualifier", Conditional, Id, 1, 2, marks_and_numbers_qualifier_04),
+        (5, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers_05),
+        (6, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers_06),
+    ]
+}
+
+segment_meta! {
+    MEA,
+    "Measurements",
+    "To specify physical measurements or counts, including dimensions, tolerances, variances, and weights (See Figures Appendix for example of use of C001)",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "737", "Measurement Reference ID Code", Optional, Id, 2, 2, measurement_reference_id_code),
+        (2, "738", "Measurement Qualifier", Optional, Id, 1, 3, measurement_qualifier),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (5, "740", "Range Minimum", Conditional, Decimal, 1, 20, range_minimum),
+        (6, "741", "Range Maximum", Conditional, Decimal, 1, 20, range_maximum),
+        (7, "935", "Measurement Significance Code", Optional, Id, 2, 2, measurement_significance_code),
+        (8, "936", "Measurement Attribute Code", Conditional, Id, 2, 2, measurement_attribute_code),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (10, "1373", "Measurement Method or Device", Optional, Id, 2, 4, measurement_method_or_device),
+    ]
+}
+
+segment_meta! {
+    MSG,
+    "Message Text",
+    "To provide a free-form format that allows the transmission of text information",
+    [_01, _02, _03],
+    [
+        (1, "933", "Free-Form Message Text", Mandatory, AlphaNumeric, 1, 264, free_form_message_text),
+        (2, "934", "Printer Carriage Control Code", Conditional, Id, 2, 2, printer_carriage_control_code),
+        (3, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    N1,
+    "Name",
+    "To identify a party by type of organization, name, and code",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "706", "Entity Relationship Code", Optional, Id, 2, 2, entity_relationship_code),
+        (6, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_06),
+    ]
+}
+
+segment_meta! {
+    N2,
+    "Additional Name Information",
+    "To specify additional names or those longer than 35 characters in length",
+    [_01, _02],
+    [
+        (1, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name_02),
+    ]
+}
+
+segment_meta! {
+    N3,
+    "Address Information",
+    "To specify the location of the named party",
+    [_01, _02],
+    [
+        (1, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_01),
+        (2, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_02),
+    ]
+}
+
+segment_meta! {
+    N4,
+    "Geographic Location",
+    "To specify the geographic place of the named party",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (5, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    N9,
+    "Reference Identification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "369", "Free-form Description", Conditional, AlphaNumeric, 1, 45, free_form_description),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Conditional, Time, 4, 8, time),
+        (6, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (7, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    NTE,
+    "Note/Special Instruction",
+    "To transmit information in a free-form format, if necessary, for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "363", "Note Reference Code", Optional, Id, 3, 3, note_reference_code),
+        (2, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    PAM,
+    "Period Amount",
+    "To indicate a quantity, and/or amount for an identified period",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "673", "Quantity Qualifier", Conditional, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (4, "344", "Unit of Time Period or Interval", Conditional, Id, 2, 2, unit_of_time_period_or_interval_04),
+        (5, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier),
+        (6, "373", "Date", Conditional, Date, 8, 8, date_06),
+        (7, "337", "Time", Optional, Time, 4, 8, time_07),
+        (8, "373", "Date", Conditional, Date, 8, 8, date_08),
+        (9, "337", "Time", Optional, Time, 4, 8, time_09),
+        (10, "522", "Amount Qualifier Code", Conditional, Id, 1, 3, amount_qualifier_code),
+        (11, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (12, "344", "Unit of Time Period or Interval", Conditional, Id, 2, 2, unit_of_time_period_or_interval_12),
+        (13, "1345", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier),
+        (14, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+        (15, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    PER,
+    "Administrative Communications Contact",
+    "To identify a person or office to whom administrative communications should be directed",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_03),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_04),
+        (5, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_05),
+        (6, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_06),
+        (7, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_07),
+        (8, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_08),
+        (9, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    PID,
+    "Product/Item Description",
+    "To describe a product or process in coded or free-form format",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "349", "Item Description Type", Mandatory, Id, 1, 1, item_description_type),
+        (2, "750", "Product/Process Characteristic Code", Optional, Id, 2, 3, product_process_characteristic_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "751", "Product Description Code", Conditional, AlphaNumeric, 1, 12, product_description_code),
+        (5, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (6, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (7, "822", "Source Subqualifier", Optional, AlphaNumeric, 1, 15, source_subqualifier),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (9, "819", "Language Code", Optional, Id, 2, 3, language_code),
+    ]
+}
+
+segment_meta! {
+    PKG,
+    "Marking, Packaging, Loading",
+    "To describe marking, packaging, loading, and unloading requirements",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "349", "Item Description Type", Conditional, Id, 1, 1, item_description_type),
+        (2, "753", "Packaging Characteristic Code", Optional, Id, 1, 5, packaging_characteristic_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "754", "Packaging Description Code", Conditional, AlphaNumeric, 1, 7, packaging_description_code),
+        (5, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (6, "400", "Unit Load Option Code", Optional, Id, 2, 2, unit_load_option_code),
+    ]
+}
+
+segment_meta! {
+    PO1,
+    "Baseline Item Data",
+    "To specify basic and most frequently used line item data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "330", "Quantity Ordered", Conditional, Decimal, 1, 15, quantity_ordered),
+        (3, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+    ]
+}
+
+segment_meta! {
+    PO3,
+    "Additional Item Detail",
+    "To specify additional item-related data involving variations in normal price/quantity structure",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "371", "Change Reason Code", Mandatory, Id, 2, 2, change_reason_code),
+        (2, "373", "Date", Optional, Date, 8, 8, date),
+        (3, "236", "Price Identifier Code", Conditional, Id, 3, 3, price_identifier_code),
+        (4, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+        (5, "639", "Basis of Unit Price Code", Conditional, Id, 2, 2, basis_of_unit_price_code),
+        (6, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (7, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (8, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    PO4,
+    "Item Physical Details",
+    "To specify the physical qualities, packaging, weights, and dimensions relating to the item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+        (1, "356", "Pack", Optional, Numeric(0), 1, 6, pack),
+        (2, "357", "Size", Conditional, Decimal, 1, 8, size),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "103", "Packaging Code", Conditional, AlphaNumeric, 3, 5, packaging_code),
+        (5, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (6, "384", "Gross Weight per Pack", Conditional, Decimal, 1, 9, gross_weight_per_pack),
+        (7, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_07),
+        (8, "385", "Gross Volume per Pack", Conditional, Decimal, 1, 9, gross_volume_per_pack),
+        (9, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_09),
+        (10, "82", "Length", Conditional, Decimal, 1, 8, length),
+        (11, "189", "Width", Conditional, Decimal, 1, 8, width),
+        (12, "65", "Height", Conditional, Decimal, 1, 8, height),
+        (13, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_13),
+        (14, "810", "Inner Pack", Optional, Numeric(0), 1, 6, inner_pack),
+        (15, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (16, "350", "Assigned Identification", Conditional, AlphaNumeric, 1, 20, assigned_identification_16),
+        (17, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification_17),
+        (18, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    PRF,
+    "Purchase Order Reference",
+    "To provide reference to a specific purchase order",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "324", "Purchase Order Number", Mandatory, AlphaNumeric, 1, 22, purchase_order_number),
+        (2, "328", "Release Number", Optional, AlphaNumeric, 1, 30, release_number),
+        (3, "327", "Change Order Sequence Number", Optional, AlphaNumeric, 1, 8, change_order_sequence_number),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (6, "367", "Contract Number", Optional, AlphaNumeric, 1, 30, contract_number),
+        (7, "92", "Purchase Order Type Code", Optional, Id, 2, 2, purchase_order_type_code),
+    ]
+}
+
+segment_meta! {
+    PWK,
+    "Paperwork",
+    "To identify the type or transmission or both of paperwork or supporting information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "755", "Report Type Code", Mandatory, Id, 2, 2, report_type_code),
+        (2, "756", "Report Transmission Code", Optional, Id, 1, 2, report_transmission_code),
+        (3, "757", "Report Copies Needed", Optional, Numeric(0), 1, 2, report_copies_needed),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (5, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (6, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (8, "C002", "Actions Indicated", Optional, Composite, 0, 0, actions_indicated),
+        (9, "1525", "Request Category Code", Optional, Id, 1, 2, request_category_code),
+    ]
+}
+
+segment_meta! {
+    QTY,
+    "Quantity",
+    "To specify quantity information",
+    [_01, _02, _03, _04],
+    [
+        (1, "673", "Quantity Qualifier", Mandatory, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "C001", "Composite Unit of Measure", Optional, Composite, 0, 0, composite_unit_of_measure),
+        (4, "61", "Free-Form Message", Conditional, AlphaNumeric, 1, 30, free_form_message),
+    ]
+}
+
+segment_meta! {
+    R4,
+    "Port or Terminal",
+    "Contractual or operational port or point relevant to the movement of the cargo",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "115", "Port or Terminal Function Code", Mandatory, Id, 1, 1, port_or_terminal_function_code),
+        (2, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (3, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (4, "114", "Port Name", Optional, AlphaNumeric, 2, 24, port_name),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "174", "Terminal Name", Optional, AlphaNumeric, 2, 30, terminal_name),
+        (7, "113", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (8, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    REF,
+    "Reference Identification",
+    "To specify identifying information",
+    [_01, _02, _03, _04],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (4, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    SAC,
+    "Service, Promotion, Allowance, or Charge Information",
+    "To request or identify a service, promotion, allowance, or charge; to specify the amount or percentage for the service, promotion, allowance, or charge",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "248", "Allowance or Charge Indicator", Mandatory, Id, 1, 1, allowance_or_charge_indicator),
+        (2, "1300", "Service, Promotion, Allowance, or Charge Code", Conditional, Id, 4, 4, service_promotion_allowance_or_charge_code),
+        (3, "559", "Agency Qualifier Code", Conditional, Id, 2, 2, agency_qualifier_code),
+        (4, "1301", "Agency Service, Promotion, Allowance, or Charge Code", Conditional, AlphaNumeric, 1, 10, agency_service_promotion_allowance_or_charge_code),
+        (5, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (6, "378", "Allowance/Charge Percent Qualifier", Conditional, Id, 1, 1, allowance_charge_percent_qualifier),
+        (7, "332", "Percent", Conditional, Decimal, 1, 6, percent),
+        (8, "118", "Rate", Optional, Decimal, 1, 9, rate),
+        (9, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "380", "Quantity", Optional, Decimal, 1, 15, quantity_11),
+        (12, "331", "Allowance or Charge Method of Handling Code", Optional, Id, 2, 2, allowance_or_charge_method_of_handling_code),
+        (13, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (14, "770", "Option Number", Optional, AlphaNumeric, 1, 20, option_number),
+        (15, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (16, "819", "Language Code", Optional, Id, 2, 3, language_code),
+    ]
+}
+
+segment_meta! {
+    SCH,
+    "Line Item Schedule",
+    "To specify the data for scheduling a specific line-item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (4, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (5, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier_05),
+        (6, "373", "Date", Mandatory, Date, 8, 8, date_06),
+        (7, "337", "Time", Conditional, Time, 4, 8, time_07),
+        (8, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_08),
+        (9, "373", "Date", Conditional, Date, 8, 8, date_09),
+        (10, "337", "Time", Conditional, Time, 4, 8, time_10),
+        (11, "326", "Request Reference Number", Optional, AlphaNumeric, 1, 45, request_reference_number),
+        (12, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+    ]
+}
+
+segment_meta! {
+    SDQ,
+    "Destination Quantity",
+    "To specify destination and quantity detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (2, "66", "Identification Code Qualifier", Optional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Mandatory, AlphaNumeric, 2, 80, identification_code_03),
+        (4, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_04),
+        (5, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_05),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_07),
+        (8, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_08),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_09),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_11),
+        (12, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_12),
+        (13, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_13),
+        (14, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_14),
+        (15, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_15),
+        (16, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_16),
+        (17, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_17),
+        (18, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_18),
+        (19, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_19),
+        (20, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_20),
+        (21, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_21),
+        (22, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_22),
+        (23, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments)",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    SLN,
+    "Subline Item Detail",
+    "To specify product subline detail item data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28],
+    [
+        (1, "350", "Assigned Identification", Mandatory, AlphaNumeric, 1, 20, assigned_identification_01),
+        (2, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification_02),
+        (3, "662", "Relationship Code", Mandatory, Id, 1, 1, relationship_code_03),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (7, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (8, "662", "Relationship Code", Optional, Id, 1, 1, relationship_code_08),
+        (9, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_09),
+        (10, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_11),
+        (12, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_12),
+        (13, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_13),
+        (14, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_14),
+        (15, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_15),
+        (16, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_16),
+        (17, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_17),
+        (18, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_18),
+        (19, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_19),
+        (20, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_20),
+        (21, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_21),
+        (22, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_22),
+        (23, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_23),
+        (24, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_24),
+        (25, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_25),
+        (26, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_26),
+        (27, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_27),
+        (28, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_28),
+    ]
+}
+
+segment_meta! {
+    SN1,
+    "Item Detail (Shipment)",
+    "To specify line-item detail relative to shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "382", "Number of Units Shipped", Mandatory, Decimal, 1, 10, number_of_units_shipped),
+        (3, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code_03),
+        (4, "646", "Quantity Shipped to Date", Optional, Decimal, 1, 15, quantity_shipped_to_date),
+        (5, "330", "Quantity Ordered", Conditional, Decimal, 1, 15, quantity_ordered),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "728", "Returnable Container Load Make-Up Code", Optional, Id, 1, 2, returnable_container_load_make_up_code),
+        (8, "668", "Line Item Status Code", Optional, Id, 2, 2, line_item_status_code),
+    ]
+}
+
+segment_meta! {
+    SR,
+    "Requested Service Schedule",
+    "To identify requested service schedules",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    TC2,
+    "Commodity",
+    "To identify a commodity or a group of commodities or a tariff page commodity",
+    [_01, _02],
+    [
+        (1, "23", "Commodity Code Qualifier", Mandatory, Id, 1, 1, commodity_code_qualifier),
+        (2, "22", "Commodity Code", Mandatory, AlphaNumeric, 1, 30, commodity_code),
+    ]
+}
+
+segment_meta! {
+    TD1,
+    "Carrier Details (Quantity and Weight)",
+    "To specify the transportation details relative to commodity, weight, and quantity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (2, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (3, "23", "Commodity Code Qualifier", Optional, Id, 1, 1, commodity_code_qualifier),
+        (4, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code),
+        (5, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (6, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (7, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_08),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (10, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_10),
+    ]
+}
+
+segment_meta! {
+    TD3,
+    "Carrier Details (Equipment)",
+    "To specify transportation details relating to the equipment used by the carrier",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "40", "Equipment Description Code", Conditional, Id, 2, 2, equipment_description_code),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+        (4, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (5, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (7, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number),
+        (8, "24", "Equipment Type", Conditional, Id, 4, 4, equipment_type),
+    ]
+}
+
+segment_meta! {
+    TD4,
+    "Carrier Details (Special Handling, or Hazardous Materials, or Both)",
+    "To specify transportation special handling requirements, or hazardous materials information, or both",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "208", "Hazardous Material Code Qualifier", Conditional, Id, 1, 1, hazardous_material_code_qualifier),
+        (3, "209", "Hazardous Material Class Code", Conditional, AlphaNumeric, 1, 4, hazardous_material_class_code),
+        (4, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (5, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    TD5,
+    "Carrier Details (Routing Sequence/Transit Time)",
+    "To specify the carrier and sequence of routing and provide transit time information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "133", "Routing Sequence Code", Optional, Id, 1, 2, routing_sequence_code),
+        (2, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (4, "91", "Transportation Method/Type Code", Conditional, Id, 1, 2, transportation_method_type_code),
+        (5, "387", "Routing", Conditional, AlphaNumeric, 1, 35, routing),
+        (6, "368", "Shipment/Order Status Code", Conditional, Id, 2, 2, shipment_order_status_code),
+        (7, "309", "Location Qualifier", Optional, Id, 1, 2, location_qualifier),
+        (8, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (9, "731", "Transit Direction Code", Optional, Id, 2, 2, transit_direction_code),
+        (10, "732", "Transit Time Direction Qualifier", Optional, Id, 2, 2, transit_time_direction_qualifier),
+        (11, "733", "Transit Time", Conditional, Decimal, 1, 4, transit_time),
+        (12, "284", "Service Level Code", Conditional, Id, 2, 2, service_level_code_12),
+        (13, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_13),
+        (14, "284", "Service Level Code", Optional, Id, 2, 2, service_level_code_14),
+        (15, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    TDS,
+    "Total Monetary Value Summary",
+    "To specify the total invoice discounts and amounts",
+    [_01, _02, _03, _04],
+    [
+        (1, "610", "Amount", Mandatory, Numeric(2), 1, 15, amount_01),
+        (2, "610", "Amount", Optional, Numeric(2), 1, 15, amount_02),
+        (3, "610", "Amount", Optional, Numeric(2), 1, 15, amount_03),
+        (4, "610", "Amount", Optional, Numeric(2), 1, 15, amount_04),
+    ]
+}
+
+segment_meta! {
+    TXI,
+    "Tax Information",
+    "To specify tax information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "963", "Tax Type Code", Mandatory, Id, 2, 2, tax_type_code),
+        (2, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (3, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+        (4, "955", "Tax Jurisdiction Code Qualifier", Conditional, Id, 2, 2, tax_jurisdiction_code_qualifier),
+        (5, "956", "Tax Jurisdiction Code", Conditional, AlphaNumeric, 1, 10, tax_jurisdiction_code),
+        (6, "441", "Tax Exempt Code", Conditional, Id, 1, 1, tax_exempt_code),
+        (7, "662", "Relationship Code", Optional, Id, 1, 1, relationship_code),
+        (8, "828", "Dollar Basis For Percent", Optional, Decimal, 1, 9, dollar_basis_for_percent),
+        (9, "325", "Tax Identification Number", Optional, AlphaNumeric, 1, 20, tax_identification_number),
+        (10, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+    ]
+}
+
+segment_meta! {
+    V1,
+    "Vessel Identification",
+    "To provide vessel details and voyage number",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "597", "Vessel Code", Conditional, Id, 1, 8, vessel_code),
+        (2, "182", "Vessel Name", Conditional, AlphaNumeric, 2, 28, vessel_name),
+        (3, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (4, "55", "Flight/Voyage Number", Optional, AlphaNumeric, 2, 10, flight_voyage_number),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "249", "Vessel Requirement Code", Optional, Id, 1, 1, vessel_requirement_code),
+        (7, "854", "Vessel Type Code", Optional, Id, 2, 2, vessel_type_code),
+        (8, "897", "Vessel Code Qualifier", Optional, Id, 1, 1, vessel_code_qualifier),
+        (9, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+segment_meta! {
+    YNQ,
+    "Yes/No Question",
+    "To identify and answer yes and no questions, including the date, time, and comments further qualifying the condition",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "1321", "Condition Indicator", Conditional, Id, 2, 3, condition_indicator),
+        (2, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (4, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (5, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_05),
+        (6, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_06),
+        (7, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_07),
+        (8, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (9, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+        (10, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text_10),
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "AMT" => Some(AMT::DESCRIPTOR),
+        "BAL" => Some(BAL::DESCRIPTOR),
+        "BEG" => Some(BEG::DESCRIPTOR),
+        "BIG" => Some(BIG::DESCRIPTOR),
+        "BSN" => Some(BSN::DESCRIPTOR),
+        "CAD" => Some(CAD::DESCRIPTOR),
+        "CLD" => Some(CLD::DESCRIPTOR),
+        "CRC" => Some(CRC::DESCRIPTOR),
+        "CTP" => Some(CTP::DESCRIPTOR),
+        "CTT" => Some(CTT::DESCRIPTOR),
+        "CUR" => Some(CUR::DESCRIPTOR),
+        "DMG" => Some(DMG::DESCRIPTOR),
+        "DTM" => Some(DTM::DESCRIPTOR),
+        "FA1" => Some(FA1::DESCRIPTOR),
+        "FA2" => Some(FA2::DESCRIPTOR),
+        "FOB" => Some(FOB::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "HL" => Some(HL::DESCRIPTOR),
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "INC" => Some(INC::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "ISS" => Some(ISS::DESCRIPTOR),
+        "IT1" => Some(IT1::DESCRIPTOR),
+        "IT3" => Some(IT3::DESCRIPTOR),
+        "ITD" => Some(ITD::DESCRIPTOR),
+        "L7" => Some(L7::DESCRIPTOR),
+        "LIN" => Some(LIN::DESCRIPTOR),
+        "LM" => Some(LM::DESCRIPTOR),
+        "LQ" => Some(LQ::DESCRIPTOR),
+        "MAN" => Some(MAN::DESCRIPTOR),
+        "MEA" => Some(MEA::DESCRIPTOR),
+        "MSG" => Some(MSG::DESCRIPTOR),
+        "N1" => Some(N1::DESCRIPTOR),
+        "N2" => Some(N2::DESCRIPTOR),
+        "N3" => Some(N3::DESCRIPTOR),
+        "N4" => Some(N4::DESCRIPTOR),
+        "N9" => Some(N9::DESCRIPTOR),
+        "NTE" => Some(NTE::DESCRIPTOR),
+        "PAM" => Some(PAM::DESCRIPTOR),
+        "PER" => Some(PER::DESCRIPTOR),
+        "PID" => Some(PID::DESCRIPTOR),
+        "PKG" => Some(PKG::DESCRIPTOR),
+        "PO1" => Some(PO1::DESCRIPTOR),
+        "PO3" => Some(PO3::DESCRIPTOR),
+        "PO4" => Some(PO4::DESCRIPTOR),
+        "PRF" => Some(PRF::DESCRIPTOR),
+        "PWK" => Some(PWK::DESCRIPTOR),
+        "QTY" => Some(QTY::DESCRIPTOR),
+        "R4" => Some(R4::DESCRIPTOR),
+        "REF" => Some(REF::DESCRIPTOR),
+        "SAC" => Some(SAC::DESCRIPTOR),
+        "SCH" => Some(SCH::DESCRIPTOR),
+        "SDQ" => Some(SDQ::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "SLN" => Some(SLN::DESCRIPTOR),
+        "SN1" => Some(SN1::DESCRIPTOR),
+        "SR" => Some(SR::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        "TC2" => Some(TC2::DESCRIPTOR),
+        "TD1" => Some(TD1::DESCRIPTOR),
+        "TD3" => Some(TD3::DESCRIPTOR),
+        "TD4" => Some(TD4::DESCRIPTOR),
+        "TD5" => Some(TD5::DESCRIPTOR),
+        "TDS" => Some(TDS::DESCRIPTOR),
+        "TXI" => Some(TXI::DESCRIPTOR),
+        "V1" => Some(V1::DESCRIPTOR),
+        "YNQ" => Some(YNQ::DESCRIPTOR),
+        _ => None,
+    }
+}
@@ -0,0 +1,55 @@
+use crate::util::meta::SegmentMeta;
+use crate::v004060::*;
+
+#[test]
+fn test_810_004060() {
+    let str = r#"ISA*00*          *00*          *ZZ*SUPPLIER01     *ZZ*RETAILER02     *230315*0912*U*00406*000000417*0*P*>~
+GS*IN*SUPPLIER01*RETAILER02*20230315*0912*417*X*004060~
+ST*810*0417~
+BIG*20230315*INV-230315-0417*20230301*PO-4500098812~
+CUR*SE*USD~
+REF*IA*118833~
+REF*BM*MBOL-20230315-PORTLAND-DC-000417-TRAILER-88~
+N1*RI*ACME AUTO PARTS LLC*92*118833~
+N3*4100 INDUSTRIAL PKWY~
+N4*TOLEDO*OH*43612*US~
+N1*ST*RETAILER DC 12*92*0012~
+N3*900 DISTRIBUTION WAY~
+N4*PORTLAND*OR*97218*US~
+ITD*01*3*2**10**30~
+DTM*011*20230314~
+IT1*1*24*EA*12.5**BP*BRK-4471*VP*AP-4471~
+PID*F****BRAKE PAD SET FRONT~
+IT1*2*10*EA*31**BP*ROT-2210*VP*AP-2210~
+PID*F****ROTOR 12 IN~
+TDS*61000~
+CTT*2~
+SE*20*0417~
+GE*1*417~
+IEA*1*000000417~
+"#;
+    let (rest, obj) = Transmission::<_810>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.isa._12, "00406");
+    assert_eq!(obj.functional_group[0].gs._08, "004060");
+    let invoice = &obj.functional_group[0].segments[0];
+    assert_eq!(invoice.big._04.as_deref(), Some("PO-4500098812"));
+    // the bill of lading number is longer than the 30 characters 004010 allows
+    let bol = invoice.r#ref.iter().find(|r| r._01 == "BM").unwrap();
+    let len = bol._02.as_ref().unwrap().len();
+    assert!(len > 30 && len <= REF::DESCRIPTOR.element(2).unwrap().max);
+    assert_eq!(invoice.loop_n1.len(), 2);
+    assert_eq!(invoice.loop_it1.len(), 2);
+    // TDS carries the invoice total in cents
+    let total: f64 = invoice
+        .loop_it1
+        .iter()
+        .map(|l| {
+            let qty: f64 = l.it1._02.parse().unwrap();
+            let price: f64 = l.it1._04.as_deref().unwrap().parse().unwrap();
+            qty * price
+        })
+        .sum();
+    assert_eq!(invoice.tds._01, format!("{:.0}", total * 100.0));
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,37 @@
+use crate::v004060::*;
+
+#[test]
+fn test_850() {
+    let str = r#"ISA*00*          *00*          *ZZ*SENDERISA      *14*0073268795005  *110226*1534*U*00406*000000001*0*T*>~
+GS*PO*SENDERGS*007326879*20110226*1534*1*X*004060~
+ST*850*000000001~
+BEG*00*SA*A99999-01**20110214~
+REF*VR*54321~
+ITD*01*3*1**15**16~
+DTM*002*20111219~
+N1*BT*BUYSNACKS INC.*9*1223334444~
+N3*P.O. BOX 0000~
+N4*TEMPLE*TX*76503~
+N1*ST*BUYSNACKS PORT*9*1223334445~
+N3*1000 N. SAMPLE HIGHWAY~
+N4*ATHENS*GA*30603~
+PO1**16*CA*12.34**CB*000111111*UA*002840022222~
+PID*F****CRUNCHY CHIPS LSS~
+PO4*48*7.89*LB~
+SCH*16*CA***002*20111219~
+PO1**13*CA*12.34**CB*000555555*UA*002840033333~
+PID*F****NACHO CHIPS LSS~
+PO4*48*8.9*LB~
+CTT*2~
+SE*20*000000001~
+GE*1*1~
+IEA*1*000000001~
+"#;
+    let (rest, obj) = Transmission::<_850>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let po = &obj.functional_group[0].segments[0];
+    assert_eq!(po.loop_n1.len(), 2);
+    assert_eq!(po.loop_po1.len(), 2);
+    assert_eq!(po.loop_po1[0].loop_sch[0].sch._06, "20111219");
+    assert_eq!(format!("{obj}"), str);
+}
@@ -0,0 +1,44 @@
+use crate::util::hl::walk;
+use crate::v004060::*;
+
+/// The sample file comes without segment terminators.
+fn sample() -> String {
+    include_str!("../../test-data/004060_856.edi")
+        .lines()
+        .map(|l| format!("{l}~\n"))
+        .collect()
+}
+
+#[test]
+fn test_856_004060() {
+    let str = sample();
+    let (rest, obj) = Transmission::<_856>::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group[0].gs._08, "004060");
+    let asn = &obj.functional_group[0].segments[0];
+    assert_eq!(asn.bsn._05, Some("0002".to_string()));
+    let shipment = &asn.loop_hl[0];
+    let td5 = &shipment.td5[0];
+    assert_eq!(td5._02.as_deref(), Some("2"));
+    assert_eq!(td5._03.as_deref(), Some("XXXX"));
+    let buyer = shipment
+        .loop_n1
+        .iter()
+        .find(|n1| n1.n1._01 == "OB")
+        .unwrap();
+    let n4 = buyer.n4.as_ref().unwrap();
+    assert_eq!(n4._03.as_deref(), Some("31139-0020"));
+    assert_eq!(n4._05.as_deref(), Some("SN"));
+    assert_eq!(n4._06.as_deref(), Some("9999"));
+    // CTT carries the number of HL segments and the hash total of SN102
+    let ctt = asn.ctt.as_ref().unwrap();
+    let levels = walk(&asn.loop_hl);
+    assert_eq!(ctt._01, levels.len().to_string());
+    let shipped: u32 = levels
+        .iter()
+        .filter_map(|hl| hl.sn1.as_ref())
+        .map(|sn1| sn1._02.parse::<u32>().unwrap())
+        .sum();
+    assert_eq!(ctt._02.as_deref(), Some(shipped.to_string().as_str()));
+    assert_eq!(format!("{obj}"), str);
+}
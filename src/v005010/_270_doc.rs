@@ -0,0 +1,304 @@
+pub use super::segment::*;
+use log::{error, trace};
+use serde::{Deserialize, Serialize};
+use x12_types_macros::DisplayX12;
+
+use crate::util::Parser;
+use nom::{combinator::opt, multi::many0, IResult, Parser as _};
+
+/// 270 - Health Care Eligibility Benefit Inquiry (005010X279A1)
+///
+/// The HL loops are nested: information source (2000A), information
+/// receiver (2000B), subscriber (2000C) and dependent (2000D).
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270 {
+    pub st: ST,
+    pub bht: BHT,
+    pub loop_2000a: Vec<_270Loop2000A>,
+    pub se: SE,
+}
+
+/// Information Source Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2000A {
+    pub hl: HL,
+    pub loop_2100a: _270Loop2100A,
+    pub loop_2000b: Vec<_270Loop2000B>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2100A {
+    pub nm1: NM1,
+}
+
+/// Information Receiver Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2000B {
+    pub hl: HL,
+    pub loop_2100b: _270Loop2100B,
+    pub loop_2000c: Vec<_270Loop2000C>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2100B {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub prv: Option<PRV>,
+}
+
+/// Subscriber Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2000C {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub loop_2100c: _270Loop2100C,
+    pub loop_2000d: Vec<_270Loop2000D>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2100C {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub prv: Option<PRV>,
+    pub dmg: Option<DMG>,
+    pub ins: Option<INS>,
+    pub hi: Option<HI>,
+    pub dtp: Vec<DTP>,
+    pub loop_2110c: Vec<_270Loop2110C>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2110C {
+    pub eq: EQ,
+    pub amt: Vec<AMT>,
+    pub iii: Option<III>,
+    pub r#ref: Option<REF>,
+    pub dtp: Option<DTP>,
+}
+
+/// Dependent Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2000D {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub loop_2100d: _270Loop2100D,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2100D {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub prv: Option<PRV>,
+    pub dmg: Option<DMG>,
+    pub ins: Option<INS>,
+    pub hi: Option<HI>,
+    pub dtp: Vec<DTP>,
+    pub loop_2110d: Vec<_270Loop2110D>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _270Loop2110D {
+    pub eq: EQ,
+    pub iii: Option<III>,
+    pub r#ref: Option<REF>,
+    pub dtp: Option<DTP>,
+}
+
+impl<'a> Parser<&'a str, _270, nom::error::Error<&'a str>> for _270 {
+    fn parse(input: &'a str) -> IResult<&'a str, _270> {
+        parse_270(input)
+    }
+}
+
+/// Parses an `HL` segment with the given level code (`HL03`).
+pub(crate) fn parse_hl_level<'a>(input: &'a str, level: &str) -> IResult<&'a str, HL> {
+    let (rest, hl) = HL::parse(input)?;
+    if hl._03 != level {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Tag,
+        )));
+    }
+    Ok((rest, hl))
+}
+
+// Top-level 270 parser
+pub fn parse_270(input: &str) -> IResult<&str, _270> {
+    trace!("enter parse_270");
+    let (rest, st) = ST::parse(input)?;
+
+    if st._01 != "270" {
+        error!(
+            "ST segment declares {} document instead of expected 270",
+            st._01
+        );
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            "ST segment does not declare an EDI 270",
+            nom::error::ErrorKind::Fail,
+        )));
+    }
+
+    let (rest, bht) = BHT::parse(rest)?;
+    let (rest, loop_2000a) = many0(parse_loop_2000_a).parse(rest)?;
+    let (rest, se) = SE::parse(rest)?;
+    trace!("exit parse_270");
+    Ok((
+        rest,
+        _270 {
+            st,
+            bht,
+            loop_2000a,
+            se,
+        },
+    ))
+}
+
+fn parse_loop_2000_a(input: &str) -> IResult<&str, _270Loop2000A> {
+    trace!("enter parse_loop_2000A");
+    let (rest, hl) = parse_hl_level(input, "20")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, loop_2000b) = many0(parse_loop_2000_b).parse(rest)?;
+    trace!("exit parse_loop_2000A");
+    Ok((
+        rest,
+        _270Loop2000A {
+            hl,
+            loop_2100a: _270Loop2100A { nm1 },
+            loop_2000b,
+        },
+    ))
+}
+
+fn parse_loop_2000_b(input: &str) -> IResult<&str, _270Loop2000B> {
+    trace!("enter parse_loop_2000B");
+    let (rest, hl) = parse_hl_level(input, "21")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, loop_2000c) = many0(parse_loop_2000_c).parse(rest)?;
+    trace!("exit parse_loop_2000B");
+    Ok((
+        rest,
+        _270Loop2000B {
+            hl,
+            loop_2100b: _270Loop2100B {
+                nm1,
+                r#ref: rref,
+                n3,
+                n4,
+                prv,
+            },
+            loop_2000c,
+        },
+    ))
+}
+
+fn parse_loop_2000_c(input: &str) -> IResult<&str, _270Loop2000C> {
+    trace!("enter parse_loop_2000C");
+    let (rest, hl) = parse_hl_level(input, "22")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+    let (rest, ins) = opt(INS::parse).parse(rest)?;
+    let (rest, hi) = opt(HI::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, loop_2110c) = many0(parse_loop_2110_c).parse(rest)?;
+    let (rest, loop_2000d) = many0(parse_loop_2000_d).parse(rest)?;
+    trace!("exit parse_loop_2000C");
+    Ok((
+        rest,
+        _270Loop2000C {
+            hl,
+            trn,
+            loop_2100c: _270Loop2100C {
+                nm1,
+                r#ref: rref,
+                n3,
+                n4,
+                prv,
+                dmg,
+                ins,
+                hi,
+                dtp,
+                loop_2110c,
+            },
+            loop_2000d,
+        },
+    ))
+}
+
+fn parse_loop_2110_c(input: &str) -> IResult<&str, _270Loop2110C> {
+    let (rest, eq) = EQ::parse(input)?;
+    let (rest, amt) = many0(AMT::parse).parse(rest)?;
+    let (rest, iii) = opt(III::parse).parse(rest)?;
+    let (rest, rref) = opt(REF::parse).parse(rest)?;
+    let (rest, dtp) = opt(DTP::parse).parse(rest)?;
+    Ok((
+        rest,
+        _270Loop2110C {
+            eq,
+            amt,
+            iii,
+            r#ref: rref,
+            dtp,
+        },
+    ))
+}
+
+fn parse_loop_2000_d(input: &str) -> IResult<&str, _270Loop2000D> {
+    trace!("enter parse_loop_2000D");
+    let (rest, hl) = parse_hl_level(input, "23")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+    let (rest, ins) = opt(INS::parse).parse(rest)?;
+    let (rest, hi) = opt(HI::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, loop_2110d) = many0(parse_loop_2110_d).parse(rest)?;
+    trace!("exit parse_loop_2000D");
+    Ok((
+        rest,
+        _270Loop2000D {
+            hl,
+            trn,
+            loop_2100d: _270Loop2100D {
+                nm1,
+                r#ref: rref,
+                n3,
+                n4,
+                prv,
+                dmg,
+                ins,
+                hi,
+                dtp,
+                loop_2110d,
+            },
+        },
+    ))
+}
+
+fn parse_loop_2110_d(input: &str) -> IResult<&str, _270Loop2110D> {
+    let (rest, eq) = EQ::parse(input)?;
+    let (rest, iii) = opt(III::parse).parse(rest)?;
+    let (rest, rref) = opt(REF::parse).parse(rest)?;
+    let (rest, dtp) = opt(DTP::parse).parse(rest)?;
+    Ok((
+        rest,
+        _270Loop2110D {
+            eq,
+            iii,
+            r#ref: rref,
+            dtp,
+        },
+    ))
+}
@@ -0,0 +1,530 @@
+pub use super::segment::*;
+use log::{error, trace};
+use serde::{Deserialize, Serialize};
+use x12_types_macros::DisplayX12;
+
+use super::_270_doc::parse_hl_level;
+use super::Transmission;
+use crate::util::amount::{Amount, AmountError};
+use crate::util::{non_empty, Parser};
+use chrono::NaiveDate;
+use nom::{combinator::opt, multi::many0, IResult, Parser as _};
+use std::fmt;
+
+/// CCYYMMDD, the `D8` date format
+const DATE_FORMAT: &str = "%Y%m%d";
+
+/// 271 - Health Care Eligibility Benefit Response (005010X279A1)
+///
+/// The HL loops are nested like in the 270. [`_271::benefits`] flattens the
+/// `EB` loops of every subscriber and dependent.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271 {
+    pub st: ST,
+    pub bht: BHT,
+    pub loop_2000a: Vec<_271Loop2000A>,
+    pub se: SE,
+}
+
+/// Information Source Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2000A {
+    pub hl: HL,
+    pub aaa: Vec<AAA>,
+    pub loop_2100a: _271Loop2100A,
+    pub loop_2000b: Vec<_271Loop2000B>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2100A {
+    pub nm1: NM1,
+    pub per: Vec<PER>,
+    pub aaa: Vec<AAA>,
+}
+
+/// Information Receiver Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2000B {
+    pub hl: HL,
+    pub loop_2100b: _271Loop2100B,
+    pub loop_2000c: Vec<_271Loop2000C>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2100B {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub aaa: Vec<AAA>,
+    pub prv: Option<PRV>,
+}
+
+/// Subscriber Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2000C {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub loop_2100c: _271Loop2100C,
+    pub loop_2000d: Vec<_271Loop2000D>,
+}
+/// Subscriber Name
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2100C {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub aaa: Vec<AAA>,
+    pub prv: Option<PRV>,
+    pub dmg: Option<DMG>,
+    pub ins: Option<INS>,
+    pub hi: Option<HI>,
+    pub dtp: Vec<DTP>,
+    pub loop_2110c: Vec<_271Loop2110C>,
+}
+/// Subscriber Eligibility or Benefit Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2110C {
+    pub eb: EB,
+    pub hsd: Vec<HSD>,
+    pub r#ref: Vec<REF>,
+    pub dtp: Vec<DTP>,
+    pub aaa: Vec<AAA>,
+    pub msg: Vec<MSG>,
+    pub loop_2115c: Vec<_271Loop2115C>,
+    pub ls: Option<LS>,
+    pub loop_2120c: Vec<_271Loop2120C>,
+    pub le: Option<LE>,
+}
+/// Eligibility or Benefit Additional Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2115C {
+    pub iii: III,
+}
+/// Subscriber Benefit Related Entity Name
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2120C {
+    pub nm1: NM1,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub per: Vec<PER>,
+    pub prv: Option<PRV>,
+}
+
+/// Dependent Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _271Loop2000D {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub loop_2100d: _271Loop2100D,
+}
+
+/// Dependent Name, same layout as the subscriber name
+pub type _271Loop2100D = _271Loop2100C;
+/// Dependent Eligibility or Benefit Information
+pub type _271Loop2110D = _271Loop2110C;
+
+/// The benefits reported for one subscriber or dependent.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct _271Member {
+    /// `true` for a dependent (2000D), `false` for the subscriber (2000C)
+    pub dependent: bool,
+    /// NM103
+    pub last_name: Option<String>,
+    /// NM104
+    pub first_name: Option<String>,
+    /// NM109
+    pub member_id: Option<String>,
+    pub benefits: Vec<_271Benefit>,
+}
+
+/// One `EB` segment with its dates and messages.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct _271Benefit {
+    /// EB01, e.g. `1` active coverage, `C` deductible, `B` co-payment
+    pub code: String,
+    /// EB02, e.g. `IND` or `FAM`
+    pub coverage_level: Option<String>,
+    /// EB03 split at the repetition separator (`ISA11`)
+    pub service_types: Vec<String>,
+    /// EB04
+    pub insurance_type: Option<String>,
+    /// EB05
+    pub plan_description: Option<String>,
+    /// EB06
+    pub time_period: Option<String>,
+    /// EB07
+    pub amount: Option<Amount>,
+    /// EB08, e.g. `0.2` for 20 percent
+    pub percent: Option<f64>,
+    /// EB09
+    pub quantity_qualifier: Option<String>,
+    /// EB10
+    pub quantity: Option<f64>,
+    /// EB11
+    pub authorization_required: Option<String>,
+    /// EB12
+    pub in_network: Option<String>,
+    /// DTP segments of the 2110 loop
+    pub dates: Vec<_271Date>,
+    /// MSG01 of the 2110 loop
+    pub messages: Vec<String>,
+}
+
+/// A `DTP` date (`D8`) or date range (`RD8`) of a benefit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _271Date {
+    /// DTP01, e.g. `346` plan begin or `291` plan
+    pub qualifier: String,
+    pub from: NaiveDate,
+    /// end of an `RD8` range
+    pub to: Option<NaiveDate>,
+}
+
+/// An amount, number or date of a benefit that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum _271BenefitError {
+    Amount(AmountError),
+    Number(String),
+    Date(String),
+}
+
+impl fmt::Display for _271BenefitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            _271BenefitError::Amount(e) => e.fmt(f),
+            _271BenefitError::Number(value) => write!(f, "invalid number {value:?}"),
+            _271BenefitError::Date(value) => write!(f, "invalid date {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for _271BenefitError {}
+
+impl From<AmountError> for _271BenefitError {
+    fn from(e: AmountError) -> _271BenefitError {
+        _271BenefitError::Amount(e)
+    }
+}
+
+impl _271 {
+    /// Flattens the `EB` loops into one entry per subscriber and dependent, in document order.
+    ///
+    /// `EB03` is split on the repetition separator of the interchange
+    /// (`ISA11`), see [`Transmission::benefits`].
+    pub fn benefits(
+        &self,
+        repetition_separator: char,
+    ) -> Result<Vec<_271Member>, _271BenefitError> {
+        let mut out = vec![];
+        for a in &self.loop_2000a {
+            for b in &a.loop_2000b {
+                for c in &b.loop_2000c {
+                    out.push(_271Member::new(&c.loop_2100c, false, repetition_separator)?);
+                    for d in &c.loop_2000d {
+                        out.push(_271Member::new(&d.loop_2100d, true, repetition_separator)?);
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Transmission<_271> {
+    /// The benefits of all 271s in the interchange, split on its repetition
+    /// separator (`ISA11`).
+    pub fn benefits(&self) -> Result<Vec<_271Member>, _271BenefitError> {
+        let separator = self.isa._11.chars().next().unwrap_or('^');
+        let mut out = vec![];
+        for tx in self
+            .functional_group
+            .iter()
+            .flat_map(|group| &group.segments)
+        {
+            out.extend(tx.benefits(separator)?);
+        }
+        Ok(out)
+    }
+}
+
+impl _271Member {
+    fn new(
+        name: &_271Loop2100C,
+        dependent: bool,
+        separator: char,
+    ) -> Result<_271Member, _271BenefitError> {
+        Ok(_271Member {
+            dependent,
+            last_name: non_empty(&name.nm1._03),
+            first_name: non_empty(&name.nm1._04),
+            member_id: non_empty(&name.nm1._09),
+            benefits: name
+                .loop_2110c
+                .iter()
+                .map(|l| _271Benefit::new(l, separator))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl _271Benefit {
+    fn new(l: &_271Loop2110C, separator: char) -> Result<_271Benefit, _271BenefitError> {
+        let eb = &l.eb;
+        Ok(_271Benefit {
+            code: eb._01.clone(),
+            coverage_level: non_empty(&eb._02),
+            service_types: eb
+                ._03
+                .iter()
+                .flat_map(|v| v.split(separator))
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect(),
+            insurance_type: non_empty(&eb._04),
+            plan_description: non_empty(&eb._05),
+            time_period: non_empty(&eb._06),
+            amount: non_empty(&eb._07).map(|v| v.parse()).transpose()?,
+            percent: parse_number(&eb._08)?,
+            quantity_qualifier: non_empty(&eb._09),
+            quantity: parse_number(&eb._10)?,
+            authorization_required: non_empty(&eb._11),
+            in_network: non_empty(&eb._12),
+            dates: l
+                .dtp
+                .iter()
+                .map(_271Date::try_from)
+                .collect::<Result<_, _>>()?,
+            messages: l.msg.iter().map(|m| m._01.clone()).collect(),
+        })
+    }
+}
+
+impl TryFrom<&DTP> for _271Date {
+    type Error = _271BenefitError;
+
+    /// Reads `D8` and `RD8` periods.
+    fn try_from(dtp: &DTP) -> Result<_271Date, _271BenefitError> {
+        let (from, to) = match dtp._03.split_once('-') {
+            Some((from, to)) if dtp._02 == "RD8" => (from, Some(to)),
+            _ => (dtp._03.as_str(), None),
+        };
+        Ok(_271Date {
+            qualifier: dtp._01.clone(),
+            from: parse_date(from)?,
+            to: to.map(parse_date).transpose()?,
+        })
+    }
+}
+
+fn parse_number(value: &Option<String>) -> Result<Option<f64>, _271BenefitError> {
+    non_empty(value)
+        .map(|v| v.parse().map_err(|_| _271BenefitError::Number(v)))
+        .transpose()
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, _271BenefitError> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT)
+        .map_err(|_| _271BenefitError::Date(value.to_string()))
+}
+
+impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
+    fn parse(input: &'a str) -> IResult<&'a str, _271> {
+        parse_271(input)
+    }
+}
+
+// Top-level 271 parser
+pub fn parse_271(input: &str) -> IResult<&str, _271> {
+    trace!("enter parse_271");
+    let (rest, st) = ST::parse(input)?;
+
+    if st._01 != "271" {
+        error!(
+            "ST segment declares {} document instead of expected 271",
+            st._01
+        );
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            "ST segment does not declare an EDI 271",
+            nom::error::ErrorKind::Fail,
+        )));
+    }
+
+    let (rest, bht) = BHT::parse(rest)?;
+    let (rest, loop_2000a) = many0(parse_loop_2000_a).parse(rest)?;
+    let (rest, se) = SE::parse(rest)?;
+    trace!("exit parse_271");
+    Ok((
+        rest,
+        _271 {
+            st,
+            bht,
+            loop_2000a,
+            se,
+        },
+    ))
+}
+
+fn parse_loop_2000_a(input: &str) -> IResult<&str, _271Loop2000A> {
+    trace!("enter parse_loop_2000A");
+    let (rest, hl) = parse_hl_level(input, "20")?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, per) = many0(PER::parse).parse(rest)?;
+    let (rest, aaa_2100a) = many0(AAA::parse).parse(rest)?;
+    let (rest, loop_2000b) = many0(parse_loop_2000_b).parse(rest)?;
+    trace!("exit parse_loop_2000A");
+    Ok((
+        rest,
+        _271Loop2000A {
+            hl,
+            aaa,
+            loop_2100a: _271Loop2100A {
+                nm1,
+                per,
+                aaa: aaa_2100a,
+            },
+            loop_2000b,
+        },
+    ))
+}
+
+fn parse_loop_2000_b(input: &str) -> IResult<&str, _271Loop2000B> {
+    trace!("enter parse_loop_2000B");
+    let (rest, hl) = parse_hl_level(input, "21")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, loop_2000c) = many0(parse_loop_2000_c).parse(rest)?;
+    trace!("exit parse_loop_2000B");
+    Ok((
+        rest,
+        _271Loop2000B {
+            hl,
+            loop_2100b: _271Loop2100B {
+                nm1,
+                r#ref: rref,
+                aaa,
+                prv,
+            },
+            loop_2000c,
+        },
+    ))
+}
+
+fn parse_loop_2000_c(input: &str) -> IResult<&str, _271Loop2000C> {
+    trace!("enter parse_loop_2000C");
+    let (rest, hl) = parse_hl_level(input, "22")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, loop_2100c) = parse_loop_2100(rest)?;
+    let (rest, loop_2000d) = many0(parse_loop_2000_d).parse(rest)?;
+    trace!("exit parse_loop_2000C");
+    Ok((
+        rest,
+        _271Loop2000C {
+            hl,
+            trn,
+            loop_2100c,
+            loop_2000d,
+        },
+    ))
+}
+
+fn parse_loop_2000_d(input: &str) -> IResult<&str, _271Loop2000D> {
+    trace!("enter parse_loop_2000D");
+    let (rest, hl) = parse_hl_level(input, "23")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, loop_2100d) = parse_loop_2100(rest)?;
+    trace!("exit parse_loop_2000D");
+    Ok((
+        rest,
+        _271Loop2000D {
+            hl,
+            trn,
+            loop_2100d,
+        },
+    ))
+}
+
+// Loop 2100C/2100D
+fn parse_loop_2100(input: &str) -> IResult<&str, _271Loop2100C> {
+    let (rest, nm1) = NM1::parse(input)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+    let (rest, ins) = opt(INS::parse).parse(rest)?;
+    let (rest, hi) = opt(HI::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, loop_2110c) = many0(parse_loop_2110).parse(rest)?;
+    Ok((
+        rest,
+        _271Loop2100C {
+            nm1,
+            r#ref: rref,
+            n3,
+            n4,
+            aaa,
+            prv,
+            dmg,
+            ins,
+            hi,
+            dtp,
+            loop_2110c,
+        },
+    ))
+}
+
+// Loop 2110C/2110D
+fn parse_loop_2110(input: &str) -> IResult<&str, _271Loop2110C> {
+    let (rest, eb) = EB::parse(input)?;
+    let (rest, hsd) = many0(HSD::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, msg) = many0(MSG::parse).parse(rest)?;
+    let (rest, iii) = many0(III::parse).parse(rest)?;
+    let loop_2115c = iii.into_iter().map(|iii| _271Loop2115C { iii }).collect();
+    let (rest, ls) = opt(LS::parse).parse(rest)?;
+    // the 2120 loop only occurs wrapped in LS/LE
+    let (rest, loop_2120c, le) = if ls.is_some() {
+        let (rest, loop_2120c) = many0(parse_loop_2120).parse(rest)?;
+        let (rest, le) = opt(LE::parse).parse(rest)?;
+        (rest, loop_2120c, le)
+    } else {
+        (rest, vec![], None)
+    };
+    Ok((
+        rest,
+        _271Loop2110C {
+            eb,
+            hsd,
+            r#ref: rref,
+            dtp,
+            aaa,
+            msg,
+            loop_2115c,
+            ls,
+            loop_2120c,
+            le,
+        },
+    ))
+}
+
+fn parse_loop_2120(input: &str) -> IResult<&str, _271Loop2120C> {
+    let (rest, nm1) = NM1::parse(input)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, per) = many0(PER::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    Ok((
+        rest,
+        _271Loop2120C {
+            nm1,
+            n3,
+            n4,
+            per,
+            prv,
+        },
+    ))
+}
@@ -0,0 +1,360 @@
+pub use super::segment::*;
+use log::{error, trace};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use x12_types_macros::DisplayX12;
+
+use super::_270_doc::parse_hl_level;
+use super::{_837Loop2300, _837};
+use crate::util::Parser;
+use nom::{combinator::opt, multi::many0, IResult, Parser as _};
+
+/// 277CA - Health Care Claim Acknowledgment (005010X214)
+///
+/// The acknowledgment returned for an 837 submission. Unlike the claim status
+/// response ([`_277`](super::_277)) the HL loops are nested: information source
+/// (2000A), information receiver (2000B), billing provider (2000C) and patient
+/// (2000D). Each 2200D loop acknowledges one claim, its `TRN02` carries the
+/// patient control number from `CLM01`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CA {
+    pub st: ST,
+    pub bht: BHT,
+    pub loop_2000a: Vec<_277CALoop2000A>,
+    pub se: SE,
+}
+
+/// Information Source Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2000A {
+    pub hl: HL,
+    pub loop_2100a: _277CALoop2100A,
+    pub loop_2200a: _277CALoop2200A,
+    pub loop_2000b: Vec<_277CALoop2000B>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2100A {
+    pub nm1: NM1,
+}
+/// Transmission receipt and process dates
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2200A {
+    pub trn: TRN,
+    pub dtp: Vec<DTP>,
+}
+
+/// Information Receiver Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2000B {
+    pub hl: HL,
+    pub loop_2100b: _277CALoop2100B,
+    pub loop_2200b: _277CALoop2200B,
+    pub loop_2000c: Vec<_277CALoop2000C>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2100B {
+    pub nm1: NM1,
+}
+/// Batch status with accepted/rejected counts (`QTY`) and totals (`AMT`)
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2200B {
+    pub trn: TRN,
+    pub stc: Vec<STC>,
+    pub qty: Vec<QTY>,
+    pub amt: Vec<AMT>,
+}
+
+/// Billing Provider of Service Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2000C {
+    pub hl: HL,
+    pub loop_2100c: _277CALoop2100C,
+    pub loop_2200c: Option<_277CALoop2200C>,
+    pub loop_2000d: Vec<_277CALoop2000D>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2100C {
+    pub nm1: NM1,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2200C {
+    pub trn: TRN,
+    pub stc: Vec<STC>,
+    pub r#ref: Vec<REF>,
+    pub qty: Vec<QTY>,
+    pub amt: Vec<AMT>,
+}
+
+/// Patient Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2000D {
+    pub hl: HL,
+    pub loop_2100d: _277CALoop2100D,
+    pub loop_2200d: Vec<_277CALoop2200D>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2100D {
+    pub nm1: NM1,
+}
+/// Claim Status Tracking
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2200D {
+    pub trn: TRN,
+    pub stc: Vec<STC>,
+    pub r#ref: Vec<REF>,
+    pub dtp: Vec<DTP>,
+    pub loop_2220d: Vec<_277CALoop2220D>,
+}
+/// Service Line Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _277CALoop2220D {
+    pub svc: SVC,
+    pub stc: Vec<STC>,
+    pub r#ref: Vec<REF>,
+    pub dtp: Vec<DTP>,
+}
+
+/// Claim status categories (`STC01-1`, `STC10-1`, `STC11-1`) that reject a
+/// claim from adjudication.
+const REJECTED_CATEGORIES: [&str; 5] = ["A3", "A4", "A6", "A7", "A8"];
+
+fn is_rejection(stc: &STC) -> bool {
+    [Some(&stc._01), stc._10.as_ref(), stc._11.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|status| status.split(|c: char| !c.is_ascii_alphanumeric()).next())
+        .any(|category| REJECTED_CATEGORIES.contains(&category))
+}
+
+impl _277CALoop2200D {
+    /// The patient control number (`TRN02`) of the acknowledged claim.
+    pub fn patient_control_number(&self) -> &str {
+        &self.trn._02
+    }
+
+    /// `true` if any claim level `STC` carries a rejection category.
+    pub fn is_rejected(&self) -> bool {
+        self.stc.iter().any(is_rejection)
+    }
+}
+
+/// One acknowledged claim with the 837 claim it refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct _277CAClaimMatch<'a> {
+    pub status: &'a _277CALoop2200D,
+    /// `None` if no claim in the 837 has this patient control number.
+    pub claim: Option<&'a _837Loop2300>,
+}
+
+/// Outcome of [`_277CA::match_claims`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _277CAReport<'a> {
+    pub accepted: Vec<_277CAClaimMatch<'a>>,
+    pub rejected: Vec<_277CAClaimMatch<'a>>,
+    /// Claims of the 837 without an acknowledgment.
+    pub unacknowledged: Vec<&'a _837Loop2300>,
+}
+
+impl _277CA {
+    /// All claim level acknowledgments (2200D) in document order.
+    pub fn claim_statuses(&self) -> impl Iterator<Item = &_277CALoop2200D> {
+        self.loop_2000a
+            .iter()
+            .flat_map(|a| &a.loop_2000b)
+            .flat_map(|b| &b.loop_2000c)
+            .flat_map(|c| &c.loop_2000d)
+            .flat_map(|d| &d.loop_2200d)
+    }
+
+    /// Matches each 2200D acknowledgment to the claim of `submission` with the
+    /// same patient control number (`CLM01`) and splits them into accepted and
+    /// rejected claims.
+    pub fn match_claims<'a>(&'a self, submission: &'a _837) -> _277CAReport<'a> {
+        let claims: Vec<&_837Loop2300> = submission.claim_loops().collect();
+        let mut by_control_number: HashMap<&str, &_837Loop2300> = HashMap::new();
+        for claim in &claims {
+            by_control_number.entry(&claim.clm._01).or_insert(claim);
+        }
+        let mut acknowledged = HashSet::new();
+        let mut report = _277CAReport::default();
+        for status in self.claim_statuses() {
+            let control_number = status.patient_control_number();
+            acknowledged.insert(control_number);
+            let claim = by_control_number.get(control_number).copied();
+            let matched = _277CAClaimMatch { status, claim };
+            if status.is_rejected() {
+                report.rejected.push(matched);
+            } else {
+                report.accepted.push(matched);
+            }
+        }
+        report.unacknowledged = claims
+            .into_iter()
+            .filter(|claim| !acknowledged.contains(claim.clm._01.as_str()))
+            .collect();
+        report
+    }
+}
+
+impl<'a> Parser<&'a str, _277CA, nom::error::Error<&'a str>> for _277CA {
+    fn parse(input: &'a str) -> IResult<&'a str, _277CA> {
+        parse_277ca(input)
+    }
+}
+
+// Top-level 277CA parser
+pub fn parse_277ca(input: &str) -> IResult<&str, _277CA> {
+    trace!("enter parse_277ca");
+    let (rest, st) = ST::parse(input)?;
+
+    if st._01 != "277" {
+        error!(
+            "ST segment declares {} document instead of expected 277",
+            st._01
+        );
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            "ST segment does not declare an EDI 277",
+            nom::error::ErrorKind::Fail,
+        )));
+    }
+
+    let (rest, bht) = BHT::parse(rest)?;
+    let (rest, loop_2000a) = many0(parse_loop_2000_a).parse(rest)?;
+    let (rest, se) = SE::parse(rest)?;
+    trace!("exit parse_277ca");
+    Ok((
+        rest,
+        _277CA {
+            st,
+            bht,
+            loop_2000a,
+            se,
+        },
+    ))
+}
+
+fn parse_loop_2000_a(input: &str) -> IResult<&str, _277CALoop2000A> {
+    trace!("enter parse_loop_2000A");
+    let (rest, hl) = parse_hl_level(input, "20")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, trn) = TRN::parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, loop_2000b) = many0(parse_loop_2000_b).parse(rest)?;
+    trace!("exit parse_loop_2000A");
+    Ok((
+        rest,
+        _277CALoop2000A {
+            hl,
+            loop_2100a: _277CALoop2100A { nm1 },
+            loop_2200a: _277CALoop2200A { trn, dtp },
+            loop_2000b,
+        },
+    ))
+}
+
+fn parse_loop_2000_b(input: &str) -> IResult<&str, _277CALoop2000B> {
+    trace!("enter parse_loop_2000B");
+    let (rest, hl) = parse_hl_level(input, "21")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, trn) = TRN::parse(rest)?;
+    let (rest, stc) = many0(STC::parse).parse(rest)?;
+    let (rest, qty) = many0(QTY::parse).parse(rest)?;
+    let (rest, amt) = many0(AMT::parse).parse(rest)?;
+    let (rest, loop_2000c) = many0(parse_loop_2000_c).parse(rest)?;
+    trace!("exit parse_loop_2000B");
+    Ok((
+        rest,
+        _277CALoop2000B {
+            hl,
+            loop_2100b: _277CALoop2100B { nm1 },
+            loop_2200b: _277CALoop2200B { trn, stc, qty, amt },
+            loop_2000c,
+        },
+    ))
+}
+
+fn parse_loop_2000_c(input: &str) -> IResult<&str, _277CALoop2000C> {
+    trace!("enter parse_loop_2000C");
+    let (rest, hl) = parse_hl_level(input, "19")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, loop_2200c) = opt(parse_loop_2200_c).parse(rest)?;
+    let (rest, loop_2000d) = many0(parse_loop_2000_d).parse(rest)?;
+    trace!("exit parse_loop_2000C");
+    Ok((
+        rest,
+        _277CALoop2000C {
+            hl,
+            loop_2100c: _277CALoop2100C { nm1 },
+            loop_2200c,
+            loop_2000d,
+        },
+    ))
+}
+
+fn parse_loop_2200_c(input: &str) -> IResult<&str, _277CALoop2200C> {
+    let (rest, trn) = TRN::parse(input)?;
+    let (rest, stc) = many0(STC::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, qty) = many0(QTY::parse).parse(rest)?;
+    let (rest, amt) = many0(AMT::parse).parse(rest)?;
+    Ok((
+        rest,
+        _277CALoop2200C {
+            trn,
+            stc,
+            r#ref: rref,
+            qty,
+            amt,
+        },
+    ))
+}
+
+fn parse_loop_2000_d(input: &str) -> IResult<&str, _277CALoop2000D> {
+    trace!("enter parse_loop_2000D");
+    let (rest, hl) = parse_hl_level(input, "PT")?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, loop_2200d) = many0(parse_loop_2200_d).parse(rest)?;
+    trace!("exit parse_loop_2000D");
+    Ok((
+        rest,
+        _277CALoop2000D {
+            hl,
+            loop_2100d: _277CALoop2100D { nm1 },
+            loop_2200d,
+        },
+    ))
+}
+
+fn parse_loop_2200_d(input: &str) -> IResult<&str, _277CALoop2200D> {
+    let (rest, trn) = TRN::parse(input)?;
+    let (rest, stc) = many0(STC::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, loop_2220d) = many0(parse_loop_2220_d).parse(rest)?;
+    Ok((
+        rest,
+        _277CALoop2200D {
+            trn,
+            stc,
+            r#ref: rref,
+            dtp,
+            loop_2220d,
+        },
+    ))
+}
+
+fn parse_loop_2220_d(input: &str) -> IResult<&str, _277CALoop2220D> {
+    let (rest, svc) = SVC::parse(input)?;
+    let (rest, stc) = many0(STC::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    Ok((
+        rest,
+        _277CALoop2220D {
+            svc,
+            stc,
+            r#ref: rref,
+            dtp,
+        },
+    ))
+}
@@ -0,0 +1,393 @@
+pub use super::segment::*;
+use log::{error, trace};
+use serde::{Deserialize, Serialize};
+use x12_types_macros::DisplayX12;
+
+use super::_270_doc::parse_hl_level;
+use crate::util::Parser;
+use nom::{combinator::opt, multi::many0, IResult, Parser as _};
+
+/// 278 - Health Care Services Review Request for Review and Response (005010X217)
+///
+/// Request (`BHT02` = `13`) and response (`BHT02` = `11`) share this type; the
+/// response-only segments (`AAA`, `HCR`) are optional.
+///
+/// The HL loops are nested: utilization management organization (2000A),
+/// requester (2000B), subscriber (2000C), dependent (2000D), patient event
+/// (2000E) and service (2000F). The patient event level hangs off the
+/// dependent if there is one, otherwise off the subscriber.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278 {
+    pub st: ST,
+    pub bht: BHT,
+    pub loop_2000a: Vec<_278Loop2000A>,
+    pub se: SE,
+}
+
+impl _278 {
+    /// `true` for a response (`BHT02` = `11`), `false` for a request.
+    pub fn is_response(&self) -> bool {
+        self.bht._02 == "11"
+    }
+}
+
+/// Utilization Management Organization (UMO) Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000A {
+    pub hl: HL,
+    pub aaa: Vec<AAA>,
+    pub loop_2010a: _278Loop2010A,
+    pub loop_2000b: Vec<_278Loop2000B>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2010A {
+    pub nm1: NM1,
+    pub per: Vec<PER>,
+    pub aaa: Vec<AAA>,
+}
+
+/// Requester Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000B {
+    pub hl: HL,
+    pub loop_2010b: _278Loop2010B,
+    pub loop_2000c: Vec<_278Loop2000C>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2010B {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub per: Option<PER>,
+    pub aaa: Vec<AAA>,
+    pub prv: Option<PRV>,
+}
+
+/// Subscriber Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000C {
+    pub hl: HL,
+    pub loop_2010c: _278Loop2010C,
+    pub loop_2000d: Vec<_278Loop2000D>,
+    pub loop_2000e: Vec<_278Loop2000E>,
+}
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2010C {
+    pub nm1: NM1,
+    pub r#ref: Vec<REF>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub aaa: Vec<AAA>,
+    pub dmg: Option<DMG>,
+    pub ins: Option<INS>,
+}
+
+/// Dependent Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000D {
+    pub hl: HL,
+    pub loop_2010d: _278Loop2010D,
+    pub loop_2000e: Vec<_278Loop2000E>,
+}
+pub type _278Loop2010D = _278Loop2010C;
+
+/// Patient Event Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000E {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub aaa: Vec<AAA>,
+    pub um: UM,
+    pub hcr: Option<HCR>,
+    pub r#ref: Vec<REF>,
+    pub dtp: Vec<DTP>,
+    pub hi: Option<HI>,
+    pub hsd: Option<HSD>,
+    pub crc: Vec<CRC>,
+    pub cl1: Option<CL1>,
+    pub cr1: Option<CR1>,
+    pub cr2: Option<CR2>,
+    pub cr4: Option<CR4>,
+    pub cr5: Option<CR5>,
+    pub cr6: Option<CR6>,
+    pub cr7: Option<CR7>,
+    pub pwk: Vec<PWK>,
+    pub msg: Option<MSG>,
+    pub loop_2010e: Vec<_278Loop2010E>,
+    pub loop_2000f: Vec<_278Loop2000F>,
+}
+pub type _278Loop2010E = _278Loop2010B;
+
+/// Service Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _278Loop2000F {
+    pub hl: HL,
+    pub trn: Vec<TRN>,
+    pub aaa: Vec<AAA>,
+    pub um: UM,
+    pub hcr: Option<HCR>,
+    pub r#ref: Vec<REF>,
+    pub dtp: Vec<DTP>,
+    pub sv1: Option<SV1>,
+    pub sv2: Option<SV2>,
+    pub sv3: Option<SV3>,
+    pub too: Vec<TOO>,
+    pub hsd: Option<HSD>,
+    pub cr3: Option<CR3>,
+    pub pwk: Vec<PWK>,
+    pub msg: Option<MSG>,
+    pub loop_2010f: Vec<_278Loop2010F>,
+}
+pub type _278Loop2010F = _278Loop2010B;
+
+impl<'a> Parser<&'a str, _278, nom::error::Error<&'a str>> for _278 {
+    fn parse(input: &'a str) -> IResult<&'a str, _278> {
+        parse_278(input)
+    }
+}
+
+// Top-level 278 parser
+pub fn parse_278(input: &str) -> IResult<&str, _278> {
+    trace!("enter parse_278");
+    let (rest, st) = ST::parse(input)?;
+
+    if st._01 != "278" {
+        error!(
+            "ST segment declares {} document instead of expected 278",
+            st._01
+        );
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            "ST segment does not declare an EDI 278",
+            nom::error::ErrorKind::Fail,
+        )));
+    }
+
+    let (rest, bht) = BHT::parse(rest)?;
+    let (rest, loop_2000a) = many0(parse_loop_2000_a).parse(rest)?;
+    let (rest, se) = SE::parse(rest)?;
+    trace!("exit parse_278");
+    Ok((
+        rest,
+        _278 {
+            st,
+            bht,
+            loop_2000a,
+            se,
+        },
+    ))
+}
+
+fn parse_loop_2000_a(input: &str) -> IResult<&str, _278Loop2000A> {
+    trace!("enter parse_loop_2000A");
+    let (rest, hl) = parse_hl_level(input, "20")?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, nm1) = NM1::parse(rest)?;
+    let (rest, per) = many0(PER::parse).parse(rest)?;
+    let (rest, aaa_2010a) = many0(AAA::parse).parse(rest)?;
+    let (rest, loop_2000b) = many0(parse_loop_2000_b).parse(rest)?;
+    trace!("exit parse_loop_2000A");
+    Ok((
+        rest,
+        _278Loop2000A {
+            hl,
+            aaa,
+            loop_2010a: _278Loop2010A {
+                nm1,
+                per,
+                aaa: aaa_2010a,
+            },
+            loop_2000b,
+        },
+    ))
+}
+
+fn parse_loop_2000_b(input: &str) -> IResult<&str, _278Loop2000B> {
+    trace!("enter parse_loop_2000B");
+    let (rest, hl) = parse_hl_level(input, "21")?;
+    let (rest, loop_2010b) = parse_loop_2010_provider(rest)?;
+    let (rest, loop_2000c) = many0(parse_loop_2000_c).parse(rest)?;
+    trace!("exit parse_loop_2000B");
+    Ok((
+        rest,
+        _278Loop2000B {
+            hl,
+            loop_2010b,
+            loop_2000c,
+        },
+    ))
+}
+
+fn parse_loop_2000_c(input: &str) -> IResult<&str, _278Loop2000C> {
+    trace!("enter parse_loop_2000C");
+    let (rest, hl) = parse_hl_level(input, "22")?;
+    let (rest, loop_2010c) = parse_loop_2010_member(rest)?;
+    let (rest, loop_2000d) = many0(parse_loop_2000_d).parse(rest)?;
+    let (rest, loop_2000e) = many0(parse_loop_2000_e).parse(rest)?;
+    trace!("exit parse_loop_2000C");
+    Ok((
+        rest,
+        _278Loop2000C {
+            hl,
+            loop_2010c,
+            loop_2000d,
+            loop_2000e,
+        },
+    ))
+}
+
+fn parse_loop_2000_d(input: &str) -> IResult<&str, _278Loop2000D> {
+    trace!("enter parse_loop_2000D");
+    let (rest, hl) = parse_hl_level(input, "23")?;
+    let (rest, loop_2010d) = parse_loop_2010_member(rest)?;
+    let (rest, loop_2000e) = many0(parse_loop_2000_e).parse(rest)?;
+    trace!("exit parse_loop_2000D");
+    Ok((
+        rest,
+        _278Loop2000D {
+            hl,
+            loop_2010d,
+            loop_2000e,
+        },
+    ))
+}
+
+fn parse_loop_2000_e(input: &str) -> IResult<&str, _278Loop2000E> {
+    trace!("enter parse_loop_2000E");
+    let (rest, hl) = parse_hl_level(input, "EV")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, um) = UM::parse(rest)?;
+    let (rest, hcr) = opt(HCR::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, hi) = opt(HI::parse).parse(rest)?;
+    let (rest, hsd) = opt(HSD::parse).parse(rest)?;
+    let (rest, crc) = many0(CRC::parse).parse(rest)?;
+    let (rest, cl1) = opt(CL1::parse).parse(rest)?;
+    let (rest, cr1) = opt(CR1::parse).parse(rest)?;
+    let (rest, cr2) = opt(CR2::parse).parse(rest)?;
+    let (rest, cr4) = opt(CR4::parse).parse(rest)?;
+    let (rest, cr5) = opt(CR5::parse).parse(rest)?;
+    let (rest, cr6) = opt(CR6::parse).parse(rest)?;
+    let (rest, cr7) = opt(CR7::parse).parse(rest)?;
+    let (rest, pwk) = many0(PWK::parse).parse(rest)?;
+    let (rest, msg) = opt(MSG::parse).parse(rest)?;
+    let (rest, loop_2010e) = many0(parse_loop_2010_provider).parse(rest)?;
+    let (rest, loop_2000f) = many0(parse_loop_2000_f).parse(rest)?;
+    trace!("exit parse_loop_2000E");
+    Ok((
+        rest,
+        _278Loop2000E {
+            hl,
+            trn,
+            aaa,
+            um,
+            hcr,
+            r#ref: rref,
+            dtp,
+            hi,
+            hsd,
+            crc,
+            cl1,
+            cr1,
+            cr2,
+            cr4,
+            cr5,
+            cr6,
+            cr7,
+            pwk,
+            msg,
+            loop_2010e,
+            loop_2000f,
+        },
+    ))
+}
+
+fn parse_loop_2000_f(input: &str) -> IResult<&str, _278Loop2000F> {
+    trace!("enter parse_loop_2000F");
+    let (rest, hl) = parse_hl_level(input, "SS")?;
+    let (rest, trn) = many0(TRN::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, um) = UM::parse(rest)?;
+    let (rest, hcr) = opt(HCR::parse).parse(rest)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, dtp) = many0(DTP::parse).parse(rest)?;
+    let (rest, sv1) = opt(SV1::parse).parse(rest)?;
+    let (rest, sv2) = opt(SV2::parse).parse(rest)?;
+    let (rest, sv3) = opt(SV3::parse).parse(rest)?;
+    let (rest, too) = many0(TOO::parse).parse(rest)?;
+    let (rest, hsd) = opt(HSD::parse).parse(rest)?;
+    let (rest, cr3) = opt(CR3::parse).parse(rest)?;
+    let (rest, pwk) = many0(PWK::parse).parse(rest)?;
+    let (rest, msg) = opt(MSG::parse).parse(rest)?;
+    let (rest, loop_2010f) = many0(parse_loop_2010_provider).parse(rest)?;
+    trace!("exit parse_loop_2000F");
+    Ok((
+        rest,
+        _278Loop2000F {
+            hl,
+            trn,
+            aaa,
+            um,
+            hcr,
+            r#ref: rref,
+            dtp,
+            sv1,
+            sv2,
+            sv3,
+            too,
+            hsd,
+            cr3,
+            pwk,
+            msg,
+            loop_2010f,
+        },
+    ))
+}
+
+// 2010B, 2010E and 2010F share their layout
+fn parse_loop_2010_provider(input: &str) -> IResult<&str, _278Loop2010B> {
+    let (rest, nm1) = NM1::parse(input)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, per) = opt(PER::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    Ok((
+        rest,
+        _278Loop2010B {
+            nm1,
+            r#ref: rref,
+            n3,
+            n4,
+            per,
+            aaa,
+            prv,
+        },
+    ))
+}
+
+// 2010C and 2010D share their layout
+fn parse_loop_2010_member(input: &str) -> IResult<&str, _278Loop2010C> {
+    let (rest, nm1) = NM1::parse(input)?;
+    let (rest, rref) = many0(REF::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, aaa) = many0(AAA::parse).parse(rest)?;
+    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+    let (rest, ins) = opt(INS::parse).parse(rest)?;
+    Ok((
+        rest,
+        _278Loop2010C {
+            nm1,
+            r#ref: rref,
+            n3,
+            n4,
+            aaa,
+            dmg,
+            ins,
+        },
+    ))
+}
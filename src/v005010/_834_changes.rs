@@ -0,0 +1,466 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+
+use super::{_834Loop2000, _834Loop2300, _834, DTP, HD, INS};
+use crate::util::builder::segment_count;
+use crate::util::non_empty;
+
+/// BGN08 of a change (update) file
+const ACTION_CHANGE: &str = "2";
+
+/// CCYYMMDD, the `D8` date format
+const DATE_FORMAT: &str = "%Y%m%d";
+
+// INS04 maintenance reason codes
+const TERMINATION_OF_BENEFITS: &str = "07";
+const PLAN_CHANGE: &str = "22";
+const CHANGE_IN_IDENTIFYING_DATA: &str = "25";
+const INITIAL_ENROLLMENT: &str = "28";
+const RE_ENROLLMENT: &str = "41";
+
+/// INS03 / HD01 maintenance type code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum _834Maintenance {
+    /// `021`
+    Addition,
+    /// `001`
+    Change,
+    /// `024`, cancellation or termination
+    Termination,
+    /// `025`
+    Reinstatement,
+}
+
+impl _834Maintenance {
+    pub fn code(&self) -> &'static str {
+        match self {
+            _834Maintenance::Addition => "021",
+            _834Maintenance::Change => "001",
+            _834Maintenance::Termination => "024",
+            _834Maintenance::Reinstatement => "025",
+        }
+    }
+}
+
+/// Identifies a member across files: the subscriber identifier (`REF*0F`) and
+/// the member identifier (NM109), or name and birth date without one.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct _834MemberKey {
+    /// REF02 of `REF*0F`
+    pub subscriber_id: String,
+    /// NM109, or NM103, NM104 and DMG02 joined by `|`
+    pub member: String,
+}
+
+/// Identifies a coverage (2300) of a member: HD03 and HD04.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct _834CoverageKey {
+    /// HD03, insurance line code, e.g. `HLT`
+    pub insurance_line: Option<String>,
+    /// HD04, plan coverage description
+    pub plan: Option<String>,
+}
+
+/// A coverage that was added, changed, terminated or reinstated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct _834CoverageChange<'a> {
+    pub key: _834CoverageKey,
+    pub maintenance: _834Maintenance,
+    pub previous: Option<&'a _834Loop2300>,
+    pub current: Option<&'a _834Loop2300>,
+}
+
+/// A member that was added, changed, terminated or reinstated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct _834MemberChange<'a> {
+    pub key: _834MemberKey,
+    pub maintenance: _834Maintenance,
+    /// INS04 maintenance reason code
+    pub reason: &'static str,
+    pub previous: Option<&'a _834Loop2000>,
+    pub current: Option<&'a _834Loop2000>,
+    /// Only the coverages that differ, all of them for additions and terminations.
+    pub coverages: Vec<_834CoverageChange<'a>>,
+}
+
+/// Differences between two full-file 834s, see [`_834::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct _834ChangeSet<'a> {
+    pub previous: &'a _834,
+    pub current: &'a _834,
+    /// Members of the current file in document order, followed by the
+    /// members that are only in the previous file.
+    pub members: Vec<_834MemberChange<'a>>,
+}
+
+impl _834 {
+    /// Computes the adds, terms, changes and reinstatements of members and
+    /// their coverages from this full file to `current`.
+    ///
+    /// A coverage with a benefit end date (`DTP*349`) on or before the date of
+    /// its file (`BGN03`) counts as terminated, a member as terminated once
+    /// all of their coverages are.
+    pub fn diff<'a>(&'a self, current: &'a _834) -> _834ChangeSet<'a> {
+        let dates = FileDates {
+            previous: parse_date(&self.bgn._03),
+            current: parse_date(&current.bgn._03),
+        };
+        let previous_members: Vec<(_834MemberKey, &_834Loop2000)> =
+            self.loop_2000.iter().map(|m| (member_key(m), m)).collect();
+        let mut by_key: HashMap<_834MemberKey, &_834Loop2000> = HashMap::new();
+        for (key, member) in &previous_members {
+            by_key.entry(key.clone()).or_insert(member);
+        }
+        let mut members = vec![];
+        let mut seen = HashSet::new();
+        for member in &current.loop_2000 {
+            let key = member_key(member);
+            let change = match by_key.get(&key) {
+                Some(previous) => {
+                    seen.insert(key.clone());
+                    member_change(key, previous, member, dates)
+                }
+                None => Some(_834MemberChange {
+                    coverages: member
+                        .loop_2300
+                        .iter()
+                        .map(|c| _834CoverageChange {
+                            key: coverage_key(c),
+                            maintenance: _834Maintenance::Addition,
+                            previous: None,
+                            current: Some(c),
+                        })
+                        .collect(),
+                    key,
+                    maintenance: _834Maintenance::Addition,
+                    reason: INITIAL_ENROLLMENT,
+                    previous: None,
+                    current: Some(member),
+                }),
+            };
+            members.extend(change);
+        }
+        for (key, member) in previous_members {
+            if seen.contains(&key) {
+                continue;
+            }
+            members.push(_834MemberChange {
+                coverages: member
+                    .loop_2300
+                    .iter()
+                    .map(|c| _834CoverageChange {
+                        key: coverage_key(c),
+                        maintenance: _834Maintenance::Termination,
+                        previous: Some(c),
+                        current: None,
+                    })
+                    .collect(),
+                key,
+                maintenance: _834Maintenance::Termination,
+                reason: TERMINATION_OF_BENEFITS,
+                previous: Some(member),
+                current: None,
+            });
+        }
+        _834ChangeSet {
+            previous: self,
+            current,
+            members,
+        }
+    }
+}
+
+impl<'a> _834ChangeSet<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// The member changes with the given maintenance type.
+    pub fn with(
+        &self,
+        maintenance: _834Maintenance,
+    ) -> impl Iterator<Item = &_834MemberChange<'a>> {
+        self.members
+            .iter()
+            .filter(move |m| m.maintenance == maintenance)
+    }
+
+    /// A change-mode 834 (`BGN08` = `2`) with the header and sponsor/payer
+    /// loops of the current file and one member loop per change, with INS03,
+    /// INS04 and HD01 filled in. Terminated coverages end on the date of the
+    /// current file (`BGN03`) unless they carry their own `DTP*349`. `SE01` is
+    /// recounted.
+    pub fn to_834(&self) -> _834 {
+        let current = self.current;
+        let termination_date = &current.bgn._03;
+        let mut output = _834 {
+            st: current.st.clone(),
+            bgn: current.bgn.clone(),
+            r#ref: current.r#ref.clone(),
+            dtp: current.dtp.clone(),
+            amt: current.amt.clone(),
+            qty: current.qty.clone(),
+            loop_1000: current.loop_1000.clone(),
+            loop_2000: self
+                .members
+                .iter()
+                .map(|m| m.to_loop(termination_date))
+                .collect(),
+            se: current.se.clone(),
+        };
+        output.bgn._08 = Some(ACTION_CHANGE.to_string());
+        output.se._01 = segment_count(&output).to_string();
+        output
+    }
+}
+
+impl _834MemberChange<'_> {
+    /// The member loop of this change as it appears in a change-mode 834.
+    ///
+    /// Terminated coverages without a benefit end date get a `DTP*349` with
+    /// `termination_date` (CCYYMMDD).
+    pub fn to_loop(&self, termination_date: &str) -> _834Loop2000 {
+        let Some(source) = self.current.or(self.previous) else {
+            return _834Loop2000::default();
+        };
+        let mut member = _834Loop2000 {
+            loop_2300: self
+                .coverages
+                .iter()
+                .filter_map(|c| {
+                    let mut coverage = c.current.or(c.previous)?.clone();
+                    if let Some(hd) = coverage.hd.as_mut() {
+                        hd._01 = c.maintenance.code().to_string();
+                    }
+                    let ended = coverage.dtp.iter().any(|d| d._01 == BENEFIT_END);
+                    if c.maintenance == _834Maintenance::Termination && !ended {
+                        coverage.dtp.push(DTP {
+                            _01: BENEFIT_END.to_string(),
+                            _02: "D8".to_string(),
+                            _03: termination_date.to_string(),
+                        });
+                    }
+                    Some(coverage)
+                })
+                .collect(),
+            ..source.clone()
+        };
+        if let Some(ins) = member.ins.as_mut() {
+            ins._03 = Some(self.maintenance.code().to_string());
+            ins._04 = Some(self.reason.to_string());
+        }
+        member
+    }
+}
+
+/// The dates (`BGN03`) of the two files, benefit end dates are compared
+/// against the date of the file they are in.
+#[derive(Clone, Copy)]
+struct FileDates {
+    previous: Option<NaiveDate>,
+    current: Option<NaiveDate>,
+}
+
+fn member_change<'a>(
+    key: _834MemberKey,
+    previous: &'a _834Loop2000,
+    current: &'a _834Loop2000,
+    dates: FileDates,
+) -> Option<_834MemberChange<'a>> {
+    let coverages = coverage_changes(previous, current, dates);
+    let demographics = !same_demographics(previous, current);
+    let was_terminated = is_terminated(previous, dates.previous);
+    let terminated = is_terminated(current, dates.current);
+    let (maintenance, reason) = if was_terminated && !terminated {
+        (_834Maintenance::Reinstatement, RE_ENROLLMENT)
+    } else if !was_terminated && terminated {
+        (_834Maintenance::Termination, TERMINATION_OF_BENEFITS)
+    } else if demographics {
+        (_834Maintenance::Change, CHANGE_IN_IDENTIFYING_DATA)
+    } else if !coverages.is_empty() {
+        (_834Maintenance::Change, PLAN_CHANGE)
+    } else {
+        return None;
+    };
+    Some(_834MemberChange {
+        key,
+        maintenance,
+        reason,
+        previous: Some(previous),
+        current: Some(current),
+        coverages,
+    })
+}
+
+fn coverage_changes<'a>(
+    previous: &'a _834Loop2000,
+    current: &'a _834Loop2000,
+    dates: FileDates,
+) -> Vec<_834CoverageChange<'a>> {
+    let mut open: Vec<Option<&_834Loop2300>> = previous.loop_2300.iter().map(Some).collect();
+    let mut out = vec![];
+    for coverage in &current.loop_2300 {
+        let key = coverage_key(coverage);
+        let before = open
+            .iter()
+            .position(|c| c.is_some_and(|c| coverage_key(c) == key))
+            .and_then(|i| open[i].take());
+        let maintenance = match before {
+            None => Some(_834Maintenance::Addition),
+            Some(before) => {
+                let was_ended = is_ended(before, dates.previous);
+                let ended = is_ended(coverage, dates.current);
+                if was_ended && !ended {
+                    Some(_834Maintenance::Reinstatement)
+                } else if !was_ended && ended {
+                    Some(_834Maintenance::Termination)
+                } else if !same_coverage(before, coverage) {
+                    Some(_834Maintenance::Change)
+                } else {
+                    None
+                }
+            }
+        };
+        if let Some(maintenance) = maintenance {
+            out.push(_834CoverageChange {
+                key,
+                maintenance,
+                previous: before,
+                current: Some(coverage),
+            });
+        }
+    }
+    out.extend(open.into_iter().flatten().map(|before| _834CoverageChange {
+        key: coverage_key(before),
+        maintenance: _834Maintenance::Termination,
+        previous: Some(before),
+        current: None,
+    }));
+    out
+}
+
+fn member_key(member: &_834Loop2000) -> _834MemberKey {
+    let subscriber_id = member
+        .r#ref
+        .iter()
+        .find(|r| r._01 == "0F")
+        .and_then(|r| non_empty(&r._02))
+        .unwrap_or_default();
+    let name = member.loop_2100.iter().find_map(|l| l.nm1.as_ref());
+    let member_id = name.and_then(|nm1| non_empty(&nm1._09));
+    let member = member_id.unwrap_or_else(|| {
+        let birth_date = member
+            .loop_2100
+            .iter()
+            .find_map(|l| l.dmg.as_ref())
+            .and_then(|dmg| dmg._02.clone());
+        [
+            name.and_then(|nm1| nm1._03.clone()),
+            name.and_then(|nm1| nm1._04.clone()),
+            birth_date,
+        ]
+        .map(Option::unwrap_or_default)
+        .join("|")
+    });
+    _834MemberKey {
+        subscriber_id,
+        member,
+    }
+}
+
+fn coverage_key(coverage: &_834Loop2300) -> _834CoverageKey {
+    let hd = coverage.hd.as_ref();
+    _834CoverageKey {
+        insurance_line: hd.and_then(|hd| non_empty(&hd._03)),
+        plan: hd.and_then(|hd| non_empty(&hd._04)),
+    }
+}
+
+/// Benefit end date (`DTP*349`)
+const BENEFIT_END: &str = "349";
+
+/// A benefit end date on or before `as_of`. For a `RD8` range the end of the
+/// range counts; dates that do not parse never end a coverage.
+fn is_ended(coverage: &_834Loop2300, as_of: Option<NaiveDate>) -> bool {
+    let Some(as_of) = as_of else {
+        return false;
+    };
+    coverage
+        .dtp
+        .iter()
+        .filter(|d: &&DTP| d._01 == BENEFIT_END)
+        .filter_map(end_date)
+        .any(|end| end <= as_of)
+}
+
+/// The date of a `D8` DTP, or the last date of a `RD8` range.
+fn end_date(dtp: &DTP) -> Option<NaiveDate> {
+    match dtp._02.as_str() {
+        "RD8" => parse_date(dtp._03.split_once('-')?.1),
+        _ => parse_date(&dtp._03),
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok()
+}
+
+fn is_terminated(member: &_834Loop2000, as_of: Option<NaiveDate>) -> bool {
+    !member.loop_2300.is_empty() && member.loop_2300.iter().all(|c| is_ended(c, as_of))
+}
+
+/// Compares the members without their coverages and maintenance codes.
+fn same_demographics(previous: &_834Loop2000, current: &_834Loop2000) -> bool {
+    let same_ins = match (&previous.ins, &current.ins) {
+        (Some(a), Some(b)) => same_ins(a, b),
+        (a, b) => a == b,
+    };
+    same_ins
+        && previous.r#ref == current.r#ref
+        && previous.dtp == current.dtp
+        && previous.loop_2100 == current.loop_2100
+        && previous.loop_2200 == current.loop_2200
+        && previous.loop_2400 == current.loop_2400
+        && previous.loop_2500 == current.loop_2500
+        && previous.loop_2600 == current.loop_2600
+        && previous.ls == current.ls
+        && previous.loop_2700 == current.loop_2700
+        && previous.le == current.le
+}
+
+/// INS without the maintenance type and reason (INS03, INS04).
+fn same_ins(a: &INS, b: &INS) -> bool {
+    a._01 == b._01
+        && a._02 == b._02
+        && [
+            &a._05, &a._06, &a._07, &a._08, &a._09, &a._10, &a._11, &a._12, &a._13, &a._14, &a._15,
+            &a._16, &a._17,
+        ] == [
+            &b._05, &b._06, &b._07, &b._08, &b._09, &b._10, &b._11, &b._12, &b._13, &b._14, &b._15,
+            &b._16, &b._17,
+        ]
+}
+
+/// Compares the coverages without their maintenance type (HD01).
+fn same_coverage(previous: &_834Loop2300, current: &_834Loop2300) -> bool {
+    let same_hd = match (&previous.hd, &current.hd) {
+        (Some(a), Some(b)) => same_hd(a, b),
+        (a, b) => a == b,
+    };
+    same_hd
+        && previous.dtp == current.dtp
+        && previous.amt == current.amt
+        && previous.r#ref == current.r#ref
+        && previous.idc == current.idc
+        && previous.loop_2310 == current.loop_2310
+        && previous.loop_2320 == current.loop_2320
+}
+
+fn same_hd(a: &HD, b: &HD) -> bool {
+    [
+        &a._02, &a._03, &a._04, &a._05, &a._06, &a._07, &a._08, &a._09, &a._10, &a._11,
+    ] == [
+        &b._02, &b._03, &b._04, &b._05, &b._06, &b._07, &b._08, &b._09, &b._10, &b._11,
+    ]
+}
@@ -0,0 +1,574 @@
+use chrono::{NaiveDate, ParseError};
+
+use super::{
+    _834Loop2000, _834Loop2100, _834Loop2300, _834Loop2310, _834Loop2320, _834Loop2330, _834, COB,
+    DMG, DTP, HD, INS, LX, N3, N4, NM1, REF,
+};
+use crate::util::builder::segment_count;
+use crate::util::non_empty;
+
+/// CCYYMMDD, the `D8` date format
+const DATE_FORMAT: &str = "%Y%m%d";
+
+/// A member (2000) with the name (2100A), coverages (2300), providers (2310)
+/// and coordination of benefits (2320/2330) of its loops resolved.
+///
+/// [`_834Member::update_loop`] writes the elements modelled here back into a
+/// member loop and keeps everything else of it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834Member {
+    /// INS01 = `Y`
+    pub subscriber: bool,
+    /// INS02 individual relationship code, `18` for the subscriber (self), e.g.
+    /// `01` spouse or `19` child
+    pub relationship: String,
+    /// INS03
+    pub maintenance_type: Option<String>,
+    /// INS04
+    pub maintenance_reason: Option<String>,
+    /// INS05 benefit status code
+    pub benefit_status: Option<String>,
+    /// INS08 employment status code
+    pub employment_status: Option<String>,
+    /// 2000 `REF`s, e.g. `0F` (subscriber number) or `1L` (group or policy number)
+    pub references: Vec<_834Reference>,
+    /// 2000 `DTP`s, e.g. `356` (eligibility begin)
+    pub dates: Vec<_834Date>,
+    /// NM101 of 2100A, `IL` (insured or subscriber) or `74` (corrected insured)
+    pub entity: String,
+    /// NM102, `1` person
+    pub entity_type: String,
+    /// NM103
+    pub last_name: Option<String>,
+    /// NM104
+    pub first_name: Option<String>,
+    /// NM105
+    pub middle_name: Option<String>,
+    /// NM108, `34` (SSN) or `ZZ` (mutually defined)
+    pub id_qualifier: Option<String>,
+    /// NM109
+    pub id: Option<String>,
+    /// N301
+    pub address: Option<String>,
+    /// N302
+    pub address_2: Option<String>,
+    /// N401
+    pub city: Option<String>,
+    /// N402
+    pub state: Option<String>,
+    /// N403
+    pub postal_code: Option<String>,
+    /// DMG02
+    pub birth_date: Option<NaiveDate>,
+    /// DMG03
+    pub gender: Option<String>,
+    pub coverages: Vec<_834Coverage>,
+}
+
+/// A `REF` qualifier and identifier.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834Reference {
+    /// REF01
+    pub qualifier: String,
+    /// REF02
+    pub value: String,
+}
+
+/// A `DTP` date (`D8`) or date range (`RD8`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834Date {
+    /// DTP01
+    pub qualifier: String,
+    pub from: NaiveDate,
+    /// end of an `RD8` range
+    pub to: Option<NaiveDate>,
+}
+
+/// A health coverage (2300).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834Coverage {
+    /// HD01
+    pub maintenance_type: String,
+    /// HD03 insurance line code, e.g. `HLT` or `DEN`
+    pub insurance_line: Option<String>,
+    /// HD04
+    pub plan: Option<String>,
+    /// HD05 coverage level code, e.g. `EMP` or `FAM`
+    pub coverage_level: Option<String>,
+    /// `DTP*348` benefit begin
+    pub effective: Option<NaiveDate>,
+    /// `DTP*349` benefit end
+    pub termination: Option<NaiveDate>,
+    /// the other 2300 `DTP`s
+    pub dates: Vec<_834Date>,
+    /// 2300 `REF`s
+    pub references: Vec<_834Reference>,
+    /// 2310 providers, see [`_834Coverage::pcps`]
+    pub providers: Vec<_834Provider>,
+    /// 2320 coordination of benefits
+    pub coordination_of_benefits: Vec<_834CoordinationOfBenefits>,
+}
+
+/// A provider (2310) or other insurance company (2330) from an `NM1`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834Provider {
+    /// LX01
+    pub line_number: Option<String>,
+    /// NM101, e.g. `P3` (primary care provider) or `IN` (insurer)
+    pub entity: String,
+    /// NM102, `1` person or `2` non-person
+    pub entity_type: String,
+    /// NM103, organization or last name
+    pub name: Option<String>,
+    /// NM104
+    pub first_name: Option<String>,
+    /// NM108, e.g. `XX` (NPI)
+    pub id_qualifier: Option<String>,
+    /// NM109
+    pub id: Option<String>,
+    /// NM110 entity relationship code, e.g. `25` established patient
+    pub relationship: Option<String>,
+}
+
+/// Other insurance of a coverage (2320).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct _834CoordinationOfBenefits {
+    /// COB01 payer responsibility sequence code, e.g. `P` primary
+    pub responsibility: Option<String>,
+    /// COB02
+    pub policy_number: Option<String>,
+    /// COB03 coordination of benefits code
+    pub code: Option<String>,
+    /// 2330 other insurance companies and administrators
+    pub insurers: Vec<_834Provider>,
+}
+
+impl _834 {
+    /// The member loops as [`_834Member`]s in document order.
+    pub fn members(&self) -> Result<Vec<_834Member>, ParseError> {
+        self.loop_2000.iter().map(_834Member::try_from).collect()
+    }
+
+    /// Writes `members` back into the member loops in document order, as
+    /// returned by [`_834::members`], and recounts `SE01`. Loops beyond the
+    /// last member are dropped, members beyond the last loop get a new one.
+    pub fn set_members(&mut self, members: &[_834Member]) {
+        let mut loops = std::mem::take(&mut self.loop_2000).into_iter();
+        self.loop_2000 = members
+            .iter()
+            .map(|member| {
+                let mut l = loops.next().unwrap_or_default();
+                member.update_loop(&mut l);
+                l
+            })
+            .collect();
+        self.se._01 = segment_count(self).to_string();
+    }
+}
+
+impl _834Member {
+    pub fn is_dependent(&self) -> bool {
+        !self.subscriber
+    }
+
+    /// REF02 of `REF*0F`
+    pub fn subscriber_id(&self) -> Option<&str> {
+        self.reference("0F")
+    }
+
+    /// NM109 if NM108 is `34`
+    pub fn ssn(&self) -> Option<&str> {
+        self.id
+            .as_deref()
+            .filter(|_| self.id_qualifier.as_deref() == Some("34"))
+    }
+
+    /// REF02 of the first `REF` with the given qualifier.
+    pub fn reference(&self, qualifier: &str) -> Option<&str> {
+        self.references
+            .iter()
+            .find(|r| r.qualifier == qualifier)
+            .map(|r| r.value.as_str())
+    }
+
+    /// Builds a new member loop (2000) from the view.
+    pub fn to_loop(&self) -> _834Loop2000 {
+        let mut l = _834Loop2000::default();
+        self.update_loop(&mut l);
+        l
+    }
+
+    /// Writes the view into an existing member loop. Segments and elements
+    /// that are not modelled, like the 2100B-H loops or `REF03`, are kept.
+    pub fn update_loop(&self, l: &mut _834Loop2000) {
+        let ins = l.ins.get_or_insert_with(INS::default);
+        ins._01 = if self.subscriber { "Y" } else { "N" }.to_string();
+        ins._02 = self.relationship.clone();
+        ins._03 = self.maintenance_type.clone();
+        ins._04 = self.maintenance_reason.clone();
+        ins._05 = self.benefit_status.clone();
+        ins._08 = self.employment_status.clone();
+        l.r#ref = merge_refs(std::mem::take(&mut l.r#ref), &self.references);
+        l.dtp = merge_dates(std::mem::take(&mut l.dtp), &self.dates);
+        let position = l.loop_2100.iter().position(is_member_name);
+        let name = match position {
+            Some(i) => &mut l.loop_2100[i],
+            None => {
+                l.loop_2100.insert(0, _834Loop2100::default());
+                &mut l.loop_2100[0]
+            }
+        };
+        self.update_name(name);
+        l.loop_2300 = merge(
+            std::mem::take(&mut l.loop_2300),
+            &self.coverages,
+            |l, c| l.hd.as_ref().and_then(|hd| hd._03.as_ref()) == c.insurance_line.as_ref(),
+            |l, c| c.update_loop(l),
+        );
+    }
+
+    /// The member name loop (2100A).
+    fn update_name(&self, name: &mut _834Loop2100) {
+        let nm1 = name.nm1.get_or_insert_with(NM1::default);
+        nm1._01 = self.entity.clone();
+        nm1._02 = self.entity_type.clone();
+        nm1._03 = self.last_name.clone();
+        nm1._04 = self.first_name.clone();
+        nm1._05 = self.middle_name.clone();
+        nm1._08 = self.id_qualifier.clone();
+        nm1._09 = self.id.clone();
+        name.n3 = self.address.clone().map(|address| N3 {
+            _01: address,
+            _02: self.address_2.clone(),
+        });
+        if self.city.is_some() || self.state.is_some() || self.postal_code.is_some() {
+            let n4 = name.n4.get_or_insert_with(N4::default);
+            n4._01 = self.city.clone();
+            n4._02 = self.state.clone();
+            n4._03 = self.postal_code.clone();
+        } else {
+            name.n4 = None;
+        }
+        if self.birth_date.is_some() || self.gender.is_some() {
+            let dmg = name.dmg.get_or_insert_with(DMG::default);
+            dmg._01 = self.birth_date.map(|_| "D8".to_string());
+            dmg._02 = self.birth_date.map(format_date);
+            dmg._03 = self.gender.clone();
+        } else {
+            name.dmg = None;
+        }
+    }
+}
+
+impl TryFrom<&_834Loop2000> for _834Member {
+    type Error = ParseError;
+
+    fn try_from(l: &_834Loop2000) -> Result<_834Member, ParseError> {
+        let ins = l.ins.clone().unwrap_or_default();
+        let name = l
+            .loop_2100
+            .iter()
+            .find(|n| is_member_name(n))
+            .cloned()
+            .unwrap_or_default();
+        let nm1 = name.nm1.unwrap_or_default();
+        let n4 = name.n4.unwrap_or_default();
+        let dmg = name.dmg.unwrap_or_default();
+        Ok(_834Member {
+            subscriber: ins._01 == "Y",
+            relationship: ins._02,
+            maintenance_type: non_empty(&ins._03),
+            maintenance_reason: non_empty(&ins._04),
+            benefit_status: non_empty(&ins._05),
+            employment_status: non_empty(&ins._08),
+            references: l.r#ref.iter().filter_map(_834Reference::new).collect(),
+            dates: l
+                .dtp
+                .iter()
+                .map(_834Date::try_from)
+                .collect::<Result<_, _>>()?,
+            entity: nm1._01,
+            entity_type: nm1._02,
+            last_name: non_empty(&nm1._03),
+            first_name: non_empty(&nm1._04),
+            middle_name: non_empty(&nm1._05),
+            id_qualifier: non_empty(&nm1._08),
+            id: non_empty(&nm1._09),
+            address: name.n3.as_ref().map(|n3| n3._01.clone()),
+            address_2: name.n3.as_ref().and_then(|n3| non_empty(&n3._02)),
+            city: non_empty(&n4._01),
+            state: non_empty(&n4._02),
+            postal_code: non_empty(&n4._03),
+            birth_date: non_empty(&dmg._02).map(|d| parse_date(&d)).transpose()?,
+            gender: non_empty(&dmg._03),
+            coverages: l
+                .loop_2300
+                .iter()
+                .map(_834Coverage::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl _834Coverage {
+    /// Primary care providers (`NM1*P3`).
+    pub fn pcps(&self) -> impl Iterator<Item = &_834Provider> {
+        self.providers.iter().filter(|p| p.entity == "P3")
+    }
+
+    pub fn to_loop(&self) -> _834Loop2300 {
+        let mut l = _834Loop2300::default();
+        self.update_loop(&mut l);
+        l
+    }
+
+    /// Writes the view into an existing coverage loop, keeping `AMT`, `IDC`
+    /// and the segments of the 2310 and 2320 loops that are not modelled.
+    pub fn update_loop(&self, l: &mut _834Loop2300) {
+        let hd = l.hd.get_or_insert_with(HD::default);
+        hd._01 = self.maintenance_type.clone();
+        hd._03 = self.insurance_line.clone();
+        hd._04 = self.plan.clone();
+        hd._05 = self.coverage_level.clone();
+        let benefit_dates: Vec<_834Date> = [("348", self.effective), ("349", self.termination)]
+            .into_iter()
+            .filter_map(|(qualifier, date)| {
+                date.map(|from| _834Date {
+                    qualifier: qualifier.to_string(),
+                    from,
+                    to: None,
+                })
+            })
+            .chain(self.dates.iter().cloned())
+            .collect();
+        l.dtp = merge_dates(std::mem::take(&mut l.dtp), &benefit_dates);
+        l.r#ref = merge_refs(std::mem::take(&mut l.r#ref), &self.references);
+        l.loop_2310 = merge(
+            std::mem::take(&mut l.loop_2310),
+            &self.providers,
+            |l, p| l.nm1.as_ref().is_some_and(|nm1| nm1._01 == p.entity),
+            |l, p| p.update_2310(l),
+        );
+        l.loop_2320 = merge(
+            std::mem::take(&mut l.loop_2320),
+            &self.coordination_of_benefits,
+            |l, c| l.cob.as_ref().and_then(|cob| cob._01.as_ref()) == c.responsibility.as_ref(),
+            |l, c| c.update_loop(l),
+        );
+    }
+}
+
+impl TryFrom<&_834Loop2300> for _834Coverage {
+    type Error = ParseError;
+
+    fn try_from(l: &_834Loop2300) -> Result<_834Coverage, ParseError> {
+        let hd = l.hd.clone().unwrap_or_default();
+        let mut coverage = _834Coverage {
+            maintenance_type: hd._01,
+            insurance_line: non_empty(&hd._03),
+            plan: non_empty(&hd._04),
+            coverage_level: non_empty(&hd._05),
+            references: l.r#ref.iter().filter_map(_834Reference::new).collect(),
+            providers: l.loop_2310.iter().map(_834Provider::from).collect(),
+            coordination_of_benefits: l
+                .loop_2320
+                .iter()
+                .map(_834CoordinationOfBenefits::from)
+                .collect(),
+            ..Default::default()
+        };
+        for dtp in &l.dtp {
+            let date = _834Date::try_from(dtp)?;
+            match date.qualifier.as_str() {
+                "348" if coverage.effective.is_none() => coverage.effective = Some(date.from),
+                "349" if coverage.termination.is_none() => coverage.termination = Some(date.from),
+                _ => coverage.dates.push(date),
+            }
+        }
+        Ok(coverage)
+    }
+}
+
+impl _834Provider {
+    fn update_nm1(&self, nm1: &mut NM1) {
+        nm1._01 = self.entity.clone();
+        nm1._02 = self.entity_type.clone();
+        nm1._03 = self.name.clone();
+        nm1._04 = self.first_name.clone();
+        nm1._08 = self.id_qualifier.clone();
+        nm1._09 = self.id.clone();
+        nm1._10 = self.relationship.clone();
+    }
+
+    fn update_2310(&self, l: &mut _834Loop2310) {
+        l.lx = self.line_number.clone().map(|n| LX { _01: n });
+        self.update_nm1(l.nm1.get_or_insert_with(NM1::default));
+    }
+
+    fn from_nm1(nm1: &NM1) -> _834Provider {
+        _834Provider {
+            line_number: None,
+            entity: nm1._01.clone(),
+            entity_type: nm1._02.clone(),
+            name: non_empty(&nm1._03),
+            first_name: non_empty(&nm1._04),
+            id_qualifier: non_empty(&nm1._08),
+            id: non_empty(&nm1._09),
+            relationship: non_empty(&nm1._10),
+        }
+    }
+}
+
+impl From<&_834Loop2310> for _834Provider {
+    fn from(l: &_834Loop2310) -> _834Provider {
+        _834Provider {
+            line_number: l.lx.as_ref().map(|lx| lx._01.clone()),
+            ..l.nm1
+                .as_ref()
+                .map(_834Provider::from_nm1)
+                .unwrap_or_default()
+        }
+    }
+}
+
+impl _834CoordinationOfBenefits {
+    fn update_loop(&self, l: &mut _834Loop2320) {
+        let cob = l.cob.get_or_insert_with(COB::default);
+        cob._01 = self.responsibility.clone();
+        cob._02 = self.policy_number.clone();
+        cob._03 = self.code.clone();
+        l.loop_2330 = merge(
+            std::mem::take(&mut l.loop_2330),
+            &self.insurers,
+            |l: &_834Loop2330, p| l.nm1.as_ref().is_some_and(|nm1| nm1._01 == p.entity),
+            |l, p| p.update_nm1(l.nm1.get_or_insert_with(NM1::default)),
+        );
+    }
+}
+
+impl From<&_834Loop2320> for _834CoordinationOfBenefits {
+    fn from(l: &_834Loop2320) -> _834CoordinationOfBenefits {
+        let cob = l.cob.clone().unwrap_or_default();
+        _834CoordinationOfBenefits {
+            responsibility: non_empty(&cob._01),
+            policy_number: non_empty(&cob._02),
+            code: non_empty(&cob._03),
+            insurers: l
+                .loop_2330
+                .iter()
+                .filter_map(|l| l.nm1.as_ref())
+                .map(_834Provider::from_nm1)
+                .collect(),
+        }
+    }
+}
+
+impl _834Reference {
+    fn new(r: &REF) -> Option<_834Reference> {
+        Some(_834Reference {
+            qualifier: r._01.clone(),
+            value: non_empty(&r._02)?,
+        })
+    }
+
+    fn update_ref(&self, r: &mut REF) {
+        r._01 = self.qualifier.clone();
+        r._02 = Some(self.value.clone());
+    }
+}
+
+impl _834Date {
+    fn to_dtp(&self) -> DTP {
+        let (format, period) = match self.to {
+            Some(to) => (
+                "RD8",
+                format!("{}-{}", format_date(self.from), format_date(to)),
+            ),
+            None => ("D8", format_date(self.from)),
+        };
+        DTP {
+            _01: self.qualifier.clone(),
+            _02: format.to_string(),
+            _03: period,
+        }
+    }
+}
+
+impl TryFrom<&DTP> for _834Date {
+    type Error = ParseError;
+
+    /// Reads `D8` and `RD8` periods, the other formats by their first eight digits.
+    fn try_from(dtp: &DTP) -> Result<_834Date, ParseError> {
+        let (from, to) = match dtp._03.split_once('-') {
+            Some((from, to)) if dtp._02 == "RD8" => (from, Some(to)),
+            _ => (dtp._03.get(..8).unwrap_or(&dtp._03), None),
+        };
+        Ok(_834Date {
+            qualifier: dtp._01.clone(),
+            from: parse_date(from)?,
+            to: to.map(parse_date).transpose()?,
+        })
+    }
+}
+
+/// The name loop (2100A) of the member itself.
+fn is_member_name(name: &_834Loop2100) -> bool {
+    name.nm1
+        .as_ref()
+        .is_some_and(|nm1| nm1._01 == "IL" || nm1._01 == "74")
+}
+
+/// Builds a repeated segment or loop from `views`. Each view updates the first
+/// unused item of `existing` it matches, or a new one, so the elements the
+/// view does not model are kept.
+fn merge<T: Default, V>(
+    existing: Vec<T>,
+    views: &[V],
+    matches: impl Fn(&T, &V) -> bool,
+    update: impl Fn(&mut T, &V),
+) -> Vec<T> {
+    let mut open: Vec<Option<T>> = existing.into_iter().map(Some).collect();
+    views
+        .iter()
+        .map(|view| {
+            let mut item = open
+                .iter_mut()
+                .find(|item| item.as_ref().is_some_and(|item| matches(item, view)))
+                .and_then(Option::take)
+                .unwrap_or_default();
+            update(&mut item, view);
+            item
+        })
+        .collect()
+}
+
+fn merge_refs(existing: Vec<REF>, references: &[_834Reference]) -> Vec<REF> {
+    merge(
+        existing,
+        references,
+        |r, reference| r._01 == reference.qualifier,
+        |r, reference| reference.update_ref(r),
+    )
+}
+
+/// Dates that did not change keep their original format, e.g. `DT`.
+fn merge_dates(existing: Vec<DTP>, dates: &[_834Date]) -> Vec<DTP> {
+    merge(
+        existing,
+        dates,
+        |dtp, date| dtp._01 == date.qualifier,
+        |dtp, date| {
+            if _834Date::try_from(&*dtp).ok().as_ref() != Some(date) {
+                *dtp = date.to_dtp();
+            }
+        },
+    )
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, ParseError> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT)
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
@@ -0,0 +1,388 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use super::{
+    _837Loop2000A, _837Loop2000B, _837Loop2000C, _837Loop2010, _837Loop2300, _837Loop2400,
+    Transmission, _837, NM1, REF,
+};
+use crate::util::amount::{Amount, AmountError};
+use crate::util::non_empty;
+
+/// CCYYMMDD, the `D8` date format
+const DATE_FORMAT: &str = "%Y%m%d";
+
+/// One claim (2300) with the providers and members of its HL levels resolved.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _837Claim {
+    /// CLM01
+    pub patient_control_number: String,
+    /// CLM02
+    pub total_charge: Amount,
+    /// CLM05-1
+    pub place_of_service: Option<String>,
+    /// CLM05-3
+    pub frequency_code: Option<String>,
+    /// 2010AA, `NM1*85`
+    pub billing_provider: Option<_837Provider>,
+    /// 2310B, `NM1*82`; the billing provider renders the service if missing
+    pub rendering_provider: Option<_837Provider>,
+    /// 2010BA, `NM1*IL`
+    pub subscriber: Option<_837Person>,
+    /// 2010CA, `NM1*QC`, or the subscriber if the claim is on the subscriber level
+    pub patient: Option<_837Person>,
+    /// 2010BB, `NM1*PR`
+    pub payer: Option<_837Provider>,
+    /// HI composites in document order
+    pub diagnoses: Vec<_837Diagnosis>,
+    pub service_lines: Vec<_837ServiceLine>,
+}
+
+/// A provider or payer from an `NM1` loop.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _837Provider {
+    /// NM103, organization or last name
+    pub name: Option<String>,
+    /// NM104
+    pub first_name: Option<String>,
+    /// NM109 if NM108 is `XX`
+    pub npi: Option<String>,
+    /// NM108
+    pub id_qualifier: Option<String>,
+    /// NM109
+    pub id: Option<String>,
+    /// REF02 of a `REF*EI` (EIN) or `REF*SY` (SSN)
+    pub tax_id: Option<String>,
+}
+
+/// A subscriber or patient from an `NM1` loop.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _837Person {
+    /// NM103
+    pub last_name: Option<String>,
+    /// NM104
+    pub first_name: Option<String>,
+    /// NM105
+    pub middle_name: Option<String>,
+    /// NM109
+    pub member_id: Option<String>,
+    /// DMG02
+    pub birth_date: Option<NaiveDate>,
+    /// DMG03
+    pub gender: Option<String>,
+    /// N301
+    pub address: Option<String>,
+    /// N401
+    pub city: Option<String>,
+    /// N402
+    pub state: Option<String>,
+    /// N403
+    pub postal_code: Option<String>,
+}
+
+/// One diagnosis code from an `HI` composite, e.g. `ABK:J45909`.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _837Diagnosis {
+    /// code list qualifier, e.g. `ABK` (ICD-10 principal) or `ABF` (ICD-10)
+    pub qualifier: String,
+    pub code: String,
+}
+
+/// A service line (2400) from `SV1` (professional) or `SV2` (institutional).
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _837ServiceLine {
+    /// LX01
+    pub line_number: String,
+    /// SV201
+    pub revenue_code: Option<String>,
+    /// SV101-1 / SV202-1
+    pub procedure_qualifier: Option<String>,
+    /// SV101-2 / SV202-2
+    pub procedure_code: Option<String>,
+    /// SV101-3..6 / SV202-3..6
+    pub modifiers: Vec<String>,
+    /// SV102 / SV203
+    pub charge: Amount,
+    /// SV103 / SV204
+    pub unit_basis: Option<String>,
+    /// SV104 / SV205
+    pub units: Option<Amount>,
+    /// SV107 pointers into [`_837Claim::diagnoses`], 1-based
+    pub diagnosis_pointers: Vec<String>,
+    /// DTP03 of the service date (`DTP*472`), the first day of a `RD8` range
+    pub service_date: Option<NaiveDate>,
+}
+
+/// A charge, unit count or date of a claim that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum _837ClaimError {
+    Amount(AmountError),
+    Units(String),
+    Date(String),
+}
+
+impl fmt::Display for _837ClaimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            _837ClaimError::Amount(e) => e.fmt(f),
+            _837ClaimError::Units(value) => write!(f, "invalid units {value:?}"),
+            _837ClaimError::Date(value) => write!(f, "invalid date {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for _837ClaimError {}
+
+impl From<AmountError> for _837ClaimError {
+    fn from(e: AmountError) -> _837ClaimError {
+        _837ClaimError::Amount(e)
+    }
+}
+
+impl _837 {
+    /// The claim loops (2300) of all subscribers and patients in document order.
+    pub fn claim_loops(&self) -> impl Iterator<Item = &_837Loop2300> {
+        self.loop_2000a
+            .iter()
+            .flat_map(|a| &a.loop_2000b)
+            .flat_map(|b| {
+                b.loop_2300
+                    .iter()
+                    .chain(b.loop_2000c.iter().flat_map(|c| &c.loop_2300))
+            })
+    }
+
+    /// All claims in document order, flattened with their HL context.
+    ///
+    /// Composite elements (`CLM05`, `HI`, `SV1`/`SV2`) are split on the
+    /// component separator of the interchange (`ISA16`), see
+    /// [`Transmission::claims`].
+    pub fn claims(
+        &self,
+        component_separator: char,
+    ) -> impl Iterator<Item = Result<_837Claim, _837ClaimError>> + '_ {
+        self.loop_2000a.iter().flat_map(move |a| {
+            a.loop_2000b.iter().flat_map(move |b| {
+                let subscriber_claims = b
+                    .loop_2300
+                    .iter()
+                    .map(move |clm| _837Claim::new(a, b, None, clm, component_separator));
+                let patient_claims = b.loop_2000c.iter().flat_map(move |c| {
+                    c.loop_2300
+                        .iter()
+                        .map(move |clm| _837Claim::new(a, b, Some(c), clm, component_separator))
+                });
+                subscriber_claims.chain(patient_claims)
+            })
+        })
+    }
+}
+
+impl Transmission<_837> {
+    /// The claims of all 837s in the interchange, split on its component
+    /// separator (`ISA16`).
+    pub fn claims(&self) -> impl Iterator<Item = Result<_837Claim, _837ClaimError>> + '_ {
+        let separator = self.isa._16.chars().next().unwrap_or(':');
+        self.functional_group
+            .iter()
+            .flat_map(|group| &group.segments)
+            .flat_map(move |tx| tx.claims(separator))
+    }
+}
+
+impl _837Claim {
+    fn new(
+        a: &_837Loop2000A,
+        b: &_837Loop2000B,
+        c: Option<&_837Loop2000C>,
+        claim: &_837Loop2300,
+        separator: char,
+    ) -> Result<_837Claim, _837ClaimError> {
+        let clm = &claim.clm;
+        let clm05 = components(clm._05.as_deref(), separator);
+        let subscriber = find_name(&b.loop_2010b, "IL")
+            .map(_837Person::try_from)
+            .transpose()?;
+        let patient = match c {
+            Some(c) => find_name(&c.loop_2010c, "QC")
+                .map(_837Person::try_from)
+                .transpose()?,
+            None => subscriber.clone(),
+        };
+        Ok(_837Claim {
+            patient_control_number: clm._01.clone(),
+            total_charge: Amount::parse_opt(clm._02.as_deref())?,
+            place_of_service: component(&clm05, 0),
+            frequency_code: component(&clm05, 2),
+            billing_provider: find_name(&a.loop_2010a, "85").map(_837Provider::from),
+            rendering_provider: claim
+                .loop_2310
+                .iter()
+                .find(|l| l.nm1._01 == "82")
+                .map(|l| _837Provider::new(&l.nm1, l.r#ref.as_ref())),
+            subscriber,
+            patient,
+            payer: find_name(&b.loop_2010b, "PR").map(_837Provider::from),
+            diagnoses: claim
+                .hi
+                .iter()
+                .flat_map(|hi| {
+                    [
+                        Some(&hi._01),
+                        hi._02.as_ref(),
+                        hi._03.as_ref(),
+                        hi._04.as_ref(),
+                        hi._05.as_ref(),
+                        hi._06.as_ref(),
+                        hi._07.as_ref(),
+                        hi._08.as_ref(),
+                        hi._09.as_ref(),
+                        hi._10.as_ref(),
+                        hi._11.as_ref(),
+                        hi._12.as_ref(),
+                    ]
+                })
+                .flatten()
+                .filter_map(|composite| {
+                    let mut parts = composite.split(separator);
+                    let qualifier = parts.next().filter(|q| !q.is_empty())?;
+                    let code = parts.next().filter(|c| !c.is_empty())?;
+                    Some(_837Diagnosis {
+                        qualifier: qualifier.to_string(),
+                        code: code.to_string(),
+                    })
+                })
+                .collect(),
+            service_lines: claim
+                .loop_2400
+                .iter()
+                .map(|l| _837ServiceLine::new(l, separator))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl _837Provider {
+    fn new(nm1: &NM1, r#ref: Option<&REF>) -> _837Provider {
+        let id = non_empty(&nm1._09);
+        _837Provider {
+            name: non_empty(&nm1._03),
+            first_name: non_empty(&nm1._04),
+            npi: id.clone().filter(|_| nm1._08.as_deref() == Some("XX")),
+            id_qualifier: non_empty(&nm1._08),
+            id,
+            tax_id: r#ref
+                .filter(|r| r._01 == "EI" || r._01 == "SY")
+                .and_then(|r| non_empty(&r._02)),
+        }
+    }
+}
+
+impl From<&_837Loop2010> for _837Provider {
+    fn from(l: &_837Loop2010) -> _837Provider {
+        _837Provider::new(&l.nm1, l.r#ref.as_ref())
+    }
+}
+
+impl TryFrom<&_837Loop2010> for _837Person {
+    type Error = _837ClaimError;
+
+    fn try_from(l: &_837Loop2010) -> Result<_837Person, _837ClaimError> {
+        let dmg = l.dmg.as_ref();
+        let n4 = l.n4.as_ref();
+        Ok(_837Person {
+            last_name: non_empty(&l.nm1._03),
+            first_name: non_empty(&l.nm1._04),
+            middle_name: non_empty(&l.nm1._05),
+            member_id: non_empty(&l.nm1._09),
+            birth_date: dmg
+                .and_then(|d| non_empty(&d._02))
+                .map(|date| parse_date(&date))
+                .transpose()?,
+            gender: dmg.and_then(|d| non_empty(&d._03)),
+            address: l.n3.as_ref().map(|n3| n3._01.clone()),
+            city: n4.and_then(|n| non_empty(&n._01)),
+            state: n4.and_then(|n| non_empty(&n._02)),
+            postal_code: n4.and_then(|n| non_empty(&n._03)),
+        })
+    }
+}
+
+impl _837ServiceLine {
+    fn new(l: &_837Loop2400, separator: char) -> Result<_837ServiceLine, _837ClaimError> {
+        let mut line = _837ServiceLine {
+            line_number: l.lx._01.clone(),
+            service_date: l
+                .dtp
+                .as_ref()
+                .filter(|d| d._01 == "472")
+                .map(|d| parse_date(&d._03))
+                .transpose()?,
+            ..Default::default()
+        };
+        let procedure = if let Some(sv1) = &l.sv1 {
+            line.charge = Amount::parse_opt(sv1._02.as_deref())?;
+            line.unit_basis = non_empty(&sv1._03);
+            line.units = parse_units(sv1._04.as_deref())?;
+            line.diagnosis_pointers = components(sv1._07.as_deref(), separator)
+                .into_iter()
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect();
+            components(Some(&sv1._01), separator)
+        } else if let Some(sv2) = &l.sv2 {
+            line.revenue_code = Some(sv2._01.clone());
+            line.charge = Amount::parse_opt(sv2._03.as_deref())?;
+            line.unit_basis = non_empty(&sv2._04);
+            line.units = parse_units(sv2._05.as_deref())?;
+            components(sv2._02.as_deref(), separator)
+        } else {
+            vec![]
+        };
+        line.procedure_qualifier = component(&procedure, 0);
+        line.procedure_code = component(&procedure, 1);
+        line.modifiers = procedure
+            .iter()
+            .skip(2)
+            .take(4)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.to_string())
+            .collect();
+        Ok(line)
+    }
+}
+
+fn find_name<'a>(loops: &'a [_837Loop2010], entity: &str) -> Option<&'a _837Loop2010> {
+    loops.iter().find(|l| l.nm1._01 == entity)
+}
+
+fn components(value: Option<&str>, separator: char) -> Vec<&str> {
+    value
+        .map(|v| v.split(separator).collect())
+        .unwrap_or_default()
+}
+
+/// A `D8` date, or the first date of a `RD8` range.
+fn parse_date(value: &str) -> Result<NaiveDate, _837ClaimError> {
+    let first = value.split('-').next().unwrap_or_default();
+    NaiveDate::parse_from_str(first, DATE_FORMAT)
+        .map_err(|_| _837ClaimError::Date(value.to_string()))
+}
+
+/// A unit count (`R`), `None` if missing or empty.
+fn parse_units(value: Option<&str>) -> Result<Option<Amount>, _837ClaimError> {
+    match value {
+        Some(v) if !v.is_empty() => v
+            .parse()
+            .map(Some)
+            .map_err(|_| _837ClaimError::Units(v.to_string())),
+        _ => Ok(None),
+    }
+}
+
+fn component(parts: &[&str], index: usize) -> Option<String> {
+    parts
+        .get(index)
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+}
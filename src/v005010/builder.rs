@@ -0,0 +1,1116 @@
+use super::*;
+use crate::util::builder::{loop_builder, transaction_builder};
+
+transaction_builder! {
+    _820Builder => _820 ("820") {
+        segments: [bpr: BPR, trn: TRN],
+        optional: [cur: CUR],
+        repeated: [r#ref: REF, dtm: DTM],
+        loops: [loop_1000: _820Loop1000, loop_2000: _820Loop2000],
+    }
+}
+
+loop_builder! {
+    _820Loop1000Builder => _820Loop1000 {
+        segments: [n1: N1],
+        optional: [n3: N3, n4: N4],
+        repeated: [n2: N2, per: PER],
+    }
+}
+
+loop_builder! {
+    _820Loop2000Builder => _820Loop2000 {
+        segments: [ent: ENT],
+        optional_loop: [loop_2100: _820Loop2100],
+        loops: [loop_2200: _820Loop2200, loop_2300: _820Loop2300],
+    }
+}
+
+loop_builder! {
+    _820Loop2100Builder => _820Loop2100 {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4],
+        repeated: [n2: N2, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _820Loop2200Builder => _820Loop2200 {
+        segments: [adx: ADX],
+        repeated: [nte: NTE, r#ref: REF, dtm: DTM],
+    }
+}
+
+loop_builder! {
+    _820Loop2300Builder => _820Loop2300 {
+        segments: [rmr: RMR],
+        repeated: [nte: NTE, r#ref: REF, dtm: DTM],
+        loops: [loop_2320: _820Loop2320],
+    }
+}
+
+loop_builder! {
+    _820Loop2320Builder => _820Loop2320 {
+        segments: [adx: ADX],
+        repeated: [nte: NTE, r#ref: REF],
+    }
+}
+
+transaction_builder! {
+    _824Builder => _824 ("824") {
+        segments: [bgn: BGN],
+        loops: [loop_1000: _824Loop1000, loop_2000: _824Loop2000],
+    }
+}
+
+loop_builder! {
+    _824Loop1000Builder => _824Loop1000 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _824Loop2000Builder => _824Loop2000 {
+        segments: [oti: OTI],
+        repeated: [r#ref: REF, dtm: DTM, per: PER, amt: AMT, qty: QTY, nm1: NM1],
+        loops: [loop_2100: _824Loop2100],
+    }
+}
+
+loop_builder! {
+    _824Loop2100Builder => _824Loop2100 {
+        segments: [ted: TED],
+        repeated: [nte: NTE, red: RED],
+    }
+}
+
+transaction_builder! {
+    _834Builder => _834 ("834") {
+        segments: [bgn: BGN],
+        repeated: [r#ref: REF, dtp: DTP, amt: AMT, qty: QTY],
+        loops: [loop_1000: _834Loop1000, loop_2000: _834Loop2000],
+    }
+}
+
+loop_builder! {
+    _834Loop1000Builder => _834Loop1000 {
+        segments: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, per: PER],
+        loops: [loop_1100: _834Loop1100],
+    }
+}
+
+loop_builder! {
+    _834Loop1100Builder => _834Loop1100 {
+        trigger: [act: ACT],
+        optional: [n3: N3, n4: N4, dtp: DTP, amt: AMT],
+        repeated: [r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _834Loop2000Builder => _834Loop2000 {
+        trigger: [ins: INS],
+        optional: [ls: LS, le: LE],
+        repeated: [r#ref: REF, dtp: DTP],
+        loops: [
+            loop_2100: _834Loop2100,
+            loop_2200: _834Loop2200,
+            loop_2300: _834Loop2300,
+            loop_2400: _834Loop2400,
+            loop_2500: _834Loop2500,
+            loop_2600: _834Loop2600,
+            loop_2700: _834Loop2700,
+        ],
+    }
+}
+
+loop_builder! {
+    _834Loop2100Builder => _834Loop2100 {
+        trigger: [nm1: NM1],
+        optional: [per: PER, n3: N3, n4: N4, dmg: DMG, pm: PM, icm: ICM, hlh: HLH],
+        repeated: [ec: EC, amt: AMT, hi: HI, lui: LUI],
+    }
+}
+
+loop_builder! {
+    _834Loop2200Builder => _834Loop2200 {
+        trigger: [dsb: DSB],
+        repeated: [dtp: DTP, ad1: AD1],
+    }
+}
+
+loop_builder! {
+    _834Loop2300Builder => _834Loop2300 {
+        trigger: [hd: HD],
+        repeated: [dtp: DTP, amt: AMT, r#ref: REF, idc: IDC],
+        loops: [loop_2310: _834Loop2310, loop_2320: _834Loop2320],
+    }
+}
+
+loop_builder! {
+    _834Loop2310Builder => _834Loop2310 {
+        trigger: [lx: LX],
+        optional: [nm1: NM1, n2: N2, n4: N4, prv: PRV, pla: PLA],
+        repeated: [n1: N1, n3: N3, per: PER, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _834Loop2320Builder => _834Loop2320 {
+        trigger: [cob: COB],
+        optional: [r#ref: REF, dtp: DTP],
+        loops: [loop_2330: _834Loop2330],
+    }
+}
+
+loop_builder! {
+    _834Loop2330Builder => _834Loop2330 {
+        trigger: [nm1: NM1],
+        optional: [n2: N2, n4: N4, per: PER],
+        repeated: [n3: N3],
+    }
+}
+
+loop_builder! {
+    _834Loop2400Builder => _834Loop2400 {
+        trigger: [lc: LC],
+        repeated: [amt: AMT, dtp: DTP, r#ref: REF],
+        loops: [loop_2410: _834Loop2410],
+    }
+}
+
+loop_builder! {
+    _834Loop2410Builder => _834Loop2410 {
+        trigger: [ben: BEN],
+        optional: [nm1: NM1, n1: N1, n2: N2, n3: N3, n4: N4, dmg: DMG],
+    }
+}
+
+loop_builder! {
+    _834Loop2500Builder => _834Loop2500 {
+        trigger: [fsa: FSA],
+        repeated: [amt: AMT, dtp: DTP, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _834Loop2600Builder => _834Loop2600 {
+        trigger: [rp: RP],
+        optional: [rel: REL],
+        repeated: [dtp: DTP, r#ref: REF, inv: INV, amt: AMT, qty: QTY, k3: K3],
+        loops: [loop_2610: _834Loop2610, loop_2630: _834Loop2630, loop_2650: _834Loop2650],
+    }
+}
+
+loop_builder! {
+    _834Loop2610Builder => _834Loop2610 {
+        trigger: [nm1: NM1],
+        optional: [n2: N2, dmg: DMG, ben: BEN],
+        repeated: [r#ref: REF],
+        loops: [loop_2620: _834Loop2620],
+    }
+}
+
+loop_builder! {
+    _834Loop2620Builder => _834Loop2620 {
+        trigger: [nx1: NX1],
+        optional: [n3: N3, n4: N4],
+        repeated: [dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _834Loop2630Builder => _834Loop2630 {
+        trigger: [fc: FC],
+        repeated: [dtp: DTP],
+        loops: [loop_2640: _834Loop2640],
+    }
+}
+
+loop_builder! {
+    _834Loop2640Builder => _834Loop2640 {
+        trigger: [inv: INV],
+        repeated: [dtp: DTP, qty: QTY, ent: ENT, r#ref: REF, amt: AMT, k3: K3],
+    }
+}
+
+loop_builder! {
+    _834Loop2650Builder => _834Loop2650 {
+        trigger: [ain: AIN],
+        repeated: [qty: QTY, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _834Loop2700Builder => _834Loop2700 {
+        trigger: [lx: LX],
+        loops: [loop_2750: _834Loop2750],
+    }
+}
+
+loop_builder! {
+    _834Loop2750Builder => _834Loop2750 {
+        segments: [n1: N1, r#ref: REF],
+        optional: [dtp: DTP],
+    }
+}
+
+transaction_builder! {
+    _835Builder => _835 ("835") {
+        segments: [bpr: BPR],
+        optional: [trn: TRN, cur: CUR],
+        repeated: [nte: NTE, r#ref: REF, dtm: DTM, plb: PLB],
+        loops: [loop_1000: _835Loop1000, loop_2000: _835Loop2000],
+    }
+}
+
+loop_builder! {
+    _835Loop1000Builder => _835Loop1000 {
+        segments: [n1: N1],
+        optional: [n4: N4, rdm: RDM, dtm: DTM],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _835Loop2000Builder => _835Loop2000 {
+        segments: [lx: LX],
+        optional: [ts3: TS3, ts2: TS2],
+        loops: [loop_2100: _835Loop2100],
+    }
+}
+
+loop_builder! {
+    _835Loop2100Builder => _835Loop2100 {
+        segments: [clp: CLP],
+        optional: [mia: MIA, moa: MOA],
+        repeated: [cas: CAS, nm1: NM1, r#ref: REF, dtm: DTM, per: PER, amt: AMT, qty: QTY],
+        loops: [loop_2110: _835Loop2110],
+    }
+}
+
+loop_builder! {
+    _835Loop2110Builder => _835Loop2110 {
+        segments: [svc: SVC],
+        repeated: [dtm: DTM, cas: CAS, r#ref: REF, amt: AMT, qty: QTY, lq: LQ],
+    }
+}
+
+transaction_builder! {
+    _837Builder => _837 ("837") {
+        segments: [bht: BHT],
+        repeated: [r#ref: REF],
+        loops: [loop_1000: _837Loop1000, loop_2000a: _837Loop2000A],
+        renumber: [loop_2000a],
+    }
+}
+
+loop_builder! {
+    _837Loop1000Builder => _837Loop1000 {
+        segments: [nm1: NM1],
+        optional: [n4: N4],
+        repeated: [n2: N2, n3: N3, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _837Loop2000ABuilder => _837Loop2000A {
+        segments: [hl: HL],
+        optional: [prv: PRV, cur: CUR],
+        loops: [loop_2010a: _837Loop2010, loop_2000b: _837Loop2000B],
+    }
+}
+
+loop_builder! {
+    _837Loop2010Builder => _837Loop2010 {
+        segments: [nm1: NM1],
+        optional: [n2: N2, n3: N3, n4: N4, dmg: DMG, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _837Loop2000BBuilder => _837Loop2000B {
+        segments: [hl: HL, sbr: SBR],
+        optional: [pat: PAT],
+        loops: [loop_2010b: _837Loop2010, loop_2300: _837Loop2300, loop_2000c: _837Loop2000C],
+    }
+}
+
+loop_builder! {
+    _837Loop2300Builder => _837Loop2300 {
+        segments: [clm: CLM],
+        optional: [
+            dtp: DTP,
+            cl1: CL1,
+            dn1: DN1,
+            dn2: DN2,
+            pwk: PWK,
+            cn1: CN1,
+            dsb: DSB,
+            ur: UR,
+            amt: AMT,
+            r#ref: REF,
+            k3: K3,
+            nte: NTE,
+            cr1: CR1,
+            cr2: CR2,
+            cr3: CR3,
+            cr4: CR4,
+            cr5: CR5,
+            cr6: CR6,
+            cr8: CR8,
+            crc: CRC,
+            hi: HI,
+            qty: QTY,
+            hcp: HCP,
+        ],
+        loops: [
+            loop_2305: _837Loop2305,
+            loop_2310: _837Loop2310,
+            loop_2320: _837Loop2320,
+            loop_2400: _837Loop2400,
+        ],
+    }
+}
+
+loop_builder! {
+    _837Loop2305Builder => _837Loop2305 {
+        segments: [cr7: CR7],
+        repeated: [hsd: HSD],
+    }
+}
+
+loop_builder! {
+    _837Loop2310Builder => _837Loop2310 {
+        segments: [nm1: NM1],
+        optional: [prv: PRV, n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _837Loop2320Builder => _837Loop2320 {
+        segments: [sbr: SBR],
+        optional: [cas: CAS, dmg: DMG, oi: OI, mia: MIA, moa: MOA],
+        repeated: [amt: AMT],
+        loops: [loop_2330: _837Loop2330],
+    }
+}
+
+loop_builder! {
+    _837Loop2330Builder => _837Loop2330 {
+        segments: [nm1: NM1],
+        optional: [n2: N2, n3: N3, n4: N4, per: PER, dtp: DTP, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _837Loop2400Builder => _837Loop2400 {
+        segments: [lx: LX],
+        optional: [
+            sv1: SV1,
+            sv2: SV2,
+            sv3: SV3,
+            too: TOO,
+            sv4: SV4,
+            sv5: SV5,
+            sv6: SV6,
+            sv7: SV7,
+            hi: HI,
+            pwk: PWK,
+            cr1: CR1,
+            cr2: CR2,
+            cr3: CR3,
+            cr4: CR4,
+            cr5: CR5,
+            crc: CRC,
+            dtp: DTP,
+            qty: QTY,
+            mea: MEA,
+            cn1: CN1,
+            r#ref: REF,
+            nte: NTE,
+            ps1: PS1,
+            imm: IMM,
+            hsd: HSD,
+            hcp: HCP,
+        ],
+        repeated: [amt: AMT, k3: K3],
+        loops: [
+            loop_2410: _837Loop2410,
+            loop_2420: _837Loop2420,
+            loop_2430: _837Loop2430,
+            loop_2440: _837Loop2440,
+        ],
+    }
+}
+
+loop_builder! {
+    _837Loop2410Builder => _837Loop2410 {
+        segments: [lin: LIN],
+        optional: [ctp: CTP, r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _837Loop2420Builder => _837Loop2420 {
+        segments: [nm1: NM1],
+        optional: [prv: PRV, n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _837Loop2430Builder => _837Loop2430 {
+        segments: [svd: SVD],
+        optional: [dtp: DTP, amt: AMT],
+        repeated: [cas: CAS],
+    }
+}
+
+loop_builder! {
+    _837Loop2440Builder => _837Loop2440 {
+        segments: [lq: LQ, frm: FRM],
+    }
+}
+
+loop_builder! {
+    _837Loop2000CBuilder => _837Loop2000C {
+        segments: [hl: HL, pat: PAT],
+        loops: [loop_2010c: _837Loop2010, loop_2300: _837Loop2300],
+    }
+}
+
+transaction_builder! {
+    _270Builder => _270 ("270") {
+        segments: [bht: BHT],
+        loops: [loop_2000a: _270Loop2000A],
+    }
+}
+
+loop_builder! {
+    _270Loop2000ABuilder => _270Loop2000A {
+        segments: [hl: HL],
+        loop: [loop_2100a: _270Loop2100A],
+        loops: [loop_2000b: _270Loop2000B],
+    }
+}
+
+loop_builder! {
+    _270Loop2100ABuilder => _270Loop2100A {
+        segments: [nm1: NM1],
+    }
+}
+
+loop_builder! {
+    _270Loop2000BBuilder => _270Loop2000B {
+        segments: [hl: HL],
+        loop: [loop_2100b: _270Loop2100B],
+        loops: [loop_2000c: _270Loop2000C],
+    }
+}
+
+loop_builder! {
+    _270Loop2100BBuilder => _270Loop2100B {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, prv: PRV],
+        repeated: [r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _270Loop2000CBuilder => _270Loop2000C {
+        segments: [hl: HL],
+        loop: [loop_2100c: _270Loop2100C],
+        repeated: [trn: TRN],
+        loops: [loop_2000d: _270Loop2000D],
+    }
+}
+
+loop_builder! {
+    _270Loop2100CBuilder => _270Loop2100C {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, prv: PRV, dmg: DMG, ins: INS, hi: HI],
+        repeated: [r#ref: REF, dtp: DTP],
+        loops: [loop_2110c: _270Loop2110C],
+    }
+}
+
+loop_builder! {
+    _270Loop2110CBuilder => _270Loop2110C {
+        segments: [eq: EQ],
+        optional: [iii: III, r#ref: REF, dtp: DTP],
+        repeated: [amt: AMT],
+    }
+}
+
+loop_builder! {
+    _270Loop2000DBuilder => _270Loop2000D {
+        segments: [hl: HL],
+        loop: [loop_2100d: _270Loop2100D],
+        repeated: [trn: TRN],
+    }
+}
+
+loop_builder! {
+    _270Loop2100DBuilder => _270Loop2100D {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, prv: PRV, dmg: DMG, ins: INS, hi: HI],
+        repeated: [r#ref: REF, dtp: DTP],
+        loops: [loop_2110d: _270Loop2110D],
+    }
+}
+
+loop_builder! {
+    _270Loop2110DBuilder => _270Loop2110D {
+        segments: [eq: EQ],
+        optional: [iii: III, r#ref: REF, dtp: DTP],
+    }
+}
+
+transaction_builder! {
+    _271Builder => _271 ("271") {
+        segments: [bht: BHT],
+        loops: [loop_2000a: _271Loop2000A],
+    }
+}
+
+loop_builder! {
+    _271Loop2000ABuilder => _271Loop2000A {
+        segments: [hl: HL],
+        loop: [loop_2100a: _271Loop2100A],
+        repeated: [aaa: AAA],
+        loops: [loop_2000b: _271Loop2000B],
+    }
+}
+
+loop_builder! {
+    _271Loop2100ABuilder => _271Loop2100A {
+        segments: [nm1: NM1],
+        repeated: [per: PER, aaa: AAA],
+    }
+}
+
+loop_builder! {
+    _271Loop2000BBuilder => _271Loop2000B {
+        segments: [hl: HL],
+        loop: [loop_2100b: _271Loop2100B],
+        loops: [loop_2000c: _271Loop2000C],
+    }
+}
+
+loop_builder! {
+    _271Loop2100BBuilder => _271Loop2100B {
+        segments: [nm1: NM1],
+        optional: [prv: PRV],
+        repeated: [r#ref: REF, aaa: AAA],
+    }
+}
+
+loop_builder! {
+    _271Loop2000CBuilder => _271Loop2000C {
+        segments: [hl: HL],
+        loop: [loop_2100c: _271Loop2100C],
+        repeated: [trn: TRN],
+        loops: [loop_2000d: _271Loop2000D],
+    }
+}
+
+loop_builder! {
+    _271Loop2100CBuilder => _271Loop2100C {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, prv: PRV, dmg: DMG, ins: INS, hi: HI],
+        repeated: [r#ref: REF, aaa: AAA, dtp: DTP],
+        loops: [loop_2110c: _271Loop2110C],
+    }
+}
+
+loop_builder! {
+    _271Loop2110CBuilder => _271Loop2110C {
+        segments: [eb: EB],
+        optional: [ls: LS, le: LE],
+        repeated: [hsd: HSD, r#ref: REF, dtp: DTP, aaa: AAA, msg: MSG],
+        loops: [loop_2115c: _271Loop2115C, loop_2120c: _271Loop2120C],
+    }
+}
+
+loop_builder! {
+    _271Loop2115CBuilder => _271Loop2115C {
+        segments: [iii: III],
+    }
+}
+
+loop_builder! {
+    _271Loop2120CBuilder => _271Loop2120C {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, prv: PRV],
+        repeated: [per: PER],
+    }
+}
+
+loop_builder! {
+    _271Loop2000DBuilder => _271Loop2000D {
+        segments: [hl: HL],
+        loop: [loop_2100d: _271Loop2100D],
+        repeated: [trn: TRN],
+    }
+}
+
+transaction_builder! {
+    _276Builder => _276 ("276") {
+        segments: [bht: BHT],
+        loops: [
+            loop_2000a: _276Loop2000A,
+            loop_2000b: _276Loop2000B,
+            loop_2000c: _276Loop2000C,
+            loop_2000d: _276Loop2000D,
+            loop_2000e: _276Loop2000E,
+        ],
+    }
+}
+
+loop_builder! {
+    _276Loop2000ABuilder => _276Loop2000A {
+        segments: [hl: HL],
+        loop: [loop_2100a: _276Loop2100A],
+        loops: [loop_2200a: _276Loop2200A],
+    }
+}
+
+loop_builder! {
+    _276Loop2100ABuilder => _276Loop2100A {
+        segments: [nm1: NM1],
+        optional: [per: PER],
+    }
+}
+
+loop_builder! {
+    _276Loop2200ABuilder => _276Loop2200A {
+        segments: [trn: TRN],
+        repeated: [r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _276Loop2000BBuilder => _276Loop2000B {
+        segments: [hl: HL],
+        loop: [loop_2100b: _276Loop2100B],
+        loops: [loop_2200b: _276Loop2200B],
+    }
+}
+
+loop_builder! {
+    _276Loop2100BBuilder => _276Loop2100B {
+        segments: [nm1: NM1],
+        optional: [per: PER],
+    }
+}
+
+loop_builder! {
+    _276Loop2200BBuilder => _276Loop2200B {
+        segments: [trn: TRN],
+        repeated: [r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _276Loop2000CBuilder => _276Loop2000C {
+        segments: [hl: HL],
+        loop: [loop_2100c: _276Loop2100C],
+        loops: [loop_2200c: _276Loop2200C],
+    }
+}
+
+loop_builder! {
+    _276Loop2100CBuilder => _276Loop2100C {
+        segments: [nm1: NM1],
+        optional: [dmg: DMG],
+        repeated: [r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _276Loop2200CBuilder => _276Loop2200C {
+        trigger: [trn: TRN],
+        optional: [svc: SVC],
+        repeated: [r#ref: REF, amt: AMT, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _276Loop2000DBuilder => _276Loop2000D {
+        segments: [hl: HL, dmg: DMG],
+        loop: [loop_2100d: _276Loop2100D],
+        loops: [loop_2200d: _276Loop2200D],
+    }
+}
+
+loop_builder! {
+    _276Loop2100DBuilder => _276Loop2100D {
+        segments: [nm1: NM1],
+        repeated: [r#ref: REF],
+    }
+}
+
+loop_builder! {
+    _276Loop2200DBuilder => _276Loop2200D {
+        segments: [trn: TRN],
+        optional: [svc: SVC],
+        repeated: [r#ref: REF, amt: AMT, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _276Loop2000EBuilder => _276Loop2000E {
+        segments: [hl: HL, dmg: DMG],
+        loop: [loop_2100e: _276Loop2100E],
+        loops: [loop_2200e: _276Loop2200E],
+    }
+}
+
+loop_builder! {
+    _276Loop2100EBuilder => _276Loop2100E {
+        segments: [nm1: NM1, svc: SVC],
+        optional: [per: PER],
+        repeated: [r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _276Loop2200EBuilder => _276Loop2200E {
+        segments: [trn: TRN],
+        optional: [svc: SVC],
+        repeated: [r#ref: REF, dtp: DTP],
+    }
+}
+
+transaction_builder! {
+    _277Builder => _277 ("277") {
+        segments: [bht: BHT],
+        loop: [loop_2000a: _277Loop2000A],
+        loops: [
+            loop_2000b: _277Loop2000B,
+            loop_2000c: _277Loop2000C,
+            loop_2000d: _277Loop2000D,
+            loop_2000e: _277Loop2000E,
+        ],
+    }
+}
+
+loop_builder! {
+    _277Loop2000ABuilder => _277Loop2000A {
+        segments: [hl: HL],
+        loop: [loop_2100a: _277Loop2100A],
+    }
+}
+
+loop_builder! {
+    _277Loop2100ABuilder => _277Loop2100A {
+        segments: [nm1: NM1],
+        optional: [trn: TRN, n3: N3, n4: N4],
+        repeated: [dtp: DTP, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _277Loop2000BBuilder => _277Loop2000B {
+        segments: [hl: HL],
+        loop: [loop_2100b: _277Loop2100B],
+        loops: [loop_2200b: _277Loop2200B],
+    }
+}
+
+loop_builder! {
+    _277Loop2100BBuilder => _277Loop2100B {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4],
+        repeated: [r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _277Loop2200BBuilder => _277Loop2200B {
+        segments: [trn: TRN],
+        repeated: [stc: STC, qty: QTY, amt: AMT, r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _277Loop2000CBuilder => _277Loop2000C {
+        segments: [hl: HL],
+        loop: [loop_2100c: _277Loop2100C],
+        loops: [loop_2200c: _277Loop2200C],
+    }
+}
+
+loop_builder! {
+    _277Loop2100CBuilder => _277Loop2100C {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4],
+        repeated: [trn: TRN, stc: STC, qty: QTY, amt: AMT, r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _277Loop2200CBuilder => _277Loop2200C {
+        trigger: [trn: TRN],
+        repeated: [stc: STC, qty: QTY, amt: AMT, r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _277Loop2000DBuilder => _277Loop2000D {
+        segments: [hl: HL],
+        loop: [loop_2100d: _277Loop2100D],
+        loops: [loop_2200d: _277Loop2200D],
+    }
+}
+
+loop_builder! {
+    _277Loop2100DBuilder => _277Loop2100D {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4],
+        repeated: [r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _277Loop2200DBuilder => _277Loop2200D {
+        repeated_trigger: [trn: TRN],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+        loops: [svc: _277Loop2220D],
+    }
+}
+
+loop_builder! {
+    _277Loop2220DBuilder => _277Loop2220D {
+        repeated_trigger: [svc: SVC],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _277Loop2000EBuilder => _277Loop2000E {
+        segments: [hl: HL],
+        loop: [loop_2100e: _277Loop2100E],
+        loops: [loop_2200e: _277Loop2200E],
+    }
+}
+
+loop_builder! {
+    _277Loop2100EBuilder => _277Loop2100E {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4],
+        repeated: [r#ref: REF, per: PER],
+    }
+}
+
+loop_builder! {
+    _277Loop2200EBuilder => _277Loop2200E {
+        segments: [trn: TRN],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+        loops: [svc: _277Loop2220E],
+    }
+}
+
+loop_builder! {
+    _277Loop2220EBuilder => _277Loop2220E {
+        repeated_trigger: [svc: SVC],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+    }
+}
+
+transaction_builder! {
+    _277CABuilder => _277CA ("277") {
+        segments: [bht: BHT],
+        loops: [loop_2000a: _277CALoop2000A],
+    }
+}
+
+loop_builder! {
+    _277CALoop2000ABuilder => _277CALoop2000A {
+        segments: [hl: HL],
+        loop: [loop_2100a: _277CALoop2100A, loop_2200a: _277CALoop2200A],
+        loops: [loop_2000b: _277CALoop2000B],
+    }
+}
+
+loop_builder! {
+    _277CALoop2100ABuilder => _277CALoop2100A {
+        segments: [nm1: NM1],
+    }
+}
+
+loop_builder! {
+    _277CALoop2200ABuilder => _277CALoop2200A {
+        segments: [trn: TRN],
+        repeated: [dtp: DTP],
+    }
+}
+
+loop_builder! {
+    _277CALoop2000BBuilder => _277CALoop2000B {
+        segments: [hl: HL],
+        loop: [loop_2100b: _277CALoop2100B, loop_2200b: _277CALoop2200B],
+        loops: [loop_2000c: _277CALoop2000C],
+    }
+}
+
+loop_builder! {
+    _277CALoop2100BBuilder => _277CALoop2100B {
+        segments: [nm1: NM1],
+    }
+}
+
+loop_builder! {
+    _277CALoop2200BBuilder => _277CALoop2200B {
+        segments: [trn: TRN],
+        repeated: [stc: STC, qty: QTY, amt: AMT],
+    }
+}
+
+loop_builder! {
+    _277CALoop2000CBuilder => _277CALoop2000C {
+        segments: [hl: HL],
+        loop: [loop_2100c: _277CALoop2100C],
+        optional_loop: [loop_2200c: _277CALoop2200C],
+        loops: [loop_2000d: _277CALoop2000D],
+    }
+}
+
+loop_builder! {
+    _277CALoop2100CBuilder => _277CALoop2100C {
+        segments: [nm1: NM1],
+    }
+}
+
+loop_builder! {
+    _277CALoop2200CBuilder => _277CALoop2200C {
+        segments: [trn: TRN],
+        repeated: [stc: STC, r#ref: REF, qty: QTY, amt: AMT],
+    }
+}
+
+loop_builder! {
+    _277CALoop2000DBuilder => _277CALoop2000D {
+        segments: [hl: HL],
+        loop: [loop_2100d: _277CALoop2100D],
+        loops: [loop_2200d: _277CALoop2200D],
+    }
+}
+
+loop_builder! {
+    _277CALoop2100DBuilder => _277CALoop2100D {
+        segments: [nm1: NM1],
+    }
+}
+
+loop_builder! {
+    _277CALoop2200DBuilder => _277CALoop2200D {
+        segments: [trn: TRN],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+        loops: [loop_2220d: _277CALoop2220D],
+    }
+}
+
+loop_builder! {
+    _277CALoop2220DBuilder => _277CALoop2220D {
+        segments: [svc: SVC],
+        repeated: [stc: STC, r#ref: REF, dtp: DTP],
+    }
+}
+
+transaction_builder! {
+    _278Builder => _278 ("278") {
+        segments: [bht: BHT],
+        loops: [loop_2000a: _278Loop2000A],
+    }
+}
+
+loop_builder! {
+    _278Loop2000ABuilder => _278Loop2000A {
+        segments: [hl: HL],
+        loop: [loop_2010a: _278Loop2010A],
+        repeated: [aaa: AAA],
+        loops: [loop_2000b: _278Loop2000B],
+    }
+}
+
+loop_builder! {
+    _278Loop2010ABuilder => _278Loop2010A {
+        segments: [nm1: NM1],
+        repeated: [per: PER, aaa: AAA],
+    }
+}
+
+loop_builder! {
+    _278Loop2000BBuilder => _278Loop2000B {
+        segments: [hl: HL],
+        loop: [loop_2010b: _278Loop2010B],
+        loops: [loop_2000c: _278Loop2000C],
+    }
+}
+
+loop_builder! {
+    _278Loop2010BBuilder => _278Loop2010B {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, per: PER, prv: PRV],
+        repeated: [r#ref: REF, aaa: AAA],
+    }
+}
+
+loop_builder! {
+    _278Loop2000CBuilder => _278Loop2000C {
+        segments: [hl: HL],
+        loop: [loop_2010c: _278Loop2010C],
+        loops: [loop_2000d: _278Loop2000D, loop_2000e: _278Loop2000E],
+    }
+}
+
+loop_builder! {
+    _278Loop2010CBuilder => _278Loop2010C {
+        segments: [nm1: NM1],
+        optional: [n3: N3, n4: N4, dmg: DMG, ins: INS],
+        repeated: [r#ref: REF, aaa: AAA],
+    }
+}
+
+loop_builder! {
+    _278Loop2000DBuilder => _278Loop2000D {
+        segments: [hl: HL],
+        loop: [loop_2010d: _278Loop2010D],
+        loops: [loop_2000e: _278Loop2000E],
+    }
+}
+
+loop_builder! {
+    _278Loop2000EBuilder => _278Loop2000E {
+        segments: [hl: HL, um: UM],
+        optional: [
+            hcr: HCR,
+            hi: HI,
+            hsd: HSD,
+            cl1: CL1,
+            cr1: CR1,
+            cr2: CR2,
+            cr4: CR4,
+            cr5: CR5,
+            cr6: CR6,
+            cr7: CR7,
+            msg: MSG,
+        ],
+        repeated: [trn: TRN, aaa: AAA, r#ref: REF, dtp: DTP, crc: CRC, pwk: PWK],
+        loops: [loop_2010e: _278Loop2010E, loop_2000f: _278Loop2000F],
+    }
+}
+
+loop_builder! {
+    _278Loop2000FBuilder => _278Loop2000F {
+        segments: [hl: HL, um: UM],
+        optional: [hcr: HCR, sv1: SV1, sv2: SV2, sv3: SV3, hsd: HSD, cr3: CR3, msg: MSG],
+        repeated: [trn: TRN, aaa: AAA, r#ref: REF, dtp: DTP, too: TOO, pwk: PWK],
+        loops: [loop_2010f: _278Loop2010F],
+    }
+}
@@ -1,6 +1,8 @@
 //! v005010 repesents all entities of the 005010 specification.
 
+use crate::util::amount::{Amount, AmountError, Balance};
 use crate::util::Parser;
+pub use builder::*;
 use nom::{
     combinator::{opt, peek},
     multi::many0,
@@ -8,8 +10,15 @@ use nom::{
 };
 pub use segment::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Display;
-use x12_types_macros::DisplayX12;
+use x12_types_macros::{DisplayX12, ParseX12};
+
+mod _270_doc;
+pub use _270_doc::*;
+
+mod _271_doc;
+pub use _271_doc::*;
 
 mod _276_doc;
 pub use _276_doc::*;
@@ -17,15 +26,48 @@ pub use _276_doc::*;
 mod _277_doc;
 pub use _277_doc::*;
 
+mod _277ca_doc;
+pub use _277ca_doc::*;
+
+mod _278_doc;
+pub use _278_doc::*;
+
+mod _834_changes;
+pub use _834_changes::*;
+
+mod _834_members;
+pub use _834_members::*;
+
+mod _837_claims;
+pub use _837_claims::*;
+
+pub mod reconcile;
+
+mod builder;
 mod segment;
 
 #[cfg(test)]
+mod test_270;
+#[cfg(test)]
+mod test_277ca;
+#[cfg(test)]
+mod test_278;
+#[cfg(test)]
+mod test_820;
+#[cfg(test)]
+mod test_824;
+#[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_update)]
 mod test_834;
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod test_835;
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod test_837;
 #[cfg(test)]
+mod test_reconcile;
+#[cfg(test)]
 mod test_segments;
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
@@ -89,6 +131,156 @@ pub struct FunctionalGroup<T> {
     pub ge: GE,
 }
 
+/// 820 - Payroll Deducted and Other Group Premium Payment for Insurance Products (005010X218)
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820 {
+    pub st: ST,
+    pub bpr: BPR,
+    pub trn: TRN,
+    pub cur: Option<CUR>,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    /// 1000A premium receiver, 1000B premium payer
+    #[x12(loop_trigger = "N1")]
+    pub loop_1000: Vec<_820Loop1000>,
+    /// 2000A organization summary remittance, 2000B individual remittance
+    #[x12(loop_trigger = "ENT")]
+    pub loop_2000: Vec<_820Loop2000>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop1000 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop2000 {
+    pub ent: ENT,
+    pub loop_2100: Option<_820Loop2100>,
+    #[x12(loop_trigger = "ADX")]
+    pub loop_2200: Vec<_820Loop2200>,
+    #[x12(loop_trigger = "RMR")]
+    pub loop_2300: Vec<_820Loop2300>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop2100 {
+    pub nm1: NM1,
+    pub n2: Vec<N2>,
+    pub n3: Option<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop2200 {
+    pub adx: ADX,
+    pub nte: Vec<NTE>,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop2300 {
+    pub rmr: RMR,
+    pub nte: Vec<NTE>,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    #[x12(loop_trigger = "ADX")]
+    pub loop_2320: Vec<_820Loop2320>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _820Loop2320 {
+    pub adx: ADX,
+    pub nte: Vec<NTE>,
+    pub r#ref: Vec<REF>,
+}
+
+impl _820 {
+    /// Sum of the premium amounts paid (`RMR04`) and the adjustments (2200 and
+    /// 2320 `ADX01`) per `ENT` loop, in document order.
+    pub fn remittance_totals(&self) -> Result<Vec<Amount>, AmountError> {
+        self.loop_2000
+            .iter()
+            .map(|ent| {
+                let paid: Amount = ent
+                    .loop_2300
+                    .iter()
+                    .map(|rmr| Amount::parse_opt(rmr.rmr._04.as_deref()))
+                    .sum::<Result<_, _>>()?;
+                let summary = ent.loop_2200.iter().map(|adx| &adx.adx);
+                let detail = ent
+                    .loop_2300
+                    .iter()
+                    .flat_map(|rmr| rmr.loop_2320.iter().map(|adx| &adx.adx));
+                let adjusted: Amount = summary
+                    .chain(detail)
+                    .map(|adx| adx._01.parse::<Amount>())
+                    .sum::<Result<_, _>>()?;
+                Ok(paid + adjusted)
+            })
+            .collect()
+    }
+
+    /// Compares the total premium payment (`BPR02`) with the sum of all
+    /// `RMR04` and `ADX01` amounts.
+    pub fn check_remittance(&self) -> Result<Balance, AmountError> {
+        Ok(Balance {
+            expected: self.bpr._02.parse()?,
+            actual: self.remittance_totals()?.into_iter().sum(),
+        })
+    }
+}
+
+/// 824 - Application Advice
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _824 {
+    pub st: ST,
+    pub bgn: BGN,
+    #[x12(loop_trigger = "N1")]
+    pub loop_1000: Vec<_824Loop1000>,
+    #[x12(loop_trigger = "OTI")]
+    pub loop_2000: Vec<_824Loop2000>,
+    pub se: SE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _824Loop1000 {
+    pub n1: N1,
+    pub n2: Vec<N2>,
+    pub n3: Vec<N3>,
+    pub n4: Option<N4>,
+    pub r#ref: Vec<REF>,
+    pub per: Vec<PER>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _824Loop2000 {
+    pub oti: OTI,
+    pub r#ref: Vec<REF>,
+    pub dtm: Vec<DTM>,
+    pub per: Vec<PER>,
+    pub amt: Vec<AMT>,
+    pub qty: Vec<QTY>,
+    pub nm1: Vec<NM1>,
+    #[x12(loop_trigger = "TED")]
+    pub loop_2100: Vec<_824Loop2100>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12, ParseX12)]
+pub struct _824Loop2100 {
+    pub ted: TED,
+    pub nte: Vec<NTE>,
+    pub red: Vec<RED>,
+}
+
 /// 834 - Benefit Enrollment and Maintenance
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _834 {
@@ -709,14 +901,343 @@ pub struct _835Loop2110 {
     pub lq: Vec<LQ>,
 }
 
+/// A 005010X221 balancing rule together with the loop it was applied to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum _835BalanceCheck {
+    /// `CLP03` minus all claim and service line adjustments equals `CLP04`.
+    Claim {
+        loop_2000: usize,
+        loop_2100: usize,
+        /// CLP01
+        claim_id: String,
+    },
+    /// The service line payments (`SVC03`) minus the claim level adjustments
+    /// equal `CLP04`.
+    ServiceLines {
+        loop_2000: usize,
+        loop_2100: usize,
+        /// CLP01
+        claim_id: String,
+    },
+    /// `SVC02` minus the service line adjustments equals `SVC03`.
+    ServiceLine {
+        loop_2000: usize,
+        loop_2100: usize,
+        loop_2110: usize,
+        /// CLP01
+        claim_id: String,
+    },
+    /// All `CLP04` payments minus the `PLB` adjustments equal `BPR02`.
+    Transaction,
+}
+
+/// A failed balancing rule; `expected` holds the amount reported in the data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct _835Imbalance {
+    pub check: _835BalanceCheck,
+    pub balance: Balance,
+}
+
+impl _835 {
+    /// Applies the 005010X221 balancing rules and returns every rule that does
+    /// not balance, in document order with the transaction total last.
+    pub fn check_balance(&self) -> Result<Vec<_835Imbalance>, AmountError> {
+        let mut out = vec![];
+        let mut paid = Amount::ZERO;
+        for (i, l2000) in self.loop_2000.iter().enumerate() {
+            for (j, claim) in l2000.loop_2100.iter().enumerate() {
+                let claim_id = &claim.clp._01;
+                let charge: Amount = claim.clp._03.parse()?;
+                let payment: Amount = claim.clp._04.parse()?;
+                paid += payment;
+                let claim_adjustments = cas_total(&claim.cas)?;
+                let mut line_adjustments = Amount::ZERO;
+                let mut line_payments = Amount::ZERO;
+                for (k, line) in claim.loop_2110.iter().enumerate() {
+                    let adjustments = cas_total(&line.cas)?;
+                    let line_paid = Amount::parse_opt(line.svc._03.as_deref())?;
+                    line_adjustments += adjustments;
+                    line_payments += line_paid;
+                    let balance = Balance {
+                        expected: line_paid,
+                        actual: line.svc._02.parse::<Amount>()? - adjustments,
+                    };
+                    if !balance.is_balanced() {
+                        out.push(_835Imbalance {
+                            check: _835BalanceCheck::ServiceLine {
+                                loop_2000: i,
+                                loop_2100: j,
+                                loop_2110: k,
+                                claim_id: claim_id.clone(),
+                            },
+                            balance,
+                        });
+                    }
+                }
+                let balance = Balance {
+                    expected: payment,
+                    actual: charge - claim_adjustments - line_adjustments,
+                };
+                if !balance.is_balanced() {
+                    out.push(_835Imbalance {
+                        check: _835BalanceCheck::Claim {
+                            loop_2000: i,
+                            loop_2100: j,
+                            claim_id: claim_id.clone(),
+                        },
+                        balance,
+                    });
+                }
+                if !claim.loop_2110.is_empty() {
+                    let balance = Balance {
+                        expected: payment,
+                        actual: line_payments - claim_adjustments,
+                    };
+                    if !balance.is_balanced() {
+                        out.push(_835Imbalance {
+                            check: _835BalanceCheck::ServiceLines {
+                                loop_2000: i,
+                                loop_2100: j,
+                                claim_id: claim_id.clone(),
+                            },
+                            balance,
+                        });
+                    }
+                }
+            }
+        }
+        let mut provider_adjustments = Amount::ZERO;
+        for plb in &self.plb {
+            for amount in [&plb._04, &plb._06, &plb._08, &plb._10, &plb._12, &plb._14] {
+                provider_adjustments += Amount::parse_opt(amount.as_deref())?;
+            }
+        }
+        let balance = Balance {
+            expected: self.bpr._02.parse()?,
+            actual: paid - provider_adjustments,
+        };
+        if !balance.is_balanced() {
+            out.push(_835Imbalance {
+                check: _835BalanceCheck::Transaction,
+                balance,
+            });
+        }
+        Ok(out)
+    }
+}
+
+/// Sum of the adjustment amounts (`CAS03`, `CAS06`, ... `CAS18`).
+fn cas_total(cas: &[CAS]) -> Result<Amount, AmountError> {
+    Ok(adjustment_totals(cas)?.into_values().sum())
+}
+
+/// Claim Adjustment Group Code (`CAS01`)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum _835AdjustmentGroup {
+    /// CO
+    ContractualObligations,
+    /// CR
+    CorrectionAndReversals,
+    /// OA
+    OtherAdjustments,
+    /// PI
+    PayorInitiatedReductions,
+    /// PR
+    PatientResponsibility,
+    /// any code outside the 005010X221 list
+    Other(String),
+}
+
+impl _835AdjustmentGroup {
+    pub fn code(&self) -> &str {
+        match self {
+            _835AdjustmentGroup::ContractualObligations => "CO",
+            _835AdjustmentGroup::CorrectionAndReversals => "CR",
+            _835AdjustmentGroup::OtherAdjustments => "OA",
+            _835AdjustmentGroup::PayorInitiatedReductions => "PI",
+            _835AdjustmentGroup::PatientResponsibility => "PR",
+            _835AdjustmentGroup::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for _835AdjustmentGroup {
+    fn from(code: &str) -> _835AdjustmentGroup {
+        match code {
+            "CO" => _835AdjustmentGroup::ContractualObligations,
+            "CR" => _835AdjustmentGroup::CorrectionAndReversals,
+            "OA" => _835AdjustmentGroup::OtherAdjustments,
+            "PI" => _835AdjustmentGroup::PayorInitiatedReductions,
+            "PR" => _835AdjustmentGroup::PatientResponsibility,
+            other => _835AdjustmentGroup::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for _835AdjustmentGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// One reason code, amount and quantity triplet of a `CAS` segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct _835Adjustment {
+    /// CAS01
+    pub group: _835AdjustmentGroup,
+    /// CAS02, CAS05, ... CAS17
+    pub reason: String,
+    /// CAS03, CAS06, ... CAS18
+    pub amount: Amount,
+    /// CAS04, CAS07, ... CAS19
+    pub quantity: Option<f64>,
+}
+
+/// A `CAS` amount or quantity that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum _835AdjustmentError {
+    Amount(AmountError),
+    /// the quantity and the element it is in, e.g. `CAS04`
+    Quantity {
+        element: &'static str,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for _835AdjustmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            _835AdjustmentError::Amount(e) => e.fmt(f),
+            _835AdjustmentError::Quantity { element, value } => {
+                write!(f, "invalid quantity {value:?} in {element}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for _835AdjustmentError {}
+
+impl From<AmountError> for _835AdjustmentError {
+    fn from(e: AmountError) -> _835AdjustmentError {
+        _835AdjustmentError::Amount(e)
+    }
+}
+
+/// Reason code, amount, quantity and the ID of the quantity element.
+type CasTriplet<'a> = (
+    Option<&'a String>,
+    Option<&'a String>,
+    &'a Option<String>,
+    &'static str,
+);
+
+impl CAS {
+    /// The reason, amount and quantity triplets with the quantity element ID.
+    fn triplets(&self) -> [CasTriplet<'_>; 6] {
+        [
+            (Some(&self._02), Some(&self._03), &self._04, "CAS04"),
+            (self._05.as_ref(), self._06.as_ref(), &self._07, "CAS07"),
+            (self._08.as_ref(), self._09.as_ref(), &self._10, "CAS10"),
+            (self._11.as_ref(), self._12.as_ref(), &self._13, "CAS13"),
+            (self._14.as_ref(), self._15.as_ref(), &self._16, "CAS16"),
+            (self._17.as_ref(), self._18.as_ref(), &self._19, "CAS19"),
+        ]
+    }
+
+    /// The up to six adjustments of this segment; triplets without a reason
+    /// code are skipped.
+    pub fn adjustments(
+        &self,
+    ) -> impl Iterator<Item = Result<_835Adjustment, _835AdjustmentError>> + '_ {
+        self.triplets()
+            .into_iter()
+            .filter_map(|(reason, amount, quantity, element)| {
+                let reason = reason.filter(|r| !r.is_empty())?;
+                let adjustment = || {
+                    Ok(_835Adjustment {
+                        group: _835AdjustmentGroup::from(self._01.as_str()),
+                        reason: reason.clone(),
+                        amount: Amount::parse_opt(amount.map(String::as_str))?,
+                        quantity: quantity
+                            .as_deref()
+                            .filter(|q| !q.is_empty())
+                            .map(|q| {
+                                q.parse().map_err(|_| _835AdjustmentError::Quantity {
+                                    element,
+                                    value: q.to_string(),
+                                })
+                            })
+                            .transpose()?,
+                    })
+                };
+                Some(adjustment())
+            })
+    }
+}
+
+/// Adds up adjustment amounts per group code; quantities are not looked at.
+fn adjustment_totals<'a>(
+    cas: impl IntoIterator<Item = &'a CAS>,
+) -> Result<BTreeMap<_835AdjustmentGroup, Amount>, AmountError> {
+    let mut totals = BTreeMap::new();
+    for cas in cas {
+        for (reason, amount, _, _) in cas.triplets() {
+            if !reason.is_some_and(|r| !r.is_empty()) {
+                continue;
+            }
+            let amount = Amount::parse_opt(amount.map(String::as_str))?;
+            *totals
+                .entry(_835AdjustmentGroup::from(cas._01.as_str()))
+                .or_insert(Amount::ZERO) += amount;
+        }
+    }
+    Ok(totals)
+}
+
+impl _835Loop2100 {
+    /// Claim level adjustments; service line adjustments are in `loop_2110`.
+    pub fn adjustments(
+        &self,
+    ) -> impl Iterator<Item = Result<_835Adjustment, _835AdjustmentError>> + '_ {
+        self.cas.iter().flat_map(CAS::adjustments)
+    }
+
+    /// Claim and service line adjustments added up per group code, e.g. the
+    /// patient responsibility under [`_835AdjustmentGroup::PatientResponsibility`].
+    pub fn adjustment_totals(&self) -> Result<BTreeMap<_835AdjustmentGroup, Amount>, AmountError> {
+        adjustment_totals(
+            self.cas
+                .iter()
+                .chain(self.loop_2110.iter().flat_map(|l| &l.cas)),
+        )
+    }
+}
+
+impl _835Loop2110 {
+    pub fn adjustments(
+        &self,
+    ) -> impl Iterator<Item = Result<_835Adjustment, _835AdjustmentError>> + '_ {
+        self.cas.iter().flat_map(CAS::adjustments)
+    }
+
+    /// Service line adjustments added up per group code.
+    pub fn adjustment_totals(&self) -> Result<BTreeMap<_835AdjustmentGroup, Amount>, AmountError> {
+        adjustment_totals(&self.cas)
+    }
+}
+
 /// 837 - Health Care Claim
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+///
+/// The HL loops are nested: billing provider (2000A), subscriber (2000B) and
+/// patient (2000C). Claims (2300) belong to the subscriber, or to the patient
+/// if the patient is not the subscriber.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct _837 {
     pub st: ST,
     pub bht: BHT,
     pub r#ref: Vec<REF>,
     pub loop_1000: Vec<_837Loop1000>,
-    pub loop_2000: Vec<_837Loop2000>,
+    pub loop_2000a: Vec<_837Loop2000A>,
     pub se: SE,
 }
 
@@ -751,278 +1272,408 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
         }
         let rest = loop_rest;
         output.loop_1000 = loop_1000;
-        // loop 2000
-        let mut loop_2000 = vec![];
-        let mut loop_rest = rest;
-        while peek(opt(HL::parse)).parse(loop_rest)?.1.is_some() {
-            let (rest, hl) = HL::parse(loop_rest)?;
-            let (rest, prv) = opt(PRV::parse).parse(rest)?;
-            let (rest, sbr) = opt(SBR::parse).parse(rest)?;
-            let (rest, pat) = opt(PAT::parse).parse(rest)?;
+        // the HL levels are nested: billing provider, subscriber, patient
+        let (rest, obj) = many0(parse_837_loop_2000a).parse(rest)?;
+        output.loop_2000a = obj;
+        let (rest, obj) = SE::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+impl Display for _837 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut loop_2000a = self.loop_2000a.clone();
+        crate::util::hl::renumber(&mut loop_2000a);
+        write!(f, "{}{}", self.st, self.bht)?;
+        for r#ref in &self.r#ref {
+            write!(f, "{ref}")?;
+        }
+        for l in &self.loop_1000 {
+            write!(f, "{l}")?;
+        }
+        for l in &loop_2000a {
+            write!(f, "{l}")?;
+        }
+        write!(f, "{}", self.se)
+    }
+}
+
+impl _837 {
+    /// Numbers the HL levels 1, 2, 3, ... in document order, points `HL02`
+    /// at the parent level and recomputes `HL04`. Rendering does this on a copy.
+    pub fn renumber(&mut self) {
+        crate::util::hl::renumber(&mut self.loop_2000a);
+    }
+}
+
+impl crate::util::hl::Level for _837Loop2000A {
+    type Child = _837Loop2000B;
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        set_hl_ids(&mut self.hl, id, parent_id, child_code);
+    }
+    fn levels_mut(&mut self) -> &mut [_837Loop2000B] {
+        &mut self.loop_2000b
+    }
+}
+
+impl crate::util::hl::Level for _837Loop2000B {
+    type Child = _837Loop2000C;
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        set_hl_ids(&mut self.hl, id, parent_id, child_code);
+    }
+    fn levels_mut(&mut self) -> &mut [_837Loop2000C] {
+        &mut self.loop_2000c
+    }
+}
+
+impl crate::util::hl::Level for _837Loop2000C {
+    type Child = _837Loop2000C;
+    fn set_ids(&mut self, id: String, parent_id: Option<String>, child_code: &str) {
+        set_hl_ids(&mut self.hl, id, parent_id, child_code);
+    }
+    /// The patient is the lowest level.
+    fn levels_mut(&mut self) -> &mut [_837Loop2000C] {
+        &mut []
+    }
+}
+
+fn set_hl_ids(hl: &mut HL, id: String, parent_id: Option<String>, child_code: &str) {
+    hl._01 = id;
+    hl._02 = parent_id;
+    hl._04 = Some(child_code.to_string());
+}
+
+fn parse_837_loop_2000a(input: &str) -> IResult<&str, _837Loop2000A> {
+    let (rest, hl) = parse_hl_level(input, "20")?;
+    let (rest, prv) = opt(PRV::parse).parse(rest)?;
+    let (rest, cur) = opt(CUR::parse).parse(rest)?;
+    let (rest, loop_2010a) = many0(parse_837_loop_2010).parse(rest)?;
+    let (rest, loop_2000b) = many0(parse_837_loop_2000b).parse(rest)?;
+    Ok((
+        rest,
+        _837Loop2000A {
+            hl,
+            prv,
+            cur,
+            loop_2010a,
+            loop_2000b,
+        },
+    ))
+}
+
+fn parse_837_loop_2000b(input: &str) -> IResult<&str, _837Loop2000B> {
+    let (rest, hl) = parse_hl_level(input, "22")?;
+    let (rest, sbr) = SBR::parse(rest)?;
+    let (rest, pat) = opt(PAT::parse).parse(rest)?;
+    let (rest, loop_2010b) = many0(parse_837_loop_2010).parse(rest)?;
+    let (rest, loop_2300) = many0(parse_837_loop_2300).parse(rest)?;
+    let (rest, loop_2000c) = many0(parse_837_loop_2000c).parse(rest)?;
+    Ok((
+        rest,
+        _837Loop2000B {
+            hl,
+            sbr,
+            pat,
+            loop_2010b,
+            loop_2300,
+            loop_2000c,
+        },
+    ))
+}
+
+fn parse_837_loop_2000c(input: &str) -> IResult<&str, _837Loop2000C> {
+    let (rest, hl) = parse_hl_level(input, "23")?;
+    let (rest, pat) = PAT::parse(rest)?;
+    let (rest, loop_2010c) = many0(parse_837_loop_2010).parse(rest)?;
+    let (rest, loop_2300) = many0(parse_837_loop_2300).parse(rest)?;
+    Ok((
+        rest,
+        _837Loop2000C {
+            hl,
+            pat,
+            loop_2010c,
+            loop_2300,
+        },
+    ))
+}
+
+fn parse_837_loop_2010(input: &str) -> IResult<&str, _837Loop2010> {
+    let (rest, nm1) = NM1::parse(input)?;
+    let (rest, n2) = opt(N2::parse).parse(rest)?;
+    let (rest, n3) = opt(N3::parse).parse(rest)?;
+    let (rest, n4) = opt(N4::parse).parse(rest)?;
+    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+    let (rest, r#ref) = opt(REF::parse).parse(rest)?;
+    let (rest, per) = opt(PER::parse).parse(rest)?;
+    Ok((
+        rest,
+        _837Loop2010 {
+            nm1,
+            n2,
+            n3,
+            n4,
+            dmg,
+            r#ref,
+            per,
+        },
+    ))
+}
+
+fn parse_837_loop_2300(input: &str) -> IResult<&str, _837Loop2300> {
+    let (rest, clm) = CLM::parse(input)?;
+    let (rest, dtp) = opt(DTP::parse).parse(rest)?;
+    let (rest, cl1) = opt(CL1::parse).parse(rest)?;
+    let (rest, dn1) = opt(DN1::parse).parse(rest)?;
+    let (rest, dn2) = opt(DN2::parse).parse(rest)?;
+    let (rest, pwk) = opt(PWK::parse).parse(rest)?;
+    let (rest, cn1) = opt(CN1::parse).parse(rest)?;
+    let (rest, dsb) = opt(DSB::parse).parse(rest)?;
+    let (rest, ur) = opt(UR::parse).parse(rest)?;
+    let (rest, amt) = opt(AMT::parse).parse(rest)?;
+    let (rest, r#ref) = opt(REF::parse).parse(rest)?;
+    let (rest, k3) = opt(K3::parse).parse(rest)?;
+    let (rest, nte) = opt(NTE::parse).parse(rest)?;
+    let (rest, cr1) = opt(CR1::parse).parse(rest)?;
+    let (rest, cr2) = opt(CR2::parse).parse(rest)?;
+    let (rest, cr3) = opt(CR3::parse).parse(rest)?;
+    let (rest, cr4) = opt(CR4::parse).parse(rest)?;
+    let (rest, cr5) = opt(CR5::parse).parse(rest)?;
+    let (rest, cr6) = opt(CR6::parse).parse(rest)?;
+    let (rest, cr8) = opt(CR8::parse).parse(rest)?;
+    let (rest, crc) = opt(CRC::parse).parse(rest)?;
+    let (rest, hi) = opt(HI::parse).parse(rest)?;
+    let (rest, qty) = opt(QTY::parse).parse(rest)?;
+    let (rest, hcp) = opt(HCP::parse).parse(rest)?;
+    let mut loop_rest = rest;
+    // loop 2310
+    let mut loop_2310 = vec![];
+    while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+        let (rest, nm1) = NM1::parse(loop_rest)?;
+        let (rest, prv) = opt(PRV::parse).parse(rest)?;
+        let (rest, n2) = opt(N2::parse).parse(rest)?;
+        let (rest, n3) = opt(N3::parse).parse(rest)?;
+        let (rest, n4) = opt(N4::parse).parse(rest)?;
+        let (rest, r#ref) = opt(REF::parse).parse(rest)?;
+        let (rest, per) = opt(PER::parse).parse(rest)?;
+        loop_rest = rest;
+        loop_2310.push(_837Loop2310 {
+            nm1,
+            prv,
+            n2,
+            n3,
+            n4,
+            r#ref,
+            per,
+        });
+    }
+    // loop 2320
+    let mut loop_2320 = vec![];
+    while peek(opt(SBR::parse)).parse(loop_rest)?.1.is_some() {
+        let (rest, sbr) = SBR::parse(loop_rest)?;
+        let (rest, cas) = opt(CAS::parse).parse(rest)?;
+        let (rest, amt) = many0(AMT::parse).parse(rest)?;
+        let (rest, dmg) = opt(DMG::parse).parse(rest)?;
+        let (rest, oi) = opt(OI::parse).parse(rest)?;
+        let (rest, mia) = opt(MIA::parse).parse(rest)?;
+        let (rest, moa) = opt(MOA::parse).parse(rest)?;
+        loop_rest = rest;
+        // loop 2330
+        let mut loop_2330 = vec![];
+        while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, nm1) = NM1::parse(loop_rest)?;
+            let (rest, n2) = opt(N2::parse).parse(rest)?;
+            let (rest, n3) = opt(N3::parse).parse(rest)?;
+            let (rest, n4) = opt(N4::parse).parse(rest)?;
+            let (rest, per) = opt(PER::parse).parse(rest)?;
             let (rest, dtp) = opt(DTP::parse).parse(rest)?;
-            let (rest, cur) = opt(CUR::parse).parse(rest)?;
+            let (rest, r#ref) = opt(REF::parse).parse(rest)?;
             loop_rest = rest;
-            // loop 2010
-            let mut loop_2010 = vec![];
-            while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
-                let (rest, nm1) = NM1::parse(loop_rest)?;
-                let (rest, n2) = opt(N2::parse).parse(rest)?;
-                let (rest, n3) = opt(N3::parse).parse(rest)?;
-                let (rest, n4) = opt(N4::parse).parse(rest)?;
-                let (rest, dmg) = opt(DMG::parse).parse(rest)?;
-                let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                let (rest, per) = opt(PER::parse).parse(rest)?;
-                loop_rest = rest;
-                loop_2010.push(_837Loop2010 {
-                    nm1,
-                    n2,
-                    n3,
-                    n4,
-                    dmg,
-                    r#ref,
-                    per,
-                });
-            }
-            // loop 2300
-            let mut loop_2300 = vec![];
-            while peek(opt(CLM::parse)).parse(loop_rest)?.1.is_some() {
-                let (rest, clm) = CLM::parse(loop_rest)?;
-                let (rest, dtp) = opt(DTP::parse).parse(rest)?;
-                let (rest, cl1) = opt(CL1::parse).parse(rest)?;
-                let (rest, dn1) = opt(DN1::parse).parse(rest)?;
-                let (rest, dn2) = opt(DN2::parse).parse(rest)?;
-                let (rest, pwk) = opt(PWK::parse).parse(rest)?;
-                let (rest, cn1) = opt(CN1::parse).parse(rest)?;
-                let (rest, dsb) = opt(DSB::parse).parse(rest)?;
-                let (rest, ur) = opt(UR::parse).parse(rest)?;
-                let (rest, amt) = opt(AMT::parse).parse(rest)?;
-                let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                let (rest, k3) = opt(K3::parse).parse(rest)?;
-                let (rest, nte) = opt(NTE::parse).parse(rest)?;
-                let (rest, cr1) = opt(CR1::parse).parse(rest)?;
-                let (rest, cr2) = opt(CR2::parse).parse(rest)?;
-                let (rest, cr3) = opt(CR3::parse).parse(rest)?;
-                let (rest, cr4) = opt(CR4::parse).parse(rest)?;
-                let (rest, cr5) = opt(CR5::parse).parse(rest)?;
-                let (rest, cr6) = opt(CR6::parse).parse(rest)?;
-                let (rest, cr8) = opt(CR8::parse).parse(rest)?;
-                let (rest, crc) = opt(CRC::parse).parse(rest)?;
-                let (rest, hi) = opt(HI::parse).parse(rest)?;
-                let (rest, qty) = opt(QTY::parse).parse(rest)?;
-                let (rest, hcp) = opt(HCP::parse).parse(rest)?;
-                loop_rest = rest;
-                // loop 2310
-                let mut loop_2310 = vec![];
-                while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
-                    let (rest, nm1) = NM1::parse(loop_rest)?;
-                    let (rest, prv) = opt(PRV::parse).parse(rest)?;
-                    let (rest, n2) = opt(N2::parse).parse(rest)?;
-                    let (rest, n3) = opt(N3::parse).parse(rest)?;
-                    let (rest, n4) = opt(N4::parse).parse(rest)?;
-                    let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                    let (rest, per) = opt(PER::parse).parse(rest)?;
-                    loop_rest = rest;
-                    loop_2310.push(_837Loop2310 {
-                        nm1,
-                        prv,
-                        n2,
-                        n3,
-                        n4,
-                        r#ref,
-                        per,
-                    });
-                }
-                // loop 2320
-                let mut loop_2320 = vec![];
-                while peek(opt(SBR::parse)).parse(loop_rest)?.1.is_some() {
-                    let (rest, sbr) = SBR::parse(loop_rest)?;
-                    let (rest, cas) = opt(CAS::parse).parse(rest)?;
-                    let (rest, amt) = many0(AMT::parse).parse(rest)?;
-                    let (rest, dmg) = opt(DMG::parse).parse(rest)?;
-                    let (rest, oi) = opt(OI::parse).parse(rest)?;
-                    let (rest, mia) = opt(MIA::parse).parse(rest)?;
-                    let (rest, moa) = opt(MOA::parse).parse(rest)?;
-                    loop_rest = rest;
-                    // loop 2330
-                    let mut loop_2330 = vec![];
-                    while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
-                        let (rest, nm1) = NM1::parse(loop_rest)?;
-                        let (rest, n2) = opt(N2::parse).parse(rest)?;
-                        let (rest, n3) = opt(N3::parse).parse(rest)?;
-                        let (rest, n4) = opt(N4::parse).parse(rest)?;
-                        let (rest, per) = opt(PER::parse).parse(rest)?;
-                        let (rest, dtp) = opt(DTP::parse).parse(rest)?;
-                        let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                        loop_rest = rest;
-                        loop_2330.push(_837Loop2330 {
-                            nm1,
-                            n2,
-                            n3,
-                            n4,
-                            per,
-                            dtp,
-                            r#ref,
-                        });
-                    }
-                    loop_2320.push(_837Loop2320 {
-                        sbr,
-                        cas,
-                        amt,
-                        dmg,
-                        oi,
-                        mia,
-                        moa,
-                        loop_2330,
-                    });
-                }
-                // loop 2400
-                let mut loop_2400 = vec![];
-                while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
-                    let (rest, lx) = LX::parse(loop_rest)?;
-                    let (rest, sv1) = opt(SV1::parse).parse(rest)?;
-                    let (rest, sv2) = opt(SV2::parse).parse(rest)?;
-                    let (rest, sv3) = opt(SV3::parse).parse(rest)?;
-                    let (rest, too) = opt(TOO::parse).parse(rest)?;
-                    let (rest, sv4) = opt(SV4::parse).parse(rest)?;
-                    let (rest, sv5) = opt(SV5::parse).parse(rest)?;
-                    let (rest, sv6) = opt(SV6::parse).parse(rest)?;
-                    let (rest, sv7) = opt(SV7::parse).parse(rest)?;
-                    let (rest, hi) = opt(HI::parse).parse(rest)?;
-                    let (rest, pwk) = opt(PWK::parse).parse(rest)?;
-                    let (rest, cr1) = opt(CR1::parse).parse(rest)?;
-                    let (rest, cr2) = opt(CR2::parse).parse(rest)?;
-                    let (rest, cr3) = opt(CR3::parse).parse(rest)?;
-                    let (rest, cr4) = opt(CR4::parse).parse(rest)?;
-                    let (rest, cr5) = opt(CR5::parse).parse(rest)?;
-                    let (rest, crc) = opt(CRC::parse).parse(rest)?;
-                    let (rest, dtp) = opt(DTP::parse).parse(rest)?;
-                    let (rest, qty) = opt(QTY::parse).parse(rest)?;
-                    let (rest, mea) = opt(MEA::parse).parse(rest)?;
-                    let (rest, cn1) = opt(CN1::parse).parse(rest)?;
-                    let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                    let (rest, amt) = many0(AMT::parse).parse(rest)?;
-                    let (rest, k3) = many0(K3::parse).parse(rest)?;
-                    let (rest, nte) = opt(NTE::parse).parse(rest)?;
-                    let (rest, ps1) = opt(PS1::parse).parse(rest)?;
-                    let (rest, imm) = opt(IMM::parse).parse(rest)?;
-                    let (rest, hsd) = opt(HSD::parse).parse(rest)?;
-                    let (rest, hcp) = opt(HCP::parse).parse(rest)?;
-                    loop_rest = rest;
-                    // loop 2420
-                    let mut loop_2420 = vec![];
-                    while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
-                        let (rest, nm1) = NM1::parse(loop_rest)?;
-                        let (rest, prv) = opt(PRV::parse).parse(rest)?;
-                        let (rest, n2) = opt(N2::parse).parse(rest)?;
-                        let (rest, n3) = opt(N3::parse).parse(rest)?;
-                        let (rest, n4) = opt(N4::parse).parse(rest)?;
-                        let (rest, r#ref) = opt(REF::parse).parse(rest)?;
-                        let (rest, per) = opt(PER::parse).parse(rest)?;
-                        loop_rest = rest;
-                        loop_2420.push(_837Loop2420 {
-                            nm1,
-                            prv,
-                            n2,
-                            n3,
-                            n4,
-                            r#ref,
-                            per,
-                        });
-                    }
-                    // loop 2430
-                    let mut loop_2430 = vec![];
-                    while peek(opt(SVD::parse)).parse(loop_rest)?.1.is_some() {
-                        let (rest, svd) = SVD::parse(loop_rest)?;
-                        let (rest, cas) = many0(CAS::parse).parse(rest)?;
-                        let (rest, dtp) = opt(DTP::parse).parse(rest)?;
-                        let (rest, amt) = opt(AMT::parse).parse(rest)?;
-                        loop_rest = rest;
-                        loop_2430.push(_837Loop2430 { svd, cas, dtp, amt });
-                    }
-                    loop_2400.push(_837Loop2400 {
-                        lx,
-                        sv1,
-                        sv2,
-                        sv3,
-                        too,
-                        sv4,
-                        sv5,
-                        sv6,
-                        sv7,
-                        hi,
-                        pwk,
-                        cr1,
-                        cr2,
-                        cr3,
-                        cr4,
-                        cr5,
-                        crc,
-                        dtp,
-                        qty,
-                        mea,
-                        cn1,
-                        r#ref,
-                        amt,
-                        k3,
-                        nte,
-                        ps1,
-                        imm,
-                        hsd,
-                        hcp,
-                        loop_2410: vec![],
-                        loop_2420,
-                        loop_2430: vec![],
-                        loop_2440: vec![],
-                    });
-                }
-                loop_2300.push(_837Loop2300 {
-                    clm,
-                    dtp,
-                    cl1,
-                    dn1,
-                    dn2,
-                    pwk,
-                    cn1,
-                    dsb,
-                    ur,
-                    amt,
-                    r#ref,
-                    k3,
-                    nte,
-                    cr1,
-                    cr2,
-                    cr3,
-                    cr4,
-                    cr5,
-                    cr6,
-                    cr8,
-                    crc,
-                    hi,
-                    qty,
-                    hcp,
-                    loop_2305: vec![],
-                    loop_2310,
-                    loop_2320,
-                    loop_2400,
-                });
-            }
-            loop_2000.push(_837Loop2000 {
-                hl,
-                prv,
-                sbr,
-                pat,
+            loop_2330.push(_837Loop2330 {
+                nm1,
+                n2,
+                n3,
+                n4,
+                per,
                 dtp,
-                cur,
-                loop_2010,
-                loop_2300,
+                r#ref,
             });
         }
-        let rest = loop_rest;
-        output.loop_2000 = loop_2000;
-        let (rest, obj) = SE::parse(rest)?;
-        output.se = obj;
-        Ok((rest, output))
+        loop_2320.push(_837Loop2320 {
+            sbr,
+            cas,
+            amt,
+            dmg,
+            oi,
+            mia,
+            moa,
+            loop_2330,
+        });
+    }
+    // loop 2400
+    let mut loop_2400 = vec![];
+    while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+        let (rest, lx) = LX::parse(loop_rest)?;
+        let (rest, sv1) = opt(SV1::parse).parse(rest)?;
+        let (rest, sv2) = opt(SV2::parse).parse(rest)?;
+        let (rest, sv3) = opt(SV3::parse).parse(rest)?;
+        let (rest, too) = opt(TOO::parse).parse(rest)?;
+        let (rest, sv4) = opt(SV4::parse).parse(rest)?;
+        let (rest, sv5) = opt(SV5::parse).parse(rest)?;
+        let (rest, sv6) = opt(SV6::parse).parse(rest)?;
+        let (rest, sv7) = opt(SV7::parse).parse(rest)?;
+        let (rest, hi) = opt(HI::parse).parse(rest)?;
+        let (rest, pwk) = opt(PWK::parse).parse(rest)?;
+        let (rest, cr1) = opt(CR1::parse).parse(rest)?;
+        let (rest, cr2) = opt(CR2::parse).parse(rest)?;
+        let (rest, cr3) = opt(CR3::parse).parse(rest)?;
+        let (rest, cr4) = opt(CR4::parse).parse(rest)?;
+        let (rest, cr5) = opt(CR5::parse).parse(rest)?;
+        let (rest, crc) = opt(CRC::parse).parse(rest)?;
+        let (rest, dtp) = opt(DTP::parse).parse(rest)?;
+        let (rest, qty) = opt(QTY::parse).parse(rest)?;
+        let (rest, mea) = opt(MEA::parse).parse(rest)?;
+        let (rest, cn1) = opt(CN1::parse).parse(rest)?;
+        let (rest, r#ref) = opt(REF::parse).parse(rest)?;
+        let (rest, amt) = many0(AMT::parse).parse(rest)?;
+        let (rest, k3) = many0(K3::parse).parse(rest)?;
+        let (rest, nte) = opt(NTE::parse).parse(rest)?;
+        let (rest, ps1) = opt(PS1::parse).parse(rest)?;
+        let (rest, imm) = opt(IMM::parse).parse(rest)?;
+        let (rest, hsd) = opt(HSD::parse).parse(rest)?;
+        let (rest, hcp) = opt(HCP::parse).parse(rest)?;
+        loop_rest = rest;
+        // loop 2420
+        let mut loop_2420 = vec![];
+        while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, nm1) = NM1::parse(loop_rest)?;
+            let (rest, prv) = opt(PRV::parse).parse(rest)?;
+            let (rest, n2) = opt(N2::parse).parse(rest)?;
+            let (rest, n3) = opt(N3::parse).parse(rest)?;
+            let (rest, n4) = opt(N4::parse).parse(rest)?;
+            let (rest, r#ref) = opt(REF::parse).parse(rest)?;
+            let (rest, per) = opt(PER::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_2420.push(_837Loop2420 {
+                nm1,
+                prv,
+                n2,
+                n3,
+                n4,
+                r#ref,
+                per,
+            });
+        }
+        // loop 2430
+        let mut loop_2430 = vec![];
+        while peek(opt(SVD::parse)).parse(loop_rest)?.1.is_some() {
+            let (rest, svd) = SVD::parse(loop_rest)?;
+            let (rest, cas) = many0(CAS::parse).parse(rest)?;
+            let (rest, dtp) = opt(DTP::parse).parse(rest)?;
+            let (rest, amt) = opt(AMT::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_2430.push(_837Loop2430 { svd, cas, dtp, amt });
+        }
+        loop_2400.push(_837Loop2400 {
+            lx,
+            sv1,
+            sv2,
+            sv3,
+            too,
+            sv4,
+            sv5,
+            sv6,
+            sv7,
+            hi,
+            pwk,
+            cr1,
+            cr2,
+            cr3,
+            cr4,
+            cr5,
+            crc,
+            dtp,
+            qty,
+            mea,
+            cn1,
+            r#ref,
+            amt,
+            k3,
+            nte,
+            ps1,
+            imm,
+            hsd,
+            hcp,
+            loop_2410: vec![],
+            loop_2420,
+            loop_2430: vec![],
+            loop_2440: vec![],
+        });
     }
+    Ok((
+        loop_rest,
+        _837Loop2300 {
+            clm,
+            dtp,
+            cl1,
+            dn1,
+            dn2,
+            pwk,
+            cn1,
+            dsb,
+            ur,
+            amt,
+            r#ref,
+            k3,
+            nte,
+            cr1,
+            cr2,
+            cr3,
+            cr4,
+            cr5,
+            cr6,
+            cr8,
+            crc,
+            hi,
+            qty,
+            hcp,
+            loop_2305: vec![],
+            loop_2310,
+            loop_2320,
+            loop_2400,
+        },
+    ))
+}
+
+/// Billing Provider Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _837Loop2000A {
+    pub hl: HL,
+    pub prv: Option<PRV>,
+    pub cur: Option<CUR>,
+    pub loop_2010a: Vec<_837Loop2010>,
+    pub loop_2000b: Vec<_837Loop2000B>,
+}
+
+/// Subscriber Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _837Loop2000B {
+    pub hl: HL,
+    pub sbr: SBR,
+    pub pat: Option<PAT>,
+    pub loop_2010b: Vec<_837Loop2010>,
+    pub loop_2300: Vec<_837Loop2300>,
+    /// patients other than the subscriber
+    pub loop_2000c: Vec<_837Loop2000C>,
+}
+
+/// Patient Level
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
+pub struct _837Loop2000C {
+    pub hl: HL,
+    pub pat: PAT,
+    pub loop_2010c: Vec<_837Loop2010>,
+    pub loop_2300: Vec<_837Loop2300>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
@@ -1035,18 +1686,6 @@ pub struct _837Loop1000 {
     pub per: Vec<PER>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
-pub struct _837Loop2000 {
-    pub hl: HL,
-    pub prv: Option<PRV>,
-    pub sbr: Option<SBR>,
-    pub pat: Option<PAT>,
-    pub dtp: Option<DTP>,
-    pub cur: Option<CUR>,
-    pub loop_2010: Vec<_837Loop2010>,
-    pub loop_2300: Vec<_837Loop2300>,
-}
-
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _837Loop2010 {
     pub nm1: NM1,
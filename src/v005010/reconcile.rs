@@ -0,0 +1,276 @@
+//! Matching 835 remittances back to the submitted 837 claims.
+//!
+//! Claims are paired by patient control number (`CLM01` ↔ `CLP01`), service
+//! lines by their line item control number (`REF*6R` in 2400 ↔ `REF*6R` in
+//! 2110). Service lines the payer returned without a control number are paired
+//! with the first open line of the same procedure code, each composite split
+//! on the component separator (`ISA16`) of its own interchange.
+//!
+//! Contractual obligations (`CO`), other adjustments (`OA`) and payer
+//! initiated reductions (`PI`) are written off by the provider; only patient
+//! responsibility (`PR`) keeps a claim from counting as paid.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::{
+    _835AdjustmentGroup, _835Loop2100, _835Loop2110, _837Loop2300, _837Loop2400, Transmission,
+    _835, _837, REF,
+};
+use crate::util::amount::{Amount, AmountError};
+
+/// How a claim or service line was adjudicated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// The charge less the write-offs (`CO`, `OA` and `PI`) was paid.
+    Paid,
+    /// Part of the charge is left to the patient (`PR`) or not accounted for.
+    /// The payment may be zero, e.g. for a claim applied to the deductible.
+    PartiallyPaid,
+    /// The claim status (`CLP02`) is `4`, or nothing was paid and nothing
+    /// was left to the patient (`PR`).
+    Denied,
+    /// A reversal of a previous payment (`CLP02` = `22`).
+    Reversed,
+}
+
+/// A submitted claim with one remittance for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimRecord<'a> {
+    /// CLM01 / CLP01
+    pub patient_control_number: String,
+    pub claim: &'a _837Loop2300,
+    pub payment: &'a _835Loop2100,
+    pub outcome: Outcome,
+    /// CLP03
+    pub charge: Amount,
+    /// CLP04
+    pub paid: Amount,
+    pub lines: Vec<LineRecord<'a>>,
+}
+
+/// A service line of a matched claim, submitted, paid or both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineRecord<'a> {
+    /// REF02 of `REF*6R`
+    pub control_number: Option<String>,
+    /// `None` if the payer reported a line that was not submitted.
+    pub line: Option<&'a _837Loop2400>,
+    /// `None` if the payer did not report the submitted line.
+    pub payment: Option<&'a _835Loop2110>,
+    /// `None` without a payment
+    pub outcome: Option<Outcome>,
+    /// SVC02, or the submitted SV102 / SV203 without a payment
+    pub charge: Amount,
+    /// SVC03
+    pub paid: Amount,
+}
+
+/// Outcome of [`reconcile`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Reconciliation<'a> {
+    pub paid: Vec<ClaimRecord<'a>>,
+    pub partially_paid: Vec<ClaimRecord<'a>>,
+    pub denied: Vec<ClaimRecord<'a>>,
+    pub reversed: Vec<ClaimRecord<'a>>,
+    /// Submitted claims without any remittance.
+    pub unmatched_claims: Vec<&'a _837Loop2300>,
+    /// Remittances for claims that were not submitted.
+    pub unmatched_payments: Vec<&'a _835Loop2100>,
+}
+
+/// Matches the claim payments of the 835s in `remittances` to the claims of
+/// the 837s in `submissions`.
+///
+/// Every `CLP` loop becomes one record, so a claim that was reversed and paid
+/// again shows up under both [`Reconciliation::reversed`] and its new outcome.
+pub fn reconcile<'a>(
+    submissions: &'a [Transmission<_837>],
+    remittances: &'a [Transmission<_835>],
+) -> Result<Reconciliation<'a>, AmountError> {
+    let claims: Vec<(&_837Loop2300, char)> = submissions
+        .iter()
+        .flat_map(|interchange| {
+            let separator = component_separator(&interchange.isa._16);
+            interchange
+                .functional_group
+                .iter()
+                .flat_map(|group| &group.segments)
+                .flat_map(_837::claim_loops)
+                .map(move |claim| (claim, separator))
+        })
+        .collect();
+    let mut by_id: HashMap<&str, (&_837Loop2300, char)> = HashMap::new();
+    for &(claim, separator) in &claims {
+        by_id
+            .entry(claim.clm._01.as_str())
+            .or_insert((claim, separator));
+    }
+    let mut matched: HashSet<&str> = HashSet::new();
+    let mut out = Reconciliation::default();
+    let payments = remittances.iter().flat_map(|interchange| {
+        let separator = component_separator(&interchange.isa._16);
+        interchange
+            .functional_group
+            .iter()
+            .flat_map(|group| &group.segments)
+            .flat_map(|r| &r.loop_2000)
+            .flat_map(|l| &l.loop_2100)
+            .map(move |payment| (payment, separator))
+    });
+    for (payment, payment_separator) in payments {
+        let id = payment.clp._01.as_str();
+        let Some(&(claim, claim_separator)) = by_id.get(id) else {
+            out.unmatched_payments.push(payment);
+            continue;
+        };
+        matched.insert(id);
+        let separators = Separators {
+            claim: claim_separator,
+            payment: payment_separator,
+        };
+        let record = ClaimRecord::new(claim, payment, separators)?;
+        match record.outcome {
+            Outcome::Paid => out.paid.push(record),
+            Outcome::PartiallyPaid => out.partially_paid.push(record),
+            Outcome::Denied => out.denied.push(record),
+            Outcome::Reversed => out.reversed.push(record),
+        }
+    }
+    out.unmatched_claims = claims
+        .into_iter()
+        .map(|(claim, _)| claim)
+        .filter(|claim| !matched.contains(claim.clm._01.as_str()))
+        .collect();
+    Ok(out)
+}
+
+/// Component separators (`ISA16`) of the interchanges of a claim and its payment.
+#[derive(Clone, Copy)]
+struct Separators {
+    claim: char,
+    payment: char,
+}
+
+fn component_separator(isa16: &str) -> char {
+    isa16.chars().next().unwrap_or(':')
+}
+
+impl<'a> ClaimRecord<'a> {
+    fn new(
+        claim: &'a _837Loop2300,
+        payment: &'a _835Loop2100,
+        separators: Separators,
+    ) -> Result<ClaimRecord<'a>, AmountError> {
+        let status = payment.clp._02.as_str();
+        let charge: Amount = payment.clp._03.parse()?;
+        let paid: Amount = payment.clp._04.parse()?;
+        let totals = payment.adjustment_totals()?;
+        Ok(ClaimRecord {
+            patient_control_number: payment.clp._01.clone(),
+            claim,
+            payment,
+            outcome: outcome(status, charge, paid, &totals),
+            charge,
+            paid,
+            lines: match_lines(status, claim, payment, separators)?,
+        })
+    }
+}
+
+fn outcome(
+    status: &str,
+    charge: Amount,
+    paid: Amount,
+    totals: &BTreeMap<_835AdjustmentGroup, Amount>,
+) -> Outcome {
+    let total = |group| totals.get(&group).copied().unwrap_or_default();
+    let written_off = total(_835AdjustmentGroup::ContractualObligations)
+        + total(_835AdjustmentGroup::OtherAdjustments)
+        + total(_835AdjustmentGroup::PayorInitiatedReductions);
+    let patient = total(_835AdjustmentGroup::PatientResponsibility);
+    if status == "22" {
+        Outcome::Reversed
+    } else if status == "4" || (paid == Amount::ZERO && patient == Amount::ZERO) {
+        Outcome::Denied
+    } else if paid + written_off == charge {
+        Outcome::Paid
+    } else {
+        Outcome::PartiallyPaid
+    }
+}
+
+fn match_lines<'a>(
+    status: &str,
+    claim: &'a _837Loop2300,
+    payment: &'a _835Loop2100,
+    separators: Separators,
+) -> Result<Vec<LineRecord<'a>>, AmountError> {
+    let mut open: Vec<Option<&_837Loop2400>> = claim.loop_2400.iter().map(Some).collect();
+    let mut out = vec![];
+    for service in &payment.loop_2110 {
+        let control = control_number(&service.r#ref);
+        let position = match control {
+            Some(control) => open.iter().position(|l| {
+                l.is_some_and(|l| control_number(l.r#ref.as_slice()) == Some(control))
+            }),
+            None => {
+                let procedure = service.svc._06.as_deref().unwrap_or(&service.svc._01);
+                open.iter().position(|l| {
+                    l.and_then(submitted_procedure)
+                        .is_some_and(|p| same_procedure(p, procedure, separators))
+                })
+            }
+        };
+        let charge: Amount = service.svc._02.parse()?;
+        let paid = Amount::parse_opt(service.svc._03.as_deref())?;
+        let totals = service.adjustment_totals()?;
+        out.push(LineRecord {
+            control_number: control.map(str::to_string),
+            line: position.and_then(|i| open[i].take()),
+            payment: Some(service),
+            outcome: Some(outcome(status, charge, paid, &totals)),
+            charge,
+            paid,
+        });
+    }
+    for line in open.into_iter().flatten() {
+        let charge = match (&line.sv1, &line.sv2) {
+            (Some(sv1), _) => Amount::parse_opt(sv1._02.as_deref())?,
+            (None, Some(sv2)) => Amount::parse_opt(sv2._03.as_deref())?,
+            (None, None) => Amount::ZERO,
+        };
+        out.push(LineRecord {
+            control_number: control_number(line.r#ref.as_slice()).map(str::to_string),
+            line: Some(line),
+            payment: None,
+            outcome: None,
+            charge,
+            paid: Amount::ZERO,
+        });
+    }
+    Ok(out)
+}
+
+/// REF02 of the `REF*6R` (line item control number)
+fn control_number(refs: &[REF]) -> Option<&str> {
+    refs.iter()
+        .find(|r| r._01 == "6R")
+        .and_then(|r| r._02.as_deref())
+        .filter(|c| !c.is_empty())
+}
+
+fn submitted_procedure(line: &_837Loop2400) -> Option<&str> {
+    match (&line.sv1, &line.sv2) {
+        (Some(sv1), _) => Some(&sv1._01),
+        (None, Some(sv2)) => sv2._02.as_deref(),
+        (None, None) => None,
+    }
+}
+
+/// Compares qualifier and code of a submitted and a paid procedure, ignoring modifiers.
+fn same_procedure(submitted: &str, paid: &str, separators: Separators) -> bool {
+    submitted
+        .split(separators.claim)
+        .take(2)
+        .eq(paid.split(separators.payment).take(2))
+}
@@ -2,7 +2,57 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
+mod meta;
+pub use meta::segment_descriptor;
+
+/// AAA - Request Validation
+///
+/// To specify the validity of the request and indicate follow-up action authorized
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 02 | 559 | Agency Qualifier Code | 1 | O | ID | 2/2
+/// 03 | 901 | Reject Reason Code | 1 | O | ID | 2/2
+/// 04 | 889 | Follow-up Action Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct AAA {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
 /// ACT - Account Identification
+///
+/// To identify the account
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 508 | Account Number | 1 | M | AN | 1/35
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 569 | Account Number Qualifier | 1 | X | ID | 1/3
+/// 06 | 508 | Account Number | 1 | X | AN | 1/35
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+/// 08 | 1216 | Payment Method Type Code | 1 | O | ID | 1/2
+/// 09 | 1217 | Benefit Status Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -53,6 +103,39 @@ pub struct AD1 {
     pub _05: Option<String>,
 }
 
+/// ADX - Adjustment
+///
+/// To convey accounts-payable adjustment information for the purpose of cash application, including payer-generated debit/credit memos
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 02 | 426 | Adjustment Reason Code | 1 | M | ID | 2/2
+/// 03 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 04 | 127 | Reference Identification | 1 | X | AN | 1/50
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct ADX {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
 /// AIN - Income
 #[derive(
     Serialize,
@@ -96,6 +179,14 @@ pub struct AIN {
 }
 
 /// AMT - Monitary Amount Information
+///
+/// To indicate the total monetary amount
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 522 | Amount Qualifier Code | 1 | M | ID | 1/3
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 478 | Credit/Debit Flag Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -146,6 +237,20 @@ pub struct BEN {
 }
 
 /// BGN - Beginning Segment
+///
+/// To indicate the beginning of a transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 337 | Time | 1 | X | TM | 4/8
+/// 05 | 623 | Time Code | 1 | O | ID | 2/2
+/// 06 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 07 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 08 | 306 | Action Code | 1 | O | ID | 1/2
+/// 09 | 786 | Security Level Code | 1 | O | ID | 2/2
 #[derive(
     Serialize,
     Deserialize,
@@ -180,6 +285,17 @@ pub struct BGN {
 }
 
 /// BHT - Beginning of Hierarchical Transaction
+///
+/// To define the business hierarchical structure of the transaction set and identify the business application purpose and reference data, i.e., number, date, and time
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1005 | Hierarchical Structure Code | 1 | M | ID | 4/4
+/// 02 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 03 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 04 | 373 | Date | 1 | O | DT | 8/8
+/// 05 | 337 | Time | 1 | O | TM | 4/8
+/// 06 | 640 | Transaction Type Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -199,6 +315,32 @@ pub struct BHT {
 }
 
 /// BPR - Beginning Segment for Payment Order/Remittance Advice
+///
+/// To indicate the beginning of a Payment Order/Remittance Advice Transaction Set and total payment amount, or to enable related transfer of funds and/or information from payer to payee to occur
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 305 | Transaction Handling Code | 1 | M | ID | 1/2
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 478 | Credit/Debit Flag Code | 1 | M | ID | 1/1
+/// 04 | 591 | Payment Method Code | 1 | M | ID | 3/3
+/// 05 | 812 | Payment Format Code | 1 | O | ID | 1/10
+/// 06 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 07 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 08 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 09 | 508 | Account Number | 1 | X | AN | 1/35
+/// 10 | 509 | Originating Company Identifier | 1 | O | AN | 10/10
+/// 11 | 510 | Originating Company Supplemental Code | 1 | O | AN | 9/9
+/// 12 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 13 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 14 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 15 | 508 | Account Number | 1 | X | AN | 1/35
+/// 16 | 373 | Date | 1 | O | DT | 8/8
+/// 17 | 1048 | Business Function Code | 1 | O | ID | 1/3
+/// 18 | 506 | (DFI) ID Number Qualifier | 1 | X | ID | 2/2
+/// 19 | 507 | (DFI) Identification Number | 1 | X | AN | 3/12
+/// 20 | 569 | Account Number Qualifier | 1 | O | ID | 1/3
+/// 21 | 508 | Account Number | 1 | X | AN | 1/35
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -248,6 +390,30 @@ pub struct BPR {
 }
 
 /// CAS - Claims Adjustment
+///
+/// To supply adjustment reason codes and amounts as needed for an entire claim or for a particular service within the claim being paid
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1033 | Claim Adjustment Group Code | 1 | M | ID | 1/2
+/// 02 | 1034 | Claim Adjustment Reason Code | 1 | M | ID | 1/5
+/// 03 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 04 | 380 | Quantity | 1 | O | R | 1/15
+/// 05 | 1034 | Claim Adjustment Reason Code | 1 | X | ID | 1/5
+/// 06 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 07 | 380 | Quantity | 1 | X | R | 1/15
+/// 08 | 1034 | Claim Adjustment Reason Code | 1 | X | ID | 1/5
+/// 09 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 10 | 380 | Quantity | 1 | X | R | 1/15
+/// 11 | 1034 | Claim Adjustment Reason Code | 1 | X | ID | 1/5
+/// 12 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 13 | 380 | Quantity | 1 | X | R | 1/15
+/// 14 | 1034 | Claim Adjustment Reason Code | 1 | X | ID | 1/5
+/// 15 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 16 | 380 | Quantity | 1 | X | R | 1/15
+/// 17 | 1034 | Claim Adjustment Reason Code | 1 | X | ID | 1/5
+/// 18 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 19 | 380 | Quantity | 1 | X | R | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -293,6 +459,15 @@ pub struct CAS {
 }
 
 /// CL1 - Claim Codes
+///
+/// To supply information specific to hospital claims
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1315 | Admission Type Code | 1 | O | ID | 1/1
+/// 02 | 1314 | Admission Source Code | 1 | O | ID | 1/1
+/// 03 | 1352 | Patient Status Code | 1 | O | ID | 1/2
+/// 04 | 1345 | Nursing Home Residential Status Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -308,6 +483,31 @@ pub struct CL1 {
 }
 
 /// CLM - Health Claim
+///
+/// To specify basic data about the claim
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1028 | Claim Submitter's Identifier | 1 | M | AN | 1/38
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 1032 | Claim Filing Indicator Code | 1 | O | ID | 1/2
+/// 04 | 1343 | Non-Institutional Claim Type Code | 1 | O | ID | 1/2
+/// 05 | C023 | Health Care Service Location Information | 1 | O |  |
+/// 06 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 07 | 1359 | Provider Accept Assignment Code | 1 | O | ID | 1/1
+/// 08 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 09 | 1363 | Release of Information Code | 1 | O | ID | 1/1
+/// 10 | 1351 | Patient Signature Source Code | 1 | O | ID | 1/1
+/// 11 | C024 | Related Causes Information | 1 | O |  |
+/// 12 | 1366 | Special Program Code | 1 | O | ID | 2/3
+/// 13 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 14 | 1338 | Level of Service Code | 1 | O | ID | 1/3
+/// 15 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 16 | 1360 | Provider Agreement Code | 1 | O | ID | 1/1
+/// 17 | 1029 | Claim Status Code | 1 | O | ID | 1/2
+/// 18 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 19 | 1383 | Claim Submission Reason Code | 1 | O | ID | 2/2
+/// 20 | 1514 | Delay Reason Code | 1 | O | ID | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -355,6 +555,25 @@ pub struct CLM {
 }
 
 /// CLP - Claim Level Data
+///
+/// To supply information common to all services of a claim
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1028 | Claim Submitter's Identifier | 1 | M | AN | 1/38
+/// 02 | 1029 | Claim Status Code | 1 | M | ID | 1/2
+/// 03 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 04 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 05 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 06 | 1032 | Claim Filing Indicator Code | 1 | O | ID | 1/2
+/// 07 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 08 | 1331 | Facility Code Value | 1 | O | AN | 1/2
+/// 09 | 1325 | Claim Frequency Type Code | 1 | O | ID | 1/1
+/// 10 | 1352 | Patient Status Code | 1 | O | ID | 1/2
+/// 11 | 1354 | Diagnosis Related Group (DRG) Code | 1 | O | ID | 1/4
+/// 12 | 380 | Quantity | 1 | O | R | 1/15
+/// 13 | 954 | Percent | 1 | O | R | 1/10
+/// 14 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -390,6 +609,17 @@ pub struct CLP {
 }
 
 /// CN1 - Contract Information
+///
+/// To specify basic data about the contract or contract line item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1166 | Contract Type Code | 1 | M | ID | 2/2
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 332 | Percent, Decimal Format | 1 | O | R | 1/6
+/// 04 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 05 | 338 | Terms Discount Percent | 1 | O | R | 1/6
+/// 06 | 799 | Version Identifier | 1 | O | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -409,6 +639,15 @@ pub struct CN1 {
 }
 
 /// COB - Coordination of Benefits
+///
+/// To supply information on coordination of benefits
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1138 | Payer Responsibility Sequence Number Code | 1 | O | ID | 1/1
+/// 02 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 03 | 1143 | Coordination of Benefits Code | 1 | O | ID | 1/1
+/// 04 | 1365 | Service Type Code | 1 | O | ID | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -424,6 +663,21 @@ pub struct COB {
 }
 
 /// CR1 - Ambulance Certification
+///
+/// To supply information related to the ambulance service rendered to a patient
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 02 | 81 | Weight | 1 | X | R | 1/10
+/// 03 | 1316 | Ambulance Transport Code | 1 | O | ID | 1/1
+/// 04 | 1317 | Ambulance Transport Reason Code | 1 | O | ID | 1/1
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 06 | 380 | Quantity | 1 | X | R | 1/15
+/// 07 | 166 | Address Information | 1 | O | AN | 1/55
+/// 08 | 166 | Address Information | 1 | O | AN | 1/55
+/// 09 | 352 | Description | 1 | O | AN | 1/80
+/// 10 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -451,6 +705,23 @@ pub struct CR1 {
 }
 
 /// CR2 - Chiropractic Certification
+///
+/// To supply information related to the chiropractic service rendered to a patient
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 609 | Count | 1 | O | N0 | 1/9
+/// 02 | 380 | Quantity | 1 | O | R | 1/15
+/// 03 | 1367 | Subluxation Level Code | 1 | X | ID | 2/3
+/// 04 | 1367 | Subluxation Level Code | 1 | O | ID | 2/3
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 06 | 380 | Quantity | 1 | X | R | 1/15
+/// 07 | 380 | Quantity | 1 | O | R | 1/15
+/// 08 | 1342 | Nature of Condition Code | 1 | O | ID | 1/1
+/// 09 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 10 | 352 | Description | 1 | O | AN | 1/80
+/// 11 | 352 | Description | 1 | O | AN | 1/80
+/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -491,6 +762,16 @@ pub struct CR2 {
 }
 
 /// CR3 - Durable Medical Equipment Certification
+///
+/// To supply information regarding a physician's certification for durable medical equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1322 | Certification Type Code | 1 | O | ID | 1/1
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 03 | 380 | Quantity | 1 | X | R | 1/15
+/// 04 | 1335 | Insulin Dependent Code | 1 | O | ID | 1/1
+/// 05 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize,
     Deserialize,
@@ -701,6 +982,14 @@ pub struct CR6 {
 }
 
 /// CR7 - Home Health Treatment Plan Certification
+///
+/// To supply certification information for a home health treatment plan
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 921 | Discipline Type Code | 1 | M | ID | 2/2
+/// 02 | 1470 | Number | 1 | M | N0 | 1/9
+/// 03 | 1470 | Number | 1 | M | N0 | 1/9
 #[derive(
     Serialize,
     Deserialize,
@@ -757,6 +1046,18 @@ pub struct CR8 {
 }
 
 /// CRC - Conditions Indicator
+///
+/// To supply information on conditions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1136 | Code Category | 1 | M | ID | 2/2
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 03 | 1321 | Condition Indicator | 1 | M | ID | 2/3
+/// 04 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 05 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 06 | 1321 | Condition Indicator | 1 | O | ID | 2/3
+/// 07 | 1321 | Condition Indicator | 1 | O | ID | 2/3
 #[derive(
     Serialize,
     Deserialize,
@@ -787,6 +1088,22 @@ pub struct CRC {
 }
 
 /// CTP - Pricing Information
+///
+/// To specify pricing information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 687 | Class of Trade Code | 1 | O | ID | 2/2
+/// 02 | 236 | Price Identifier Code | 1 | X | ID | 3/3
+/// 03 | 212 | Unit Price | 1 | X | R | 1/17
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 06 | 648 | Price Multiplier Qualifier | 1 | X | ID | 3/3
+/// 07 | 649 | Multiplier | 1 | X | R | 1/10
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 639 | Basis of Unit Price Code | 1 | O | ID | 2/2
+/// 10 | 499 | Condition Value | 1 | O | AN | 1/10
+/// 11 | 289 | Multiple Price Quantity | 1 | O | N0 | 1/2
 #[derive(
     Serialize,
     Deserialize,
@@ -825,6 +1142,32 @@ pub struct CTP {
 }
 
 /// CUR - Currency
+///
+/// To specify the currency (dollars, pounds, francs, etc.) used in a transaction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 100 | Currency Code | 1 | M | ID | 3/3
+/// 03 | 280 | Exchange Rate | 1 | O | R | 4/10
+/// 04 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 05 | 100 | Currency Code | 1 | X | ID | 3/3
+/// 06 | 669 | Currency Market/Exchange Code | 1 | O | ID | 3/3
+/// 07 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 08 | 373 | Date | 1 | O | DT | 8/8
+/// 09 | 337 | Time | 1 | O | TM | 4/8
+/// 10 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 11 | 373 | Date | 1 | X | DT | 8/8
+/// 12 | 337 | Time | 1 | X | TM | 4/8
+/// 13 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 14 | 373 | Date | 1 | X | DT | 8/8
+/// 15 | 337 | Time | 1 | X | TM | 4/8
+/// 16 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 17 | 373 | Date | 1 | X | DT | 8/8
+/// 18 | 337 | Time | 1 | X | TM | 4/8
+/// 19 | 374 | Date/Time Qualifier | 1 | X | ID | 3/3
+/// 20 | 373 | Date | 1 | X | DT | 8/8
+/// 21 | 337 | Time | 1 | X | TM | 4/8
 #[derive(
     Serialize,
     Deserialize,
@@ -883,6 +1226,22 @@ pub struct CUR {
 }
 
 /// DMG - Demographic Information
+///
+/// To supply demographic information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 02 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 03 | 1068 | Gender Code | 1 | O | ID | 1/1
+/// 04 | 1067 | Marital Status Code | 1 | O | ID | 1/1
+/// 05 | C056 | Composite Race or Ethnicity Information | 1 | X |  |
+/// 06 | 1066 | Citizenship Status Code | 1 | O | ID | 1/2
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 659 | Basis of Verification Code | 1 | O | ID | 1/2
+/// 09 | 380 | Quantity | 1 | O | R | 1/15
+/// 10 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 11 | 1271 | Industry Code | 1 | X | AN | 1/30
 #[derive(
     Serialize,
     Deserialize,
@@ -921,6 +1280,15 @@ pub struct DMG {
 }
 
 /// DN1 - Orthodontic Information
+///
+/// To supply orthodontic information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | O | R | 1/15
+/// 02 | 380 | Quantity | 1 | O | R | 1/15
+/// 03 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 04 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize,
     Deserialize,
@@ -945,6 +1313,17 @@ pub struct DN1 {
 }
 
 /// DN2 - Tooth Summary
+///
+/// To supply information on the status of individual teeth
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1271 | Industry Code | 1 | M | AN | 1/30
+/// 02 | 1368 | Tooth Status Code | 1 | M | ID | 1/2
+/// 03 | 380 | Quantity | 1 | O | R | 1/15
+/// 04 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 05 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 06 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
 #[derive(
     Serialize,
     Deserialize,
@@ -973,6 +1352,19 @@ pub struct DN2 {
 }
 
 /// DSB - Disability Information
+///
+/// To supply disability information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1146 | Disability Type Code | 1 | M | ID | 1/1
+/// 02 | 380 | Quantity | 1 | O | R | 1/15
+/// 03 | 1149 | Occupation Code | 1 | O | ID | 4/6
+/// 04 | 1154 | Work Intensity Code | 1 | O | ID | 1/1
+/// 05 | 1161 | Product Option Code | 1 | O | AN | 1/2
+/// 06 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 07 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 08 | 1137 | Medical Code Value | 1 | X | AN | 1/15
 #[derive(
     Serialize,
     Deserialize,
@@ -1005,6 +1397,17 @@ pub struct DSB {
 }
 
 /// DTM - Date/Time Reference
+///
+/// To specify pertinent dates and times
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 02 | 373 | Date | 1 | X | DT | 8/8
+/// 03 | 337 | Time | 1 | X | TM | 4/8
+/// 04 | 623 | Time Code | 1 | O | ID | 2/2
+/// 05 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 06 | 1251 | Date Time Period | 1 | X | AN | 1/35
 #[derive(
     Serialize,
     Deserialize,
@@ -1033,6 +1436,14 @@ pub struct DTM {
 }
 
 /// DTP - Date or Time or Period
+///
+/// To specify any or all of a date, a time, or a time period
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 02 | 1250 | Date Time Period Format Qualifier | 1 | M | ID | 2/3
+/// 03 | 1251 | Date Time Period | 1 | M | AN | 1/35
 #[derive(
     Serialize,
     Deserialize,
@@ -1054,7 +1465,81 @@ pub struct DTP {
     pub _03: String,
 }
 
+/// EB - Eligibility or Benefit Information
+///
+/// To supply eligibility or benefit information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1390 | Eligibility or Benefit Information Code | 1 | M | ID | 1/2
+/// 02 | 1207 | Coverage Level Code | 1 | O | ID | 3/3
+/// 03 | 1365 | Service Type Code | 1 | O | ID | 1/2
+/// 04 | 1336 | Insurance Type Code | 1 | O | ID | 1/3
+/// 05 | 1204 | Plan Coverage Description | 1 | O | AN | 1/50
+/// 06 | 615 | Time Period Qualifier | 1 | O | ID | 1/2
+/// 07 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 08 | 954 | Percent | 1 | O | R | 1/10
+/// 09 | 673 | Quantity Qualifier | 1 | O | ID | 2/2
+/// 10 | 380 | Quantity | 1 | O | R | 1/15
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 13 | C003 | Composite Medical Procedure Identifier | 1 | O |  |
+/// 14 | C004 | Composite Diagnosis Code Pointer | 1 | O |  |
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct EB {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+}
+
 /// EC - Employment Class
+///
+/// To specify employment class
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1176 | Employment Class Code | 1 | O | ID | 2/3
+/// 02 | 1176 | Employment Class Code | 1 | O | ID | 2/3
+/// 03 | 1176 | Employment Class Code | 1 | O | ID | 2/3
+/// 04 | 954 | Percent | 1 | O | R | 1/10
+/// 05 | 1201 | Information Status Code | 1 | O | ID | 1/1
+/// 06 | 1165 | Occupation Code | 1 | O | ID | 4/6
 #[derive(
     Serialize,
     Deserialize,
@@ -1083,6 +1568,20 @@ pub struct EC {
 }
 
 /// ENT - Entity
+///
+/// To designate the entities which are parties to a transaction and specify a reference meaningful to those entities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | O | N0 | 1/6
+/// 02 | 98 | Entity Identifier Code | 1 | X | ID | 2/3
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 98 | Entity Identifier Code | 1 | X | ID | 2/3
+/// 06 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 07 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 08 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 09 | 127 | Reference Identification | 1 | X | AN | 1/50
 #[derive(
     Serialize,
     Deserialize,
@@ -1116,6 +1615,42 @@ pub struct ENT {
     pub _09: Option<String>,
 }
 
+/// EQ - Eligibility or Benefit Inquiry
+///
+/// To specify inquired eligibility or benefit information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1365 | Service Type Code | 1 | X | ID | 1/2
+/// 02 | C003 | Composite Medical Procedure Identifier | 1 | X |  |
+/// 03 | 1207 | Coverage Level Code | 1 | O | ID | 3/3
+/// 04 | 1336 | Insurance Type Code | 1 | O | ID | 1/3
+/// 05 | C004 | Composite Diagnosis Code Pointer | 1 | O |  |
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct EQ {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+}
+
 /// FC - Financial Contribution
 #[derive(
     Serialize,
@@ -1143,6 +1678,16 @@ pub struct FC {
 }
 
 /// FRM - Supporting Documentation
+///
+/// To specify information in response to a codified questionnaire document
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | M | AN | 1/20
+/// 02 | 1073 | Yes/No Condition or Response Code | 1 | X | ID | 1/1
+/// 03 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 04 | 373 | Date | 1 | X | DT | 8/8
+/// 05 | 332 | Percent, Decimal Format | 1 | X | R | 1/6
 #[derive(
     Serialize,
     Deserialize,
@@ -1203,6 +1748,13 @@ pub struct FSA {
 }
 
 /// GE - Functional Group Trailer
+///
+/// To indicate the end of a functional group and to provide control information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 97 | Number of Transaction Sets Included | 1 | M | N0 | 1/6
+/// 02 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
 #[derive(
     Serialize,
     Deserialize,
@@ -1223,6 +1775,19 @@ pub struct GE {
 }
 
 /// GS - FunctionalGroup Header
+///
+/// To indicate the beginning of a functional group and to provide control information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 479 | Functional Identifier Code | 1 | M | ID | 2/2
+/// 02 | 142 | Application Sender's Code | 1 | M | AN | 2/15
+/// 03 | 124 | Application Receiver's Code | 1 | M | AN | 2/15
+/// 04 | 373 | Date | 1 | M/Z | DT | 6/6
+/// 05 | 337 | Time | 1 | M/Z | TM | 4/6
+/// 06 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
+/// 07 | 455 | Responsible Agency Code | 1 | M | ID | 1/2
+/// 08 | 480 | Version / Release / Industry Identifier Code | 1 | M | AN | 1/12
 #[derive(
     Serialize,
     Deserialize,
@@ -1255,6 +1820,26 @@ pub struct GS {
 }
 
 /// HCP - Health Care Pricing
+///
+/// To specify pricing or repricing information about a health care claim or line item
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1473 | Pricing Methodology | 1 | M | ID | 2/2
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 04 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 05 | 118 | Rate | 1 | O | R | 1/9
+/// 06 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 07 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 08 | 234 | Product/Service ID | 1 | O | AN | 1/48
+/// 09 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
+/// 10 | 234 | Product/Service ID | 1 | O | AN | 1/48
+/// 11 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 12 | 380 | Quantity | 1 | O | R | 1/15
+/// 13 | 901 | Reject Reason Code | 1 | O | ID | 2/2
+/// 14 | 1526 | Policy Compliance Code | 1 | O | ID | 1/2
+/// 15 | 1527 | Exception Code | 1 | O | ID | 1/2
 #[derive(
     Serialize,
     Deserialize,
@@ -1300,7 +1885,56 @@ pub struct HCP {
     pub _15: Option<String>,
 }
 
+/// HCR - Health Care Services Review
+///
+/// To specify the outcome of a health care services review
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 306 | Action Code | 1 | M | ID | 1/2
+/// 02 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 03 | 1271 | Industry Code | 1 | O | AN | 1/30
+/// 04 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct HCR {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+}
+
 /// HD - Health Coverage
+///
+/// To provide information on health coverage
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 875 | Maintenance Type Code | 1 | M | ID | 3/3
+/// 02 | 1203 | Maintenance Reason Code | 1 | O | ID | 2/3
+/// 03 | 1205 | Insurance Line Code | 1 | O | ID | 2/3
+/// 04 | 1204 | Plan Coverage Description | 1 | O | AN | 1/50
+/// 05 | 1207 | Coverage Level Code | 1 | O | ID | 3/3
+/// 06 | 609 | Count | 1 | O | N0 | 1/9
+/// 07 | 609 | Count | 1 | O | N0 | 1/9
+/// 08 | 1209 | Underwriting Decision Code | 1 | O | ID | 1/1
+/// 09 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 10 | 1211 | Drug House Code | 1 | O | ID | 2/3
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -1339,6 +1973,23 @@ pub struct HD {
 }
 
 /// HI - Health Care Information Codes
+///
+/// To supply information related to the delivery of health care
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C022 | Health Care Code Information | 1 | M |  |
+/// 02 | C022 | Health Care Code Information | 1 | O |  |
+/// 03 | C022 | Health Care Code Information | 1 | O |  |
+/// 04 | C022 | Health Care Code Information | 1 | O |  |
+/// 05 | C022 | Health Care Code Information | 1 | O |  |
+/// 06 | C022 | Health Care Code Information | 1 | O |  |
+/// 07 | C022 | Health Care Code Information | 1 | O |  |
+/// 08 | C022 | Health Care Code Information | 1 | O |  |
+/// 09 | C022 | Health Care Code Information | 1 | O |  |
+/// 10 | C022 | Health Care Code Information | 1 | O |  |
+/// 11 | C022 | Health Care Code Information | 1 | O |  |
+/// 12 | C022 | Health Care Code Information | 1 | O |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -1379,6 +2030,15 @@ pub struct HI {
 }
 
 /// HL - Hierarchical Level
+///
+/// To identify dependencies among and the content of hierarchically related groups of data segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 628 | Hierarchical ID Number | 1 | M | AN | 1/12
+/// 02 | 734 | Hierarchical Parent ID Number | 1 | O | AN | 1/12
+/// 03 | 735 | Hierarchical Level Code | 1 | M | ID | 1/2
+/// 04 | 736 | Hierarchical Child Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -1403,6 +2063,18 @@ pub struct HL {
 }
 
 /// HLH - Health Information
+///
+/// To provide health information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1212 | Health-Related Code | 1 | M | ID | 1/1
+/// 02 | 65 | Height | 1 | O | R | 1/8
+/// 03 | 81 | Weight | 1 | O | R | 1/10
+/// 04 | 81 | Weight | 1 | O | R | 1/10
+/// 05 | 352 | Description | 1 | O | AN | 1/80
+/// 06 | 1213 | Current Health Condition Code | 1 | O | ID | 1/1
+/// 07 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
     Serialize,
     Deserialize,
@@ -1433,6 +2105,19 @@ pub struct HLH {
 }
 
 /// HSD - Health Care Services Delivery
+///
+/// To specify the delivery pattern of health care services
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 673 | Quantity Qualifier | 1 | X | ID | 2/2
+/// 02 | 380 | Quantity | 1 | X | R | 1/15
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 04 | 1167 | Sample Selection Modulus | 1 | O | R | 1/6
+/// 05 | 615 | Time Period Qualifier | 1 | X | ID | 1/2
+/// 06 | 616 | Number of Periods | 1 | O | N0 | 1/3
+/// 07 | 678 | Ship/Delivery or Calendar Pattern Code | 1 | O | ID | 1/2
+/// 08 | 679 | Ship/Delivery Pattern Time Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -1465,6 +2150,17 @@ pub struct HSD {
 }
 
 /// ICM - Individual Income
+///
+/// To supply individual income information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 594 | Frequency Code | 1 | M | ID | 1/1
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 380 | Quantity | 1 | O | R | 1/15
+/// 04 | 310 | Location Identifier | 1 | O | AN | 1/30
+/// 05 | 1214 | Salary Grade | 1 | O | AN | 1/5
+/// 06 | 100 | Currency Code | 1 | O | ID | 3/3
 #[derive(
     Serialize,
     Deserialize,
@@ -1493,6 +2189,15 @@ pub struct ICM {
 }
 
 /// IDC - Health Coverage
+///
+/// To provide information for the production of an identification card
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1204 | Plan Coverage Description | 1 | M | AN | 1/50
+/// 02 | 1215 | Identification Card Type Code | 1 | M | ID | 1/1
+/// 03 | 380 | Quantity | 1 | O | R | 1/15
+/// 04 | 306 | Action Code | 1 | O | ID | 1/2
 #[derive(
     Serialize,
     Deserialize,
@@ -1546,6 +2251,54 @@ pub struct IEA {
     pub _02: String,
 }
 
+/// III - Information
+///
+/// To report information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 02 | 1271 | Industry Code | 1 | X | AN | 1/30
+/// 03 | 1136 | Code Category | 1 | X | ID | 2/2
+/// 04 | 933 | Free-form Message Text | 1 | X | AN | 1/264
+/// 05 | 380 | Quantity | 1 | O | R | 1/15
+/// 06 | C001 | Composite Unit of Measure | 1 | O |  |
+/// 07 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 08 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 09 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct III {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
 /// IMM - Immunization Status
 #[derive(
     Serialize,
@@ -1575,6 +2328,28 @@ pub struct IMM {
 }
 
 /// INS - Insured Benefit
+///
+/// To provide benefit information on insured entities
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1073 | Yes/No Condition or Response Code | 1 | M | ID | 1/1
+/// 02 | 1069 | Individual Relationship Code | 1 | M | ID | 2/2
+/// 03 | 875 | Maintenance Type Code | 1 | O | ID | 3/3
+/// 04 | 1203 | Maintenance Reason Code | 1 | O | ID | 2/3
+/// 05 | 1216 | Benefit Status Code | 1 | O | ID | 1/1
+/// 06 | C052 | Medicare Status Code | 1 | O |  |
+/// 07 | 1219 | Consolidated Omnibus Budget Reconciliation Act (COBRA) Qualifying Event Code | 1 | O | ID | 1/2
+/// 08 | 584 | Employment Status Code | 1 | O | ID | 2/2
+/// 09 | 1220 | Student Status Code | 1 | O | ID | 1/1
+/// 10 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 11 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 12 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 13 | 1165 | Confidentiality Code | 1 | O | ID | 1/1
+/// 14 | 19 | City Name | 1 | O | AN | 2/30
+/// 15 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 16 | 26 | Country Code | 1 | O | ID | 2/3
+/// 17 | 1470 | Number | 1 | O | N0 | 1/9
 #[derive(
     Serialize,
     Deserialize,
@@ -1670,8 +2445,8 @@ pub struct INV {
 /// 08 | I07 | Interchange Receiver ID | 1 | M | AN | 15/15
 /// 09 | I08 | Interchange Date | 1 | M | DT | 6/6
 /// 10 | I09 | Interchange Time | 1 | M | TM | 4/4
-/// 11 | I10 | Interchange Control Standards Identifier | 1 | M | ID | 1/1
-/// 12 | I11 | Interchange Control Version Number | 1 | M | ID | 5/5
+/// 11 | I65 | Repetition Separator | 1 | M | AN | 1/1
+/// 12 | I11 | Interchange Control Version Number Code | 1 | M | ID | 5/5
 /// 13 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
 /// 14 | I13 | Acknowledgment Requested | 1 | M | ID | 1/1
 /// 15 | I14 | Usage Indicator | 1 | M | ID | 1/1
@@ -1773,15 +2548,15 @@ pub struct ISA {
     /// - MAX=4
     #[serde(rename = "10")]
     pub _10: String,
-    /// I10 - Interchange Control Standards Identifier
+    /// I65 - Repetition Separator
     ///
-    /// Code to identify the agency responsible for the control standard used by the message that is enclosed by the interchange header and trailer
-    /// - TYPE=ID
+    /// Type is not applicable; the repetition separator is a delimiter and not a data element; this field provides the delimiter used to separate repeated occurrences of a simple data element or a composite data structure
+    /// - TYPE=AN
     /// - MIN=1
     /// - MAX=1
     #[serde(rename = "11")]
     pub _11: String,
-    /// I11 - Interchange Control Version Number
+    /// I11 - Interchange Control Version Number Code
     ///
     /// This version number covers the interchange control segments
     /// - TYPE=ID
@@ -1824,6 +2599,14 @@ pub struct ISA {
 }
 
 /// K3 - File Information
+///
+/// To transmit a fixed-format record or matrix contents
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 449 | Fixed Format Information | 1 | M | AN | 1/80
+/// 02 | 1333 | Record Format Code | 1 | O | ID | 1/2
+/// 03 | C001 | Composite Unit of Measure | 1 | O |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -1876,6 +2659,12 @@ pub struct LC {
 }
 
 /// LE - Loop Trailer
+///
+/// To indicate that the loop immediately preceding this segment is complete
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/4
 #[derive(
     Serialize,
     Deserialize,
@@ -1894,6 +2683,42 @@ pub struct LE {
 }
 
 /// LIN - Item Identification
+///
+/// To specify basic item identification data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 350 | Assigned Identification | 1 | O | AN | 1/20
+/// 02 | 235 | Product/Service ID Qualifier | 1 | M | ID | 2/2
+/// 03 | 234 | Product/Service ID | 1 | M | AN | 1/48
+/// 04 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 05 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 06 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 07 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 08 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 09 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 10 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 11 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 12 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 13 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 14 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 15 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 16 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 17 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 18 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 19 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 20 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 21 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 22 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 23 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 24 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 25 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 26 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 27 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 28 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 29 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 30 | 235 | Product/Service ID Qualifier | 1 | X | ID | 2/2
+/// 31 | 234 | Product/Service ID | 1 | X | AN | 1/48
 #[derive(
     Serialize,
     Deserialize,
@@ -1983,6 +2808,13 @@ struct LM {
 }
 
 /// LQ - Industry Code Identification
+///
+/// Code to transmit standard industry codes
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 02 | 1271 | Industry Code | 1 | X | AN | 1/30
 #[derive(
     Serialize,
     Deserialize,
@@ -2003,6 +2835,12 @@ pub struct LQ {
 }
 
 /// LS - Loop Header
+///
+/// To indicate that the next segment begins a loop
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/4
 #[derive(
     Serialize,
     Deserialize,
@@ -2021,6 +2859,16 @@ pub struct LS {
 }
 
 /// LUI - Language Use
+///
+/// To specify language, type of usage, and proficiency or fluency
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 02 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 03 | 352 | Description | 1 | X | AN | 1/80
+/// 04 | 1303 | Use of Language Indicator | 1 | O | ID | 1/2
+/// 05 | 1476 | Language Proficiency Indicator | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -2047,6 +2895,12 @@ pub struct LUI {
 }
 
 /// LX - Transaction Set Line Number
+///
+/// To reference a line number in a transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
 #[derive(
     Serialize,
     Deserialize,
@@ -2065,6 +2919,23 @@ pub struct LX {
 }
 
 /// MEA - Measurements
+///
+/// To specify physical measurements or counts, including dimensions, tolerances, variances, and weights
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 737 | Measurement Reference ID Code | 1 | O | ID | 2/2
+/// 02 | 738 | Measurement Qualifier | 1 | O | ID | 1/3
+/// 03 | 739 | Measurement Value | 1 | X | R | 1/20
+/// 04 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 05 | 740 | Range Minimum | 1 | X | R | 1/20
+/// 06 | 741 | Range Maximum | 1 | X | R | 1/20
+/// 07 | 935 | Measurement Significance Code | 1 | O | ID | 2/2
+/// 08 | 936 | Measurement Attribute Code | 1 | X | ID | 2/2
+/// 09 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 10 | 1373 | Measurement Method or Device | 1 | O | ID | 2/4
+/// 11 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 12 | 1271 | Industry Code | 1 | X | AN | 1/30
 #[derive(
     Serialize,
     Deserialize,
@@ -2105,6 +2976,35 @@ pub struct MEA {
 }
 
 /// MIA - Medicare Inpatient Adjudication
+///
+/// To provide claim-level data related to the adjudication of Medicare inpatient claims
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | M | R | 1/15
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 380 | Quantity | 1 | O | R | 1/15
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 06 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 07 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 10 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 11 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 12 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 13 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 14 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 15 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 16 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 17 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 18 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 19 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 20 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 21 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 22 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 23 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 24 | 782 | Monetary Amount | 1 | O | R | 1/18
 #[derive(
     Serialize,
     Deserialize,
@@ -2169,6 +3069,20 @@ pub struct MIA {
 }
 
 /// MOA - Medicare Outpatient Adjudication
+///
+/// To convey claim-level data related to the adjudication of Medicare claims not related to an inpatient setting
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 954 | Percent | 1 | O | R | 1/10
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 04 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 05 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 06 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 07 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 782 | Monetary Amount | 1 | O | R | 1/18
 #[derive(
     Serialize,
     Deserialize,
@@ -2202,7 +3116,48 @@ pub struct MOA {
     pub _09: Option<String>,
 }
 
+/// MSG - Message Text
+///
+/// To provide a free-form format that allows the transmission of text information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 933 | Free-form Message Text | 1 | M | AN | 1/264
+/// 02 | 934 | Printer Carriage Control Code | 1 | X | ID | 2/2
+/// 03 | 1470 | Number | 1 | O | N0 | 1/9
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct MSG {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
 /// N1 - Party Identifier
+///
+/// To identify a party by type of organization, name, and code
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 93 | Name | 1 | X | AN | 1/60
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 706 | Entity Relationship Code | 1 | O | ID | 2/2
+/// 06 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
     Serialize,
     Deserialize,
@@ -2231,6 +3186,13 @@ pub struct N1 {
 }
 
 /// N2 - Additional Name Information
+///
+/// To specify additional names or those longer than 35 characters in length
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 93 | Name | 1 | M | AN | 1/60
+/// 02 | 93 | Name | 1 | O | AN | 1/60
 #[derive(
     Serialize,
     Deserialize,
@@ -2251,6 +3213,13 @@ pub struct N2 {
 }
 
 /// N3 - Party Location
+///
+/// To specify the location of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 166 | Address Information | 1 | M | AN | 1/55
+/// 02 | 166 | Address Information | 1 | O | AN | 1/55
 #[derive(
     Serialize,
     Deserialize,
@@ -2271,6 +3240,18 @@ pub struct N3 {
 }
 
 /// N4 - Geographic Location
+///
+/// To specify the geographic place of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 19 | City Name | 1 | O | AN | 2/30
+/// 02 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 03 | 116 | Postal Code | 1 | O | ID | 3/15
+/// 04 | 26 | Country Code | 1 | X | ID | 2/3
+/// 05 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 06 | 310 | Location Identifier | 1 | O | AN | 1/30
+/// 07 | 1715 | Country Subdivision Code | 1 | X | ID | 1/3
 #[derive(
     Serialize,
     Deserialize,
@@ -2301,6 +3282,23 @@ pub struct N4 {
 }
 
 /// NM1 - Individual or Organizational Name
+///
+/// To supply the full name of an individual or organizational entity
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 1065 | Entity Type Qualifier | 1 | M | ID | 1/1
+/// 03 | 1035 | Name Last or Organization Name | 1 | X | AN | 1/60
+/// 04 | 1036 | Name First | 1 | O | AN | 1/35
+/// 05 | 1037 | Name Middle | 1 | O | AN | 1/25
+/// 06 | 1038 | Name Prefix | 1 | O | AN | 1/10
+/// 07 | 1039 | Name Suffix | 1 | O | AN | 1/10
+/// 08 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 09 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 10 | 706 | Entity Relationship Code | 1 | X | ID | 2/2
+/// 11 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 12 | 1035 | Name Last or Organization Name | 1 | O | AN | 1/60
 #[derive(
     Serialize,
     Deserialize,
@@ -2341,6 +3339,13 @@ pub struct NM1 {
 }
 
 /// NTE - Note/Special Instruction
+///
+/// To transmit information in a free-form format, if necessary, for comment or special instruction
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 363 | Note Reference Code | 1 | O | ID | 3/3
+/// 02 | 352 | Description | 1 | M | AN | 1/80
 #[derive(
     Serialize,
     Deserialize,
@@ -2361,6 +3366,16 @@ pub struct NTE {
 }
 
 /// NX1 - Property or Entity Identification
+///
+/// To identify the entity or property being referenced
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1106 | Property or Entity Identification Code | 1 | M | ID | 1/3
+/// 02 | 1106 | Property or Entity Identification Code | 1 | O | ID | 1/3
+/// 03 | 1106 | Property or Entity Identification Code | 1 | O | ID | 1/3
+/// 04 | 1106 | Property or Entity Identification Code | 1 | O | ID | 1/3
+/// 05 | 1106 | Property or Entity Identification Code | 1 | O | ID | 1/3
 #[derive(
     Serialize,
     Deserialize,
@@ -2387,6 +3402,17 @@ pub struct NX1 {
 }
 
 /// OI - Other Health Insurance Information
+///
+/// To specify information associated with other health insurance coverage
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1032 | Claim Filing Indicator Code | 1 | O | ID | 1/2
+/// 02 | 1383 | Claim Submission Reason Code | 1 | O | ID | 2/2
+/// 03 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 04 | 1351 | Patient Signature Source Code | 1 | O | ID | 1/1
+/// 05 | 1360 | Provider Agreement Code | 1 | O | ID | 1/1
+/// 06 | 1363 | Release of Information Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -2414,7 +3440,93 @@ pub struct OI {
     pub _06: Option<String>,
 }
 
+/// OTI - Original Transaction Identification
+///
+/// To identify the edited transaction set and the level at which the results of the edit are reported, and to indicate the accepted, rejected, or accepted-with-change edit result
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 110 | Application Acknowledgment Code | 1 | M | ID | 1/2
+/// 02 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 03 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 04 | 142 | Application Sender's Code | 1 | O | AN | 2/15
+/// 05 | 124 | Application Receiver's Code | 1 | O | AN | 2/15
+/// 06 | 373 | Date | 1 | O | DT | 8/8
+/// 07 | 337 | Time | 1 | O | TM | 4/8
+/// 08 | 28 | Group Control Number | 1 | X | N0 | 1/9
+/// 09 | 329 | Transaction Set Control Number | 1 | O | AN | 4/9
+/// 10 | 143 | Transaction Set Identifier Code | 1 | O | ID | 3/3
+/// 11 | 480 | Version / Release / Industry Identifier Code | 1 | O | AN | 1/12
+/// 12 | 353 | Transaction Set Purpose Code | 1 | O | ID | 2/2
+/// 13 | 640 | Transaction Type Code | 1 | O | ID | 2/2
+/// 14 | 346 | Application Type | 1 | O | ID | 2/2
+/// 15 | 306 | Action Code | 1 | O | ID | 1/2
+/// 16 | 305 | Transaction Handling Code | 1 | O | ID | 1/2
+/// 17 | 633 | Purpose Code | 1 | O | ID | 2/2
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct OTI {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
+    #[serde(rename = "13")]
+    pub _13: Option<String>,
+    #[serde(rename = "14")]
+    pub _14: Option<String>,
+    #[serde(rename = "15")]
+    pub _15: Option<String>,
+    #[serde(rename = "16")]
+    pub _16: Option<String>,
+    #[serde(rename = "17")]
+    pub _17: Option<String>,
+}
+
 /// PAT - Patient Information
+///
+/// To supply patient information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1069 | Individual Relationship Code | 1 | O | ID | 2/2
+/// 02 | 1384 | Patient Location Code | 1 | O | ID | 1/1
+/// 03 | 584 | Employment Status Code | 1 | O | ID | 2/2
+/// 04 | 1220 | Student Status Code | 1 | O | ID | 1/1
+/// 05 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 06 | 1251 | Date Time Period | 1 | X | AN | 1/35
+/// 07 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 08 | 81 | Weight | 1 | X | R | 1/10
+/// 09 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -2449,6 +3561,20 @@ pub struct PAT {
 }
 
 /// PER - Administrative Communications Contact
+///
+/// To identify a person or office to whom administrative communications should be directed
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 366 | Contact Function Code | 1 | M | ID | 2/2
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+/// 03 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 04 | 364 | Communication Number | 1 | X | AN | 1/256
+/// 05 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 06 | 364 | Communication Number | 1 | X | AN | 1/256
+/// 07 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 08 | 364 | Communication Number | 1 | X | AN | 1/256
+/// 09 | 443 | Contact Inquiry Reference | 1 | O | AN | 1/20
 #[derive(
     Serialize,
     Deserialize,
@@ -2483,6 +3609,16 @@ pub struct PER {
 }
 
 /// PLA - Place or Location
+///
+/// To indicate action to be taken for the location specified and to qualify the location specified
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 306 | Action Code | 1 | M | ID | 1/2
+/// 02 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 337 | Time | 1 | O | TM | 4/8
+/// 05 | 1203 | Maintenance Reason Code | 1 | O | ID | 2/3
 #[derive(
     Serialize,
     Deserialize,
@@ -2509,6 +3645,25 @@ pub struct PLA {
 }
 
 /// PLB - Provider Level Adjustment
+///
+/// To convey provider level adjustment information for debit or credit transactions such as, accelerated payments, cost report settlements for a fiscal year and timeliness report penalties unrelated to a specific claim or service
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 02 | 373 | Date | 1 | M | DT | 8/8
+/// 03 | C042 | Adjustment Identifier | 1 | M |  |
+/// 04 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 05 | C042 | Adjustment Identifier | 1 | X |  |
+/// 06 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 07 | C042 | Adjustment Identifier | 1 | X |  |
+/// 08 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 09 | C042 | Adjustment Identifier | 1 | X |  |
+/// 10 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 11 | C042 | Adjustment Identifier | 1 | X |  |
+/// 12 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 13 | C042 | Adjustment Identifier | 1 | X |  |
+/// 14 | 782 | Monetary Amount | 1 | X | R | 1/18
 #[derive(
     Serialize,
     Deserialize,
@@ -2553,6 +3708,15 @@ pub struct PLB {
 }
 
 /// PM - Electronic Funds Transfer Information
+///
+/// To identify the electronic funds transfer (EFT) information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 506 | (DFI) ID Number Qualifier | 1 | M | ID | 2/2
+/// 02 | 507 | (DFI) Identification Number | 1 | M | AN | 3/12
+/// 03 | 569 | Account Number Qualifier | 1 | M | ID | 1/3
+/// 04 | 508 | Account Number | 1 | M | AN | 1/35
 #[derive(
     Serialize,
     Deserialize,
@@ -2581,6 +3745,17 @@ pub struct PM {
 }
 
 /// PRV - Provider Information
+///
+/// To specify the identifying characteristics of a provider
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1221 | Provider Code | 1 | M | ID | 1/3
+/// 02 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 03 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 04 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 05 | C035 | Provider Specialty Information | 1 | O |  |
+/// 06 | 1223 | Provider Organization Code | 1 | O | ID | 3/3
 #[derive(
     Serialize,
     Deserialize,
@@ -2609,6 +3784,14 @@ pub struct PRV {
 }
 
 /// PS1 - Purchase Service
+///
+/// To provide information related to purchased services
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 156 | State or Province Code | 1 | O | ID | 2/2
 #[derive(
     Serialize,
     Deserialize,
@@ -2631,6 +3814,20 @@ pub struct PS1 {
 }
 
 /// PWK - Disability Information
+///
+/// To identify the type or transmission or both of paperwork or supporting information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 755 | Report Type Code | 1 | M | ID | 2/2
+/// 02 | 756 | Report Transmission Code | 1 | O | ID | 1/2
+/// 03 | 757 | Report Copies Needed | 1 | O | N0 | 1/2
+/// 04 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
+/// 05 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 06 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+/// 08 | C002 | Actions Indicated | 1 | O |  |
+/// 09 | 1525 | Request Category Code | 1 | O | ID | 1/2
 #[derive(
     Serialize,
     Deserialize,
@@ -2665,6 +3862,15 @@ pub struct PWK {
 }
 
 /// QTY - Beginning Segment
+///
+/// To specify quantity information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 673 | Quantity Qualifier | 1 | M | ID | 2/2
+/// 02 | 380 | Quantity | 1 | X | R | 1/15
+/// 03 | C001 | Composite Unit of Measure | 1 | O |  |
+/// 04 | 61 | Free-Form Message | 1 | X | AN | 1/30
 #[derive(
     Serialize,
     Deserialize,
@@ -2689,6 +3895,16 @@ pub struct QTY {
 }
 
 /// RDM - Remittance Delivery Method
+///
+/// To identify remittance delivery when remittance is separated from payment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 756 | Report Transmission Code | 1 | M | ID | 1/2
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+/// 03 | 364 | Communication Number | 1 | O | AN | 1/256
+/// 04 | C040 | Reference Identifier | 1 | O |  |
+/// 05 | C040 | Reference Identifier | 1 | O |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -2714,7 +3930,46 @@ pub struct RDM {
     pub _05: Option<String>,
 }
 
+/// RED - Related Data
+///
+/// To relate data that is associated with a previously defined error condition
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 352 | Description | 1 | M | AN | 1/80
+/// 02 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 03 | 1271 | Industry Code | 1 | X | AN | 1/30
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct RED {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
 /// REF - Reference Information
+///
+/// To specify identifying information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 03 | 352 | Description | 1 | X | AN | 1/80
+/// 04 | C040 | Reference Identifier | 1 | O/Z |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -2758,6 +4013,51 @@ pub struct REL {
     pub _02: Option<String>,
 }
 
+/// RMR - Remittance Advice Accounts Receivable Open Item Reference
+///
+/// To specify the accounts receivable open item(s) to be included in the cash application and to convey the appropriate detail
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/50
+/// 03 | 482 | Payment Action Code | 1 | O | ID | 2/2
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 06 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 07 | 426 | Adjustment Reason Code | 1 | O | ID | 2/2
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct RMR {
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
 /// RP - Retirement Product
 #[derive(
     Serialize,
@@ -2793,6 +4093,20 @@ pub struct RP {
 }
 
 /// SBR - Subscriber Information
+///
+/// To supply information about the subscriber of a health care plan
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1138 | Payer Responsibility Sequence Number Code | 1 | M | ID | 1/1
+/// 02 | 1069 | Individual Relationship Code | 1 | O | ID | 2/2
+/// 03 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 04 | 93 | Name | 1 | O | AN | 1/60
+/// 05 | 1336 | Insurance Type Code | 1 | O | ID | 1/3
+/// 06 | 1143 | Coordination of Benefits Code | 1 | O | ID | 1/1
+/// 07 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 08 | 584 | Employment Status Code | 1 | O | ID | 2/2
+/// 09 | 1032 | Claim Filing Indicator Code | 1 | O | ID | 1/2
 #[derive(
     Serialize,
     Deserialize,
@@ -2827,6 +4141,13 @@ pub struct SBR {
 }
 
 /// SE - Transaction Set Trailer
+///
+/// To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments).
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
+/// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -2838,6 +4159,14 @@ pub struct SE {
 }
 
 /// ST - Transaction Set Header
+///
+/// To indicate the start of a transaction set and to assign a control number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 143 | Transaction Set Identifier Code | 1 | M | ID | 3/3
+/// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
+/// 03 | 1705 | Implementation Convention Reference | 1 | O | AN | 1/35
 #[derive(
     Serialize,
     Deserialize,
@@ -2860,6 +4189,23 @@ pub struct ST {
 }
 
 /// STC - Claim or Service Line Status information
+///
+/// To report the status, required action, and paid information of a claim or service line
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C043 | Health Care Claim Status | 1 | M |  |
+/// 02 | 373 | Date | 1 | O | DT | 8/8
+/// 03 | 306 | Action Code | 1 | O | ID | 1/2
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 06 | 373 | Date | 1 | O | DT | 8/8
+/// 07 | 591 | Payment Method Code | 1 | O | ID | 3/3
+/// 08 | 373 | Date | 1 | O | DT | 8/8
+/// 09 | 429 | Check Number | 1 | O | AN | 1/16
+/// 10 | C043 | Health Care Claim Status | 1 | O |  |
+/// 11 | C043 | Health Care Claim Status | 1 | O |  |
+/// 12 | 933 | Free-Form Message Text | 1 | O | AN | 1/264
 #[derive(
     Serialize,
     Deserialize,
@@ -2912,9 +4258,43 @@ pub struct STC {
     /// STC10 - Free-form message text or additional info
     #[serde(rename = "10")]
     pub _10: Option<String>,
+
+    /// STC11 - Health Care Claim Status, a further status composite
+    #[serde(rename = "11")]
+    pub _11: Option<String>,
+
+    /// STC12 - Free-Form Message Text
+    #[serde(rename = "12")]
+    pub _12: Option<String>,
 }
 
 /// SV1 - Professional Service
+///
+/// To specify the claim service detail for a Health Care professional
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C003 | Composite Medical Procedure Identifier | 1 | M |  |
+/// 02 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 04 | 380 | Quantity | 1 | X | R | 1/15
+/// 05 | 1331 | Facility Code Value | 1 | O | AN | 1/2
+/// 06 | 1365 | Service Type Code | 1 | O | ID | 1/2
+/// 07 | C004 | Composite Diagnosis Code Pointer | 1 | O |  |
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 10 | 1340 | Multiple Procedure Code | 1 | O | ID | 1/2
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 13 | 1364 | Review Code | 1 | O | ID | 1/2
+/// 14 | 1341 | National or Local Assigned Review Value | 1 | O | AN | 1/2
+/// 15 | 1327 | Copay Status Code | 1 | O | ID | 1/1
+/// 16 | 1334 | Health Care Professional Shortage Area Code | 1 | O | ID | 1/1
+/// 17 | 127 | Reference Identification | 1 | O | AN | 1/50
+/// 18 | 116 | Postal Code | 1 | O | ID | 3/15
+/// 19 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 20 | 1337 | Level of Care Code | 1 | O | ID | 1/1
+/// 21 | 1360 | Provider Agreement Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -2973,6 +4353,21 @@ pub struct SV1 {
 }
 
 /// SV2 - Institutional Service
+///
+/// To specify the service line item detail for a health care institution
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 234 | Product/Service ID | 1 | X | AN | 1/48
+/// 02 | C003 | Composite Medical Procedure Identifier | 1 | X |  |
+/// 03 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
+/// 05 | 380 | Quantity | 1 | X | R | 1/15
+/// 06 | 1371 | Unit Rate | 1 | X | R | 1/10
+/// 07 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 08 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 09 | 1345 | Nursing Home Residential Status Code | 1 | O | ID | 1/1
+/// 10 | 1337 | Level of Care Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -3009,6 +4404,21 @@ pub struct SV2 {
 }
 
 /// SV3 - Dental Service
+///
+/// To specify the claim service detail for dental work
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C003 | Composite Medical Procedure Identifier | 1 | M |  |
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 1331 | Facility Code Value | 1 | X | AN | 1/2
+/// 04 | C006 | Oral Cavity Designation | 1 | O |  |
+/// 05 | 1358 | Prosthesis, Crown, or Inlay Code | 1 | O | ID | 1/1
+/// 06 | 380 | Quantity | 1 | O | R | 1/15
+/// 07 | 352 | Description | 1 | O | AN | 1/80
+/// 08 | 1327 | Copay Status Code | 1 | O | ID | 1/1
+/// 09 | 1360 | Provider Agreement Code | 1 | O | ID | 1/1
+/// 10 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -3097,6 +4507,18 @@ pub struct SV4 {
 }
 
 /// SV5 - Durable Medical Equipment Service
+///
+/// To specify the claim service detail for durable medical equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C003 | Composite Medical Procedure Identifier | 1 | M |  |
+/// 02 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 03 | 380 | Quantity | 1 | M | R | 1/15
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 06 | 594 | Frequency Code | 1 | O | ID | 1/1
+/// 07 | 923 | Prognosis Code | 1 | O | ID | 1/1
 #[derive(
     Serialize,
     Deserialize,
@@ -3127,6 +4549,18 @@ pub struct SV5 {
 }
 
 /// SV6 - Anesthesia Service
+///
+/// To specify the claim service detail for anesthesia
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C003 | Composite Medical Procedure Identifier | 1 | M |  |
+/// 02 | 1332 | Facility Code Qualifier | 1 | O | ID | 1/2
+/// 03 | 1331 | Facility Code Value | 1 | O | AN | 1/2
+/// 04 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 05 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 06 | 380 | Quantity | 1 | O | R | 1/15
+/// 07 | C004 | Composite Diagnosis Code Pointer | 1 | O |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -3185,6 +4619,18 @@ pub struct SV7 {
 }
 
 /// SVC - Service Information
+///
+/// To supply payment and control information to a provider for a particular service
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | C003 | Composite Medical Procedure Identifier | 1 | M |  |
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 04 | 234 | Product/Service ID | 1 | O | AN | 1/48
+/// 05 | 380 | Quantity | 1 | O | R | 1/15
+/// 06 | C003 | Composite Medical Procedure Identifier | 1 | O |  |
+/// 07 | 380 | Quantity | 1 | O | R | 1/15
 #[derive(
     Serialize,
     Deserialize,
@@ -3215,6 +4661,17 @@ pub struct SVC {
 }
 
 /// SVD - Service Line Adjudication
+///
+/// To convey service line adjudication information for coordination of benefits between the initial payers of a health care claim and all subsequent payers
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 67 | Identification Code | 1 | M | AN | 2/80
+/// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 03 | C003 | Composite Medical Procedure Identifier | 1 | O |  |
+/// 04 | 234 | Product/Service ID | 1 | O | AN | 1/48
+/// 05 | 380 | Quantity | 1 | O | R | 1/15
+/// 06 | 554 | Assigned Number | 1 | O | N0 | 1/6
 #[derive(
     Serialize,
     Deserialize,
@@ -3242,7 +4699,60 @@ pub struct SVD {
     pub _06: Option<String>,
 }
 
+/// TED - Technical Error Description
+///
+/// To identify the error and, if feasible, the erroneous segment, or data element, or both
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 647 | Application Error Condition Code | 1 | M | ID | 1/3
+/// 02 | 3 | Free Form Message | 1 | O | AN | 1/60
+/// 03 | 721 | Segment ID Code | 1 | O | ID | 2/3
+/// 04 | 719 | Segment Position in Transaction Set | 1 | O | N0 | 1/10
+/// 05 | C030 | Position in Segment | 1 | O |  |
+/// 06 | 725 | Data Element Reference Number | 1 | O | N0 | 1/4
+/// 07 | 724 | Copy of Bad Data Element | 1 | O | AN | 1/99
+/// 08 | 961 | Data Element New Content | 1 | O | AN | 1/99
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct TED {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
 /// TOO - Tooth Identification
+///
+/// To identify a tooth by number and, if applicable, one or more tooth surfaces
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1270 | Code List Qualifier Code | 1 | O | ID | 1/3
+/// 02 | 1271 | Industry Code | 1 | O | AN | 1/30
+/// 03 | C005 | Tooth Surface | 1 | O |  |
 #[derive(
     Serialize,
     Deserialize,
@@ -3265,6 +4775,15 @@ pub struct TOO {
 }
 
 /// TRN - Trace
+///
+/// To uniquely identify a transaction to an application
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 481 | Trace Type Code | 1 | M | ID | 1/2
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 03 | 509 | Originating Company Identifier | 1 | O | AN | 10/10
+/// 04 | 127 | Reference Identification | 1 | O | AN | 1/50
 #[derive(
     Serialize,
     Deserialize,
@@ -3343,6 +4862,35 @@ pub struct TS2 {
 }
 
 /// TS3 - Transaction Statistics
+///
+/// To supply provider-level control information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 127 | Reference Identification | 1 | M | AN | 1/50
+/// 02 | 1331 | Facility Code Value | 1 | M | AN | 1/2
+/// 03 | 373 | Date | 1 | M | DT | 8/8
+/// 04 | 380 | Quantity | 1 | M | R | 1/15
+/// 05 | 782 | Monetary Amount | 1 | M | R | 1/18
+/// 06 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 07 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 08 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 09 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 10 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 11 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 12 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 13 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 14 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 15 | 380 | Quantity | 1 | O | R | 1/15
+/// 16 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 17 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 18 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 19 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 20 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 21 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 22 | 380 | Quantity | 1 | O | R | 1/15
+/// 23 | 782 | Monetary Amount | 1 | O | R | 1/18
+/// 24 | 782 | Monetary Amount | 1 | O | R | 1/18
 #[derive(
     Serialize,
     Deserialize,
@@ -3406,6 +4954,57 @@ pub struct TS3 {
     pub _24: Option<String>,
 }
 
+/// UM - Health Care Services Review Information
+///
+/// To specify health care services review information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1525 | Request Category Code | 1 | M | ID | 1/2
+/// 02 | 1322 | Certification Type Code | 1 | O | ID | 1/1
+/// 03 | 1365 | Service Type Code | 1 | O | ID | 1/2
+/// 04 | C023 | Health Care Service Location Information | 1 | O |  |
+/// 05 | C024 | Related Causes Information | 1 | O |  |
+/// 06 | 1338 | Level of Service Code | 1 | O | ID | 1/3
+/// 07 | 1213 | Current Health Condition Code | 1 | O | ID | 1/1
+/// 08 | 380 | Quantity | 1 | O | R | 1/15
+/// 09 | 923 | Prognosis Code | 1 | O | ID | 1/1
+/// 10 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+pub struct UM {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+    #[serde(rename = "10")]
+    pub _10: Option<String>,
+}
+
 /// UR - Peer Review Organization or Utilization Review
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
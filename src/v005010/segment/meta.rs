@@ -0,0 +1,1910 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    AAA,
+    "Request Validation",
+    "To specify the validity of the request and indicate follow-up action authorized",
+    [_01, _02, _03, _04],
+    [
+        (1, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (2, "559", "Agency Qualifier Code", Optional, Id, 2, 2, agency_qualifier_code),
+        (3, "901", "Reject Reason Code", Optional, Id, 2, 2, reject_reason_code),
+        (4, "889", "Follow-up Action Code", Optional, Id, 1, 1, follow_up_action_code),
+    ]
+}
+
+segment_meta! {
+    ACT,
+    "Account Identification",
+    "To identify the account",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "508", "Account Number", Mandatory, AlphaNumeric, 1, 35, account_number_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "569", "Account Number Qualifier", Conditional, Id, 1, 3, account_number_qualifier),
+        (6, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_06),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (8, "1216", "Payment Method Type Code", Optional, Id, 1, 2, payment_method_type_code),
+        (9, "1217", "Benefit Status Code", Optional, Id, 1, 1, benefit_status_code),
+    ]
+}
+
+segment_meta! {
+    AD1,
+    "Adjustment Amount",
+    "",
+    [_01, _02, _03, _04, _05],
+    [
+    ]
+}
+
+segment_meta! {
+    ADX,
+    "Adjustment",
+    "To convey accounts-payable adjustment information for the purpose of cash application, including payer-generated debit/credit memos",
+    [_01, _02, _03, _04],
+    [
+        (1, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (2, "426", "Adjustment Reason Code", Mandatory, Id, 2, 2, adjustment_reason_code),
+        (3, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (4, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+    ]
+}
+
+segment_meta! {
+    AIN,
+    "Income",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+    ]
+}
+
+segment_meta! {
+    AMT,
+    "Monitary Amount Information",
+    "To indicate the total monetary amount",
+    [_01, _02, _03],
+    [
+        (1, "522", "Amount Qualifier Code", Mandatory, Id, 1, 3, amount_qualifier_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "478", "Credit/Debit Flag Code", Optional, Id, 1, 1, credit_debit_flag_code),
+    ]
+}
+
+segment_meta! {
+    BEN,
+    "Financial Contribution",
+    "",
+    [_01, _02, _03, _04, _05, _06],
+    [
+    ]
+}
+
+segment_meta! {
+    BGN,
+    "Beginning Segment",
+    "To indicate the beginning of a transaction set",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification_02),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "337", "Time", Conditional, Time, 4, 8, time),
+        (5, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (6, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_06),
+        (7, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (8, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (9, "786", "Security Level Code", Optional, Id, 2, 2, security_level_code),
+    ]
+}
+
+segment_meta! {
+    BHT,
+    "Beginning of Hierarchical Transaction",
+    "To define the business hierarchical structure of the transaction set and identify the business application purpose and reference data, i.e., number, date, and time",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1005", "Hierarchical Structure Code", Mandatory, Id, 4, 4, hierarchical_structure_code),
+        (2, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Optional, Time, 4, 8, time),
+        (6, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+    ]
+}
+
+segment_meta! {
+    BPR,
+    "Beginning Segment for Payment Order/Remittance Advice",
+    "To indicate the beginning of a Payment Order/Remittance Advice Transaction Set and total payment amount, or to enable related transfer of funds and/or information from payer to payee to occur",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "305", "Transaction Handling Code", Mandatory, Id, 1, 2, transaction_handling_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "478", "Credit/Debit Flag Code", Mandatory, Id, 1, 1, credit_debit_flag_code),
+        (4, "591", "Payment Method Code", Mandatory, Id, 3, 3, payment_method_code),
+        (5, "812", "Payment Format Code", Optional, Id, 1, 10, payment_format_code),
+        (6, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_06),
+        (7, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_07),
+        (8, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_08),
+        (9, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_09),
+        (10, "509", "Originating Company Identifier", Optional, AlphaNumeric, 10, 10, originating_company_identifier),
+        (11, "510", "Originating Company Supplemental Code", Optional, AlphaNumeric, 9, 9, originating_company_supplemental_code),
+        (12, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_12),
+        (13, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_13),
+        (14, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_14),
+        (15, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_15),
+        (16, "373", "Date", Optional, Date, 8, 8, date),
+        (17, "1048", "Business Function Code", Optional, Id, 1, 3, business_function_code),
+        (18, "506", "(DFI) ID Number Qualifier", Conditional, Id, 2, 2, dfi_id_number_qualifier_18),
+        (19, "507", "(DFI) Identification Number", Conditional, AlphaNumeric, 3, 12, dfi_identification_number_19),
+        (20, "569", "Account Number Qualifier", Optional, Id, 1, 3, account_number_qualifier_20),
+        (21, "508", "Account Number", Conditional, AlphaNumeric, 1, 35, account_number_21),
+    ]
+}
+
+segment_meta! {
+    CAS,
+    "Claims Adjustment",
+    "To supply adjustment reason codes and amounts as needed for an entire claim or for a particular service within the claim being paid",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19],
+    [
+        (1, "1033", "Claim Adjustment Group Code", Mandatory, Id, 1, 2, claim_adjustment_group_code),
+        (2, "1034", "Claim Adjustment Reason Code", Mandatory, Id, 1, 5, claim_adjustment_reason_code_02),
+        (3, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_03),
+        (4, "380", "Quantity", Optional, Decimal, 1, 15, quantity_04),
+        (5, "1034", "Claim Adjustment Reason Code", Conditional, Id, 1, 5, claim_adjustment_reason_code_05),
+        (6, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_06),
+        (7, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_07),
+        (8, "1034", "Claim Adjustment Reason Code", Conditional, Id, 1, 5, claim_adjustment_reason_code_08),
+        (9, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_09),
+        (10, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_10),
+        (11, "1034", "Claim Adjustment Reason Code", Conditional, Id, 1, 5, claim_adjustment_reason_code_11),
+        (12, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_12),
+        (13, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_13),
+        (14, "1034", "Claim Adjustment Reason Code", Conditional, Id, 1, 5, claim_adjustment_reason_code_14),
+        (15, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_15),
+        (16, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_16),
+        (17, "1034", "Claim Adjustment Reason Code", Conditional, Id, 1, 5, claim_adjustment_reason_code_17),
+        (18, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_18),
+        (19, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_19),
+    ]
+}
+
+segment_meta! {
+    CL1,
+    "Claim Codes",
+    "To supply information specific to hospital claims",
+    [_01, _02, _03, _04],
+    [
+        (1, "1315", "Admission Type Code", Optional, Id, 1, 1, admission_type_code),
+        (2, "1314", "Admission Source Code", Optional, Id, 1, 1, admission_source_code),
+        (3, "1352", "Patient Status Code", Optional, Id, 1, 2, patient_status_code),
+        (4, "1345", "Nursing Home Residential Status Code", Optional, Id, 1, 1, nursing_home_residential_status_code),
+    ]
+}
+
+segment_meta! {
+    CLM,
+    "Health Claim",
+    "To specify basic data about the claim",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20],
+    [
+        (1, "1028", "Claim Submitter's Identifier", Mandatory, AlphaNumeric, 1, 38, claim_submitters_identifier),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (3, "1032", "Claim Filing Indicator Code", Optional, Id, 1, 2, claim_filing_indicator_code),
+        (4, "1343", "Non-Institutional Claim Type Code", Optional, Id, 1, 2, non_institutional_claim_type_code),
+        (5, "C023", "Health Care Service Location Information", Optional, Composite, 0, 0, health_care_service_location_information),
+        (6, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_06),
+        (7, "1359", "Provider Accept Assignment Code", Optional, Id, 1, 1, provider_accept_assignment_code),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_08),
+        (9, "1363", "Release of Information Code", Optional, Id, 1, 1, release_of_information_code),
+        (10, "1351", "Patient Signature Source Code", Optional, Id, 1, 1, patient_signature_source_code),
+        (11, "C024", "Related Causes Information", Optional, Composite, 0, 0, related_causes_information),
+        (12, "1366", "Special Program Code", Optional, Id, 2, 3, special_program_code),
+        (13, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_13),
+        (14, "1338", "Level of Service Code", Optional, Id, 1, 3, level_of_service_code),
+        (15, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_15),
+        (16, "1360", "Provider Agreement Code", Optional, Id, 1, 1, provider_agreement_code),
+        (17, "1029", "Claim Status Code", Optional, Id, 1, 2, claim_status_code),
+        (18, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_18),
+        (19, "1383", "Claim Submission Reason Code", Optional, Id, 2, 2, claim_submission_reason_code),
+        (20, "1514", "Delay Reason Code", Optional, Id, 1, 2, delay_reason_code),
+    ]
+}
+
+segment_meta! {
+    CLP,
+    "Claim Level Data",
+    "To supply information common to all services of a claim",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "1028", "Claim Submitter's Identifier", Mandatory, AlphaNumeric, 1, 38, claim_submitters_identifier),
+        (2, "1029", "Claim Status Code", Mandatory, Id, 1, 2, claim_status_code),
+        (3, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_03),
+        (4, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_04),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_05),
+        (6, "1032", "Claim Filing Indicator Code", Optional, Id, 1, 2, claim_filing_indicator_code),
+        (7, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (8, "1331", "Facility Code Value", Optional, AlphaNumeric, 1, 2, facility_code_value),
+        (9, "1325", "Claim Frequency Type Code", Optional, Id, 1, 1, claim_frequency_type_code),
+        (10, "1352", "Patient Status Code", Optional, Id, 1, 2, patient_status_code),
+        (11, "1354", "Diagnosis Related Group (DRG) Code", Optional, Id, 1, 4, diagnosis_related_group_drg_code),
+        (12, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (13, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (14, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    CN1,
+    "Contract Information",
+    "To specify basic data about the contract or contract line item",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1166", "Contract Type Code", Mandatory, Id, 2, 2, contract_type_code),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (3, "332", "Percent, Decimal Format", Optional, Decimal, 1, 6, percent_decimal_format),
+        (4, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (5, "338", "Terms Discount Percent", Optional, Decimal, 1, 6, terms_discount_percent),
+        (6, "799", "Version Identifier", Optional, AlphaNumeric, 1, 30, version_identifier),
+    ]
+}
+
+segment_meta! {
+    COB,
+    "Coordination of Benefits",
+    "To supply information on coordination of benefits",
+    [_01, _02, _03, _04],
+    [
+        (1, "1138", "Payer Responsibility Sequence Number Code", Optional, Id, 1, 1, payer_responsibility_sequence_number_code),
+        (2, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "1143", "Coordination of Benefits Code", Optional, Id, 1, 1, coordination_of_benefits_code),
+        (4, "1365", "Service Type Code", Optional, Id, 1, 2, service_type_code),
+    ]
+}
+
+segment_meta! {
+    CR1,
+    "Ambulance Certification",
+    "To supply information related to the ambulance service rendered to a patient",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_01),
+        (2, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (3, "1316", "Ambulance Transport Code", Optional, Id, 1, 1, ambulance_transport_code),
+        (4, "1317", "Ambulance Transport Reason Code", Optional, Id, 1, 1, ambulance_transport_reason_code),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_05),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (7, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_07),
+        (8, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_08),
+        (9, "352", "Description", Optional, AlphaNumeric, 1, 80, description_09),
+        (10, "352", "Description", Optional, AlphaNumeric, 1, 80, description_10),
+    ]
+}
+
+segment_meta! {
+    CR2,
+    "Chiropractic Certification",
+    "To supply information related to the chiropractic service rendered to a patient",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "609", "Count", Optional, Numeric(0), 1, 9, count),
+        (2, "380", "Quantity", Optional, Decimal, 1, 15, quantity_02),
+        (3, "1367", "Subluxation Level Code", Conditional, Id, 2, 3, subluxation_level_code_03),
+        (4, "1367", "Subluxation Level Code", Optional, Id, 2, 3, subluxation_level_code_04),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity_07),
+        (8, "1342", "Nature of Condition Code", Optional, Id, 1, 1, nature_of_condition_code),
+        (9, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_09),
+        (10, "352", "Description", Optional, AlphaNumeric, 1, 80, description_10),
+        (11, "352", "Description", Optional, AlphaNumeric, 1, 80, description_11),
+        (12, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_12),
+    ]
+}
+
+segment_meta! {
+    CR3,
+    "Durable Medical Equipment Certification",
+    "To supply information regarding a physician's certification for durable medical equipment",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "1322", "Certification Type Code", Optional, Id, 1, 1, certification_type_code),
+        (2, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (4, "1335", "Insulin Dependent Code", Optional, Id, 1, 1, insulin_dependent_code),
+        (5, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    CR4,
+    "Enteral or Parenteral Therapy Certification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29],
+    [
+    ]
+}
+
+segment_meta! {
+    CR5,
+    "Oxygen Therapy Certification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+    ]
+}
+
+segment_meta! {
+    CR6,
+    "Home Health Care Certification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+    ]
+}
+
+segment_meta! {
+    CR7,
+    "Home Health Treatment Plan Certification",
+    "To supply certification information for a home health treatment plan",
+    [_01, _02, _03],
+    [
+        (1, "921", "Discipline Type Code", Mandatory, Id, 2, 2, discipline_type_code),
+        (2, "1470", "Number", Mandatory, Numeric(0), 1, 9, number_02),
+        (3, "1470", "Number", Mandatory, Numeric(0), 1, 9, number_03),
+    ]
+}
+
+segment_meta! {
+    CR8,
+    "Pacemaker Certification",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+    ]
+}
+
+segment_meta! {
+    CRC,
+    "Conditions Indicator",
+    "To supply information on conditions",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1136", "Code Category", Mandatory, Id, 2, 2, code_category),
+        (2, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "1321", "Condition Indicator", Mandatory, Id, 2, 3, condition_indicator_03),
+        (4, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_04),
+        (5, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_05),
+        (6, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_06),
+        (7, "1321", "Condition Indicator", Optional, Id, 2, 3, condition_indicator_07),
+    ]
+}
+
+segment_meta! {
+    CTP,
+    "Pricing Information",
+    "To specify pricing information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "687", "Class of Trade Code", Optional, Id, 2, 2, class_of_trade_code),
+        (2, "236", "Price Identifier Code", Conditional, Id, 3, 3, price_identifier_code),
+        (3, "212", "Unit Price", Conditional, Decimal, 1, 17, unit_price),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (6, "648", "Price Multiplier Qualifier", Conditional, Id, 3, 3, price_multiplier_qualifier),
+        (7, "649", "Multiplier", Conditional, Decimal, 1, 10, multiplier),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (9, "639", "Basis of Unit Price Code", Optional, Id, 2, 2, basis_of_unit_price_code),
+        (10, "499", "Condition Value", Optional, AlphaNumeric, 1, 10, condition_value),
+        (11, "289", "Multiple Price Quantity", Optional, Numeric(0), 1, 2, multiple_price_quantity),
+    ]
+}
+
+segment_meta! {
+    CUR,
+    "Currency",
+    "To specify the currency (dollars, pounds, francs, etc.) used in a transaction",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code_02),
+        (3, "280", "Exchange Rate", Optional, Decimal, 4, 10, exchange_rate),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_04),
+        (5, "100", "Currency Code", Conditional, Id, 3, 3, currency_code_05),
+        (6, "669", "Currency Market/Exchange Code", Optional, Id, 3, 3, currency_market_exchange_code),
+        (7, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_07),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "337", "Time", Optional, Time, 4, 8, time_09),
+        (10, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_10),
+        (11, "373", "Date", Conditional, Date, 8, 8, date_11),
+        (12, "337", "Time", Conditional, Time, 4, 8, time_12),
+        (13, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_13),
+        (14, "373", "Date", Conditional, Date, 8, 8, date_14),
+        (15, "337", "Time", Conditional, Time, 4, 8, time_15),
+        (16, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_16),
+        (17, "373", "Date", Conditional, Date, 8, 8, date_17),
+        (18, "337", "Time", Conditional, Time, 4, 8, time_18),
+        (19, "374", "Date/Time Qualifier", Conditional, Id, 3, 3, date_time_qualifier_19),
+        (20, "373", "Date", Conditional, Date, 8, 8, date_20),
+        (21, "337", "Time", Conditional, Time, 4, 8, time_21),
+    ]
+}
+
+segment_meta! {
+    DMG,
+    "Demographic Information",
+    "To supply demographic information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (2, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (3, "1068", "Gender Code", Optional, Id, 1, 1, gender_code),
+        (4, "1067", "Marital Status Code", Optional, Id, 1, 1, marital_status_code),
+        (5, "C056", "Composite Race or Ethnicity Information", Conditional, Composite, 0, 0, composite_race_or_ethnicity_information),
+        (6, "1066", "Citizenship Status Code", Optional, Id, 1, 2, citizenship_status_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "659", "Basis of Verification Code", Optional, Id, 1, 2, basis_of_verification_code),
+        (9, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (10, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (11, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    DN1,
+    "Orthodontic Information",
+    "To supply orthodontic information",
+    [_01, _02, _03, _04],
+    [
+        (1, "380", "Quantity", Optional, Decimal, 1, 15, quantity_01),
+        (2, "380", "Quantity", Optional, Decimal, 1, 15, quantity_02),
+        (3, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (4, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    DN2,
+    "Tooth Summary",
+    "To supply information on the status of individual teeth",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1271", "Industry Code", Mandatory, AlphaNumeric, 1, 30, industry_code),
+        (2, "1368", "Tooth Status Code", Mandatory, Id, 1, 2, tooth_status_code),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (4, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (5, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (6, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+    ]
+}
+
+segment_meta! {
+    DSB,
+    "Disability Information",
+    "To supply disability information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "1146", "Disability Type Code", Mandatory, Id, 1, 1, disability_type_code),
+        (2, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (3, "1149", "Occupation Code", Optional, Id, 4, 6, occupation_code),
+        (4, "1154", "Work Intensity Code", Optional, Id, 1, 1, work_intensity_code),
+        (5, "1161", "Product Option Code", Optional, AlphaNumeric, 1, 2, product_option_code),
+        (6, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (7, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier),
+        (8, "1137", "Medical Code Value", Conditional, AlphaNumeric, 1, 15, medical_code_value),
+    ]
+}
+
+segment_meta! {
+    DTM,
+    "Date/Time Reference",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (5, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (6, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+    ]
+}
+
+segment_meta! {
+    DTP,
+    "Date or Time or Period",
+    "To specify any or all of a date, a time, or a time period",
+    [_01, _02, _03],
+    [
+        (1, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier),
+        (2, "1250", "Date Time Period Format Qualifier", Mandatory, Id, 2, 3, date_time_period_format_qualifier),
+        (3, "1251", "Date Time Period", Mandatory, AlphaNumeric, 1, 35, date_time_period),
+    ]
+}
+
+segment_meta! {
+    EB,
+    "Eligibility or Benefit Information",
+    "To supply eligibility or benefit information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "1390", "Eligibility or Benefit Information Code", Mandatory, Id, 1, 2, eligibility_or_benefit_information_code),
+        (2, "1207", "Coverage Level Code", Optional, Id, 3, 3, coverage_level_code),
+        (3, "1365", "Service Type Code", Optional, Id, 1, 2, service_type_code),
+        (4, "1336", "Insurance Type Code", Optional, Id, 1, 3, insurance_type_code),
+        (5, "1204", "Plan Coverage Description", Optional, AlphaNumeric, 1, 50, plan_coverage_description),
+        (6, "615", "Time Period Qualifier", Optional, Id, 1, 2, time_period_qualifier),
+        (7, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (8, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (9, "673", "Quantity Qualifier", Optional, Id, 2, 2, quantity_qualifier),
+        (10, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_11),
+        (12, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_12),
+        (13, "C003", "Composite Medical Procedure Identifier", Optional, Composite, 0, 0, composite_medical_procedure_identifier),
+        (14, "C004", "Composite Diagnosis Code Pointer", Optional, Composite, 0, 0, composite_diagnosis_code_pointer),
+    ]
+}
+
+segment_meta! {
+    EC,
+    "Employment Class",
+    "To specify employment class",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1176", "Employment Class Code", Optional, Id, 2, 3, employment_class_code_01),
+        (2, "1176", "Employment Class Code", Optional, Id, 2, 3, employment_class_code_02),
+        (3, "1176", "Employment Class Code", Optional, Id, 2, 3, employment_class_code_03),
+        (4, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (5, "1201", "Information Status Code", Optional, Id, 1, 1, information_status_code),
+        (6, "1165", "Occupation Code", Optional, Id, 4, 6, occupation_code),
+    ]
+}
+
+segment_meta! {
+    ENT,
+    "Entity",
+    "To designate the entities which are parties to a transaction and specify a reference meaningful to those entities",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "554", "Assigned Number", Optional, Numeric(0), 1, 6, assigned_number),
+        (2, "98", "Entity Identifier Code", Conditional, Id, 2, 3, entity_identifier_code_02),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier_03),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_04),
+        (5, "98", "Entity Identifier Code", Conditional, Id, 2, 3, entity_identifier_code_05),
+        (6, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier_06),
+        (7, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code_07),
+        (8, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (9, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+    ]
+}
+
+segment_meta! {
+    EQ,
+    "Eligibility or Benefit Inquiry",
+    "To specify inquired eligibility or benefit information",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "1365", "Service Type Code", Conditional, Id, 1, 2, service_type_code),
+        (2, "C003", "Composite Medical Procedure Identifier", Conditional, Composite, 0, 0, composite_medical_procedure_identifier),
+        (3, "1207", "Coverage Level Code", Optional, Id, 3, 3, coverage_level_code),
+        (4, "1336", "Insurance Type Code", Optional, Id, 1, 3, insurance_type_code),
+        (5, "C004", "Composite Diagnosis Code Pointer", Optional, Composite, 0, 0, composite_diagnosis_code_pointer),
+    ]
+}
+
+segment_meta! {
+    FC,
+    "Financial Contribution",
+    "",
+    [_01, _02, _03, _04, _05],
+    [
+    ]
+}
+
+segment_meta! {
+    FRM,
+    "Supporting Documentation",
+    "To specify information in response to a codified questionnaire document",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "350", "Assigned Identification", Mandatory, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "1073", "Yes/No Condition or Response Code", Conditional, Id, 1, 1, yes_no_condition_or_response_code),
+        (3, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (4, "373", "Date", Conditional, Date, 8, 8, date),
+        (5, "332", "Percent, Decimal Format", Conditional, Decimal, 1, 6, percent_decimal_format),
+    ]
+}
+
+segment_meta! {
+    FSA,
+    "Flexible Spending Amount",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "FunctionalGroup Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 6, 6, date),
+        (5, "337", "Time", Mandatory, Time, 4, 6, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    HCP,
+    "Health Care Pricing",
+    "To specify pricing or repricing information about a health care claim or line item",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "1473", "Pricing Methodology", Mandatory, Id, 2, 2, pricing_methodology),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_02),
+        (3, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_03),
+        (4, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_04),
+        (5, "118", "Rate", Optional, Decimal, 1, 9, rate),
+        (6, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_06),
+        (7, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_07),
+        (8, "234", "Product/Service ID", Optional, AlphaNumeric, 1, 48, product_service_id_08),
+        (9, "235", "Product/Service ID Qualifier", Optional, Id, 2, 2, product_service_id_qualifier),
+        (10, "234", "Product/Service ID", Optional, AlphaNumeric, 1, 48, product_service_id_10),
+        (11, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (12, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (13, "901", "Reject Reason Code", Optional, Id, 2, 2, reject_reason_code),
+        (14, "1526", "Policy Compliance Code", Optional, Id, 1, 2, policy_compliance_code),
+        (15, "1527", "Exception Code", Optional, Id, 1, 2, exception_code),
+    ]
+}
+
+segment_meta! {
+    HCR,
+    "Health Care Services Review",
+    "To specify the outcome of a health care services review",
+    [_01, _02, _03, _04],
+    [
+        (1, "306", "Action Code", Mandatory, Id, 1, 2, action_code),
+        (2, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "1271", "Industry Code", Optional, AlphaNumeric, 1, 30, industry_code),
+        (4, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    HD,
+    "Health Coverage",
+    "To provide information on health coverage",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "875", "Maintenance Type Code", Mandatory, Id, 3, 3, maintenance_type_code),
+        (2, "1203", "Maintenance Reason Code", Optional, Id, 2, 3, maintenance_reason_code),
+        (3, "1205", "Insurance Line Code", Optional, Id, 2, 3, insurance_line_code),
+        (4, "1204", "Plan Coverage Description", Optional, AlphaNumeric, 1, 50, plan_coverage_description),
+        (5, "1207", "Coverage Level Code", Optional, Id, 3, 3, coverage_level_code),
+        (6, "609", "Count", Optional, Numeric(0), 1, 9, count_06),
+        (7, "609", "Count", Optional, Numeric(0), 1, 9, count_07),
+        (8, "1209", "Underwriting Decision Code", Optional, Id, 1, 1, underwriting_decision_code),
+        (9, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_09),
+        (10, "1211", "Drug House Code", Optional, Id, 2, 3, drug_house_code),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_11),
+    ]
+}
+
+segment_meta! {
+    HI,
+    "Health Care Information Codes",
+    "To supply information related to the delivery of health care",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "C022", "Health Care Code Information", Mandatory, Composite, 0, 0, health_care_code_information_01),
+        (2, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_02),
+        (3, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_03),
+        (4, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_04),
+        (5, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_05),
+        (6, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_06),
+        (7, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_07),
+        (8, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_08),
+        (9, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_09),
+        (10, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_10),
+        (11, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_11),
+        (12, "C022", "Health Care Code Information", Optional, Composite, 0, 0, health_care_code_information_12),
+    ]
+}
+
+segment_meta! {
+    HL,
+    "Hierarchical Level",
+    "To identify dependencies among and the content of hierarchically related groups of data segments",
+    [_01, _02, _03, _04],
+    [
+        (1, "628", "Hierarchical ID Number", Mandatory, AlphaNumeric, 1, 12, hierarchical_id_number),
+        (2, "734", "Hierarchical Parent ID Number", Optional, AlphaNumeric, 1, 12, hierarchical_parent_id_number),
+        (3, "735", "Hierarchical Level Code", Mandatory, Id, 1, 2, hierarchical_level_code),
+        (4, "736", "Hierarchical Child Code", Optional, Id, 1, 1, hierarchical_child_code),
+    ]
+}
+
+segment_meta! {
+    HLH,
+    "Health Information",
+    "To provide health information",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "1212", "Health-Related Code", Mandatory, Id, 1, 1, health_related_code),
+        (2, "65", "Height", Optional, Decimal, 1, 8, height),
+        (3, "81", "Weight", Optional, Decimal, 1, 10, weight_03),
+        (4, "81", "Weight", Optional, Decimal, 1, 10, weight_04),
+        (5, "352", "Description", Optional, AlphaNumeric, 1, 80, description_05),
+        (6, "1213", "Current Health Condition Code", Optional, Id, 1, 1, current_health_condition_code),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description_07),
+    ]
+}
+
+segment_meta! {
+    HSD,
+    "Health Care Services Delivery",
+    "To specify the delivery pattern of health care services",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "673", "Quantity Qualifier", Conditional, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "1167", "Sample Selection Modulus", Optional, Decimal, 1, 6, sample_selection_modulus),
+        (5, "615", "Time Period Qualifier", Conditional, Id, 1, 2, time_period_qualifier),
+        (6, "616", "Number of Periods", Optional, Numeric(0), 1, 3, number_of_periods),
+        (7, "678", "Ship/Delivery or Calendar Pattern Code", Optional, Id, 1, 2, ship_delivery_or_calendar_pattern_code),
+        (8, "679", "Ship/Delivery Pattern Time Code", Optional, Id, 1, 1, ship_delivery_pattern_time_code),
+    ]
+}
+
+segment_meta! {
+    ICM,
+    "Individual Income",
+    "To supply individual income information",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "594", "Frequency Code", Mandatory, Id, 1, 1, frequency_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (4, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (5, "1214", "Salary Grade", Optional, AlphaNumeric, 1, 5, salary_grade),
+        (6, "100", "Currency Code", Optional, Id, 3, 3, currency_code),
+    ]
+}
+
+segment_meta! {
+    IDC,
+    "Health Coverage",
+    "To provide information for the production of an identification card",
+    [_01, _02, _03, _04],
+    [
+        (1, "1204", "Plan Coverage Description", Mandatory, AlphaNumeric, 1, 50, plan_coverage_description),
+        (2, "1215", "Identification Card Type Code", Mandatory, Id, 1, 1, identification_card_type_code),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (4, "306", "Action Code", Optional, Id, 1, 2, action_code),
+    ]
+}
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer",
+    "To define the end of an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    III,
+    "Information",
+    "To report information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (2, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+        (3, "1136", "Code Category", Conditional, Id, 2, 2, code_category),
+        (4, "933", "Free-form Message Text", Conditional, AlphaNumeric, 1, 264, free_form_message_text),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (6, "C001", "Composite Unit of Measure", Optional, Composite, 0, 0, composite_unit_of_measure),
+        (7, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code_07),
+        (8, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code_08),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code_09),
+    ]
+}
+
+segment_meta! {
+    IMM,
+    "Immunization Status",
+    "",
+    [_01, _02, _03, _04, _05, _06],
+    [
+    ]
+}
+
+segment_meta! {
+    INS,
+    "Insured Benefit",
+    "To provide benefit information on insured entities",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "1073", "Yes/No Condition or Response Code", Mandatory, Id, 1, 1, yes_no_condition_or_response_code_01),
+        (2, "1069", "Individual Relationship Code", Mandatory, Id, 2, 2, individual_relationship_code),
+        (3, "875", "Maintenance Type Code", Optional, Id, 3, 3, maintenance_type_code),
+        (4, "1203", "Maintenance Reason Code", Optional, Id, 2, 3, maintenance_reason_code),
+        (5, "1216", "Benefit Status Code", Optional, Id, 1, 1, benefit_status_code),
+        (6, "C052", "Medicare Status Code", Optional, Composite, 0, 0, medicare_status_code),
+        (7, "1219", "Consolidated Omnibus Budget Reconciliation Act (COBRA) Qualifying Event Code", Optional, Id, 1, 2, consolidated_omnibus_budget_reconciliation_act_cobra_qualifying_event_code),
+        (8, "584", "Employment Status Code", Optional, Id, 2, 2, employment_status_code),
+        (9, "1220", "Student Status Code", Optional, Id, 1, 1, student_status_code),
+        (10, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_10),
+        (11, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (12, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (13, "1165", "Confidentiality Code", Optional, Id, 1, 1, confidentiality_code),
+        (14, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (15, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (16, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (17, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    INV,
+    "Incestment Vehicle Selection",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header",
+    "To start and identify an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I65", "Repetition Separator", Mandatory, AlphaNumeric, 1, 1, repetition_separator),
+        (12, "I11", "Interchange Control Version Number Code", Mandatory, Id, 5, 5, interchange_control_version_number_code),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Usage Indicator", Mandatory, Id, 1, 1, usage_indicator),
+        (16, "I15", "Component Element Separator", Mandatory, Composite, 1, 1, component_element_separator),
+    ]
+}
+
+segment_meta! {
+    K3,
+    "File Information",
+    "To transmit a fixed-format record or matrix contents",
+    [_01, _02, _03],
+    [
+        (1, "449", "Fixed Format Information", Mandatory, AlphaNumeric, 1, 80, fixed_format_information),
+        (2, "1333", "Record Format Code", Optional, Id, 1, 2, record_format_code),
+        (3, "C001", "Composite Unit of Measure", Optional, Composite, 0, 0, composite_unit_of_measure),
+    ]
+}
+
+segment_meta! {
+    LC,
+    "Life Coverage",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+    ]
+}
+
+segment_meta! {
+    LE,
+    "Loop Trailer",
+    "To indicate that the loop immediately preceding this segment is complete",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 4, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LIN,
+    "Item Identification",
+    "To specify basic item identification data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31],
+    [
+        (1, "350", "Assigned Identification", Optional, AlphaNumeric, 1, 20, assigned_identification),
+        (2, "235", "Product/Service ID Qualifier", Mandatory, Id, 2, 2, product_service_id_qualifier_02),
+        (3, "234", "Product/Service ID", Mandatory, AlphaNumeric, 1, 48, product_service_id_03),
+        (4, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_04),
+        (5, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_05),
+        (6, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_06),
+        (7, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_07),
+        (8, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_08),
+        (9, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_09),
+        (10, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_10),
+        (11, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_11),
+        (12, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_12),
+        (13, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_13),
+        (14, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_14),
+        (15, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_15),
+        (16, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_16),
+        (17, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_17),
+        (18, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_18),
+        (19, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_19),
+        (20, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_20),
+        (21, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_21),
+        (22, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_22),
+        (23, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_23),
+        (24, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_24),
+        (25, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_25),
+        (26, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_26),
+        (27, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_27),
+        (28, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_28),
+        (29, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_29),
+        (30, "235", "Product/Service ID Qualifier", Conditional, Id, 2, 2, product_service_id_qualifier_30),
+        (31, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id_31),
+    ]
+}
+
+segment_meta! {
+    LQ,
+    "Industry Code Identification",
+    "Code to transmit standard industry codes",
+    [_01, _02],
+    [
+        (1, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (2, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    LS,
+    "Loop Header",
+    "To indicate that the next segment begins a loop",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 4, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LUI,
+    "Language Use",
+    "To specify language, type of usage, and proficiency or fluency",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (2, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (4, "1303", "Use of Language Indicator", Optional, Id, 1, 2, use_of_language_indicator),
+        (5, "1476", "Language Proficiency Indicator", Optional, Id, 1, 1, language_proficiency_indicator),
+    ]
+}
+
+segment_meta! {
+    LX,
+    "Transaction Set Line Number",
+    "To reference a line number in a transaction set",
+    [_01],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+    ]
+}
+
+segment_meta! {
+    MEA,
+    "Measurements",
+    "To specify physical measurements or counts, including dimensions, tolerances, variances, and weights",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "737", "Measurement Reference ID Code", Optional, Id, 2, 2, measurement_reference_id_code),
+        (2, "738", "Measurement Qualifier", Optional, Id, 1, 3, measurement_qualifier),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (5, "740", "Range Minimum", Conditional, Decimal, 1, 20, range_minimum),
+        (6, "741", "Range Maximum", Conditional, Decimal, 1, 20, range_maximum),
+        (7, "935", "Measurement Significance Code", Optional, Id, 2, 2, measurement_significance_code),
+        (8, "936", "Measurement Attribute Code", Conditional, Id, 2, 2, measurement_attribute_code),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (10, "1373", "Measurement Method or Device", Optional, Id, 2, 4, measurement_method_or_device),
+        (11, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (12, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    MIA,
+    "Medicare Inpatient Adjudication",
+    "To provide claim-level data related to the adjudication of Medicare inpatient claims",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24],
+    [
+        (1, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_01),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_02),
+        (3, "380", "Quantity", Optional, Decimal, 1, 15, quantity_03),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_04),
+        (5, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_05),
+        (6, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_06),
+        (7, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_07),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+        (9, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_09),
+        (10, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_10),
+        (11, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_11),
+        (12, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_12),
+        (13, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_13),
+        (14, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_14),
+        (15, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_15),
+        (16, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_16),
+        (17, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_17),
+        (18, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_18),
+        (19, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_19),
+        (20, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_20),
+        (21, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_21),
+        (22, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_22),
+        (23, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_23),
+        (24, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_24),
+    ]
+}
+
+segment_meta! {
+    MOA,
+    "Medicare Outpatient Adjudication",
+    "To convey claim-level data related to the adjudication of Medicare claims not related to an inpatient setting",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_02),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_03),
+        (4, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_04),
+        (5, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_05),
+        (6, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_06),
+        (7, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_07),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+        (9, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_09),
+    ]
+}
+
+segment_meta! {
+    MSG,
+    "Message Text",
+    "To provide a free-form format that allows the transmission of text information",
+    [_01, _02, _03],
+    [
+        (1, "933", "Free-form Message Text", Mandatory, AlphaNumeric, 1, 264, free_form_message_text),
+        (2, "934", "Printer Carriage Control Code", Conditional, Id, 2, 2, printer_carriage_control_code),
+        (3, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+    ]
+}
+
+segment_meta! {
+    N1,
+    "Party Identifier",
+    "To identify a party by type of organization, name, and code",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "706", "Entity Relationship Code", Optional, Id, 2, 2, entity_relationship_code),
+        (6, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_06),
+    ]
+}
+
+segment_meta! {
+    N2,
+    "Additional Name Information",
+    "To specify additional names or those longer than 35 characters in length",
+    [_01, _02],
+    [
+        (1, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name_02),
+    ]
+}
+
+segment_meta! {
+    N3,
+    "Party Location",
+    "To specify the location of the named party",
+    [_01, _02],
+    [
+        (1, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_01),
+        (2, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_02),
+    ]
+}
+
+segment_meta! {
+    N4,
+    "Geographic Location",
+    "To specify the geographic place of the named party",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Conditional, Id, 2, 3, country_code),
+        (5, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (7, "1715", "Country Subdivision Code", Conditional, Id, 1, 3, country_subdivision_code),
+    ]
+}
+
+segment_meta! {
+    NM1,
+    "Individual or Organizational Name",
+    "To supply the full name of an individual or organizational entity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "1065", "Entity Type Qualifier", Mandatory, Id, 1, 1, entity_type_qualifier),
+        (3, "1035", "Name Last or Organization Name", Conditional, AlphaNumeric, 1, 60, name_last_or_organization_name_03),
+        (4, "1036", "Name First", Optional, AlphaNumeric, 1, 35, name_first),
+        (5, "1037", "Name Middle", Optional, AlphaNumeric, 1, 25, name_middle),
+        (6, "1038", "Name Prefix", Optional, AlphaNumeric, 1, 10, name_prefix),
+        (7, "1039", "Name Suffix", Optional, AlphaNumeric, 1, 10, name_suffix),
+        (8, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (9, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (10, "706", "Entity Relationship Code", Conditional, Id, 2, 2, entity_relationship_code),
+        (11, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_11),
+        (12, "1035", "Name Last or Organization Name", Optional, AlphaNumeric, 1, 60, name_last_or_organization_name_12),
+    ]
+}
+
+segment_meta! {
+    NTE,
+    "Note/Special Instruction",
+    "To transmit information in a free-form format, if necessary, for comment or special instruction",
+    [_01, _02],
+    [
+        (1, "363", "Note Reference Code", Optional, Id, 3, 3, note_reference_code),
+        (2, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+    ]
+}
+
+segment_meta! {
+    NX1,
+    "Property or Entity Identification",
+    "To identify the entity or property being referenced",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "1106", "Property or Entity Identification Code", Mandatory, Id, 1, 3, property_or_entity_identification_code_01),
+        (2, "1106", "Property or Entity Identification Code", Optional, Id, 1, 3, property_or_entity_identification_code_02),
+        (3, "1106", "Property or Entity Identification Code", Optional, Id, 1, 3, property_or_entity_identification_code_03),
+        (4, "1106", "Property or Entity Identification Code", Optional, Id, 1, 3, property_or_entity_identification_code_04),
+        (5, "1106", "Property or Entity Identification Code", Optional, Id, 1, 3, property_or_entity_identification_code_05),
+    ]
+}
+
+segment_meta! {
+    OI,
+    "Other Health Insurance Information",
+    "To specify information associated with other health insurance coverage",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1032", "Claim Filing Indicator Code", Optional, Id, 1, 2, claim_filing_indicator_code),
+        (2, "1383", "Claim Submission Reason Code", Optional, Id, 2, 2, claim_submission_reason_code),
+        (3, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (4, "1351", "Patient Signature Source Code", Optional, Id, 1, 1, patient_signature_source_code),
+        (5, "1360", "Provider Agreement Code", Optional, Id, 1, 1, provider_agreement_code),
+        (6, "1363", "Release of Information Code", Optional, Id, 1, 1, release_of_information_code),
+    ]
+}
+
+segment_meta! {
+    OTI,
+    "Original Transaction Identification",
+    "To identify the edited transaction set and the level at which the results of the edit are reported, and to indicate the accepted, rejected, or accepted-with-change edit result",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "110", "Application Acknowledgment Code", Mandatory, Id, 1, 2, application_acknowledgment_code),
+        (2, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (3, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification),
+        (4, "142", "Application Sender's Code", Optional, AlphaNumeric, 2, 15, application_senders_code),
+        (5, "124", "Application Receiver's Code", Optional, AlphaNumeric, 2, 15, application_receivers_code),
+        (6, "373", "Date", Optional, Date, 8, 8, date),
+        (7, "337", "Time", Optional, Time, 4, 8, time),
+        (8, "28", "Group Control Number", Conditional, Numeric(0), 1, 9, group_control_number),
+        (9, "329", "Transaction Set Control Number", Optional, AlphaNumeric, 4, 9, transaction_set_control_number),
+        (10, "143", "Transaction Set Identifier Code", Optional, Id, 3, 3, transaction_set_identifier_code),
+        (11, "480", "Version / Release / Industry Identifier Code", Optional, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+        (12, "353", "Transaction Set Purpose Code", Optional, Id, 2, 2, transaction_set_purpose_code),
+        (13, "640", "Transaction Type Code", Optional, Id, 2, 2, transaction_type_code),
+        (14, "346", "Application Type", Optional, Id, 2, 2, application_type),
+        (15, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (16, "305", "Transaction Handling Code", Optional, Id, 1, 2, transaction_handling_code),
+        (17, "633", "Purpose Code", Optional, Id, 2, 2, purpose_code),
+    ]
+}
+
+segment_meta! {
+    PAT,
+    "Patient Information",
+    "To supply patient information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1069", "Individual Relationship Code", Optional, Id, 2, 2, individual_relationship_code),
+        (2, "1384", "Patient Location Code", Optional, Id, 1, 1, patient_location_code),
+        (3, "584", "Employment Status Code", Optional, Id, 2, 2, employment_status_code),
+        (4, "1220", "Student Status Code", Optional, Id, 1, 1, student_status_code),
+        (5, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (6, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+        (7, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (8, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (9, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    PER,
+    "Administrative Communications Contact",
+    "To identify a person or office to whom administrative communications should be directed",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_03),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 256, communication_number_04),
+        (5, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_05),
+        (6, "364", "Communication Number", Conditional, AlphaNumeric, 1, 256, communication_number_06),
+        (7, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_07),
+        (8, "364", "Communication Number", Conditional, AlphaNumeric, 1, 256, communication_number_08),
+        (9, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    PLA,
+    "Place or Location",
+    "To indicate action to be taken for the location specified and to qualify the location specified",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "306", "Action Code", Mandatory, Id, 1, 2, action_code),
+        (2, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "337", "Time", Optional, Time, 4, 8, time),
+        (5, "1203", "Maintenance Reason Code", Optional, Id, 2, 3, maintenance_reason_code),
+    ]
+}
+
+segment_meta! {
+    PLB,
+    "Provider Level Adjustment",
+    "To convey provider level adjustment information for debit or credit transactions such as, accelerated payments, cost report settlements for a fiscal year and timeliness report penalties unrelated to a specific claim or service",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification),
+        (2, "373", "Date", Mandatory, Date, 8, 8, date),
+        (3, "C042", "Adjustment Identifier", Mandatory, Composite, 0, 0, adjustment_identifier_03),
+        (4, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_04),
+        (5, "C042", "Adjustment Identifier", Conditional, Composite, 0, 0, adjustment_identifier_05),
+        (6, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_06),
+        (7, "C042", "Adjustment Identifier", Conditional, Composite, 0, 0, adjustment_identifier_07),
+        (8, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_08),
+        (9, "C042", "Adjustment Identifier", Conditional, Composite, 0, 0, adjustment_identifier_09),
+        (10, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_10),
+        (11, "C042", "Adjustment Identifier", Conditional, Composite, 0, 0, adjustment_identifier_11),
+        (12, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_12),
+        (13, "C042", "Adjustment Identifier", Conditional, Composite, 0, 0, adjustment_identifier_13),
+        (14, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount_14),
+    ]
+}
+
+segment_meta! {
+    PM,
+    "Electronic Funds Transfer Information",
+    "To identify the electronic funds transfer (EFT) information",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "506", "(DFI) ID Number Qualifier", Mandatory, Id, 2, 2, dfi_id_number_qualifier),
+        (2, "507", "(DFI) Identification Number", Mandatory, AlphaNumeric, 3, 12, dfi_identification_number),
+        (3, "569", "Account Number Qualifier", Mandatory, Id, 1, 3, account_number_qualifier),
+        (4, "508", "Account Number", Mandatory, AlphaNumeric, 1, 35, account_number),
+    ]
+}
+
+segment_meta! {
+    PRV,
+    "Provider Information",
+    "To specify the identifying characteristics of a provider",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "1221", "Provider Code", Mandatory, Id, 1, 3, provider_code),
+        (2, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (3, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (4, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (5, "C035", "Provider Specialty Information", Optional, Composite, 0, 0, provider_specialty_information),
+        (6, "1223", "Provider Organization Code", Optional, Id, 3, 3, provider_organization_code),
+    ]
+}
+
+segment_meta! {
+    PS1,
+    "Purchase Service",
+    "To provide information related to purchased services",
+    [_01, _02, _03],
+    [
+        (1, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+    ]
+}
+
+segment_meta! {
+    PWK,
+    "Disability Information",
+    "To identify the type or transmission or both of paperwork or supporting information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "755", "Report Type Code", Mandatory, Id, 2, 2, report_type_code),
+        (2, "756", "Report Transmission Code", Optional, Id, 1, 2, report_transmission_code),
+        (3, "757", "Report Copies Needed", Optional, Numeric(0), 1, 2, report_copies_needed),
+        (4, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (5, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (6, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (8, "C002", "Actions Indicated", Optional, Composite, 0, 0, actions_indicated),
+        (9, "1525", "Request Category Code", Optional, Id, 1, 2, request_category_code),
+    ]
+}
+
+segment_meta! {
+    QTY,
+    "Beginning Segment",
+    "To specify quantity information",
+    [_01, _02, _03, _04],
+    [
+        (1, "673", "Quantity Qualifier", Mandatory, Id, 2, 2, quantity_qualifier),
+        (2, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (3, "C001", "Composite Unit of Measure", Optional, Composite, 0, 0, composite_unit_of_measure),
+        (4, "61", "Free-Form Message", Conditional, AlphaNumeric, 1, 30, free_form_message),
+    ]
+}
+
+segment_meta! {
+    RDM,
+    "Remittance Delivery Method",
+    "To identify remittance delivery when remittance is separated from payment",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "756", "Report Transmission Code", Mandatory, Id, 1, 2, report_transmission_code),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "364", "Communication Number", Optional, AlphaNumeric, 1, 256, communication_number),
+        (4, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier_04),
+        (5, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier_05),
+    ]
+}
+
+segment_meta! {
+    RED,
+    "Related Data",
+    "To relate data that is associated with a previously defined error condition",
+    [_01, _02, _03],
+    [
+        (1, "352", "Description", Mandatory, AlphaNumeric, 1, 80, description),
+        (2, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (3, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    REF,
+    "Reference Information",
+    "To specify identifying information",
+    [_01, _02, _03, _04],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (4, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    REL,
+    "Relationship",
+    "",
+    [_01, _02],
+    [
+    ]
+}
+
+segment_meta! {
+    RMR,
+    "Remittance Advice Accounts Receivable Open Item Reference",
+    "To specify the accounts receivable open item(s) to be included in the cash application and to convey the appropriate detail",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 50, reference_identification),
+        (3, "482", "Payment Action Code", Optional, Id, 2, 2, payment_action_code),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_04),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_05),
+        (6, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_06),
+        (7, "426", "Adjustment Reason Code", Optional, Id, 2, 2, adjustment_reason_code),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+    ]
+}
+
+segment_meta! {
+    RP,
+    "Retirement Product",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+    ]
+}
+
+segment_meta! {
+    SBR,
+    "Subscriber Information",
+    "To supply information about the subscriber of a health care plan",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1138", "Payer Responsibility Sequence Number Code", Mandatory, Id, 1, 1, payer_responsibility_sequence_number_code),
+        (2, "1069", "Individual Relationship Code", Optional, Id, 2, 2, individual_relationship_code),
+        (3, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (4, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (5, "1336", "Insurance Type Code", Optional, Id, 1, 3, insurance_type_code),
+        (6, "1143", "Coordination of Benefits Code", Optional, Id, 1, 1, coordination_of_benefits_code),
+        (7, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (8, "584", "Employment Status Code", Optional, Id, 2, 2, employment_status_code),
+        (9, "1032", "Claim Filing Indicator Code", Optional, Id, 1, 2, claim_filing_indicator_code),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments).",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02, _03],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+        (3, "1705", "Implementation Convention Reference", Optional, AlphaNumeric, 1, 35, implementation_convention_reference),
+    ]
+}
+
+segment_meta! {
+    STC,
+    "Claim or Service Line Status information",
+    "To report the status, required action, and paid information of a claim or service line",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "C043", "Health Care Claim Status", Mandatory, Composite, 0, 0, health_care_claim_status_01),
+        (2, "373", "Date", Optional, Date, 8, 8, date_02),
+        (3, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_04),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_05),
+        (6, "373", "Date", Optional, Date, 8, 8, date_06),
+        (7, "591", "Payment Method Code", Optional, Id, 3, 3, payment_method_code),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "429", "Check Number", Optional, AlphaNumeric, 1, 16, check_number),
+        (10, "C043", "Health Care Claim Status", Optional, Composite, 0, 0, health_care_claim_status_10),
+        (11, "C043", "Health Care Claim Status", Optional, Composite, 0, 0, health_care_claim_status_11),
+        (12, "933", "Free-Form Message Text", Optional, AlphaNumeric, 1, 264, free_form_message_text),
+    ]
+}
+
+segment_meta! {
+    SV1,
+    "Professional Service",
+    "To specify the claim service detail for a Health Care professional",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21],
+    [
+        (1, "C003", "Composite Medical Procedure Identifier", Mandatory, Composite, 0, 0, composite_medical_procedure_identifier),
+        (2, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_02),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (5, "1331", "Facility Code Value", Optional, AlphaNumeric, 1, 2, facility_code_value),
+        (6, "1365", "Service Type Code", Optional, Id, 1, 2, service_type_code),
+        (7, "C004", "Composite Diagnosis Code Pointer", Optional, Composite, 0, 0, composite_diagnosis_code_pointer),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+        (9, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_09),
+        (10, "1340", "Multiple Procedure Code", Optional, Id, 1, 2, multiple_procedure_code),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_11),
+        (12, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_12),
+        (13, "1364", "Review Code", Optional, Id, 1, 2, review_code),
+        (14, "1341", "National or Local Assigned Review Value", Optional, AlphaNumeric, 1, 2, national_or_local_assigned_review_value),
+        (15, "1327", "Copay Status Code", Optional, Id, 1, 1, copay_status_code),
+        (16, "1334", "Health Care Professional Shortage Area Code", Optional, Id, 1, 1, health_care_professional_shortage_area_code),
+        (17, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification),
+        (18, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (19, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_19),
+        (20, "1337", "Level of Care Code", Optional, Id, 1, 1, level_of_care_code),
+        (21, "1360", "Provider Agreement Code", Optional, Id, 1, 1, provider_agreement_code),
+    ]
+}
+
+segment_meta! {
+    SV2,
+    "Institutional Service",
+    "To specify the service line item detail for a health care institution",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "234", "Product/Service ID", Conditional, AlphaNumeric, 1, 48, product_service_id),
+        (2, "C003", "Composite Medical Procedure Identifier", Conditional, Composite, 0, 0, composite_medical_procedure_identifier),
+        (3, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_03),
+        (4, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (5, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (6, "1371", "Unit Rate", Conditional, Decimal, 1, 10, unit_rate),
+        (7, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_07),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (9, "1345", "Nursing Home Residential Status Code", Optional, Id, 1, 1, nursing_home_residential_status_code),
+        (10, "1337", "Level of Care Code", Optional, Id, 1, 1, level_of_care_code),
+    ]
+}
+
+segment_meta! {
+    SV3,
+    "Dental Service",
+    "To specify the claim service detail for dental work",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "C003", "Composite Medical Procedure Identifier", Mandatory, Composite, 0, 0, composite_medical_procedure_identifier),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "1331", "Facility Code Value", Conditional, AlphaNumeric, 1, 2, facility_code_value),
+        (4, "C006", "Oral Cavity Designation", Optional, Composite, 0, 0, oral_cavity_designation),
+        (5, "1358", "Prosthesis, Crown, or Inlay Code", Optional, Id, 1, 1, prosthesis_crown_or_inlay_code),
+        (6, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (7, "352", "Description", Optional, AlphaNumeric, 1, 80, description),
+        (8, "1327", "Copay Status Code", Optional, Id, 1, 1, copay_status_code),
+        (9, "1360", "Provider Agreement Code", Optional, Id, 1, 1, provider_agreement_code),
+        (10, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    SV4,
+    "Drug Service",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+    ]
+}
+
+segment_meta! {
+    SV5,
+    "Durable Medical Equipment Service",
+    "To specify the claim service detail for durable medical equipment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "C003", "Composite Medical Procedure Identifier", Mandatory, Composite, 0, 0, composite_medical_procedure_identifier),
+        (2, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (3, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_04),
+        (5, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_05),
+        (6, "594", "Frequency Code", Optional, Id, 1, 1, frequency_code),
+        (7, "923", "Prognosis Code", Optional, Id, 1, 1, prognosis_code),
+    ]
+}
+
+segment_meta! {
+    SV6,
+    "Anesthesia Service",
+    "To specify the claim service detail for anesthesia",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "C003", "Composite Medical Procedure Identifier", Mandatory, Composite, 0, 0, composite_medical_procedure_identifier),
+        (2, "1332", "Facility Code Qualifier", Optional, Id, 1, 2, facility_code_qualifier),
+        (3, "1331", "Facility Code Value", Optional, AlphaNumeric, 1, 2, facility_code_value),
+        (4, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount),
+        (5, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (7, "C004", "Composite Diagnosis Code Pointer", Optional, Composite, 0, 0, composite_diagnosis_code_pointer),
+    ]
+}
+
+segment_meta! {
+    SV7,
+    "Drug Adjudication",
+    "",
+    [_01, _02, _03, _04, _05, _06],
+    [
+    ]
+}
+
+segment_meta! {
+    SVC,
+    "Service Information",
+    "To supply payment and control information to a provider for a particular service",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "C003", "Composite Medical Procedure Identifier", Mandatory, Composite, 0, 0, composite_medical_procedure_identifier_01),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_02),
+        (3, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_03),
+        (4, "234", "Product/Service ID", Optional, AlphaNumeric, 1, 48, product_service_id),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity_05),
+        (6, "C003", "Composite Medical Procedure Identifier", Optional, Composite, 0, 0, composite_medical_procedure_identifier_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity_07),
+    ]
+}
+
+segment_meta! {
+    SVD,
+    "Service Line Adjudication",
+    "To convey service line adjudication information for coordination of benefits between the initial payers of a health care claim and all subsequent payers",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "67", "Identification Code", Mandatory, AlphaNumeric, 2, 80, identification_code),
+        (2, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount),
+        (3, "C003", "Composite Medical Procedure Identifier", Optional, Composite, 0, 0, composite_medical_procedure_identifier),
+        (4, "234", "Product/Service ID", Optional, AlphaNumeric, 1, 48, product_service_id),
+        (5, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (6, "554", "Assigned Number", Optional, Numeric(0), 1, 6, assigned_number),
+    ]
+}
+
+segment_meta! {
+    TED,
+    "Technical Error Description",
+    "To identify the error and, if feasible, the erroneous segment, or data element, or both",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "647", "Application Error Condition Code", Mandatory, Id, 1, 3, application_error_condition_code),
+        (2, "3", "Free Form Message", Optional, AlphaNumeric, 1, 60, free_form_message),
+        (3, "721", "Segment ID Code", Optional, Id, 2, 3, segment_id_code),
+        (4, "719", "Segment Position in Transaction Set", Optional, Numeric(0), 1, 10, segment_position_in_transaction_set),
+        (5, "C030", "Position in Segment", Optional, Composite, 0, 0, position_in_segment),
+        (6, "725", "Data Element Reference Number", Optional, Numeric(0), 1, 4, data_element_reference_number),
+        (7, "724", "Copy of Bad Data Element", Optional, AlphaNumeric, 1, 99, copy_of_bad_data_element),
+        (8, "961", "Data Element New Content", Optional, AlphaNumeric, 1, 99, data_element_new_content),
+    ]
+}
+
+segment_meta! {
+    TOO,
+    "Tooth Identification",
+    "To identify a tooth by number and, if applicable, one or more tooth surfaces",
+    [_01, _02, _03],
+    [
+        (1, "1270", "Code List Qualifier Code", Optional, Id, 1, 3, code_list_qualifier_code),
+        (2, "1271", "Industry Code", Optional, AlphaNumeric, 1, 30, industry_code),
+        (3, "C005", "Tooth Surface", Optional, Composite, 0, 0, tooth_surface),
+    ]
+}
+
+segment_meta! {
+    TRN,
+    "Trace",
+    "To uniquely identify a transaction to an application",
+    [_01, _02, _03, _04],
+    [
+        (1, "481", "Trace Type Code", Mandatory, Id, 1, 2, trace_type_code),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification_02),
+        (3, "509", "Originating Company Identifier", Optional, AlphaNumeric, 10, 10, originating_company_identifier),
+        (4, "127", "Reference Identification", Optional, AlphaNumeric, 1, 50, reference_identification_04),
+    ]
+}
+
+segment_meta! {
+    TS2,
+    "Transaction Supplemental Statistics",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19],
+    [
+    ]
+}
+
+segment_meta! {
+    TS3,
+    "Transaction Statistics",
+    "To supply provider-level control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24],
+    [
+        (1, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 50, reference_identification),
+        (2, "1331", "Facility Code Value", Mandatory, AlphaNumeric, 1, 2, facility_code_value),
+        (3, "373", "Date", Mandatory, Date, 8, 8, date),
+        (4, "380", "Quantity", Mandatory, Decimal, 1, 15, quantity_04),
+        (5, "782", "Monetary Amount", Mandatory, Decimal, 1, 18, monetary_amount_05),
+        (6, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_06),
+        (7, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_07),
+        (8, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_08),
+        (9, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_09),
+        (10, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_10),
+        (11, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_11),
+        (12, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_12),
+        (13, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_13),
+        (14, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_14),
+        (15, "380", "Quantity", Optional, Decimal, 1, 15, quantity_15),
+        (16, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_16),
+        (17, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_17),
+        (18, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_18),
+        (19, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_19),
+        (20, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_20),
+        (21, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_21),
+        (22, "380", "Quantity", Optional, Decimal, 1, 15, quantity_22),
+        (23, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_23),
+        (24, "782", "Monetary Amount", Optional, Decimal, 1, 18, monetary_amount_24),
+    ]
+}
+
+segment_meta! {
+    UM,
+    "Health Care Services Review Information",
+    "To specify health care services review information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "1525", "Request Category Code", Mandatory, Id, 1, 2, request_category_code),
+        (2, "1322", "Certification Type Code", Optional, Id, 1, 1, certification_type_code),
+        (3, "1365", "Service Type Code", Optional, Id, 1, 2, service_type_code),
+        (4, "C023", "Health Care Service Location Information", Optional, Composite, 0, 0, health_care_service_location_information),
+        (5, "C024", "Related Causes Information", Optional, Composite, 0, 0, related_causes_information),
+        (6, "1338", "Level of Service Code", Optional, Id, 1, 3, level_of_service_code),
+        (7, "1213", "Current Health Condition Code", Optional, Id, 1, 1, current_health_condition_code),
+        (8, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (9, "923", "Prognosis Code", Optional, Id, 1, 1, prognosis_code),
+        (10, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    UR,
+    "Peer Review Organization or Utilization Review",
+    "",
+    [_01, _02],
+    [
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "AAA" => Some(AAA::DESCRIPTOR),
+        "ACT" => Some(ACT::DESCRIPTOR),
+        "AD1" => Some(AD1::DESCRIPTOR),
+        "ADX" => Some(ADX::DESCRIPTOR),
+        "AIN" => Some(AIN::DESCRIPTOR),
+        "AMT" => Some(AMT::DESCRIPTOR),
+        "BEN" => Some(BEN::DESCRIPTOR),
+        "BGN" => Some(BGN::DESCRIPTOR),
+        "BHT" => Some(BHT::DESCRIPTOR),
+        "BPR" => Some(BPR::DESCRIPTOR),
+        "CAS" => Some(CAS::DESCRIPTOR),
+        "CL1" => Some(CL1::DESCRIPTOR),
+        "CLM" => Some(CLM::DESCRIPTOR),
+        "CLP" => Some(CLP::DESCRIPTOR),
+        "CN1" => Some(CN1::DESCRIPTOR),
+        "COB" => Some(COB::DESCRIPTOR),
+        "CR1" => Some(CR1::DESCRIPTOR),
+        "CR2" => Some(CR2::DESCRIPTOR),
+        "CR3" => Some(CR3::DESCRIPTOR),
+        "CR4" => Some(CR4::DESCRIPTOR),
+        "CR5" => Some(CR5::DESCRIPTOR),
+        "CR6" => Some(CR6::DESCRIPTOR),
+        "CR7" => Some(CR7::DESCRIPTOR),
+        "CR8" => Some(CR8::DESCRIPTOR),
+        "CRC" => Some(CRC::DESCRIPTOR),
+        "CTP" => Some(CTP::DESCRIPTOR),
+        "CUR" => Some(CUR::DESCRIPTOR),
+        "DMG" => Some(DMG::DESCRIPTOR),
+        "DN1" => Some(DN1::DESCRIPTOR),
+        "DN2" => Some(DN2::DESCRIPTOR),
+        "DSB" => Some(DSB::DESCRIPTOR),
+        "DTM" => Some(DTM::DESCRIPTOR),
+        "DTP" => Some(DTP::DESCRIPTOR),
+        "EB" => Some(EB::DESCRIPTOR),
+        "EC" => Some(EC::DESCRIPTOR),
+        "ENT" => Some(ENT::DESCRIPTOR),
+        "EQ" => Some(EQ::DESCRIPTOR),
+        "FC" => Some(FC::DESCRIPTOR),
+        "FRM" => Some(FRM::DESCRIPTOR),
+        "FSA" => Some(FSA::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "HCP" => Some(HCP::DESCRIPTOR),
+        "HCR" => Some(HCR::DESCRIPTOR),
+        "HD" => Some(HD::DESCRIPTOR),
+        "HI" => Some(HI::DESCRIPTOR),
+        "HL" => Some(HL::DESCRIPTOR),
+        "HLH" => Some(HLH::DESCRIPTOR),
+        "HSD" => Some(HSD::DESCRIPTOR),
+        "ICM" => Some(ICM::DESCRIPTOR),
+        "IDC" => Some(IDC::DESCRIPTOR),
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "III" => Some(III::DESCRIPTOR),
+        "IMM" => Some(IMM::DESCRIPTOR),
+        "INS" => Some(INS::DESCRIPTOR),
+        "INV" => Some(INV::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "K3" => Some(K3::DESCRIPTOR),
+        "LC" => Some(LC::DESCRIPTOR),
+        "LE" => Some(LE::DESCRIPTOR),
+        "LIN" => Some(LIN::DESCRIPTOR),
+        "LQ" => Some(LQ::DESCRIPTOR),
+        "LS" => Some(LS::DESCRIPTOR),
+        "LUI" => Some(LUI::DESCRIPTOR),
+        "LX" => Some(LX::DESCRIPTOR),
+        "MEA" => Some(MEA::DESCRIPTOR),
+        "MIA" => Some(MIA::DESCRIPTOR),
+        "MOA" => Some(MOA::DESCRIPTOR),
+        "MSG" => Some(MSG::DESCRIPTOR),
+        "N1" => Some(N1::DESCRIPTOR),
+        "N2" => Some(N2::DESCRIPTOR),
+        "N3" => Some(N3::DESCRIPTOR),
+        "N4" => Some(N4::DESCRIPTOR),
+        "NM1" => Some(NM1::DESCRIPTOR),
+        "NTE" => Some(NTE::DESCRIPTOR),
+        "NX1" => Some(NX1::DESCRIPTOR),
+        "OI" => Some(OI::DESCRIPTOR),
+        "OTI" => Some(OTI::DESCRIPTOR),
+        "PAT" => Some(PAT::DESCRIPTOR),
+        "PER" => Some(PER::DESCRIPTOR),
+        "PLA" => Some(PLA::DESCRIPTOR),
+        "PLB" => Some(PLB::DESCRIPTOR),
+        "PM" => Some(PM::DESCRIPTOR),
+        "PRV" => Some(PRV::DESCRIPTOR),
+        "PS1" => Some(PS1::DESCRIPTOR),
+        "PWK" => Some(PWK::DESCRIPTOR),
+        "QTY" => Some(QTY::DESCRIPTOR),
+        "RDM" => Some(RDM::DESCRIPTOR),
+        "RED" => Some(RED::DESCRIPTOR),
+        "REF" => Some(REF::DESCRIPTOR),
+        "REL" => Some(REL::DESCRIPTOR),
+        "RMR" => Some(RMR::DESCRIPTOR),
+        "RP" => Some(RP::DESCRIPTOR),
+        "SBR" => Some(SBR::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        "STC" => Some(STC::DESCRIPTOR),
+        "SV1" => Some(SV1::DESCRIPTOR),
+        "SV2" => Some(SV2::DESCRIPTOR),
+        "SV3" => Some(SV3::DESCRIPTOR),
+        "SV4" => Some(SV4::DESCRIPTOR),
+        "SV5" => Some(SV5::DESCRIPTOR),
+        "SV6" => Some(SV6::DESCRIPTOR),
+        "SV7" => Some(SV7::DESCRIPTOR),
+        "SVC" => Some(SVC::DESCRIPTOR),
+        "SVD" => Some(SVD::DESCRIPTOR),
+        "TED" => Some(TED::DESCRIPTOR),
+        "TOO" => Some(TOO::DESCRIPTOR),
+        "TRN" => Some(TRN::DESCRIPTOR),
+        "TS2" => Some(TS2::DESCRIPTOR),
+        "TS3" => Some(TS3::DESCRIPTOR),
+        "UM" => Some(UM::DESCRIPTOR),
+        "UR" => Some(UR::DESCRIPTOR),
+        _ => None,
+    }
+}
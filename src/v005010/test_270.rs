@@ -0,0 +1,235 @@
+use crate::util::amount::Amount;
+use crate::v005010::*;
+use chrono::NaiveDate;
+
+#[test]
+fn parse_270() {
+    let str = r#"ST*270*1234*005010X279A1~
+BHT*0022*13*10001234*20240105*1319~
+HL*1**20*1~
+NM1*PR*2*ABC COMPANY*****PI*842610001~
+HL*2*1*21*1~
+NM1*1P*2*BONE AND JOINT CLINIC*****XX*1234567893~
+PRV*PE*PXC*207X00000X~
+HL*3*2*22*1~
+TRN*1*93175-012547*9877281234~
+NM1*IL*1*SMITH*ROBERT****MI*11122333301~
+DMG*D8*19430519~
+DTP*291*D8*20240105~
+EQ*30~
+HL*4*3*23*0~
+TRN*1*93175-012548*9877281234~
+NM1*03*1*SMITH*MARY~
+DMG*D8*19450616*F~
+EQ*30~
+EQ*98~
+SE*19*1234~
+"#;
+    let (rest, obj) = _270::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.loop_2000a.len(), 1);
+    let source = &obj.loop_2000a[0];
+    assert_eq!(source.loop_2100a.nm1._03, Some("ABC COMPANY".to_string()));
+    let receiver = &source.loop_2000b[0];
+    assert!(receiver.loop_2100b.prv.is_some());
+    let subscriber = &receiver.loop_2000c[0];
+    assert_eq!(subscriber.trn.len(), 1);
+    assert_eq!(
+        subscriber.loop_2100c.loop_2110c[0].eq._01,
+        Some("30".to_string())
+    );
+    let dependent = &subscriber.loop_2000d[0];
+    assert_eq!(dependent.loop_2100d.nm1._04, Some("MARY".to_string()));
+    assert_eq!(dependent.loop_2100d.loop_2110d.len(), 2);
+    assert_eq!(format!("{obj}"), str);
+}
+
+const RESPONSE: &str = r#"ST*271*4322*005010X279A1~
+BHT*0022*11*10001234*20240105*1319~
+HL*1**20*1~
+NM1*PR*2*ABC COMPANY*****PI*842610001~
+PER*IC**TE*8005551212~
+HL*2*1*21*1~
+NM1*1P*2*BONE AND JOINT CLINIC*****XX*1234567893~
+HL*3*2*22*1~
+TRN*2*93175-012547*9877281234~
+NM1*IL*1*SMITH*ROBERT****MI*11122333301~
+N3*15197 BROADWAY AVENUE*APT 215~
+N4*KANSAS CITY*MO*64108~
+DMG*D8*19430519*M~
+DTP*346*D8*20240101~
+EB*1**30^1^33^35^47^86^88^98^AL^MH^UC*HM*GOLD 123 PLAN~
+EB*C*IND*30***23*500*****Y~
+DTP*291*D8*20240105~
+MSG*DEDUCTIBLE REMAINING 150.00~
+EB*B**98*****20****N~
+III*ZZ*21~
+LS*2120~
+NM1*P3*1*JONES*MARCUS****SV*0202034~
+PER*IC**TE*8005559876~
+LE*2120~
+HL*4*3*23*0~
+TRN*2*93175-012548*9877281234~
+NM1*03*1*SMITH*MARY~
+DMG*D8*19450616*F~
+INS*N*01~
+EB*1**30*HM*GOLD 123 PLAN~
+EB*6**30~
+AAA*N**72*C~
+SE*32*4322~
+"#;
+
+#[test]
+fn build_270() {
+    let nm1 = |entity: &str, kind: &str, name: &str, qualifier: &str, id: &str| {
+        NM1::builder()
+            .entity_identifier_code_01(entity)
+            .entity_type_qualifier(kind)
+            .name_last_or_organization_name_03(name)
+            .identification_code_qualifier(qualifier)
+            .identification_code(id)
+    };
+    let hl = |id: &str, level: &str, child: &str| {
+        HL::builder()
+            .hierarchical_id_number(id)
+            .hierarchical_level_code(level)
+            .hierarchical_child_code(child)
+    };
+    let obj = _270::builder("1234")
+        .implementation_convention_reference("005010X279A1")
+        .bht(
+            BHT::builder()
+                .hierarchical_structure_code("0022")
+                .transaction_set_purpose_code("13")
+                .reference_identification("10001234")
+                .date("20240105")
+                .time("1319"),
+        )
+        .loop_2000a(
+            _270Loop2000A::builder()
+                .hl(hl("1", "20", "1"))
+                .loop_2100a(_270Loop2100A::builder().nm1(nm1(
+                    "PR",
+                    "2",
+                    "ABC COMPANY",
+                    "PI",
+                    "842610001",
+                )))
+                .loop_2000b(
+                    _270Loop2000B::builder()
+                        .hl(hl("2", "21", "1").hierarchical_parent_id_number("1"))
+                        .loop_2100b(_270Loop2100B::builder().nm1(nm1(
+                            "1P",
+                            "2",
+                            "BONE AND JOINT CLINIC",
+                            "XX",
+                            "1234567893",
+                        )))
+                        .loop_2000c(
+                            _270Loop2000C::builder()
+                                .hl(hl("3", "22", "0").hierarchical_parent_id_number("2"))
+                                .loop_2100c(
+                                    _270Loop2100C::builder()
+                                        .nm1(nm1("IL", "1", "SMITH", "MI", "11122333301"))
+                                        .loop_2110c(
+                                            _270Loop2110C::builder()
+                                                .eq(EQ::builder().service_type_code("30")),
+                                        ),
+                                ),
+                        ),
+                ),
+        )
+        .build()
+        .unwrap();
+    let str = format!("{obj}");
+    assert!(str.starts_with("ST*270*1234*005010X279A1~\nBHT*0022*13*10001234*20240105*1319~\n"));
+    assert!(str.ends_with("EQ*30~\nSE*10*1234~\n"));
+    let (rest, parsed) = _270::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(format!("{parsed}"), str);
+}
+
+#[test]
+fn parse_271() {
+    let (rest, obj) = _271::parse(RESPONSE).unwrap();
+    assert!(rest.is_empty());
+    let source = &obj.loop_2000a[0];
+    assert_eq!(source.loop_2100a.per.len(), 1);
+    let subscriber = &source.loop_2000b[0].loop_2000c[0];
+    assert_eq!(
+        subscriber.loop_2100c.n3.as_ref().unwrap()._01,
+        "15197 BROADWAY AVENUE"
+    );
+    let eb = &subscriber.loop_2100c.loop_2110c;
+    assert_eq!(eb.len(), 3);
+    assert_eq!(eb[1].dtp.len(), 1);
+    assert_eq!(eb[1].msg.len(), 1);
+    assert_eq!(eb[2].loop_2115c.len(), 1);
+    assert!(eb[2].ls.is_some());
+    assert_eq!(eb[2].loop_2120c[0].nm1._03, Some("JONES".to_string()));
+    assert_eq!(eb[2].loop_2120c[0].per.len(), 1);
+    assert!(eb[2].le.is_some());
+    let dependent = &subscriber.loop_2000d[0];
+    assert!(dependent.loop_2100d.ins.is_some());
+    assert_eq!(
+        dependent.loop_2100d.loop_2110c[1].aaa[0]._03,
+        Some("72".to_string())
+    );
+    assert_eq!(format!("{obj}"), RESPONSE);
+}
+
+#[test]
+fn benefits_271() {
+    let (_, obj) = _271::parse(RESPONSE).unwrap();
+    let members = obj.benefits('^').unwrap();
+    assert_eq!(members.len(), 2);
+
+    let subscriber = &members[0];
+    assert!(!subscriber.dependent);
+    assert_eq!(subscriber.last_name.as_deref(), Some("SMITH"));
+    assert_eq!(subscriber.first_name.as_deref(), Some("ROBERT"));
+    assert_eq!(subscriber.member_id.as_deref(), Some("11122333301"));
+    assert_eq!(subscriber.benefits.len(), 3);
+    let coverage = &subscriber.benefits[0];
+    assert_eq!(coverage.code, "1");
+    assert_eq!(coverage.service_types.len(), 11);
+    assert_eq!(coverage.service_types[10], "UC");
+    assert_eq!(coverage.plan_description.as_deref(), Some("GOLD 123 PLAN"));
+    let deductible = &subscriber.benefits[1];
+    assert_eq!(deductible.coverage_level.as_deref(), Some("IND"));
+    assert_eq!(deductible.time_period.as_deref(), Some("23"));
+    assert_eq!(deductible.amount, Some(Amount::from_cents(50000)));
+    assert_eq!(deductible.in_network.as_deref(), Some("Y"));
+    assert_eq!(
+        deductible.dates,
+        vec![_271Date {
+            qualifier: "291".to_string(),
+            from: NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+            to: None,
+        }]
+    );
+    assert_eq!(deductible.messages, vec!["DEDUCTIBLE REMAINING 150.00"]);
+    assert_eq!(subscriber.benefits[2].percent, Some(20.0));
+
+    let dependent = &members[1];
+    assert!(dependent.dependent);
+    assert_eq!(dependent.first_name.as_deref(), Some("MARY"));
+    assert_eq!(dependent.member_id, None);
+    assert_eq!(dependent.benefits[1].code, "6");
+}
+
+#[test]
+fn benefits_271_repetition_separator_from_isa11() {
+    let str = format!(
+        "ISA*00*          *00*          *ZZ*PAYER          *ZZ*PROVIDER       *240105*1200*!*00501*000000905*0*P*:~\n\
+         GS*HB*PAYER*PROVIDER*20240105*1200*905*X*005010X279A1~\n\
+         {}GE*1*905~\n\
+         IEA*1*000000905~\n",
+        RESPONSE.replace('^', "!")
+    );
+    let (rest, obj) = Transmission::<_271>::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    let members = obj.benefits().unwrap();
+    assert_eq!(members[0].benefits[0].service_types.len(), 11);
+    assert_eq!(members[0].benefits[0].service_types[1], "1");
+}
@@ -0,0 +1,136 @@
+use crate::util::xml;
+use crate::v005010::*;
+
+const ACKNOWLEDGMENT: &str = r#"ST*277*0001*005010X214~
+BHT*0085*08*277X214*20240312*1045*TH~
+HL*1**20*1~
+NM1*PR*2*ABC INSURANCE*****PI*12345~
+TRN*1*200403120001~
+DTP*050*D8*20240311~
+DTP*009*D8*20240312~
+HL*2*1*21*1~
+NM1*41*2*BILLING SERVICE*****46*S00001~
+TRN*2*000000055~
+STC*A1:20*20240312*WQ*360.01~
+QTY*90*2~
+QTY*AA*1~
+AMT*YU*210.01~
+AMT*YY*150~
+HL*3*2*19*1~
+NM1*85*2*BILLING NAME ABC*****XX*1111111111~
+HL*4*3*PT~
+NM1*QC*1*LASTNAME*FIRST****MI*111111111~
+TRN*2*A100~
+STC*A2:20:PR*20240312*WQ*210.01~
+REF*1K*2024071000001~
+DTP*472*RD8*20240301-20240301~
+HL*5*3*PT~
+NM1*QC*1*OTHER*PATIENT****MI*222222222~
+TRN*2*A200~
+STC*A7:562:85*20240312*U*150~
+DTP*472*RD8*20240302-20240302~
+SVC*HC:99213*150****1~
+STC*A7:453*20240312*U*150~
+REF*FJ*1~
+HL*6*3*PT~
+NM1*QC*1*UNKNOWN*PATIENT****MI*333333333~
+TRN*2*A999~
+STC*A1:20*20240312*WQ*10~
+SE*35*0001~
+"#;
+
+const SUBMISSION: &str = r#"ST*837*000000055*005010X222A1~
+BHT*0019*00*0123*20240311*1023*CH~
+NM1*41*2*BILLING SERVICE*****46*S00001~
+PER*IC*JOHN DOE*TE*5555551234~
+NM1*40*2*ABC INSURANCE*****46*12345~
+HL*1**20*1~
+NM1*85*2*BILLING NAME ABC*****XX*1111111111~
+N3*11111 NO NAME ROAD~
+N4*HOUSTON*TX*770744336~
+REF*EI*111111111~
+HL*2*1*22*0~
+SBR*P*18*******CI~
+NM1*IL*1*LASTNAME*FIRST****MI*111111111~
+NM1*PR*2*ABC INSURANCE*****PI*12345~
+CLM*A100*210.01***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99392*210.01*UN*1***1~
+DTP*472*D8*20240301~
+CLM*A200*150***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99213*150*UN*1***1~
+DTP*472*D8*20240302~
+CLM*A300*75***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99212*75*UN*1***1~
+DTP*472*D8*20240303~
+SE*30*000000055~
+"#;
+
+#[test]
+fn parse_277ca() {
+    let (rest, obj) = _277CA::parse(ACKNOWLEDGMENT).unwrap();
+    assert!(rest.is_empty());
+    let source = &obj.loop_2000a[0];
+    assert_eq!(source.loop_2200a.dtp.len(), 2);
+    let receiver = &source.loop_2000b[0];
+    assert_eq!(receiver.loop_2200b.qty.len(), 2);
+    let provider = &receiver.loop_2000c[0];
+    assert!(provider.loop_2200c.is_none());
+    assert_eq!(provider.loop_2000d.len(), 3);
+    let statuses: Vec<_> = obj.claim_statuses().collect();
+    assert_eq!(statuses.len(), 3);
+    assert_eq!(statuses[0].patient_control_number(), "A100");
+    assert!(!statuses[0].is_rejected());
+    assert!(statuses[1].is_rejected());
+    assert_eq!(statuses[1].loop_2220d[0].svc._01, "HC:99213");
+    assert_eq!(format!("{obj}"), ACKNOWLEDGMENT);
+}
+
+#[test]
+fn reject_277ca_on_stc11() {
+    // the third status composite alone rejects the claim
+    let str = ACKNOWLEDGMENT.replace(
+        "STC*A1:20*20240312*WQ*10~",
+        "STC*A1:20*20240312*WQ*10******A1:21*A7:562:85~",
+    );
+    let (rest, obj) = _277CA::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    let status = obj.claim_statuses().nth(2).unwrap();
+    assert_eq!(status.stc[0]._11.as_deref(), Some("A7:562:85"));
+    assert!(status.is_rejected());
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn match_277ca_to_837() {
+    let (_, ack) = _277CA::parse(ACKNOWLEDGMENT).unwrap();
+    let (rest, submission) = _837::parse(SUBMISSION).unwrap();
+    assert!(rest.is_empty());
+    let report = ack.match_claims(&submission);
+
+    assert_eq!(report.accepted.len(), 2);
+    assert_eq!(report.accepted[0].claim.unwrap().clm._01, "A100");
+    assert!(report.accepted[1].claim.is_none());
+    assert_eq!(report.accepted[1].status.patient_control_number(), "A999");
+
+    assert_eq!(report.rejected.len(), 1);
+    assert_eq!(report.rejected[0].claim.unwrap().clm._01, "A200");
+
+    assert_eq!(report.unacknowledged.len(), 1);
+    assert_eq!(report.unacknowledged[0].clm._01, "A300");
+}
+
+#[test]
+fn xml_277ca() {
+    let (_, obj) = _277CA::parse(ACKNOWLEDGMENT).unwrap();
+    let x = xml::to_string(&obj).unwrap();
+    assert!(x.starts_with("<TransactionSet>\n  <ST>\n    <ST01>277</ST01>"));
+    assert!(x.contains("<Loop2000D>"));
+    let back: _277CA = xml::from_str(&x).unwrap();
+    assert_eq!(back, obj);
+}
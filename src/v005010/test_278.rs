@@ -0,0 +1,96 @@
+use crate::v005010::*;
+
+#[test]
+fn parse_278_request() {
+    let str = r#"ST*278*0001*005010X217~
+BHT*0007*13*REF47517*20240501*1319~
+HL*1**20*1~
+NM1*X3*2*ABC HEALTH PLAN*****PI*12345~
+HL*2*1*21*1~
+NM1*1P*1*WATSON*SUSAN****XX*1234567890~
+N3*123 MAIN STREET~
+N4*ANYTOWN*PA*17111~
+PER*IC*SUSAN WATSON*TE*7177771234~
+PRV*PE*PXC*207Q00000X~
+HL*3*2*22*1~
+NM1*IL*1*SMITH*JOE****MI*12345678901~
+DMG*D8*19430917*M~
+HL*4*3*EV*1~
+TRN*1*111099*9012345678~
+UM*HS*I*2*11:B~
+DTP*472*RD8*20240601-20240630~
+HI*ABK:J45909~
+HSD*VS*1~
+CRC*ZF*Y*AL~
+CR6*4*20240601~
+MSG*PATIENT REQUIRES DAILY NEBULIZER~
+NM1*71*1*JONES*MARCUS****XX*1234567891~
+PRV*AT*PXC*207RP1001X~
+HL*5*4*SS*0~
+TRN*1*111100*9012345678~
+UM*HS*I*1~
+SV1*HC:94640*40*UN*30~
+SE*29*0001~
+"#;
+    let (rest, obj) = _278::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert!(!obj.is_response());
+    let umo = &obj.loop_2000a[0];
+    assert_eq!(umo.loop_2010a.nm1._01, "X3");
+    let requester = &umo.loop_2000b[0];
+    assert_eq!(
+        requester.loop_2010b.prv.as_ref().unwrap()._03,
+        Some("207Q00000X".to_string())
+    );
+    let subscriber = &requester.loop_2000c[0];
+    assert!(subscriber.loop_2000d.is_empty());
+    let event = &subscriber.loop_2000e[0];
+    assert_eq!(event.um._01, "HS");
+    assert_eq!(event.hi.as_ref().unwrap()._01, "ABK:J45909");
+    assert_eq!(event.crc.len(), 1);
+    assert!(event.cr6.is_some());
+    assert_eq!(event.loop_2010e.len(), 1);
+    let service = &event.loop_2000f[0];
+    assert_eq!(service.sv1.as_ref().unwrap()._01, "HC:94640".to_string());
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_278_response() {
+    let str = r#"ST*278*0002*005010X217~
+BHT*0007*11*REF47517*20240502*0815~
+HL*1**20*1~
+NM1*X3*2*ABC HEALTH PLAN*****PI*12345~
+HL*2*1*21*1~
+NM1*1P*1*WATSON*SUSAN****XX*1234567890~
+HL*3*2*22*1~
+NM1*IL*1*SMITH*JOE****MI*12345678901~
+HL*4*3*23*1~
+NM1*QC*1*SMITH*ANNA~
+DMG*D8*19450616*F~
+HL*5*4*EV*0~
+TRN*2*111099*9012345678~
+UM*HS*I*2*11:B~
+HCR*A1*AUTH0001~
+REF*NT*AUTH0001~
+DTP*472*RD8*20240601-20240630~
+SE*17*0002~
+"#;
+    let (rest, obj) = _278::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert!(obj.is_response());
+    let subscriber = &obj.loop_2000a[0].loop_2000b[0].loop_2000c[0];
+    assert!(subscriber.loop_2000e.is_empty());
+    let dependent = &subscriber.loop_2000d[0];
+    assert_eq!(dependent.loop_2010d.nm1._04, Some("ANNA".to_string()));
+    let hcr = dependent.loop_2000e[0].hcr.as_ref().unwrap();
+    assert_eq!(hcr._01, "A1");
+    assert_eq!(hcr._02, Some("AUTH0001".to_string()));
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn parse_278_wrong_set() {
+    let str = "ST*270*0001*005010X279A1~\nBHT*0022*13*1*20240105*1319~\nSE*2*0001~\n";
+    assert!(matches!(_278::parse(str), Err(nom::Err::Failure(_))));
+}
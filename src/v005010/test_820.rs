@@ -0,0 +1,77 @@
+use crate::util::amount::Amount;
+use crate::v005010::*;
+
+#[test]
+fn parse_820() {
+    let str = r#"ST*820*0001*005010X218~
+BPR*I*2390.5*C*ACH*CCP*01*199999999*DA*98765*1512345678**01*199999999*DA*12345*20240105~
+TRN*3*78905*1512345678~
+REF*38*123456789~
+DTM*582****RD8*20240101-20240131~
+N1*PE*HEALTH PLAN INC*FI*999999999~
+N1*PR*EMPLOYER GROUP*FI*888888888~
+PER*IC*BENEFITS OFFICE*TE*8005551212~
+ENT*1*2J*EI*888888888~
+RMR*11*GRP0001**1500.00~
+REF*38*123456789~
+DTM*582****RD8*20240101-20240131~
+ENT*2*2J*34*111223333~
+NM1*IL*1*DOE*JOHN****34*111223333~
+RMR*AZ*111223333**450.50~
+DTM*582****RD8*20240101-20240131~
+ENT*3*2J*34*444556666~
+NM1*IL*1*ROE*JANE****34*444556666~
+RMR*AZ*444556666**460.00~
+ADX*-20.00*52~
+SE*21*0001~
+"#;
+    let (rest, obj) = _820::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.st._03, Some("005010X218".to_string()));
+    assert_eq!(obj.trn._02, "78905");
+    assert_eq!(obj.loop_1000.len(), 2);
+    assert_eq!(obj.loop_1000[1].per.len(), 1);
+    assert_eq!(obj.loop_2000.len(), 3);
+    assert!(obj.loop_2000[0].loop_2100.is_none());
+    assert_eq!(obj.loop_2000[0].loop_2300[0].r#ref.len(), 1);
+    let member = obj.loop_2000[1].loop_2100.as_ref().unwrap();
+    assert_eq!(member.nm1._03, Some("DOE".to_string()));
+    assert_eq!(obj.loop_2000[2].loop_2300[0].loop_2320[0].adx._02, "52");
+    assert_eq!(format!("{obj}"), str);
+
+    assert_eq!(
+        obj.remittance_totals().unwrap(),
+        vec![
+            Amount::from_cents(150000),
+            Amount::from_cents(45050),
+            Amount::from_cents(44000)
+        ]
+    );
+    let balance = obj.check_remittance().unwrap();
+    assert!(balance.is_balanced());
+    assert_eq!(balance.expected, Amount::from_cents(239050));
+}
+
+#[test]
+fn check_remittance_820_summary_adjustment() {
+    let str = r#"ST*820*0001*005010X218~
+BPR*I*1475*C*ACH*CCP*01*199999999*DA*98765*1512345678**01*199999999*DA*12345*20240105~
+TRN*3*78905*1512345678~
+N1*PE*HEALTH PLAN INC*FI*999999999~
+N1*PR*EMPLOYER GROUP*FI*888888888~
+ENT*1*2J*EI*888888888~
+ADX*-25.00*52~
+RMR*11*GRP0001**1500.00~
+SE*8*0001~
+"#;
+    let (rest, mut obj) = _820::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.loop_2000[0].loop_2200[0].adx._01, "-25.00");
+    assert_eq!(format!("{obj}"), str);
+    assert!(obj.check_remittance().unwrap().is_balanced());
+
+    obj.loop_2000[0].loop_2200.clear();
+    let balance = obj.check_remittance().unwrap();
+    assert!(!balance.is_balanced());
+    assert_eq!(balance.difference().to_string(), "-25.00");
+}
@@ -0,0 +1,48 @@
+use crate::v005010::*;
+
+#[test]
+fn test_824() {
+    let str = include_str!("../../test-data/005010_824.edi");
+    let (rest, obj) = Transmission::<_824>::parse(str).unwrap();
+    assert!(rest.trim().is_empty());
+    let advice = &obj.functional_group[0].segments[0];
+    assert_eq!(advice.st._03, Some("005010X186A1".to_string()));
+    assert_eq!(advice.bgn._02, "FFA.ABCDEF.123456");
+    assert_eq!(advice.loop_1000.len(), 2);
+    assert_eq!(advice.loop_1000[0].per.len(), 1);
+    assert_eq!(advice.loop_2000.len(), 1);
+    let oti = &advice.loop_2000[0].oti;
+    assert_eq!(oti._01, "TA");
+    assert_eq!(oti._10, Some("834".to_string()));
+    assert_eq!(oti._11, Some("005010X220A1".to_string()));
+    let s = format!("{obj}");
+    assert_eq!(s.replace('\n', ""), str.replace(['\n', '\r'], ""));
+}
+
+#[test]
+fn test_824_errors() {
+    let str = r#"ST*824*0001*005010X186A1~
+BGN*11*REJ0001*20240110*1030~
+N1*41*ABC INSURANCE*46*111111111~
+OTI*TR*TN*0001*****1*0001*834*005010X220A1~
+REF*0F*123456789~
+NM1*IL*1*DOE*JOHN****34*111223333~
+TED*024*INVALID COVERAGE DATE*DTP*23**373*20241301~
+NTE*ADD*DATE OUTSIDE PLAN YEAR~
+RED*COVERAGE START DATE*ZZ*348~
+TED*006*MISSING MEMBER ID*REF*12~
+SE*11*0001~
+"#;
+    let (rest, obj) = _824::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let oti = &obj.loop_2000[0];
+    assert_eq!(oti.oti._01, "TR");
+    assert_eq!(oti.r#ref.len(), 1);
+    assert_eq!(oti.nm1.len(), 1);
+    assert_eq!(oti.loop_2100.len(), 2);
+    assert_eq!(oti.loop_2100[0].ted._07, Some("20241301".to_string()));
+    assert_eq!(oti.loop_2100[0].nte.len(), 1);
+    assert_eq!(oti.loop_2100[0].red[0]._03, Some("348".to_string()));
+    assert_eq!(oti.loop_2100[1].ted._01, "006");
+    assert_eq!(format!("{obj}"), str);
+}
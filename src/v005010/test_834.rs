@@ -1,4 +1,5 @@
 use super::*;
+use chrono::NaiveDate;
 
 #[test]
 fn parse_834() {
@@ -1121,3 +1122,332 @@ IEA*1*000000001~"#;
     println!("{rest}");
     println!("{obj:?}");
 }
+
+const PREVIOUS_FULL_FILE: &str = r#"ST*834*0001*005010X220A1~
+BGN*00*FULL1*20240101*1200****4~
+N1*P5**FI*999888777~
+N1*IN**FI*654456654~
+INS*Y*18*030*XN*A***FT~
+REF*0F*111111111~
+NM1*IL*1*DOE*JOHN****34*111111111~
+N3*100 MARKET ST~
+N4*CAMP HILL*PA*17011~
+DMG*D8*19800816*M~
+HD*030**HLT~
+DTP*348*D8*20230101~
+HD*030**DEN~
+DTP*348*D8*20230101~
+INS*N*01*030*XN*A~
+REF*0F*111111111~
+NM1*IL*1*DOE*JANE~
+DMG*D8*19820102*F~
+HD*030**HLT~
+DTP*348*D8*20230101~
+INS*Y*18*030*XN*A***FT~
+REF*0F*222222222~
+NM1*IL*1*SMITH*BOB****34*222222222~
+HD*030**HLT~
+DTP*348*D8*20230101~
+INS*Y*18*030*XN*A***FT~
+REF*0F*333333333~
+NM1*IL*1*JONES*ALICE****34*333333333~
+HD*030**HLT~
+DTP*348*D8*20230101~
+DTP*349*D8*20231231~
+INS*Y*18*030*XN*A***FT~
+REF*0F*555555555~
+NM1*IL*1*BROWN*DAVE****34*555555555~
+N3*1 OLD ROAD~
+HD*030**HLT~
+DTP*348*D8*20230101~
+SE*38*0001~
+"#;
+
+const CURRENT_FULL_FILE: &str = r#"ST*834*0002*005010X220A1~
+BGN*00*FULL2*20240201*1200****4~
+N1*P5**FI*999888777~
+N1*IN**FI*654456654~
+INS*Y*18*030*XN*A***FT~
+REF*0F*111111111~
+NM1*IL*1*DOE*JOHN****34*111111111~
+N3*100 MARKET ST~
+N4*CAMP HILL*PA*17011~
+DMG*D8*19800816*M~
+HD*030**HLT~
+DTP*348*D8*20230101~
+INS*N*01*030*XN*A~
+REF*0F*111111111~
+NM1*IL*1*DOE*JANE~
+DMG*D8*19820102*F~
+HD*030**HLT~
+DTP*348*D8*20230101~
+INS*Y*18*030*XN*A***FT~
+REF*0F*333333333~
+NM1*IL*1*JONES*ALICE****34*333333333~
+HD*030**HLT~
+DTP*348*D8*20240201~
+INS*Y*18*030*XN*A***FT~
+REF*0F*444444444~
+NM1*IL*1*WHITE*CAROL****34*444444444~
+HD*030**HLT~
+DTP*348*D8*20240201~
+INS*Y*18*030*XN*A***FT~
+REF*0F*555555555~
+NM1*IL*1*BROWN*DAVE****34*555555555~
+N3*2 NEW ROAD~
+HD*030**HLT~
+DTP*348*D8*20230101~
+SE*35*0002~
+"#;
+
+#[test]
+fn diff_834() {
+    let (_, previous) = _834::parse(PREVIOUS_FULL_FILE).unwrap();
+    let (_, current) = _834::parse(CURRENT_FULL_FILE).unwrap();
+    assert!(previous.diff(&previous).is_empty());
+
+    let changes = previous.diff(&current);
+    let members: Vec<(&str, _834Maintenance, &str)> = changes
+        .members
+        .iter()
+        .map(|m| (m.key.member.as_str(), m.maintenance, m.reason))
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            ("111111111", _834Maintenance::Change, "22"),
+            ("333333333", _834Maintenance::Reinstatement, "41"),
+            ("444444444", _834Maintenance::Addition, "28"),
+            ("555555555", _834Maintenance::Change, "25"),
+            ("222222222", _834Maintenance::Termination, "07"),
+        ]
+    );
+    let john = &changes.members[0];
+    assert_eq!(john.coverages.len(), 1);
+    assert_eq!(john.coverages[0].maintenance, _834Maintenance::Termination);
+    assert_eq!(john.coverages[0].key.insurance_line.as_deref(), Some("DEN"));
+    assert!(changes.members[3].coverages.is_empty());
+    assert_eq!(changes.with(_834Maintenance::Change).count(), 2);
+}
+
+#[test]
+fn diff_834_to_change_file() {
+    let (_, previous) = _834::parse(PREVIOUS_FULL_FILE).unwrap();
+    let (_, current) = _834::parse(CURRENT_FULL_FILE).unwrap();
+    let change_file = previous.diff(&current).to_834();
+    assert_eq!(
+        change_file.to_string(),
+        r#"ST*834*0002*005010X220A1~
+BGN*00*FULL2*20240201*1200****2~
+N1*P5**FI*999888777~
+N1*IN**FI*654456654~
+INS*Y*18*001*22*A***FT~
+REF*0F*111111111~
+NM1*IL*1*DOE*JOHN****34*111111111~
+N3*100 MARKET ST~
+N4*CAMP HILL*PA*17011~
+DMG*D8*19800816*M~
+HD*024**DEN~
+DTP*348*D8*20230101~
+DTP*349*D8*20240201~
+INS*Y*18*025*41*A***FT~
+REF*0F*333333333~
+NM1*IL*1*JONES*ALICE****34*333333333~
+HD*025**HLT~
+DTP*348*D8*20240201~
+INS*Y*18*021*28*A***FT~
+REF*0F*444444444~
+NM1*IL*1*WHITE*CAROL****34*444444444~
+HD*021**HLT~
+DTP*348*D8*20240201~
+INS*Y*18*001*25*A***FT~
+REF*0F*555555555~
+NM1*IL*1*BROWN*DAVE****34*555555555~
+N3*2 NEW ROAD~
+INS*Y*18*024*07*A***FT~
+REF*0F*222222222~
+NM1*IL*1*SMITH*BOB****34*222222222~
+HD*024**HLT~
+DTP*348*D8*20230101~
+DTP*349*D8*20240201~
+SE*34*0002~
+"#
+    );
+    let rendered = change_file.to_string();
+    let (rest, reparsed) = _834::parse(&rendered).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(reparsed, change_file);
+}
+
+#[test]
+fn diff_834_benefit_end_date() {
+    let (_, current) = _834::parse(CURRENT_FULL_FILE).unwrap();
+    // Dave's coverage ends after the date of the file (BGN03 = 20240201)
+    let mut ending = current.clone();
+    ending.loop_2000[4].loop_2300[0].dtp.push(DTP {
+        _01: "349".to_string(),
+        _02: "D8".to_string(),
+        _03: "20240630".to_string(),
+    });
+    let changes = current.diff(&ending);
+    assert_eq!(changes.members.len(), 1);
+    let dave = &changes.members[0];
+    assert_eq!(dave.key.member, "555555555");
+    assert_eq!(
+        (dave.maintenance, dave.reason),
+        (_834Maintenance::Change, "22")
+    );
+    assert_eq!(dave.coverages[0].maintenance, _834Maintenance::Change);
+
+    ending.loop_2000[4].loop_2300[0].dtp[1]._03 = "20240131".to_string();
+    let changes = current.diff(&ending);
+    let dave = &changes.members[0];
+    assert_eq!(
+        (dave.maintenance, dave.reason),
+        (_834Maintenance::Termination, "07")
+    );
+    assert_eq!(dave.coverages[0].maintenance, _834Maintenance::Termination);
+    // the coverage keeps its own end date in the change file
+    let rendered = changes.to_834().to_string();
+    assert!(rendered.contains("HD*024**HLT~\nDTP*348*D8*20230101~\nDTP*349*D8*20240131~\nSE*"));
+
+    // a range ends with its last date, not its first
+    ending.loop_2000[4].loop_2300[0].dtp[1] = DTP {
+        _01: "349".to_string(),
+        _02: "RD8".to_string(),
+        _03: "20240101-20241231".to_string(),
+    };
+    let changes = current.diff(&ending);
+    assert_eq!(changes.members[0].maintenance, _834Maintenance::Change);
+    ending.loop_2000[4].loop_2300[0].dtp[1]._03 = "20230101-20240131".to_string();
+    let changes = current.diff(&ending);
+    assert_eq!(changes.members[0].maintenance, _834Maintenance::Termination);
+}
+
+const MEMBERS: &str = r#"ST*834*0003*005010X220A1~
+BGN*00*FULL3*20240301*1200****4~
+N1*P5**FI*999888777~
+N1*IN**FI*654456654~
+INS*Y*18*030*XN*A***FT~
+REF*0F*111111111~
+REF*1L*GRP001~
+DTP*356*D8*20230101~
+NM1*IL*1*DOE*JOHN*P***34*111111111~
+N3*100 MARKET ST*APT 3G~
+N4*CAMP HILL*PA*17011~
+DMG*D8*19800816*M~
+HD*030**HLT**FAM~
+DTP*348*D8*20230101~
+DTP*349*D8*20241231~
+LX*1~
+NM1*P3*1*JONES*MARCUS****XX*1234567890*25~
+COB*P*890111*5~
+NM1*IN*2*OTHER INSURANCE~
+INS*N*19*030*XN*A~
+REF*0F*111111111~
+NM1*IL*1*DOE*JIMMY~
+DMG*D8*20100315*M~
+HD*030**HLT**FAM~
+DTP*348*D8*20230101~
+SE*26*0003~
+"#;
+
+#[test]
+fn members_834() {
+    let (rest, tx) = _834::parse(MEMBERS).unwrap();
+    assert!(rest.is_empty());
+    let members = tx.members().unwrap();
+    assert_eq!(members.len(), 2);
+
+    let john = &members[0];
+    assert!(john.subscriber);
+    assert_eq!(john.relationship, "18");
+    assert_eq!(john.subscriber_id(), Some("111111111"));
+    assert_eq!(john.reference("1L"), Some("GRP001"));
+    assert_eq!(john.ssn(), Some("111111111"));
+    assert_eq!(john.dates[0].qualifier, "356");
+    assert_eq!(john.birth_date, NaiveDate::from_ymd_opt(1980, 8, 16));
+    assert_eq!(john.address_2.as_deref(), Some("APT 3G"));
+    let health = &john.coverages[0];
+    assert_eq!(health.insurance_line.as_deref(), Some("HLT"));
+    assert_eq!(health.coverage_level.as_deref(), Some("FAM"));
+    assert_eq!(health.effective, NaiveDate::from_ymd_opt(2023, 1, 1));
+    assert_eq!(health.termination, NaiveDate::from_ymd_opt(2024, 12, 31));
+    let pcp: Vec<&_834Provider> = health.pcps().collect();
+    assert_eq!(pcp.len(), 1);
+    assert_eq!(pcp[0].id.as_deref(), Some("1234567890"));
+    assert_eq!(
+        health.coordination_of_benefits[0].insurers[0]
+            .name
+            .as_deref(),
+        Some("OTHER INSURANCE")
+    );
+
+    let jimmy = &members[1];
+    assert!(jimmy.is_dependent());
+    assert_eq!(jimmy.relationship, "19");
+    assert_eq!(jimmy.ssn(), None);
+    assert_eq!(jimmy.subscriber_id(), Some("111111111"));
+    assert_eq!(jimmy.coverages[0].termination, None);
+}
+
+#[test]
+fn members_834_build_loops() {
+    let (_, tx) = _834::parse(MEMBERS).unwrap();
+    let mut members = tx.members().unwrap();
+    let mut rebuilt = tx.clone();
+    rebuilt.set_members(&members);
+    assert_eq!(rebuilt.to_string(), MEMBERS);
+
+    members[1].coverages[0].termination = NaiveDate::from_ymd_opt(2024, 3, 31);
+    rebuilt.set_members(&members);
+    let rendered = rebuilt.to_string();
+    assert!(rendered.ends_with("DTP*348*D8*20230101~\nDTP*349*D8*20240331~\nSE*27*0003~\n"));
+    let (_, reparsed) = _834::parse(&rendered).unwrap();
+    assert_eq!(reparsed.members().unwrap(), members);
+}
+
+#[test]
+fn members_834_keep_unmodelled() {
+    let (_, mut tx) = _834::parse(MEMBERS).unwrap();
+    let john = &mut tx.loop_2000[0];
+    john.r#ref[1]._03 = Some("GROUP ONE".to_string());
+    john.loop_2100[0].nm1.as_mut().unwrap()._02 = "2".to_string();
+    // 2100C member mailing address
+    john.loop_2100.push(_834Loop2100 {
+        nm1: Some(NM1 {
+            _01: "31".to_string(),
+            _02: "1".to_string(),
+            ..Default::default()
+        }),
+        n3: Some(N3 {
+            _01: "PO BOX 12".to_string(),
+            _02: None,
+        }),
+        ..Default::default()
+    });
+    john.loop_2300[0].amt.push(AMT {
+        _01: "P3".to_string(),
+        _02: "150".to_string(),
+        _03: None,
+    });
+    let mut members = tx.members().unwrap();
+    assert_eq!(members[0].entity_type, "2");
+    members[0].last_name = Some("DOE-SMITH".to_string());
+    members[0].coverages[0].plan = Some("GOLD".to_string());
+    members[0].coverages[0].providers[0].name = Some("JONES-LEE".to_string());
+    tx.set_members(&members);
+
+    let rendered = tx.to_string();
+    assert!(rendered.contains("REF*1L*GRP001*GROUP ONE~\n"));
+    assert!(rendered.contains("NM1*IL*2*DOE-SMITH*JOHN*P***34*111111111~\n"));
+    assert!(rendered.contains("NM1*31*1~\nN3*PO BOX 12~\n"));
+    assert!(rendered.contains(
+        "HD*030**HLT*GOLD*FAM~\nDTP*348*D8*20230101~\nDTP*349*D8*20241231~\nAMT*P3*150~\n"
+    ));
+    assert!(rendered.contains("NM1*P3*1*JONES-LEE*MARCUS****XX*1234567890*25~\n"));
+    let (rest, reparsed) = _834::parse(&rendered).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(reparsed.members().unwrap(), members);
+    assert_eq!(reparsed.loop_2000[0].loop_2300[0].amt.len(), 1);
+}
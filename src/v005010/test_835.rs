@@ -104,4 +104,226 @@ IEA*1*004075123~"#;
     println!("{rest}");
     println!("{obj:?}");
     assert!(rest.is_empty());
+    let remittance = &obj.functional_group[0].segments[0];
+    assert_eq!(remittance.check_balance().unwrap(), vec![]);
+}
+
+#[test]
+fn check_balance_835() {
+    let str = r#"ST*835*0001~
+BPR*I*150*C*ACH*CCP*01*999999992*DA*123456*1512345678**01*999988880*DA*98765*20240315~
+TRN*1*12345*1512345678~
+N1*PR*INSURANCE COMPANY~
+N1*PE*PROVIDER*XX*1234567893~
+LX*1~
+CLP*A100*1*200*120**12*PAYERCLAIM1~
+CAS*PR*1*30~
+SVC*HC:99213*120*100**1~
+CAS*CO*45*20~
+SVC*HC:85025*80*50**1~
+CAS*CO*45*20*1*253*5~
+CLP*A200*1*100*60**12*PAYERCLAIM2~
+CAS*CO*45*30~
+SE*14*0001~
+"#;
+    let (rest, mut obj) = _835::parse(str).unwrap();
+    assert!(rest.is_empty());
+    // claim A100: 200 - 30 - 20 - 25 = 125, line 2: 80 - 25 = 55,
+    // lines: 150 - 30 = 120; A200: 100 - 30 = 70; total 180
+    let imbalances = obj.check_balance().unwrap();
+    let checks: Vec<(&_835BalanceCheck, String, String)> = imbalances
+        .iter()
+        .map(|i| {
+            (
+                &i.check,
+                i.balance.expected.to_string(),
+                i.balance.actual.to_string(),
+            )
+        })
+        .collect();
+    let claim = |id: &str, j| _835BalanceCheck::Claim {
+        loop_2000: 0,
+        loop_2100: j,
+        claim_id: id.to_string(),
+    };
+    assert_eq!(
+        checks,
+        vec![
+            (
+                &_835BalanceCheck::ServiceLine {
+                    loop_2000: 0,
+                    loop_2100: 0,
+                    loop_2110: 1,
+                    claim_id: "A100".to_string(),
+                },
+                "50.00".to_string(),
+                "55.00".to_string()
+            ),
+            (
+                &claim("A100", 0),
+                "120.00".to_string(),
+                "125.00".to_string()
+            ),
+            (&claim("A200", 1), "60.00".to_string(), "70.00".to_string()),
+            (
+                &_835BalanceCheck::Transaction,
+                "150.00".to_string(),
+                "180.00".to_string()
+            ),
+        ]
+    );
+
+    // fix the data, a provider level adjustment of 30 explains BPR02
+    let a100 = &mut obj.loop_2000[0].loop_2100[0];
+    a100.loop_2110[1].svc._03 = Some("55".to_string());
+    a100.clp._04 = "125".to_string();
+    obj.loop_2000[0].loop_2100[1].clp._04 = "70".to_string();
+    obj.bpr._02 = "165".to_string();
+    obj.plb.push(PLB {
+        _01: "1234567893".to_string(),
+        _02: "20241231".to_string(),
+        _03: Some("WO:INV1".to_string()),
+        _04: Some("30".to_string()),
+        ..Default::default()
+    });
+    assert_eq!(obj.check_balance().unwrap(), vec![]);
+}
+
+#[test]
+fn named_json_835() {
+    use crate::util::named::{self, Named};
+    let str = r#"ST*835*07504123~
+BPR*H*5.75*C*NON************20110315~
+TRN*1*A04B001017.07504*1346000128~
+N1*PR*ASHTABULA COUNTY ADAMH BD*XX*6457839886~
+N1*PE*LAKE AREA RECOVERY CENTER *FI*346608640~
+LX*1~
+CLP*444444*1*56.70*56.52*0*MC*0000000655555555*53~
+NM1*QC*1*FUDD*ELMER*S***MI*1333333~
+SVC*HC:H0005:HF:H9*56.70*56.52**6~
+CAS*CO*42*0.18*0~
+SE*10*07504123~
+"#;
+    let (_, obj) = _835::parse(str).unwrap();
+    let json = serde_json::to_string(&Named::new(&obj, segment_descriptor)).unwrap();
+    assert!(json.contains(r#""CLP01_ClaimSubmittersIdentifier":"444444""#));
+    assert!(json.contains(r#""CAS03_MonetaryAmount":"0.18""#));
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let back: _835 = named::deserialize(&mut de).unwrap();
+    assert_eq!(back, obj);
+    // plain positional keys are accepted as well
+    let json = serde_json::to_string(&obj).unwrap();
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let back: _835 = named::deserialize(&mut de).unwrap();
+    assert_eq!(back, obj);
+}
+
+#[test]
+fn xml_835() {
+    use crate::util::xml;
+    let str = r#"ISA*01*0000000000*01*0000000000*ZZ*ABCDEFGHIJKLMNO*ZZ*123456789012345*101127*1719*U*00400*000003438*0*P*>~
+GS*HP*ABCCOM*01017*20110315*1005*1*X*004010X091A1~
+ST*835*07504123~
+BPR*H*5.75*C*NON************20110315~
+TRN*1*A04B001017.07504*1346000128~
+N1*PR*ASHTABULA COUNTY ADAMH BD*XX*6457839886~
+N1*PE*LAKE AREA RECOVERY CENTER *FI*346608640~
+LX*1~
+CLP*444444*1*56.70*56.52*0*MC*0000000655555555*53~
+NM1*QC*1*FUDD*ELMER*S***MI*1333333~
+SVC*HC:H0005:HF:H9*56.70*56.52**6~
+CAS*CO*42*0.18*0~
+CAS*OA*23*57.6*0*23*-1.08*0~
+SE*11*07504123~
+GE*1*1~
+IEA*1*004075123~"#;
+    let (_, obj) = Transmission::<_835>::parse(str).unwrap();
+    let x = xml::to_string(&obj).unwrap();
+    assert!(x.starts_with("<Interchange>\n  <ISA>\n    <ISA01>01</ISA01>"));
+    assert!(x.contains("    <TransactionSet>\n      <ST>\n        <ST01>835</ST01>"));
+    assert!(x.contains("<Loop2000>"));
+    assert!(x.contains("<ISA06>ABCDEFGHIJKLMNO</ISA06>"));
+    let back: Transmission<_835> = xml::from_str(&x).unwrap();
+    assert_eq!(back, obj);
+}
+
+#[test]
+fn cas_adjustments_835() {
+    let (_, cas) = CAS::parse("CAS*CO*29*162.13*0*42*0*0~").unwrap();
+    let adjustments: Vec<_835Adjustment> = cas.adjustments().map(Result::unwrap).collect();
+    assert_eq!(
+        adjustments,
+        vec![
+            _835Adjustment {
+                group: _835AdjustmentGroup::ContractualObligations,
+                reason: "29".to_string(),
+                amount: Amount::from_cents(16213),
+                quantity: Some(0.0),
+            },
+            _835Adjustment {
+                group: _835AdjustmentGroup::ContractualObligations,
+                reason: "42".to_string(),
+                amount: Amount::ZERO,
+                quantity: Some(0.0),
+            },
+        ]
+    );
+    let (_, cas) = CAS::parse("CAS*PR*1*30*1.5~").unwrap();
+    let adjustment = cas.adjustments().next().unwrap().unwrap();
+    assert_eq!(adjustment.quantity, Some(1.5));
+    let (_, cas) = CAS::parse("CAS*PR*1*30*ONE~").unwrap();
+    let err = cas.adjustments().next().unwrap().unwrap_err();
+    assert_eq!(
+        err,
+        _835AdjustmentError::Quantity {
+            element: "CAS04",
+            value: "ONE".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), r#"invalid quantity "ONE" in CAS04"#);
+    let (_, cas) = CAS::parse("CAS*PR*1*30*1*2*10*TWO~").unwrap();
+    let err = cas.adjustments().nth(1).unwrap().unwrap_err();
+    assert_eq!(err.to_string(), r#"invalid quantity "TWO" in CAS07"#);
+
+    let str = r#"ST*835*0001~
+BPR*I*95*C*ACH*CCP*01*999999992*DA*123456*1512345678**01*999988880*DA*98765*20240315~
+N1*PR*INSURANCE COMPANY~
+N1*PE*PROVIDER*XX*1234567893~
+LX*1~
+CLP*A100*1*200*95**12*PAYERCLAIM1~
+CAS*PR*1*30~
+SVC*HC:99213*120*100**1~
+CAS*CO*45*20~
+SVC*HC:85025*80*25**1~
+CAS*PR*2*15*1*3*10~
+CAS*CO*45*30~
+SE*12*0001~
+"#;
+    let (rest, remittance) = _835::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(remittance.check_balance().unwrap(), vec![]);
+    let claim = &remittance.loop_2000[0].loop_2100[0];
+    assert_eq!(claim.adjustments().count(), 1);
+    assert_eq!(claim.loop_2110[1].adjustments().count(), 3);
+    let totals = claim.adjustment_totals().unwrap();
+    let totals: Vec<(String, String)> = totals
+        .iter()
+        .map(|(group, amount)| (group.to_string(), amount.to_string()))
+        .collect();
+    assert_eq!(
+        totals,
+        vec![
+            ("CO".to_string(), "50.00".to_string()),
+            ("PR".to_string(), "55.00".to_string())
+        ]
+    );
+    let line = claim.loop_2110[1].adjustment_totals().unwrap();
+    assert_eq!(
+        line[&_835AdjustmentGroup::PatientResponsibility],
+        Amount::from_cents(2500)
+    );
+
+    let (_, cas) = CAS::parse("CAS*PR*1*ABC~").unwrap();
+    let err = cas.adjustments().next().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), r#"invalid amount "ABC""#);
 }
@@ -1,4 +1,6 @@
 use super::*;
+use crate::util::amount::Amount;
+use chrono::NaiveDate;
 
 #[test]
 fn parse_837() {
@@ -265,3 +267,227 @@ IEA*1*000000001~"#;
     println!("{rest}");
     println!("{obj:?}");
 }
+
+#[cfg(feature = "schema")]
+#[test]
+fn json_schema_837() {
+    use crate::util::schema::json_schema;
+    let schema = json_schema::<_837>(segment_descriptor).unwrap();
+    let defs = &schema["$defs"];
+    let tx = &defs["_837"];
+    // vectors without `skip_serializing_if` are always written
+    assert_eq!(
+        tx["required"],
+        serde_json::json!(["st", "bht", "ref", "loop_1000", "loop_2000a", "se"])
+    );
+    assert_eq!(
+        tx["properties"]["loop_2000a"]["items"]["$ref"],
+        "#/$defs/_837Loop2000A"
+    );
+    let clm = &defs["CLM"];
+    assert_eq!(
+        clm["properties"]["01"]["description"],
+        "Claim Submitter's Identifier (1028)"
+    );
+    assert_eq!(clm["properties"]["01"]["maxLength"], 38);
+}
+
+#[test]
+fn parse_837_tree() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (rest, obj) = Transmission::<_837>::parse(str).unwrap();
+    assert!(rest.trim().is_empty());
+    let claim = &obj.functional_group[0].segments[0];
+    let provider = &claim.loop_2000a[0];
+    assert_eq!(provider.loop_2010a.len(), 2);
+    let subscriber = &provider.loop_2000b[0];
+    assert_eq!(subscriber.sbr._01, "P");
+    assert!(subscriber.loop_2300.is_empty());
+    let patient = &subscriber.loop_2000c[0];
+    assert_eq!(patient.pat._01, Some("19".to_string()));
+    assert_eq!(patient.loop_2300[0].clm._01, "26463774");
+    assert_eq!(patient.loop_2300[0].loop_2400.len(), 4);
+}
+
+#[test]
+fn render_837_renumbers_hl() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let mut claim = obj.functional_group[0].segments[0].clone();
+    // a second subscriber with the same patient, numbered arbitrarily
+    let mut subscriber = claim.loop_2000a[0].loop_2000b[0].clone();
+    subscriber.hl._01 = "99".to_string();
+    subscriber.loop_2000c[0].hl._01 = "42".to_string();
+    claim.loop_2000a[0].loop_2000b.push(subscriber);
+    let s = format!("{claim}");
+    let hls: Vec<&str> = s.lines().filter(|l| l.starts_with("HL*")).collect();
+    assert_eq!(
+        hls,
+        vec![
+            "HL*1**20*1~",
+            "HL*2*1*22*1~",
+            "HL*3*2*23*0~",
+            "HL*4*1*22*1~",
+            "HL*5*4*23*0~"
+        ]
+    );
+    let (_, again) = _837::parse(&s).unwrap();
+    assert_eq!(again.loop_2000a[0].loop_2000b.len(), 2);
+    assert_eq!(again.loop_2000a[0].loop_2000b[1].loop_2000c.len(), 1);
+}
+
+#[test]
+fn build_837_renumbers_hl() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let parsed = &obj.functional_group[0].segments[0];
+    let mut provider = parsed.loop_2000a[0].clone();
+    let mut subscriber = provider.loop_2000b[0].clone();
+    subscriber.hl._01 = "99".to_string();
+    subscriber.loop_2000c[0].hl._01 = "42".to_string();
+    provider.loop_2000b.push(subscriber);
+    let claim = _837::builder("0021")
+        .bht(parsed.bht.clone())
+        .loop_2000a(provider)
+        .build()
+        .unwrap();
+    let provider = &claim.loop_2000a[0];
+    assert_eq!(provider.hl._01, "1");
+    let ids: Vec<(&str, Option<&str>)> = provider
+        .loop_2000b
+        .iter()
+        .flat_map(|b| std::iter::once(&b.hl).chain(b.loop_2000c.iter().map(|c| &c.hl)))
+        .map(|hl| (hl._01.as_str(), hl._02.as_deref()))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            ("2", Some("1")),
+            ("3", Some("2")),
+            ("4", Some("1")),
+            ("5", Some("4"))
+        ]
+    );
+}
+
+#[test]
+fn renumber_837_child_codes() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let mut claim = obj.functional_group[0].segments[0].clone();
+    // the patient moves into the subscriber loop, HL04 of the subscriber goes stale
+    let subscriber = &mut claim.loop_2000a[0].loop_2000b[0];
+    let patient = subscriber.loop_2000c.remove(0);
+    subscriber.loop_2300 = patient.loop_2300;
+    assert_eq!(subscriber.hl._04, Some("1".to_string()));
+    claim.renumber();
+    let subscriber = &claim.loop_2000a[0].loop_2000b[0];
+    assert_eq!(subscriber.hl._01, "2");
+    assert_eq!(subscriber.hl._02, Some("1".to_string()));
+    assert_eq!(subscriber.hl._04, Some("0".to_string()));
+    let s = format!("{claim}");
+    let hls: Vec<&str> = s.lines().filter(|l| l.starts_with("HL*")).collect();
+    assert_eq!(hls, vec!["HL*1**20*1~", "HL*2*1*22*0~"]);
+}
+
+#[test]
+fn claims_837() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let mut tx = obj.functional_group[0].segments[0].clone();
+    let claims: Vec<_837Claim> = obj.claims().collect::<Result<_, _>>().unwrap();
+    assert_eq!(claims.len(), 1);
+    let claim = &claims[0];
+    assert_eq!(claim.patient_control_number, "26463774");
+    assert_eq!(claim.total_charge, Amount::from_cents(10000));
+    assert_eq!(claim.place_of_service, Some("11".to_string()));
+    assert_eq!(claim.frequency_code, Some("1".to_string()));
+    let billing = claim.billing_provider.as_ref().unwrap();
+    assert_eq!(billing.npi, Some("9876543210".to_string()));
+    assert_eq!(billing.tax_id, Some("587654321".to_string()));
+    assert!(claim.rendering_provider.is_none());
+    let subscriber = claim.subscriber.as_ref().unwrap();
+    assert_eq!(subscriber.member_id, Some("JS00111223333".to_string()));
+    let patient = claim.patient.as_ref().unwrap();
+    assert_eq!(patient.first_name, Some("TED".to_string()));
+    assert_eq!(patient.birth_date, NaiveDate::from_ymd_opt(1973, 5, 1));
+    assert_eq!(patient.city, Some("MIAMI".to_string()));
+    assert_eq!(
+        claim.payer.as_ref().unwrap().name,
+        Some("KEY INSURANCE COMPANY".to_string())
+    );
+    let codes: Vec<(&str, &str)> = claim
+        .diagnoses
+        .iter()
+        .map(|d| (d.qualifier.as_str(), d.code.as_str()))
+        .collect();
+    assert_eq!(codes, vec![("BK", "0340"), ("BF", "V7389")]);
+    assert_eq!(claim.service_lines.len(), 4);
+    let line = &claim.service_lines[3];
+    assert_eq!(line.line_number, "4");
+    assert_eq!(line.procedure_qualifier, Some("HC".to_string()));
+    assert_eq!(line.procedure_code, Some("86663".to_string()));
+    assert_eq!(line.charge, Amount::from_cents(1000));
+    assert_eq!(line.units, Some(Amount::from_cents(100)));
+    assert_eq!(line.diagnosis_pointers, vec!["2"]);
+    assert_eq!(line.service_date, NaiveDate::from_ymd_opt(2006, 10, 10));
+
+    // the same claim on the subscriber level, rendered by someone else
+    let subscriber = &mut tx.loop_2000a[0].loop_2000b[0];
+    let mut moved = subscriber.loop_2000c.remove(0).loop_2300;
+    moved[0].loop_2310.push(_837Loop2310 {
+        nm1: NM1 {
+            _01: "82".to_string(),
+            _02: "1".to_string(),
+            _03: Some("KILDARE".to_string()),
+            _04: Some("BEN".to_string()),
+            _08: Some("XX".to_string()),
+            _09: Some("1234567893".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    subscriber.loop_2300 = moved;
+    let claim = tx.claims(':').next().unwrap().unwrap();
+    assert_eq!(claim.patient, claim.subscriber);
+    let rendering = claim.rendering_provider.unwrap();
+    assert_eq!(rendering.npi, Some("1234567893".to_string()));
+
+    tx.loop_2000a[0].loop_2000b[0].loop_2300[0].loop_2400[0]
+        .dtp
+        .as_mut()
+        .unwrap()
+        ._03 = "2006-10-10".to_string();
+    let err = tx.claims(':').next().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), r#"invalid date "2006-10-10""#);
+}
+
+#[test]
+fn claims_837_invalid_birth_date() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let mut tx = obj.functional_group[0].segments[0].clone();
+    tx.loop_2000a[0].loop_2000b[0].loop_2000c[0].loop_2010c[0]
+        .dmg
+        .as_mut()
+        .unwrap()
+        ._02 = Some("05/01/1973".to_string());
+    let err = tx.claims(':').next().unwrap().unwrap_err();
+    assert_eq!(err, _837ClaimError::Date("05/01/1973".to_string()));
+}
+
+#[test]
+fn claims_837_component_separator() {
+    // ISA16 and all composites use `>`
+    let str = include_str!("../../test-data/005010_837.edi").replace(':', ">");
+    let (_, obj) = Transmission::<_837>::parse(&str).unwrap();
+    assert_eq!(obj.isa._16, ">");
+    let claim = obj.claims().next().unwrap().unwrap();
+    assert_eq!(claim.place_of_service, Some("11".to_string()));
+    let codes: Vec<&str> = claim.diagnoses.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, vec!["0340", "V7389"]);
+    assert_eq!(
+        claim.service_lines[3].procedure_code,
+        Some("86663".to_string())
+    );
+}
@@ -0,0 +1,217 @@
+use super::reconcile::*;
+use super::*;
+use crate::util::amount::Amount;
+
+const SUBMISSION: &str = r#"ST*837*0001*005010X222A1~
+BHT*0019*00*0123*20240311*1023*CH~
+NM1*41*2*BILLING SERVICE*****46*S00001~
+PER*IC*JOHN DOE*TE*5555551234~
+NM1*40*2*ABC INSURANCE*****46*12345~
+HL*1**20*1~
+NM1*85*2*BILLING NAME ABC*****XX*1111111111~
+N3*11111 NO NAME ROAD~
+N4*HOUSTON*TX*770744336~
+REF*EI*111111111~
+HL*2*1*22*0~
+SBR*P*18*******CI~
+NM1*IL*1*LASTNAME*FIRST****MI*111111111~
+NM1*PR*2*ABC INSURANCE*****PI*12345~
+CLM*A100*200***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99213*120*UN*1***1~
+DTP*472*D8*20240301~
+REF*6R*A100-1~
+LX*2~
+SV1*HC:85025*80*UN*1***1~
+DTP*472*D8*20240301~
+REF*6R*A100-2~
+CLM*A200*100***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99212:25*100*UN*1***1~
+DTP*472*D8*20240302~
+CLM*A300*75***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99211*75*UN*1***1~
+DTP*472*D8*20240303~
+CLM*A400*50***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+LX*1~
+SV1*HC:99211*50*UN*1***1~
+DTP*472*D8*20240304~
+SE*40*0001~
+"#;
+
+const REMITTANCE: &str = r#"ST*835*0001~
+BPR*I*130*C*ACH*CCP*01*999999992*DA*123456*1512345678**01*999988880*DA*98765*20240315~
+TRN*1*12345*1512345678~
+N1*PR*ABC INSURANCE~
+N1*PE*BILLING NAME ABC*XX*1111111111~
+LX*1~
+CLP*A100*1*200*120*30*12*PAYER1~
+SVC*HC:99213*120*80**1~
+CAS*CO*45*10~
+CAS*PR*3*30~
+REF*6R*A100-1~
+SVC*HC:85025*80*40**1~
+CAS*CO*45*40~
+REF*6R*A100-2~
+CLP*A200*1*100*90**12*PAYER2~
+SVC*HC:99212:25*100*90**1~
+CAS*CO*45*10~
+CLP*A300*4*75*0**12*PAYER3~
+CAS*CO*50*75~
+CLP*X999*1*10*10**12*PAYER4~
+CLP*A200*22*-100*-90**12*PAYER2~
+CAS*CO*45*-10~
+SE*22*0001~
+"#;
+
+/// Wraps a transaction set in an interchange with the component separator `ISA16`.
+fn interchange(st: &str, separator: char) -> String {
+    format!(
+        "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *240315*1200*^*00501*000000001*0*P*{separator}~\n\
+         GS*HC*SENDER*RECEIVER*20240315*1200*1*X*005010~\n\
+         {st}GE*1*1~\n\
+         IEA*1*000000001~\n"
+    )
+}
+
+fn submissions() -> Vec<Transmission<_837>> {
+    let str = interchange(SUBMISSION, ':');
+    let (rest, submission) = Transmission::<_837>::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    vec![submission]
+}
+
+fn remittances(st: &str, separator: char) -> Vec<Transmission<_835>> {
+    let str = interchange(st, separator);
+    let (rest, remittance) = Transmission::<_835>::parse(&str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(
+        remittance.functional_group[0].segments[0]
+            .check_balance()
+            .unwrap(),
+        vec![]
+    );
+    vec![remittance]
+}
+
+#[test]
+fn reconcile_835_with_837() {
+    let submissions = submissions();
+    let remittances = remittances(REMITTANCE, ':');
+    let result = reconcile(&submissions, &remittances).unwrap();
+
+    assert_eq!(result.partially_paid.len(), 1);
+    let a100 = &result.partially_paid[0];
+    assert_eq!(a100.patient_control_number, "A100");
+    assert_eq!(a100.charge, Amount::from_cents(20000));
+    assert_eq!(a100.paid, Amount::from_cents(12000));
+    let lines: Vec<(Option<&str>, bool, Option<Outcome>)> = a100
+        .lines
+        .iter()
+        .map(|l| (l.control_number.as_deref(), l.line.is_some(), l.outcome))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (Some("A100-1"), true, Some(Outcome::PartiallyPaid)),
+            (Some("A100-2"), true, Some(Outcome::Paid)),
+        ]
+    );
+    assert_eq!(
+        a100.lines[1].line.unwrap().sv1.as_ref().unwrap()._01,
+        "HC:85025"
+    );
+
+    // matched by procedure code, the payer did not return REF*6R
+    assert_eq!(result.paid.len(), 1);
+    let a200 = &result.paid[0];
+    assert_eq!(a200.patient_control_number, "A200");
+    assert!(a200.lines[0].line.is_some());
+
+    assert_eq!(result.denied.len(), 1);
+    let a300 = &result.denied[0];
+    assert_eq!(a300.patient_control_number, "A300");
+    assert_eq!(a300.lines.len(), 1);
+    assert!(a300.lines[0].payment.is_none());
+    assert_eq!(a300.lines[0].outcome, None);
+    assert_eq!(a300.lines[0].charge, Amount::from_cents(7500));
+
+    assert_eq!(result.reversed.len(), 1);
+    assert_eq!(result.reversed[0].patient_control_number, "A200");
+
+    assert_eq!(result.unmatched_payments.len(), 1);
+    assert_eq!(result.unmatched_payments[0].clp._01, "X999");
+    assert_eq!(result.unmatched_claims.len(), 1);
+    assert_eq!(result.unmatched_claims[0].clm._01, "A400");
+}
+
+#[test]
+fn reconcile_835_deductible() {
+    let remittance = r#"ST*835*0002~
+BPR*H*0*C*NON************20240315~
+TRN*1*12346*1512345678~
+N1*PR*ABC INSURANCE~
+N1*PE*BILLING NAME ABC*XX*1111111111~
+LX*1~
+CLP*A300*1*75*0*75*12*PAYER3~
+CAS*PR*1*75~
+CLP*A400*1*50*0**12*PAYER4~
+CAS*CO*50*50~
+SE*11*0002~
+"#;
+    let submissions = submissions();
+    let remittances = remittances(remittance, ':');
+    let result = reconcile(&submissions, &remittances).unwrap();
+
+    // applied to the deductible, the patient owes the charge
+    assert_eq!(result.partially_paid.len(), 1);
+    let a300 = &result.partially_paid[0];
+    assert_eq!(a300.patient_control_number, "A300");
+    assert_eq!(a300.paid, Amount::ZERO);
+
+    assert_eq!(result.denied.len(), 1);
+    assert_eq!(result.denied[0].patient_control_number, "A400");
+}
+
+#[test]
+fn reconcile_835_other_adjustments_are_written_off() {
+    let remittance = r#"ST*835*0003~
+BPR*I*85*C*ACH*CCP*01*999999992*DA*123456*1512345678**01*999988880*DA*98765*20240315~
+TRN*1*12347*1512345678~
+N1*PR*ABC INSURANCE~
+N1*PE*BILLING NAME ABC*XX*1111111111~
+LX*1~
+CLP*A300*1*75*60**12*PAYER3~
+CAS*OA*23*15~
+CLP*A400*1*50*25**12*PAYER4~
+CAS*PI*104*25~
+SE*11*0003~
+"#;
+    let submissions = submissions();
+    let remittances = remittances(remittance, ':');
+    let result = reconcile(&submissions, &remittances).unwrap();
+    let paid: Vec<&str> = result
+        .paid
+        .iter()
+        .map(|c| c.patient_control_number.as_str())
+        .collect();
+    assert_eq!(paid, vec!["A300", "A400"]);
+    assert!(result.partially_paid.is_empty());
+}
+
+#[test]
+fn reconcile_835_procedure_split_on_its_own_isa16() {
+    let submissions = submissions();
+    // the payer's interchange uses `>` as component separator
+    let remittances = remittances(&REMITTANCE.replace(':', ">"), '>');
+    let result = reconcile(&submissions, &remittances).unwrap();
+    let a200 = &result.paid[0];
+    assert_eq!(a200.patient_control_number, "A200");
+    assert_eq!(a200.lines[0].payment.unwrap().svc._01, "HC>99212>25");
+    assert!(a200.lines[0].line.is_some());
+}
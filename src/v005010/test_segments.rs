@@ -150,3 +150,25 @@ fn test_iea() {
     assert!(rest.is_empty());
     assert_eq!(obj._01, "1");
 }
+
+#[test]
+fn cas_metadata() {
+    use crate::util::meta::{DataType, SegmentMeta};
+    let s = "CAS*CO*29*162.13*0*42*0*0~";
+    let (_, obj) = CAS::parse(s).unwrap();
+    let desc = CAS::DESCRIPTOR;
+    assert_eq!(desc.elements.len(), 19);
+    assert_eq!(desc.element(1).unwrap().id, "1033");
+    assert_eq!(desc.element(3).unwrap().data_type, DataType::Decimal);
+    assert_eq!(desc.elements_by_id("1034").count(), 6);
+    assert_eq!(obj.element(5), Some("42"));
+    assert_eq!(obj.element_by_name("Monetary Amount"), Some("162.13"));
+}
+
+#[test]
+fn metadata_uses_5010_dictionary() {
+    use crate::util::meta::SegmentMeta;
+    assert_eq!(REF::DESCRIPTOR.element(2).unwrap().max, 50);
+    assert_eq!(PER::DESCRIPTOR.element(4).unwrap().max, 256);
+    assert_eq!(ISA::DESCRIPTOR.element(11).unwrap().id, "I65");
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::util::builder::{loop_builder, transaction_builder};
+
+transaction_builder! {
+    _404Builder => _404 ("404") {
+        segments: [m3: M3, f9: F9, d9: D9],
+        optional: [
+            zc1: ZC1,
+            bx: BX,
+            bnx: BNX,
+            m1: M1,
+            dtm: DTM,
+            na: NA,
+            r9: R9,
+            l3: L3,
+            ls: LS,
+            le: LE,
+            per: PER,
+            lh2: LH2,
+            lhr: LHR,
+            lh6: LH6,
+            xh: XH,
+            x7: X7,
+        ],
+        repeated: [n9: N9, cm: CM, r2: R2, h3: H3, ps: PS],
+        loops: [
+            loop_n7: _404LoopN7,
+            loop_n1: _404LoopN1,
+            loop_s1: _404LoopS1,
+            loop_e1: _404LoopE1,
+            loop_lx: _404LoopLX,
+            loop_t1: _404LoopT1,
+            loop_lh1: _404LoopLH1,
+        ],
+    }
+}
+
+loop_builder! {
+    _404LoopN7Builder => _404LoopN7 {
+        segments: [n7: N7],
+        optional: [em: EM, m7: M7, n5: N5, ic: IC, im: IM, m12: M12, ga: GA],
+        loops: [loop_vc: _404LoopVC, loop_e1: _404LoopN7E1, loop_ref: _404LoopN7Ref],
+    }
+}
+
+loop_builder! {
+    _404LoopVCBuilder => _404LoopVC {
+        trigger: [vc: VC],
+        loops: [loop_n1: _404LoopVcN1],
+    }
+}
+
+loop_builder! {
+    _404LoopVcN1Builder => _404LoopVcN1 {
+        trigger: [n1: N1],
+        optional: [n3: N3, n4: N4, h3: H3],
+    }
+}
+
+loop_builder! {
+    _404LoopN7E1Builder => _404LoopN7E1 {
+        segments: [e1: E1],
+        optional: [e4: E4, e5: E5, pi: PI],
+    }
+}
+
+loop_builder! {
+    _404LoopN7RefBuilder => _404LoopN7Ref {
+        trigger: [_ref: REF],
+        optional: [n10: N10],
+        loops: [loop_n1: _404LoopN7RefN1],
+    }
+}
+
+loop_builder! {
+    _404LoopN7RefN1Builder => _404LoopN7RefN1 {
+        trigger: [n1: N1],
+        optional: [n3: N3, n4: N4],
+    }
+}
+
+loop_builder! {
+    _404LoopN1Builder => _404LoopN1 {
+        segments: [n1: N1],
+        optional: [n2: N2, n3: N3, n4: N4, r#ref: REF, per: PER, bl: BL],
+    }
+}
+
+loop_builder! {
+    _404LoopS1Builder => _404LoopS1 {
+        trigger: [s1: S1],
+        optional: [s2: S2, s9: S9, n1: N1, n2: N2, n3: N3, n4: N4, per: PER],
+    }
+}
+
+loop_builder! {
+    _404LoopE1Builder => _404LoopE1 {
+        segments: [e1: E1],
+        optional: [e4: E4, e5: E5, pi: PI],
+    }
+}
+
+loop_builder! {
+    _404LoopLXBuilder => _404LoopLX {
+        segments: [lx: LX, l5: L5],
+        optional: [x1: X1],
+        loops: [loop_l0: _404LoopL0],
+    }
+}
+
+loop_builder! {
+    _404LoopL0Builder => _404LoopL0 {
+        trigger: [l0: L0],
+        optional: [mea: MEA, l1: L1],
+        loops: [loop_pi: _404LoopL0PI],
+    }
+}
+
+loop_builder! {
+    _404LoopL0PIBuilder => _404LoopL0PI {
+        trigger: [pi: PI],
+        repeated: [cd: CD],
+    }
+}
+
+loop_builder! {
+    _404LoopT1Builder => _404LoopT1 {
+        trigger: [t1: T1],
+        optional: [t2: T2, t3: T3, t6: T6, t8: T8],
+    }
+}
+
+loop_builder! {
+    _404LoopLH1Builder => _404LoopLH1 {
+        trigger: [lh1: LH1],
+        optional: [lep: LEP, lh4: LH4, lht: LHT, lhr: LHR, per: PER],
+        repeated: [lh2: LH2, lh3: LH3, lfh: LFH],
+        loops: [loop_n1: _404LoopLh1N1],
+    }
+}
+
+loop_builder! {
+    _404LoopLh1N1Builder => _404LoopLh1N1 {
+        trigger: [n1: N1],
+        optional: [n4: N4],
+        repeated: [n3: N3, per: PER],
+    }
+}
@@ -1,6 +1,7 @@
 //! v005030 repesents all entities of the 005030 specification.
 
 use crate::util::Parser;
+pub use builder::*;
 use nom::{
     combinator::{opt, peek},
     multi::many0,
@@ -11,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use x12_types_macros::DisplayX12;
 
+mod builder;
 mod segment;
 
 #[cfg(test)]
@@ -2,7 +2,32 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
+mod meta;
+pub use meta::segment_descriptor;
+
 /// BL - Billing Information
+///
+/// To identify the individual billing segments within a movement when joint rail rates have been established between carriers but do not cover the entire movement
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 747 | Rebill Reason Code | 1 | M | ID | 2/2
+/// 02 | 573 | Freight Station Accounting Code | 1 | M/Z | ID | 1/5
+/// 03 | 573 | Freight Station Accounting Code | 1 | M/Z | ID | 1/5
+/// 04 | 154 | Standard Point Location Code | 1 | X/Z | ID | 6/9
+/// 05 | 19 | City Name | 1 | X/Z | AN | 2/30
+/// 06 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 154 | Standard Point Location Code | 1 | X/Z | ID | 6/9
+/// 09 | 19 | City Name | 1 | X/Z | AN | 2/30
+/// 10 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 11 | 26 | Country Code | 1 | O | ID | 2/3
+/// 12 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 13 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 14 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 15 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 16 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 17 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -44,6 +69,15 @@ pub struct BL {
 }
 
 /// BNX - Rail Shipment Information
+///
+/// To transmit rail-specific shipment data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 460 | Shipment Weight Code | 1 | O | ID | 1/1
+/// 02 | 129 | Referenced Pattern Identifier | 1 | O | AN | 1/13
+/// 03 | 11 | Billing Code | 1 | O | ID | 1/1
+/// 04 | 223 | Repetitive Pattern Number | 1 | O | N0 | 5/5
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -59,6 +93,25 @@ pub struct BNX {
 }
 
 /// BX - General Shipment Information
+///
+/// To transmit identification numbers and other basic shipment data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
+/// 02 | 91 | Transportation Method/Type Code | 1 | M | ID | 1/2
+/// 03 | 146 | Shipment Method of Payment | 1 | M | ID | 2/2
+/// 04 | 145 | Shipment Identification Number | 1 | O | AN | 1/30
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 06 | 188 | Weight Unit Code | 1 | O | ID | 1/1
+/// 07 | 147 | Shipment Qualifier | 1 | O | ID | 1/1
+/// 08 | 226 | Section Seven Code | 1 | O | ID | 1/1
+/// 09 | 195 | Capacity Load Code | 1 | O | ID | 1/1
+/// 10 | 160 | Status Report Request Code | 1 | O | ID | 1/1
+/// 11 | 501 | Customs Documentation Handling Code | 1 | O | ID | 2/2
+/// 12 | 199 | Confidential Billing Request Code | 1 | O | ID | 1/1
+/// 13 | 714 | Goods and Services Tax Reason Code | 1 | O | ID | 1/1
+/// 14 | 346 | Application Type | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -123,6 +176,28 @@ pub struct CD {
 }
 
 /// CM - Cargo Manifest
+///
+/// To identify specific flight or voyage information for multimodal shipments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 55 | Flight/Voyage Number | 1 | O | AN | 2/10
+/// 02 | 115 | Port or Terminal Function Code | 1 | X | ID | 1/1
+/// 03 | 114 | Port Name | 1 | O | AN | 2/24
+/// 04 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 05 | 13 | Booking Number | 1 | O | AN | 1/17
+/// 06 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 08 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 09 | 182 | Vessel Name | 1 | O | AN | 2/28
+/// 10 | 113 | Pier Number | 1 | O | AN | 1/4
+/// 11 | 112 | Pier Name | 1 | O | AN | 2/14
+/// 12 | 174 | Terminal Name | 1 | O | AN | 2/30
+/// 13 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 14 | 26 | Country Code | 1 | O | ID | 2/3
+/// 15 | 127 | Reference Identification | 1 | O/Z | AN | 1/30
+/// 16 | 202 | Correction Indicator NEW | 1 | O | ID | 2/2
+/// 17 | 91 | Transportation Method/Type Code NEW | 1 | O | ID | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -164,6 +239,23 @@ pub struct CM {
 }
 
 /// D9 - Destination Station
+///
+/// To identify the rail destination of the shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 573 | Freight Station Accounting Code | 1 | O | ID | 1/5
+/// 02 | 19 | City Name | 1 | M/Z | AN | 2/30
+/// 03 | 156 | State or Province Code | 1 | M | ID | 2/2
+/// 04 | 26 | Country Code | 1 | O/Z | ID | 2/3
+/// 05 | 573 | Freight Station Accounting Code | 1 | O/Z | ID | 1/5
+/// 06 | 19 | City Name | 1 | O | AN | 2/30
+/// 07 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 08 | 154 | Standard Point Location Code | 1 | O/Z | ID | 6/9
+/// 09 | 116 | Postal Code | 1 | O/Z | ID | 3/15
+/// 10 | 154 | Standard Point Location Code NEW | 1 | O/Z | ID | 6/9
+/// 11 | 116 | Postal Code NEW | 1 | O/Z | ID | 3/15
+/// 12 | 26 | Country Code NEW | 1 | O/Z | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -195,6 +287,17 @@ pub struct D9 {
 }
 
 /// DTM - Date/Time Reference
+///
+/// To specify pertinent dates and times
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 374 | Date/Time Qualifier | 1 | M | ID | 3/3
+/// 02 | 373 | Date | 1 | X | DT | 8/8
+/// 03 | 337 | Time | 1 | X | TM | 4/8
+/// 04 | 623 | Time Code | 1 | O | ID | 2/2
+/// 05 | 1250 | Date Time Period Format Qualifier | 1 | X | ID | 2/3
+/// 06 | 1251 | Date Time Period | 1 | X | AN | 1/35
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -214,6 +317,14 @@ pub struct DTM {
 }
 
 /// E1 - Empty Car Disposition - Pended Destination Consignee
+///
+/// To identify the party receiving the empty car
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 459 | Name (30 Character Format) | 1 | M | AN | 2/30
+/// 02 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 03 | 67 | Identification Code | 1 | X | AN | 2/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -227,6 +338,15 @@ pub struct E1 {
 }
 
 /// E4 - Empty Car Disposition - Pended Destination City
+///
+/// To specify the geographic place of named party receiving the empty car
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 19 | City Name | 1 | M | AN | 2/30
+/// 02 | 156 | State or Province Code | 1 | M | ID | 2/2
+/// 03 | 116 | Postal Code | 1 | O | ID | 3/15
+/// 04 | 26 | Country Code | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -244,6 +364,15 @@ pub struct E4 {
 }
 
 /// E5 - Empty Car Disposition - Pended Destination Route
+///
+/// To specify the routing of the empty car
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 140 | Standard Carrier Alpha Code | 1 | M | ID | 2/4
+/// 02 | 133 | Routing Sequence Code | 1 | M | ID | 1/2
+/// 03 | 19 | City Name | 1 | O | AN | 2/30
+/// 04 | 154 | Standard Point Location Code | 1 | O | ID | 6/9
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -259,6 +388,18 @@ pub struct E5 {
 }
 
 /// EM - Equipment Characteristics
+///
+/// To send additional information regarding a specific piece of equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 188 | Weight Unit Code | 1 | X | ID | 1/1
+/// 02 | 81 | Weight | 1 | O/Z | R | 1/10
+/// 03 | 184 | Volume Unit Qualifier | 1 | X | ID | 1/1
+/// 04 | 183 | Volume | 1 | O/Z | R | 1/8
+/// 05 | 26 | Country Code | 1 | O/Z | ID | 2/3
+/// 06 | 1429 | Construction Type | 1 | O | ID | 1/2
+/// 07 | 373 | Date | 1 | O/Z | DT | 8/8
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -280,6 +421,23 @@ pub struct EM {
 }
 
 /// F9 - Origin Station
+///
+/// To identify the rail origin of the shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 573 | Freight Station Accounting Code | 1 | O | ID | 1/5
+/// 02 | 19 | City Name | 1 | M/Z | AN | 2/30
+/// 03 | 156 | State or Province Code | 1 | M | ID | 2/2
+/// 04 | 26 | Country Code | 1 | O/Z | ID | 2/3
+/// 05 | 573 | Freight Station Accounting Code | 1 | O/Z | ID | 1/5
+/// 06 | 19 | City Name | 1 | O | AN | 2/30
+/// 07 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 08 | 154 | Standard Point Location Code | 1 | O/Z | ID | 6/9
+/// 09 | 116 | Postal Code | 1 | O/Z | ID | 3/15
+/// 10 | 154 | Standard Point Location Code NEW | 1 | O/Z | ID | 6/9
+/// 11 | 116 | Postal Code NEW | 1 | O/Z | ID | 3/15
+/// 12 | 26 | Country Code NEW | 1 | O/Z | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -311,6 +469,28 @@ pub struct F9 {
 }
 
 /// GA - Price Authority Information
+///
+/// To transmit the transportation and distribution requirements of grain at Canadian ports
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1275 | Fumigated/Cleaned Indicator | 1 | O | ID | 1/1
+/// 02 | 22 | Commodity Code | 1 | O/Z | AN | 1/30
+/// 03 | 1576 | Inspected/Weighed Indicator Code | 1 | O | ID | 1/2
+/// 04 | 128 | Reference Identification Qualifier | 1 | O | ID | 2/3
+/// 05 | 127 | Reference Identification | 1 | O | AN | 1/30
+/// 06 | 642 | Week | 1 | O/Z | N0 | 4/4
+/// 07 | 899 | Unload Terminal Elevator Code | 1 | O | ID | 3/4
+/// 08 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 09 | 1470 | Number | 1 | O/Z | N0 | 1/9
+/// 10 | 1276 | Machine Separable Indicator Code | 1 | O | ID | 2/2
+/// 11 | 1277 | Canadian Wheat Board (CWB) Marketing Class Code | 1 | O | ID | 1/1
+/// 12 | 1278 | Canadian Wheat Board (CWB) Marketing Class Type Code | 1 | O | ID | 1/1
+/// 13 | 1073 | Yes/No Condition or Response Code | 1 | O | ID | 1/1
+/// 14 | 310 | Location Identifier | 1 | X/Z | AN | 1/30
+/// 15 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 16 | 1004 | Percent Qualifier | 1 | X | ID | 1/2
+/// 17 | 954 | Percent | 1 | X | R | 1/10
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -432,6 +612,16 @@ pub struct GS {
 }
 
 /// H3 - Special Handling Instructions
+///
+/// To specify special handling instructions in coded or free-form format
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 152 | Special Handling Code | 1 | X | ID | 2/3
+/// 02 | 153 | Special Handling Description | 1 | X | AN | 2/30
+/// 03 | 241 | Protective Service Code | 1 | O | ID | 1/4
+/// 04 | 242 | Vent Instruction Code | 1 | O | ID | 1/7
+/// 05 | 257 | Tariff Application Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -449,6 +639,19 @@ pub struct H3 {
 }
 
 /// IC - Intermodal Chassis Equipment
+///
+/// To specify the chassis equipment details in terms of identifying numbers, weights, and ownership
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 206 | Equipment Initial | 1 | M | AN | 1/4
+/// 02 | 207 | Equipment Number | 1 | M | AN | 1/10
+/// 03 | 167 | Tare Weight | 1 | X | N0 | 3/8
+/// 04 | 571 | Tare Qualifier Code | 1 | X | ID | 1/1
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 06 | 567 | Equipment Length | 1 | O | N0 | 4/5
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 08 | 845 | Chassis Type | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -504,6 +707,14 @@ pub struct IEA {
 }
 
 /// IM - Intermodal Movement Information
+///
+/// To specify the overall movement of a shipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 533 | Water Movement Code | 1 | O | ID | 1/1
+/// 02 | 152 | Special Handling Code | 1 | O | ID | 2/3
+/// 03 | 534 | Inland Transportation Code | 1 | O/Z | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -686,6 +897,26 @@ pub struct ISA {
 }
 
 /// L0 - Line Item - Quantity and Weight
+///
+/// To specify quantity, weight, volume, and type of service for a line item including applicable "quantity/rate-as" data
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 213 | Lading Line Item Number | 1 | O | N0 | 1/3
+/// 02 | 220 | Billed/Rated-as Quantity | 1 | X | R | 1/11
+/// 03 | 221 | Billed/Rated-as Qualifier | 1 | X | ID | 2/2
+/// 04 | 81 | Weight | 1 | X | R | 1/10
+/// 05 | 187 | Weight Qualifier | 1 | X | ID | 1/2
+/// 06 | 183 | Volume | 1 | X | R | 1/8
+/// 07 | 184 | Volume Unit Qualifier | 1 | X | ID | 1/1
+/// 08 | 80 | Lading Quantity | 1 | X/Z | N0 | 1/7
+/// 09 | 211 | Packaging Form Code | 1 | X | ID | 3/3
+/// 10 | 458 | Dunnage Description | 1 | O | AN | 2/25
+/// 11 | 188 | Weight Unit Code | 1 | O | ID | 1/1
+/// 12 | 56 | Type of Service Code | 1 | O | ID | 2/2
+/// 13 | 380 | Quantity | 1 | X/Z | R | 1/15
+/// 14 | 211 | Packaging Form Code | 1 | O | ID | 3/3
+/// 15 | 1073 | Yes/No Condition or Response Code | 1 | X/Z | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -723,6 +954,32 @@ pub struct L0 {
 }
 
 /// L1 - Rates and Charges
+///
+/// To specify rate and charges detail relative to a line item including freight charges, advances, special charges, and entitlements
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 213 | Lading Line Item Number | 1 | O | N0 | 1/3
+/// 02 | 60 | Freight Rate | 1 | X | R | 1/9
+/// 03 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 04 | 58 | Charge | 1 | X | N2 | 1/12
+/// 05 | 191 | Advances | 1 | X | N2 | 1/9
+/// 06 | 117 | Prepaid Amount | 1 | X | N2 | 1/9
+/// 07 | 120 | Rate Combination Point Code | 1 | O | AN | 3/9
+/// 08 | 150 | Special Charge or Allowance Code | 1 | O | ID | 3/3
+/// 09 | 121 | Rate Class Code | 1 | O | ID | 1/3
+/// 10 | 39 | Entitlement Code | 1 | O | ID | 1/1
+/// 11 | 16 | Charge Method of Payment | 1 | O | ID | 1/1
+/// 12 | 276 | Special Charge Description | 1 | O | AN | 2/25
+/// 13 | 257 | Tariff Application Code | 1 | O | ID | 1/1
+/// 14 | 74 | Declared Value | 1 | X | N2 | 2/12
+/// 15 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 16 | 372 | Lading Liability Code | 1 | O | ID | 1/1
+/// 17 | 220 | Billed/Rated-as Quantity | 1 | X | R | 1/11
+/// 18 | 221 | Billed/Rated-as Qualifier | 1 | X | ID | 2/2
+/// 19 | 954 | Percent | 1 | O/Z | R | 1/10
+/// 20 | 100 | Currency Code | 1 | O/Z | ID | 3/3
+/// 21 | 610 | Amount | 1 | O/Z | N2 | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -774,6 +1031,26 @@ pub struct L1 {
 }
 
 /// L3 - Total Weight and Charges
+///
+/// To specify the total shipment in terms of weight, volume, rates, charges, advances, and prepaid amounts applicable to one or more line items
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 81 | Weight | 1 | X | R | 1/10
+/// 02 | 187 | Weight Qualifier | 1 | X | ID | 1/2
+/// 03 | 60 | Freight Rate | 1 | X | R | 1/9
+/// 04 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 05 | 58 | Charge | 1 | O/Z | N2 | 1/12
+/// 06 | 191 | Advances | 1 | O | N2 | 1/9
+/// 07 | 117 | Prepaid Amount | 1 | O | N2 | 1/9
+/// 08 | 150 | Special Charge or Allowance Code | 1 | O | ID | 3/3
+/// 09 | 183 | Volume | 1 | X | R | 1/8
+/// 10 | 184 | Volume Unit Qualifier | 1 | X | ID | 1/1
+/// 11 | 80 | Lading Quantity | 1 | O | N0 | 1/7
+/// 12 | 188 | Weight Unit Code | 1 | O | ID | 1/1
+/// 13 | 171 | Tariff Number | 1 | O | AN | 1/7
+/// 14 | 74 | Declared Value | 1 | X | N2 | 2/12
+/// 15 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -811,6 +1088,21 @@ pub struct L3 {
 }
 
 /// L5 - Description, Marks and Numbers
+///
+/// To specify the line item in terms of description, quantity, packaging, and marks and numbers
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 213 | Lading Line Item Number | 1 | O | N0 | 1/3
+/// 02 | 79 | Lading Description | 1 | O | AN | 1/50
+/// 03 | 22 | Commodity Code | 1 | X | AN | 1/30
+/// 04 | 23 | Commodity Code Qualifier | 1 | X | ID | 1/1
+/// 05 | 103 | Packaging Code | 1 | O | AN | 3/5
+/// 06 | 87 | Marks and Numbers | 1 | X | AN | 1/48
+/// 07 | 88 | Marks and Numbers Qualifier | 1 | O | ID | 1/2
+/// 08 | 23 | Commodity Code Qualifier | 1 | X | ID | 1/1
+/// 09 | 22 | Commodity Code | 1 | X | AN | 1/30
+/// 10 | 595 | Compartment ID Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -838,6 +1130,12 @@ pub struct L5 {
 }
 
 /// LE - Loop Trailer
+///
+/// To indicate that the loop immediately preceding this segment is complete
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/6
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -847,6 +1145,15 @@ pub struct LE {
 }
 
 /// LEP - EPA Required Data
+///
+/// To specify the Environmental Protection Agency (EPA) information relating to shipments of hazardous material
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 806 | EPA Waste Stream Number Code | 1 | O | ID | 4/6
+/// 02 | 807 | Waste Characteristics Code | 1 | O | ID | 12/16
+/// 03 | 156 | State or Province Code NEW | 1 | X/Z | ID | 2/2
+/// 04 | 127 | Reference Identification NEW | 1 | X/Z | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -862,6 +1169,18 @@ pub struct LEP {
 }
 
 /// LFH - Free-form Hazardous Material Information
+///
+/// To uniquely identify the variable information required by government regulation covering the transportation of hazardous material shipments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 808 | Hazardous Material Shipment Information Qualifier | 1 | M | ID | 3/3
+/// 02 | 809 | Hazardous Material Shipment Information | 1 | M | AN | 1/25
+/// 03 | 809 | Hazardous Material Shipment Information | 1 | O | AN | 1/25
+/// 04 | 1023 | Hazard Zone Code | 1 | O | ID | 1/1
+/// 05 | 355 | Unit or Basis for Measurement Code NEW | 1 | X | ID | 2/2
+/// 06 | 380 | Quantity NEW | 1 | X/Z | R | 1/15
+/// 07 | 380 | Quantity NEW | 1 | O/Z | R | 1/15
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -885,6 +1204,22 @@ pub struct LFH {
 }
 
 /// LH1 - Hazardous Identification Information
+///
+/// To specify the hazardous commodity identification reference number and quantity
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 355 | Unit or Basis for Measurement Code | 1 | M | ID | 2/2
+/// 02 | 80 | Lading Quantity | 1 | M | N0 | 1/7
+/// 03 | 277 | UN/NA Identification Code | 1 | O | ID | 6/6
+/// 04 | 200 | Hazardous Materials Page | 1 | O | AN | 1/6
+/// 05 | 22 | Commodity Code | 1 | O | AN | 1/30
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 07 | 380 | Quantity | 1 | O | R | 1/15
+/// 08 | 595 | Compartment ID Code | 1 | O | ID | 1/1
+/// 09 | 665 | Residue Indicator Code | 1 | O | ID | 1/1
+/// 10 | 254 | Packing Group Code | 1 | O | ID | 1/3
+/// 11 | 1375 | Interim Hazardous Material Regulatory Number | 1 | O | AN | 1/5
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -916,6 +1251,22 @@ pub struct LH1 {
 }
 
 /// LH2 - Hazardous Identification Information
+///
+/// To specify the hazardous notation and endorsement information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 215 | Hazardous Classification | 1 | O | ID | 1/30
+/// 02 | 983 | Hazardous Class Qualifier | 1 | O | ID | 1/1
+/// 03 | 218 | Hazardous Placard Notation | 1 | O | ID | 14/40
+/// 04 | 222 | Hazardous Endorsement | 1 | O | ID | 4/25
+/// 05 | 759 | Reportable Quantity Code | 1 | O | ID | 2/2
+/// 06 | 355 | Unit or Basis for Measurement Code | 1 | X/Z | ID | 2/2
+/// 07 | 408 | Temperature | 1 | X | R | 1/4
+/// 08 | 355 | Unit or Basis for Measurement Code NEW | 1 | X/Z | ID | 2/2
+/// 09 | 408 | Temperature NEW | 1 | X | R | 1/4
+/// 10 | 355 | Unit or Basis for Measurement Code NEW | 1 | X/Z | ID | 2/2
+/// 11 | 408 | Temperature NEW | 1 | X | R | 1/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -949,6 +1300,15 @@ pub struct LH2 {
 }
 
 /// LH3 - Hazardous Material Shipping Name Information
+///
+/// To specify the hazardous material shipping name and additional descriptive requirements
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 224 | Hazardous Material Shipping Name | 1 | X | AN | 1/25
+/// 02 | 984 | Hazardous Material Shipping Name Qualifier | 1 | X | ID | 1/1
+/// 03 | 985 | N.O.S. Indicator Code | 1 | O | ID | 3/3
+/// 04 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -964,6 +1324,23 @@ pub struct LH3 {
 }
 
 /// LH4 - Canadian Dangerous Requirements
+///
+/// To specify additional Transport Canada requirements covering transportation of dangerous goods in Canada
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 238 | Emergency Response Plan Number | 1 | O | AN | 1/12
+/// 02 | 364 | Communication Number | 1 | O | AN | 1/80
+/// 03 | 254 | Packing Group Code | 1 | O | ID | 1/3
+/// 04 | 230 | Subsidiary Classification | 1 | O | ID | 1/3
+/// 05 | 230 | Subsidiary Classification | 1 | O | ID | 1/3
+/// 06 | 230 | Subsidiary Classification | 1 | O | ID | 1/3
+/// 07 | 271 | Subsidiary Risk Indicator | 1 | O | ID | 1/2
+/// 08 | 267 | Net Explosive Quantity | 1 | X | N0 | 1/6
+/// 09 | 805 | Canadian Hazardous Notation | 1 | O | AN | 1/25
+/// 10 | 986 | Special Commodity Indicator Code | 1 | O | ID | 1/1
+/// 11 | 364 | Communication Number | 1 | O/Z | AN | 1/80
+/// 12 | 355 | Unit or Basis for Measurement Code NEW | 1 | X | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -995,6 +1372,15 @@ pub struct LH4 {
 }
 
 /// LH6 - Hazardous Certification
+///
+/// To specify the name of the person certifying that the shipment complies with the regulations and/or the actual certification
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 93 | Name | 1 | O | AN | 1/60
+/// 02 | 272 | Hazardous Certification Code | 1 | X | ID | 1/1
+/// 03 | 273 | Hazardous Certification Declaration | 1 | X | AN | 1/25
+/// 04 | 273 | Hazardous Certification Declaration | 1 | O | AN | 1/25
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1010,6 +1396,14 @@ pub struct LH6 {
 }
 
 /// LHR - Hazardous Material Identification Reference Numbers
+///
+/// To transmit specific hazardous material reference numbers
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 03 | 373 | Date NEW | 1 | O | DT | 8/8
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1023,6 +1417,14 @@ pub struct LHR {
 }
 
 /// LHT - Hazardous Material Identification Reference Numbers
+///
+/// To specify the placard information required by the second government agency when shipment is to cross into another country
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 215 | Hazardous Classification | 1 | O | ID | 1/30
+/// 02 | 218 | Hazardous Placard Notation | 1 | O | ID | 14/40
+/// 03 | 222 | Hazardous Endorsement | 1 | O | ID | 4/25
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1036,6 +1438,12 @@ pub struct LHT {
 }
 
 /// LS - Loop Header
+///
+/// To indicate that the next segment begins a loop
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/6
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1045,6 +1453,12 @@ pub struct LS {
 }
 
 /// LX - Transaction Set Line Number
+///
+/// To reference a line number in a transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1054,6 +1468,23 @@ pub struct LX {
 }
 
 /// M1 - Insurance
+///
+/// To specify details related to insurance
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 26 | Country Code | 1 | M/Z | ID | 2/3
+/// 02 | 14 | Carriage Value | 1 | O | N0 | 2/8
+/// 03 | 74 | Declared Value | 1 | O | N2 | 2/12
+/// 04 | 122 | Rate/Value Qualifier | 1 | O | ID | 2/2
+/// 05 | 98 | Entity Identifier Code | 1 | O/Z | ID | 2/3
+/// 06 | 61 | Free-Form Message | 1 | O/Z | AN | 1/30
+/// 07 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 08 | 782 | Monetary Amount | 1 | X | R | 1/18
+/// 09 | 1004 | Percent Qualifier | 1 | X | ID | 1/2
+/// 10 | 954 | Percent | 1 | X | R | 1/10
+/// 11 | 1004 | Percent Qualifier | 1 | X | ID | 1/2
+/// 12 | 954 | Percent | 1 | X | R | 1/10
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1085,6 +1516,22 @@ pub struct M1 {
 }
 
 /// M12 - In-bond Indifying Information
+///
+/// To transmit in-bond information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 581 | Customs Entry Type Code | 1 | M | ID | 2/2
+/// 02 | 601 | Customs Entry Number | 1 | X | AN | 1/15
+/// 03 | 310 | Location Identifier | 1 | O/Z | AN | 1/30
+/// 04 | 310 | Location Identifier | 1 | O/Z | AN | 1/30
+/// 05 | 602 | Customs Shipment Value | 1 | O | AN | 2/8
+/// 06 | 603 | In-bond Control Number | 1 | X | AN | 1/25
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 08 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
+/// 09 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 10 | 91 | Transportation Method/Type Code | 1 | X | ID | 1/2
+/// 11 | 182 | Vessel Name | 1 | X | AN | 2/28
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1120,6 +1567,15 @@ pub struct M12 {
 }
 
 /// M3 - Release
+///
+/// To indicate that the equipment is or is not to be released
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 132 | Release Code | 1 | M/Z | ID | 1/1
+/// 02 | 373 | Date | 1 | X | DT | 8/8
+/// 03 | 337 | Time | 1 | X | TM | 4/8
+/// 04 | 623 | Time Code | 1 | O/Z | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1135,6 +1591,16 @@ pub struct M3 {
 }
 
 /// M7 - Seal Numbers
+///
+/// To record seal numbers used and the organization that applied the seals
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 225 | Seal Number | 1 | M | AN | 2/15
+/// 02 | 225 | Seal Number | 1 | O | AN | 2/15
+/// 03 | 225 | Seal Number | 1 | O | AN | 2/15
+/// 04 | 225 | Seal Number | 1 | O | AN | 2/15
+/// 05 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1152,6 +1618,23 @@ pub struct M7 {
 }
 
 /// MEA - Measurements
+///
+/// To specify physical measurements or counts, including dimensions, tolerances, variances, and weights
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 737 | Measurement Reference ID Code | 1 | O | ID | 2/2
+/// 02 | 738 | Measurement Qualifier | 1 | O | ID | 1/3
+/// 03 | 739 | Measurement Value | 1 | X | R | 1/20
+/// 04 | C001 | Composite Unit of Measure | 1 | X |  |
+/// 05 | 740 | Range Minimum | 1 | X | R | 1/20
+/// 06 | 741 | Range Maximum | 1 | X | R | 1/20
+/// 07 | 935 | Measurement Significance Code | 1 | O | ID | 2/2
+/// 08 | 936 | Measurement Attribute Code | 1 | X | ID | 2/2
+/// 09 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
+/// 10 | 1373 | Measurement Method or Device | 1 | O | ID | 2/4
+/// 11 | 1270 | Code List Qualifier Code | 1 | X | ID | 1/3
+/// 12 | 1271 | Industry Code | 1 | X | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1183,6 +1666,17 @@ pub struct MEA {
 }
 
 /// N1 - Party Identification
+///
+/// To identify a party by type of organization, name, and code
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 98 | Entity Identifier Code | 1 | M | ID | 2/3
+/// 02 | 93 | Name | 1 | X | AN | 1/60
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 706 | Entity Relationship Code | 1 | O | ID | 2/2
+/// 06 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1202,6 +1696,24 @@ pub struct N1 {
 }
 
 /// N10 - Quantity and Description
+///
+/// To indicate line item quantity, description, marks and numbers, commodity code, weight, and customs value
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 380 | Quantity | 1 | O | R | 1/15
+/// 02 | 369 | Free-form Description | 1 | O | AN | 1/45
+/// 03 | 87 | Marks and Numbers | 1 | O | AN | 1/48
+/// 04 | 23 | Commodity Code Qualifier | 1 | X | ID | 1/1
+/// 05 | 22 | Commodity Code | 1 | X | AN | 1/30
+/// 06 | 602 | Customs Shipment Value | 1 | X | AN | 2/8
+/// 07 | 188 | Weight Unit Code | 1 | X | ID | 1/1
+/// 08 | 81 | Weight | 1 | X | R | 1/10
+/// 09 | 127 | Reference Identification | 1 | O/Z | AN | 1/30
+/// 10 | 599 | Manifest Unit Code NEW | 1 | O | ID | 1/3
+/// 11 | 26 | Country Code NEW | 1 | O/Z | ID | 2/3
+/// 12 | 26 | Country Code NEW | 1 | O/Z | ID | 2/3
+/// 13 | 100 | Currency Code NEW | 1 | X/Z | ID | 3/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1235,6 +1747,13 @@ pub struct N10 {
 }
 
 /// N2 - Additional Name Information
+///
+/// To specify additional names or those longer than 35 characters in length
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 93 | Name | 1 | M | AN | 1/60
+/// 02 | 93 | Name | 1 | O | AN | 1/60
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1246,6 +1765,13 @@ pub struct N2 {
 }
 
 /// N3 - Party Location
+///
+/// To specify the location of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 166 | Address Information | 1 | M | AN | 1/55
+/// 02 | 166 | Address Information | 1 | O | AN | 1/55
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1257,6 +1783,18 @@ pub struct N3 {
 }
 
 /// N4 - Geographic Location
+///
+/// To specify the geographic place of the named party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 19 | City Name | 1 | O | AN | 2/30
+/// 02 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 03 | 116 | Postal Code | 1 | O | ID | 3/15
+/// 04 | 26 | Country Code | 1 | X | ID | 2/3
+/// 05 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 06 | 310 | Location Identifier | 1 | O | AN | 1/30
+/// 07 | 1715 | Country Subdivision Code | 1 | X | ID | 1/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1278,6 +1816,20 @@ pub struct N4 {
 }
 
 /// N5 - Equipment Ordered
+///
+/// To specify carrier equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 567 | Equipment Length | 1 | O | N0 | 4/5
+/// 02 | 233 | Weight Capacity | 1 | O | N0 | 2/3
+/// 03 | 203 | Cubic Capacity | 1 | O | N0 | 2/4
+/// 04 | 301 | Car Type Code | 1 | O | ID | 1/4
+/// 05 | 216 | Metric Qualifier | 1 | O | ID | 1/1
+/// 06 | 65 | Height | 1 | O | R | 1/8
+/// 07 | 643 | Lading Percentage | 1 | X | N2 | 2/4
+/// 08 | 644 | Lading Percent Qualifier | 1 | X | ID | 1/1
+/// 09 | 40 | Equipment Description Code | 1 | O | ID | 2/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1303,6 +1855,35 @@ pub struct N5 {
 }
 
 /// N7 - Equipment Details
+///
+/// To identify the equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 02 | 207 | Equipment Number | 1 | M | AN | 1/10
+/// 03 | 81 | Weight | 1 | X | R | 1/10
+/// 04 | 187 | Weight Qualifier | 1 | X | ID | 1/2
+/// 05 | 167 | Tare Weight | 1 | X | N0 | 3/8
+/// 06 | 232 | Weight Allowance | 1 | O | N0 | 2/6
+/// 07 | 205 | Dunnage | 1 | O | N0 | 1/6
+/// 08 | 183 | Volume | 1 | X | R | 1/8
+/// 09 | 184 | Volume Unit Qualifier | 1 | X | ID | 1/1
+/// 10 | 102 | Ownership Code | 1 | O | ID | 1/1
+/// 11 | 40 | Equipment Description Code | 1 | O | ID | 2/2
+/// 12 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 13 | 319 | Temperature Control | 1 | O | AN | 3/6
+/// 14 | 219 | Position | 1 | O | AN | 1/3
+/// 15 | 567 | Equipment Length | 1 | O | N0 | 4/5
+/// 16 | 571 | Tare Qualifier Code | 1 | X | ID | 1/1
+/// 17 | 188 | Weight Unit Code | 1 | O | ID | 1/1
+/// 18 | 761 | Equipment Number Check Digit | 1 | O | N0 | 1/1
+/// 19 | 56 | Type of Service Code | 1 | O | ID | 2/2
+/// 20 | 65 | Height | 1 | O | R | 1/8
+/// 21 | 189 | Width | 1 | O | R | 1/8
+/// 22 | 24 | Equipment Type | 1 | O | ID | 4/4
+/// 23 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 24 | 301 | Car Type Code | 1 | O | ID | 1/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1358,6 +1939,16 @@ pub struct N7 {
 }
 
 /// N9 - Extended Reference Information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 03 | 369 | Free-form Description | 1 | X | AN | 1/45
+/// 04 | 373 | Date | 1 | O | DT | 8/8
+/// 05 | 337 | Time | 1 | X | TM | 4/8
+/// 06 | 623 | Time Code | 1 | O/Z | ID | 2/2
+/// 07 | C040 | Reference Identifier | 1 | O/Z |  |
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1379,6 +1970,22 @@ pub struct N9 {
 }
 
 /// NA - Cross-Reference Equipment
+///
+/// To cross-reference additional equipment to a primary piece of equipment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | O | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 03 | 206 | Equipment Initial | 1 | M | AN | 1/4
+/// 04 | 207 | Equipment Number | 1 | M | AN | 1/10
+/// 05 | 231 | Cross Reference Type Code | 1 | O | ID | 1/1
+/// 06 | 219 | Position | 1 | O | AN | 1/3
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 08 | 567 | Equipment Length | 1 | O | N0 | 4/5
+/// 09 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 10 | 845 | Chassis Type | 1 | O | ID | 2/2
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1410,6 +2017,20 @@ pub struct NA {
 }
 
 /// PER - Administrative Communication Contract
+///
+/// To identify a person or office to whom administrative communications should be directed
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 366 | Contact Function Code | 1 | M | ID | 2/2
+/// 02 | 93 | Name | 1 | O | AN | 1/60
+/// 03 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 04 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 05 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 06 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 07 | 365 | Communication Number Qualifier | 1 | X | ID | 2/2
+/// 08 | 364 | Communication Number | 1 | X | AN | 1/80
+/// 09 | 443 | Contact Inquiry Reference | 1 | O | AN | 1/20
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1435,6 +2056,26 @@ pub struct PER {
 }
 
 /// PI - Price Authority Information
+///
+/// To communicate basis of pricing, such as contract number, quote number, or tariff number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
+/// 03 | 436 | Primary Publication Authority Code | 1 | O | ID | 2/2
+/// 04 | 930 | Regulatory Agency Code | 1 | O | ID | 3/5
+/// 05 | 168 | Tariff Agency Code | 1 | O | ID | 1/4
+/// 06 | 965 | Issuing Carrier Identifier | 1 | O | AN | 1/10
+/// 07 | 660 | Contract Suffix | 1 | O/Z | AN | 1/2
+/// 08 | 169 | Tariff Item Number | 1 | O/Z | AN | 1/16
+/// 09 | 173 | Tariff Supplement Identifier | 1 | O/Z | AN | 1/4
+/// 10 | 172 | Tariff Section | 1 | O/Z | AN | 1/2
+/// 11 | 660 | Contract Suffix | 1 | O/Z | AN | 1/2
+/// 12 | 373 | Date | 1 | X/Z | DT | 8/8
+/// 13 | 373 | Date | 1 | X/Z | DT | 8/8
+/// 14 | 629 | Alternation Precedence Code | 1 | O | ID | 1/1
+/// 15 | 629 | Alternation Precedence Code | 1 | O | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1474,6 +2115,25 @@ pub struct PI {
 }
 
 /// PS - Protective Service Instructions
+///
+/// To specify mechanical protective service and ventilation instructions
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 746 | Protective Service Rule Code | 1 | M | ID | 3/9
+/// 02 | 241 | Protective Service Code | 1 | M | ID | 1/4
+/// 03 | 355 | Unit or Basis for Measurement Code | 1 | X/Z | ID | 2/2
+/// 04 | 408 | Temperature | 1 | X/Z | R | 1/4
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
+/// 06 | 573 | Freight Station Accounting Code | 1 | O | ID | 1/5
+/// 07 | 19 | City Name | 1 | O | AN | 2/30
+/// 08 | 156 | State or Province Code | 1 | O | ID | 2/2
+/// 09 | 81 | Weight | 1 | O | R | 1/10
+/// 10 | 745 | Pre-Cooled (Rule 710) Code | 1 | O | ID | 1/1
+/// 11 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 12 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 13 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 14 | 408 | Temperature | 1 | X/Z | R | 1/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1509,6 +2169,24 @@ pub struct PS {
 }
 
 /// R2 - Route Information
+///
+/// To specify carrier and routing sequences and details
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 140 | Standard Carrier Alpha Code | 1 | M | ID | 2/4
+/// 02 | 133 | Routing Sequence Code | 1 | M | ID | 1/2
+/// 03 | 19 | City Name | 1 | O/Z | AN | 2/30
+/// 04 | 154 | Standard Point Location Code | 1 | O | ID | 6/9
+/// 05 | 177 | Intermodal Service Code | 1 | O | ID | 1/2
+/// 06 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
+/// 07 | 296 | Intermediate Switch Carrier | 1 | X | ID | 2/4
+/// 08 | 296 | Intermediate Switch Carrier | 1 | O | ID | 2/4
+/// 09 | 76 | Invoice Number | 1 | O | AN | 1/22
+/// 10 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 11 | 369 | Free-form Description | 1 | O | AN | 1/45
+/// 12 | 56 | Type of Service Code | 1 | O | ID | 2/2
+/// 13 | 742 | Route Description | 1 | O | AN | 1/35
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1542,6 +2220,19 @@ pub struct R2 {
 }
 
 /// R9 - Code Identification
+///
+/// To specify the route using a single code
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 1 | Route Code | 1 | M | AN | 1/13
+/// 02 | 192 | Agent/Shipper Routing Code | 1 | O | ID | 1/1
+/// 03 | 177 | Intermodal Service Code | 1 | O | ID | 1/2
+/// 04 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 05 | 306 | Action Code | 1 | O | ID | 1/2
+/// 06 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 07 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
+/// 08 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1567,6 +2258,15 @@ pub struct R9 {
 }
 
 /// REF - Party Identification
+///
+/// To specify identifying information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 128 | Reference Identification Qualifier | 1 | M | ID | 2/3
+/// 02 | 127 | Reference Identification | 1 | X | AN | 1/30
+/// 03 | 352 | Description | 1 | X | AN | 1/80
+/// 04 | C040 | Reference Identifier | 1 | O/Z |  |
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1582,6 +2282,17 @@ pub struct REF {
 }
 
 /// S1 - Stop-off Name
+///
+/// To identify a stop-off party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 165 | Stop Sequence Number | 1 | M | N0 | 1/3
+/// 02 | 459 | Name (30 Character Format) | 1 | M | AN | 2/30
+/// 03 | 66 | Identification Code Qualifier | 1 | X | ID | 1/2
+/// 04 | 67 | Identification Code | 1 | X | AN | 2/80
+/// 05 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 06 | 190 | Accomplish Code | 1 | M | ID | 1/1
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1601,6 +2312,14 @@ pub struct S1 {
 }
 
 /// S2 - Stop-off Address
+///
+/// To specify the address of the stop-off party
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 165 | Stop Sequence Number | 1 | M | N0 | 1/3
+/// 02 | 166 | Address Information | 1 | M | AN | 1/55
+/// 03 | 166 | Address Information | 1 | O | AN | 1/55
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1614,6 +2333,19 @@ pub struct S2 {
 }
 
 /// S9 - Stop-off Name
+///
+/// To specify location details for a stop-off
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 165 | Stop Sequence Number | 1 | M | N0 | 1/3
+/// 02 | 154 | Standard Point Location Code | 1 | O | ID | 6/9
+/// 03 | 19 | City Name | 1 | M | AN | 2/30
+/// 04 | 156 | State or Province Code | 1 | M | ID | 2/2
+/// 05 | 26 | Country Code | 1 | O | ID | 2/3
+/// 06 | 163 | Stop Reason Code | 1 | M | ID | 2/2
+/// 07 | 309 | Location Qualifier | 1 | X | ID | 1/2
+/// 08 | 310 | Location Identifier | 1 | X | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1687,6 +2419,20 @@ pub struct ST {
 }
 
 /// T1 - Transit Inbound Origin
+///
+/// To specify origin point and waybill references of movement to transit waybill point
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
+/// 02 | 186 | Waybill Number | 1 | O/Z | N0 | 1/6
+/// 03 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 04 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
+/// 05 | 19 | City Name | 1 | X/Z | AN | 2/30
+/// 06 | 156 | State or Province Code | 1 | X | ID | 2/2
+/// 07 | 154 | Standard Point Location Code | 1 | O | ID | 6/9
+/// 08 | 229 | Transit Registration Number | 1 | O | AN | 1/6
+/// 09 | 461 | Transit Level Code | 1 | O | ID | 1/3
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1716,6 +2462,23 @@ pub struct T1 {
 }
 
 /// T2 - Transit Inbound Lading
+///
+/// To specify lading description, including weight and rate details applying to the associated T1 segment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
+/// 02 | 79 | Lading Description | 1 | O | AN | 1/50
+/// 03 | 81 | Weight | 1 | O/Z | R | 1/10
+/// 04 | 187 | Weight Qualifier | 1 | O | ID | 1/2
+/// 05 | 60 | Freight Rate | 1 | X | R | 1/9
+/// 06 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 07 | 60 | Freight Rate | 1 | X | R | 1/9
+/// 08 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 09 | 19 | City Name | 1 | O/Z | AN | 2/30
+/// 10 | 19 | City Name | 1 | O/Z | AN | 2/30
+/// 11 | 462 | Through Surcharge Percent | 1 | O | N2 | 2/4
+/// 12 | 463 | Paid-In Surcharge Percent | 1 | O | N2 | 2/4
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1747,6 +2510,18 @@ pub struct T2 {
 }
 
 /// T3 - Transit Inbound Route
+///
+/// To specify transit inbound routing, including equipment identifications for associated T1 and T2 segments
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
+/// 02 | 140 | Standard Carrier Alpha Code | 1 | M | ID | 2/4
+/// 03 | 133 | Routing Sequence Code | 1 | O | ID | 1/2
+/// 04 | 19 | City Name | 1 | O | AN | 2/30
+/// 05 | 154 | Standard Point Location Code | 1 | O | ID | 6/9
+/// 06 | 206 | Equipment Initial | 1 | X/Z | AN | 1/4
+/// 07 | 207 | Equipment Number | 1 | X/Z | AN | 1/10
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1768,6 +2543,18 @@ pub struct T3 {
 }
 
 /// T6 - Transit Inbound Rates
+///
+/// To identify the transit inbound prior origin point and waybill reference of movement to the point specified in T1 segment
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
+/// 02 | 60 | Freight Rate | 1 | X/Z | R | 1/9
+/// 03 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 04 | 19 | City Name | 1 | O/Z | AN | 2/30
+/// 05 | 60 | Freight Rate | 1 | X/Z | R | 1/9
+/// 06 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
+/// 07 | 19 | City Name | 1 | O | AN | 2/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1789,6 +2576,13 @@ pub struct T6 {
 }
 
 /// T8 - Transit Inbound Rates
+///
+/// To transmit information in a free-form format relating to a specified transit sequence number
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
+/// 02 | 299 | Free-form Transit Data | 1 | M | AN | 1/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1800,6 +2594,22 @@ pub struct T8 {
 }
 
 /// VC - Motor Vehicle Control
+///
+/// To define motor vehicle identification and logistics
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 539 | Vehicle Identification Number | 1 | M | AN | 1/25
+/// 02 | 836 | Vehicle Deck Position Code | 1 | O | ID | 2/2
+/// 03 | 837 | Vehicle Type Code | 1 | O | ID | 1/1
+/// 04 | 838 | Dealer Code | 1 | O | AN | 2/9
+/// 05 | 1 | Route Code | 1 | O/Z | AN | 1/13
+/// 06 | 839 | Bay Location | 1 | O | AN | 1/6
+/// 07 | 833 | Automotive Manufacturers Code | 1 | O | ID | 2/2
+/// 08 | 308 | Damage Exception Indicator | 1 | O | ID | 1/1
+/// 09 | 835 | Supplemental Inspection Code | 1 | O | ID | 1/1
+/// 10 | 583 | Factory Car Order Number | 1 | O | AN | 6/10
+/// 11 | 877 | Vessel Stowage Location | 1 | O | AN | 1/12
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1833,6 +2643,27 @@ pub struct VC {
 }
 
 /// X1 - Export License
+///
+/// To transmit information contained on an export license
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 83 | Licensing Agency Code | 1 | O | ID | 1/1
+/// 02 | 50 | Export License Number | 1 | O | AN | 6/12
+/// 03 | 51 | Export License Status Code | 1 | O | ID | 1/1
+/// 04 | 373 | Date | 1 | O/Z | DT | 8/8
+/// 05 | 52 | Export License Symbol Code | 1 | O | ID | 1/2
+/// 06 | 48 | Export License Control Code | 1 | O | ID | 1/1
+/// 07 | 26 | Country Code | 1 | O | ID | 2/3
+/// 08 | 141 | Schedule B Code | 1 | O | ID | 7/10
+/// 09 | 210 | International/Domestic Code | 1 | O | ID | 1/1
+/// 10 | 80 | Lading Quantity | 1 | O | N0 | 1/7
+/// 11 | 148 | Lading Value | 1 | O | R | 2/9
+/// 12 | 47 | Export Filing Key Code | 1 | O | ID | 1/1
+/// 13 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
+/// 14 | 212 | Unit Price | 1 | O | R | 1/17
+/// 15 | 1306 | U.S. Government License Type | 1 | O | AN | 1/1
+/// 16 | 67 | Identification Code NEW | 1 | O/Z | AN | 2/80
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1874,6 +2705,13 @@ pub struct X1 {
 }
 
 /// X7 - Customs Information
+///
+/// To indicate customs information
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 61 | Free-Form Message | 1 | M | AN | 1/30
+/// 02 | 61 | Free-Form Message | 1 | O | AN | 1/30
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1885,6 +2723,18 @@ pub struct X7 {
 }
 
 /// XH - Pro Forma - B13 Information
+///
+/// This segment is used to specify a pro forma invoice and B13 Canadian Customs Export Declaration information, required by U.S. and Canadian customs
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 100 | Currency Code | 1 | M | ID | 3/3
+/// 02 | 645 | Related Company Indication Code | 1 | O | ID | 1/1
+/// 03 | 150 | Special Charge or Allowance Code | 1 | O | ID | 3/3
+/// 04 | 610 | Amount | 1 | O/Z | N2 | 1/15
+/// 05 | 503 | Block 20 Code | 1 | O | ID | 1/1
+/// 06 | 504 | Chemical Analysis Percentage | 1 | O/Z | N2 | 2/9
+/// 07 | 212 | Unit Price | 1 | O/Z | R | 1/17
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -1906,6 +2756,19 @@ pub struct XH {
 }
 
 /// ZC1 - Beginning Segment for Data Correction or Change
+///
+/// To transmit identifying numbers, dates, and other basic data relating to the transaction set
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | 145 | Shipment Identification Number | 1 | O | AN | 1/30
+/// 02 | 206 | Equipment Initial | 1 | O | AN | 1/4
+/// 03 | 207 | Equipment Number | 1 | M | AN | 1/10
+/// 04 | 244 | Transaction Reference Number | 1 | M | AN | 1/15
+/// 05 | 243 | Transaction Reference Date | 1 | M | DT | 8/8
+/// 06 | 202 | Correction Indicator | 1 | M | ID | 2/2
+/// 07 | 140 | Standard Carrier Alpha Code | 1 | M | ID | 2/4
+/// 08 | 91 | Transportation Method/Type Code | 1 | M/Z | ID | 1/2
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
@@ -0,0 +1,1293 @@
+use super::*;
+use crate::util::meta::{segment_meta, SegmentDescriptor, SegmentMeta};
+
+segment_meta! {
+    BL,
+    "Billing Information",
+    "To identify the individual billing segments within a movement when joint rail rates have been established between carriers but do not cover the entire movement",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "747", "Rebill Reason Code", Mandatory, Id, 2, 2, rebill_reason_code),
+        (2, "573", "Freight Station Accounting Code", Mandatory, Id, 1, 5, freight_station_accounting_code_02),
+        (3, "573", "Freight Station Accounting Code", Mandatory, Id, 1, 5, freight_station_accounting_code_03),
+        (4, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code_04),
+        (5, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name_05),
+        (6, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code_06),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code_07),
+        (8, "154", "Standard Point Location Code", Conditional, Id, 6, 9, standard_point_location_code_08),
+        (9, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name_09),
+        (10, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code_10),
+        (11, "26", "Country Code", Optional, Id, 2, 3, country_code_11),
+        (12, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_13),
+        (14, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_14),
+        (15, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_15),
+        (16, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_16),
+        (17, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_17),
+    ]
+}
+
+segment_meta! {
+    BNX,
+    "Rail Shipment Information",
+    "To transmit rail-specific shipment data",
+    [_01, _02, _03, _04],
+    [
+        (1, "460", "Shipment Weight Code", Optional, Id, 1, 1, shipment_weight_code),
+        (2, "129", "Referenced Pattern Identifier", Optional, AlphaNumeric, 1, 13, referenced_pattern_identifier),
+        (3, "11", "Billing Code", Optional, Id, 1, 1, billing_code),
+        (4, "223", "Repetitive Pattern Number", Optional, Numeric(0), 5, 5, repetitive_pattern_number),
+    ]
+}
+
+segment_meta! {
+    BX,
+    "General Shipment Information",
+    "To transmit identification numbers and other basic shipment data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "353", "Transaction Set Purpose Code", Mandatory, Id, 2, 2, transaction_set_purpose_code),
+        (2, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+        (3, "146", "Shipment Method of Payment", Mandatory, Id, 2, 2, shipment_method_of_payment),
+        (4, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (7, "147", "Shipment Qualifier", Optional, Id, 1, 1, shipment_qualifier),
+        (8, "226", "Section Seven Code", Optional, Id, 1, 1, section_seven_code),
+        (9, "195", "Capacity Load Code", Optional, Id, 1, 1, capacity_load_code),
+        (10, "160", "Status Report Request Code", Optional, Id, 1, 1, status_report_request_code),
+        (11, "501", "Customs Documentation Handling Code", Optional, Id, 2, 2, customs_documentation_handling_code),
+        (12, "199", "Confidential Billing Request Code", Optional, Id, 1, 1, confidential_billing_request_code),
+        (13, "714", "Goods and Services Tax Reason Code", Optional, Id, 1, 1, goods_and_services_tax_reason_code),
+        (14, "346", "Application Type", Optional, Id, 2, 2, application_type),
+    ]
+}
+
+segment_meta! {
+    CD,
+    "Shipment Condition",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+    ]
+}
+
+segment_meta! {
+    CM,
+    "Cargo Manifest",
+    "To identify specific flight or voyage information for multimodal shipments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "55", "Flight/Voyage Number", Optional, AlphaNumeric, 2, 10, flight_voyage_number),
+        (2, "115", "Port or Terminal Function Code", Conditional, Id, 1, 1, port_or_terminal_function_code),
+        (3, "114", "Port Name", Optional, AlphaNumeric, 2, 24, port_name),
+        (4, "373", "Date", Optional, Date, 8, 8, date_04),
+        (5, "13", "Booking Number", Optional, AlphaNumeric, 1, 17, booking_number),
+        (6, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_06),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "373", "Date", Optional, Date, 8, 8, date_08),
+        (9, "182", "Vessel Name", Optional, AlphaNumeric, 2, 28, vessel_name),
+        (10, "113", "Pier Number", Optional, AlphaNumeric, 1, 4, pier_number),
+        (11, "112", "Pier Name", Optional, AlphaNumeric, 2, 14, pier_name),
+        (12, "174", "Terminal Name", Optional, AlphaNumeric, 2, 30, terminal_name),
+        (13, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (14, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (15, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (16, "202", "Correction Indicator", Optional, Id, 2, 2, correction_indicator),
+        (17, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+segment_meta! {
+    D9,
+    "Destination Station",
+    "To identify the rail destination of the shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_01),
+        (2, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name_02),
+        (3, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code_03),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code_04),
+        (5, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_05),
+        (6, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_06),
+        (7, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code_07),
+        (8, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_08),
+        (9, "116", "Postal Code", Optional, Id, 3, 15, postal_code_09),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_10),
+        (11, "116", "Postal Code", Optional, Id, 3, 15, postal_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+    ]
+}
+
+segment_meta! {
+    DTM,
+    "Date/Time Reference",
+    "To specify pertinent dates and times",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "374", "Date/Time Qualifier", Mandatory, Id, 3, 3, date_time_qualifier),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (5, "1250", "Date Time Period Format Qualifier", Conditional, Id, 2, 3, date_time_period_format_qualifier),
+        (6, "1251", "Date Time Period", Conditional, AlphaNumeric, 1, 35, date_time_period),
+    ]
+}
+
+segment_meta! {
+    E1,
+    "Empty Car Disposition - Pended Destination Consignee",
+    "To identify the party receiving the empty car",
+    [_01, _02, _03],
+    [
+        (1, "459", "Name (30 Character Format)", Mandatory, AlphaNumeric, 2, 30, name_30_character_format),
+        (2, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (3, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+    ]
+}
+
+segment_meta! {
+    E4,
+    "Empty Car Disposition - Pended Destination City",
+    "To specify the geographic place of named party receiving the empty car",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code),
+    ]
+}
+
+segment_meta! {
+    E5,
+    "Empty Car Disposition - Pended Destination Route",
+    "To specify the routing of the empty car",
+    [_01, _02, _03, _04],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (4, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+    ]
+}
+
+segment_meta! {
+    EM,
+    "Equipment Characteristics",
+    "To send additional information regarding a specific piece of equipment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (2, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (3, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (4, "183", "Volume", Optional, Decimal, 1, 8, volume),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "1429", "Construction Type", Optional, Id, 1, 2, construction_type),
+        (7, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    F9,
+    "Origin Station",
+    "To identify the rail origin of the shipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_01),
+        (2, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name_02),
+        (3, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code_03),
+        (4, "26", "Country Code", Optional, Id, 2, 3, country_code_04),
+        (5, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code_05),
+        (6, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_06),
+        (7, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code_07),
+        (8, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_08),
+        (9, "116", "Postal Code", Optional, Id, 3, 15, postal_code_09),
+        (10, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code_10),
+        (11, "116", "Postal Code", Optional, Id, 3, 15, postal_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+    ]
+}
+
+segment_meta! {
+    GA,
+    "Price Authority Information",
+    "To transmit the transportation and distribution requirements of grain at Canadian ports",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18],
+    [
+        (1, "1275", "Fumigated/Cleaned Indicator", Optional, Id, 1, 1, fumigated_cleaned_indicator),
+        (2, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (3, "1576", "Inspected/Weighed Indicator Code", Optional, Id, 1, 2, inspected_weighed_indicator_code),
+        (4, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (5, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (6, "642", "Week", Optional, Numeric(0), 4, 4, week),
+        (7, "899", "Unload Terminal Elevator Code", Optional, Id, 3, 4, unload_terminal_elevator_code),
+        (8, "373", "Date", Optional, Date, 8, 8, date),
+        (9, "1470", "Number", Optional, Numeric(0), 1, 9, number),
+        (10, "1276", "Machine Separable Indicator Code", Optional, Id, 2, 2, machine_separable_indicator_code),
+        (11, "1277", "Canadian Wheat Board (CWB) Marketing Class Code", Optional, Id, 1, 1, canadian_wheat_board_cwb_marketing_class_code),
+        (12, "1278", "Canadian Wheat Board (CWB) Marketing Class Type Code", Optional, Id, 1, 1, canadian_wheat_board_cwb_marketing_class_type_code),
+        (13, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+        (14, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+        (15, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (16, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier),
+        (17, "954", "Percent", Conditional, Decimal, 1, 10, percent),
+    ]
+}
+
+segment_meta! {
+    GE,
+    "Functional Group Trailer",
+    "To indicate the end of a functional group and to provide control information",
+    [_01, _02],
+    [
+        (1, "97", "Number of Transaction Sets Included", Mandatory, Numeric(0), 1, 6, number_of_transaction_sets_included),
+        (2, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+    ]
+}
+
+segment_meta! {
+    GS,
+    "Functional Group Header",
+    "To indicate the beginning of a functional group and to provide control information",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "479", "Functional Identifier Code", Mandatory, Id, 2, 2, functional_identifier_code),
+        (2, "142", "Application Sender's Code", Mandatory, AlphaNumeric, 2, 15, application_senders_code),
+        (3, "124", "Application Receiver's Code", Mandatory, AlphaNumeric, 2, 15, application_receivers_code),
+        (4, "373", "Date", Mandatory, Date, 8, 8, date),
+        (5, "337", "Time", Mandatory, Time, 4, 8, time),
+        (6, "28", "Group Control Number", Mandatory, Numeric(0), 1, 9, group_control_number),
+        (7, "455", "Responsible Agency Code", Mandatory, Id, 1, 2, responsible_agency_code),
+        (8, "480", "Version / Release / Industry Identifier Code", Mandatory, AlphaNumeric, 1, 12, version_release_industry_identifier_code),
+    ]
+}
+
+segment_meta! {
+    H3,
+    "Special Handling Instructions",
+    "To specify special handling instructions in coded or free-form format",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "152", "Special Handling Code", Conditional, Id, 2, 3, special_handling_code),
+        (2, "153", "Special Handling Description", Conditional, AlphaNumeric, 2, 30, special_handling_description),
+        (3, "241", "Protective Service Code", Optional, Id, 1, 4, protective_service_code),
+        (4, "242", "Vent Instruction Code", Optional, Id, 1, 7, vent_instruction_code),
+        (5, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+    ]
+}
+
+segment_meta! {
+    IC,
+    "Intermodal Chassis Equipment",
+    "To specify the chassis equipment details in terms of identifying numbers, weights, and ownership",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (3, "167", "Tare Weight", Conditional, Numeric(0), 3, 8, tare_weight),
+        (4, "571", "Tare Qualifier Code", Conditional, Id, 1, 1, tare_qualifier_code),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_05),
+        (6, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "845", "Chassis Type", Optional, Id, 2, 2, chassis_type),
+    ]
+}
+
+segment_meta! {
+    IEA,
+    "Interchange Control Trailer",
+    "To define the end of an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02],
+    [
+        (1, "I16", "Number of Included Functional Groups", Mandatory, Numeric(0), 1, 5, number_of_included_functional_groups),
+        (2, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+    ]
+}
+
+segment_meta! {
+    IM,
+    "Intermodal Movement Information",
+    "To specify the overall movement of a shipment",
+    [_01, _02, _03],
+    [
+        (1, "533", "Water Movement Code", Optional, Id, 1, 1, water_movement_code),
+        (2, "152", "Special Handling Code", Optional, Id, 2, 3, special_handling_code),
+        (3, "534", "Inland Transportation Code", Optional, Id, 2, 2, inland_transportation_code),
+    ]
+}
+
+segment_meta! {
+    ISA,
+    "Interchange Control Header",
+    "To start and identify an interchange of zero or more functional groups and interchange-related control segments",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "I01", "Authorization Information Qualifier", Mandatory, Id, 2, 2, authorization_information_qualifier),
+        (2, "I02", "Authorization Information", Mandatory, AlphaNumeric, 10, 10, authorization_information),
+        (3, "I03", "Security Information Qualifier", Mandatory, Id, 2, 2, security_information_qualifier),
+        (4, "I04", "Security Information", Mandatory, AlphaNumeric, 10, 10, security_information),
+        (5, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_05),
+        (6, "I06", "Interchange Sender ID", Mandatory, AlphaNumeric, 15, 15, interchange_sender_id),
+        (7, "I05", "Interchange ID Qualifier", Mandatory, Id, 2, 2, interchange_id_qualifier_07),
+        (8, "I07", "Interchange Receiver ID", Mandatory, AlphaNumeric, 15, 15, interchange_receiver_id),
+        (9, "I08", "Interchange Date", Mandatory, Date, 6, 6, interchange_date),
+        (10, "I09", "Interchange Time", Mandatory, Time, 4, 4, interchange_time),
+        (11, "I10", "Interchange Control Standards Identifier", Mandatory, Id, 1, 1, interchange_control_standards_identifier),
+        (12, "I11", "Interchange Control Version Number", Mandatory, Id, 5, 5, interchange_control_version_number),
+        (13, "I12", "Interchange Control Number", Mandatory, Numeric(0), 9, 9, interchange_control_number),
+        (14, "I13", "Acknowledgment Requested", Mandatory, Id, 1, 1, acknowledgment_requested),
+        (15, "I14", "Usage Indicator", Mandatory, Id, 1, 1, usage_indicator),
+        (16, "I15", "Component Element Separator", Mandatory, Composite, 1, 1, component_element_separator),
+    ]
+}
+
+segment_meta! {
+    L0,
+    "Line Item - Quantity and Weight",
+    "To specify quantity, weight, volume, and type of service for a line item including applicable \"quantity/rate-as\" data",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (3, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (4, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (5, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (6, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (7, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (8, "80", "Lading Quantity", Conditional, Numeric(0), 1, 7, lading_quantity),
+        (9, "211", "Packaging Form Code", Conditional, Id, 3, 3, packaging_form_code_09),
+        (10, "458", "Dunnage Description", Optional, AlphaNumeric, 2, 25, dunnage_description),
+        (11, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (12, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (13, "380", "Quantity", Conditional, Decimal, 1, 15, quantity),
+        (14, "211", "Packaging Form Code", Optional, Id, 3, 3, packaging_form_code_14),
+        (15, "1073", "Yes/No Condition or Response Code", Conditional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    L1,
+    "Rates and Charges",
+    "To specify rate and charges detail relative to a line item including freight charges, advances, special charges, and entitlements",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (3, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_03),
+        (4, "58", "Charge", Conditional, Numeric(2), 1, 12, charge),
+        (5, "191", "Advances", Conditional, Numeric(2), 1, 9, advances),
+        (6, "117", "Prepaid Amount", Conditional, Numeric(2), 1, 9, prepaid_amount),
+        (7, "120", "Rate Combination Point Code", Optional, AlphaNumeric, 3, 9, rate_combination_point_code),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "121", "Rate Class Code", Optional, Id, 1, 3, rate_class_code),
+        (10, "39", "Entitlement Code", Optional, Id, 1, 1, entitlement_code),
+        (11, "16", "Charge Method of Payment", Optional, Id, 1, 1, charge_method_of_payment),
+        (12, "276", "Special Charge Description", Optional, AlphaNumeric, 2, 25, special_charge_description),
+        (13, "257", "Tariff Application Code", Optional, Id, 1, 1, tariff_application_code),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+        (16, "372", "Lading Liability Code", Optional, Id, 1, 1, lading_liability_code),
+        (17, "220", "Billed/Rated-as Quantity", Conditional, Decimal, 1, 11, billed_rated_as_quantity),
+        (18, "221", "Billed/Rated-as Qualifier", Conditional, Id, 2, 2, billed_rated_as_qualifier),
+        (19, "954", "Percent", Optional, Decimal, 1, 10, percent),
+        (20, "100", "Currency Code", Optional, Id, 3, 3, currency_code),
+        (21, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+    ]
+}
+
+segment_meta! {
+    L3,
+    "Total Weight and Charges",
+    "To specify the total shipment in terms of weight, volume, rates, charges, advances, and prepaid amounts applicable to one or more line items",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15],
+    [
+        (1, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (2, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (3, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate),
+        (4, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_04),
+        (5, "58", "Charge", Optional, Numeric(2), 1, 12, charge),
+        (6, "191", "Advances", Optional, Numeric(2), 1, 9, advances),
+        (7, "117", "Prepaid Amount", Optional, Numeric(2), 1, 9, prepaid_amount),
+        (8, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (9, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (10, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (11, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (12, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (13, "171", "Tariff Number", Optional, AlphaNumeric, 1, 7, tariff_number),
+        (14, "74", "Declared Value", Conditional, Numeric(2), 2, 12, declared_value),
+        (15, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_15),
+    ]
+}
+
+segment_meta! {
+    L5,
+    "Description, Marks and Numbers",
+    "To specify the line item in terms of description, quantity, packaging, and marks and numbers",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10],
+    [
+        (1, "213", "Lading Line Item Number", Optional, Numeric(0), 1, 3, lading_line_item_number),
+        (2, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (3, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_03),
+        (4, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_04),
+        (5, "103", "Packaging Code", Optional, AlphaNumeric, 3, 5, packaging_code),
+        (6, "87", "Marks and Numbers", Conditional, AlphaNumeric, 1, 48, marks_and_numbers),
+        (7, "88", "Marks and Numbers Qualifier", Optional, Id, 1, 2, marks_and_numbers_qualifier),
+        (8, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier_08),
+        (9, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code_09),
+        (10, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+    ]
+}
+
+segment_meta! {
+    LE,
+    "Loop Trailer",
+    "To indicate that the loop immediately preceding this segment is complete",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 6, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LEP,
+    "EPA Required Data",
+    "To specify the Environmental Protection Agency (EPA) information relating to shipments of hazardous material",
+    [_01, _02, _03, _04],
+    [
+        (1, "806", "EPA Waste Stream Number Code", Optional, Id, 4, 6, epa_waste_stream_number_code),
+        (2, "807", "Waste Characteristics Code", Optional, Id, 12, 16, waste_characteristics_code),
+        (3, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (4, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+    ]
+}
+
+segment_meta! {
+    LFH,
+    "Free-form Hazardous Material Information",
+    "To uniquely identify the variable information required by government regulation covering the transportation of hazardous material shipments",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "808", "Hazardous Material Shipment Information Qualifier", Mandatory, Id, 3, 3, hazardous_material_shipment_information_qualifier),
+        (2, "809", "Hazardous Material Shipment Information", Mandatory, AlphaNumeric, 1, 25, hazardous_material_shipment_information_02),
+        (3, "809", "Hazardous Material Shipment Information", Optional, AlphaNumeric, 1, 25, hazardous_material_shipment_information_03),
+        (4, "1023", "Hazard Zone Code", Optional, Id, 1, 1, hazard_zone_code),
+        (5, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (6, "380", "Quantity", Conditional, Decimal, 1, 15, quantity_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity_07),
+    ]
+}
+
+segment_meta! {
+    LH1,
+    "Hazardous Identification Information",
+    "To specify the hazardous commodity identification reference number and quantity",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "355", "Unit or Basis for Measurement Code", Mandatory, Id, 2, 2, unit_or_basis_for_measurement_code_01),
+        (2, "80", "Lading Quantity", Mandatory, Numeric(0), 1, 7, lading_quantity),
+        (3, "277", "UN/NA Identification Code", Optional, Id, 6, 6, un_na_identification_code),
+        (4, "200", "Hazardous Materials Page", Optional, AlphaNumeric, 1, 6, hazardous_materials_page),
+        (5, "22", "Commodity Code", Optional, AlphaNumeric, 1, 30, commodity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (8, "595", "Compartment ID Code", Optional, Id, 1, 1, compartment_id_code),
+        (9, "665", "Residue Indicator Code", Optional, Id, 1, 1, residue_indicator_code),
+        (10, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (11, "1375", "Interim Hazardous Material Regulatory Number", Optional, AlphaNumeric, 1, 5, interim_hazardous_material_regulatory_number),
+    ]
+}
+
+segment_meta! {
+    LH2,
+    "Hazardous Identification Information",
+    "To specify the hazardous notation and endorsement information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "983", "Hazardous Class Qualifier", Optional, Id, 1, 1, hazardous_class_qualifier),
+        (3, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (4, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+        (5, "759", "Reportable Quantity Code", Optional, Id, 2, 2, reportable_quantity_code),
+        (6, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_06),
+        (7, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_07),
+        (8, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_08),
+        (9, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_09),
+        (10, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code_10),
+        (11, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_11),
+    ]
+}
+
+segment_meta! {
+    LH3,
+    "Hazardous Material Shipping Name Information",
+    "To specify the hazardous material shipping name and additional descriptive requirements",
+    [_01, _02, _03, _04],
+    [
+        (1, "224", "Hazardous Material Shipping Name", Conditional, AlphaNumeric, 1, 25, hazardous_material_shipping_name),
+        (2, "984", "Hazardous Material Shipping Name Qualifier", Conditional, Id, 1, 1, hazardous_material_shipping_name_qualifier),
+        (3, "985", "N.O.S. Indicator Code", Optional, Id, 3, 3, n_o_s_indicator_code),
+        (4, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    LH4,
+    "Canadian Dangerous Requirements",
+    "To specify additional Transport Canada requirements covering transportation of dangerous goods in Canada",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "238", "Emergency Response Plan Number", Optional, AlphaNumeric, 1, 12, emergency_response_plan_number),
+        (2, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_02),
+        (3, "254", "Packing Group Code", Optional, Id, 1, 3, packing_group_code),
+        (4, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_04),
+        (5, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_05),
+        (6, "230", "Subsidiary Classification", Optional, Id, 1, 3, subsidiary_classification_06),
+        (7, "271", "Subsidiary Risk Indicator", Optional, Id, 1, 2, subsidiary_risk_indicator),
+        (8, "267", "Net Explosive Quantity", Conditional, Numeric(0), 1, 6, net_explosive_quantity),
+        (9, "805", "Canadian Hazardous Notation", Optional, AlphaNumeric, 1, 25, canadian_hazardous_notation),
+        (10, "986", "Special Commodity Indicator Code", Optional, Id, 1, 1, special_commodity_indicator_code),
+        (11, "364", "Communication Number", Optional, AlphaNumeric, 1, 80, communication_number_11),
+        (12, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+    ]
+}
+
+segment_meta! {
+    LH6,
+    "Hazardous Certification",
+    "To specify the name of the person certifying that the shipment complies with the regulations and/or the actual certification",
+    [_01, _02, _03, _04],
+    [
+        (1, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (2, "272", "Hazardous Certification Code", Conditional, Id, 1, 1, hazardous_certification_code),
+        (3, "273", "Hazardous Certification Declaration", Conditional, AlphaNumeric, 1, 25, hazardous_certification_declaration_03),
+        (4, "273", "Hazardous Certification Declaration", Optional, AlphaNumeric, 1, 25, hazardous_certification_declaration_04),
+    ]
+}
+
+segment_meta! {
+    LHR,
+    "Hazardous Material Identification Reference Numbers",
+    "To transmit specific hazardous material reference numbers",
+    [_01, _02, _03],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+    ]
+}
+
+segment_meta! {
+    LHT,
+    "Hazardous Material Identification Reference Numbers",
+    "To specify the placard information required by the second government agency when shipment is to cross into another country",
+    [_01, _02, _03],
+    [
+        (1, "215", "Hazardous Classification", Optional, Id, 1, 30, hazardous_classification),
+        (2, "218", "Hazardous Placard Notation", Optional, Id, 14, 40, hazardous_placard_notation),
+        (3, "222", "Hazardous Endorsement", Optional, Id, 4, 25, hazardous_endorsement),
+    ]
+}
+
+segment_meta! {
+    LS,
+    "Loop Header",
+    "To indicate that the next segment begins a loop",
+    [_01],
+    [
+        (1, "447", "Loop Identifier Code", Mandatory, AlphaNumeric, 1, 6, loop_identifier_code),
+    ]
+}
+
+segment_meta! {
+    LX,
+    "Transaction Set Line Number",
+    "To reference a line number in a transaction set",
+    [_01],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+    ]
+}
+
+segment_meta! {
+    M1,
+    "Insurance",
+    "To specify details related to insurance",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "26", "Country Code", Mandatory, Id, 2, 3, country_code),
+        (2, "14", "Carriage Value", Optional, Numeric(0), 2, 8, carriage_value),
+        (3, "74", "Declared Value", Optional, Numeric(2), 2, 12, declared_value),
+        (4, "122", "Rate/Value Qualifier", Optional, Id, 2, 2, rate_value_qualifier_04),
+        (5, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+        (6, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message),
+        (7, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_07),
+        (8, "782", "Monetary Amount", Conditional, Decimal, 1, 18, monetary_amount),
+        (9, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier_09),
+        (10, "954", "Percent", Conditional, Decimal, 1, 10, percent_10),
+        (11, "1004", "Percent Qualifier", Conditional, Id, 1, 2, percent_qualifier_11),
+        (12, "954", "Percent", Conditional, Decimal, 1, 10, percent_12),
+    ]
+}
+
+segment_meta! {
+    M12,
+    "In-bond Indifying Information",
+    "To transmit in-bond information",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "581", "Customs Entry Type Code", Mandatory, Id, 2, 2, customs_entry_type_code),
+        (2, "601", "Customs Entry Number", Conditional, AlphaNumeric, 1, 15, customs_entry_number),
+        (3, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier_03),
+        (4, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier_04),
+        (5, "602", "Customs Shipment Value", Optional, AlphaNumeric, 2, 8, customs_shipment_value),
+        (6, "603", "In-bond Control Number", Conditional, AlphaNumeric, 1, 25, in_bond_control_number),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "128", "Reference Identification Qualifier", Conditional, Id, 2, 3, reference_identification_qualifier),
+        (9, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (10, "91", "Transportation Method/Type Code", Conditional, Id, 1, 2, transportation_method_type_code),
+        (11, "182", "Vessel Name", Conditional, AlphaNumeric, 2, 28, vessel_name),
+    ]
+}
+
+segment_meta! {
+    M3,
+    "Release",
+    "To indicate that the equipment is or is not to be released",
+    [_01, _02, _03, _04],
+    [
+        (1, "132", "Release Code", Mandatory, Id, 1, 1, release_code),
+        (2, "373", "Date", Conditional, Date, 8, 8, date),
+        (3, "337", "Time", Conditional, Time, 4, 8, time),
+        (4, "623", "Time Code", Optional, Id, 2, 2, time_code),
+    ]
+}
+
+segment_meta! {
+    M7,
+    "Seal Numbers",
+    "To record seal numbers used and the organization that applied the seals",
+    [_01, _02, _03, _04, _05],
+    [
+        (1, "225", "Seal Number", Mandatory, AlphaNumeric, 2, 15, seal_number_01),
+        (2, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_02),
+        (3, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_03),
+        (4, "225", "Seal Number", Optional, AlphaNumeric, 2, 15, seal_number_04),
+        (5, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code),
+    ]
+}
+
+segment_meta! {
+    MEA,
+    "Measurements",
+    "To specify physical measurements or counts, including dimensions, tolerances, variances, and weights",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "737", "Measurement Reference ID Code", Optional, Id, 2, 2, measurement_reference_id_code),
+        (2, "738", "Measurement Qualifier", Optional, Id, 1, 3, measurement_qualifier),
+        (3, "739", "Measurement Value", Conditional, Decimal, 1, 20, measurement_value),
+        (4, "C001", "Composite Unit of Measure", Conditional, Composite, 0, 0, composite_unit_of_measure),
+        (5, "740", "Range Minimum", Conditional, Decimal, 1, 20, range_minimum),
+        (6, "741", "Range Maximum", Conditional, Decimal, 1, 20, range_maximum),
+        (7, "935", "Measurement Significance Code", Optional, Id, 2, 2, measurement_significance_code),
+        (8, "936", "Measurement Attribute Code", Conditional, Id, 2, 2, measurement_attribute_code),
+        (9, "752", "Surface/Layer/Position Code", Optional, Id, 2, 2, surface_layer_position_code),
+        (10, "1373", "Measurement Method or Device", Optional, Id, 2, 4, measurement_method_or_device),
+        (11, "1270", "Code List Qualifier Code", Conditional, Id, 1, 3, code_list_qualifier_code),
+        (12, "1271", "Industry Code", Conditional, AlphaNumeric, 1, 30, industry_code),
+    ]
+}
+
+segment_meta! {
+    N1,
+    "Party Identification",
+    "To identify a party by type of organization, name, and code",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "98", "Entity Identifier Code", Mandatory, Id, 2, 3, entity_identifier_code_01),
+        (2, "93", "Name", Conditional, AlphaNumeric, 1, 60, name),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "706", "Entity Relationship Code", Optional, Id, 2, 2, entity_relationship_code),
+        (6, "98", "Entity Identifier Code", Optional, Id, 2, 3, entity_identifier_code_06),
+    ]
+}
+
+segment_meta! {
+    N10,
+    "Quantity and Description",
+    "To indicate line item quantity, description, marks and numbers, commodity code, weight, and customs value",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "380", "Quantity", Optional, Decimal, 1, 15, quantity),
+        (2, "369", "Free-form Description", Optional, AlphaNumeric, 1, 45, free_form_description),
+        (3, "87", "Marks and Numbers", Optional, AlphaNumeric, 1, 48, marks_and_numbers),
+        (4, "23", "Commodity Code Qualifier", Conditional, Id, 1, 1, commodity_code_qualifier),
+        (5, "22", "Commodity Code", Conditional, AlphaNumeric, 1, 30, commodity_code),
+        (6, "602", "Customs Shipment Value", Conditional, AlphaNumeric, 2, 8, customs_shipment_value),
+        (7, "188", "Weight Unit Code", Conditional, Id, 1, 1, weight_unit_code),
+        (8, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (9, "127", "Reference Identification", Optional, AlphaNumeric, 1, 30, reference_identification),
+        (10, "599", "Manifest Unit Code", Optional, Id, 1, 3, manifest_unit_code),
+        (11, "26", "Country Code", Optional, Id, 2, 3, country_code_11),
+        (12, "26", "Country Code", Optional, Id, 2, 3, country_code_12),
+        (13, "100", "Currency Code", Conditional, Id, 3, 3, currency_code),
+    ]
+}
+
+segment_meta! {
+    N2,
+    "Additional Name Information",
+    "To specify additional names or those longer than 35 characters in length",
+    [_01, _02],
+    [
+        (1, "93", "Name", Mandatory, AlphaNumeric, 1, 60, name_01),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name_02),
+    ]
+}
+
+segment_meta! {
+    N3,
+    "Party Location",
+    "To specify the location of the named party",
+    [_01, _02],
+    [
+        (1, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_01),
+        (2, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_02),
+    ]
+}
+
+segment_meta! {
+    N4,
+    "Geographic Location",
+    "To specify the geographic place of the named party",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (2, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (3, "116", "Postal Code", Optional, Id, 3, 15, postal_code),
+        (4, "26", "Country Code", Conditional, Id, 2, 3, country_code),
+        (5, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (6, "310", "Location Identifier", Optional, AlphaNumeric, 1, 30, location_identifier),
+        (7, "1715", "Country Subdivision Code", Conditional, Id, 1, 3, country_subdivision_code),
+    ]
+}
+
+segment_meta! {
+    N5,
+    "Equipment Ordered",
+    "To specify carrier equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (2, "233", "Weight Capacity", Optional, Numeric(0), 2, 3, weight_capacity),
+        (3, "203", "Cubic Capacity", Optional, Numeric(0), 2, 4, cubic_capacity),
+        (4, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+        (5, "216", "Metric Qualifier", Optional, Id, 1, 1, metric_qualifier),
+        (6, "65", "Height", Optional, Decimal, 1, 8, height),
+        (7, "643", "Lading Percentage", Conditional, Numeric(2), 2, 4, lading_percentage),
+        (8, "644", "Lading Percent Qualifier", Conditional, Id, 1, 1, lading_percent_qualifier),
+        (9, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+    ]
+}
+
+segment_meta! {
+    N7,
+    "Equipment Details",
+    "To identify the equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24],
+    [
+        (1, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (2, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (3, "81", "Weight", Conditional, Decimal, 1, 10, weight),
+        (4, "187", "Weight Qualifier", Conditional, Id, 1, 2, weight_qualifier),
+        (5, "167", "Tare Weight", Conditional, Numeric(0), 3, 8, tare_weight),
+        (6, "232", "Weight Allowance", Optional, Numeric(0), 2, 6, weight_allowance),
+        (7, "205", "Dunnage", Optional, Numeric(0), 1, 6, dunnage),
+        (8, "183", "Volume", Conditional, Decimal, 1, 8, volume),
+        (9, "184", "Volume Unit Qualifier", Conditional, Id, 1, 1, volume_unit_qualifier),
+        (10, "102", "Ownership Code", Optional, Id, 1, 1, ownership_code),
+        (11, "40", "Equipment Description Code", Optional, Id, 2, 2, equipment_description_code),
+        (12, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_12),
+        (13, "319", "Temperature Control", Optional, AlphaNumeric, 3, 6, temperature_control),
+        (14, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (15, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (16, "571", "Tare Qualifier Code", Conditional, Id, 1, 1, tare_qualifier_code),
+        (17, "188", "Weight Unit Code", Optional, Id, 1, 1, weight_unit_code),
+        (18, "761", "Equipment Number Check Digit", Optional, Numeric(0), 1, 1, equipment_number_check_digit),
+        (19, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (20, "65", "Height", Optional, Decimal, 1, 8, height),
+        (21, "189", "Width", Optional, Decimal, 1, 8, width),
+        (22, "24", "Equipment Type", Optional, Id, 4, 4, equipment_type),
+        (23, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_23),
+        (24, "301", "Car Type Code", Optional, Id, 1, 4, car_type_code),
+    ]
+}
+
+segment_meta! {
+    N9,
+    "Extended Reference Information",
+    "",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "369", "Free-form Description", Conditional, AlphaNumeric, 1, 45, free_form_description),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "337", "Time", Conditional, Time, 4, 8, time),
+        (6, "623", "Time Code", Optional, Id, 2, 2, time_code),
+        (7, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    NA,
+    "Cross-Reference Equipment",
+    "To cross-reference additional equipment to a primary piece of equipment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "128", "Reference Identification Qualifier", Optional, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "206", "Equipment Initial", Mandatory, AlphaNumeric, 1, 4, equipment_initial),
+        (4, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (5, "231", "Cross Reference Type Code", Optional, Id, 1, 1, cross_reference_type_code),
+        (6, "219", "Position", Optional, AlphaNumeric, 1, 3, position),
+        (7, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_07),
+        (8, "567", "Equipment Length", Optional, Numeric(0), 4, 5, equipment_length),
+        (9, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_09),
+        (10, "845", "Chassis Type", Optional, Id, 2, 2, chassis_type),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code),
+    ]
+}
+
+segment_meta! {
+    PER,
+    "Administrative Communication Contract",
+    "To identify a person or office to whom administrative communications should be directed",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "366", "Contact Function Code", Mandatory, Id, 2, 2, contact_function_code),
+        (2, "93", "Name", Optional, AlphaNumeric, 1, 60, name),
+        (3, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_03),
+        (4, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_04),
+        (5, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_05),
+        (6, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_06),
+        (7, "365", "Communication Number Qualifier", Conditional, Id, 2, 2, communication_number_qualifier_07),
+        (8, "364", "Communication Number", Conditional, AlphaNumeric, 1, 80, communication_number_08),
+        (9, "443", "Contact Inquiry Reference", Optional, AlphaNumeric, 1, 20, contact_inquiry_reference),
+    ]
+}
+
+segment_meta! {
+    PI,
+    "Price Authority Information",
+    "To communicate basis of pricing, such as contract number, quote number, or tariff number",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Mandatory, AlphaNumeric, 1, 30, reference_identification),
+        (3, "436", "Primary Publication Authority Code", Optional, Id, 2, 2, primary_publication_authority_code),
+        (4, "930", "Regulatory Agency Code", Optional, Id, 3, 5, regulatory_agency_code),
+        (5, "168", "Tariff Agency Code", Optional, Id, 1, 4, tariff_agency_code),
+        (6, "965", "Issuing Carrier Identifier", Optional, AlphaNumeric, 1, 10, issuing_carrier_identifier),
+        (7, "660", "Contract Suffix", Optional, AlphaNumeric, 1, 2, contract_suffix_07),
+        (8, "169", "Tariff Item Number", Optional, AlphaNumeric, 1, 16, tariff_item_number),
+        (9, "173", "Tariff Supplement Identifier", Optional, AlphaNumeric, 1, 4, tariff_supplement_identifier),
+        (10, "172", "Tariff Section", Optional, AlphaNumeric, 1, 2, tariff_section),
+        (11, "660", "Contract Suffix", Optional, AlphaNumeric, 1, 2, contract_suffix_11),
+        (12, "373", "Date", Conditional, Date, 8, 8, date_12),
+        (13, "373", "Date", Conditional, Date, 8, 8, date_13),
+        (14, "629", "Alternation Precedence Code", Optional, Id, 1, 1, alternation_precedence_code_14),
+        (15, "629", "Alternation Precedence Code", Optional, Id, 1, 1, alternation_precedence_code_15),
+    ]
+}
+
+segment_meta! {
+    PS,
+    "Protective Service Instructions",
+    "To specify mechanical protective service and ventilation instructions",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14],
+    [
+        (1, "746", "Protective Service Rule Code", Mandatory, Id, 3, 9, protective_service_rule_code),
+        (2, "241", "Protective Service Code", Mandatory, Id, 1, 4, protective_service_code),
+        (3, "355", "Unit or Basis for Measurement Code", Conditional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (4, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_04),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "573", "Freight Station Accounting Code", Optional, Id, 1, 5, freight_station_accounting_code),
+        (7, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (8, "156", "State or Province Code", Optional, Id, 2, 2, state_or_province_code),
+        (9, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (10, "745", "Pre-Cooled (Rule 710) Code", Optional, Id, 1, 1, pre_cooled_rule_710_code),
+        (11, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_11),
+        (12, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_12),
+        (13, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_13),
+        (14, "408", "Temperature", Conditional, Decimal, 1, 4, temperature_14),
+    ]
+}
+
+segment_meta! {
+    R2,
+    "Route Information",
+    "To specify carrier and routing sequences and details",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (2, "133", "Routing Sequence Code", Mandatory, Id, 1, 2, routing_sequence_code),
+        (3, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (4, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (5, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (6, "91", "Transportation Method/Type Code", Optional, Id, 1, 2, transportation_method_type_code),
+        (7, "296", "Intermediate Switch Carrier", Conditional, Id, 2, 4, intermediate_switch_carrier_07),
+        (8, "296", "Intermediate Switch Carrier", Optional, Id, 2, 4, intermediate_switch_carrier_08),
+        (9, "76", "Invoice Number", Optional, AlphaNumeric, 1, 22, invoice_number),
+        (10, "373", "Date", Optional, Date, 8, 8, date),
+        (11, "369", "Free-form Description", Optional, AlphaNumeric, 1, 45, free_form_description),
+        (12, "56", "Type of Service Code", Optional, Id, 2, 2, type_of_service_code),
+        (13, "742", "Route Description", Optional, AlphaNumeric, 1, 35, route_description),
+    ]
+}
+
+segment_meta! {
+    R9,
+    "Code Identification",
+    "To specify the route using a single code",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "1", "Route Code", Mandatory, AlphaNumeric, 1, 13, route_code),
+        (2, "192", "Agent/Shipper Routing Code", Optional, Id, 1, 1, agent_shipper_routing_code),
+        (3, "177", "Intermodal Service Code", Optional, Id, 1, 2, intermodal_service_code),
+        (4, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_04),
+        (5, "306", "Action Code", Optional, Id, 1, 2, action_code),
+        (6, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code_06),
+        (7, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_07),
+        (8, "1073", "Yes/No Condition or Response Code", Optional, Id, 1, 1, yes_no_condition_or_response_code_08),
+    ]
+}
+
+segment_meta! {
+    REF,
+    "Party Identification",
+    "To specify identifying information",
+    [_01, _02, _03, _04],
+    [
+        (1, "128", "Reference Identification Qualifier", Mandatory, Id, 2, 3, reference_identification_qualifier),
+        (2, "127", "Reference Identification", Conditional, AlphaNumeric, 1, 30, reference_identification),
+        (3, "352", "Description", Conditional, AlphaNumeric, 1, 80, description),
+        (4, "C040", "Reference Identifier", Optional, Composite, 0, 0, reference_identifier),
+    ]
+}
+
+segment_meta! {
+    S1,
+    "Stop-off Name",
+    "To identify a stop-off party",
+    [_01, _02, _03, _04, _05, _06],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "459", "Name (30 Character Format)", Mandatory, AlphaNumeric, 2, 30, name_30_character_format),
+        (3, "66", "Identification Code Qualifier", Conditional, Id, 1, 2, identification_code_qualifier),
+        (4, "67", "Identification Code", Conditional, AlphaNumeric, 2, 80, identification_code),
+        (5, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (6, "190", "Accomplish Code", Mandatory, Id, 1, 1, accomplish_code),
+    ]
+}
+
+segment_meta! {
+    S2,
+    "Stop-off Address",
+    "To specify the address of the stop-off party",
+    [_01, _02, _03],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "166", "Address Information", Mandatory, AlphaNumeric, 1, 55, address_information_02),
+        (3, "166", "Address Information", Optional, AlphaNumeric, 1, 55, address_information_03),
+    ]
+}
+
+segment_meta! {
+    S9,
+    "Stop-off Name",
+    "To specify location details for a stop-off",
+    [_01, _02, _03, _04, _05, _06, _07, _08],
+    [
+        (1, "165", "Stop Sequence Number", Mandatory, Numeric(0), 1, 3, stop_sequence_number),
+        (2, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (3, "19", "City Name", Mandatory, AlphaNumeric, 2, 30, city_name),
+        (4, "156", "State or Province Code", Mandatory, Id, 2, 2, state_or_province_code),
+        (5, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (6, "163", "Stop Reason Code", Mandatory, Id, 2, 2, stop_reason_code),
+        (7, "309", "Location Qualifier", Conditional, Id, 1, 2, location_qualifier),
+        (8, "310", "Location Identifier", Conditional, AlphaNumeric, 1, 30, location_identifier),
+    ]
+}
+
+segment_meta! {
+    SE,
+    "Transaction Set Trailer",
+    "To indicate the end of the transaction set and provide the count of the transmitted segments (including the beginning (ST) and ending (SE) segments)",
+    [_01, _02],
+    [
+        (1, "96", "Number of Included Segments", Mandatory, Numeric(0), 1, 10, number_of_included_segments),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    ST,
+    "Transaction Set Header",
+    "To indicate the start of a transaction set and to assign a control number",
+    [_01, _02],
+    [
+        (1, "143", "Transaction Set Identifier Code", Mandatory, Id, 3, 3, transaction_set_identifier_code),
+        (2, "329", "Transaction Set Control Number", Mandatory, AlphaNumeric, 4, 9, transaction_set_control_number),
+    ]
+}
+
+segment_meta! {
+    T1,
+    "Transit Inbound Origin",
+    "To specify origin point and waybill references of movement to transit waybill point",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "186", "Waybill Number", Optional, Numeric(0), 1, 6, waybill_number),
+        (3, "373", "Date", Optional, Date, 8, 8, date),
+        (4, "140", "Standard Carrier Alpha Code", Optional, Id, 2, 4, standard_carrier_alpha_code),
+        (5, "19", "City Name", Conditional, AlphaNumeric, 2, 30, city_name),
+        (6, "156", "State or Province Code", Conditional, Id, 2, 2, state_or_province_code),
+        (7, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (8, "229", "Transit Registration Number", Optional, AlphaNumeric, 1, 6, transit_registration_number),
+        (9, "461", "Transit Level Code", Optional, Id, 1, 3, transit_level_code),
+    ]
+}
+
+segment_meta! {
+    T2,
+    "Transit Inbound Lading",
+    "To specify lading description, including weight and rate details applying to the associated T1 segment",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "79", "Lading Description", Optional, AlphaNumeric, 1, 50, lading_description),
+        (3, "81", "Weight", Optional, Decimal, 1, 10, weight),
+        (4, "187", "Weight Qualifier", Optional, Id, 1, 2, weight_qualifier),
+        (5, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_05),
+        (6, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_06),
+        (7, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_07),
+        (8, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_08),
+        (9, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_09),
+        (10, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_10),
+        (11, "462", "Through Surcharge Percent", Optional, Numeric(2), 2, 4, through_surcharge_percent),
+        (12, "463", "Paid-In Surcharge Percent", Optional, Numeric(2), 2, 4, paid_in_surcharge_percent),
+    ]
+}
+
+segment_meta! {
+    T3,
+    "Transit Inbound Route",
+    "To specify transit inbound routing, including equipment identifications for associated T1 and T2 segments",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (3, "133", "Routing Sequence Code", Optional, Id, 1, 2, routing_sequence_code),
+        (4, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name),
+        (5, "154", "Standard Point Location Code", Optional, Id, 6, 9, standard_point_location_code),
+        (6, "206", "Equipment Initial", Conditional, AlphaNumeric, 1, 4, equipment_initial),
+        (7, "207", "Equipment Number", Conditional, AlphaNumeric, 1, 10, equipment_number),
+    ]
+}
+
+segment_meta! {
+    T6,
+    "Transit Inbound Rates",
+    "To identify the transit inbound prior origin point and waybill reference of movement to the point specified in T1 segment",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_02),
+        (3, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_03),
+        (4, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_04),
+        (5, "60", "Freight Rate", Conditional, Decimal, 1, 9, freight_rate_05),
+        (6, "122", "Rate/Value Qualifier", Conditional, Id, 2, 2, rate_value_qualifier_06),
+        (7, "19", "City Name", Optional, AlphaNumeric, 2, 30, city_name_07),
+    ]
+}
+
+segment_meta! {
+    T8,
+    "Transit Inbound Rates",
+    "To transmit information in a free-form format relating to a specified transit sequence number",
+    [_01, _02],
+    [
+        (1, "554", "Assigned Number", Mandatory, Numeric(0), 1, 6, assigned_number),
+        (2, "299", "Free-form Transit Data", Mandatory, AlphaNumeric, 1, 80, free_form_transit_data),
+    ]
+}
+
+segment_meta! {
+    VC,
+    "Motor Vehicle Control",
+    "To define motor vehicle identification and logistics",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13],
+    [
+        (1, "539", "Vehicle Identification Number", Mandatory, AlphaNumeric, 1, 25, vehicle_identification_number),
+        (2, "836", "Vehicle Deck Position Code", Optional, Id, 2, 2, vehicle_deck_position_code),
+        (3, "837", "Vehicle Type Code", Optional, Id, 1, 1, vehicle_type_code),
+        (4, "838", "Dealer Code", Optional, AlphaNumeric, 2, 9, dealer_code),
+        (5, "1", "Route Code", Optional, AlphaNumeric, 1, 13, route_code),
+        (6, "839", "Bay Location", Optional, AlphaNumeric, 1, 6, bay_location),
+        (7, "833", "Automotive Manufacturers Code", Optional, Id, 2, 2, automotive_manufacturers_code),
+        (8, "308", "Damage Exception Indicator", Optional, Id, 1, 1, damage_exception_indicator),
+        (9, "835", "Supplemental Inspection Code", Optional, Id, 1, 1, supplemental_inspection_code),
+        (10, "583", "Factory Car Order Number", Optional, AlphaNumeric, 6, 10, factory_car_order_number),
+        (11, "877", "Vessel Stowage Location", Optional, AlphaNumeric, 1, 12, vessel_stowage_location),
+    ]
+}
+
+segment_meta! {
+    X1,
+    "Export License",
+    "To transmit information contained on an export license",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17],
+    [
+        (1, "83", "Licensing Agency Code", Optional, Id, 1, 1, licensing_agency_code),
+        (2, "50", "Export License Number", Optional, AlphaNumeric, 6, 12, export_license_number),
+        (3, "51", "Export License Status Code", Optional, Id, 1, 1, export_license_status_code),
+        (4, "373", "Date", Optional, Date, 8, 8, date),
+        (5, "52", "Export License Symbol Code", Optional, Id, 1, 2, export_license_symbol_code),
+        (6, "48", "Export License Control Code", Optional, Id, 1, 1, export_license_control_code),
+        (7, "26", "Country Code", Optional, Id, 2, 3, country_code),
+        (8, "141", "Schedule B Code", Optional, Id, 7, 10, schedule_b_code),
+        (9, "210", "International/Domestic Code", Optional, Id, 1, 1, international_domestic_code),
+        (10, "80", "Lading Quantity", Optional, Numeric(0), 1, 7, lading_quantity),
+        (11, "148", "Lading Value", Optional, Decimal, 2, 9, lading_value),
+        (12, "47", "Export Filing Key Code", Optional, Id, 1, 1, export_filing_key_code),
+        (13, "355", "Unit or Basis for Measurement Code", Optional, Id, 2, 2, unit_or_basis_for_measurement_code),
+        (14, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+        (15, "1306", "U.S. Government License Type", Optional, AlphaNumeric, 1, 1, u_s_government_license_type),
+        (16, "67", "Identification Code", Optional, AlphaNumeric, 2, 80, identification_code),
+    ]
+}
+
+segment_meta! {
+    X7,
+    "Customs Information",
+    "To indicate customs information",
+    [_01, _02],
+    [
+        (1, "61", "Free-Form Message", Mandatory, AlphaNumeric, 1, 30, free_form_message_01),
+        (2, "61", "Free-Form Message", Optional, AlphaNumeric, 1, 30, free_form_message_02),
+    ]
+}
+
+segment_meta! {
+    XH,
+    "Pro Forma - B13 Information",
+    "This segment is used to specify a pro forma invoice and B13 Canadian Customs Export Declaration information, required by U.S. and Canadian customs",
+    [_01, _02, _03, _04, _05, _06, _07],
+    [
+        (1, "100", "Currency Code", Mandatory, Id, 3, 3, currency_code),
+        (2, "645", "Related Company Indication Code", Optional, Id, 1, 1, related_company_indication_code),
+        (3, "150", "Special Charge or Allowance Code", Optional, Id, 3, 3, special_charge_or_allowance_code),
+        (4, "610", "Amount", Optional, Numeric(2), 1, 15, amount),
+        (5, "503", "Block 20 Code", Optional, Id, 1, 1, block_20_code),
+        (6, "504", "Chemical Analysis Percentage", Optional, Numeric(2), 2, 9, chemical_analysis_percentage),
+        (7, "212", "Unit Price", Optional, Decimal, 1, 17, unit_price),
+    ]
+}
+
+segment_meta! {
+    ZC1,
+    "Beginning Segment for Data Correction or Change",
+    "To transmit identifying numbers, dates, and other basic data relating to the transaction set",
+    [_01, _02, _03, _04, _05, _06, _07, _08, _09],
+    [
+        (1, "145", "Shipment Identification Number", Optional, AlphaNumeric, 1, 30, shipment_identification_number),
+        (2, "206", "Equipment Initial", Optional, AlphaNumeric, 1, 4, equipment_initial),
+        (3, "207", "Equipment Number", Mandatory, AlphaNumeric, 1, 10, equipment_number),
+        (4, "244", "Transaction Reference Number", Mandatory, AlphaNumeric, 1, 15, transaction_reference_number),
+        (5, "243", "Transaction Reference Date", Mandatory, Date, 8, 8, transaction_reference_date),
+        (6, "202", "Correction Indicator", Mandatory, Id, 2, 2, correction_indicator),
+        (7, "140", "Standard Carrier Alpha Code", Mandatory, Id, 2, 4, standard_carrier_alpha_code),
+        (8, "91", "Transportation Method/Type Code", Mandatory, Id, 1, 2, transportation_method_type_code),
+    ]
+}
+
+/// Descriptor of the segment with the given ID, e.g. `"ST"`.
+pub fn segment_descriptor(id: &str) -> Option<&'static SegmentDescriptor> {
+    match id {
+        "BL" => Some(BL::DESCRIPTOR),
+        "BNX" => Some(BNX::DESCRIPTOR),
+        "BX" => Some(BX::DESCRIPTOR),
+        "CD" => Some(CD::DESCRIPTOR),
+        "CM" => Some(CM::DESCRIPTOR),
+        "D9" => Some(D9::DESCRIPTOR),
+        "DTM" => Some(DTM::DESCRIPTOR),
+        "E1" => Some(E1::DESCRIPTOR),
+        "E4" => Some(E4::DESCRIPTOR),
+        "E5" => Some(E5::DESCRIPTOR),
+        "EM" => Some(EM::DESCRIPTOR),
+        "F9" => Some(F9::DESCRIPTOR),
+        "GA" => Some(GA::DESCRIPTOR),
+        "GE" => Some(GE::DESCRIPTOR),
+        "GS" => Some(GS::DESCRIPTOR),
+        "H3" => Some(H3::DESCRIPTOR),
+        "IC" => Some(IC::DESCRIPTOR),
+        "IEA" => Some(IEA::DESCRIPTOR),
+        "IM" => Some(IM::DESCRIPTOR),
+        "ISA" => Some(ISA::DESCRIPTOR),
+        "L0" => Some(L0::DESCRIPTOR),
+        "L1" => Some(L1::DESCRIPTOR),
+        "L3" => Some(L3::DESCRIPTOR),
+        "L5" => Some(L5::DESCRIPTOR),
+        "LE" => Some(LE::DESCRIPTOR),
+        "LEP" => Some(LEP::DESCRIPTOR),
+        "LFH" => Some(LFH::DESCRIPTOR),
+        "LH1" => Some(LH1::DESCRIPTOR),
+        "LH2" => Some(LH2::DESCRIPTOR),
+        "LH3" => Some(LH3::DESCRIPTOR),
+        "LH4" => Some(LH4::DESCRIPTOR),
+        "LH6" => Some(LH6::DESCRIPTOR),
+        "LHR" => Some(LHR::DESCRIPTOR),
+        "LHT" => Some(LHT::DESCRIPTOR),
+        "LS" => Some(LS::DESCRIPTOR),
+        "LX" => Some(LX::DESCRIPTOR),
+        "M1" => Some(M1::DESCRIPTOR),
+        "M12" => Some(M12::DESCRIPTOR),
+        "M3" => Some(M3::DESCRIPTOR),
+        "M7" => Some(M7::DESCRIPTOR),
+        "MEA" => Some(MEA::DESCRIPTOR),
+        "N1" => Some(N1::DESCRIPTOR),
+        "N10" => Some(N10::DESCRIPTOR),
+        "N2" => Some(N2::DESCRIPTOR),
+        "N3" => Some(N3::DESCRIPTOR),
+        "N4" => Some(N4::DESCRIPTOR),
+        "N5" => Some(N5::DESCRIPTOR),
+        "N7" => Some(N7::DESCRIPTOR),
+        "N9" => Some(N9::DESCRIPTOR),
+        "NA" => Some(NA::DESCRIPTOR),
+        "PER" => Some(PER::DESCRIPTOR),
+        "PI" => Some(PI::DESCRIPTOR),
+        "PS" => Some(PS::DESCRIPTOR),
+        "R2" => Some(R2::DESCRIPTOR),
+        "R9" => Some(R9::DESCRIPTOR),
+        "REF" => Some(REF::DESCRIPTOR),
+        "S1" => Some(S1::DESCRIPTOR),
+        "S2" => Some(S2::DESCRIPTOR),
+        "S9" => Some(S9::DESCRIPTOR),
+        "SE" => Some(SE::DESCRIPTOR),
+        "ST" => Some(ST::DESCRIPTOR),
+        "T1" => Some(T1::DESCRIPTOR),
+        "T2" => Some(T2::DESCRIPTOR),
+        "T3" => Some(T3::DESCRIPTOR),
+        "T6" => Some(T6::DESCRIPTOR),
+        "T8" => Some(T8::DESCRIPTOR),
+        "VC" => Some(VC::DESCRIPTOR),
+        "X1" => Some(X1::DESCRIPTOR),
+        "X7" => Some(X7::DESCRIPTOR),
+        "XH" => Some(XH::DESCRIPTOR),
+        "ZC1" => Some(ZC1::DESCRIPTOR),
+        _ => None,
+    }
+}